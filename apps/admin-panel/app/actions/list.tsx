@@ -67,6 +67,13 @@ gql`
                 }
               }
             }
+            ... on CreditFacility {
+              creditFacilityId
+              publicId
+              customer {
+                email
+              }
+            }
           }
         }
         cursor
@@ -112,7 +119,8 @@ const List: React.FC<ListProps> = ({ dashboard = false }) => {
     ) {
       return `/credit-facility-proposals/${data.target.creditFacilityProposalId}`
     } else if (
-      data.approvalProcessType === ApprovalProcessType.WithdrawalApproval &&
+      (data.approvalProcessType === ApprovalProcessType.WithdrawalApproval ||
+        data.approvalProcessType === ApprovalProcessType.WithdrawalOverLimitApproval) &&
       data.target.__typename === "Withdrawal"
     ) {
       return `/withdrawals/${data.target.publicId}`
@@ -121,6 +129,12 @@ const List: React.FC<ListProps> = ({ dashboard = false }) => {
       data.target.__typename === "CreditFacilityDisbursal"
     ) {
       return `/disbursals/${data.target.publicId}`
+    } else if (
+      (data.approvalProcessType === ApprovalProcessType.CreditFacilityAmendmentApproval ||
+        data.approvalProcessType === ApprovalProcessType.CreditFacilityWriteOffApproval) &&
+      data.target.__typename === "CreditFacility"
+    ) {
+      return `/credit-facilities/${data.target.publicId}`
     }
     return null
   }
@@ -135,6 +149,8 @@ const List: React.FC<ListProps> = ({ dashboard = false }) => {
             return target.creditFacility.customer.email
           case "CreditFacilityProposal":
             return target.customer.email
+          case "CreditFacility":
+            return target.customer.email
           case "Withdrawal":
             return target.account.customer.email
           default:
@@ -75,6 +75,10 @@ export const CreditFacilityHistory: React.FC<CreditFacilityHistoryProps> = ({
             return t("entryTypes.repaymentAmountReceived")
           case "CreditFacilityCollateralSentOut":
             return t("entryTypes.collateralSentOut")
+          case "CreditFacilityTermsAmended":
+            return t("entryTypes.termsAmended")
+          case "CreditFacilityWrittenOff":
+            return t("entryTypes.writtenOff")
         }
         const exhaustiveCheck: never = entry.__typename
         return exhaustiveCheck
@@ -118,6 +122,7 @@ export const CreditFacilityHistory: React.FC<CreditFacilityHistoryProps> = ({
           case "CreditFacilityDisbursalExecuted":
           case "CreditFacilityInterestAccrued":
           case "CreditFacilityRepaymentAmountReceived":
+          case "CreditFacilityWrittenOff":
             return <Balance amount={entry.cents} currency="usd" />
           case "CreditFacilityCollateralSentOut":
             return <Balance amount={entry.amount} currency="btc" />
@@ -65,6 +65,15 @@ gql`
         txId
         effective
       }
+      ... on CreditFacilityTermsAmended {
+        recordedAt
+        effective
+      }
+      ... on CreditFacilityWrittenOff {
+        cents
+        recordedAt
+        effective
+      }
       ... on PendingCreditFacilityCollateralizationUpdated {
         pendingState: state
         collateral
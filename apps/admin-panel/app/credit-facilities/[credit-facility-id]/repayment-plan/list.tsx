@@ -45,6 +45,8 @@ export const CreditFacilityRepaymentPlan: React.FC<CreditFacilityRepaymentPlanPr
         return t("repaymentTypes.principal")
       case CreditFacilityRepaymentType.Interest:
         return t("repaymentTypes.interest")
+      case CreditFacilityRepaymentType.Penalty:
+        return t("repaymentTypes.penalty")
       default: {
         const exhaustiveCheck: never = type
         return exhaustiveCheck
@@ -114,6 +116,10 @@ const getStatusVariant = (status: RepaymentPlan["status"]): BadgeProps["variant"
       return "destructive"
     case CreditFacilityRepaymentStatus.Paid:
       return "success"
+    case CreditFacilityRepaymentStatus.Rescheduled:
+      return "secondary"
+    case CreditFacilityRepaymentStatus.WrittenOff:
+      return "destructive"
     default: {
       const exhaustiveCheck: never = status
       return exhaustiveCheck
@@ -138,6 +144,10 @@ const RepaymentStatusBadge: React.FC<StatusBadgeProps> = ({ status, t, ...props
         return "defaulted"
       case CreditFacilityRepaymentStatus.Paid:
         return "paid"
+      case CreditFacilityRepaymentStatus.Rescheduled:
+        return "rescheduled"
+      case CreditFacilityRepaymentStatus.WrittenOff:
+        return "writtenOff"
       default: {
         const exhaustiveCheck: never = status
         return exhaustiveCheck
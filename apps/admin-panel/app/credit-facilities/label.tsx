@@ -107,6 +107,12 @@ export const InterestIntervalLabel = ({
       return t("endOfDay")
     case InterestInterval.EndOfMonth:
       return t("endOfMonth")
+    case InterestInterval.EndOfWeek:
+      return t("endOfWeek")
+    case InterestInterval.EndOfQuarter:
+      return t("endOfQuarter")
+    case InterestInterval.MonthlyAnniversary:
+      return t("monthlyAnniversary")
   }
 
   const exhaustiveCheck: never = interval
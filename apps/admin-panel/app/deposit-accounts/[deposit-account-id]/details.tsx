@@ -3,7 +3,7 @@
 import React, { useState, useMemo } from "react"
 import { useTranslations } from "next-intl"
 import { useRouter } from "next/navigation"
-import { ArrowRight, Snowflake, Sun, XCircle } from "lucide-react"
+import { ArrowRight, RotateCcw, Snowflake, Sun, XCircle } from "lucide-react"
 
 import { Button } from "@lana/web/ui/button"
 import {
@@ -28,6 +28,7 @@ import {
   DepositAccountStatus,
 } from "@/lib/graphql/generated"
 import UnfreezeDepositAccountDialog from "@/app/deposit-accounts/[deposit-account-id]/unfreeze-deposit-account"
+import ReactivateDepositAccountDialog from "@/app/deposit-accounts/[deposit-account-id]/reactivate-deposit-account"
 
 type DepositAccountDetailsProps = {
   depositAccount: NonNullable<GetDepositAccountDetailsQuery["depositAccountByPublicId"]>
@@ -45,6 +46,7 @@ const DepositAccountDetailsCard: React.FC<DepositAccountDetailsProps> = ({
   const router = useRouter()
   const [openFreezeDialog, setOpenFreezeDialog] = useState(false)
   const [openUnfreezeDialog, setOpenUnfreezeDialog] = useState(false)
+  const [openReactivateDialog, setOpenReactivateDialog] = useState(false)
   const [openCloseDialog, setOpenCloseDialog] = useState(false)
 
   const isBalanceZero = useMemo(() => {
@@ -70,6 +72,10 @@ const DepositAccountDetailsCard: React.FC<DepositAccountDetailsProps> = ({
     setOpenUnfreezeDialog(true)
   }
 
+  const handleReactivateAccount = () => {
+    setOpenReactivateDialog(true)
+  }
+
   const handleCloseAccount = () => {
     setOpenCloseDialog(true)
   }
@@ -116,6 +122,12 @@ const DepositAccountDetailsCard: React.FC<DepositAccountDetailsProps> = ({
           {t("buttons.unfreezeDepositAccount")}
         </Button>
       )}
+      {depositAccount.status === DepositAccountStatus.Dormant && (
+        <Button variant="outline" onClick={handleReactivateAccount}>
+          <RotateCcw />
+          {t("buttons.reactivateDepositAccount")}
+        </Button>
+      )}
       {(depositAccount.status === DepositAccountStatus.Active ||
         depositAccount.status === DepositAccountStatus.Inactive) && (
         <TooltipProvider>
@@ -157,6 +169,11 @@ const DepositAccountDetailsCard: React.FC<DepositAccountDetailsProps> = ({
         openUnfreezeDialog={openUnfreezeDialog}
         setOpenUnfreezeDialog={setOpenUnfreezeDialog}
       />
+      <ReactivateDepositAccountDialog
+        depositAccountId={depositAccount.depositAccountId}
+        openReactivateDialog={openReactivateDialog}
+        setOpenReactivateDialog={setOpenReactivateDialog}
+      />
       <CloseDepositAccountDialog
         depositAccountId={depositAccount.depositAccountId}
         openCloseDialog={openCloseDialog}
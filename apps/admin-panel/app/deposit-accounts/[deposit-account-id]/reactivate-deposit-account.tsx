@@ -0,0 +1,109 @@
+"use client"
+
+import React, { useState } from "react"
+import { gql } from "@apollo/client"
+import { useTranslations } from "next-intl"
+import { toast } from "sonner"
+
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from "@lana/web/ui/dialog"
+import { Button } from "@lana/web/ui/button"
+
+import {
+  useDepositAccountReactivateMutation,
+  GetDepositAccountDetailsDocument,
+} from "@/lib/graphql/generated"
+
+gql`
+  mutation DepositAccountReactivate($input: DepositAccountReactivateInput!) {
+    depositAccountReactivate(input: $input) {
+      account {
+        id
+      }
+    }
+  }
+`
+
+type ReactivateDepositAccountDialogProps = {
+  setOpenReactivateDialog: (isOpen: boolean) => void
+  openReactivateDialog: boolean
+  depositAccountId: string
+}
+
+export const ReactivateDepositAccountDialog: React.FC<ReactivateDepositAccountDialogProps> = ({
+  setOpenReactivateDialog,
+  openReactivateDialog,
+  depositAccountId,
+}) => {
+  const t = useTranslations(
+    "DepositAccounts.DepositAccountDetails.reactivateDepositAccount",
+  )
+  const commonT = useTranslations("Common")
+
+  const [reactivateDepositAccount, { loading, reset }] = useDepositAccountReactivateMutation({
+    refetchQueries: [GetDepositAccountDetailsDocument],
+  })
+  const [error, setError] = useState<string | null>(null)
+
+  const handleSubmit = async (e: React.FormEvent) => {
+    e.preventDefault()
+    setError(null)
+    try {
+      const result = await reactivateDepositAccount({
+        variables: {
+          input: {
+            depositAccountId,
+          },
+        },
+      })
+
+      if (result.data?.depositAccountReactivate) {
+        toast.success(t("success"))
+        handleCloseDialog()
+      } else {
+        setError(commonT("error"))
+      }
+    } catch (error) {
+      console.error("Error reactivating deposit account:", error)
+      setError(error instanceof Error && error.message ? error.message : commonT("error"))
+    }
+  }
+
+  const handleCloseDialog = () => {
+    setOpenReactivateDialog(false)
+    setError(null)
+    reset()
+  }
+
+  return (
+    <Dialog open={openReactivateDialog} onOpenChange={handleCloseDialog}>
+      <DialogContent>
+        <DialogHeader>
+          <DialogTitle>{t("title")}</DialogTitle>
+          <DialogDescription>{t("description")}</DialogDescription>
+        </DialogHeader>
+        <form className="flex flex-col gap-4" onSubmit={handleSubmit}>
+          {error && <p className="text-destructive">{error}</p>}
+          <DialogFooter>
+            <Button
+              type="submit"
+              variant="default"
+              disabled={loading}
+              data-testid="reactivate-deposit-account-dialog-button"
+            >
+              {t("buttons.reactivate")}
+            </Button>
+          </DialogFooter>
+        </form>
+      </DialogContent>
+    </Dialog>
+  )
+}
+
+export default ReactivateDepositAccountDialog
@@ -21,6 +21,10 @@ export const DepositAccountStatusBadge: React.FC<{ status: DepositAccountStatus
         return "secondary"
       case DepositAccountStatus.Closed:
         return "destructive"
+      case DepositAccountStatus.Dormant:
+        return "secondary"
+      case DepositAccountStatus.Escheated:
+        return "destructive"
       default: {
         const exhaustiveCheck: never = status
         return exhaustiveCheck
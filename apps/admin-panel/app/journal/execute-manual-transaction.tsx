@@ -41,9 +41,10 @@ import { getCurrentLocalDate } from "@/lib/utils"
 gql`
   mutation ExecuteManualTransaction($input: ManualTransactionExecuteInput!) {
     manualTransactionExecute(input: $input) {
-      transaction {
+      manualTransaction {
         id
-        ledgerTransactionId
+        manualTransactionId
+        status
         createdAt
         description
       }
@@ -109,11 +110,9 @@ export const ExecuteManualTransactionDialog: React.FC<ExecuteManualTransactionPr
           input: { ...formValues },
         },
         onCompleted: (data) => {
-          if (data?.manualTransactionExecute.transaction) {
-            toast.success(t("success"))
-            navigate(
-              `/ledger-transactions/${data.manualTransactionExecute.transaction.ledgerTransactionId}`,
-            )
+          if (data?.manualTransactionExecute.manualTransaction) {
+            toast.success(t("submittedForApproval"))
+            navigate("/actions")
           } else {
             throw new Error(t("errored"))
           }
@@ -60,6 +60,7 @@ export type AccountInfo = {
 
 export type AccountingBaseConfigInput = {
   assetsCode: Scalars['String']['input'];
+  cashFlowMapping?: Array<CashFlowMappingInput>;
   costOfRevenueCode: Scalars['String']['input'];
   equityCode: Scalars['String']['input'];
   equityRetainedEarningsGainCode: Scalars['String']['input'];
@@ -72,6 +73,7 @@ export type AccountingBaseConfigInput = {
 export type AccountingBaseConfigOutput = {
   __typename?: 'AccountingBaseConfigOutput';
   assetsCode: Scalars['String']['output'];
+  cashFlowMapping: Array<CashFlowMapping>;
   costOfRevenueCode: Scalars['String']['output'];
   equityCode: Scalars['String']['output'];
   equityRetainedEarningsGainCode: Scalars['String']['output'];
@@ -170,12 +172,18 @@ export enum ApprovalProcessStatus {
   InProgress = 'IN_PROGRESS'
 }
 
-export type ApprovalProcessTarget = CreditFacilityDisbursal | CreditFacilityProposal | Withdrawal;
+export type ApprovalProcessTarget = CreditFacility | CreditFacilityDisbursal | CreditFacilityProposal | ManualTransaction | Transfer | Withdrawal;
 
 export enum ApprovalProcessType {
+  CreditFacilityAmendmentApproval = 'CREDIT_FACILITY_AMENDMENT_APPROVAL',
   CreditFacilityProposalApproval = 'CREDIT_FACILITY_PROPOSAL_APPROVAL',
+  CreditFacilityWriteOffApproval = 'CREDIT_FACILITY_WRITE_OFF_APPROVAL',
   DisbursalApproval = 'DISBURSAL_APPROVAL',
-  WithdrawalApproval = 'WITHDRAWAL_APPROVAL'
+  ManualTransactionApproval = 'MANUAL_TRANSACTION_APPROVAL',
+  ManualTransactionReversalApproval = 'MANUAL_TRANSACTION_REVERSAL_APPROVAL',
+  TransferApproval = 'TRANSFER_APPROVAL',
+  WithdrawalApproval = 'WITHDRAWAL_APPROVAL',
+  WithdrawalOverLimitApproval = 'WITHDRAWAL_OVER_LIMIT_APPROVAL'
 }
 
 export type ApprovalProcessVoter = {
@@ -265,12 +273,76 @@ export type BtcLedgerAccountBalanceRange = {
   periodActivity: BtcLedgerAccountBalance;
 };
 
+export type CancelledTransferEntry = {
+  __typename?: 'CancelledTransferEntry';
+  amount: Scalars['UsdCents']['output'];
+  recordedAt: Scalars['Timestamp']['output'];
+  transfer: Transfer;
+};
+
 export type CancelledWithdrawalEntry = {
   __typename?: 'CancelledWithdrawalEntry';
   recordedAt: Scalars['Timestamp']['output'];
   withdrawal: Withdrawal;
 };
 
+export enum CashFlowCategory {
+  Cash = 'CASH',
+  Financing = 'FINANCING',
+  Investing = 'INVESTING',
+  Operating = 'OPERATING'
+}
+
+export type CashFlowLine = {
+  __typename?: 'CashFlowLine';
+  amount: Scalars['SignedUsdCents']['output'];
+  code: Scalars['String']['output'];
+  ledgerAccount: LedgerAccount;
+  ledgerAccountId: Scalars['UUID']['output'];
+  name: Scalars['String']['output'];
+};
+
+export type CashFlowMapping = {
+  __typename?: 'CashFlowMapping';
+  category: CashFlowCategory;
+  code: Scalars['String']['output'];
+};
+
+export type CashFlowMappingInput = {
+  category: CashFlowCategory;
+  code: Scalars['String']['input'];
+};
+
+export type CashFlowSection = {
+  __typename?: 'CashFlowSection';
+  lines: Array<CashFlowLine>;
+  total: Scalars['SignedUsdCents']['output'];
+};
+
+export type CashFlowStatement = {
+  __typename?: 'CashFlowStatement';
+  closingCash: Scalars['SignedUsdCents']['output'];
+  financing: CashFlowSection;
+  from: Scalars['Date']['output'];
+  investing: CashFlowSection;
+  netChangeInCash: Scalars['SignedUsdCents']['output'];
+  netIncome: Scalars['SignedUsdCents']['output'];
+  openingCash: Scalars['SignedUsdCents']['output'];
+  operating: CashFlowSection;
+  unreconciled: Scalars['SignedUsdCents']['output'];
+  until?: Maybe<Scalars['Date']['output']>;
+};
+
+export type CashFlowStatementCsvCreateInput = {
+  from: Scalars['Date']['input'];
+  until?: InputMaybe<Scalars['Date']['input']>;
+};
+
+export type CashFlowStatementCsvCreatePayload = {
+  __typename?: 'CashFlowStatementCsvCreatePayload';
+  accountingCsvDocument: AccountingCsvDocument;
+};
+
 export type ChartNode = {
   __typename?: 'ChartNode';
   accountCode: Scalars['AccountCode']['output'];
@@ -309,6 +381,15 @@ export type ChartOfAccountsAddRootNodePayload = {
   chartOfAccounts: ChartOfAccounts;
 };
 
+export type ChartOfAccountsCashFlowMappingUpdateInput = {
+  cashFlowMapping: Array<CashFlowMappingInput>;
+};
+
+export type ChartOfAccountsCashFlowMappingUpdatePayload = {
+  __typename?: 'ChartOfAccountsCashFlowMappingUpdatePayload';
+  chartOfAccounts: ChartOfAccounts;
+};
+
 export type ChartOfAccountsCsvImportInput = {
   file: Scalars['Upload']['input'];
 };
@@ -488,6 +569,7 @@ export type CreditFacility = {
   creditFacilityTerms: TermValues;
   currentCvl: CvlPct;
   customer: Customer;
+  cvlAsOf: CvlPct;
   disbursals: Array<CreditFacilityDisbursal>;
   facilityAmount: Scalars['UsdCents']['output'];
   history: Array<CreditFacilityHistoryEntry>;
@@ -506,6 +588,11 @@ export type CreditFacility = {
   wallet?: Maybe<Wallet>;
 };
 
+
+export type CreditFacilityCvlAsOfArgs = {
+  date: Scalars['Date']['input'];
+};
+
 export type CreditFacilityApproved = {
   __typename?: 'CreditFacilityApproved';
   cents: Scalars['UsdCents']['output'];
@@ -644,7 +731,7 @@ export type CreditFacilityEdge = {
   node: CreditFacility;
 };
 
-export type CreditFacilityHistoryEntry = CreditFacilityApproved | CreditFacilityCollateralSentOut | CreditFacilityCollateralUpdated | CreditFacilityCollateralizationUpdated | CreditFacilityDisbursalExecuted | CreditFacilityIncrementalPayment | CreditFacilityInterestAccrued | CreditFacilityRepaymentAmountReceived | PendingCreditFacilityCollateralizationUpdated;
+export type CreditFacilityHistoryEntry = CreditFacilityApproved | CreditFacilityCollateralSentOut | CreditFacilityCollateralUpdated | CreditFacilityCollateralizationUpdated | CreditFacilityDisbursalExecuted | CreditFacilityIncrementalPayment | CreditFacilityInterestAccrued | CreditFacilityRepaymentAmountReceived | CreditFacilityTermsAmended | CreditFacilityWrittenOff | PendingCreditFacilityCollateralizationUpdated;
 
 export type CreditFacilityIncrementalPayment = {
   __typename?: 'CreditFacilityIncrementalPayment';
@@ -821,12 +908,15 @@ export enum CreditFacilityRepaymentStatus {
   NotYetDue = 'NOT_YET_DUE',
   Overdue = 'OVERDUE',
   Paid = 'PAID',
-  Upcoming = 'UPCOMING'
+  Rescheduled = 'RESCHEDULED',
+  Upcoming = 'UPCOMING',
+  WrittenOff = 'WRITTEN_OFF'
 }
 
 export enum CreditFacilityRepaymentType {
   Disbursal = 'DISBURSAL',
-  Interest = 'INTEREST'
+  Interest = 'INTEREST',
+  Penalty = 'PENALTY'
 }
 
 export enum CreditFacilityStatus {
@@ -835,6 +925,24 @@ export enum CreditFacilityStatus {
   Matured = 'MATURED'
 }
 
+export type CreditFacilityTermsAmended = {
+  __typename?: 'CreditFacilityTermsAmended';
+  effective: Scalars['Date']['output'];
+  maturesAt: Scalars['Timestamp']['output'];
+  previousMaturesAt: Scalars['Timestamp']['output'];
+  previousTerms: TermValues;
+  recordedAt: Scalars['Timestamp']['output'];
+  rescheduledOverdueObligations: Scalars['Boolean']['output'];
+  terms: TermValues;
+};
+
+export type CreditFacilityWrittenOff = {
+  __typename?: 'CreditFacilityWrittenOff';
+  cents: Scalars['UsdCents']['output'];
+  effective: Scalars['Date']['output'];
+  recordedAt: Scalars['Timestamp']['output'];
+};
+
 export type CreditModuleConfig = {
   __typename?: 'CreditModuleConfig';
   chartOfAccountCollateralInLiquidationParentCode?: Maybe<Scalars['String']['output']>;
@@ -1163,6 +1271,7 @@ export type Deposit = {
 
 export type DepositAccount = {
   __typename?: 'DepositAccount';
+  accruedInterest: Scalars['UsdCents']['output'];
   balance: DepositAccountBalance;
   createdAt: Scalars['Timestamp']['output'];
   customer: Customer;
@@ -1170,10 +1279,13 @@ export type DepositAccount = {
   depositAccountId: Scalars['UUID']['output'];
   deposits: Array<Deposit>;
   history: DepositAccountHistoryEntryConnection;
+  holds: Array<Hold>;
   id: Scalars['ID']['output'];
+  interestTerms?: Maybe<DepositAccountInterestTerms>;
   ledgerAccounts: DepositAccountLedgerAccounts;
   publicId: Scalars['PublicId']['output'];
   status: DepositAccountStatus;
+  transfers: Array<Transfer>;
   withdrawals: Array<Withdrawal>;
 };
 
@@ -1185,10 +1297,21 @@ export type DepositAccountHistoryArgs = {
 
 export type DepositAccountBalance = {
   __typename?: 'DepositAccountBalance';
+  available: Scalars['UsdCents']['output'];
+  held: Scalars['UsdCents']['output'];
   pending: Scalars['UsdCents']['output'];
   settled: Scalars['UsdCents']['output'];
 };
 
+export type DepositAccountBreakTermInput = {
+  depositAccountId: Scalars['UUID']['input'];
+};
+
+export type DepositAccountBreakTermPayload = {
+  __typename?: 'DepositAccountBreakTermPayload';
+  account: DepositAccount;
+};
+
 export type DepositAccountCloseInput = {
   depositAccountId: Scalars['UUID']['input'];
 };
@@ -1235,7 +1358,7 @@ export type DepositAccountFreezePayload = {
   account: DepositAccount;
 };
 
-export type DepositAccountHistoryEntry = CancelledWithdrawalEntry | DepositEntry | DisbursalEntry | FreezeEntry | PaymentEntry | UnfreezeEntry | UnknownEntry | WithdrawalEntry;
+export type DepositAccountHistoryEntry = CancelledTransferEntry | CancelledWithdrawalEntry | DepositEntry | DisbursalEntry | EarlyBreakPenaltyEntry | EscheatmentEntry | FreezeEntry | HoldCapturedEntry | HoldPlacedEntry | HoldReleasedEntry | InterestCapitalizedEntry | PaymentEntry | TransferInEntry | TransferOutEntry | UnfreezeEntry | UnknownEntry | WithdrawalEntry;
 
 export type DepositAccountHistoryEntryConnection = {
   __typename?: 'DepositAccountHistoryEntryConnection';
@@ -1256,6 +1379,13 @@ export type DepositAccountHistoryEntryEdge = {
   node: DepositAccountHistoryEntry;
 };
 
+export type DepositAccountInterestTerms = {
+  __typename?: 'DepositAccountInterestTerms';
+  annualRate: Scalars['Decimal']['output'];
+  earlyBreakPenaltyRate?: Maybe<Scalars['Decimal']['output']>;
+  maturityDate?: Maybe<Scalars['Date']['output']>;
+};
+
 export type DepositAccountLedgerAccounts = {
   __typename?: 'DepositAccountLedgerAccounts';
   depositAccount: LedgerAccount;
@@ -1264,9 +1394,20 @@ export type DepositAccountLedgerAccounts = {
   frozenDepositAccountId: Scalars['UUID']['output'];
 };
 
+export type DepositAccountReactivateInput = {
+  depositAccountId: Scalars['UUID']['input'];
+};
+
+export type DepositAccountReactivatePayload = {
+  __typename?: 'DepositAccountReactivatePayload';
+  account: DepositAccount;
+};
+
 export enum DepositAccountStatus {
   Active = 'ACTIVE',
   Closed = 'CLOSED',
+  Dormant = 'DORMANT',
+  Escheated = 'ESCHEATED',
   Frozen = 'FROZEN',
   Inactive = 'INACTIVE'
 }
@@ -1280,6 +1421,18 @@ export type DepositAccountUnfreezePayload = {
   account: DepositAccount;
 };
 
+export type DepositAccountUpdateInterestTermsInput = {
+  annualRate: Scalars['Decimal']['input'];
+  depositAccountId: Scalars['UUID']['input'];
+  earlyBreakPenaltyRate?: InputMaybe<Scalars['Decimal']['input']>;
+  maturityDate?: InputMaybe<Scalars['Date']['input']>;
+};
+
+export type DepositAccountUpdateInterestTermsPayload = {
+  __typename?: 'DepositAccountUpdateInterestTermsPayload';
+  account: DepositAccount;
+};
+
 export type DepositConnection = {
   __typename?: 'DepositConnection';
   /** A list of edges. */
@@ -1320,7 +1473,9 @@ export type DepositModuleConfig = {
   chartOfAccountsGovernmentEntityDepositAccountsParentCode?: Maybe<Scalars['String']['output']>;
   chartOfAccountsId?: Maybe<Scalars['UUID']['output']>;
   chartOfAccountsIndividualDepositAccountsParentCode?: Maybe<Scalars['String']['output']>;
+  chartOfAccountsInterestExpenseParentCode?: Maybe<Scalars['String']['output']>;
   chartOfAccountsOmnibusParentCode?: Maybe<Scalars['String']['output']>;
+  chartOfAccountsUnclaimedFundsParentCode?: Maybe<Scalars['String']['output']>;
 };
 
 export type DepositModuleConfigureInput = {
@@ -1336,7 +1491,9 @@ export type DepositModuleConfigureInput = {
   chartOfAccountsFrozenIndividualDepositAccountsParentCode: Scalars['String']['input'];
   chartOfAccountsGovernmentEntityDepositAccountsParentCode: Scalars['String']['input'];
   chartOfAccountsIndividualDepositAccountsParentCode: Scalars['String']['input'];
+  chartOfAccountsInterestExpenseParentCode?: InputMaybe<Scalars['String']['input']>;
   chartOfAccountsOmnibusParentCode: Scalars['String']['input'];
+  chartOfAccountsUnclaimedFundsParentCode?: InputMaybe<Scalars['String']['input']>;
 };
 
 export type DepositModuleConfigurePayload = {
@@ -1344,6 +1501,13 @@ export type DepositModuleConfigurePayload = {
   depositConfig: DepositModuleConfig;
 };
 
+/** The product an account is held under, derived from its interest terms. */
+export enum DepositProduct {
+  Current = 'CURRENT',
+  Savings = 'SAVINGS',
+  TermDeposit = 'TERM_DEPOSIT'
+}
+
 export type DepositRecordInput = {
   amount: Scalars['UsdCents']['input'];
   depositAccountId: Scalars['UUID']['input'];
@@ -1453,6 +1617,20 @@ export type DurationInput = {
   units: Scalars['Int']['input'];
 };
 
+export type EarlyBreakPenaltyEntry = {
+  __typename?: 'EarlyBreakPenaltyEntry';
+  amount: Scalars['UsdCents']['output'];
+  recordedAt: Scalars['Timestamp']['output'];
+  txId: Scalars['UUID']['output'];
+};
+
+export type EscheatmentEntry = {
+  __typename?: 'EscheatmentEntry';
+  amount: Scalars['UsdCents']['output'];
+  recordedAt: Scalars['Timestamp']['output'];
+  txId: Scalars['UUID']['output'];
+};
+
 export type FacilityRemaining = {
   __typename?: 'FacilityRemaining';
   usdBalance: Scalars['UsdCents']['output'];
@@ -1553,6 +1731,75 @@ export type GovernanceNavigationItems = {
   policy: Scalars['Boolean']['output'];
 };
 
+export type Hold = {
+  __typename?: 'Hold';
+  account: DepositAccount;
+  amount: Scalars['UsdCents']['output'];
+  createdAt: Scalars['Timestamp']['output'];
+  depositAccountId: Scalars['UUID']['output'];
+  holdId: Scalars['UUID']['output'];
+  id: Scalars['ID']['output'];
+  ledgerTransactions: Array<LedgerTransaction>;
+  reason: Scalars['String']['output'];
+  status: HoldStatus;
+};
+
+export type HoldCaptureInput = {
+  holdId: Scalars['UUID']['input'];
+};
+
+export type HoldCapturePayload = {
+  __typename?: 'HoldCapturePayload';
+  hold: Hold;
+};
+
+export type HoldCapturedEntry = {
+  __typename?: 'HoldCapturedEntry';
+  amount: Scalars['UsdCents']['output'];
+  recordedAt: Scalars['Timestamp']['output'];
+  txId: Scalars['UUID']['output'];
+};
+
+export type HoldPlaceInput = {
+  amount: Scalars['UsdCents']['input'];
+  depositAccountId: Scalars['UUID']['input'];
+  reason: Scalars['String']['input'];
+};
+
+export type HoldPlacePayload = {
+  __typename?: 'HoldPlacePayload';
+  hold: Hold;
+};
+
+export type HoldPlacedEntry = {
+  __typename?: 'HoldPlacedEntry';
+  amount: Scalars['UsdCents']['output'];
+  recordedAt: Scalars['Timestamp']['output'];
+  txId: Scalars['UUID']['output'];
+};
+
+export type HoldReleaseInput = {
+  holdId: Scalars['UUID']['input'];
+};
+
+export type HoldReleasePayload = {
+  __typename?: 'HoldReleasePayload';
+  hold: Hold;
+};
+
+export type HoldReleasedEntry = {
+  __typename?: 'HoldReleasedEntry';
+  amount: Scalars['UsdCents']['output'];
+  recordedAt: Scalars['Timestamp']['output'];
+  txId: Scalars['UUID']['output'];
+};
+
+export enum HoldStatus {
+  Active = 'ACTIVE',
+  Captured = 'CAPTURED',
+  Released = 'RELEASED'
+}
+
 export type InfiniteCvlPct = {
   __typename?: 'InfiniteCvlPct';
   isInfinite: Scalars['Boolean']['output'];
@@ -1565,9 +1812,19 @@ export type Interest = {
   total: Total;
 };
 
+export type InterestCapitalizedEntry = {
+  __typename?: 'InterestCapitalizedEntry';
+  amount: Scalars['UsdCents']['output'];
+  recordedAt: Scalars['Timestamp']['output'];
+  txId: Scalars['UUID']['output'];
+};
+
 export enum InterestInterval {
   EndOfDay = 'END_OF_DAY',
-  EndOfMonth = 'END_OF_MONTH'
+  EndOfMonth = 'END_OF_MONTH',
+  EndOfQuarter = 'END_OF_QUARTER',
+  EndOfWeek = 'END_OF_WEEK',
+  MonthlyAnniversary = 'MONTHLY_ANNIVERSARY'
 }
 
 export type JournalEntry = {
@@ -1718,7 +1975,7 @@ export type LedgerTransactionEdge = {
   node: LedgerTransaction;
 };
 
-export type LedgerTransactionEntity = CreditFacilityDisbursal | Deposit | Withdrawal;
+export type LedgerTransactionEntity = CreditFacilityDisbursal | Deposit | Transfer | Withdrawal;
 
 export type LedgerTransactionInitiator = System | User;
 
@@ -1805,6 +2062,33 @@ export enum LoanAgreementStatus {
   Pending = 'PENDING'
 }
 
+export type ManualTransaction = {
+  __typename?: 'ManualTransaction';
+  approvalProcess?: Maybe<ApprovalProcess>;
+  createdAt: Scalars['Timestamp']['output'];
+  description: Scalars['String']['output'];
+  effective?: Maybe<Scalars['Date']['output']>;
+  entries: Array<ManualTransactionEntry>;
+  id: Scalars['ID']['output'];
+  ledgerTransaction?: Maybe<LedgerTransaction>;
+  manualTransactionId: Scalars['UUID']['output'];
+  reference: Scalars['String']['output'];
+  reversalApprovalProcess?: Maybe<ApprovalProcess>;
+  reversalEffective?: Maybe<Scalars['Date']['output']>;
+  reversalLedgerTransaction?: Maybe<LedgerTransaction>;
+  status: ManualTransactionStatus;
+  statusReason?: Maybe<Scalars['String']['output']>;
+};
+
+export type ManualTransactionEntry = {
+  __typename?: 'ManualTransactionEntry';
+  amount: Scalars['Decimal']['output'];
+  currency: Scalars['String']['output'];
+  description: Scalars['String']['output'];
+  direction: DebitOrCredit;
+  ledgerAccount: LedgerAccount;
+};
+
 export type ManualTransactionEntryInput = {
   accountRef: Scalars['String']['input'];
   amount: Scalars['Decimal']['input'];
@@ -1822,9 +2106,29 @@ export type ManualTransactionExecuteInput = {
 
 export type ManualTransactionExecutePayload = {
   __typename?: 'ManualTransactionExecutePayload';
-  transaction: LedgerTransaction;
+  manualTransaction: ManualTransaction;
+};
+
+export type ManualTransactionReverseInput = {
+  effective?: InputMaybe<Scalars['Date']['input']>;
+  manualTransactionId: Scalars['UUID']['input'];
+  nextOpenPeriod?: Scalars['Boolean']['input'];
+};
+
+export type ManualTransactionReversePayload = {
+  __typename?: 'ManualTransactionReversePayload';
+  manualTransaction: ManualTransaction;
 };
 
+export enum ManualTransactionStatus {
+  Denied = 'DENIED',
+  Expired = 'EXPIRED',
+  PendingApproval = 'PENDING_APPROVAL',
+  Posted = 'POSTED',
+  ReversalPendingApproval = 'REVERSAL_PENDING_APPROVAL',
+  Reversed = 'REVERSED'
+}
+
 export type Me = {
   __typename?: 'Me';
   user: User;
@@ -1839,8 +2143,10 @@ export type Mutation = {
   accountingCsvDownloadLinkGenerate: AccountingCsvDownloadLinkGeneratePayload;
   approvalProcessApprove: ApprovalProcessApprovePayload;
   approvalProcessDeny: ApprovalProcessDenyPayload;
+  cashFlowStatementCsvCreate: CashFlowStatementCsvCreatePayload;
   chartOfAccountsAddChildNode: ChartOfAccountsAddChildNodePayload;
   chartOfAccountsAddRootNode: ChartOfAccountsAddRootNodePayload;
+  chartOfAccountsCashFlowMappingUpdate: ChartOfAccountsCashFlowMappingUpdatePayload;
   chartOfAccountsCsvImport: ChartOfAccountsCsvImportPayload;
   chartOfAccountsCsvImportWithBaseConfig: ChartOfAccountsCsvImportWithBaseConfigPayload;
   collateralRecordProceedsFromLiquidation: CollateralRecordProceedsFromLiquidationPayload;
@@ -1864,10 +2170,13 @@ export type Mutation = {
   customerDocumentDownloadLinkGenerate: CustomerDocumentDownloadLinksGeneratePayload;
   customerEmailUpdate: CustomerEmailUpdatePayload;
   customerTelegramHandleUpdate: CustomerTelegramHandleUpdatePayload;
+  depositAccountBreakTerm: DepositAccountBreakTermPayload;
   depositAccountClose: DepositAccountClosePayload;
   depositAccountCreate: DepositAccountCreatePayload;
   depositAccountFreeze: DepositAccountFreezePayload;
+  depositAccountReactivate: DepositAccountReactivatePayload;
   depositAccountUnfreeze: DepositAccountUnfreezePayload;
+  depositAccountUpdateInterestTerms: DepositAccountUpdateInterestTermsPayload;
   depositModuleConfigure: DepositModuleConfigurePayload;
   depositRecord: DepositRecordPayload;
   depositRevert: DepositRevertPayload;
@@ -1876,27 +2185,38 @@ export type Mutation = {
   fiscalYearCloseMonth: FiscalYearCloseMonthPayload;
   fiscalYearInit: FiscalYearInitPayload;
   fiscalYearOpenNext: FiscalYearOpenNextPayload;
+  holdCapture: HoldCapturePayload;
+  holdPlace: HoldPlacePayload;
+  holdRelease: HoldReleasePayload;
   ledgerAccountCsvCreate: LedgerAccountCsvCreatePayload;
   loanAgreementDownloadLinkGenerate: LoanAgreementDownloadLinksGeneratePayload;
   loanAgreementGenerate: LoanAgreementGeneratePayload;
   manualTransactionExecute: ManualTransactionExecutePayload;
+  manualTransactionReverse: ManualTransactionReversePayload;
   policyAssignCommittee: PolicyAssignCommitteePayload;
   prospectClose: ProspectClosePayload;
   prospectConvert: ProspectConvertPayload;
   prospectCreate: ProspectCreatePayload;
+  recurringJournalEntryCreate: RecurringJournalEntryCreatePayload;
+  recurringJournalEntryPause: RecurringJournalEntryPausePayload;
+  recurringJournalEntryResume: RecurringJournalEntryResumePayload;
+  recurringJournalEntrySkipOccurrence: RecurringJournalEntrySkipOccurrencePayload;
   reportFileGenerateDownloadLink: ReportFileGenerateDownloadLinkPayload;
   roleAddPermissionSets: RoleAddPermissionSetsPayload;
   roleCreate: RoleCreatePayload;
   roleRemovePermissionSets: RoleRemovePermissionSetsPayload;
   sumsubPermalinkCreate: SumsubPermalinkCreatePayload;
   termsTemplateCreate: TermsTemplateCreatePayload;
+  termsTemplateCustomerSelectabilityUpdate: TermsTemplateCustomerSelectabilityUpdatePayload;
   termsTemplateUpdate: TermsTemplateUpdatePayload;
+  transferInitiate: TransferInitiatePayload;
   triggerReportRun: ReportRunCreatePayload;
   userCreate: UserCreatePayload;
   userUpdateRole: UserUpdateRolePayload;
   withdrawalCancel: WithdrawalCancelPayload;
   withdrawalConfirm: WithdrawalConfirmPayload;
   withdrawalInitiate: WithdrawalInitiatePayload;
+  withdrawalLimitsConfigUpdate: WithdrawalLimitsConfigUpdatePayload;
   withdrawalRevert: WithdrawalRevertPayload;
 };
 
@@ -1917,6 +2237,11 @@ export type MutationApprovalProcessDenyArgs = {
 };
 
 
+export type MutationCashFlowStatementCsvCreateArgs = {
+  input: CashFlowStatementCsvCreateInput;
+};
+
+
 export type MutationChartOfAccountsAddChildNodeArgs = {
   input: ChartOfAccountsAddChildNodeInput;
 };
@@ -1927,6 +2252,11 @@ export type MutationChartOfAccountsAddRootNodeArgs = {
 };
 
 
+export type MutationChartOfAccountsCashFlowMappingUpdateArgs = {
+  input: ChartOfAccountsCashFlowMappingUpdateInput;
+};
+
+
 export type MutationChartOfAccountsCsvImportArgs = {
   input: ChartOfAccountsCsvImportInput;
 };
@@ -2042,6 +2372,11 @@ export type MutationCustomerTelegramHandleUpdateArgs = {
 };
 
 
+export type MutationDepositAccountBreakTermArgs = {
+  input: DepositAccountBreakTermInput;
+};
+
+
 export type MutationDepositAccountCloseArgs = {
   input: DepositAccountCloseInput;
 };
@@ -2057,11 +2392,21 @@ export type MutationDepositAccountFreezeArgs = {
 };
 
 
+export type MutationDepositAccountReactivateArgs = {
+  input: DepositAccountReactivateInput;
+};
+
+
 export type MutationDepositAccountUnfreezeArgs = {
   input: DepositAccountUnfreezeInput;
 };
 
 
+export type MutationDepositAccountUpdateInterestTermsArgs = {
+  input: DepositAccountUpdateInterestTermsInput;
+};
+
+
 export type MutationDepositModuleConfigureArgs = {
   input: DepositModuleConfigureInput;
 };
@@ -2102,6 +2447,21 @@ export type MutationFiscalYearOpenNextArgs = {
 };
 
 
+export type MutationHoldCaptureArgs = {
+  input: HoldCaptureInput;
+};
+
+
+export type MutationHoldPlaceArgs = {
+  input: HoldPlaceInput;
+};
+
+
+export type MutationHoldReleaseArgs = {
+  input: HoldReleaseInput;
+};
+
+
 export type MutationLedgerAccountCsvCreateArgs = {
   input: LedgerAccountCsvCreateInput;
 };
@@ -2122,6 +2482,11 @@ export type MutationManualTransactionExecuteArgs = {
 };
 
 
+export type MutationManualTransactionReverseArgs = {
+  input: ManualTransactionReverseInput;
+};
+
+
 export type MutationPolicyAssignCommitteeArgs = {
   input: PolicyAssignCommitteeInput;
 };
@@ -2142,6 +2507,26 @@ export type MutationProspectCreateArgs = {
 };
 
 
+export type MutationRecurringJournalEntryCreateArgs = {
+  input: RecurringJournalEntryCreateInput;
+};
+
+
+export type MutationRecurringJournalEntryPauseArgs = {
+  input: RecurringJournalEntryPauseInput;
+};
+
+
+export type MutationRecurringJournalEntryResumeArgs = {
+  input: RecurringJournalEntryResumeInput;
+};
+
+
+export type MutationRecurringJournalEntrySkipOccurrenceArgs = {
+  input: RecurringJournalEntrySkipOccurrenceInput;
+};
+
+
 export type MutationReportFileGenerateDownloadLinkArgs = {
   input: ReportFileGenerateDownloadLinkInput;
 };
@@ -2172,11 +2557,21 @@ export type MutationTermsTemplateCreateArgs = {
 };
 
 
+export type MutationTermsTemplateCustomerSelectabilityUpdateArgs = {
+  input: TermsTemplateCustomerSelectabilityUpdateInput;
+};
+
+
 export type MutationTermsTemplateUpdateArgs = {
   input: TermsTemplateUpdateInput;
 };
 
 
+export type MutationTransferInitiateArgs = {
+  input: TransferInitiateInput;
+};
+
+
 export type MutationUserCreateArgs = {
   input: UserCreateInput;
 };
@@ -2202,6 +2597,11 @@ export type MutationWithdrawalInitiateArgs = {
 };
 
 
+export type MutationWithdrawalLimitsConfigUpdateArgs = {
+  input: WithdrawalLimitsConfigUpdateInput;
+};
+
+
 export type MutationWithdrawalRevertArgs = {
   input: WithdrawalRevertInput;
 };
@@ -2479,7 +2879,7 @@ export enum ProspectStatus {
   Open = 'OPEN'
 }
 
-export type PublicIdTarget = CreditFacility | CreditFacilityDisbursal | Customer | Deposit | DepositAccount | Prospect | Withdrawal;
+export type PublicIdTarget = CreditFacility | CreditFacilityDisbursal | Customer | Deposit | DepositAccount | Prospect | Transfer | Withdrawal;
 
 export type Query = {
   __typename?: 'Query';
@@ -2489,6 +2889,7 @@ export type Query = {
   audit: AuditEntryConnection;
   auditSubjects: Array<Scalars['AuditSubjectId']['output']>;
   balanceSheet: BalanceSheet;
+  cashFlowStatement: CashFlowStatement;
   chartOfAccounts: ChartOfAccounts;
   committee?: Maybe<Committee>;
   committees: CommitteeConnection;
@@ -2520,6 +2921,7 @@ export type Query = {
   fiscalYear?: Maybe<FiscalYear>;
   fiscalYearByYear?: Maybe<FiscalYear>;
   fiscalYears: FiscalYearConnection;
+  hold?: Maybe<Hold>;
   journalEntries: JournalEntryConnection;
   ledgerAccount?: Maybe<LedgerAccount>;
   ledgerAccountByCode?: Maybe<LedgerAccount>;
@@ -2528,6 +2930,7 @@ export type Query = {
   liquidation?: Maybe<Liquidation>;
   liquidations: LiquidationConnection;
   loanAgreement?: Maybe<LoanAgreement>;
+  manualTransaction?: Maybe<ManualTransaction>;
   me: Me;
   pendingCreditFacilities: PendingCreditFacilityConnection;
   pendingCreditFacility?: Maybe<PendingCreditFacility>;
@@ -2540,6 +2943,8 @@ export type Query = {
   prospects: ProspectConnection;
   publicIdTarget?: Maybe<PublicIdTarget>;
   realtimePrice: RealtimePrice;
+  recurringJournalEntries: RecurringJournalEntryConnection;
+  recurringJournalEntry?: Maybe<RecurringJournalEntry>;
   reportRun?: Maybe<ReportRun>;
   reportRuns: ReportRunConnection;
   role?: Maybe<Role>;
@@ -2547,11 +2952,15 @@ export type Query = {
   termsTemplate?: Maybe<TermsTemplate>;
   termsTemplates: Array<TermsTemplate>;
   transactionTemplates: TransactionTemplateConnection;
+  transfer?: Maybe<Transfer>;
+  transferByPublicId?: Maybe<Transfer>;
+  transfers: TransferConnection;
   trialBalance: TrialBalance;
   user?: Maybe<User>;
   users: Array<User>;
   withdrawal?: Maybe<Withdrawal>;
   withdrawalByPublicId?: Maybe<Withdrawal>;
+  withdrawalLimitsConfig: WithdrawalLimitsConfig;
   withdrawals: WithdrawalConnection;
 };
 
@@ -2588,6 +2997,12 @@ export type QueryBalanceSheetArgs = {
 };
 
 
+export type QueryCashFlowStatementArgs = {
+  from: Scalars['Date']['input'];
+  until?: InputMaybe<Scalars['Date']['input']>;
+};
+
+
 export type QueryCommitteeArgs = {
   id: Scalars['UUID']['input'];
 };
@@ -2737,6 +3152,11 @@ export type QueryFiscalYearsArgs = {
 };
 
 
+export type QueryHoldArgs = {
+  id: Scalars['UUID']['input'];
+};
+
+
 export type QueryJournalEntriesArgs = {
   after?: InputMaybe<Scalars['String']['input']>;
   first: Scalars['Int']['input'];
@@ -2781,6 +3201,11 @@ export type QueryLoanAgreementArgs = {
 };
 
 
+export type QueryManualTransactionArgs = {
+  id: Scalars['UUID']['input'];
+};
+
+
 export type QueryPendingCreditFacilitiesArgs = {
   after?: InputMaybe<Scalars['String']['input']>;
   first: Scalars['Int']['input'];
@@ -2837,6 +3262,17 @@ export type QueryPublicIdTargetArgs = {
 };
 
 
+export type QueryRecurringJournalEntriesArgs = {
+  after?: InputMaybe<Scalars['String']['input']>;
+  first: Scalars['Int']['input'];
+};
+
+
+export type QueryRecurringJournalEntryArgs = {
+  id: Scalars['UUID']['input'];
+};
+
+
 export type QueryReportRunArgs = {
   id: Scalars['UUID']['input'];
 };
@@ -2870,6 +3306,22 @@ export type QueryTransactionTemplatesArgs = {
 };
 
 
+export type QueryTransferArgs = {
+  id: Scalars['UUID']['input'];
+};
+
+
+export type QueryTransferByPublicIdArgs = {
+  id: Scalars['PublicId']['input'];
+};
+
+
+export type QueryTransfersArgs = {
+  after?: InputMaybe<Scalars['String']['input']>;
+  first: Scalars['Int']['input'];
+};
+
+
 export type QueryTrialBalanceArgs = {
   from: Scalars['Date']['input'];
   until: Scalars['Date']['input'];
@@ -2901,6 +3353,128 @@ export type RealtimePrice = {
   usdCentsPerBtc: Scalars['UsdCents']['output'];
 };
 
+export type RecurringJournalEntry = {
+  __typename?: 'RecurringJournalEntry';
+  amount: Scalars['Decimal']['output'];
+  amountSchedule: Array<RecurringJournalEntryScheduledAmount>;
+  createdAt: Scalars['Timestamp']['output'];
+  creditAccount: LedgerAccount;
+  currency: Scalars['String']['output'];
+  debitAccount: LedgerAccount;
+  description: Scalars['String']['output'];
+  endDate?: Maybe<Scalars['Date']['output']>;
+  frequency: RecurringJournalEntryFrequency;
+  id: Scalars['ID']['output'];
+  manualTransactions: Array<ManualTransaction>;
+  pausedOn?: Maybe<Scalars['Date']['output']>;
+  recurringJournalEntryId: Scalars['UUID']['output'];
+  reference: Scalars['String']['output'];
+  startDate: Scalars['Date']['output'];
+  status: RecurringJournalEntryStatus;
+  upcomingOccurrences: Array<RecurringJournalEntryOccurrence>;
+};
+
+
+export type RecurringJournalEntryUpcomingOccurrencesArgs = {
+  first?: Scalars['Int']['input'];
+};
+
+export type RecurringJournalEntryConnection = {
+  __typename?: 'RecurringJournalEntryConnection';
+  /** A list of edges. */
+  edges: Array<RecurringJournalEntryEdge>;
+  /** A list of nodes. */
+  nodes: Array<RecurringJournalEntry>;
+  /** Information to aid in pagination. */
+  pageInfo: PageInfo;
+};
+
+export type RecurringJournalEntryCreateInput = {
+  amount: Scalars['Decimal']['input'];
+  amountSchedule?: Array<RecurringJournalEntryScheduledAmountInput>;
+  creditAccountRef: Scalars['String']['input'];
+  currency: Scalars['String']['input'];
+  debitAccountRef: Scalars['String']['input'];
+  description: Scalars['String']['input'];
+  endDate?: InputMaybe<Scalars['Date']['input']>;
+  frequency: RecurringJournalEntryFrequency;
+  reference: Scalars['String']['input'];
+  startDate: Scalars['Date']['input'];
+};
+
+export type RecurringJournalEntryCreatePayload = {
+  __typename?: 'RecurringJournalEntryCreatePayload';
+  recurringJournalEntry: RecurringJournalEntry;
+};
+
+/** An edge in a connection. */
+export type RecurringJournalEntryEdge = {
+  __typename?: 'RecurringJournalEntryEdge';
+  /** A cursor for use in pagination */
+  cursor: Scalars['String']['output'];
+  /** The item at the end of the edge */
+  node: RecurringJournalEntry;
+};
+
+export enum RecurringJournalEntryFrequency {
+  Monthly = 'MONTHLY',
+  Quarterly = 'QUARTERLY',
+  Weekly = 'WEEKLY',
+  Yearly = 'YEARLY'
+}
+
+export type RecurringJournalEntryOccurrence = {
+  __typename?: 'RecurringJournalEntryOccurrence';
+  amount: Scalars['Decimal']['output'];
+  date: Scalars['Date']['output'];
+  skipped: Scalars['Boolean']['output'];
+};
+
+export type RecurringJournalEntryPauseInput = {
+  recurringJournalEntryId: Scalars['UUID']['input'];
+};
+
+export type RecurringJournalEntryPausePayload = {
+  __typename?: 'RecurringJournalEntryPausePayload';
+  recurringJournalEntry: RecurringJournalEntry;
+};
+
+export type RecurringJournalEntryResumeInput = {
+  recurringJournalEntryId: Scalars['UUID']['input'];
+};
+
+export type RecurringJournalEntryResumePayload = {
+  __typename?: 'RecurringJournalEntryResumePayload';
+  recurringJournalEntry: RecurringJournalEntry;
+};
+
+export type RecurringJournalEntryScheduledAmount = {
+  __typename?: 'RecurringJournalEntryScheduledAmount';
+  amount: Scalars['Decimal']['output'];
+  startingOn: Scalars['Date']['output'];
+};
+
+export type RecurringJournalEntryScheduledAmountInput = {
+  amount: Scalars['Decimal']['input'];
+  startingOn: Scalars['Date']['input'];
+};
+
+export type RecurringJournalEntrySkipOccurrenceInput = {
+  date: Scalars['Date']['input'];
+  recurringJournalEntryId: Scalars['UUID']['input'];
+};
+
+export type RecurringJournalEntrySkipOccurrencePayload = {
+  __typename?: 'RecurringJournalEntrySkipOccurrencePayload';
+  recurringJournalEntry: RecurringJournalEntry;
+};
+
+export enum RecurringJournalEntryStatus {
+  Active = 'ACTIVE',
+  Completed = 'COMPLETED',
+  Paused = 'PAUSED'
+}
+
 export type Report = {
   __typename?: 'Report';
   createdAt: Scalars['Timestamp']['output'];
@@ -3129,6 +3703,7 @@ export type TermsInput = {
 export type TermsTemplate = {
   __typename?: 'TermsTemplate';
   createdAt: Scalars['Timestamp']['output'];
+  customerSelectable: Scalars['Boolean']['output'];
   id: Scalars['ID']['output'];
   name: Scalars['String']['output'];
   termsId: Scalars['UUID']['output'];
@@ -3157,6 +3732,16 @@ export type TermsTemplateCreatePayload = {
   termsTemplate: TermsTemplate;
 };
 
+export type TermsTemplateCustomerSelectabilityUpdateInput = {
+  customerSelectable: Scalars['Boolean']['input'];
+  id: Scalars['UUID']['input'];
+};
+
+export type TermsTemplateCustomerSelectabilityUpdatePayload = {
+  __typename?: 'TermsTemplateCustomerSelectabilityUpdatePayload';
+  termsTemplate: TermsTemplate;
+};
+
 export type TermsTemplateUpdateInput = {
   accrualCycleInterval: InterestInterval;
   accrualInterval: InterestInterval;
@@ -3210,6 +3795,76 @@ export type TransactionTemplateEdge = {
   node: TransactionTemplate;
 };
 
+export type Transfer = {
+  __typename?: 'Transfer';
+  amount: Scalars['UsdCents']['output'];
+  approvalProcess?: Maybe<ApprovalProcess>;
+  createdAt: Scalars['Timestamp']['output'];
+  destinationAccount: DepositAccount;
+  destinationAccountId: Scalars['UUID']['output'];
+  id: Scalars['ID']['output'];
+  ledgerTransactions: Array<LedgerTransaction>;
+  publicId: Scalars['PublicId']['output'];
+  reference: Scalars['String']['output'];
+  sourceAccount: DepositAccount;
+  sourceAccountId: Scalars['UUID']['output'];
+  status: TransferStatus;
+  transferId: Scalars['UUID']['output'];
+};
+
+export type TransferConnection = {
+  __typename?: 'TransferConnection';
+  /** A list of edges. */
+  edges: Array<TransferEdge>;
+  /** A list of nodes. */
+  nodes: Array<Transfer>;
+  /** Information to aid in pagination. */
+  pageInfo: PageInfo;
+};
+
+/** An edge in a connection. */
+export type TransferEdge = {
+  __typename?: 'TransferEdge';
+  /** A cursor for use in pagination */
+  cursor: Scalars['String']['output'];
+  /** The item at the end of the edge */
+  node: Transfer;
+};
+
+export type TransferInEntry = {
+  __typename?: 'TransferInEntry';
+  amount: Scalars['UsdCents']['output'];
+  recordedAt: Scalars['Timestamp']['output'];
+  transfer: Transfer;
+};
+
+export type TransferInitiateInput = {
+  amount: Scalars['UsdCents']['input'];
+  destinationAccountId: Scalars['UUID']['input'];
+  reference?: InputMaybe<Scalars['String']['input']>;
+  sourceAccountId: Scalars['UUID']['input'];
+};
+
+export type TransferInitiatePayload = {
+  __typename?: 'TransferInitiatePayload';
+  transfer: Transfer;
+};
+
+export type TransferOutEntry = {
+  __typename?: 'TransferOutEntry';
+  amount: Scalars['UsdCents']['output'];
+  recordedAt: Scalars['Timestamp']['output'];
+  transfer: Transfer;
+};
+
+export enum TransferStatus {
+  Approved = 'APPROVED',
+  Completed = 'COMPLETED',
+  Denied = 'DENIED',
+  Initiated = 'INITIATED',
+  PendingApproval = 'PENDING_APPROVAL'
+}
+
 export type TrialBalance = {
   __typename?: 'TrialBalance';
   accounts: Array<LedgerAccount>;
@@ -3384,6 +4039,37 @@ export type WithdrawalInitiatePayload = {
   withdrawal: Withdrawal;
 };
 
+export type WithdrawalLimitRule = {
+  __typename?: 'WithdrawalLimitRule';
+  customerType?: Maybe<CustomerType>;
+  daily?: Maybe<Scalars['UsdCents']['output']>;
+  monthly?: Maybe<Scalars['UsdCents']['output']>;
+  product?: Maybe<DepositProduct>;
+  weekly?: Maybe<Scalars['UsdCents']['output']>;
+};
+
+export type WithdrawalLimitRuleInput = {
+  customerType?: InputMaybe<CustomerType>;
+  daily?: InputMaybe<Scalars['UsdCents']['input']>;
+  monthly?: InputMaybe<Scalars['UsdCents']['input']>;
+  product?: InputMaybe<DepositProduct>;
+  weekly?: InputMaybe<Scalars['UsdCents']['input']>;
+};
+
+export type WithdrawalLimitsConfig = {
+  __typename?: 'WithdrawalLimitsConfig';
+  rules: Array<WithdrawalLimitRule>;
+};
+
+export type WithdrawalLimitsConfigUpdateInput = {
+  rules: Array<WithdrawalLimitRuleInput>;
+};
+
+export type WithdrawalLimitsConfigUpdatePayload = {
+  __typename?: 'WithdrawalLimitsConfigUpdatePayload';
+  withdrawalLimitsConfig: WithdrawalLimitsConfig;
+};
+
 export type WithdrawalRevertInput = {
   withdrawalId: Scalars['UUID']['input'];
 };
@@ -3432,8 +4118,11 @@ export type AllActionsQueryVariables = Exact<{ [key: string]: never; }>;
 
 
 export type AllActionsQuery = { __typename?: 'Query', approvalProcesses: { __typename?: 'ApprovalProcessConnection', pageInfo: { __typename?: 'PageInfo', hasNextPage: boolean, hasPreviousPage: boolean }, edges: Array<{ __typename?: 'ApprovalProcessEdge', cursor: string, node: { __typename?: 'ApprovalProcess', id: string, approvalProcessType: ApprovalProcessType, status: ApprovalProcessStatus, userCanSubmitDecision: boolean, createdAt: any, target:
+          | { __typename: 'CreditFacility', creditFacilityId: string, publicId: any, customer: { __typename?: 'Customer', email: string } }
           | { __typename: 'CreditFacilityDisbursal', id: string, disbursalId: string, publicId: any, creditFacility: { __typename?: 'CreditFacility', publicId: any, customer: { __typename?: 'Customer', email: string } } }
           | { __typename: 'CreditFacilityProposal', creditFacilityProposalId: string, customer: { __typename?: 'Customer', email: string } }
+          | { __typename?: 'ManualTransaction' }
+          | { __typename?: 'Transfer' }
           | { __typename: 'Withdrawal', withdrawalId: string, publicId: any, account: { __typename?: 'DepositAccount', customer: { __typename?: 'Customer', email: string } } }
          } }> } };
 
@@ -3712,6 +4401,8 @@ export type CreditFacilityHistoryFragmentFragment = { __typename?: 'CreditFacili
     | { __typename?: 'CreditFacilityIncrementalPayment', cents: UsdCents, recordedAt: any, txId: string, effective: any }
     | { __typename?: 'CreditFacilityInterestAccrued', cents: UsdCents, recordedAt: any, txId: string, days: number, effective: any }
     | { __typename?: 'CreditFacilityRepaymentAmountReceived', cents: UsdCents, recordedAt: any, txId: string, effective: any }
+    | { __typename?: 'CreditFacilityTermsAmended', recordedAt: any, effective: any }
+    | { __typename?: 'CreditFacilityWrittenOff', cents: UsdCents, recordedAt: any, effective: any }
     | { __typename?: 'PendingCreditFacilityCollateralizationUpdated', collateral: Satoshis, price: UsdCents, recordedAt: any, effective: any, pendingState: PendingCreditFacilityCollateralizationState }
   > };
 
@@ -4072,16 +4763,32 @@ export type GetDepositAccountDetailsQueryVariables = Exact<{
 
 
 export type GetDepositAccountDetailsQuery = { __typename?: 'Query', depositAccountByPublicId?: { __typename?: 'DepositAccount', id: string, publicId: any, depositAccountId: string, createdAt: any, status: DepositAccountStatus, history: { __typename?: 'DepositAccountHistoryEntryConnection', pageInfo: { __typename?: 'PageInfo', hasNextPage: boolean, endCursor?: string | null, hasPreviousPage: boolean, startCursor?: string | null }, edges: Array<{ __typename?: 'DepositAccountHistoryEntryEdge', cursor: string, node:
+          | { __typename?: 'CancelledTransferEntry' }
           | { __typename: 'CancelledWithdrawalEntry', recordedAt: any, withdrawal: { __typename?: 'Withdrawal', id: string, withdrawalId: string, publicId: any, accountId: string, amount: UsdCents, createdAt: any, reference: string, status: WithdrawalStatus } }
           | { __typename: 'DepositEntry', recordedAt: any, deposit: { __typename?: 'Deposit', id: string, depositId: string, publicId: any, accountId: string, amount: UsdCents, createdAt: any, reference: string, status: DepositStatus } }
           | { __typename: 'DisbursalEntry', recordedAt: any, disbursal: { __typename?: 'CreditFacilityDisbursal', id: string, disbursalId: string, publicId: any, amount: UsdCents, createdAt: any, status: DisbursalStatus } }
+          | { __typename?: 'EarlyBreakPenaltyEntry' }
+          | { __typename?: 'EscheatmentEntry' }
           | { __typename: 'FreezeEntry', txId: string, recordedAt: any, amount: UsdCents }
+          | { __typename?: 'HoldCapturedEntry' }
+          | { __typename?: 'HoldPlacedEntry' }
+          | { __typename?: 'HoldReleasedEntry' }
+          | { __typename?: 'InterestCapitalizedEntry' }
           | { __typename: 'PaymentEntry', recordedAt: any, payment: { __typename?: 'CreditFacilityPaymentAllocation', id: string, paymentAllocationId: string, amount: UsdCents, createdAt: any } }
+          | { __typename?: 'TransferInEntry' }
+          | { __typename?: 'TransferOutEntry' }
           | { __typename: 'UnfreezeEntry', txId: string, recordedAt: any, amount: UsdCents }
           | { __typename?: 'UnknownEntry' }
           | { __typename: 'WithdrawalEntry', recordedAt: any, withdrawal: { __typename?: 'Withdrawal', id: string, withdrawalId: string, publicId: any, accountId: string, amount: UsdCents, createdAt: any, reference: string, status: WithdrawalStatus } }
          }> }, balance: { __typename?: 'DepositAccountBalance', settled: UsdCents, pending: UsdCents }, ledgerAccounts: { __typename?: 'DepositAccountLedgerAccounts', depositAccountId: string, frozenDepositAccountId: string }, customer: { __typename?: 'Customer', id: string, customerId: string, publicId: any, applicantId: string, email: string } } | null };
 
+export type DepositAccountReactivateMutationVariables = Exact<{
+  input: DepositAccountReactivateInput;
+}>;
+
+
+export type DepositAccountReactivateMutation = { __typename?: 'Mutation', depositAccountReactivate: { __typename?: 'DepositAccountReactivatePayload', account: { __typename?: 'DepositAccount', id: string } } };
+
 export type DepositAccountUnfreezeMutationVariables = Exact<{
   input: DepositAccountUnfreezeInput;
 }>;
@@ -4238,7 +4945,7 @@ export type ExecuteManualTransactionMutationVariables = Exact<{
 }>;
 
 
-export type ExecuteManualTransactionMutation = { __typename?: 'Mutation', manualTransactionExecute: { __typename?: 'ManualTransactionExecutePayload', transaction: { __typename?: 'LedgerTransaction', id: string, ledgerTransactionId: string, createdAt: any, description?: string | null } } };
+export type ExecuteManualTransactionMutation = { __typename?: 'Mutation', manualTransactionExecute: { __typename?: 'ManualTransactionExecutePayload', manualTransaction: { __typename?: 'ManualTransaction', id: string, manualTransactionId: string, status: ManualTransactionStatus, createdAt: any, description: string } } };
 
 export type JournalEntriesQueryVariables = Exact<{
   first: Scalars['Int']['input'];
@@ -4354,6 +5061,7 @@ export type LedgerTransactionQuery = { __typename?: 'Query', ledgerTransaction?:
     , entity?:
       | { __typename: 'CreditFacilityDisbursal', publicId: any }
       | { __typename: 'Deposit', publicId: any }
+      | { __typename?: 'Transfer' }
       | { __typename: 'Withdrawal', publicId: any }
      | null, entries: Array<{ __typename?: 'JournalEntry', id: string, entryId: string, entryType: string, direction: DebitOrCredit, layer: Layer, amount:
         | { __typename: 'BtcAmount', btc: Satoshis }
@@ -4960,6 +5668,7 @@ export type SearchPublicIdTargetQuery = { __typename?: 'Query', publicIdTarget?:
     | { __typename: 'Deposit', id: string, amount: UsdCents, publicId: any, depositId: string }
     | { __typename: 'DepositAccount', id: string, customer: { __typename?: 'Customer', id: string, customerId: string, publicId: any, email: string } }
     | { __typename: 'Prospect', id: string, prospectId: string, publicId: any, email: string }
+    | { __typename?: 'Transfer' }
     | { __typename: 'Withdrawal', id: string, amount: UsdCents, publicId: any, withdrawalId: string }
    | null };
 
@@ -5283,6 +5992,15 @@ export const CreditFacilityHistoryFragmentFragmentDoc = gql`
       txId
       effective
     }
+    ... on CreditFacilityTermsAmended {
+      recordedAt
+      effective
+    }
+    ... on CreditFacilityWrittenOff {
+      cents
+      recordedAt
+      effective
+    }
     ... on PendingCreditFacilityCollateralizationUpdated {
       pendingState: state
       collateral
@@ -6167,6 +6885,13 @@ export const AllActionsDocument = gql`
               }
             }
           }
+          ... on CreditFacility {
+            creditFacilityId
+            publicId
+            customer {
+              email
+            }
+          }
         }
       }
       cursor
@@ -8572,6 +9297,41 @@ export type GetDepositAccountDetailsQueryHookResult = ReturnType<typeof useGetDe
 export type GetDepositAccountDetailsLazyQueryHookResult = ReturnType<typeof useGetDepositAccountDetailsLazyQuery>;
 export type GetDepositAccountDetailsSuspenseQueryHookResult = ReturnType<typeof useGetDepositAccountDetailsSuspenseQuery>;
 export type GetDepositAccountDetailsQueryResult = Apollo.QueryResult<GetDepositAccountDetailsQuery, GetDepositAccountDetailsQueryVariables>;
+export const DepositAccountReactivateDocument = gql`
+    mutation DepositAccountReactivate($input: DepositAccountReactivateInput!) {
+  depositAccountReactivate(input: $input) {
+    account {
+      id
+    }
+  }
+}
+    `;
+export type DepositAccountReactivateMutationFn = Apollo.MutationFunction<DepositAccountReactivateMutation, DepositAccountReactivateMutationVariables>;
+
+/**
+ * __useDepositAccountReactivateMutation__
+ *
+ * To run a mutation, you first call `useDepositAccountReactivateMutation` within a React component and pass it any options that fit your needs.
+ * When your component renders, `useDepositAccountReactivateMutation` returns a tuple that includes:
+ * - A mutate function that you can call at any time to execute the mutation
+ * - An object with fields that represent the current status of the mutation's execution
+ *
+ * @param baseOptions options that will be passed into the mutation, supported options are listed on: https://www.apollographql.com/docs/react/api/react-hooks/#options-2;
+ *
+ * @example
+ * const [depositAccountReactivateMutation, { data, loading, error }] = useDepositAccountReactivateMutation({
+ *   variables: {
+ *      input: // value for 'input'
+ *   },
+ * });
+ */
+export function useDepositAccountReactivateMutation(baseOptions?: Apollo.MutationHookOptions<DepositAccountReactivateMutation, DepositAccountReactivateMutationVariables>) {
+        const options = {...defaultOptions, ...baseOptions}
+        return Apollo.useMutation<DepositAccountReactivateMutation, DepositAccountReactivateMutationVariables>(DepositAccountReactivateDocument, options);
+      }
+export type DepositAccountReactivateMutationHookResult = ReturnType<typeof useDepositAccountReactivateMutation>;
+export type DepositAccountReactivateMutationResult = Apollo.MutationResult<DepositAccountReactivateMutation>;
+export type DepositAccountReactivateMutationOptions = Apollo.BaseMutationOptions<DepositAccountReactivateMutation, DepositAccountReactivateMutationVariables>;
 export const DepositAccountUnfreezeDocument = gql`
     mutation DepositAccountUnfreeze($input: DepositAccountUnfreezeInput!) {
   depositAccountUnfreeze(input: $input) {
@@ -9319,9 +10079,10 @@ export type FiscalYearOpenNextMutationOptions = Apollo.BaseMutationOptions<Fisca
 export const ExecuteManualTransactionDocument = gql`
     mutation ExecuteManualTransaction($input: ManualTransactionExecuteInput!) {
   manualTransactionExecute(input: $input) {
-    transaction {
+    manualTransaction {
       id
-      ledgerTransactionId
+      manualTransactionId
+      status
       createdAt
       description
     }
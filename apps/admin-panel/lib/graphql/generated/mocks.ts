@@ -56,7 +56,7 @@ const mockEnums = {
 };
 
 import { fakerEN as faker } from '@faker-js/faker';
-import { AccountInfo, AccountingBaseConfigInput, AccountingBaseConfigOutput, AccountingCsvDocument, AccountingCsvDownloadLink, AccountingCsvDownloadLinkGenerateInput, AccountingCsvDownloadLinkGeneratePayload, ApprovalProcess, ApprovalProcessApproveInput, ApprovalProcessApprovePayload, ApprovalProcessConnection, ApprovalProcessDenyInput, ApprovalProcessDenyPayload, ApprovalProcessEdge, ApprovalProcessVoter, AuditEntry, AuditEntryConnection, AuditEntryEdge, BalanceSheet, BitgoConfig, BtcAmount, BtcBalanceDetails, BtcLedgerAccountBalance, BtcLedgerAccountBalanceRange, CancelledWithdrawalEntry, ChartNode, ChartOfAccounts, ChartOfAccountsAddChildNodeInput, ChartOfAccountsAddChildNodePayload, ChartOfAccountsAddRootNodeInput, ChartOfAccountsAddRootNodePayload, ChartOfAccountsCsvImportInput, ChartOfAccountsCsvImportPayload, ChartOfAccountsCsvImportWithBaseConfigInput, ChartOfAccountsCsvImportWithBaseConfigPayload, Collateral, CollateralBalance, CollateralRecordProceedsFromLiquidationInput, CollateralRecordProceedsFromLiquidationPayload, CollateralRecordSentToLiquidationInput, CollateralRecordSentToLiquidationPayload, CollateralUpdateInput, CollateralUpdatePayload, Committee, CommitteeAddUserInput, CommitteeAddUserPayload, CommitteeConnection, CommitteeCreateInput, CommitteeCreatePayload, CommitteeEdge, CommitteeRemoveUserInput, CommitteeRemoveUserPayload, CommitteeThreshold, CreditFacilitiesFilter, CreditFacilitiesSort, CreditFacility, CreditFacilityApproved, CreditFacilityBalance, CreditFacilityCollateralSentOut, CreditFacilityCollateralUpdated, CreditFacilityCollateralizationPayload, CreditFacilityCollateralizationUpdated, CreditFacilityCompleteInput, CreditFacilityCompletePayload, CreditFacilityConnection, CreditFacilityDisbursal, CreditFacilityDisbursalConnection, CreditFacilityDisbursalEdge, CreditFacilityDisbursalExecuted, CreditFacilityDisbursalInitiateInput, CreditFacilityDisbursalInitiatePayload, CreditFacilityEdge, CreditFacilityIncrementalPayment, CreditFacilityInterestAccrued, CreditFacilityLedgerAccounts, CreditFacilityPartialPaymentRecordInput, CreditFacilityPartialPaymentRecordPayload, CreditFacilityPartialPaymentWithDateRecordInput, CreditFacilityPaymentAllocation, CreditFacilityProposal, CreditFacilityProposalConcludedPayload, CreditFacilityProposalConnection, CreditFacilityProposalCreateInput, CreditFacilityProposalCreatePayload, CreditFacilityProposalCustomerApprovalConcludeInput, CreditFacilityProposalCustomerApprovalConcludePayload, CreditFacilityProposalEdge, CreditFacilityRepaymentAmountReceived, CreditFacilityRepaymentPlanEntry, CreditModuleConfig, CreditModuleConfigureInput, CreditModuleConfigurePayload, Custodian, CustodianConfigInput, CustodianConfigUpdateInput, CustodianConfigUpdatePayload, CustodianConnection, CustodianCreateInput, CustodianCreatePayload, CustodianEdge, Customer, CustomerConnection, CustomerDocument, CustomerDocumentArchiveInput, CustomerDocumentArchivePayload, CustomerDocumentCreateInput, CustomerDocumentCreatePayload, CustomerDocumentDeleteInput, CustomerDocumentDeletePayload, CustomerDocumentDownloadLinksGenerateInput, CustomerDocumentDownloadLinksGeneratePayload, CustomerEdge, CustomerEmailUpdateInput, CustomerEmailUpdatePayload, CustomerTelegramHandleUpdateInput, CustomerTelegramHandleUpdatePayload, CustomersFilter, CustomersSort, Dashboard, Deposit, DepositAccount, DepositAccountBalance, DepositAccountCloseInput, DepositAccountClosePayload, DepositAccountConnection, DepositAccountCreateInput, DepositAccountCreatePayload, DepositAccountEdge, DepositAccountFreezeInput, DepositAccountFreezePayload, DepositAccountHistoryEntryConnection, DepositAccountHistoryEntryEdge, DepositAccountLedgerAccounts, DepositAccountUnfreezeInput, DepositAccountUnfreezePayload, DepositConnection, DepositEdge, DepositEntry, DepositModuleConfig, DepositModuleConfigureInput, DepositModuleConfigurePayload, DepositRecordInput, DepositRecordPayload, DepositRevertInput, DepositRevertPayload, DisbursalEntry, Disbursed, DomainConfig, DomainConfigConnection, DomainConfigEdge, DomainConfigUpdateInput, DomainConfigUpdatePayload, Duration, DurationInput, FacilityRemaining, FiniteCvlPct, FiscalMonthClosure, FiscalYear, FiscalYearCloseInput, FiscalYearCloseMonthInput, FiscalYearCloseMonthPayload, FiscalYearClosePayload, FiscalYearConnection, FiscalYearEdge, FiscalYearInitInput, FiscalYearInitPayload, FiscalYearOpenNextInput, FiscalYearOpenNextPayload, FreezeEntry, GovernanceNavigationItems, InfiniteCvlPct, Interest, JournalEntry, JournalEntryConnection, JournalEntryEdge, KomainuConfig, LedgerAccount, LedgerAccountBalanceRangeByCurrency, LedgerAccountCsvCreateInput, LedgerAccountCsvCreatePayload, LedgerAccountCsvExportUploadedPayload, LedgerTransaction, LedgerTransactionConnection, LedgerTransactionEdge, Liquidation, LiquidationCollateralSent, LiquidationConnection, LiquidationEdge, LiquidationProceedsReceived, Loan, LoanAgreement, LoanAgreementDownloadLinksGenerateInput, LoanAgreementDownloadLinksGeneratePayload, LoanAgreementGenerateInput, LoanAgreementGeneratePayload, ManualTransactionEntryInput, ManualTransactionExecuteInput, ManualTransactionExecutePayload, Me, Mutation, Outstanding, PageInfo, PaymentEntry, PaymentsUnapplied, PendingCreditFacility, PendingCreditFacilityCollateralizationPayload, PendingCreditFacilityCollateralizationUpdated, PendingCreditFacilityCompletedPayload, PendingCreditFacilityConnection, PendingCreditFacilityEdge, PermissionSet, PermissionSetConnection, PermissionSetEdge, PersonalInfo, Policy, PolicyAssignCommitteeInput, PolicyAssignCommitteePayload, PolicyConnection, PolicyEdge, ProfitAndLossStatement, Prospect, ProspectCloseInput, ProspectClosePayload, ProspectConnection, ProspectConvertInput, ProspectConvertPayload, ProspectCreateInput, ProspectCreatePayload, ProspectEdge, Query, RealtimePrice, Report, ReportFile, ReportFileGenerateDownloadLinkInput, ReportFileGenerateDownloadLinkPayload, ReportRun, ReportRunConnection, ReportRunCreatePayload, ReportRunEdge, ReportRunUpdatedPayload, Role, RoleAddPermissionSetsInput, RoleAddPermissionSetsPayload, RoleConnection, RoleCreateInput, RoleCreatePayload, RoleEdge, RoleRemovePermissionSetsInput, RoleRemovePermissionSetsPayload, Subscription, SumsubPermalinkCreateInput, SumsubPermalinkCreatePayload, System, SystemApproval, TermValues, TermsInput, TermsTemplate, TermsTemplateCreateInput, TermsTemplateCreatePayload, TermsTemplateUpdateInput, TermsTemplateUpdatePayload, Total, TransactionTemplate, TransactionTemplateConnection, TransactionTemplateEdge, TrialBalance, UnfreezeEntry, UnknownEntry, UsdAmount, UsdBalanceDetails, UsdLedgerAccountBalance, UsdLedgerAccountBalanceRange, User, UserCreateInput, UserCreatePayload, UserUpdateRoleInput, UserUpdateRolePayload, VisibleNavigationItems, Wallet, Withdrawal, WithdrawalCancelInput, WithdrawalCancelPayload, WithdrawalConfirmInput, WithdrawalConfirmPayload, WithdrawalConnection, WithdrawalEdge, WithdrawalEntry, WithdrawalInitiateInput, WithdrawalInitiatePayload, WithdrawalRevertInput, WithdrawalRevertPayload, AccountCategory, Activity, ApprovalProcessStatus, ApprovalProcessType, CollateralDirection, CollateralizationState, ConfigType, CreditFacilitiesSortBy, CreditFacilityProposalStatus, CreditFacilityRepaymentStatus, CreditFacilityRepaymentType, CreditFacilityStatus, CustomerType, CustomersSortBy, DebitOrCredit, DepositAccountStatus, DepositStatus, DisbursalPolicy, DisbursalStatus, DocumentStatus, InterestInterval, KycLevel, KycStatus, KycVerification, Layer, LoanAgreementStatus, PendingCreditFacilityCollateralizationState, PendingCreditFacilityStatus, Period, ProspectStage, ProspectStatus, ReportRunState, ReportRunType, SortDirection, WalletNetwork, WithdrawalStatus } from './index';
+import { AccountInfo, AccountingBaseConfigInput, AccountingBaseConfigOutput, AccountingCsvDocument, AccountingCsvDownloadLink, AccountingCsvDownloadLinkGenerateInput, AccountingCsvDownloadLinkGeneratePayload, ApprovalProcess, ApprovalProcessApproveInput, ApprovalProcessApprovePayload, ApprovalProcessConnection, ApprovalProcessDenyInput, ApprovalProcessDenyPayload, ApprovalProcessEdge, ApprovalProcessVoter, AuditEntry, AuditEntryConnection, AuditEntryEdge, BalanceSheet, BitgoConfig, BtcAmount, BtcBalanceDetails, BtcLedgerAccountBalance, BtcLedgerAccountBalanceRange, CancelledWithdrawalEntry, CashFlowLine, CashFlowMapping, CashFlowMappingInput, CashFlowSection, CashFlowStatement, CashFlowStatementCsvCreateInput, CashFlowStatementCsvCreatePayload, ChartNode, ChartOfAccounts, ChartOfAccountsAddChildNodeInput, ChartOfAccountsAddChildNodePayload, ChartOfAccountsAddRootNodeInput, ChartOfAccountsAddRootNodePayload, ChartOfAccountsCashFlowMappingUpdateInput, ChartOfAccountsCashFlowMappingUpdatePayload, ChartOfAccountsCsvImportInput, ChartOfAccountsCsvImportPayload, ChartOfAccountsCsvImportWithBaseConfigInput, ChartOfAccountsCsvImportWithBaseConfigPayload, Collateral, CollateralBalance, CollateralRecordProceedsFromLiquidationInput, CollateralRecordProceedsFromLiquidationPayload, CollateralRecordSentToLiquidationInput, CollateralRecordSentToLiquidationPayload, CollateralUpdateInput, CollateralUpdatePayload, Committee, CommitteeAddUserInput, CommitteeAddUserPayload, CommitteeConnection, CommitteeCreateInput, CommitteeCreatePayload, CommitteeEdge, CommitteeRemoveUserInput, CommitteeRemoveUserPayload, CommitteeThreshold, CreditFacilitiesFilter, CreditFacilitiesSort, CreditFacility, CreditFacilityApproved, CreditFacilityBalance, CreditFacilityCollateralSentOut, CreditFacilityCollateralUpdated, CreditFacilityCollateralizationPayload, CreditFacilityCollateralizationUpdated, CreditFacilityCompleteInput, CreditFacilityCompletePayload, CreditFacilityConnection, CreditFacilityDisbursal, CreditFacilityDisbursalConnection, CreditFacilityDisbursalEdge, CreditFacilityDisbursalExecuted, CreditFacilityDisbursalInitiateInput, CreditFacilityDisbursalInitiatePayload, CreditFacilityEdge, CreditFacilityIncrementalPayment, CreditFacilityInterestAccrued, CreditFacilityLedgerAccounts, CreditFacilityPartialPaymentRecordInput, CreditFacilityPartialPaymentRecordPayload, CreditFacilityPartialPaymentWithDateRecordInput, CreditFacilityPaymentAllocation, CreditFacilityProposal, CreditFacilityProposalConcludedPayload, CreditFacilityProposalConnection, CreditFacilityProposalCreateInput, CreditFacilityProposalCreatePayload, CreditFacilityProposalCustomerApprovalConcludeInput, CreditFacilityProposalCustomerApprovalConcludePayload, CreditFacilityProposalEdge, CreditFacilityRepaymentAmountReceived, CreditFacilityRepaymentPlanEntry, CreditModuleConfig, CreditModuleConfigureInput, CreditModuleConfigurePayload, Custodian, CustodianConfigInput, CustodianConfigUpdateInput, CustodianConfigUpdatePayload, CustodianConnection, CustodianCreateInput, CustodianCreatePayload, CustodianEdge, Customer, CustomerConnection, CustomerDocument, CustomerDocumentArchiveInput, CustomerDocumentArchivePayload, CustomerDocumentCreateInput, CustomerDocumentCreatePayload, CustomerDocumentDeleteInput, CustomerDocumentDeletePayload, CustomerDocumentDownloadLinksGenerateInput, CustomerDocumentDownloadLinksGeneratePayload, CustomerEdge, CustomerEmailUpdateInput, CustomerEmailUpdatePayload, CustomerTelegramHandleUpdateInput, CustomerTelegramHandleUpdatePayload, CustomersFilter, CustomersSort, Dashboard, Deposit, DepositAccount, DepositAccountBalance, DepositAccountCloseInput, DepositAccountClosePayload, DepositAccountConnection, DepositAccountCreateInput, DepositAccountCreatePayload, DepositAccountEdge, DepositAccountFreezeInput, DepositAccountFreezePayload, DepositAccountHistoryEntryConnection, DepositAccountHistoryEntryEdge, DepositAccountLedgerAccounts, DepositAccountUnfreezeInput, DepositAccountUnfreezePayload, DepositConnection, DepositEdge, DepositEntry, DepositModuleConfig, DepositModuleConfigureInput, DepositModuleConfigurePayload, DepositRecordInput, DepositRecordPayload, DepositRevertInput, DepositRevertPayload, DisbursalEntry, Disbursed, DomainConfig, DomainConfigConnection, DomainConfigEdge, DomainConfigUpdateInput, DomainConfigUpdatePayload, Duration, DurationInput, FacilityRemaining, FiniteCvlPct, FiscalMonthClosure, FiscalYear, FiscalYearCloseInput, FiscalYearCloseMonthInput, FiscalYearCloseMonthPayload, FiscalYearClosePayload, FiscalYearConnection, FiscalYearEdge, FiscalYearInitInput, FiscalYearInitPayload, FiscalYearOpenNextInput, FiscalYearOpenNextPayload, FreezeEntry, GovernanceNavigationItems, InfiniteCvlPct, Interest, JournalEntry, JournalEntryConnection, JournalEntryEdge, KomainuConfig, LedgerAccount, LedgerAccountBalanceRangeByCurrency, LedgerAccountCsvCreateInput, LedgerAccountCsvCreatePayload, LedgerAccountCsvExportUploadedPayload, LedgerTransaction, LedgerTransactionConnection, LedgerTransactionEdge, Liquidation, LiquidationCollateralSent, LiquidationConnection, LiquidationEdge, LiquidationProceedsReceived, Loan, LoanAgreement, LoanAgreementDownloadLinksGenerateInput, LoanAgreementDownloadLinksGeneratePayload, LoanAgreementGenerateInput, LoanAgreementGeneratePayload, ManualTransaction, ManualTransactionEntry, ManualTransactionEntryInput, ManualTransactionExecuteInput, ManualTransactionExecutePayload, ManualTransactionReverseInput, ManualTransactionReversePayload, Me, Mutation, Outstanding, PageInfo, PaymentEntry, PaymentsUnapplied, PendingCreditFacility, PendingCreditFacilityCollateralizationPayload, PendingCreditFacilityCollateralizationUpdated, PendingCreditFacilityCompletedPayload, PendingCreditFacilityConnection, PendingCreditFacilityEdge, PermissionSet, PermissionSetConnection, PermissionSetEdge, PersonalInfo, Policy, PolicyAssignCommitteeInput, PolicyAssignCommitteePayload, PolicyConnection, PolicyEdge, ProfitAndLossStatement, Prospect, ProspectCloseInput, ProspectClosePayload, ProspectConnection, ProspectConvertInput, ProspectConvertPayload, ProspectCreateInput, ProspectCreatePayload, ProspectEdge, Query, RealtimePrice, RecurringJournalEntry, RecurringJournalEntryConnection, RecurringJournalEntryCreateInput, RecurringJournalEntryCreatePayload, RecurringJournalEntryEdge, RecurringJournalEntryOccurrence, RecurringJournalEntryPauseInput, RecurringJournalEntryPausePayload, RecurringJournalEntryResumeInput, RecurringJournalEntryResumePayload, RecurringJournalEntryScheduledAmount, RecurringJournalEntryScheduledAmountInput, RecurringJournalEntrySkipOccurrenceInput, RecurringJournalEntrySkipOccurrencePayload, Report, ReportFile, ReportFileGenerateDownloadLinkInput, ReportFileGenerateDownloadLinkPayload, ReportRun, ReportRunConnection, ReportRunCreatePayload, ReportRunEdge, ReportRunUpdatedPayload, Role, RoleAddPermissionSetsInput, RoleAddPermissionSetsPayload, RoleConnection, RoleCreateInput, RoleCreatePayload, RoleEdge, RoleRemovePermissionSetsInput, RoleRemovePermissionSetsPayload, Subscription, SumsubPermalinkCreateInput, SumsubPermalinkCreatePayload, System, SystemApproval, TermValues, TermsInput, TermsTemplate, TermsTemplateCreateInput, TermsTemplateCreatePayload, TermsTemplateCustomerSelectabilityUpdateInput, TermsTemplateCustomerSelectabilityUpdatePayload, TermsTemplateUpdateInput, TermsTemplateUpdatePayload, Total, TransactionTemplate, TransactionTemplateConnection, TransactionTemplateEdge, TrialBalance, UnfreezeEntry, UnknownEntry, UsdAmount, UsdBalanceDetails, UsdLedgerAccountBalance, UsdLedgerAccountBalanceRange, User, UserCreateInput, UserCreatePayload, UserUpdateRoleInput, UserUpdateRolePayload, VisibleNavigationItems, Wallet, Withdrawal, WithdrawalCancelInput, WithdrawalCancelPayload, WithdrawalConfirmInput, WithdrawalConfirmPayload, WithdrawalConnection, WithdrawalEdge, WithdrawalEntry, WithdrawalInitiateInput, WithdrawalInitiatePayload, WithdrawalRevertInput, WithdrawalRevertPayload, AccountCategory, Activity, ApprovalProcessStatus, ApprovalProcessType, CashFlowCategory, CollateralDirection, CollateralizationState, ConfigType, CreditFacilitiesSortBy, CreditFacilityProposalStatus, CreditFacilityRepaymentStatus, CreditFacilityRepaymentType, CreditFacilityStatus, CustomerType, CustomersSortBy, DebitOrCredit, DepositAccountStatus, DepositStatus, DisbursalPolicy, DisbursalStatus, DocumentStatus, InterestInterval, KycLevel, KycStatus, KycVerification, Layer, LoanAgreementStatus, ManualTransactionStatus, PendingCreditFacilityCollateralizationState, PendingCreditFacilityStatus, Period, ProspectStage, ProspectStatus, RecurringJournalEntryFrequency, RecurringJournalEntryStatus, ReportRunState, ReportRunType, SortDirection, WalletNetwork, WithdrawalStatus } from './index';
 
 faker.seed(0);
 
@@ -76,6 +76,7 @@ export const mockAccountingBaseConfigInput = (overrides?: Partial<AccountingBase
     relationshipsToOmit.add('AccountingBaseConfigInput');
     return {
         assetsCode: overrides && overrides.hasOwnProperty('assetsCode') ? overrides.assetsCode! : faker.lorem.word(),
+        cashFlowMapping: overrides && overrides.hasOwnProperty('cashFlowMapping') ? overrides.cashFlowMapping! : [relationshipsToOmit.has('CashFlowMappingInput') ? {} as CashFlowMappingInput : mockCashFlowMappingInput({}, relationshipsToOmit)],
         costOfRevenueCode: overrides && overrides.hasOwnProperty('costOfRevenueCode') ? overrides.costOfRevenueCode! : faker.lorem.word(),
         equityCode: overrides && overrides.hasOwnProperty('equityCode') ? overrides.equityCode! : faker.lorem.word(),
         equityRetainedEarningsGainCode: overrides && overrides.hasOwnProperty('equityRetainedEarningsGainCode') ? overrides.equityRetainedEarningsGainCode! : faker.lorem.word(),
@@ -92,6 +93,7 @@ export const mockAccountingBaseConfigOutput = (overrides?: Partial<AccountingBas
     return {
         __typename: 'AccountingBaseConfigOutput',
         assetsCode: overrides && overrides.hasOwnProperty('assetsCode') ? overrides.assetsCode! : faker.lorem.word(),
+        cashFlowMapping: overrides && overrides.hasOwnProperty('cashFlowMapping') ? overrides.cashFlowMapping! : [relationshipsToOmit.has('CashFlowMapping') ? {} as CashFlowMapping : mockCashFlowMapping({}, relationshipsToOmit)],
         costOfRevenueCode: overrides && overrides.hasOwnProperty('costOfRevenueCode') ? overrides.costOfRevenueCode! : faker.lorem.word(),
         equityCode: overrides && overrides.hasOwnProperty('equityCode') ? overrides.equityCode! : faker.lorem.word(),
         equityRetainedEarningsGainCode: overrides && overrides.hasOwnProperty('equityRetainedEarningsGainCode') ? overrides.equityRetainedEarningsGainCode! : faker.lorem.word(),
@@ -344,6 +346,84 @@ export const mockCancelledWithdrawalEntry = (overrides?: Partial<CancelledWithdr
     };
 };
 
+export const mockCashFlowLine = (overrides?: Partial<CashFlowLine>, _relationshipsToOmit: Set<string> = new Set()): { __typename: 'CashFlowLine' } & CashFlowLine => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('CashFlowLine');
+    return {
+        __typename: 'CashFlowLine',
+        amount: overrides && overrides.hasOwnProperty('amount') ? overrides.amount! : generateMockValue.signedUsdCents(),
+        code: overrides && overrides.hasOwnProperty('code') ? overrides.code! : faker.lorem.word(),
+        ledgerAccount: overrides && overrides.hasOwnProperty('ledgerAccount') ? overrides.ledgerAccount! : relationshipsToOmit.has('LedgerAccount') ? {} as LedgerAccount : mockLedgerAccount({}, relationshipsToOmit),
+        ledgerAccountId: overrides && overrides.hasOwnProperty('ledgerAccountId') ? overrides.ledgerAccountId! : generateMockValue.uuid(),
+        name: overrides && overrides.hasOwnProperty('name') ? overrides.name! : faker.lorem.word(),
+    };
+};
+
+export const mockCashFlowMapping = (overrides?: Partial<CashFlowMapping>, _relationshipsToOmit: Set<string> = new Set()): { __typename: 'CashFlowMapping' } & CashFlowMapping => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('CashFlowMapping');
+    return {
+        __typename: 'CashFlowMapping',
+        category: overrides && overrides.hasOwnProperty('category') ? overrides.category! : CashFlowCategory.Cash,
+        code: overrides && overrides.hasOwnProperty('code') ? overrides.code! : faker.lorem.word(),
+    };
+};
+
+export const mockCashFlowMappingInput = (overrides?: Partial<CashFlowMappingInput>, _relationshipsToOmit: Set<string> = new Set()): CashFlowMappingInput => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('CashFlowMappingInput');
+    return {
+        category: overrides && overrides.hasOwnProperty('category') ? overrides.category! : CashFlowCategory.Cash,
+        code: overrides && overrides.hasOwnProperty('code') ? overrides.code! : faker.lorem.word(),
+    };
+};
+
+export const mockCashFlowSection = (overrides?: Partial<CashFlowSection>, _relationshipsToOmit: Set<string> = new Set()): { __typename: 'CashFlowSection' } & CashFlowSection => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('CashFlowSection');
+    return {
+        __typename: 'CashFlowSection',
+        lines: overrides && overrides.hasOwnProperty('lines') ? overrides.lines! : [relationshipsToOmit.has('CashFlowLine') ? {} as CashFlowLine : mockCashFlowLine({}, relationshipsToOmit)],
+        total: overrides && overrides.hasOwnProperty('total') ? overrides.total! : generateMockValue.signedUsdCents(),
+    };
+};
+
+export const mockCashFlowStatement = (overrides?: Partial<CashFlowStatement>, _relationshipsToOmit: Set<string> = new Set()): { __typename: 'CashFlowStatement' } & CashFlowStatement => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('CashFlowStatement');
+    return {
+        __typename: 'CashFlowStatement',
+        closingCash: overrides && overrides.hasOwnProperty('closingCash') ? overrides.closingCash! : generateMockValue.signedUsdCents(),
+        financing: overrides && overrides.hasOwnProperty('financing') ? overrides.financing! : relationshipsToOmit.has('CashFlowSection') ? {} as CashFlowSection : mockCashFlowSection({}, relationshipsToOmit),
+        from: overrides && overrides.hasOwnProperty('from') ? overrides.from! : faker.date.past({ years: 1, refDate: new Date(2022, 0) }).toISOString(),
+        investing: overrides && overrides.hasOwnProperty('investing') ? overrides.investing! : relationshipsToOmit.has('CashFlowSection') ? {} as CashFlowSection : mockCashFlowSection({}, relationshipsToOmit),
+        netChangeInCash: overrides && overrides.hasOwnProperty('netChangeInCash') ? overrides.netChangeInCash! : generateMockValue.signedUsdCents(),
+        netIncome: overrides && overrides.hasOwnProperty('netIncome') ? overrides.netIncome! : generateMockValue.signedUsdCents(),
+        openingCash: overrides && overrides.hasOwnProperty('openingCash') ? overrides.openingCash! : generateMockValue.signedUsdCents(),
+        operating: overrides && overrides.hasOwnProperty('operating') ? overrides.operating! : relationshipsToOmit.has('CashFlowSection') ? {} as CashFlowSection : mockCashFlowSection({}, relationshipsToOmit),
+        unreconciled: overrides && overrides.hasOwnProperty('unreconciled') ? overrides.unreconciled! : generateMockValue.signedUsdCents(),
+        until: overrides && overrides.hasOwnProperty('until') ? overrides.until! : faker.date.past({ years: 1, refDate: new Date(2022, 0) }).toISOString(),
+    };
+};
+
+export const mockCashFlowStatementCsvCreateInput = (overrides?: Partial<CashFlowStatementCsvCreateInput>, _relationshipsToOmit: Set<string> = new Set()): CashFlowStatementCsvCreateInput => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('CashFlowStatementCsvCreateInput');
+    return {
+        from: overrides && overrides.hasOwnProperty('from') ? overrides.from! : faker.date.past({ years: 1, refDate: new Date(2022, 0) }).toISOString(),
+        until: overrides && overrides.hasOwnProperty('until') ? overrides.until! : faker.date.past({ years: 1, refDate: new Date(2022, 0) }).toISOString(),
+    };
+};
+
+export const mockCashFlowStatementCsvCreatePayload = (overrides?: Partial<CashFlowStatementCsvCreatePayload>, _relationshipsToOmit: Set<string> = new Set()): { __typename: 'CashFlowStatementCsvCreatePayload' } & CashFlowStatementCsvCreatePayload => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('CashFlowStatementCsvCreatePayload');
+    return {
+        __typename: 'CashFlowStatementCsvCreatePayload',
+        accountingCsvDocument: overrides && overrides.hasOwnProperty('accountingCsvDocument') ? overrides.accountingCsvDocument! : relationshipsToOmit.has('AccountingCsvDocument') ? {} as AccountingCsvDocument : mockAccountingCsvDocument({}, relationshipsToOmit),
+    };
+};
+
 export const mockChartNode = (overrides?: Partial<ChartNode>, _relationshipsToOmit: Set<string> = new Set()): { __typename: 'ChartNode' } & ChartNode => {
     const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
     relationshipsToOmit.add('ChartNode');
@@ -406,6 +486,23 @@ export const mockChartOfAccountsAddRootNodePayload = (overrides?: Partial<ChartO
     };
 };
 
+export const mockChartOfAccountsCashFlowMappingUpdateInput = (overrides?: Partial<ChartOfAccountsCashFlowMappingUpdateInput>, _relationshipsToOmit: Set<string> = new Set()): ChartOfAccountsCashFlowMappingUpdateInput => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('ChartOfAccountsCashFlowMappingUpdateInput');
+    return {
+        cashFlowMapping: overrides && overrides.hasOwnProperty('cashFlowMapping') ? overrides.cashFlowMapping! : [relationshipsToOmit.has('CashFlowMappingInput') ? {} as CashFlowMappingInput : mockCashFlowMappingInput({}, relationshipsToOmit)],
+    };
+};
+
+export const mockChartOfAccountsCashFlowMappingUpdatePayload = (overrides?: Partial<ChartOfAccountsCashFlowMappingUpdatePayload>, _relationshipsToOmit: Set<string> = new Set()): { __typename: 'ChartOfAccountsCashFlowMappingUpdatePayload' } & ChartOfAccountsCashFlowMappingUpdatePayload => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('ChartOfAccountsCashFlowMappingUpdatePayload');
+    return {
+        __typename: 'ChartOfAccountsCashFlowMappingUpdatePayload',
+        chartOfAccounts: overrides && overrides.hasOwnProperty('chartOfAccounts') ? overrides.chartOfAccounts! : relationshipsToOmit.has('ChartOfAccounts') ? {} as ChartOfAccounts : mockChartOfAccounts({}, relationshipsToOmit),
+    };
+};
+
 export const mockChartOfAccountsCsvImportInput = (overrides?: Partial<ChartOfAccountsCsvImportInput>, _relationshipsToOmit: Set<string> = new Set()): ChartOfAccountsCsvImportInput => {
     const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
     relationshipsToOmit.add('ChartOfAccountsCsvImportInput');
@@ -2301,6 +2398,41 @@ export const mockLoanAgreementGeneratePayload = (overrides?: Partial<LoanAgreeme
     };
 };
 
+export const mockManualTransaction = (overrides?: Partial<ManualTransaction>, _relationshipsToOmit: Set<string> = new Set()): { __typename: 'ManualTransaction' } & ManualTransaction => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('ManualTransaction');
+    return {
+        __typename: 'ManualTransaction',
+        approvalProcess: overrides && overrides.hasOwnProperty('approvalProcess') ? overrides.approvalProcess! : relationshipsToOmit.has('ApprovalProcess') ? {} as ApprovalProcess : mockApprovalProcess({}, relationshipsToOmit),
+        createdAt: overrides && overrides.hasOwnProperty('createdAt') ? overrides.createdAt! : generateMockValue.timestamp(),
+        description: overrides && overrides.hasOwnProperty('description') ? overrides.description! : generateMockValue.description(),
+        effective: overrides && overrides.hasOwnProperty('effective') ? overrides.effective! : faker.date.past({ years: 1, refDate: new Date(2022, 0) }).toISOString(),
+        entries: overrides && overrides.hasOwnProperty('entries') ? overrides.entries! : [relationshipsToOmit.has('ManualTransactionEntry') ? {} as ManualTransactionEntry : mockManualTransactionEntry({}, relationshipsToOmit)],
+        id: overrides && overrides.hasOwnProperty('id') ? overrides.id! : faker.string.uuid(),
+        ledgerTransaction: overrides && overrides.hasOwnProperty('ledgerTransaction') ? overrides.ledgerTransaction! : relationshipsToOmit.has('LedgerTransaction') ? {} as LedgerTransaction : mockLedgerTransaction({}, relationshipsToOmit),
+        manualTransactionId: overrides && overrides.hasOwnProperty('manualTransactionId') ? overrides.manualTransactionId! : generateMockValue.uuid(),
+        reference: overrides && overrides.hasOwnProperty('reference') ? overrides.reference! : generateMockValue.reference(),
+        reversalApprovalProcess: overrides && overrides.hasOwnProperty('reversalApprovalProcess') ? overrides.reversalApprovalProcess! : relationshipsToOmit.has('ApprovalProcess') ? {} as ApprovalProcess : mockApprovalProcess({}, relationshipsToOmit),
+        reversalEffective: overrides && overrides.hasOwnProperty('reversalEffective') ? overrides.reversalEffective! : faker.date.past({ years: 1, refDate: new Date(2022, 0) }).toISOString(),
+        reversalLedgerTransaction: overrides && overrides.hasOwnProperty('reversalLedgerTransaction') ? overrides.reversalLedgerTransaction! : relationshipsToOmit.has('LedgerTransaction') ? {} as LedgerTransaction : mockLedgerTransaction({}, relationshipsToOmit),
+        status: overrides && overrides.hasOwnProperty('status') ? overrides.status! : ManualTransactionStatus.Denied,
+        statusReason: overrides && overrides.hasOwnProperty('statusReason') ? overrides.statusReason! : faker.lorem.word(),
+    };
+};
+
+export const mockManualTransactionEntry = (overrides?: Partial<ManualTransactionEntry>, _relationshipsToOmit: Set<string> = new Set()): { __typename: 'ManualTransactionEntry' } & ManualTransactionEntry => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('ManualTransactionEntry');
+    return {
+        __typename: 'ManualTransactionEntry',
+        amount: overrides && overrides.hasOwnProperty('amount') ? overrides.amount! : faker.lorem.word(),
+        currency: overrides && overrides.hasOwnProperty('currency') ? overrides.currency! : faker.lorem.word(),
+        description: overrides && overrides.hasOwnProperty('description') ? overrides.description! : generateMockValue.description(),
+        direction: overrides && overrides.hasOwnProperty('direction') ? overrides.direction! : DebitOrCredit.Credit,
+        ledgerAccount: overrides && overrides.hasOwnProperty('ledgerAccount') ? overrides.ledgerAccount! : relationshipsToOmit.has('LedgerAccount') ? {} as LedgerAccount : mockLedgerAccount({}, relationshipsToOmit),
+    };
+};
+
 export const mockManualTransactionEntryInput = (overrides?: Partial<ManualTransactionEntryInput>, _relationshipsToOmit: Set<string> = new Set()): ManualTransactionEntryInput => {
     const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
     relationshipsToOmit.add('ManualTransactionEntryInput');
@@ -2329,7 +2461,26 @@ export const mockManualTransactionExecutePayload = (overrides?: Partial<ManualTr
     relationshipsToOmit.add('ManualTransactionExecutePayload');
     return {
         __typename: 'ManualTransactionExecutePayload',
-        transaction: overrides && overrides.hasOwnProperty('transaction') ? overrides.transaction! : relationshipsToOmit.has('LedgerTransaction') ? {} as LedgerTransaction : mockLedgerTransaction({}, relationshipsToOmit),
+        manualTransaction: overrides && overrides.hasOwnProperty('manualTransaction') ? overrides.manualTransaction! : relationshipsToOmit.has('ManualTransaction') ? {} as ManualTransaction : mockManualTransaction({}, relationshipsToOmit),
+    };
+};
+
+export const mockManualTransactionReverseInput = (overrides?: Partial<ManualTransactionReverseInput>, _relationshipsToOmit: Set<string> = new Set()): ManualTransactionReverseInput => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('ManualTransactionReverseInput');
+    return {
+        effective: overrides && overrides.hasOwnProperty('effective') ? overrides.effective! : faker.date.past({ years: 1, refDate: new Date(2022, 0) }).toISOString(),
+        manualTransactionId: overrides && overrides.hasOwnProperty('manualTransactionId') ? overrides.manualTransactionId! : generateMockValue.uuid(),
+        nextOpenPeriod: overrides && overrides.hasOwnProperty('nextOpenPeriod') ? overrides.nextOpenPeriod! : faker.datatype.boolean(),
+    };
+};
+
+export const mockManualTransactionReversePayload = (overrides?: Partial<ManualTransactionReversePayload>, _relationshipsToOmit: Set<string> = new Set()): { __typename: 'ManualTransactionReversePayload' } & ManualTransactionReversePayload => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('ManualTransactionReversePayload');
+    return {
+        __typename: 'ManualTransactionReversePayload',
+        manualTransaction: overrides && overrides.hasOwnProperty('manualTransaction') ? overrides.manualTransaction! : relationshipsToOmit.has('ManualTransaction') ? {} as ManualTransaction : mockManualTransaction({}, relationshipsToOmit),
     };
 };
 
@@ -2354,8 +2505,10 @@ export const mockMutation = (overrides?: Partial<Mutation>, _relationshipsToOmit
         accountingCsvDownloadLinkGenerate: overrides && overrides.hasOwnProperty('accountingCsvDownloadLinkGenerate') ? overrides.accountingCsvDownloadLinkGenerate! : relationshipsToOmit.has('AccountingCsvDownloadLinkGeneratePayload') ? {} as AccountingCsvDownloadLinkGeneratePayload : mockAccountingCsvDownloadLinkGeneratePayload({}, relationshipsToOmit),
         approvalProcessApprove: overrides && overrides.hasOwnProperty('approvalProcessApprove') ? overrides.approvalProcessApprove! : relationshipsToOmit.has('ApprovalProcessApprovePayload') ? {} as ApprovalProcessApprovePayload : mockApprovalProcessApprovePayload({}, relationshipsToOmit),
         approvalProcessDeny: overrides && overrides.hasOwnProperty('approvalProcessDeny') ? overrides.approvalProcessDeny! : relationshipsToOmit.has('ApprovalProcessDenyPayload') ? {} as ApprovalProcessDenyPayload : mockApprovalProcessDenyPayload({}, relationshipsToOmit),
+        cashFlowStatementCsvCreate: overrides && overrides.hasOwnProperty('cashFlowStatementCsvCreate') ? overrides.cashFlowStatementCsvCreate! : relationshipsToOmit.has('CashFlowStatementCsvCreatePayload') ? {} as CashFlowStatementCsvCreatePayload : mockCashFlowStatementCsvCreatePayload({}, relationshipsToOmit),
         chartOfAccountsAddChildNode: overrides && overrides.hasOwnProperty('chartOfAccountsAddChildNode') ? overrides.chartOfAccountsAddChildNode! : relationshipsToOmit.has('ChartOfAccountsAddChildNodePayload') ? {} as ChartOfAccountsAddChildNodePayload : mockChartOfAccountsAddChildNodePayload({}, relationshipsToOmit),
         chartOfAccountsAddRootNode: overrides && overrides.hasOwnProperty('chartOfAccountsAddRootNode') ? overrides.chartOfAccountsAddRootNode! : relationshipsToOmit.has('ChartOfAccountsAddRootNodePayload') ? {} as ChartOfAccountsAddRootNodePayload : mockChartOfAccountsAddRootNodePayload({}, relationshipsToOmit),
+        chartOfAccountsCashFlowMappingUpdate: overrides && overrides.hasOwnProperty('chartOfAccountsCashFlowMappingUpdate') ? overrides.chartOfAccountsCashFlowMappingUpdate! : relationshipsToOmit.has('ChartOfAccountsCashFlowMappingUpdatePayload') ? {} as ChartOfAccountsCashFlowMappingUpdatePayload : mockChartOfAccountsCashFlowMappingUpdatePayload({}, relationshipsToOmit),
         chartOfAccountsCsvImport: overrides && overrides.hasOwnProperty('chartOfAccountsCsvImport') ? overrides.chartOfAccountsCsvImport! : relationshipsToOmit.has('ChartOfAccountsCsvImportPayload') ? {} as ChartOfAccountsCsvImportPayload : mockChartOfAccountsCsvImportPayload({}, relationshipsToOmit),
         chartOfAccountsCsvImportWithBaseConfig: overrides && overrides.hasOwnProperty('chartOfAccountsCsvImportWithBaseConfig') ? overrides.chartOfAccountsCsvImportWithBaseConfig! : relationshipsToOmit.has('ChartOfAccountsCsvImportWithBaseConfigPayload') ? {} as ChartOfAccountsCsvImportWithBaseConfigPayload : mockChartOfAccountsCsvImportWithBaseConfigPayload({}, relationshipsToOmit),
         collateralRecordProceedsFromLiquidation: overrides && overrides.hasOwnProperty('collateralRecordProceedsFromLiquidation') ? overrides.collateralRecordProceedsFromLiquidation! : relationshipsToOmit.has('CollateralRecordProceedsFromLiquidationPayload') ? {} as CollateralRecordProceedsFromLiquidationPayload : mockCollateralRecordProceedsFromLiquidationPayload({}, relationshipsToOmit),
@@ -2395,16 +2548,22 @@ export const mockMutation = (overrides?: Partial<Mutation>, _relationshipsToOmit
         loanAgreementDownloadLinkGenerate: overrides && overrides.hasOwnProperty('loanAgreementDownloadLinkGenerate') ? overrides.loanAgreementDownloadLinkGenerate! : relationshipsToOmit.has('LoanAgreementDownloadLinksGeneratePayload') ? {} as LoanAgreementDownloadLinksGeneratePayload : mockLoanAgreementDownloadLinksGeneratePayload({}, relationshipsToOmit),
         loanAgreementGenerate: overrides && overrides.hasOwnProperty('loanAgreementGenerate') ? overrides.loanAgreementGenerate! : relationshipsToOmit.has('LoanAgreementGeneratePayload') ? {} as LoanAgreementGeneratePayload : mockLoanAgreementGeneratePayload({}, relationshipsToOmit),
         manualTransactionExecute: overrides && overrides.hasOwnProperty('manualTransactionExecute') ? overrides.manualTransactionExecute! : relationshipsToOmit.has('ManualTransactionExecutePayload') ? {} as ManualTransactionExecutePayload : mockManualTransactionExecutePayload({}, relationshipsToOmit),
+        manualTransactionReverse: overrides && overrides.hasOwnProperty('manualTransactionReverse') ? overrides.manualTransactionReverse! : relationshipsToOmit.has('ManualTransactionReversePayload') ? {} as ManualTransactionReversePayload : mockManualTransactionReversePayload({}, relationshipsToOmit),
         policyAssignCommittee: overrides && overrides.hasOwnProperty('policyAssignCommittee') ? overrides.policyAssignCommittee! : relationshipsToOmit.has('PolicyAssignCommitteePayload') ? {} as PolicyAssignCommitteePayload : mockPolicyAssignCommitteePayload({}, relationshipsToOmit),
         prospectClose: overrides && overrides.hasOwnProperty('prospectClose') ? overrides.prospectClose! : relationshipsToOmit.has('ProspectClosePayload') ? {} as ProspectClosePayload : mockProspectClosePayload({}, relationshipsToOmit),
         prospectConvert: overrides && overrides.hasOwnProperty('prospectConvert') ? overrides.prospectConvert! : relationshipsToOmit.has('ProspectConvertPayload') ? {} as ProspectConvertPayload : mockProspectConvertPayload({}, relationshipsToOmit),
         prospectCreate: overrides && overrides.hasOwnProperty('prospectCreate') ? overrides.prospectCreate! : relationshipsToOmit.has('ProspectCreatePayload') ? {} as ProspectCreatePayload : mockProspectCreatePayload({}, relationshipsToOmit),
+        recurringJournalEntryCreate: overrides && overrides.hasOwnProperty('recurringJournalEntryCreate') ? overrides.recurringJournalEntryCreate! : relationshipsToOmit.has('RecurringJournalEntryCreatePayload') ? {} as RecurringJournalEntryCreatePayload : mockRecurringJournalEntryCreatePayload({}, relationshipsToOmit),
+        recurringJournalEntryPause: overrides && overrides.hasOwnProperty('recurringJournalEntryPause') ? overrides.recurringJournalEntryPause! : relationshipsToOmit.has('RecurringJournalEntryPausePayload') ? {} as RecurringJournalEntryPausePayload : mockRecurringJournalEntryPausePayload({}, relationshipsToOmit),
+        recurringJournalEntryResume: overrides && overrides.hasOwnProperty('recurringJournalEntryResume') ? overrides.recurringJournalEntryResume! : relationshipsToOmit.has('RecurringJournalEntryResumePayload') ? {} as RecurringJournalEntryResumePayload : mockRecurringJournalEntryResumePayload({}, relationshipsToOmit),
+        recurringJournalEntrySkipOccurrence: overrides && overrides.hasOwnProperty('recurringJournalEntrySkipOccurrence') ? overrides.recurringJournalEntrySkipOccurrence! : relationshipsToOmit.has('RecurringJournalEntrySkipOccurrencePayload') ? {} as RecurringJournalEntrySkipOccurrencePayload : mockRecurringJournalEntrySkipOccurrencePayload({}, relationshipsToOmit),
         reportFileGenerateDownloadLink: overrides && overrides.hasOwnProperty('reportFileGenerateDownloadLink') ? overrides.reportFileGenerateDownloadLink! : relationshipsToOmit.has('ReportFileGenerateDownloadLinkPayload') ? {} as ReportFileGenerateDownloadLinkPayload : mockReportFileGenerateDownloadLinkPayload({}, relationshipsToOmit),
         roleAddPermissionSets: overrides && overrides.hasOwnProperty('roleAddPermissionSets') ? overrides.roleAddPermissionSets! : relationshipsToOmit.has('RoleAddPermissionSetsPayload') ? {} as RoleAddPermissionSetsPayload : mockRoleAddPermissionSetsPayload({}, relationshipsToOmit),
         roleCreate: overrides && overrides.hasOwnProperty('roleCreate') ? overrides.roleCreate! : relationshipsToOmit.has('RoleCreatePayload') ? {} as RoleCreatePayload : mockRoleCreatePayload({}, relationshipsToOmit),
         roleRemovePermissionSets: overrides && overrides.hasOwnProperty('roleRemovePermissionSets') ? overrides.roleRemovePermissionSets! : relationshipsToOmit.has('RoleRemovePermissionSetsPayload') ? {} as RoleRemovePermissionSetsPayload : mockRoleRemovePermissionSetsPayload({}, relationshipsToOmit),
         sumsubPermalinkCreate: overrides && overrides.hasOwnProperty('sumsubPermalinkCreate') ? overrides.sumsubPermalinkCreate! : relationshipsToOmit.has('SumsubPermalinkCreatePayload') ? {} as SumsubPermalinkCreatePayload : mockSumsubPermalinkCreatePayload({}, relationshipsToOmit),
         termsTemplateCreate: overrides && overrides.hasOwnProperty('termsTemplateCreate') ? overrides.termsTemplateCreate! : relationshipsToOmit.has('TermsTemplateCreatePayload') ? {} as TermsTemplateCreatePayload : mockTermsTemplateCreatePayload({}, relationshipsToOmit),
+        termsTemplateCustomerSelectabilityUpdate: overrides && overrides.hasOwnProperty('termsTemplateCustomerSelectabilityUpdate') ? overrides.termsTemplateCustomerSelectabilityUpdate! : relationshipsToOmit.has('TermsTemplateCustomerSelectabilityUpdatePayload') ? {} as TermsTemplateCustomerSelectabilityUpdatePayload : mockTermsTemplateCustomerSelectabilityUpdatePayload({}, relationshipsToOmit),
         termsTemplateUpdate: overrides && overrides.hasOwnProperty('termsTemplateUpdate') ? overrides.termsTemplateUpdate! : relationshipsToOmit.has('TermsTemplateUpdatePayload') ? {} as TermsTemplateUpdatePayload : mockTermsTemplateUpdatePayload({}, relationshipsToOmit),
         triggerReportRun: overrides && overrides.hasOwnProperty('triggerReportRun') ? overrides.triggerReportRun! : relationshipsToOmit.has('ReportRunCreatePayload') ? {} as ReportRunCreatePayload : mockReportRunCreatePayload({}, relationshipsToOmit),
         userCreate: overrides && overrides.hasOwnProperty('userCreate') ? overrides.userCreate! : relationshipsToOmit.has('UserCreatePayload') ? {} as UserCreatePayload : mockUserCreatePayload({}, relationshipsToOmit),
@@ -2756,6 +2915,7 @@ export const mockQuery = (overrides?: Partial<Query>, _relationshipsToOmit: Set<
         audit: overrides && overrides.hasOwnProperty('audit') ? overrides.audit! : relationshipsToOmit.has('AuditEntryConnection') ? {} as AuditEntryConnection : mockAuditEntryConnection({}, relationshipsToOmit),
         auditSubjects: overrides && overrides.hasOwnProperty('auditSubjects') ? overrides.auditSubjects! : [generateMockValue.uuid()],
         balanceSheet: overrides && overrides.hasOwnProperty('balanceSheet') ? overrides.balanceSheet! : relationshipsToOmit.has('BalanceSheet') ? {} as BalanceSheet : mockBalanceSheet({}, relationshipsToOmit),
+        cashFlowStatement: overrides && overrides.hasOwnProperty('cashFlowStatement') ? overrides.cashFlowStatement! : relationshipsToOmit.has('CashFlowStatement') ? {} as CashFlowStatement : mockCashFlowStatement({}, relationshipsToOmit),
         chartOfAccounts: overrides && overrides.hasOwnProperty('chartOfAccounts') ? overrides.chartOfAccounts! : relationshipsToOmit.has('ChartOfAccounts') ? {} as ChartOfAccounts : mockChartOfAccounts({}, relationshipsToOmit),
         committee: overrides && overrides.hasOwnProperty('committee') ? overrides.committee! : relationshipsToOmit.has('Committee') ? {} as Committee : mockCommittee({}, relationshipsToOmit),
         committees: overrides && overrides.hasOwnProperty('committees') ? overrides.committees! : relationshipsToOmit.has('CommitteeConnection') ? {} as CommitteeConnection : mockCommitteeConnection({}, relationshipsToOmit),
@@ -2807,6 +2967,8 @@ export const mockQuery = (overrides?: Partial<Query>, _relationshipsToOmit: Set<
         prospects: overrides && overrides.hasOwnProperty('prospects') ? overrides.prospects! : relationshipsToOmit.has('ProspectConnection') ? {} as ProspectConnection : mockProspectConnection({}, relationshipsToOmit),
         publicIdTarget: overrides && overrides.hasOwnProperty('publicIdTarget') ? overrides.publicIdTarget! : relationshipsToOmit.has('CreditFacility') ? {} as CreditFacility : mockCreditFacility({}, relationshipsToOmit),
         realtimePrice: overrides && overrides.hasOwnProperty('realtimePrice') ? overrides.realtimePrice! : relationshipsToOmit.has('RealtimePrice') ? {} as RealtimePrice : mockRealtimePrice({}, relationshipsToOmit),
+        recurringJournalEntries: overrides && overrides.hasOwnProperty('recurringJournalEntries') ? overrides.recurringJournalEntries! : relationshipsToOmit.has('RecurringJournalEntryConnection') ? {} as RecurringJournalEntryConnection : mockRecurringJournalEntryConnection({}, relationshipsToOmit),
+        recurringJournalEntry: overrides && overrides.hasOwnProperty('recurringJournalEntry') ? overrides.recurringJournalEntry! : relationshipsToOmit.has('RecurringJournalEntry') ? {} as RecurringJournalEntry : mockRecurringJournalEntry({}, relationshipsToOmit),
         reportRun: overrides && overrides.hasOwnProperty('reportRun') ? overrides.reportRun! : relationshipsToOmit.has('ReportRun') ? {} as ReportRun : mockReportRun({}, relationshipsToOmit),
         reportRuns: overrides && overrides.hasOwnProperty('reportRuns') ? overrides.reportRuns! : relationshipsToOmit.has('ReportRunConnection') ? {} as ReportRunConnection : mockReportRunConnection({}, relationshipsToOmit),
         role: overrides && overrides.hasOwnProperty('role') ? overrides.role! : relationshipsToOmit.has('Role') ? {} as Role : mockRole({}, relationshipsToOmit),
@@ -2832,6 +2994,160 @@ export const mockRealtimePrice = (overrides?: Partial<RealtimePrice>, _relations
     };
 };
 
+export const mockRecurringJournalEntry = (overrides?: Partial<RecurringJournalEntry>, _relationshipsToOmit: Set<string> = new Set()): { __typename: 'RecurringJournalEntry' } & RecurringJournalEntry => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('RecurringJournalEntry');
+    return {
+        __typename: 'RecurringJournalEntry',
+        amount: overrides && overrides.hasOwnProperty('amount') ? overrides.amount! : faker.lorem.word(),
+        amountSchedule: overrides && overrides.hasOwnProperty('amountSchedule') ? overrides.amountSchedule! : [relationshipsToOmit.has('RecurringJournalEntryScheduledAmount') ? {} as RecurringJournalEntryScheduledAmount : mockRecurringJournalEntryScheduledAmount({}, relationshipsToOmit)],
+        createdAt: overrides && overrides.hasOwnProperty('createdAt') ? overrides.createdAt! : generateMockValue.timestamp(),
+        creditAccount: overrides && overrides.hasOwnProperty('creditAccount') ? overrides.creditAccount! : relationshipsToOmit.has('LedgerAccount') ? {} as LedgerAccount : mockLedgerAccount({}, relationshipsToOmit),
+        currency: overrides && overrides.hasOwnProperty('currency') ? overrides.currency! : faker.lorem.word(),
+        debitAccount: overrides && overrides.hasOwnProperty('debitAccount') ? overrides.debitAccount! : relationshipsToOmit.has('LedgerAccount') ? {} as LedgerAccount : mockLedgerAccount({}, relationshipsToOmit),
+        description: overrides && overrides.hasOwnProperty('description') ? overrides.description! : generateMockValue.description(),
+        endDate: overrides && overrides.hasOwnProperty('endDate') ? overrides.endDate! : faker.date.past({ years: 1, refDate: new Date(2022, 0) }).toISOString(),
+        frequency: overrides && overrides.hasOwnProperty('frequency') ? overrides.frequency! : RecurringJournalEntryFrequency.Monthly,
+        id: overrides && overrides.hasOwnProperty('id') ? overrides.id! : faker.string.uuid(),
+        manualTransactions: overrides && overrides.hasOwnProperty('manualTransactions') ? overrides.manualTransactions! : [relationshipsToOmit.has('ManualTransaction') ? {} as ManualTransaction : mockManualTransaction({}, relationshipsToOmit)],
+        pausedOn: overrides && overrides.hasOwnProperty('pausedOn') ? overrides.pausedOn! : faker.date.past({ years: 1, refDate: new Date(2022, 0) }).toISOString(),
+        recurringJournalEntryId: overrides && overrides.hasOwnProperty('recurringJournalEntryId') ? overrides.recurringJournalEntryId! : generateMockValue.uuid(),
+        reference: overrides && overrides.hasOwnProperty('reference') ? overrides.reference! : generateMockValue.reference(),
+        startDate: overrides && overrides.hasOwnProperty('startDate') ? overrides.startDate! : faker.date.past({ years: 1, refDate: new Date(2022, 0) }).toISOString(),
+        status: overrides && overrides.hasOwnProperty('status') ? overrides.status! : RecurringJournalEntryStatus.Active,
+        upcomingOccurrences: overrides && overrides.hasOwnProperty('upcomingOccurrences') ? overrides.upcomingOccurrences! : [relationshipsToOmit.has('RecurringJournalEntryOccurrence') ? {} as RecurringJournalEntryOccurrence : mockRecurringJournalEntryOccurrence({}, relationshipsToOmit)],
+    };
+};
+
+export const mockRecurringJournalEntryConnection = (overrides?: Partial<RecurringJournalEntryConnection>, _relationshipsToOmit: Set<string> = new Set()): { __typename: 'RecurringJournalEntryConnection' } & RecurringJournalEntryConnection => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('RecurringJournalEntryConnection');
+    return {
+        __typename: 'RecurringJournalEntryConnection',
+        edges: overrides && overrides.hasOwnProperty('edges') ? overrides.edges! : [relationshipsToOmit.has('RecurringJournalEntryEdge') ? {} as RecurringJournalEntryEdge : mockRecurringJournalEntryEdge({}, relationshipsToOmit)],
+        nodes: overrides && overrides.hasOwnProperty('nodes') ? overrides.nodes! : [relationshipsToOmit.has('RecurringJournalEntry') ? {} as RecurringJournalEntry : mockRecurringJournalEntry({}, relationshipsToOmit)],
+        pageInfo: overrides && overrides.hasOwnProperty('pageInfo') ? overrides.pageInfo! : relationshipsToOmit.has('PageInfo') ? {} as PageInfo : mockPageInfo({}, relationshipsToOmit),
+    };
+};
+
+export const mockRecurringJournalEntryCreateInput = (overrides?: Partial<RecurringJournalEntryCreateInput>, _relationshipsToOmit: Set<string> = new Set()): RecurringJournalEntryCreateInput => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('RecurringJournalEntryCreateInput');
+    return {
+        amount: overrides && overrides.hasOwnProperty('amount') ? overrides.amount! : faker.lorem.word(),
+        amountSchedule: overrides && overrides.hasOwnProperty('amountSchedule') ? overrides.amountSchedule! : [relationshipsToOmit.has('RecurringJournalEntryScheduledAmountInput') ? {} as RecurringJournalEntryScheduledAmountInput : mockRecurringJournalEntryScheduledAmountInput({}, relationshipsToOmit)],
+        creditAccountRef: overrides && overrides.hasOwnProperty('creditAccountRef') ? overrides.creditAccountRef! : faker.lorem.word(),
+        currency: overrides && overrides.hasOwnProperty('currency') ? overrides.currency! : faker.lorem.word(),
+        debitAccountRef: overrides && overrides.hasOwnProperty('debitAccountRef') ? overrides.debitAccountRef! : faker.lorem.word(),
+        description: overrides && overrides.hasOwnProperty('description') ? overrides.description! : generateMockValue.description(),
+        endDate: overrides && overrides.hasOwnProperty('endDate') ? overrides.endDate! : faker.date.past({ years: 1, refDate: new Date(2022, 0) }).toISOString(),
+        frequency: overrides && overrides.hasOwnProperty('frequency') ? overrides.frequency! : RecurringJournalEntryFrequency.Monthly,
+        reference: overrides && overrides.hasOwnProperty('reference') ? overrides.reference! : generateMockValue.reference(),
+        startDate: overrides && overrides.hasOwnProperty('startDate') ? overrides.startDate! : faker.date.past({ years: 1, refDate: new Date(2022, 0) }).toISOString(),
+    };
+};
+
+export const mockRecurringJournalEntryCreatePayload = (overrides?: Partial<RecurringJournalEntryCreatePayload>, _relationshipsToOmit: Set<string> = new Set()): { __typename: 'RecurringJournalEntryCreatePayload' } & RecurringJournalEntryCreatePayload => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('RecurringJournalEntryCreatePayload');
+    return {
+        __typename: 'RecurringJournalEntryCreatePayload',
+        recurringJournalEntry: overrides && overrides.hasOwnProperty('recurringJournalEntry') ? overrides.recurringJournalEntry! : relationshipsToOmit.has('RecurringJournalEntry') ? {} as RecurringJournalEntry : mockRecurringJournalEntry({}, relationshipsToOmit),
+    };
+};
+
+export const mockRecurringJournalEntryEdge = (overrides?: Partial<RecurringJournalEntryEdge>, _relationshipsToOmit: Set<string> = new Set()): { __typename: 'RecurringJournalEntryEdge' } & RecurringJournalEntryEdge => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('RecurringJournalEntryEdge');
+    return {
+        __typename: 'RecurringJournalEntryEdge',
+        cursor: overrides && overrides.hasOwnProperty('cursor') ? overrides.cursor! : generateMockValue.cursor(),
+        node: overrides && overrides.hasOwnProperty('node') ? overrides.node! : relationshipsToOmit.has('RecurringJournalEntry') ? {} as RecurringJournalEntry : mockRecurringJournalEntry({}, relationshipsToOmit),
+    };
+};
+
+export const mockRecurringJournalEntryOccurrence = (overrides?: Partial<RecurringJournalEntryOccurrence>, _relationshipsToOmit: Set<string> = new Set()): { __typename: 'RecurringJournalEntryOccurrence' } & RecurringJournalEntryOccurrence => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('RecurringJournalEntryOccurrence');
+    return {
+        __typename: 'RecurringJournalEntryOccurrence',
+        amount: overrides && overrides.hasOwnProperty('amount') ? overrides.amount! : faker.lorem.word(),
+        date: overrides && overrides.hasOwnProperty('date') ? overrides.date! : faker.date.past({ years: 1, refDate: new Date(2022, 0) }).toISOString(),
+        skipped: overrides && overrides.hasOwnProperty('skipped') ? overrides.skipped! : faker.datatype.boolean(),
+    };
+};
+
+export const mockRecurringJournalEntryPauseInput = (overrides?: Partial<RecurringJournalEntryPauseInput>, _relationshipsToOmit: Set<string> = new Set()): RecurringJournalEntryPauseInput => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('RecurringJournalEntryPauseInput');
+    return {
+        recurringJournalEntryId: overrides && overrides.hasOwnProperty('recurringJournalEntryId') ? overrides.recurringJournalEntryId! : generateMockValue.uuid(),
+    };
+};
+
+export const mockRecurringJournalEntryPausePayload = (overrides?: Partial<RecurringJournalEntryPausePayload>, _relationshipsToOmit: Set<string> = new Set()): { __typename: 'RecurringJournalEntryPausePayload' } & RecurringJournalEntryPausePayload => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('RecurringJournalEntryPausePayload');
+    return {
+        __typename: 'RecurringJournalEntryPausePayload',
+        recurringJournalEntry: overrides && overrides.hasOwnProperty('recurringJournalEntry') ? overrides.recurringJournalEntry! : relationshipsToOmit.has('RecurringJournalEntry') ? {} as RecurringJournalEntry : mockRecurringJournalEntry({}, relationshipsToOmit),
+    };
+};
+
+export const mockRecurringJournalEntryResumeInput = (overrides?: Partial<RecurringJournalEntryResumeInput>, _relationshipsToOmit: Set<string> = new Set()): RecurringJournalEntryResumeInput => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('RecurringJournalEntryResumeInput');
+    return {
+        recurringJournalEntryId: overrides && overrides.hasOwnProperty('recurringJournalEntryId') ? overrides.recurringJournalEntryId! : generateMockValue.uuid(),
+    };
+};
+
+export const mockRecurringJournalEntryResumePayload = (overrides?: Partial<RecurringJournalEntryResumePayload>, _relationshipsToOmit: Set<string> = new Set()): { __typename: 'RecurringJournalEntryResumePayload' } & RecurringJournalEntryResumePayload => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('RecurringJournalEntryResumePayload');
+    return {
+        __typename: 'RecurringJournalEntryResumePayload',
+        recurringJournalEntry: overrides && overrides.hasOwnProperty('recurringJournalEntry') ? overrides.recurringJournalEntry! : relationshipsToOmit.has('RecurringJournalEntry') ? {} as RecurringJournalEntry : mockRecurringJournalEntry({}, relationshipsToOmit),
+    };
+};
+
+export const mockRecurringJournalEntryScheduledAmount = (overrides?: Partial<RecurringJournalEntryScheduledAmount>, _relationshipsToOmit: Set<string> = new Set()): { __typename: 'RecurringJournalEntryScheduledAmount' } & RecurringJournalEntryScheduledAmount => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('RecurringJournalEntryScheduledAmount');
+    return {
+        __typename: 'RecurringJournalEntryScheduledAmount',
+        amount: overrides && overrides.hasOwnProperty('amount') ? overrides.amount! : faker.lorem.word(),
+        startingOn: overrides && overrides.hasOwnProperty('startingOn') ? overrides.startingOn! : faker.date.past({ years: 1, refDate: new Date(2022, 0) }).toISOString(),
+    };
+};
+
+export const mockRecurringJournalEntryScheduledAmountInput = (overrides?: Partial<RecurringJournalEntryScheduledAmountInput>, _relationshipsToOmit: Set<string> = new Set()): RecurringJournalEntryScheduledAmountInput => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('RecurringJournalEntryScheduledAmountInput');
+    return {
+        amount: overrides && overrides.hasOwnProperty('amount') ? overrides.amount! : faker.lorem.word(),
+        startingOn: overrides && overrides.hasOwnProperty('startingOn') ? overrides.startingOn! : faker.date.past({ years: 1, refDate: new Date(2022, 0) }).toISOString(),
+    };
+};
+
+export const mockRecurringJournalEntrySkipOccurrenceInput = (overrides?: Partial<RecurringJournalEntrySkipOccurrenceInput>, _relationshipsToOmit: Set<string> = new Set()): RecurringJournalEntrySkipOccurrenceInput => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('RecurringJournalEntrySkipOccurrenceInput');
+    return {
+        date: overrides && overrides.hasOwnProperty('date') ? overrides.date! : faker.date.past({ years: 1, refDate: new Date(2022, 0) }).toISOString(),
+        recurringJournalEntryId: overrides && overrides.hasOwnProperty('recurringJournalEntryId') ? overrides.recurringJournalEntryId! : generateMockValue.uuid(),
+    };
+};
+
+export const mockRecurringJournalEntrySkipOccurrencePayload = (overrides?: Partial<RecurringJournalEntrySkipOccurrencePayload>, _relationshipsToOmit: Set<string> = new Set()): { __typename: 'RecurringJournalEntrySkipOccurrencePayload' } & RecurringJournalEntrySkipOccurrencePayload => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('RecurringJournalEntrySkipOccurrencePayload');
+    return {
+        __typename: 'RecurringJournalEntrySkipOccurrencePayload',
+        recurringJournalEntry: overrides && overrides.hasOwnProperty('recurringJournalEntry') ? overrides.recurringJournalEntry! : relationshipsToOmit.has('RecurringJournalEntry') ? {} as RecurringJournalEntry : mockRecurringJournalEntry({}, relationshipsToOmit),
+    };
+};
+
 export const mockReport = (overrides?: Partial<Report>, _relationshipsToOmit: Set<string> = new Set()): { __typename: 'Report' } & Report => {
     const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
     relationshipsToOmit.add('Report');
@@ -3109,6 +3425,7 @@ export const mockTermsTemplate = (overrides?: Partial<TermsTemplate>, _relations
     return {
         __typename: 'TermsTemplate',
         createdAt: overrides && overrides.hasOwnProperty('createdAt') ? overrides.createdAt! : generateMockValue.timestamp(),
+        customerSelectable: overrides && overrides.hasOwnProperty('customerSelectable') ? overrides.customerSelectable! : faker.datatype.boolean(),
         id: overrides && overrides.hasOwnProperty('id') ? overrides.id! : faker.string.uuid(),
         name: overrides && overrides.hasOwnProperty('name') ? overrides.name! : generateMockValue.name(),
         termsId: overrides && overrides.hasOwnProperty('termsId') ? overrides.termsId! : generateMockValue.uuid(),
@@ -3146,6 +3463,24 @@ export const mockTermsTemplateCreatePayload = (overrides?: Partial<TermsTemplate
     };
 };
 
+export const mockTermsTemplateCustomerSelectabilityUpdateInput = (overrides?: Partial<TermsTemplateCustomerSelectabilityUpdateInput>, _relationshipsToOmit: Set<string> = new Set()): TermsTemplateCustomerSelectabilityUpdateInput => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('TermsTemplateCustomerSelectabilityUpdateInput');
+    return {
+        customerSelectable: overrides && overrides.hasOwnProperty('customerSelectable') ? overrides.customerSelectable! : faker.datatype.boolean(),
+        id: overrides && overrides.hasOwnProperty('id') ? overrides.id! : generateMockValue.uuid(),
+    };
+};
+
+export const mockTermsTemplateCustomerSelectabilityUpdatePayload = (overrides?: Partial<TermsTemplateCustomerSelectabilityUpdatePayload>, _relationshipsToOmit: Set<string> = new Set()): { __typename: 'TermsTemplateCustomerSelectabilityUpdatePayload' } & TermsTemplateCustomerSelectabilityUpdatePayload => {
+    const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
+    relationshipsToOmit.add('TermsTemplateCustomerSelectabilityUpdatePayload');
+    return {
+        __typename: 'TermsTemplateCustomerSelectabilityUpdatePayload',
+        termsTemplate: overrides && overrides.hasOwnProperty('termsTemplate') ? overrides.termsTemplate! : relationshipsToOmit.has('TermsTemplate') ? {} as TermsTemplate : mockTermsTemplate({}, relationshipsToOmit),
+    };
+};
+
 export const mockTermsTemplateUpdateInput = (overrides?: Partial<TermsTemplateUpdateInput>, _relationshipsToOmit: Set<string> = new Set()): TermsTemplateUpdateInput => {
     const relationshipsToOmit: Set<string> = new Set(_relationshipsToOmit);
     relationshipsToOmit.add('TermsTemplateUpdateInput');
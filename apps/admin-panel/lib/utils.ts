@@ -102,8 +102,20 @@ export const formatProcessType = (processType: ApprovalProcessType) => {
       return "Credit Facility Proposal"
     case ApprovalProcessType.WithdrawalApproval:
       return "Withdrawal"
+    case ApprovalProcessType.WithdrawalOverLimitApproval:
+      return "Withdrawal (Over Limit)"
+    case ApprovalProcessType.TransferApproval:
+      return "Transfer"
+    case ApprovalProcessType.ManualTransactionApproval:
+      return "Manual Transaction"
+    case ApprovalProcessType.ManualTransactionReversalApproval:
+      return "Manual Transaction Reversal"
     case ApprovalProcessType.DisbursalApproval:
       return "Disbursal"
+    case ApprovalProcessType.CreditFacilityAmendmentApproval:
+      return "Credit Facility Amendment"
+    case ApprovalProcessType.CreditFacilityWriteOffApproval:
+      return "Credit Facility Write-Off"
   }
 }
 
@@ -37,6 +37,15 @@ export const CustomerTransactionsTable: React.FC<CustomerTransactionsTableProps>
         "PaymentEntry",
         "FreezeEntry",
         "UnfreezeEntry",
+        "TransferOutEntry",
+        "TransferInEntry",
+        "CancelledTransferEntry",
+        "InterestCapitalizedEntry",
+        "EarlyBreakPenaltyEntry",
+        "EscheatmentEntry",
+        "HoldPlacedEntry",
+        "HoldReleasedEntry",
+        "HoldCapturedEntry",
       ].includes(entry.__typename),
   )
 
@@ -67,6 +76,22 @@ export const CustomerTransactionsTable: React.FC<CustomerTransactionsTableProps>
             return "Freeze"
           case "UnfreezeEntry":
             return "Unfreeze"
+          case "TransferOutEntry":
+          case "TransferInEntry":
+          case "CancelledTransferEntry":
+            return "Transfer"
+          case "InterestCapitalizedEntry":
+            return "Interest"
+          case "EarlyBreakPenaltyEntry":
+            return "Early Break Penalty"
+          case "EscheatmentEntry":
+            return "Unclaimed Funds Transfer"
+          case "HoldPlacedEntry":
+            return "Hold"
+          case "HoldReleasedEntry":
+            return "Hold Released"
+          case "HoldCapturedEntry":
+            return "Hold Captured"
           default:
             return "-"
         }
@@ -88,6 +113,15 @@ export const CustomerTransactionsTable: React.FC<CustomerTransactionsTableProps>
             return <Balance amount={entry.payment.amount} currency="usd" />
           case "FreezeEntry":
           case "UnfreezeEntry":
+          case "TransferOutEntry":
+          case "TransferInEntry":
+          case "CancelledTransferEntry":
+          case "InterestCapitalizedEntry":
+          case "EarlyBreakPenaltyEntry":
+          case "EscheatmentEntry":
+          case "HoldPlacedEntry":
+          case "HoldReleasedEntry":
+          case "HoldCapturedEntry":
             return <Balance amount={entry.amount} currency="usd" />
           default:
             return "-"
@@ -27,6 +27,31 @@ export type Scalars = {
   UsdCents: { input: any; output: any; }
 };
 
+export type AccountStatement = {
+  __typename?: 'AccountStatement';
+  accountStatementId: Scalars['UUID']['output'];
+  createdAt: Scalars['Timestamp']['output'];
+  depositAccountId: Scalars['UUID']['output'];
+  downloadLink: Scalars['String']['output'];
+  id: Scalars['ID']['output'];
+  periodEnd: Scalars['Date']['output'];
+  periodStart: Scalars['Date']['output'];
+  status: AccountStatementStatus;
+};
+
+export enum AccountStatementStatus {
+  Completed = 'COMPLETED',
+  Failed = 'FAILED',
+  Pending = 'PENDING'
+}
+
+export type CancelledTransferEntry = {
+  __typename?: 'CancelledTransferEntry';
+  amount: Scalars['UsdCents']['output'];
+  recordedAt: Scalars['Timestamp']['output'];
+  txId: Scalars['UUID']['output'];
+};
+
 export type CancelledWithdrawalEntry = {
   __typename?: 'CancelledWithdrawalEntry';
   recordedAt: Scalars['Timestamp']['output'];
@@ -242,6 +267,7 @@ export type DepositAccount = {
   deposits: Array<Deposit>;
   history: DepositAccountHistoryEntryConnection;
   id: Scalars['ID']['output'];
+  statements: Array<AccountStatement>;
   withdrawals: Array<Withdrawal>;
 };
 
@@ -253,11 +279,13 @@ export type DepositAccountHistoryArgs = {
 
 export type DepositAccountBalance = {
   __typename?: 'DepositAccountBalance';
+  available: Scalars['UsdCents']['output'];
+  held: Scalars['UsdCents']['output'];
   pending: Scalars['UsdCents']['output'];
   settled: Scalars['UsdCents']['output'];
 };
 
-export type DepositAccountHistoryEntry = CancelledWithdrawalEntry | DepositEntry | DisbursalEntry | FreezeEntry | PaymentEntry | UnfreezeEntry | UnknownEntry | WithdrawalEntry;
+export type DepositAccountHistoryEntry = CancelledTransferEntry | CancelledWithdrawalEntry | DepositEntry | DisbursalEntry | EarlyBreakPenaltyEntry | EscheatmentEntry | FreezeEntry | HoldCapturedEntry | HoldPlacedEntry | HoldReleasedEntry | InterestCapitalizedEntry | PaymentEntry | TransferInEntry | TransferOutEntry | UnfreezeEntry | UnknownEntry | WithdrawalEntry;
 
 export type DepositAccountHistoryEntryConnection = {
   __typename?: 'DepositAccountHistoryEntryConnection';
@@ -315,6 +343,20 @@ export type Duration = {
   units: Scalars['Int']['output'];
 };
 
+export type EarlyBreakPenaltyEntry = {
+  __typename?: 'EarlyBreakPenaltyEntry';
+  amount: Scalars['UsdCents']['output'];
+  recordedAt: Scalars['Timestamp']['output'];
+  txId: Scalars['UUID']['output'];
+};
+
+export type EscheatmentEntry = {
+  __typename?: 'EscheatmentEntry';
+  amount: Scalars['UsdCents']['output'];
+  recordedAt: Scalars['Timestamp']['output'];
+  txId: Scalars['UUID']['output'];
+};
+
 export type FacilityRemaining = {
   __typename?: 'FacilityRemaining';
   usdBalance: Scalars['UsdCents']['output'];
@@ -327,6 +369,27 @@ export type FreezeEntry = {
   txId: Scalars['UUID']['output'];
 };
 
+export type HoldCapturedEntry = {
+  __typename?: 'HoldCapturedEntry';
+  amount: Scalars['UsdCents']['output'];
+  recordedAt: Scalars['Timestamp']['output'];
+  txId: Scalars['UUID']['output'];
+};
+
+export type HoldPlacedEntry = {
+  __typename?: 'HoldPlacedEntry';
+  amount: Scalars['UsdCents']['output'];
+  recordedAt: Scalars['Timestamp']['output'];
+  txId: Scalars['UUID']['output'];
+};
+
+export type HoldReleasedEntry = {
+  __typename?: 'HoldReleasedEntry';
+  amount: Scalars['UsdCents']['output'];
+  recordedAt: Scalars['Timestamp']['output'];
+  txId: Scalars['UUID']['output'];
+};
+
 export type Interest = {
   __typename?: 'Interest';
   dueOutstanding: Outstanding;
@@ -334,9 +397,19 @@ export type Interest = {
   total: Total;
 };
 
+export type InterestCapitalizedEntry = {
+  __typename?: 'InterestCapitalizedEntry';
+  amount: Scalars['UsdCents']['output'];
+  recordedAt: Scalars['Timestamp']['output'];
+  txId: Scalars['UUID']['output'];
+};
+
 export enum InterestInterval {
   EndOfDay = 'END_OF_DAY',
-  EndOfMonth = 'END_OF_MONTH'
+  EndOfMonth = 'END_OF_MONTH',
+  EndOfQuarter = 'END_OF_QUARTER',
+  EndOfWeek = 'END_OF_WEEK',
+  MonthlyAnniversary = 'MONTHLY_ANNIVERSARY'
 }
 
 export enum KycLevel {
@@ -442,6 +515,20 @@ export type Total = {
   usdBalance: Scalars['UsdCents']['output'];
 };
 
+export type TransferInEntry = {
+  __typename?: 'TransferInEntry';
+  amount: Scalars['UsdCents']['output'];
+  recordedAt: Scalars['Timestamp']['output'];
+  txId: Scalars['UUID']['output'];
+};
+
+export type TransferOutEntry = {
+  __typename?: 'TransferOutEntry';
+  amount: Scalars['UsdCents']['output'];
+  recordedAt: Scalars['Timestamp']['output'];
+  txId: Scalars['UUID']['output'];
+};
+
 export type UnfreezeEntry = {
   __typename?: 'UnfreezeEntry';
   amount: Scalars['UsdCents']['output'];
@@ -515,11 +602,20 @@ export type GetTransactionHistoryQueryVariables = Exact<{
 
 
 export type GetTransactionHistoryQuery = { __typename?: 'Query', me: { __typename?: 'Me', customer: { __typename?: 'Customer', depositAccount: { __typename?: 'DepositAccount', history: { __typename?: 'DepositAccountHistoryEntryConnection', pageInfo: { __typename?: 'PageInfo', hasNextPage: boolean, endCursor?: string | null, hasPreviousPage: boolean, startCursor?: string | null }, edges: Array<{ __typename?: 'DepositAccountHistoryEntryEdge', cursor: string, node:
+              | { __typename?: 'CancelledTransferEntry', recordedAt: any, amount: any }
               | { __typename?: 'CancelledWithdrawalEntry', recordedAt: any, withdrawal: { __typename?: 'Withdrawal', id: string, withdrawalId: any, accountId: any, amount: any, createdAt: any, reference: string, status: WithdrawalStatus } }
               | { __typename?: 'DepositEntry', recordedAt: any, deposit: { __typename?: 'Deposit', id: string, depositId: any, accountId: any, amount: any, createdAt: any, reference: string } }
               | { __typename?: 'DisbursalEntry', recordedAt: any, disbursal: { __typename?: 'CreditFacilityDisbursal', id: string, disbursalId: any, amount: any, createdAt: any, status: DisbursalStatus } }
+              | { __typename?: 'EarlyBreakPenaltyEntry', recordedAt: any, amount: any }
+              | { __typename?: 'EscheatmentEntry', recordedAt: any, amount: any }
               | { __typename?: 'FreezeEntry', recordedAt: any, amount: any }
+              | { __typename?: 'HoldCapturedEntry', recordedAt: any, amount: any }
+              | { __typename?: 'HoldPlacedEntry', recordedAt: any, amount: any }
+              | { __typename?: 'HoldReleasedEntry', recordedAt: any, amount: any }
+              | { __typename?: 'InterestCapitalizedEntry', recordedAt: any, amount: any }
               | { __typename?: 'PaymentEntry', recordedAt: any, payment: { __typename?: 'CreditFacilityPaymentAllocation', id: string, paymentAllocationId: any, amount: any, createdAt: any } }
+              | { __typename?: 'TransferInEntry', recordedAt: any, amount: any }
+              | { __typename?: 'TransferOutEntry', recordedAt: any, amount: any }
               | { __typename?: 'UnfreezeEntry', recordedAt: any, amount: any }
               | { __typename?: 'UnknownEntry' }
               | { __typename?: 'WithdrawalEntry', recordedAt: any, withdrawal: { __typename?: 'Withdrawal', id: string, withdrawalId: any, accountId: any, amount: any, createdAt: any, reference: string, status: WithdrawalStatus } }
@@ -920,6 +1016,42 @@ export const GetTransactionHistoryDocument = gql`
                 recordedAt
                 amount
               }
+              ... on TransferOutEntry {
+                recordedAt
+                amount
+              }
+              ... on TransferInEntry {
+                recordedAt
+                amount
+              }
+              ... on CancelledTransferEntry {
+                recordedAt
+                amount
+              }
+              ... on InterestCapitalizedEntry {
+                recordedAt
+                amount
+              }
+              ... on EarlyBreakPenaltyEntry {
+                recordedAt
+                amount
+              }
+              ... on EscheatmentEntry {
+                recordedAt
+                amount
+              }
+              ... on HoldPlacedEntry {
+                recordedAt
+                amount
+              }
+              ... on HoldReleasedEntry {
+                recordedAt
+                amount
+              }
+              ... on HoldCapturedEntry {
+                recordedAt
+                amount
+              }
             }
           }
         }
@@ -85,6 +85,42 @@ gql`
                   recordedAt
                   amount
                 }
+                ... on TransferOutEntry {
+                  recordedAt
+                  amount
+                }
+                ... on TransferInEntry {
+                  recordedAt
+                  amount
+                }
+                ... on CancelledTransferEntry {
+                  recordedAt
+                  amount
+                }
+                ... on InterestCapitalizedEntry {
+                  recordedAt
+                  amount
+                }
+                ... on EarlyBreakPenaltyEntry {
+                  recordedAt
+                  amount
+                }
+                ... on EscheatmentEntry {
+                  recordedAt
+                  amount
+                }
+                ... on HoldPlacedEntry {
+                  recordedAt
+                  amount
+                }
+                ... on HoldReleasedEntry {
+                  recordedAt
+                  amount
+                }
+                ... on HoldCapturedEntry {
+                  recordedAt
+                  amount
+                }
               }
             }
           }
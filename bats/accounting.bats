@@ -15,6 +15,30 @@ teardown_file() {
   cp "$LOG_FILE" "$PERSISTED_LOG_FILE"
 }
 
+wait_for_manual_transaction_posted() {
+  variables=$(
+    jq -n \
+      --arg id "$1" \
+    '{ id: $id }'
+  )
+  exec_admin_graphql 'find-manual-transaction' "$variables"
+  echo "manual transaction | $(graphql_output)" >> $RUN_LOG_FILE
+  status=$(graphql_output '.data.manualTransaction.status')
+  [[ "$status" == "POSTED" ]] || return 1
+}
+
+wait_for_manual_transaction_reversed() {
+  variables=$(
+    jq -n \
+      --arg id "$1" \
+    '{ id: $id }'
+  )
+  exec_admin_graphql 'find-manual-transaction' "$variables"
+  echo "manual transaction | $(graphql_output)" >> $RUN_LOG_FILE
+  status=$(graphql_output '.data.manualTransaction.status')
+  [[ "$status" == "REVERSED" ]] || return 1
+}
+
 @test "accounting: imported CSV file from seed into chart of accounts" {
   exec_admin_graphql 'chart-of-accounts'
   chart_id=$(graphql_output '.data.chartOfAccounts.chartId')
@@ -126,6 +150,9 @@ teardown_file() {
 
   retained_earnings_loss_code=$(graphql_output "${config}.equityRetainedEarningsLossCode")
   [[ "$retained_earnings_loss_code" == "32.02" ]] || exit 1
+
+  cash_code=$(graphql_output "[${config}.cashFlowMapping[] | select(.category == \"CASH\") | .code][0]")
+  [[ "$cash_code" == "11.01" ]] || exit 1
 }
 
 @test "accounting: can query descendant account sets by category" {
@@ -265,6 +292,15 @@ teardown_file() {
   )
 
   exec_admin_graphql 'manual-transaction-execute' "$variables"
+  manual_transaction_id=$(graphql_output .data.manualTransactionExecute.manualTransaction.manualTransactionId)
+  [[ "$manual_transaction_id" != "null" ]] || exit 1
+  status=$(graphql_output .data.manualTransactionExecute.manualTransaction.status)
+  [[ "$status" == "PENDING_APPROVAL" ]] || exit 1
+  process_type=$(graphql_output .data.manualTransactionExecute.manualTransaction.approvalProcess.approvalProcessType)
+  [[ "$process_type" == "MANUAL_TRANSACTION_APPROVAL" ]] || exit 1
+
+  retry 20 1 wait_for_manual_transaction_posted $manual_transaction_id
+  cache_value 'manual_transaction_id' "$manual_transaction_id"
 
   exec_admin_graphql 'ledger-account-by-code' '{"code":"11.01.0101"}'
   txId1=$(graphql_output .data.ledgerAccountByCode.history.nodes[0].txId)
@@ -289,6 +325,129 @@ teardown_file() {
   [[ "$entryType1" != "$entryType2" ]] || exit 1
 }
 
+@test "accounting: can reverse manual transaction" {
+  manual_transaction_id=$(read_value 'manual_transaction_id')
+
+  variables=$(
+    jq -n \
+    --arg id "$manual_transaction_id" \
+    '{
+      input: {
+        manualTransactionId: $id,
+        effective: "2025-01-02"
+      }
+    }'
+  )
+  exec_admin_graphql 'manual-transaction-reverse' "$variables"
+  status=$(graphql_output .data.manualTransactionReverse.manualTransaction.status)
+  [[ "$status" == "REVERSAL_PENDING_APPROVAL" ]] || exit 1
+  process_type=$(graphql_output .data.manualTransactionReverse.manualTransaction.reversalApprovalProcess.approvalProcessType)
+  [[ "$process_type" == "MANUAL_TRANSACTION_REVERSAL_APPROVAL" ]] || exit 1
+
+  retry 20 1 wait_for_manual_transaction_reversed $manual_transaction_id
+  reversal_effective=$(graphql_output .data.manualTransaction.reversalEffective)
+  [[ "$reversal_effective" == "2025-01-02" ]] || exit 1
+  reversal_tx_id=$(graphql_output .data.manualTransaction.reversalLedgerTransaction.ledgerTransactionId)
+  [[ "$reversal_tx_id" != "null" ]] || exit 1
+
+  exec_admin_graphql 'manual-transaction-reverse' "$variables"
+  reversal_tx_id_again=$(graphql_output .data.manualTransactionReverse.manualTransaction.reversalLedgerTransaction.ledgerTransactionId)
+  [[ "$reversal_tx_id" == "$reversal_tx_id_again" ]] || exit 1
+}
+
+@test "accounting: can create and manage recurring journal entry" {
+  reference="rent-$RANDOM$RANDOM"
+  variables=$(
+    jq -n \
+    --arg reference "$reference" \
+    '{
+      input: {
+        reference: $reference,
+        description: "Monthly rent accrual",
+        debitAccountRef: "61.01",
+        creditAccountRef: "11.01.0101",
+        currency: "USD",
+        amount: "100",
+        amountSchedule: [{ startingOn: "2099-03-01", amount: "150" }],
+        frequency: "MONTHLY",
+        startDate: "2099-01-31",
+        endDate: "2099-12-31"
+      }
+    }'
+  )
+  exec_admin_graphql 'recurring-journal-entry-create' "$variables"
+  recurring_journal_entry_id=$(graphql_output .data.recurringJournalEntryCreate.recurringJournalEntry.recurringJournalEntryId)
+  [[ "$recurring_journal_entry_id" != "null" ]] || exit 1
+  status=$(graphql_output .data.recurringJournalEntryCreate.recurringJournalEntry.status)
+  [[ "$status" == "ACTIVE" ]] || exit 1
+  second_date=$(graphql_output '.data.recurringJournalEntryCreate.recurringJournalEntry.upcomingOccurrences[1].date')
+  [[ "$second_date" == "2099-02-28" ]] || exit 1
+  third_amount=$(graphql_output '.data.recurringJournalEntryCreate.recurringJournalEntry.upcomingOccurrences[2].amount')
+  [[ "$third_amount" == "150" ]] || exit 1
+
+  variables=$(
+    jq -n \
+    --arg id "$recurring_journal_entry_id" \
+    '{ input: { recurringJournalEntryId: $id, date: "2099-02-28" } }'
+  )
+  exec_admin_graphql 'recurring-journal-entry-skip-occurrence' "$variables"
+  skipped=$(graphql_output '.data.recurringJournalEntrySkipOccurrence.recurringJournalEntry.upcomingOccurrences[1].skipped')
+  [[ "$skipped" == "true" ]] || exit 1
+
+  variables=$(
+    jq -n \
+    --arg id "$recurring_journal_entry_id" \
+    '{ input: { recurringJournalEntryId: $id } }'
+  )
+  exec_admin_graphql 'recurring-journal-entry-pause' "$variables"
+  status=$(graphql_output .data.recurringJournalEntryPause.recurringJournalEntry.status)
+  [[ "$status" == "PAUSED" ]] || exit 1
+
+  exec_admin_graphql 'recurring-journal-entry-resume' "$variables"
+  status=$(graphql_output .data.recurringJournalEntryResume.recurringJournalEntry.status)
+  [[ "$status" == "ACTIVE" ]] || exit 1
+
+  variables=$(
+    jq -n \
+    --arg id "$recurring_journal_entry_id" \
+    '{ id: $id }'
+  )
+  exec_admin_graphql 'find-recurring-journal-entry' "$variables"
+  found_reference=$(graphql_output .data.recurringJournalEntry.reference)
+  [[ "$found_reference" == "$reference" ]] || exit 1
+
+  exec_admin_graphql 'recurring-journal-entries' '{"first": 10}'
+  listed_id=$(graphql_output '.data.recurringJournalEntries.nodes[0].recurringJournalEntryId')
+  [[ "$listed_id" == "$recurring_journal_entry_id" ]] || exit 1
+}
+
+@test "accounting: can query cash flow statement" {
+  variables=$(
+    jq -n \
+    --arg from "$(date -u +%Y-01-01)" \
+    '{ from: $from }'
+  )
+  exec_admin_graphql 'cash-flow-statement' "$variables"
+  opening_cash=$(graphql_output '.data.cashFlowStatement.openingCash')
+  closing_cash=$(graphql_output '.data.cashFlowStatement.closingCash')
+  net_change=$(graphql_output '.data.cashFlowStatement.netChangeInCash')
+  unreconciled=$(graphql_output '.data.cashFlowStatement.unreconciled')
+  [[ "$opening_cash" != "null" ]] || exit 1
+  [[ $(( closing_cash - opening_cash - net_change )) -eq "$unreconciled" ]] || exit 1
+
+  operating_lines=$(graphql_output '.data.cashFlowStatement.operating.lines | length')
+  [[ "$operating_lines" -gt 0 ]] || exit 1
+
+  variables=$(
+    jq -n \
+    --arg from "$(date -u +%Y-01-01)" \
+    '{ input: { from: $from } }'
+  )
+  exec_admin_graphql 'cash-flow-statement-csv-create' "$variables"
+  filename=$(graphql_output '.data.cashFlowStatementCsvCreate.accountingCsvDocument.filename')
+  [[ "$filename" == cash-flow-* ]] || exit 1
+}
+
 @test "accounting: can not execute transaction before system inception date" {
   exec_admin_graphql 'fiscal-years' '{"first": 1}'
   graphql_output
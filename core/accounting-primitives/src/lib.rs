@@ -313,6 +313,12 @@ pub enum AccountingBaseConfigError {
     AccountCodeNotTopLevel(String),
     #[error("AccountingBaseConfigError - RetainedEarningsCodeNotChildOfEquity: {0}")]
     RetainedEarningsCodeNotChildOfEquity(String),
+    #[error("AccountingBaseConfigError - CashFlowMappingCodeNotOnBalanceSheet: {0}")]
+    CashFlowMappingCodeNotOnBalanceSheet(String),
+    #[error("AccountingBaseConfigError - CashFlowMappingCashCodeNotAsset: {0}")]
+    CashFlowMappingCashCodeNotAsset(String),
+    #[error("AccountingBaseConfigError - CashFlowMappingOverlappingCodes: {0} and {1}")]
+    CashFlowMappingOverlappingCodes(String, String),
 }
 
 impl ErrorSeverity for AccountingBaseConfigError {
@@ -321,6 +327,9 @@ impl ErrorSeverity for AccountingBaseConfigError {
             Self::DuplicateAccountCode(_) => Level::ERROR,
             Self::AccountCodeNotTopLevel(_) => Level::ERROR,
             Self::RetainedEarningsCodeNotChildOfEquity(_) => Level::ERROR,
+            Self::CashFlowMappingCodeNotOnBalanceSheet(_) => Level::ERROR,
+            Self::CashFlowMappingCashCodeNotAsset(_) => Level::ERROR,
+            Self::CashFlowMappingOverlappingCodes(_, _) => Level::ERROR,
         }
     }
 }
@@ -336,6 +345,8 @@ pub struct AccountingBaseConfig {
     pub revenue_code: AccountCode,
     pub cost_of_revenue_code: AccountCode,
     pub expenses_code: AccountCode,
+    #[serde(default)]
+    pub cash_flow_mapping: Vec<CashFlowMapping>,
 }
 
 impl AccountingBaseConfig {
@@ -358,10 +369,20 @@ impl AccountingBaseConfig {
             revenue_code,
             cost_of_revenue_code,
             expenses_code,
+            cash_flow_mapping: Vec::new(),
         };
         config.validate()?;
         Ok(config)
     }
+
+    pub fn with_cash_flow_mapping(
+        mut self,
+        cash_flow_mapping: Vec<CashFlowMapping>,
+    ) -> Result<Self, AccountingBaseConfigError> {
+        self.cash_flow_mapping = cash_flow_mapping;
+        self.validate()?;
+        Ok(self)
+    }
     fn validate(&self) -> Result<(), AccountingBaseConfigError> {
         let codes = [
             &self.assets_code,
@@ -397,9 +418,61 @@ impl AccountingBaseConfig {
                 ),
             );
         }
+
+        self.validate_cash_flow_mapping()
+    }
+
+    fn validate_cash_flow_mapping(&self) -> Result<(), AccountingBaseConfigError> {
+        for mapping in &self.cash_flow_mapping {
+            let code = &mapping.code;
+            if mapping.category == CashFlowCategory::Cash {
+                if !self.is_assets_account_set_or_account(code) {
+                    return Err(AccountingBaseConfigError::CashFlowMappingCashCodeNotAsset(
+                        code.to_string(),
+                    ));
+                }
+            } else if !self.is_assets_account_set_or_account(code)
+                && !self.is_liabilities_account_set_or_account(code)
+                && !self.is_equity_account_set_or_account(code)
+            {
+                return Err(
+                    AccountingBaseConfigError::CashFlowMappingCodeNotOnBalanceSheet(
+                        code.to_string(),
+                    ),
+                );
+            }
+        }
+
+        for (idx, mapping) in self.cash_flow_mapping.iter().enumerate() {
+            for other in &self.cash_flow_mapping[idx + 1..] {
+                if mapping.code == other.code
+                    || mapping.code.is_parent_of(&other.code.sections)
+                    || other.code.is_parent_of(&mapping.code.sections)
+                {
+                    return Err(AccountingBaseConfigError::CashFlowMappingOverlappingCodes(
+                        mapping.code.to_string(),
+                        other.code.to_string(),
+                    ));
+                }
+            }
+        }
+
         Ok(())
     }
 
+    /// Whether both configs designate the same account codes, regardless of
+    /// their cash flow mapping.
+    pub fn has_same_account_codes(&self, other: &Self) -> bool {
+        self.assets_code == other.assets_code
+            && self.liabilities_code == other.liabilities_code
+            && self.equity_code == other.equity_code
+            && self.equity_retained_earnings_gain_code == other.equity_retained_earnings_gain_code
+            && self.equity_retained_earnings_loss_code == other.equity_retained_earnings_loss_code
+            && self.revenue_code == other.revenue_code
+            && self.cost_of_revenue_code == other.cost_of_revenue_code
+            && self.expenses_code == other.expenses_code
+    }
+
     pub fn is_off_balance_sheet_account_set_or_account(&self, code: &AccountCode) -> bool {
         let on_balance_sheet = [
             &self.assets_code,
@@ -466,6 +539,37 @@ impl AccountingBaseConfig {
     }
 }
 
+/// Section of the cash flow statement a balance sheet account set is reported in.
+/// `Cash` marks the account sets whose balances make up cash and cash equivalents.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CashFlowCategory {
+    Cash,
+    Operating,
+    Investing,
+    Financing,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct CashFlowMapping {
+    pub code: AccountCode,
+    pub category: CashFlowCategory,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display, strum::EnumString)]
 pub enum AccountCategory {
     OffBalanceSheet,
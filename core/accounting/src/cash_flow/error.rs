@@ -0,0 +1,26 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+#[derive(Error, Debug)]
+pub enum CashFlowStatementError {
+    #[error("CashFlowStatementError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("CashFlowStatementError - CashFlowLedgerError: {0}")]
+    CashFlowLedgerError(#[from] super::ledger::error::CashFlowLedgerError),
+    #[error("CashFlowStatementError - ChartOfAccountsError: {0}")]
+    ChartOfAccountsError(#[from] crate::chart_of_accounts::error::ChartOfAccountsError),
+    #[error("CashFlowStatementError - AccountingBaseConfigNotFound")]
+    AccountingBaseConfigNotFound,
+}
+
+impl ErrorSeverity for CashFlowStatementError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::AuthorizationError(e) => e.severity(),
+            Self::CashFlowLedgerError(e) => e.severity(),
+            Self::ChartOfAccountsError(e) => e.severity(),
+            Self::AccountingBaseConfigNotFound => Level::ERROR,
+        }
+    }
+}
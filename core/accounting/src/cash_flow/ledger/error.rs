@@ -0,0 +1,17 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+#[derive(Error, Debug)]
+pub enum CashFlowLedgerError {
+    #[error("CashFlowLedgerError - CalaBalance: {0}")]
+    CalaBalance(#[from] cala_ledger::balance::error::BalanceError),
+}
+
+impl ErrorSeverity for CashFlowLedgerError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::CalaBalance(_) => Level::ERROR,
+        }
+    }
+}
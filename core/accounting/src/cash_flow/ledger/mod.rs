@@ -0,0 +1,70 @@
+pub mod error;
+
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use tracing::instrument;
+
+use cala_ledger::{AccountSetId, CalaLedger, Currency, JournalId, balance::AccountBalance};
+use rust_decimal::Decimal;
+use tracing_macros::record_error_severity;
+
+use super::NetBalances;
+
+use error::*;
+
+#[derive(Clone)]
+pub struct CashFlowLedger {
+    cala: CalaLedger,
+    journal_id: JournalId,
+}
+
+impl CashFlowLedger {
+    pub fn new(cala: &CalaLedger, journal_id: JournalId) -> Self {
+        Self {
+            cala: cala.clone(),
+            journal_id,
+        }
+    }
+
+    /// Opening and closing settled USD balances of the account sets, netted as
+    /// debits minus credits. Account sets without any activity are omitted.
+    #[record_error_severity]
+    #[instrument(name = "cash_flow_ledger.get_net_balances", skip(self, account_set_ids), fields(count = account_set_ids.len(), from = %from, until = ?until))]
+    pub async fn get_net_balances(
+        &self,
+        account_set_ids: &[AccountSetId],
+        from: NaiveDate,
+        until: Option<NaiveDate>,
+    ) -> Result<HashMap<AccountSetId, NetBalances>, CashFlowLedgerError> {
+        let balance_ids = account_set_ids
+            .iter()
+            .map(|id| (self.journal_id, (*id).into(), Currency::USD))
+            .collect::<Vec<_>>();
+        let mut res = self
+            .cala
+            .balances()
+            .effective()
+            .find_all_in_range(&balance_ids, from, until)
+            .await?;
+
+        Ok(account_set_ids
+            .iter()
+            .filter_map(|id| {
+                res.remove(&(self.journal_id, (*id).into(), Currency::USD))
+                    .map(|range| {
+                        (
+                            *id,
+                            NetBalances {
+                                opening: settled_net(&range.open),
+                                closing: settled_net(&range.close),
+                            },
+                        )
+                    })
+            })
+            .collect())
+    }
+}
+
+fn settled_net(balance: &AccountBalance) -> Decimal {
+    balance.details.settled.dr_balance - balance.details.settled.cr_balance
+}
@@ -0,0 +1,348 @@
+pub mod error;
+pub mod ledger;
+
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use cala_ledger::CalaLedger;
+use tracing_macros::record_error_severity;
+
+use crate::{
+    chart_of_accounts::ChartOfAccounts,
+    primitives::{
+        AccountCode, AccountInfo, CalaAccountSetId, CashFlowCategory, ChartId,
+        CoreAccountingAction, CoreAccountingObject, LedgerAccountId,
+    },
+};
+
+use error::*;
+use ledger::*;
+
+#[derive(Clone)]
+pub struct CashFlowStatements<Perms>
+where
+    Perms: PermissionCheck,
+{
+    authz: Perms,
+    chart_of_accounts: ChartOfAccounts<Perms>,
+    cash_flow_ledger: CashFlowLedger,
+}
+
+impl<Perms> CashFlowStatements<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    pub fn new(
+        authz: &Perms,
+        cala: &CalaLedger,
+        journal_id: cala_ledger::JournalId,
+        chart_of_accounts: &ChartOfAccounts<Perms>,
+    ) -> Self {
+        Self {
+            authz: authz.clone(),
+            chart_of_accounts: chart_of_accounts.clone(),
+            cash_flow_ledger: CashFlowLedger::new(cala, journal_id),
+        }
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "core_accounting.cash_flow.cash_flow_statement", skip(self))]
+    pub async fn cash_flow_statement(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        from: NaiveDate,
+        until: Option<NaiveDate>,
+    ) -> Result<CashFlowStatement, CashFlowStatementError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_cash_flow_statements(),
+                CoreAccountingAction::CASH_FLOW_STATEMENT_READ,
+            )
+            .await?;
+
+        self.find_cash_flow_statement(chart_ref, from, until).await
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "core_accounting.cash_flow.find_cash_flow_statement",
+        skip(self)
+    )]
+    pub(crate) async fn find_cash_flow_statement(
+        &self,
+        chart_ref: &str,
+        from: NaiveDate,
+        until: Option<NaiveDate>,
+    ) -> Result<CashFlowStatement, CashFlowStatementError> {
+        let chart = self.chart_of_accounts.find_by_reference(chart_ref).await?;
+        let resolved = chart
+            .resolve_accounting_base_config()
+            .ok_or(CashFlowStatementError::AccountingBaseConfigNotFound)?;
+        let mapped = chart
+            .cash_flow_account_sets()
+            .ok_or(CashFlowStatementError::AccountingBaseConfigNotFound)?;
+
+        let net_income_account_set_ids = [
+            resolved.revenue,
+            resolved.cost_of_revenue,
+            resolved.expenses,
+        ];
+        let account_set_ids = net_income_account_set_ids
+            .iter()
+            .copied()
+            .chain(mapped.iter().map(|(_, info)| info.account_set_id))
+            .collect::<Vec<_>>();
+
+        let balances = self
+            .cash_flow_ledger
+            .get_net_balances(&account_set_ids, from, until)
+            .await?;
+
+        Ok(CashFlowStatement::build(
+            chart.id,
+            from,
+            until,
+            &net_income_account_set_ids,
+            mapped,
+            &balances,
+        ))
+    }
+}
+
+/// Opening and closing settled balance of an account set, netted as debits minus credits.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NetBalances {
+    pub opening: Decimal,
+    pub closing: Decimal,
+}
+
+impl NetBalances {
+    fn change(&self) -> Decimal {
+        self.closing - self.opening
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CashFlowLine {
+    pub ledger_account_id: LedgerAccountId,
+    pub code: AccountCode,
+    pub name: String,
+    pub amount: Decimal,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CashFlowSection {
+    pub lines: Vec<CashFlowLine>,
+    pub total: Decimal,
+}
+
+impl CashFlowSection {
+    fn push(&mut self, line: CashFlowLine) {
+        self.total += line.amount;
+        self.lines.push(line);
+    }
+}
+
+/// Cash flow statement in USD prepared with the indirect method: operating
+/// activities start from net income and are adjusted by the change in the
+/// balances mapped to the operating section.
+#[derive(Debug, Clone)]
+pub struct CashFlowStatement {
+    pub chart_id: ChartId,
+    pub from: NaiveDate,
+    pub until: Option<NaiveDate>,
+    pub net_income: Decimal,
+    pub operating: CashFlowSection,
+    pub investing: CashFlowSection,
+    pub financing: CashFlowSection,
+    pub opening_cash: Decimal,
+    pub closing_cash: Decimal,
+}
+
+impl CashFlowStatement {
+    fn build(
+        chart_id: ChartId,
+        from: NaiveDate,
+        until: Option<NaiveDate>,
+        net_income_account_set_ids: &[CalaAccountSetId],
+        mapped: Vec<(CashFlowCategory, AccountInfo)>,
+        balances: &HashMap<CalaAccountSetId, NetBalances>,
+    ) -> Self {
+        let balances_for = |id: &CalaAccountSetId| balances.get(id).copied().unwrap_or_default();
+
+        let net_income = -net_income_account_set_ids
+            .iter()
+            .map(|id| balances_for(id).change())
+            .sum::<Decimal>();
+
+        let mut statement = Self {
+            chart_id,
+            from,
+            until,
+            net_income,
+            operating: CashFlowSection {
+                lines: vec![],
+                total: net_income,
+            },
+            investing: CashFlowSection::default(),
+            financing: CashFlowSection::default(),
+            opening_cash: Decimal::ZERO,
+            closing_cash: Decimal::ZERO,
+        };
+
+        for (category, info) in mapped {
+            let balances = balances_for(&info.account_set_id);
+            let line = CashFlowLine {
+                ledger_account_id: info.account_set_id.into(),
+                code: info.code,
+                name: info.name.to_string(),
+                amount: -balances.change(),
+            };
+            match category {
+                CashFlowCategory::Cash => {
+                    statement.opening_cash += balances.opening;
+                    statement.closing_cash += balances.closing;
+                }
+                CashFlowCategory::Operating => statement.operating.push(line),
+                CashFlowCategory::Investing => statement.investing.push(line),
+                CashFlowCategory::Financing => statement.financing.push(line),
+            }
+        }
+
+        statement
+    }
+
+    pub fn net_change_in_cash(&self) -> Decimal {
+        self.operating.total + self.investing.total + self.financing.total
+    }
+
+    /// Difference between the change in cash balances and the net change in
+    /// cash explained by the sections. Non zero when balance sheet accounts
+    /// with activity in the period are missing from the cash flow mapping.
+    pub fn unreconciled(&self) -> Decimal {
+        self.closing_cash - self.opening_cash - self.net_change_in_cash()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn info(code: &str) -> AccountInfo {
+        AccountInfo {
+            account_set_id: CalaAccountSetId::new(),
+            code: code.parse().unwrap(),
+            name: format!("Account {code}").parse().unwrap(),
+        }
+    }
+
+    fn balances(opening: Decimal, closing: Decimal) -> NetBalances {
+        NetBalances { opening, closing }
+    }
+
+    #[test]
+    fn operating_section_starts_from_net_income() {
+        let revenue = CalaAccountSetId::new();
+        let expenses = CalaAccountSetId::new();
+        let cash = info("11.01");
+        let receivables = info("11.02");
+        let payables = info("21.01");
+
+        let balances = HashMap::from([
+            (revenue, balances(dec!(0), dec!(-1000))),
+            (expenses, balances(dec!(0), dec!(400))),
+            (cash.account_set_id, balances(dec!(500), dec!(1000))),
+            (receivables.account_set_id, balances(dec!(100), dec!(300))),
+            (payables.account_set_id, balances(dec!(-50), dec!(-150))),
+        ]);
+
+        let statement = CashFlowStatement::build(
+            ChartId::new(),
+            "2021-01-01".parse().unwrap(),
+            None,
+            &[revenue, expenses],
+            vec![
+                (CashFlowCategory::Cash, cash),
+                (CashFlowCategory::Operating, receivables),
+                (CashFlowCategory::Operating, payables),
+            ],
+            &balances,
+        );
+
+        assert_eq!(statement.net_income, dec!(600));
+        assert_eq!(statement.operating.lines.len(), 2);
+        assert_eq!(statement.operating.lines[0].amount, dec!(-200));
+        assert_eq!(statement.operating.lines[1].amount, dec!(100));
+        assert_eq!(statement.operating.total, dec!(500));
+        assert_eq!(statement.opening_cash, dec!(500));
+        assert_eq!(statement.closing_cash, dec!(1000));
+        assert_eq!(statement.net_change_in_cash(), dec!(500));
+        assert_eq!(statement.unreconciled(), Decimal::ZERO);
+    }
+
+    #[test]
+    fn investing_and_financing_sections() {
+        let cash = info("11.01");
+        let equipment = info("12");
+        let loans = info("22");
+
+        let balances = HashMap::from([
+            (cash.account_set_id, balances(dec!(1000), dec!(1300))),
+            (equipment.account_set_id, balances(dec!(0), dec!(200))),
+            (loans.account_set_id, balances(dec!(0), dec!(-500))),
+        ]);
+
+        let statement = CashFlowStatement::build(
+            ChartId::new(),
+            "2021-01-01".parse().unwrap(),
+            None,
+            &[],
+            vec![
+                (CashFlowCategory::Cash, cash),
+                (CashFlowCategory::Investing, equipment),
+                (CashFlowCategory::Financing, loans),
+            ],
+            &balances,
+        );
+
+        assert_eq!(statement.operating.total, Decimal::ZERO);
+        assert_eq!(statement.investing.total, dec!(-200));
+        assert_eq!(statement.financing.total, dec!(500));
+        assert_eq!(statement.net_change_in_cash(), dec!(300));
+        assert_eq!(statement.unreconciled(), Decimal::ZERO);
+    }
+
+    #[test]
+    fn unmapped_activity_is_unreconciled() {
+        let cash = info("11.01");
+        let other = info("11.02");
+
+        let balances = HashMap::from([
+            (cash.account_set_id, balances(dec!(0), dec!(100))),
+            (other.account_set_id, balances(dec!(0), dec!(-100))),
+        ]);
+
+        let statement = CashFlowStatement::build(
+            ChartId::new(),
+            "2021-01-01".parse().unwrap(),
+            None,
+            &[],
+            vec![(CashFlowCategory::Cash, cash)],
+            &balances,
+        );
+
+        assert_eq!(statement.net_change_in_cash(), Decimal::ZERO);
+        assert_eq!(statement.unreconciled(), dec!(100));
+    }
+}
@@ -31,6 +31,9 @@ pub enum ChartEvent {
     BaseConfigSet {
         base_config: AccountingBaseConfig,
     },
+    CashFlowMappingUpdated {
+        cash_flow_mapping: Vec<CashFlowMapping>,
+    },
     ClosedAsOf {
         closed_as_of: NaiveDate,
     },
@@ -144,7 +147,8 @@ impl Chart {
     ) -> Result<Idempotent<BulkImportResult>, ChartOfAccountsError> {
         idempotency_guard!(
             self.events.iter_all().rev(),
-            ChartEvent::BaseConfigSet { base_config: existing, .. } if &base_config == existing,
+            ChartEvent::BaseConfigSet { base_config: existing, .. }
+                if base_config.has_same_account_codes(existing),
         );
         if self.base_config.is_some() {
             return Err(ChartOfAccountsError::BaseConfigAlreadyInitializedWithDifferentConfig);
@@ -161,6 +165,30 @@ impl Chart {
         Ok(Idempotent::Executed(res))
     }
 
+    /// Replaces the cash flow mapping of an already configured chart.
+    pub(super) fn update_cash_flow_mapping(
+        &mut self,
+        cash_flow_mapping: Vec<CashFlowMapping>,
+    ) -> Result<Idempotent<()>, ChartOfAccountsError> {
+        let base_config = self
+            .base_config
+            .as_ref()
+            .ok_or(ChartOfAccountsError::BaseConfigNotInitialized)?;
+        if base_config.cash_flow_mapping == cash_flow_mapping {
+            return Ok(Idempotent::AlreadyApplied);
+        }
+
+        let base_config = base_config
+            .clone()
+            .with_cash_flow_mapping(cash_flow_mapping.clone())?;
+        self.check_base_config_codes_exists_in_chart(&base_config)?;
+
+        self.events
+            .push(ChartEvent::CashFlowMappingUpdated { cash_flow_mapping });
+        self.base_config = Some(base_config);
+        Ok(Idempotent::Executed(()))
+    }
+
     pub(super) fn import_accounts(
         &mut self,
         account_specs: Vec<AccountSpec>,
@@ -405,6 +433,30 @@ impl Chart {
             .collect()
     }
 
+    /// Account sets mapped to a cash flow statement category in the base config,
+    /// in the order they were configured.
+    pub fn cash_flow_account_sets(&self) -> Option<Vec<(CashFlowCategory, AccountInfo)>> {
+        let base_config = self.base_config.as_ref()?;
+
+        Some(
+            base_config
+                .cash_flow_mapping
+                .iter()
+                .filter_map(|mapping| {
+                    let details = self.find_node_details_by_code(&mapping.code)?;
+                    Some((
+                        mapping.category,
+                        AccountInfo {
+                            account_set_id: details.account_set_id,
+                            code: details.spec.code,
+                            name: details.spec.name,
+                        },
+                    ))
+                })
+                .collect(),
+        )
+    }
+
     pub fn resolve_accounting_base_config(&self) -> Option<ResolvedAccountingBaseConfig> {
         let config = self.base_config.clone()?;
 
@@ -463,6 +515,11 @@ impl Chart {
                 )
             })?;
 
+        for mapping in &base_config.cash_flow_mapping {
+            self.find_node_details_by_code(&mapping.code)
+                .ok_or_else(|| ChartOfAccountsError::CodeNotFoundInChart(mapping.code.clone()))?;
+        }
+
         Ok(())
     }
 
@@ -534,6 +591,7 @@ impl Chart {
 impl TryFromEvents<ChartEvent> for Chart {
     fn try_from_events(events: EntityEvents<ChartEvent>) -> Result<Self, EsEntityError> {
         let mut builder = ChartBuilder::default();
+        let mut base_config: Option<AccountingBaseConfig> = None;
 
         for event in events.iter_all() {
             match event {
@@ -550,15 +608,22 @@ impl TryFromEvents<ChartEvent> for Chart {
                         .reference(reference.to_string())
                         .name(name.to_string());
                 }
-                ChartEvent::BaseConfigSet { base_config } => {
-                    builder = builder.base_config(Some(base_config.clone()));
+                ChartEvent::BaseConfigSet {
+                    base_config: config,
+                } => {
+                    base_config = Some(config.clone());
+                }
+                ChartEvent::CashFlowMappingUpdated { cash_flow_mapping } => {
+                    if let Some(config) = base_config.as_mut() {
+                        config.cash_flow_mapping = cash_flow_mapping.clone();
+                    }
                 }
                 ChartEvent::ClosedAsOf { .. } => {}
                 ChartEvent::ClosingTransactionPosted { .. } => {}
             }
         }
 
-        builder.events(events).build()
+        builder.base_config(base_config).events(events).build()
     }
 }
 
@@ -1128,6 +1193,117 @@ mod test {
         }
     }
 
+    mod update_cash_flow_mapping {
+        use super::*;
+
+        fn configured_chart() -> Chart {
+            let mut chart = chart_from(initial_events());
+            assert!(
+                chart
+                    .configure_with_initial_accounts(
+                        account_specs_for_base_config(),
+                        base_config(),
+                        CalaJournalId::new(),
+                    )
+                    .unwrap()
+                    .did_execute()
+            );
+            hydrate_chart_of_accounts(&mut chart);
+            chart
+        }
+
+        fn mapping() -> Vec<CashFlowMapping> {
+            vec![
+                CashFlowMapping {
+                    code: code("1"),
+                    category: CashFlowCategory::Cash,
+                },
+                CashFlowMapping {
+                    code: code("2"),
+                    category: CashFlowCategory::Financing,
+                },
+            ]
+        }
+
+        #[test]
+        fn errors_when_base_config_not_set() {
+            let mut chart = chart_from(initial_events());
+            let res = chart.update_cash_flow_mapping(mapping());
+            assert!(matches!(
+                res,
+                Err(ChartOfAccountsError::BaseConfigNotInitialized)
+            ));
+        }
+
+        #[test]
+        fn updates_mapping_idempotently() {
+            let mut chart = configured_chart();
+
+            assert!(
+                chart
+                    .update_cash_flow_mapping(mapping())
+                    .unwrap()
+                    .did_execute()
+            );
+            assert!(
+                chart
+                    .update_cash_flow_mapping(mapping())
+                    .unwrap()
+                    .was_already_applied()
+            );
+
+            let account_sets = chart.cash_flow_account_sets().unwrap();
+            assert_eq!(account_sets.len(), 2);
+            assert_eq!(account_sets[0].0, CashFlowCategory::Cash);
+            assert_eq!(account_sets[0].1.code, code("1"));
+            assert_eq!(account_sets[1].0, CashFlowCategory::Financing);
+        }
+
+        #[test]
+        fn mapping_survives_rehydration() {
+            let mut chart = configured_chart();
+            assert!(
+                chart
+                    .update_cash_flow_mapping(mapping())
+                    .unwrap()
+                    .did_execute()
+            );
+
+            let events = chart.events.iter_all().cloned().collect::<Vec<_>>();
+            let chart = chart_from(events);
+
+            assert_eq!(chart.base_config.unwrap().cash_flow_mapping, mapping());
+        }
+
+        #[test]
+        fn errors_when_code_not_in_chart() {
+            let mut chart = configured_chart();
+            let res = chart.update_cash_flow_mapping(vec![CashFlowMapping {
+                code: code("1.9"),
+                category: CashFlowCategory::Operating,
+            }]);
+            assert!(matches!(
+                res,
+                Err(ChartOfAccountsError::CodeNotFoundInChart(_))
+            ));
+        }
+
+        #[test]
+        fn reconfiguring_with_only_a_different_mapping_is_already_applied() {
+            let mut chart = configured_chart();
+            let config = base_config().with_cash_flow_mapping(mapping()).unwrap();
+
+            let res = chart
+                .configure_with_initial_accounts(
+                    account_specs_for_base_config(),
+                    config,
+                    CalaJournalId::new(),
+                )
+                .unwrap();
+            assert!(res.was_already_applied());
+        }
+    }
+
     mod find_account_set_id_in_category {
         use super::*;
 
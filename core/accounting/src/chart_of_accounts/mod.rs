@@ -19,7 +19,7 @@ use cala_ledger::{CalaLedger, account::Account};
 
 use crate::primitives::{
     AccountCategory, AccountCode, AccountIdOrCode, AccountInfo, AccountName, AccountSpec,
-    AccountingBaseConfig, CalaAccountSetId, CalaJournalId, ChartId, ClockHandle,
+    AccountingBaseConfig, CalaAccountSetId, CalaJournalId, CashFlowMapping, ChartId, ClockHandle,
     ClosingAccountCodes, ClosingTxDetails, CoreAccountingAction, CoreAccountingObject,
     LedgerAccountId,
 };
@@ -262,6 +262,36 @@ where
         Ok(base_config)
     }
 
+    #[record_error_severity]
+    #[instrument(
+        name = "core_accounting.chart_of_accounts.update_cash_flow_mapping",
+        skip(self, cash_flow_mapping)
+    )]
+    pub async fn update_cash_flow_mapping(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        cash_flow_mapping: Vec<CashFlowMapping>,
+    ) -> Result<Chart, ChartOfAccountsError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_charts(),
+                CoreAccountingAction::CHART_UPDATE,
+            )
+            .await?;
+
+        let mut chart = self.find_by_reference(chart_ref).await?;
+        if chart
+            .update_cash_flow_mapping(cash_flow_mapping)?
+            .did_execute()
+        {
+            self.repo.update(&mut chart).await?;
+        }
+
+        Ok(chart)
+    }
+
     #[record_error_severity]
     #[instrument(name = "core_accounting.chart_of_accounts.add_root_node", skip(self,))]
     pub async fn add_root_node(
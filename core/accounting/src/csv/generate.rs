@@ -1,3 +1,4 @@
+use chrono::NaiveDate;
 use csv::Writer;
 use rust_decimal::Decimal;
 
@@ -6,8 +7,8 @@ use authz::PermissionCheck;
 use cala_ledger::DebitOrCredit;
 
 use crate::{
-    CoreAccountingAction, CoreAccountingObject, ledger_account::LedgerAccounts,
-    primitives::LedgerAccountId,
+    CoreAccountingAction, CoreAccountingObject, cash_flow::CashFlowStatements,
+    ledger_account::LedgerAccounts, primitives::LedgerAccountId,
 };
 
 use super::error::AccountingCsvExportError;
@@ -19,6 +20,7 @@ where
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
 {
     ledger_accounts: LedgerAccounts<Perms>,
+    cash_flow_statements: CashFlowStatements<Perms>,
 }
 
 impl<Perms> GenerateCsvExport<Perms>
@@ -27,9 +29,13 @@ where
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
 {
-    pub fn new(ledger_accounts: &LedgerAccounts<Perms>) -> Self {
+    pub fn new(
+        ledger_accounts: &LedgerAccounts<Perms>,
+        cash_flow_statements: &CashFlowStatements<Perms>,
+    ) -> Self {
         Self {
             ledger_accounts: ledger_accounts.clone(),
+            cash_flow_statements: cash_flow_statements.clone(),
         }
     }
 
@@ -79,4 +85,76 @@ where
 
         Ok(csv_data)
     }
+
+    pub async fn generate_cash_flow_csv(
+        &self,
+        chart_ref: &str,
+        from: NaiveDate,
+        until: Option<NaiveDate>,
+    ) -> Result<Vec<u8>, AccountingCsvExportError> {
+        let statement = self
+            .cash_flow_statements
+            .find_cash_flow_statement(chart_ref, from, until)
+            .await?;
+
+        let mut wtr = Writer::from_writer(vec![]);
+        wtr.write_record(["Section", "Code", "Account", "Amount (USD)"])
+            .map_err(|e| AccountingCsvExportError::CsvError(e.to_string()))?;
+
+        write_cash_flow_row(
+            &mut wtr,
+            "Operating",
+            "",
+            "Net Income",
+            statement.net_income,
+        )?;
+        for (name, section) in [
+            ("Operating", &statement.operating),
+            ("Investing", &statement.investing),
+            ("Financing", &statement.financing),
+        ] {
+            for line in &section.lines {
+                write_cash_flow_row(
+                    &mut wtr,
+                    name,
+                    &line.code.to_string(),
+                    &line.name,
+                    line.amount,
+                )?;
+            }
+            write_cash_flow_row(
+                &mut wtr,
+                name,
+                "",
+                &format!("Net Cash From {name} Activities"),
+                section.total,
+            )?;
+        }
+        write_cash_flow_row(
+            &mut wtr,
+            "",
+            "",
+            "Net Change In Cash",
+            statement.net_change_in_cash(),
+        )?;
+        write_cash_flow_row(&mut wtr, "", "", "Opening Cash", statement.opening_cash)?;
+        write_cash_flow_row(&mut wtr, "", "", "Closing Cash", statement.closing_cash)?;
+
+        let csv_data = wtr
+            .into_inner()
+            .map_err(|e| AccountingCsvExportError::CsvError(e.to_string()))?;
+
+        Ok(csv_data)
+    }
+}
+
+fn write_cash_flow_row(
+    wtr: &mut Writer<Vec<u8>>,
+    section: &str,
+    code: &str,
+    name: &str,
+    amount: Decimal,
+) -> Result<(), AccountingCsvExportError> {
+    wtr.write_record([section, code, name, &amount.to_string()])
+        .map_err(|e| AccountingCsvExportError::CsvError(e.to_string()))
 }
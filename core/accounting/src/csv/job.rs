@@ -3,6 +3,7 @@ use async_trait::async_trait;
 use authz::PermissionCheck;
 
 use audit::AuditSvc;
+use chrono::NaiveDate;
 use document_storage::{DocumentId, DocumentStorage};
 use job::*;
 use obix::out::{Outbox, OutboxEventMarker};
@@ -10,15 +11,30 @@ use serde::{Deserialize, Serialize};
 
 use crate::event::CoreAccountingEvent;
 use crate::primitives::AccountingCsvId;
-use crate::{ledger_account::LedgerAccounts, primitives::LedgerAccountId};
+use crate::{
+    cash_flow::CashFlowStatements, ledger_account::LedgerAccounts, primitives::LedgerAccountId,
+};
 
 use super::publisher::AccountingCsvPublisher;
-use super::{CoreAccountingAction, CoreAccountingObject, generate::GenerateCsvExport};
+use super::{
+    AccountingCsvType, CoreAccountingAction, CoreAccountingObject, error::AccountingCsvExportError,
+    generate::GenerateCsvExport,
+};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CsvStatementPeriod {
+    pub chart_ref: String,
+    pub from: NaiveDate,
+    pub until: Option<NaiveDate>,
+}
 
 #[derive(Serialize, Deserialize)]
 pub struct GenerateAccountingCsvConfig<Perms, E> {
     pub document_id: DocumentId,
-    pub ledger_account_id: LedgerAccountId,
+    #[serde(default)]
+    pub csv_type: AccountingCsvType,
+    pub ledger_account_id: Option<LedgerAccountId>,
+    pub statement_period: Option<CsvStatementPeriod>,
     pub _phantom: std::marker::PhantomData<(Perms, E)>,
 }
 
@@ -26,7 +42,9 @@ impl<Perms, E> Clone for GenerateAccountingCsvConfig<Perms, E> {
     fn clone(&self) -> Self {
         Self {
             document_id: self.document_id,
+            csv_type: self.csv_type,
             ledger_account_id: self.ledger_account_id,
+            statement_period: self.statement_period.clone(),
             _phantom: std::marker::PhantomData,
         }
     }
@@ -39,6 +57,7 @@ where
 {
     document_storage: DocumentStorage,
     ledger_accounts: LedgerAccounts<Perms>,
+    cash_flow_statements: CashFlowStatements<Perms>,
     publisher: AccountingCsvPublisher<E>,
 }
 
@@ -50,11 +69,13 @@ where
     pub fn new(
         document_storage: &DocumentStorage,
         ledger_accounts: &LedgerAccounts<Perms>,
+        cash_flow_statements: &CashFlowStatements<Perms>,
         outbox: &Outbox<E>,
     ) -> Self {
         Self {
             document_storage: document_storage.clone(),
             ledger_accounts: ledger_accounts.clone(),
+            cash_flow_statements: cash_flow_statements.clone(),
             publisher: AccountingCsvPublisher::new(outbox),
         }
     }
@@ -82,7 +103,7 @@ where
         Ok(Box::new(GenerateAccountingCsvExportJobRunner {
             config: job.config()?,
             document_storage: self.document_storage.clone(),
-            generator: GenerateCsvExport::new(&self.ledger_accounts),
+            generator: GenerateCsvExport::new(&self.ledger_accounts, &self.cash_flow_statements),
             publisher: self.publisher.clone(),
         }))
     }
@@ -113,10 +134,25 @@ where
         &self,
         current_job: CurrentJob,
     ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
-        let csv_result = self
-            .generator
-            .generate_ledger_account_csv(self.config.ledger_account_id)
-            .await?;
+        let csv_result = match (
+            self.config.csv_type,
+            self.config.ledger_account_id,
+            &self.config.statement_period,
+        ) {
+            (AccountingCsvType::LedgerAccount, Some(ledger_account_id), _) => {
+                self.generator
+                    .generate_ledger_account_csv(ledger_account_id)
+                    .await?
+            }
+            (AccountingCsvType::CashFlow, _, Some(period)) => {
+                self.generator
+                    .generate_cash_flow_csv(&period.chart_ref, period.from, period.until)
+                    .await?
+            }
+            (csv_type, _, _) => {
+                return Err(AccountingCsvExportError::UnsupportedCsvType(csv_type).into());
+            }
+        };
 
         let document_id = self.config.document_id;
         let mut document = self.document_storage.find_by_id(document_id).await?;
@@ -126,10 +162,12 @@ where
             .upload_in_op(&mut op, csv_result, &mut document)
             .await?;
 
-        let csv_id = AccountingCsvId::from(uuid::Uuid::from(document.id));
-        self.publisher
-            .publish_csv_export_uploaded_in_op(&mut op, csv_id, self.config.ledger_account_id)
-            .await?;
+        if let Some(ledger_account_id) = self.config.ledger_account_id {
+            let csv_id = AccountingCsvId::from(uuid::Uuid::from(document.id));
+            self.publisher
+                .publish_csv_export_uploaded_in_op(&mut op, csv_id, ledger_account_id)
+                .await?;
+        }
         op.commit().await?;
 
         Ok(JobCompletion::Complete)
@@ -9,6 +9,7 @@ use tracing::instrument;
 use ::job::JobId;
 use audit::AuditSvc;
 use authz::PermissionCheck;
+use chrono::NaiveDate;
 use document_storage::{
     Document, DocumentId, DocumentStorage, DocumentType, DocumentsByCreatedAtCursor,
     GeneratedDocumentDownloadLink, ReferenceId,
@@ -16,7 +17,9 @@ use document_storage::{
 use obix::out::{Outbox, OutboxEventMarker};
 use tracing_macros::record_error_severity;
 
-use crate::{Jobs, event::CoreAccountingEvent};
+use crate::{
+    Jobs, cash_flow::CashFlowStatements, chart_of_accounts::Chart, event::CoreAccountingEvent,
+};
 
 use super::{
     CoreAccountingAction, CoreAccountingObject, ledger_account::LedgerAccounts,
@@ -24,13 +27,15 @@ use super::{
 };
 
 use self::job::{
-    GenerateAccountingCsvConfig, GenerateAccountingCsvInit, GenerateAccountingCsvJobSpawner,
+    CsvStatementPeriod, GenerateAccountingCsvConfig, GenerateAccountingCsvInit,
+    GenerateAccountingCsvJobSpawner,
 };
 use error::*;
 use es_entity::PaginatedQueryArgs;
 pub use primitives::*;
 
 pub const LEDGER_ACCOUNT_CSV: DocumentType = DocumentType::new("ledger_account_csv");
+pub const CASH_FLOW_CSV: DocumentType = DocumentType::new("cash_flow_csv");
 
 pub struct AccountingCsvExports<Perms, E>
 where
@@ -68,11 +73,16 @@ where
         jobs: &mut Jobs,
         document_storage: DocumentStorage,
         ledger_accounts: &LedgerAccounts<Perms>,
+        cash_flow_statements: &CashFlowStatements<Perms>,
         outbox: &Outbox<E>,
     ) -> Self {
-        let generate_accounting_csv_job_spawner = jobs.add_initializer(
-            GenerateAccountingCsvInit::new(&document_storage, ledger_accounts, outbox),
-        );
+        let generate_accounting_csv_job_spawner =
+            jobs.add_initializer(GenerateAccountingCsvInit::new(
+                &document_storage,
+                ledger_accounts,
+                cash_flow_statements,
+                outbox,
+            ));
 
         Self {
             authz: authz.clone(),
@@ -116,7 +126,64 @@ where
                 JobId::from(uuid::Uuid::from(document.id)),
                 GenerateAccountingCsvConfig {
                     document_id: document.id,
-                    ledger_account_id,
+                    csv_type: AccountingCsvType::LedgerAccount,
+                    ledger_account_id: Some(ledger_account_id),
+                    statement_period: None,
+                    _phantom: std::marker::PhantomData,
+                },
+            )
+            .await?;
+        db.commit().await?;
+        Ok(document)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "core_accounting.csv.create_cash_flow", skip(self, chart), fields(chart_id = %chart.id))]
+    pub async fn create_cash_flow_csv(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart: &Chart,
+        from: NaiveDate,
+        until: Option<NaiveDate>,
+    ) -> Result<Document, AccountingCsvExportError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_accounting_csvs(),
+                CoreAccountingAction::ACCOUNTING_CSV_CREATE,
+            )
+            .await?;
+
+        let filename = match until {
+            Some(until) => format!("cash-flow-{from}-{until}.csv"),
+            None => format!("cash-flow-{from}.csv"),
+        };
+
+        let mut db = self.document_storage.begin_op().await?;
+        let document = self
+            .document_storage
+            .create_in_op(
+                &mut db,
+                filename,
+                "text/csv",
+                ReferenceId::from(uuid::Uuid::from(chart.id)),
+                CASH_FLOW_CSV,
+            )
+            .await?;
+
+        self.generate_accounting_csv_job_spawner
+            .spawn_in_op(
+                &mut db,
+                JobId::from(uuid::Uuid::from(document.id)),
+                GenerateAccountingCsvConfig {
+                    document_id: document.id,
+                    csv_type: AccountingCsvType::CashFlow,
+                    ledger_account_id: None,
+                    statement_period: Some(CsvStatementPeriod {
+                        chart_ref: chart.reference.clone(),
+                        from,
+                        until,
+                    }),
                     _phantom: std::marker::PhantomData,
                 },
             )
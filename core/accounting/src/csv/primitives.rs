@@ -7,14 +7,24 @@ es_entity::entity_id! {
 }
 
 #[derive(
-    Debug, Clone, Serialize, Deserialize, PartialEq, strum::Display, strum::EnumString, Copy,
+    Debug,
+    Clone,
+    Serialize,
+    Deserialize,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    Copy,
+    Default,
 )]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum AccountingCsvType {
+    #[default]
     LedgerAccount,
     ProfitAndLoss,
     BalanceSheet,
+    CashFlow,
 }
 
 #[derive(Debug, Clone)]
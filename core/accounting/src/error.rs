@@ -26,6 +26,10 @@ pub enum CoreAccountingError {
     ProfitAndLossLedgerError(
         #[from] super::profit_and_loss::ledger::error::ProfitAndLossStatementLedgerError,
     ),
+    #[error("CoreAccountingError - GovernanceError: {0}")]
+    GovernanceError(#[from] governance::error::GovernanceError),
+    #[error("CoreAccountingError - RegisterEventHandler: {0}")]
+    RegisterEventHandler(#[from] Box<dyn std::error::Error + Send + Sync>),
 }
 
 impl ErrorSeverity for CoreAccountingError {
@@ -42,6 +46,8 @@ impl ErrorSeverity for CoreAccountingError {
             Self::Sqlx(_) => Level::ERROR,
             Self::BalanceSheetLedgerError(e) => e.severity(),
             Self::ProfitAndLossLedgerError(e) => e.severity(),
+            Self::GovernanceError(e) => e.severity(),
+            Self::RegisterEventHandler(_) => Level::ERROR,
         }
     }
 }
@@ -173,6 +173,32 @@ impl FiscalYear {
         Some(next_month)
     }
 
+    /// The first day of the year that has not been closed by a monthly
+    /// closing, or `None` once every month of the year is closed.
+    pub fn first_open_date(&self) -> Option<NaiveDate> {
+        if self.is_last_month_of_year_closed() {
+            return None;
+        }
+
+        let first_open_date = self
+            .events
+            .iter_all()
+            .rev()
+            .find_map(|event| match event {
+                FiscalYearEvent::MonthClosed {
+                    month_closed_as_of, ..
+                } => Some(
+                    month_closed_as_of
+                        .succ_opt()
+                        .expect("Failed to compute first open date"),
+                ),
+                _ => None,
+            })
+            .unwrap_or(self.opened_as_of);
+
+        Some(first_open_date)
+    }
+
     #[instrument(name = "fiscal_year.next", skip(self))]
     pub(super) fn next(&self, now: DateTime<Utc>) -> Option<NewFiscalYear> {
         let next_year_opened_as_of = self
@@ -404,6 +430,28 @@ mod test {
         assert!(second_closing.unwrap().was_already_applied());
     }
 
+    #[test]
+    fn first_open_date_follows_month_closures() {
+        let period_start = "2024-01-01".parse::<NaiveDate>().unwrap();
+        let mut fiscal_year = fiscal_year_from(initial_events_with_opened_date(period_start));
+        assert_eq!(fiscal_year.first_open_date(), Some(period_start));
+
+        let _ = fiscal_year.close_next_sequential_month(Utc::now()).unwrap();
+        assert_eq!(
+            fiscal_year.first_open_date(),
+            Some("2024-02-01".parse::<NaiveDate>().unwrap())
+        );
+    }
+
+    #[test]
+    fn first_open_date_is_none_when_all_months_closed() {
+        let period_start = "2024-12-01".parse::<NaiveDate>().unwrap();
+        let mut fiscal_year = fiscal_year_from(initial_events_with_opened_date(period_start));
+
+        let _ = fiscal_year.close_next_sequential_month(Utc::now()).unwrap();
+        assert_eq!(fiscal_year.first_open_date(), None);
+    }
+
     #[test]
     fn next_returns_none_when_year_is_invalid() {
         let now = Utc::now().date_naive();
@@ -285,6 +285,55 @@ where
         self.repo.find_all(ids).await
     }
 
+    /// The earliest date on which the chart still accepts postings, as
+    /// determined by the monthly closings of its fiscal years. Returns
+    /// `None` if no fiscal year has been opened for the chart.
+    #[record_error_severity]
+    #[instrument(
+        name = "core_accounting.fiscal_year.first_open_date_for_chart",
+        skip(self)
+    )]
+    pub(crate) async fn first_open_date_for_chart(
+        &self,
+        chart_id: ChartId,
+    ) -> Result<Option<NaiveDate>, FiscalYearError> {
+        let mut first_open_date: Option<NaiveDate> = None;
+        let mut latest_closes_as_of: Option<NaiveDate> = None;
+        let mut query = PaginatedQueryArgs::<FiscalYearsByCreatedAtCursor> {
+            first: 100,
+            after: None,
+        };
+        loop {
+            let result = self
+                .repo
+                .list_for_chart_id_by_created_at(
+                    chart_id,
+                    query,
+                    es_entity::ListDirection::Descending,
+                )
+                .await?;
+            for fiscal_year in result.entities.iter() {
+                if let Some(date) = fiscal_year.first_open_date() {
+                    first_open_date = Some(first_open_date.map_or(date, |d| d.min(date)));
+                }
+                let closes_as_of = fiscal_year.closes_as_of();
+                latest_closes_as_of =
+                    Some(latest_closes_as_of.map_or(closes_as_of, |d| d.max(closes_as_of)));
+            }
+            if !result.has_next_page {
+                break;
+            }
+            query = PaginatedQueryArgs {
+                first: 100,
+                after: result.end_cursor,
+            };
+        }
+
+        // Every month of every opened year is closed, so postings are only
+        // possible once the next fiscal year begins.
+        Ok(first_open_date.or_else(|| latest_closes_as_of.and_then(|d| d.succ_opt())))
+    }
+
     async fn find_latest_for_chart(
         &self,
         chart_id: ChartId,
@@ -2,6 +2,7 @@
 #![cfg_attr(feature = "fail-on-warnings", deny(clippy::all))]
 
 pub mod balance_sheet;
+pub mod cash_flow;
 pub mod chart_of_accounts;
 pub mod csv;
 pub mod error;
@@ -12,7 +13,9 @@ pub mod ledger_account;
 pub mod ledger_transaction;
 pub mod manual_transaction;
 mod primitives;
+mod processes;
 pub mod profit_and_loss;
+pub mod recurring_journal_entry;
 pub mod transaction_templates;
 pub mod trial_balance;
 
@@ -22,13 +25,18 @@ use audit::AuditSvc;
 use authz::PermissionCheck;
 use cala_ledger::CalaLedger;
 use document_storage::DocumentStorage;
+use governance::{Governance, GovernanceEvent};
 use job::Jobs;
-use manual_transaction::ManualTransactions;
-use obix::out::{Outbox, OutboxEventMarker};
+use manual_transaction::{ManualTransactions, repo::ManualTransactionRepo};
+use obix::out::{Outbox, OutboxEventJobConfig, OutboxEventMarker};
+use processes::manual_transaction_approval::{
+    ApproveManualTransaction, MANUAL_TRANSACTION_APPROVE_JOB, ManualTransactionApprovalHandler,
+};
 use tracing::instrument;
 use tracing_macros::record_error_severity;
 
 pub use balance_sheet::{BalanceSheet, BalanceSheets};
+pub use cash_flow::{CashFlowStatement, CashFlowStatements};
 pub use chart_of_accounts::{Chart, ChartOfAccounts, error as chart_of_accounts_error, tree};
 pub use csv::AccountingCsvExports;
 use error::CoreAccountingError;
@@ -39,10 +47,16 @@ pub use fiscal_year::{
 pub use journal::{Journal, error as journal_error};
 pub use ledger_account::{LedgerAccount, LedgerAccountChildrenCursor, LedgerAccounts};
 pub use ledger_transaction::{LedgerTransaction, LedgerTransactions};
-pub use manual_transaction::ManualEntryInput;
+pub use manual_transaction::{
+    ManualEntryInput, ManualTransaction, ManualTransactionStatus, ReversalEffective,
+};
 pub use primitives::AccountInfo;
 pub use primitives::*;
+pub use processes::manual_transaction_approval::{
+    APPROVE_MANUAL_TRANSACTION_PROCESS, APPROVE_MANUAL_TRANSACTION_REVERSAL_PROCESS,
+};
 pub use profit_and_loss::{ProfitAndLossStatement, ProfitAndLossStatements};
+pub use recurring_journal_entry::{RecurringJournalEntries, RecurringJournalEntry};
 pub use transaction_templates::TransactionTemplates;
 pub use trial_balance::{TrialBalanceRoot, TrialBalances};
 
@@ -52,12 +66,13 @@ pub mod event_schema {
     pub use crate::chart_of_accounts::chart_node::ChartNodeEvent;
     pub use crate::fiscal_year::FiscalYearEvent;
     pub use crate::manual_transaction::ManualTransactionEvent;
+    pub use crate::recurring_journal_entry::RecurringJournalEntryEvent;
 }
 
 pub struct CoreAccounting<Perms, E>
 where
     Perms: PermissionCheck,
-    E: OutboxEventMarker<CoreAccountingEvent>,
+    E: OutboxEventMarker<CoreAccountingEvent> + OutboxEventMarker<GovernanceEvent>,
 {
     clock: ClockHandle,
     authz: Perms,
@@ -65,10 +80,12 @@ where
     journal: Journal<Perms>,
     ledger_accounts: LedgerAccounts<Perms>,
     ledger_transactions: LedgerTransactions<Perms>,
-    manual_transactions: ManualTransactions<Perms>,
+    manual_transactions: ManualTransactions<Perms, E>,
+    recurring_journal_entries: RecurringJournalEntries<Perms, E>,
     profit_and_loss: ProfitAndLossStatements<Perms>,
     transaction_templates: TransactionTemplates<Perms>,
     balance_sheets: BalanceSheets<Perms>,
+    cash_flow_statements: CashFlowStatements<Perms>,
     csvs: AccountingCsvExports<Perms, E>,
     trial_balances: TrialBalances<Perms>,
     fiscal_year: FiscalYears<Perms>,
@@ -77,7 +94,7 @@ where
 impl<Perms, E> Clone for CoreAccounting<Perms, E>
 where
     Perms: PermissionCheck,
-    E: OutboxEventMarker<CoreAccountingEvent>,
+    E: OutboxEventMarker<CoreAccountingEvent> + OutboxEventMarker<GovernanceEvent>,
 {
     fn clone(&self) -> Self {
         Self {
@@ -87,10 +104,12 @@ where
             journal: self.journal.clone(),
             ledger_accounts: self.ledger_accounts.clone(),
             manual_transactions: self.manual_transactions.clone(),
+            recurring_journal_entries: self.recurring_journal_entries.clone(),
             ledger_transactions: self.ledger_transactions.clone(),
             profit_and_loss: self.profit_and_loss.clone(),
             transaction_templates: self.transaction_templates.clone(),
             balance_sheets: self.balance_sheets.clone(),
+            cash_flow_statements: self.cash_flow_statements.clone(),
             csvs: self.csvs.clone(),
             trial_balances: self.trial_balances.clone(),
             fiscal_year: self.fiscal_year.clone(),
@@ -101,11 +120,16 @@ where
 impl<Perms, E> CoreAccounting<Perms, E>
 where
     Perms: PermissionCheck,
-    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
-    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
-    E: OutboxEventMarker<CoreAccountingEvent>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreAccountingEvent> + OutboxEventMarker<GovernanceEvent>,
 {
-    pub fn new(
+    #[record_error_severity]
+    #[tracing::instrument(name = "core_accounting.init", skip_all, fields(journal_id = %journal_id))]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn init(
         pool: &sqlx::PgPool,
         authz: &Perms,
         cala: &CalaLedger,
@@ -113,28 +137,70 @@ where
         document_storage: DocumentStorage,
         jobs: &mut Jobs,
         outbox: &Outbox<E>,
-    ) -> Self {
+        governance: &Governance<Perms, E>,
+    ) -> Result<Self, CoreAccountingError> {
         let clock = jobs.clock().clone();
         let chart_of_accounts = ChartOfAccounts::new(pool, clock.clone(), authz, cala, journal_id);
         let fiscal_year = FiscalYears::new(pool, clock.clone(), authz, &chart_of_accounts);
         let journal = Journal::new(authz, cala, journal_id);
         let ledger_accounts = LedgerAccounts::new(authz, cala, journal_id);
+        let manual_transaction_repo = ManualTransactionRepo::new(pool, clock.clone());
         let manual_transactions = ManualTransactions::new(
-            pool,
+            &manual_transaction_repo,
             authz,
             &chart_of_accounts,
+            &fiscal_year,
+            governance,
             cala,
+            clock.clone(),
             journal_id,
+        );
+
+        let recurring_journal_entries = RecurringJournalEntries::new(
+            pool,
+            authz,
+            &chart_of_accounts,
+            &manual_transactions,
             clock.clone(),
         );
+
+        let approve_manual_transaction = ApproveManualTransaction::new(
+            &manual_transaction_repo,
+            authz.audit(),
+            governance,
+            cala,
+            journal_id,
+        );
+        outbox
+            .register_event_handler(
+                jobs,
+                OutboxEventJobConfig::new(MANUAL_TRANSACTION_APPROVE_JOB),
+                ManualTransactionApprovalHandler::new(&approve_manual_transaction),
+            )
+            .await?;
+        governance
+            .init_policy(APPROVE_MANUAL_TRANSACTION_PROCESS)
+            .await?;
+        governance
+            .init_policy(APPROVE_MANUAL_TRANSACTION_REVERSAL_PROCESS)
+            .await?;
+
         let ledger_transactions = LedgerTransactions::new(authz, cala);
         let profit_and_loss = ProfitAndLossStatements::new(pool, authz, cala, journal_id);
         let transaction_templates = TransactionTemplates::new(authz, cala);
         let balance_sheets = BalanceSheets::new(pool, authz, cala, journal_id);
-        let csvs =
-            AccountingCsvExports::new(authz, jobs, document_storage, &ledger_accounts, outbox);
+        let cash_flow_statements =
+            CashFlowStatements::new(authz, cala, journal_id, &chart_of_accounts);
+        let csvs = AccountingCsvExports::new(
+            authz,
+            jobs,
+            document_storage,
+            &ledger_accounts,
+            &cash_flow_statements,
+            outbox,
+        );
         let trial_balances = TrialBalances::new(pool, authz, cala, journal_id);
-        Self {
+        Ok(Self {
             clock,
             authz: authz.clone(),
             chart_of_accounts,
@@ -142,13 +208,15 @@ where
             ledger_accounts,
             ledger_transactions,
             manual_transactions,
+            recurring_journal_entries,
             profit_and_loss,
             transaction_templates,
             balance_sheets,
+            cash_flow_statements,
             csvs,
             trial_balances,
             fiscal_year,
-        }
+        })
     }
 
     pub fn chart_of_accounts(&self) -> &ChartOfAccounts<Perms> {
@@ -167,10 +235,14 @@ where
         &self.ledger_transactions
     }
 
-    pub fn manual_transactions(&self) -> &ManualTransactions<Perms> {
+    pub fn manual_transactions(&self) -> &ManualTransactions<Perms, E> {
         &self.manual_transactions
     }
 
+    pub fn recurring_journal_entries(&self) -> &RecurringJournalEntries<Perms, E> {
+        &self.recurring_journal_entries
+    }
+
     pub fn profit_and_loss(&self) -> &ProfitAndLossStatements<Perms> {
         &self.profit_and_loss
     }
@@ -187,6 +259,10 @@ where
         &self.balance_sheets
     }
 
+    pub fn cash_flow_statements(&self) -> &CashFlowStatements<Perms> {
+        &self.cash_flow_statements
+    }
+
     pub fn trial_balances(&self) -> &TrialBalances<Perms> {
         &self.trial_balances
     }
@@ -251,6 +327,8 @@ where
             .await?)
     }
 
+    /// Creates a manual transaction draft. It is posted to the ledger only
+    /// after its approval process concludes with an approval.
     #[record_error_severity]
     #[instrument(
         name = "core_accounting.execute_manual_transaction",
@@ -264,11 +342,8 @@ where
         description: String,
         effective: Option<chrono::NaiveDate>,
         entries: Vec<ManualEntryInput>,
-    ) -> Result<
-        LedgerTransaction<<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject>,
-        CoreAccountingError,
-    > {
-        let tx = self
+    ) -> Result<ManualTransaction, CoreAccountingError> {
+        Ok(self
             .manual_transactions
             .execute(
                 sub,
@@ -278,13 +353,22 @@ where
                 effective.unwrap_or_else(|| self.clock.today()),
                 entries,
             )
-            .await?;
+            .await?)
+    }
 
-        let ledger_tx_id = tx.ledger_transaction_id;
-        let mut txs = self.ledger_transactions.find_all(&[ledger_tx_id]).await?;
-        Ok(txs
-            .remove(&ledger_tx_id)
-            .expect("Could not find LedgerTransaction"))
+    #[record_error_severity]
+    #[instrument(name = "core_accounting.reverse_manual_transaction", skip(self))]
+    pub async fn reverse_manual_transaction(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        id: impl Into<ManualTransactionId> + std::fmt::Debug,
+        effective: ReversalEffective,
+    ) -> Result<ManualTransaction, CoreAccountingError> {
+        Ok(self
+            .manual_transactions
+            .reverse(sub, chart_ref, id, effective)
+            .await?)
     }
 
     #[record_error_severity]
@@ -5,7 +5,12 @@ use serde::{Deserialize, Serialize};
 
 use es_entity::*;
 
-use crate::primitives::{CalaTxId, ManualTransactionId};
+use crate::primitives::{ApprovalProcessId, CalaTxId, ManualTransactionId};
+
+use super::{
+    error::ManualTransactionError,
+    primitives::{ManualTransactionEntry, ManualTransactionStatus},
+};
 
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
@@ -17,6 +22,40 @@ pub enum ManualTransactionEvent {
         ledger_transaction_id: CalaTxId,
         description: String,
         reference: String,
+        #[serde(default)]
+        effective: Option<chrono::NaiveDate>,
+        #[serde(default)]
+        entries: Vec<ManualTransactionEntry>,
+        #[serde(default)]
+        initiated_by: Option<String>,
+        #[serde(default)]
+        approval_process_id: Option<ApprovalProcessId>,
+    },
+    Posted {
+        ledger_transaction_id: CalaTxId,
+    },
+    Denied {
+        approval_process_id: ApprovalProcessId,
+        reason: String,
+    },
+    Expired {
+        approval_process_id: ApprovalProcessId,
+        reason: String,
+    },
+    ReversalRequested {
+        reversal_ledger_transaction_id: CalaTxId,
+        reversal_effective: chrono::NaiveDate,
+        requested_by: String,
+        approval_process_id: ApprovalProcessId,
+    },
+    ReversalDenied {
+        approval_process_id: ApprovalProcessId,
+        reason: String,
+    },
+    Reversed {
+        reversal_ledger_transaction_id: CalaTxId,
+        reversal_effective: chrono::NaiveDate,
+        reversed_by: String,
     },
 }
 
@@ -27,15 +66,275 @@ pub struct ManualTransaction {
     pub reference: String,
     pub description: String,
     pub ledger_transaction_id: CalaTxId,
+    #[builder(default)]
+    pub effective: Option<chrono::NaiveDate>,
+    #[builder(default)]
+    pub entries: Vec<ManualTransactionEntry>,
+    #[builder(default)]
+    pub initiated_by: Option<String>,
+    #[builder(default)]
+    pub approval_process_id: Option<ApprovalProcessId>,
     events: EntityEvents<ManualTransactionEvent>,
 }
 
+/// Everything needed to post an approved draft to the ledger.
+pub struct ManualTransactionPosting {
+    pub ledger_transaction_id: CalaTxId,
+    pub description: String,
+    pub effective: chrono::NaiveDate,
+    pub initiated_by: String,
+    pub entries: Vec<ManualTransactionEntry>,
+}
+
+/// A reversal awaiting approval, as requested by the maker.
+pub struct ManualTransactionReversal {
+    pub ledger_transaction_id: CalaTxId,
+    pub effective: chrono::NaiveDate,
+    pub requested_by: String,
+    pub approval_process_id: ApprovalProcessId,
+}
+
 impl ManualTransaction {
     pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
         self.events
             .entity_first_persisted_at()
             .expect("No events for deposit")
     }
+
+    pub fn status(&self) -> ManualTransactionStatus {
+        // Transactions created before maker-checker was introduced were
+        // posted directly and never had an approval process.
+        let initial_status = if self.approval_process_id.is_none() {
+            ManualTransactionStatus::Posted
+        } else {
+            ManualTransactionStatus::PendingApproval
+        };
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|e| match e {
+                ManualTransactionEvent::Posted { .. } => Some(ManualTransactionStatus::Posted),
+                ManualTransactionEvent::Denied { .. } => Some(ManualTransactionStatus::Denied),
+                ManualTransactionEvent::Expired { .. } => Some(ManualTransactionStatus::Expired),
+                ManualTransactionEvent::ReversalRequested { .. } => {
+                    Some(ManualTransactionStatus::ReversalPendingApproval)
+                }
+                ManualTransactionEvent::ReversalDenied { .. } => {
+                    Some(ManualTransactionStatus::Posted)
+                }
+                ManualTransactionEvent::Reversed { .. } => Some(ManualTransactionStatus::Reversed),
+                ManualTransactionEvent::Initialized { .. } => None,
+            })
+            .unwrap_or(initial_status)
+    }
+
+    /// Why the draft was denied or expired, or why its latest reversal
+    /// request was turned down.
+    pub fn status_reason(&self) -> Option<&str> {
+        self.events.iter_all().rev().find_map(|e| match e {
+            ManualTransactionEvent::Denied { reason, .. }
+            | ManualTransactionEvent::Expired { reason, .. }
+            | ManualTransactionEvent::ReversalDenied { reason, .. } => Some(reason.as_str()),
+            _ => None,
+        })
+    }
+
+    pub fn is_posted(&self) -> bool {
+        self.status() == ManualTransactionStatus::Posted
+    }
+
+    /// Whether the transaction has reached the ledger, even if it has since
+    /// been reversed.
+    pub fn has_ledger_transaction(&self) -> bool {
+        matches!(
+            self.status(),
+            ManualTransactionStatus::Posted
+                | ManualTransactionStatus::ReversalPendingApproval
+                | ManualTransactionStatus::Reversed
+        )
+    }
+
+    pub fn reversal_ledger_transaction_id(&self) -> Option<CalaTxId> {
+        self.events.iter_all().rev().find_map(|e| match e {
+            ManualTransactionEvent::Reversed {
+                reversal_ledger_transaction_id,
+                ..
+            } => Some(*reversal_ledger_transaction_id),
+            _ => None,
+        })
+    }
+
+    pub fn reversal_effective(&self) -> Option<chrono::NaiveDate> {
+        self.events.iter_all().rev().find_map(|e| match e {
+            ManualTransactionEvent::Reversed {
+                reversal_effective, ..
+            } => Some(*reversal_effective),
+            _ => None,
+        })
+    }
+
+    /// The approval process of the latest reversal request, if any.
+    pub fn reversal_approval_process_id(&self) -> Option<ApprovalProcessId> {
+        self.events.iter_all().rev().find_map(|e| match e {
+            ManualTransactionEvent::ReversalRequested {
+                approval_process_id,
+                ..
+            } => Some(*approval_process_id),
+            _ => None,
+        })
+    }
+
+    pub fn posting(&self) -> Result<ManualTransactionPosting, ManualTransactionError> {
+        match (self.effective, self.initiated_by.as_ref()) {
+            (Some(effective), Some(initiated_by))
+                if self.status() == ManualTransactionStatus::PendingApproval =>
+            {
+                Ok(ManualTransactionPosting {
+                    ledger_transaction_id: self.ledger_transaction_id,
+                    description: self.description.clone(),
+                    effective,
+                    initiated_by: initiated_by.clone(),
+                    entries: self.entries.clone(),
+                })
+            }
+            _ => Err(ManualTransactionError::NotPendingApproval(self.id)),
+        }
+    }
+
+    pub fn post(&mut self) -> Result<Idempotent<()>, ManualTransactionError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            ManualTransactionEvent::Posted { .. }
+        );
+        self.ensure_pending_approval()?;
+
+        self.events.push(ManualTransactionEvent::Posted {
+            ledger_transaction_id: self.ledger_transaction_id,
+        });
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn deny(&mut self, reason: String) -> Result<Idempotent<()>, ManualTransactionError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            ManualTransactionEvent::Denied { .. }
+        );
+        let approval_process_id = self.ensure_pending_approval()?;
+
+        self.events.push(ManualTransactionEvent::Denied {
+            approval_process_id,
+            reason,
+        });
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn expire(&mut self, reason: String) -> Result<Idempotent<()>, ManualTransactionError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            ManualTransactionEvent::Expired { .. }
+        );
+        let approval_process_id = self.ensure_pending_approval()?;
+
+        self.events.push(ManualTransactionEvent::Expired {
+            approval_process_id,
+            reason,
+        });
+        Ok(Idempotent::Executed(()))
+    }
+
+    /// Records the maker's request to reverse a posted transaction. The
+    /// reversal only reaches the ledger once its approval process concludes.
+    pub fn request_reversal(
+        &mut self,
+        reversal_ledger_transaction_id: CalaTxId,
+        effective: chrono::NaiveDate,
+        requested_by: impl Into<String>,
+        approval_process_id: ApprovalProcessId,
+    ) -> Result<Idempotent<()>, ManualTransactionError> {
+        match self.status() {
+            ManualTransactionStatus::ReversalPendingApproval
+            | ManualTransactionStatus::Reversed => return Ok(Idempotent::AlreadyApplied),
+            ManualTransactionStatus::Posted => {}
+            _ => return Err(ManualTransactionError::NotPosted(self.id)),
+        }
+
+        self.events.push(ManualTransactionEvent::ReversalRequested {
+            reversal_ledger_transaction_id,
+            reversal_effective: effective,
+            requested_by: requested_by.into(),
+            approval_process_id,
+        });
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn pending_reversal(&self) -> Result<ManualTransactionReversal, ManualTransactionError> {
+        if self.status() != ManualTransactionStatus::ReversalPendingApproval {
+            return Err(ManualTransactionError::NoPendingReversal(self.id));
+        }
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|e| match e {
+                ManualTransactionEvent::ReversalRequested {
+                    reversal_ledger_transaction_id,
+                    reversal_effective,
+                    requested_by,
+                    approval_process_id,
+                } => Some(ManualTransactionReversal {
+                    ledger_transaction_id: *reversal_ledger_transaction_id,
+                    effective: *reversal_effective,
+                    requested_by: requested_by.clone(),
+                    approval_process_id: *approval_process_id,
+                }),
+                _ => None,
+            })
+            .ok_or(ManualTransactionError::NoPendingReversal(self.id))
+    }
+
+    pub fn reverse(&mut self) -> Result<Idempotent<CalaTxId>, ManualTransactionError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            ManualTransactionEvent::Reversed { .. }
+        );
+        let reversal = self.pending_reversal()?;
+
+        self.events.push(ManualTransactionEvent::Reversed {
+            reversal_ledger_transaction_id: reversal.ledger_transaction_id,
+            reversal_effective: reversal.effective,
+            reversed_by: reversal.requested_by,
+        });
+        Ok(Idempotent::Executed(reversal.ledger_transaction_id))
+    }
+
+    pub fn deny_reversal(
+        &mut self,
+        reason: String,
+    ) -> Result<Idempotent<()>, ManualTransactionError> {
+        let reversal = match self.pending_reversal() {
+            Ok(reversal) => reversal,
+            Err(_) if self.status() == ManualTransactionStatus::Reversed => {
+                return Err(ManualTransactionError::NoPendingReversal(self.id));
+            }
+            Err(_) => return Ok(Idempotent::AlreadyApplied),
+        };
+
+        self.events.push(ManualTransactionEvent::ReversalDenied {
+            approval_process_id: reversal.approval_process_id,
+            reason,
+        });
+        Ok(Idempotent::Executed(()))
+    }
+
+    fn ensure_pending_approval(&self) -> Result<ApprovalProcessId, ManualTransactionError> {
+        match self.approval_process_id {
+            Some(approval_process_id)
+                if self.status() == ManualTransactionStatus::PendingApproval =>
+            {
+                Ok(approval_process_id)
+            }
+            _ => Err(ManualTransactionError::NotPendingApproval(self.id)),
+        }
+    }
 }
 
 impl TryFromEvents<ManualTransactionEvent> for ManualTransaction {
@@ -50,14 +349,27 @@ impl TryFromEvents<ManualTransactionEvent> for ManualTransaction {
                     reference,
                     description,
                     ledger_transaction_id,
-                    ..
+                    effective,
+                    entries,
+                    initiated_by,
+                    approval_process_id,
                 } => {
                     builder = builder
                         .id(*id)
                         .reference(reference.clone())
                         .description(description.clone())
                         .ledger_transaction_id(*ledger_transaction_id)
+                        .effective(*effective)
+                        .entries(entries.clone())
+                        .initiated_by(initiated_by.clone())
+                        .approval_process_id(*approval_process_id)
                 }
+                ManualTransactionEvent::Posted { .. }
+                | ManualTransactionEvent::Denied { .. }
+                | ManualTransactionEvent::Expired { .. }
+                | ManualTransactionEvent::ReversalRequested { .. }
+                | ManualTransactionEvent::ReversalDenied { .. }
+                | ManualTransactionEvent::Reversed { .. } => {}
             }
         }
         builder.events(events).build()
@@ -71,6 +383,12 @@ pub struct NewManualTransaction {
     reference: Option<String>,
     pub(super) ledger_transaction_id: CalaTxId,
     description: String,
+    effective: chrono::NaiveDate,
+    entries: Vec<ManualTransactionEntry>,
+    #[builder(setter(into))]
+    initiated_by: String,
+    #[builder(setter(into))]
+    pub(super) approval_process_id: ApprovalProcessId,
 }
 
 impl NewManualTransaction {
@@ -96,7 +414,177 @@ impl IntoEvents<ManualTransactionEvent> for NewManualTransaction {
                 id: self.id,
                 ledger_transaction_id: self.ledger_transaction_id,
                 description: self.description,
+                effective: Some(self.effective),
+                entries: self.entries,
+                initiated_by: Some(self.initiated_by),
+                approval_process_id: Some(self.approval_process_id),
             }],
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use cala_ledger::{Currency, DebitOrCredit};
+
+    use crate::primitives::LedgerAccountId;
+
+    use super::*;
+
+    fn draft() -> ManualTransaction {
+        let id = ManualTransactionId::new();
+        let new_tx = NewManualTransaction::builder()
+            .id(id)
+            .reference(None)
+            .ledger_transaction_id(CalaTxId::new())
+            .description("draft".to_string())
+            .effective(chrono::NaiveDate::from_ymd_opt(2021, 1, 1).unwrap())
+            .entries(vec![ManualTransactionEntry {
+                account_id: LedgerAccountId::new(),
+                amount: dec!(100),
+                currency: Currency::USD,
+                direction: DebitOrCredit::Debit,
+                description: "entry".to_string(),
+            }])
+            .initiated_by("maker")
+            .approval_process_id(id)
+            .build()
+            .unwrap();
+        ManualTransaction::try_from_events(new_tx.into_events()).unwrap()
+    }
+
+    #[test]
+    fn draft_is_pending_approval() {
+        let tx = draft();
+        assert_eq!(tx.status(), ManualTransactionStatus::PendingApproval);
+        assert!(tx.posting().is_ok());
+    }
+
+    #[test]
+    fn post_is_idempotent() {
+        let mut tx = draft();
+        assert!(tx.post().unwrap().did_execute());
+        assert_eq!(tx.status(), ManualTransactionStatus::Posted);
+        assert!(tx.post().unwrap().was_already_applied());
+        assert!(tx.posting().is_err());
+    }
+
+    #[test]
+    fn denied_draft_keeps_reason_and_cannot_be_posted() {
+        let mut tx = draft();
+        assert!(tx.deny("wrong account".to_string()).unwrap().did_execute());
+        assert_eq!(tx.status(), ManualTransactionStatus::Denied);
+        assert_eq!(tx.status_reason(), Some("wrong account"));
+        assert!(matches!(
+            tx.post(),
+            Err(ManualTransactionError::NotPendingApproval(_))
+        ));
+    }
+
+    #[test]
+    fn expired_draft_keeps_reason() {
+        let mut tx = draft();
+        assert!(
+            tx.expire("period closed".to_string())
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(tx.status(), ManualTransactionStatus::Expired);
+        assert_eq!(tx.status_reason(), Some("period closed"));
+        assert!(matches!(
+            tx.deny("too late".to_string()),
+            Err(ManualTransactionError::NotPendingApproval(_))
+        ));
+    }
+
+    #[test]
+    fn reversal_waits_for_approval_and_applies_once() {
+        let mut tx = draft();
+        assert!(tx.post().unwrap().did_execute());
+        let reversal_tx_id = CalaTxId::new();
+        let effective = chrono::NaiveDate::from_ymd_opt(2021, 2, 1).unwrap();
+
+        assert!(
+            tx.request_reversal(reversal_tx_id, effective, "maker", ApprovalProcessId::new())
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(
+            tx.status(),
+            ManualTransactionStatus::ReversalPendingApproval
+        );
+        assert_eq!(tx.reversal_ledger_transaction_id(), None);
+        assert!(
+            tx.request_reversal(
+                CalaTxId::new(),
+                effective,
+                "maker",
+                ApprovalProcessId::new()
+            )
+            .unwrap()
+            .was_already_applied()
+        );
+
+        assert!(tx.reverse().unwrap().did_execute());
+        assert_eq!(tx.status(), ManualTransactionStatus::Reversed);
+        assert_eq!(tx.reversal_ledger_transaction_id(), Some(reversal_tx_id));
+        assert_eq!(tx.reversal_effective(), Some(effective));
+        assert!(tx.has_ledger_transaction());
+        assert!(tx.reverse().unwrap().was_already_applied());
+    }
+
+    #[test]
+    fn denied_reversal_leaves_transaction_posted() {
+        let mut tx = draft();
+        assert!(tx.post().unwrap().did_execute());
+        let effective = chrono::NaiveDate::from_ymd_opt(2021, 2, 1).unwrap();
+        assert!(
+            tx.request_reversal(
+                CalaTxId::new(),
+                effective,
+                "maker",
+                ApprovalProcessId::new(),
+            )
+            .unwrap()
+            .did_execute()
+        );
+
+        assert!(
+            tx.deny_reversal("not needed".to_string())
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(tx.status(), ManualTransactionStatus::Posted);
+        assert_eq!(tx.status_reason(), Some("not needed"));
+        assert!(matches!(
+            tx.reverse(),
+            Err(ManualTransactionError::NoPendingReversal(_))
+        ));
+        assert!(
+            tx.request_reversal(
+                CalaTxId::new(),
+                effective,
+                "maker",
+                ApprovalProcessId::new()
+            )
+            .unwrap()
+            .did_execute()
+        );
+    }
+
+    #[test]
+    fn draft_cannot_be_reversed() {
+        let mut tx = draft();
+        assert!(matches!(
+            tx.request_reversal(
+                CalaTxId::new(),
+                chrono::NaiveDate::from_ymd_opt(2021, 2, 1).unwrap(),
+                "maker",
+                ApprovalProcessId::new()
+            ),
+            Err(ManualTransactionError::NotPosted(_))
+        ));
+    }
+}
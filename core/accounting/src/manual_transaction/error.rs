@@ -18,6 +18,20 @@ pub enum ManualTransactionError {
     AuthorizationError(#[from] authz::error::AuthorizationError),
     #[error("ManualTransactionError - ChartOfAccounts: {0}")]
     ChartOfAccountsError(#[from] chart_of_accounts::error::ChartOfAccountsError),
+    #[error("ManualTransactionError - FiscalYearError: {0}")]
+    FiscalYearError(#[from] crate::fiscal_year::error::FiscalYearError),
+    #[error("ManualTransactionError - GovernanceError: {0}")]
+    GovernanceError(#[from] governance::error::GovernanceError),
+    #[error("ManualTransactionError - AuditError: {0}")]
+    AuditError(#[from] audit::error::AuditError),
+    #[error("ManualTransactionError - NotPendingApproval: {0}")]
+    NotPendingApproval(crate::primitives::ManualTransactionId),
+    #[error("ManualTransactionError - NotPosted: {0}")]
+    NotPosted(crate::primitives::ManualTransactionId),
+    #[error("ManualTransactionError - NoPendingReversal: {0}")]
+    NoPendingReversal(crate::primitives::ManualTransactionId),
+    #[error("ManualTransactionError - PeriodClosed: {0} falls in a closed period")]
+    PeriodClosed(chrono::NaiveDate),
 }
 
 es_entity::from_es_entity_error!(ManualTransactionError);
@@ -31,6 +45,13 @@ impl ErrorSeverity for ManualTransactionError {
             Self::ManualTransactionLedgerError(e) => e.severity(),
             Self::AuthorizationError(e) => e.severity(),
             Self::ChartOfAccountsError(e) => e.severity(),
+            Self::FiscalYearError(e) => e.severity(),
+            Self::GovernanceError(e) => e.severity(),
+            Self::AuditError(e) => e.severity(),
+            Self::NotPendingApproval(_) => Level::WARN,
+            Self::NotPosted(_) => Level::WARN,
+            Self::NoPendingReversal(_) => Level::WARN,
+            Self::PeriodClosed(_) => Level::WARN,
         }
     }
 }
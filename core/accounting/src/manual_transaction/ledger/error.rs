@@ -8,6 +8,10 @@ pub enum ManualTransactionLedgerError {
     Sqlx(#[from] sqlx::Error),
     #[error("ManualTransactionLedgerError - CalaLedger: {0}")]
     CalaLedger(#[from] cala_ledger::error::LedgerError),
+    #[error("ManualTransactionLedgerError - CalaEntryError: {0}")]
+    CalaEntry(#[from] cala_ledger::entry::error::EntryError),
+    #[error("ManualTransactionLedgerError - CalaTransaction: {0}")]
+    CalaTransaction(#[from] cala_ledger::transaction::error::TransactionError),
     #[error("ManualTransactionLedgerError - CalaTxTemplate: {0}")]
     CalaTxTemplate(#[from] cala_ledger::tx_template::error::TxTemplateError),
 }
@@ -17,7 +21,21 @@ impl ErrorSeverity for ManualTransactionLedgerError {
         match self {
             Self::Sqlx(_) => Level::ERROR,
             Self::CalaLedger(_) => Level::ERROR,
+            Self::CalaEntry(_) => Level::ERROR,
+            Self::CalaTransaction(_) => Level::ERROR,
             Self::CalaTxTemplate(_) => Level::ERROR,
         }
     }
 }
+
+impl ManualTransactionLedgerError {
+    /// The posting was rejected by the period closing controls on the chart.
+    pub fn is_period_closed(&self) -> bool {
+        matches!(
+            self,
+            Self::CalaLedger(cala_ledger::error::LedgerError::VelocityError(
+                cala_ledger::velocity::error::VelocityError::Enforcement(_)
+            ))
+        )
+    }
+}
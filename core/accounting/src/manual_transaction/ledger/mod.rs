@@ -9,6 +9,12 @@ use error::*;
 use template::*;
 pub use template::{EntryParams, ManualTransactionParams};
 
+/// A transaction as it was posted to the ledger.
+pub struct PostedManualTransaction {
+    pub effective: chrono::NaiveDate,
+    pub entry_params: Vec<EntryParams>,
+}
+
 #[derive(Clone)]
 pub struct ManualTransactionLedger {
     cala: CalaLedger,
@@ -34,4 +40,31 @@ impl ManualTransactionLedger {
 
         Ok(())
     }
+
+    pub async fn find_posted(
+        &self,
+        tx_id: CalaTxId,
+    ) -> Result<PostedManualTransaction, ManualTransactionLedgerError> {
+        let transaction = self.cala.transactions().find_by_id(tx_id).await?;
+        let entries = self.cala.entries().list_for_transaction_id(tx_id).await?;
+
+        let entry_params = entries
+            .into_iter()
+            .map(|entry| {
+                let values = entry.into_values();
+                EntryParams {
+                    account_id: values.account_id,
+                    currency: values.currency,
+                    amount: values.units,
+                    description: values.description.unwrap_or_default(),
+                    direction: values.direction,
+                }
+            })
+            .collect();
+
+        Ok(PostedManualTransaction {
+            effective: transaction.values().effective,
+            entry_params,
+        })
+    }
 }
@@ -20,6 +20,15 @@ pub struct EntryParams {
 }
 
 impl EntryParams {
+    /// The entry that offsets this one when a transaction is reversed.
+    pub fn reversed(self) -> Self {
+        let direction = match self.direction {
+            DebitOrCredit::Debit => DebitOrCredit::Credit,
+            DebitOrCredit::Credit => DebitOrCredit::Debit,
+        };
+        Self { direction, ..self }
+    }
+
     pub fn populate_params(&self, params: &mut Params, n: usize) {
         params.insert(Self::account_id_param_name(n), self.account_id);
         params.insert(Self::currency_param_name(n), self.currency);
@@ -96,6 +105,7 @@ pub struct ManualTransactionParams<S: std::fmt::Display> {
     pub effective: chrono::NaiveDate,
     pub initiated_by: S,
     pub entry_params: Vec<EntryParams>,
+    pub reverses: Option<TransactionId>,
 }
 
 impl<S: std::fmt::Display> From<ManualTransactionParams<S>> for Params {
@@ -104,12 +114,13 @@ impl<S: std::fmt::Display> From<ManualTransactionParams<S>> for Params {
         params.insert("journal_id", input_params.journal_id);
         params.insert("description", input_params.description);
         params.insert("effective", input_params.effective);
-        params.insert(
-            "meta",
-            serde_json::json!({
-                "initiated_by": input_params.initiated_by.to_string(),
-            }),
-        );
+        let mut meta = serde_json::json!({
+            "initiated_by": input_params.initiated_by.to_string(),
+        });
+        if let Some(reverses) = input_params.reverses {
+            meta["reverses"] = serde_json::json!(reverses);
+        }
+        params.insert("meta", meta);
 
         for (n, entry) in input_params.entry_params.iter().enumerate() {
             entry.populate_params(&mut params, n);
@@ -2,7 +2,7 @@ mod entity;
 pub mod error;
 pub mod ledger;
 mod primitives;
-mod repo;
+pub(crate) mod repo;
 
 use tracing::instrument;
 
@@ -11,13 +11,23 @@ use std::collections::HashMap;
 use audit::AuditSvc;
 use authz::PermissionCheck;
 use cala_ledger::{CalaLedger, JournalId};
+use chrono::{Datelike, Months};
 use es_entity::clock::ClockHandle;
-use ledger::{EntryParams, ManualTransactionLedger, ManualTransactionParams};
+use governance::{Governance, GovernanceEvent};
+use ledger::{ManualTransactionLedger, ManualTransactionParams};
+use obix::out::OutboxEventMarker;
 use tracing_macros::record_error_severity;
 
 use crate::{
     chart_of_accounts::ChartOfAccounts,
-    primitives::{CalaTxId, CoreAccountingAction, CoreAccountingObject, ManualTransactionId},
+    fiscal_year::FiscalYears,
+    primitives::{
+        ApprovalProcessId, CalaTxId, CoreAccountingAction, CoreAccountingObject, GovernanceAction,
+        GovernanceObject, ManualTransactionId,
+    },
+    processes::manual_transaction_approval::{
+        APPROVE_MANUAL_TRANSACTION_PROCESS, APPROVE_MANUAL_TRANSACTION_REVERSAL_PROCESS,
+    },
 };
 use error::*;
 
@@ -29,39 +39,69 @@ pub use primitives::*;
 pub use repo::manual_transaction_cursor::ManualTransactionsByCreatedAtCursor;
 use repo::*;
 
-#[derive(Clone)]
-pub struct ManualTransactions<Perms>
+pub struct ManualTransactions<Perms, E>
 where
     Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
 {
     ledger: ManualTransactionLedger,
     authz: Perms,
     chart_of_accounts: ChartOfAccounts<Perms>,
+    fiscal_years: FiscalYears<Perms>,
+    governance: Governance<Perms, E>,
+    clock: ClockHandle,
     journal_id: JournalId,
     repo: ManualTransactionRepo,
 }
 
-impl<Perms> ManualTransactions<Perms>
+impl<Perms, E> Clone for ManualTransactions<Perms, E>
 where
     Perms: PermissionCheck,
-    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
-    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
 {
-    pub fn new(
-        pool: &sqlx::PgPool,
+    fn clone(&self) -> Self {
+        Self {
+            ledger: self.ledger.clone(),
+            authz: self.authz.clone(),
+            chart_of_accounts: self.chart_of_accounts.clone(),
+            fiscal_years: self.fiscal_years.clone(),
+            governance: self.governance.clone(),
+            clock: self.clock.clone(),
+            journal_id: self.journal_id,
+            repo: self.repo.clone(),
+        }
+    }
+}
+
+impl<Perms, E> ManualTransactions<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        repo: &ManualTransactionRepo,
         authz: &Perms,
         chart_of_accounts: &ChartOfAccounts<Perms>,
+        fiscal_years: &FiscalYears<Perms>,
+        governance: &Governance<Perms, E>,
         cala: &CalaLedger,
-        journal_id: JournalId,
         clock: ClockHandle,
+        journal_id: JournalId,
     ) -> Self {
-        let repo = ManualTransactionRepo::new(pool, clock);
         Self {
             ledger: ManualTransactionLedger::new(cala),
             chart_of_accounts: chart_of_accounts.clone(),
+            fiscal_years: fiscal_years.clone(),
             authz: authz.clone(),
+            governance: governance.clone(),
+            clock,
             journal_id,
-            repo,
+            repo: repo.clone(),
         }
     }
 
@@ -116,6 +156,8 @@ where
         self.repo.find_all(ids).await
     }
 
+    /// Records a draft journal entry and routes it to approval. The draft is
+    /// only posted to the ledger once its approval process concludes.
     #[record_error_severity]
     #[instrument(name = "manual_transaction.execute", skip(self, entries), fields(subject = %sub, chart_ref = %chart_ref, effective = %effective, entries_count = entries.len()))]
     pub async fn execute(
@@ -135,21 +177,7 @@ where
             )
             .await?;
 
-        let ledger_tx_id = CalaTxId::new();
-        let manual_tx_id = ManualTransactionId::new();
-
-        let new_tx = NewManualTransaction::builder()
-            .id(manual_tx_id)
-            .ledger_transaction_id(ledger_tx_id)
-            .description(description.clone())
-            .reference(reference)
-            .build()
-            .expect("Couldn't build new manual transaction");
-
-        let mut db = self.repo.begin_op().await?;
-        let manual_transaction = self.repo.create_in_op(&mut db, new_tx).await?;
-
-        let mut entry_params = vec![];
+        let mut draft_entries = vec![];
         for e in entries {
             let account_id = self
                 .chart_of_accounts
@@ -159,8 +187,8 @@ where
                     e.account_id_or_code,
                 )
                 .await?;
-            entry_params.push(EntryParams {
-                account_id: account_id.into(),
+            draft_entries.push(ManualTransactionEntry {
+                account_id,
                 amount: e.amount,
                 currency: e.currency,
                 direction: e.direction,
@@ -168,21 +196,162 @@ where
             });
         }
 
+        let mut db = self.repo.begin_op().await?;
+        let manual_transaction = self
+            .create_in_op(
+                &mut db,
+                sub,
+                reference,
+                description,
+                effective,
+                draft_entries,
+            )
+            .await?;
+        db.commit().await?;
+
+        Ok(manual_transaction)
+    }
+
+    /// Records a draft with already resolved accounts and starts its
+    /// approval process as part of `db`.
+    pub(crate) async fn create_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        initiated_by: &(impl std::fmt::Display + std::fmt::Debug),
+        reference: Option<String>,
+        description: String,
+        effective: chrono::NaiveDate,
+        draft_entries: Vec<ManualTransactionEntry>,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        let ledger_tx_id = CalaTxId::new();
+        let manual_tx_id = ManualTransactionId::new();
+
+        let new_tx = NewManualTransaction::builder()
+            .id(manual_tx_id)
+            .ledger_transaction_id(ledger_tx_id)
+            .description(description.clone())
+            .reference(reference)
+            .effective(effective)
+            .entries(draft_entries.clone())
+            .initiated_by(initiated_by.to_string())
+            .approval_process_id(manual_tx_id)
+            .build()
+            .expect("Couldn't build new manual transaction");
+
+        // Post the draft inside a savepoint that is always rolled back so that
+        // an unbalanced entry or a closed period is reported to the maker now
+        // rather than to the approvers later.
+        let mut validation = db.begin().await?;
         self.ledger
             .execute_in_op(
-                &mut db,
+                &mut validation,
                 ledger_tx_id,
                 ManualTransactionParams {
                     journal_id: self.journal_id,
                     description,
-                    entry_params,
+                    entry_params: draft_entries.iter().map(Into::into).collect(),
                     effective,
-                    initiated_by: sub,
+                    initiated_by,
+                    reverses: None,
                 },
             )
             .await?;
+        drop(validation);
 
-        db.commit().await?;
+        self.governance
+            .start_process_initiated_by_in_op(
+                db,
+                manual_tx_id,
+                manual_tx_id.to_string(),
+                APPROVE_MANUAL_TRANSACTION_PROCESS,
+                initiated_by.to_string(),
+            )
+            .await?;
+        self.repo.create_in_op(db, new_tx).await
+    }
+
+    /// Requests a ledger transaction that offsets a posted manual transaction
+    /// and starts its approval process. The offsetting transaction is only
+    /// posted, and linked on the original, once the reversal is approved. The
+    /// request is refused if its effective date falls in a month that has
+    /// already been closed.
+    #[record_error_severity]
+    #[instrument(name = "manual_transaction.reverse", skip(self), fields(subject = %sub))]
+    pub async fn reverse(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        id: impl Into<ManualTransactionId> + std::fmt::Debug,
+        effective: ReversalEffective,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::manual_transaction(id),
+                CoreAccountingAction::MANUAL_TRANSACTION_REVERSE,
+            )
+            .await?;
+
+        let mut manual_transaction = self.repo.find_by_id(id).await?;
+        match manual_transaction.status() {
+            ManualTransactionStatus::ReversalPendingApproval
+            | ManualTransactionStatus::Reversed => return Ok(manual_transaction),
+            ManualTransactionStatus::Posted => {}
+            _ => return Err(ManualTransactionError::NotPosted(id)),
+        }
+
+        let chart = self.chart_of_accounts.find_by_reference(chart_ref).await?;
+        let first_open_date = self
+            .fiscal_years
+            .first_open_date_for_chart(chart.id)
+            .await?;
+        let posted = self
+            .ledger
+            .find_posted(manual_transaction.ledger_transaction_id)
+            .await?;
+
+        let effective = match effective {
+            ReversalEffective::Today => self.clock.today(),
+            ReversalEffective::On(date) => date,
+            ReversalEffective::NextOpenPeriod => {
+                let next_period = posted
+                    .effective
+                    .with_day(1)
+                    .and_then(|d| d.checked_add_months(Months::new(1)))
+                    .expect("Failed to compute start of next period");
+                first_open_date.map_or(next_period, |d| d.max(next_period))
+            }
+        };
+        if first_open_date.is_some_and(|first_open_date| effective < first_open_date) {
+            return Err(ManualTransactionError::PeriodClosed(effective));
+        }
+
+        let approval_process_id = ApprovalProcessId::new();
+        if manual_transaction
+            .request_reversal(
+                CalaTxId::new(),
+                effective,
+                sub.to_string(),
+                approval_process_id,
+            )?
+            .did_execute()
+        {
+            let mut db = self.repo.begin_op().await?;
+            self.governance
+                .start_process_initiated_by_in_op(
+                    &mut db,
+                    approval_process_id,
+                    manual_transaction.id.to_string(),
+                    APPROVE_MANUAL_TRANSACTION_REVERSAL_PROCESS,
+                    sub.to_string(),
+                )
+                .await?;
+            self.repo
+                .update_in_op(&mut db, &mut manual_transaction)
+                .await?;
+            db.commit().await?;
+        }
 
         Ok(manual_transaction)
     }
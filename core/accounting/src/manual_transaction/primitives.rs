@@ -1,9 +1,14 @@
 use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 use cala_ledger::{Currency, DebitOrCredit};
 use rust_decimal::Decimal;
 
-use crate::primitives::AccountIdOrCode;
+use crate::primitives::{AccountIdOrCode, LedgerAccountId};
+
+use super::ledger::EntryParams;
 
 pub use cala_ledger::TransactionId as CalaTransactionId;
 
@@ -22,3 +27,49 @@ impl ManualEntryInput {
         ManualEntryInputBuilder::default()
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum ManualTransactionStatus {
+    PendingApproval,
+    Posted,
+    Denied,
+    Expired,
+    ReversalPendingApproval,
+    Reversed,
+}
+
+/// When the reversing ledger transaction of a manual transaction takes effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReversalEffective {
+    Today,
+    On(chrono::NaiveDate),
+    /// The first day of the month after the original posting, moved forward
+    /// to the first open day if that month has already been closed.
+    NextOpenPeriod,
+}
+
+/// A journal entry line of a draft, with its account already resolved
+/// against the chart so that approval cannot change what gets posted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct ManualTransactionEntry {
+    pub account_id: LedgerAccountId,
+    pub amount: Decimal,
+    pub currency: Currency,
+    pub direction: DebitOrCredit,
+    pub description: String,
+}
+
+impl From<&ManualTransactionEntry> for EntryParams {
+    fn from(entry: &ManualTransactionEntry) -> Self {
+        Self {
+            account_id: entry.account_id.into(),
+            amount: entry.amount,
+            currency: entry.currency,
+            direction: entry.direction,
+            description: entry.description.clone(),
+        }
+    }
+}
@@ -6,6 +6,10 @@ use authz::{ActionPermission, AllOrOne, action_description::*, map_action};
 
 pub const ACCOUNTING_TRIAL_BALANCE: audit::SystemActor =
     audit::SystemActor::new("accounting-trial-balance");
+pub const MANUAL_TRANSACTION_APPROVAL: audit::SystemActor =
+    audit::SystemActor::new("manual-transaction-approval");
+pub const RECURRING_JOURNAL_ENTRY_POSTING: audit::SystemActor =
+    audit::SystemActor::new("recurring-journal-entry-posting");
 
 // Re-export everything from the primitives crate
 pub use core_accounting_primitives::*;
@@ -17,6 +21,7 @@ pub use cala_ledger::{
     balance::{AccountBalance as CalaAccountBalance, BalanceRange as CalaBalanceRange},
 };
 
+pub use governance::{ApprovalProcessId, GovernanceAction, GovernanceObject};
 pub use money::{Satoshis, UsdCents};
 
 es_entity::entity_id! {
@@ -24,11 +29,13 @@ es_entity::entity_id! {
     ManualTransactionId,
     LedgerAccountId,
     AccountingCsvId,
-    FiscalYearId;
+    FiscalYearId,
+    RecurringJournalEntryId;
 
     LedgerAccountId => CalaAccountId,
     LedgerAccountId => CalaAccountSetId,
     AccountingCsvId => job::JobId,
+    ManualTransactionId => ApprovalProcessId,
 }
 
 impl From<cala_ledger::account_set::AccountSetMemberId> for LedgerAccountId {
@@ -171,6 +178,8 @@ pub type AccountingCsvAllOrOne = AllOrOne<AccountingCsvId>;
 pub type TrialBalanceAllOrOne = AllOrOne<LedgerAccountId>; // what to do if there is only All
 // option
 pub type FiscalYearAllOrOne = AllOrOne<FiscalYearId>;
+pub type RecurringJournalEntryAllOrOne = AllOrOne<RecurringJournalEntryId>;
+pub type CashFlowStatementAllOrOne = AllOrOne<ChartId>;
 
 permission_sets_macro::permission_sets! {
     AccountingViewer("Can view accounting reports, journal entries, ledger accounts, and transactions"),
@@ -194,6 +203,8 @@ pub enum CoreAccountingAction {
     AccountingCsv(AccountingCsvAction),
     TrialBalance(TrialBalanceAction),
     FiscalYear(FiscalYearAction),
+    RecurringJournalEntry(RecurringJournalEntryAction),
+    CashFlowStatement(CashFlowStatementAction),
 }
 
 impl CoreAccountingAction {
@@ -243,6 +254,14 @@ impl CoreAccountingAction {
                 FiscalYear => {
                     map_action!(accounting, FiscalYear, FiscalYearAction)
                 }
+                RecurringJournalEntry => map_action!(
+                    accounting,
+                    RecurringJournalEntry,
+                    RecurringJournalEntryAction
+                ),
+                CashFlowStatement => {
+                    map_action!(accounting, CashFlowStatement, CashFlowStatementAction)
+                }
             })
             .collect()
     }
@@ -265,6 +284,8 @@ pub enum CoreAccountingObject {
     AccountingCsv(AccountingCsvAllOrOne),
     TrialBalance(TrialBalanceAllOrOne),
     FiscalYear(FiscalYearAllOrOne),
+    RecurringJournalEntry(RecurringJournalEntryAllOrOne),
+    CashFlowStatement(CashFlowStatementAllOrOne),
 }
 
 impl CoreAccountingObject {
@@ -353,6 +374,18 @@ impl CoreAccountingObject {
     pub fn all_fiscal_years() -> Self {
         CoreAccountingObject::FiscalYear(AllOrOne::All)
     }
+
+    pub fn all_recurring_journal_entries() -> Self {
+        CoreAccountingObject::RecurringJournalEntry(AllOrOne::All)
+    }
+
+    pub fn recurring_journal_entry(id: RecurringJournalEntryId) -> Self {
+        CoreAccountingObject::RecurringJournalEntry(AllOrOne::ById(id))
+    }
+
+    pub fn all_cash_flow_statements() -> Self {
+        CoreAccountingObject::CashFlowStatement(AllOrOne::All)
+    }
 }
 
 impl Display for CoreAccountingObject {
@@ -373,6 +406,8 @@ impl Display for CoreAccountingObject {
             AccountingCsv(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             TrialBalance(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             FiscalYear(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            RecurringJournalEntry(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            CashFlowStatement(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
         }
     }
 }
@@ -446,6 +481,18 @@ impl FromStr for CoreAccountingObject {
                 let obj_ref = id.parse().map_err(|_| "could not parse FiscalYear")?;
                 CoreAccountingObject::FiscalYear(obj_ref)
             }
+            RecurringJournalEntry => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse RecurringJournalEntry")?;
+                CoreAccountingObject::RecurringJournalEntry(obj_ref)
+            }
+            CashFlowStatement => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CashFlowStatement")?;
+                CoreAccountingObject::CashFlowStatement(obj_ref)
+            }
         };
         Ok(res)
     }
@@ -483,6 +530,10 @@ impl CoreAccountingAction {
         CoreAccountingAction::ManualTransaction(ManualTransactionAction::Create);
     pub const MANUAL_TRANSACTION_LIST: Self =
         CoreAccountingAction::ManualTransaction(ManualTransactionAction::List);
+    pub const MANUAL_TRANSACTION_CONCLUDE_APPROVAL_PROCESS: Self =
+        CoreAccountingAction::ManualTransaction(ManualTransactionAction::ConcludeApprovalProcess);
+    pub const MANUAL_TRANSACTION_REVERSE: Self =
+        CoreAccountingAction::ManualTransaction(ManualTransactionAction::Reverse);
     pub const PROFIT_AND_LOSS_READ: Self =
         CoreAccountingAction::ProfitAndLoss(ProfitAndLossAction::Read);
     pub const PROFIT_AND_LOSS_CREATE: Self =
@@ -526,6 +577,20 @@ impl CoreAccountingAction {
     pub const FISCAL_YEAR_CLOSE_MONTH: Self =
         CoreAccountingAction::FiscalYear(FiscalYearAction::CloseMonth);
     pub const FISCAL_YEAR_CLOSE: Self = CoreAccountingAction::FiscalYear(FiscalYearAction::Close);
+
+    pub const RECURRING_JOURNAL_ENTRY_CREATE: Self =
+        CoreAccountingAction::RecurringJournalEntry(RecurringJournalEntryAction::Create);
+    pub const RECURRING_JOURNAL_ENTRY_READ: Self =
+        CoreAccountingAction::RecurringJournalEntry(RecurringJournalEntryAction::Read);
+    pub const RECURRING_JOURNAL_ENTRY_LIST: Self =
+        CoreAccountingAction::RecurringJournalEntry(RecurringJournalEntryAction::List);
+    pub const RECURRING_JOURNAL_ENTRY_UPDATE: Self =
+        CoreAccountingAction::RecurringJournalEntry(RecurringJournalEntryAction::Update);
+    pub const RECURRING_JOURNAL_ENTRY_POST_OCCURRENCE: Self =
+        CoreAccountingAction::RecurringJournalEntry(RecurringJournalEntryAction::PostOccurrence);
+
+    pub const CASH_FLOW_STATEMENT_READ: Self =
+        CoreAccountingAction::CashFlowStatement(CashFlowStatementAction::Read);
 }
 
 impl Display for CoreAccountingAction {
@@ -546,6 +611,8 @@ impl Display for CoreAccountingAction {
             AccountingCsv(action) => action.fmt(f),
             TrialBalance(action) => action.fmt(f),
             FiscalYear(action) => action.fmt(f),
+            RecurringJournalEntry(action) => action.fmt(f),
+            CashFlowStatement(action) => action.fmt(f),
         }
     }
 }
@@ -595,6 +662,12 @@ impl FromStr for CoreAccountingAction {
             CoreAccountingActionDiscriminants::FiscalYear => {
                 CoreAccountingAction::from(action.parse::<FiscalYearAction>()?)
             }
+            CoreAccountingActionDiscriminants::RecurringJournalEntry => {
+                CoreAccountingAction::from(action.parse::<RecurringJournalEntryAction>()?)
+            }
+            CoreAccountingActionDiscriminants::CashFlowStatement => {
+                CoreAccountingAction::from(action.parse::<CashFlowStatementAction>()?)
+            }
         };
         Ok(res)
     }
@@ -720,13 +793,17 @@ pub enum ManualTransactionAction {
     Read,
     Create,
     List,
+    ConcludeApprovalProcess,
+    Reverse,
 }
 
 impl ActionPermission for ManualTransactionAction {
     fn permission_set(&self) -> &'static str {
         match self {
             Self::List | Self::Read => PERMISSION_SET_ACCOUNTING_VIEWER,
-            Self::Create => PERMISSION_SET_ACCOUNTING_WRITER,
+            Self::Create | Self::ConcludeApprovalProcess | Self::Reverse => {
+                PERMISSION_SET_ACCOUNTING_WRITER
+            }
         }
     }
 }
@@ -902,6 +979,51 @@ impl From<FiscalYearAction> for CoreAccountingAction {
     }
 }
 
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum RecurringJournalEntryAction {
+    Create,
+    Read,
+    List,
+    Update,
+    PostOccurrence,
+}
+
+impl ActionPermission for RecurringJournalEntryAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::Read | Self::List => PERMISSION_SET_ACCOUNTING_VIEWER,
+            Self::Create | Self::Update | Self::PostOccurrence => PERMISSION_SET_ACCOUNTING_WRITER,
+        }
+    }
+}
+
+impl From<RecurringJournalEntryAction> for CoreAccountingAction {
+    fn from(action: RecurringJournalEntryAction) -> Self {
+        CoreAccountingAction::RecurringJournalEntry(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum CashFlowStatementAction {
+    Read,
+}
+
+impl ActionPermission for CashFlowStatementAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::Read => PERMISSION_SET_ACCOUNTING_VIEWER,
+        }
+    }
+}
+
+impl From<CashFlowStatementAction> for CoreAccountingAction {
+    fn from(action: CashFlowStatementAction) -> Self {
+        CoreAccountingAction::CashFlowStatement(action)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BalanceRange {
     pub open: Option<CalaAccountBalance>,
@@ -1167,6 +1289,65 @@ mod tests {
             ))
         }
 
+        fn cash_flow_mapping(mapping: &[(&str, CashFlowCategory)]) -> Vec<CashFlowMapping> {
+            mapping
+                .iter()
+                .map(|(code, category)| CashFlowMapping {
+                    code: code.parse().unwrap(),
+                    category: *category,
+                })
+                .collect()
+        }
+
+        #[test]
+        fn with_cash_flow_mapping_ok_for_balance_sheet_codes() {
+            let config = default_config().with_cash_flow_mapping(cash_flow_mapping(&[
+                ("11.01", CashFlowCategory::Cash),
+                ("11.02", CashFlowCategory::Operating),
+                ("12", CashFlowCategory::Investing),
+                ("2", CashFlowCategory::Financing),
+                ("31", CashFlowCategory::Financing),
+            ]));
+            assert!(config.is_ok());
+        }
+
+        #[test]
+        fn with_cash_flow_mapping_err_when_code_not_on_balance_sheet() {
+            let res = default_config()
+                .with_cash_flow_mapping(cash_flow_mapping(&[("41", CashFlowCategory::Operating)]));
+            assert!(matches!(
+                res,
+                Err(AccountingBaseConfigError::CashFlowMappingCodeNotOnBalanceSheet(_))
+            ))
+        }
+
+        #[test]
+        fn with_cash_flow_mapping_err_when_cash_not_asset() {
+            let res = default_config()
+                .with_cash_flow_mapping(cash_flow_mapping(&[("21", CashFlowCategory::Cash)]));
+            assert!(matches!(
+                res,
+                Err(AccountingBaseConfigError::CashFlowMappingCashCodeNotAsset(
+                    _
+                ))
+            ))
+        }
+
+        #[test]
+        fn with_cash_flow_mapping_err_when_codes_overlap() {
+            let res = default_config().with_cash_flow_mapping(cash_flow_mapping(&[
+                ("11", CashFlowCategory::Operating),
+                ("11.01", CashFlowCategory::Cash),
+            ]));
+            assert!(matches!(
+                res,
+                Err(AccountingBaseConfigError::CashFlowMappingOverlappingCodes(
+                    _,
+                    _
+                ))
+            ))
+        }
+
         #[test]
         fn is_off_balance_sheet_returns_false_for_configured_codes() {
             let config = default_config();
@@ -0,0 +1,90 @@
+use authz::PermissionCheck;
+use tracing::{Span, instrument};
+
+use audit::AuditSvc;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use obix::out::{OutboxEventHandler, OutboxEventMarker, PersistentOutboxEvent};
+
+use job::JobType;
+
+use crate::primitives::{CoreAccountingAction, CoreAccountingObject, ManualTransactionId};
+
+use super::ApproveManualTransaction;
+
+pub const MANUAL_TRANSACTION_APPROVE_JOB: JobType =
+    JobType::new("outbox.manual-transaction-approval");
+
+pub struct ManualTransactionApprovalHandler<Perms, E>
+where
+    E: OutboxEventMarker<GovernanceEvent>,
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+{
+    process: ApproveManualTransaction<Perms, E>,
+}
+
+impl<Perms, E> ManualTransactionApprovalHandler<Perms, E>
+where
+    E: OutboxEventMarker<GovernanceEvent>,
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+{
+    pub fn new(process: &ApproveManualTransaction<Perms, E>) -> Self {
+        Self {
+            process: process.clone(),
+        }
+    }
+}
+
+impl<Perms, E> OutboxEventHandler<E> for ManualTransactionApprovalHandler<Perms, E>
+where
+    E: OutboxEventMarker<GovernanceEvent>,
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+{
+    #[instrument(name = "core_accounting.manual_transaction_approval_job.process_message", parent = None, skip(self, _op, event), fields(seq = %event.sequence, handled = false, event_type = tracing::field::Empty, process_type = tracing::field::Empty))]
+    async fn handle_persistent(
+        &self,
+        _op: &mut es_entity::DbOp<'_>,
+        event: &PersistentOutboxEvent<E>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(e @ GovernanceEvent::ApprovalProcessConcluded { entity }) = event.as_event() {
+            if entity.process_type == super::APPROVE_MANUAL_TRANSACTION_PROCESS {
+                event.inject_trace_parent();
+                Span::current().record("handled", true);
+                Span::current().record("event_type", e.as_ref());
+                Span::current().record("process_type", entity.process_type.to_string());
+                Box::pin(
+                    self.process.execute_manual_transaction_approval(
+                        entity.id,
+                        entity.status.is_approved(),
+                    ),
+                )
+                .await?;
+            } else if entity.process_type == super::APPROVE_MANUAL_TRANSACTION_REVERSAL_PROCESS {
+                event.inject_trace_parent();
+                Span::current().record("handled", true);
+                Span::current().record("event_type", e.as_ref());
+                Span::current().record("process_type", entity.process_type.to_string());
+                let id = entity.target_ref.parse::<ManualTransactionId>()?;
+                Box::pin(
+                    self.process.execute_manual_transaction_reversal_approval(
+                        id,
+                        entity.status.is_approved(),
+                    ),
+                )
+                .await?;
+            }
+        }
+        Ok(())
+    }
+}
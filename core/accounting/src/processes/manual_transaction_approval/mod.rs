@@ -0,0 +1,255 @@
+mod job;
+
+use authz::PermissionCheck;
+use governance::{ApprovalProcessType, Governance, GovernanceEvent};
+use tracing::instrument;
+use tracing_macros::record_error_severity;
+
+use audit::{AuditSvc, SystemSubject};
+use cala_ledger::{CalaLedger, JournalId};
+use obix::out::OutboxEventMarker;
+
+use crate::{
+    manual_transaction::{
+        ManualTransaction,
+        error::ManualTransactionError,
+        ledger::{ManualTransactionLedger, ManualTransactionParams},
+        repo::ManualTransactionRepo,
+    },
+    primitives::{
+        CoreAccountingAction, CoreAccountingObject, GovernanceAction, GovernanceObject,
+        MANUAL_TRANSACTION_APPROVAL, ManualTransactionId,
+    },
+};
+
+pub use job::*;
+
+pub const APPROVE_MANUAL_TRANSACTION_PROCESS: ApprovalProcessType =
+    ApprovalProcessType::new("manual-transaction");
+pub const APPROVE_MANUAL_TRANSACTION_REVERSAL_PROCESS: ApprovalProcessType =
+    ApprovalProcessType::new("manual-transaction-reversal");
+
+const PERIOD_CLOSED_REASON: &str =
+    "The accounting period of the effective date was closed before the transaction was approved";
+const REVERSAL_PERIOD_CLOSED_REASON: &str =
+    "The accounting period of the effective date was closed before the reversal was approved";
+
+pub struct ApproveManualTransaction<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    repo: ManualTransactionRepo,
+    ledger: ManualTransactionLedger,
+    audit: Perms::Audit,
+    governance: Governance<Perms, E>,
+    journal_id: JournalId,
+}
+
+impl<Perms, E> Clone for ApproveManualTransaction<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            repo: self.repo.clone(),
+            ledger: self.ledger.clone(),
+            audit: self.audit.clone(),
+            governance: self.governance.clone(),
+            journal_id: self.journal_id,
+        }
+    }
+}
+
+impl<Perms, E> ApproveManualTransaction<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(
+        repo: &ManualTransactionRepo,
+        audit: &Perms::Audit,
+        governance: &Governance<Perms, E>,
+        cala: &CalaLedger,
+        journal_id: JournalId,
+    ) -> Self {
+        Self {
+            repo: repo.clone(),
+            ledger: ManualTransactionLedger::new(cala),
+            audit: audit.clone(),
+            governance: governance.clone(),
+            journal_id,
+        }
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "core_accounting.manual_transaction_approval.execute",
+        skip(self)
+    )]
+    #[es_entity::retry_on_concurrent_modification]
+    pub async fn execute_manual_transaction_approval(
+        &self,
+        id: impl es_entity::RetryableInto<ManualTransactionId>,
+        approved: bool,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        let id = id.into();
+        let mut manual_transaction = self.repo.find_by_id(id).await?;
+        let Ok(posting) = manual_transaction.posting() else {
+            return Ok(manual_transaction);
+        };
+
+        let mut op = self.repo.begin_op().await?;
+        self.audit
+            .record_system_entry_in_op(
+                &mut op,
+                MANUAL_TRANSACTION_APPROVAL,
+                CoreAccountingObject::manual_transaction(id),
+                CoreAccountingAction::MANUAL_TRANSACTION_CONCLUDE_APPROVAL_PROCESS,
+            )
+            .await?;
+
+        let concluded = if approved {
+            let mut ledger_op = op.begin().await?;
+            match self
+                .ledger
+                .execute_in_op(
+                    &mut ledger_op,
+                    posting.ledger_transaction_id,
+                    ManualTransactionParams {
+                        journal_id: self.journal_id,
+                        description: posting.description,
+                        entry_params: posting.entries.iter().map(Into::into).collect(),
+                        effective: posting.effective,
+                        initiated_by: posting.initiated_by,
+                        reverses: None,
+                    },
+                )
+                .await
+            {
+                Ok(()) => {
+                    ledger_op.commit().await?;
+                    manual_transaction.post()?
+                }
+                Err(e) if e.is_period_closed() => {
+                    drop(ledger_op);
+                    manual_transaction.expire(PERIOD_CLOSED_REASON.to_string())?
+                }
+                Err(e) => return Err(e.into()),
+            }
+        } else {
+            let sub = <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(
+                MANUAL_TRANSACTION_APPROVAL,
+            );
+            let reason = self
+                .governance
+                .find_approval_process_by_id(&sub, id)
+                .await?
+                .and_then(|process| process.denied_reason().map(str::to_string))
+                .unwrap_or_default();
+            manual_transaction.deny(reason)?
+        };
+
+        if concluded.did_execute() {
+            self.repo
+                .update_in_op(&mut op, &mut manual_transaction)
+                .await?;
+        }
+        op.commit().await?;
+
+        Ok(manual_transaction)
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "core_accounting.manual_transaction_approval.execute_reversal",
+        skip(self)
+    )]
+    #[es_entity::retry_on_concurrent_modification]
+    pub async fn execute_manual_transaction_reversal_approval(
+        &self,
+        id: impl es_entity::RetryableInto<ManualTransactionId>,
+        approved: bool,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        let id = id.into();
+        let mut manual_transaction = self.repo.find_by_id(id).await?;
+        let Ok(reversal) = manual_transaction.pending_reversal() else {
+            return Ok(manual_transaction);
+        };
+
+        let mut op = self.repo.begin_op().await?;
+        self.audit
+            .record_system_entry_in_op(
+                &mut op,
+                MANUAL_TRANSACTION_APPROVAL,
+                CoreAccountingObject::manual_transaction(id),
+                CoreAccountingAction::MANUAL_TRANSACTION_CONCLUDE_APPROVAL_PROCESS,
+            )
+            .await?;
+
+        let concluded = if approved {
+            let posted = self
+                .ledger
+                .find_posted(manual_transaction.ledger_transaction_id)
+                .await?;
+            let mut ledger_op = op.begin().await?;
+            match self
+                .ledger
+                .execute_in_op(
+                    &mut ledger_op,
+                    reversal.ledger_transaction_id,
+                    ManualTransactionParams {
+                        journal_id: self.journal_id,
+                        description: format!("Reversal of {}", manual_transaction.reference),
+                        entry_params: posted
+                            .entry_params
+                            .into_iter()
+                            .map(|entry| entry.reversed())
+                            .collect(),
+                        effective: reversal.effective,
+                        initiated_by: reversal.requested_by,
+                        reverses: Some(manual_transaction.ledger_transaction_id),
+                    },
+                )
+                .await
+            {
+                Ok(()) => {
+                    ledger_op.commit().await?;
+                    manual_transaction.reverse()?.did_execute()
+                }
+                Err(e) if e.is_period_closed() => {
+                    drop(ledger_op);
+                    manual_transaction
+                        .deny_reversal(REVERSAL_PERIOD_CLOSED_REASON.to_string())?
+                        .did_execute()
+                }
+                Err(e) => return Err(e.into()),
+            }
+        } else {
+            let sub = <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(
+                MANUAL_TRANSACTION_APPROVAL,
+            );
+            let reason = self
+                .governance
+                .find_approval_process_by_id(&sub, reversal.approval_process_id)
+                .await?
+                .and_then(|process| process.denied_reason().map(str::to_string))
+                .unwrap_or_default();
+            manual_transaction.deny_reversal(reason)?.did_execute()
+        };
+
+        if concluded {
+            self.repo
+                .update_in_op(&mut op, &mut manual_transaction)
+                .await?;
+        }
+        op.commit().await?;
+
+        Ok(manual_transaction)
+    }
+}
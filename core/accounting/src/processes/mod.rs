@@ -0,0 +1 @@
+pub mod manual_transaction_approval;
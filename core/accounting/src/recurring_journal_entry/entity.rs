@@ -0,0 +1,649 @@
+use chrono::NaiveDate;
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use cala_ledger::Currency;
+use es_entity::*;
+use rust_decimal::Decimal;
+
+use crate::primitives::{ChartId, LedgerAccountId, ManualTransactionId, RecurringJournalEntryId};
+
+use super::{error::RecurringJournalEntryError, primitives::*};
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "RecurringJournalEntryId")]
+pub enum RecurringJournalEntryEvent {
+    Initialized {
+        id: RecurringJournalEntryId,
+        chart_id: ChartId,
+        reference: String,
+        description: String,
+        debit_account_id: LedgerAccountId,
+        credit_account_id: LedgerAccountId,
+        currency: Currency,
+        amount: Decimal,
+        amount_schedule: Vec<ScheduledAmount>,
+        frequency: RecurringJournalEntryFrequency,
+        start_date: NaiveDate,
+        end_date: Option<NaiveDate>,
+        created_by: String,
+    },
+    Paused {
+        paused_on: NaiveDate,
+        paused_by: String,
+    },
+    Resumed {
+        resumed_on: NaiveDate,
+        resumed_by: String,
+    },
+    OccurrenceSkipped {
+        date: NaiveDate,
+        skipped_by: String,
+    },
+    OccurrenceSubmitted {
+        date: NaiveDate,
+        manual_transaction_id: ManualTransactionId,
+    },
+    OccurrenceFailed {
+        date: NaiveDate,
+        reason: String,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct RecurringJournalEntry {
+    pub id: RecurringJournalEntryId,
+    pub chart_id: ChartId,
+    pub reference: String,
+    pub description: String,
+    pub debit_account_id: LedgerAccountId,
+    pub credit_account_id: LedgerAccountId,
+    pub currency: Currency,
+    pub amount: Decimal,
+    pub amount_schedule: Vec<ScheduledAmount>,
+    pub frequency: RecurringJournalEntryFrequency,
+    pub start_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+    events: EntityEvents<RecurringJournalEntryEvent>,
+}
+
+impl RecurringJournalEntry {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for recurring journal entry")
+    }
+
+    pub fn status(&self) -> RecurringJournalEntryStatus {
+        if self.paused_on().is_some() {
+            return RecurringJournalEntryStatus::Paused;
+        }
+        match self.end_date {
+            Some(end_date)
+                if self
+                    .occurrence_dates()
+                    .take_while(|date| *date <= end_date)
+                    .all(|date| {
+                        self.is_settled(date) || self.is_skipped(date) || self.is_suppressed(date)
+                    }) =>
+            {
+                RecurringJournalEntryStatus::Completed
+            }
+            _ => RecurringJournalEntryStatus::Active,
+        }
+    }
+
+    /// The date the entry was paused on if it is currently paused.
+    pub fn paused_on(&self) -> Option<NaiveDate> {
+        self.events.iter_all().rev().find_map(|e| match e {
+            RecurringJournalEntryEvent::Paused { paused_on, .. } => Some(Some(*paused_on)),
+            RecurringJournalEntryEvent::Resumed { .. } => Some(None),
+            _ => None,
+        })?
+    }
+
+    /// The amount posted for an occurrence on `date`, taking the latest step
+    /// of the amount schedule that has started by then.
+    pub fn amount_on(&self, date: NaiveDate) -> Decimal {
+        self.amount_schedule
+            .iter()
+            .filter(|step| step.starting_on <= date)
+            .max_by_key(|step| step.starting_on)
+            .map_or(self.amount, |step| step.amount)
+    }
+
+    pub fn is_occurrence_date(&self, date: NaiveDate) -> bool {
+        self.occurrence_dates()
+            .take_while(|d| *d <= date)
+            .any(|d| d == date)
+    }
+
+    /// Occurrences up to and including `as_of` that still have to be posted.
+    pub fn due_occurrences(&self, as_of: NaiveDate) -> Vec<RecurringJournalEntryOccurrence> {
+        self.occurrence_dates()
+            .take_while(|date| *date <= as_of)
+            .filter(|date| {
+                !self.is_settled(*date) && !self.is_suppressed(*date) && !self.is_skipped(*date)
+            })
+            .map(|date| self.occurrence(date))
+            .collect()
+    }
+
+    /// Preview of the next `limit` occurrences on or after `from` that have
+    /// not been posted yet. Skipped occurrences are included and flagged, as
+    /// are occurrences that fall after a pause that is still in effect.
+    pub fn upcoming_occurrences(
+        &self,
+        from: NaiveDate,
+        limit: usize,
+    ) -> Vec<RecurringJournalEntryOccurrence> {
+        self.occurrence_dates()
+            .skip_while(|date| *date < from)
+            .filter(|date| !self.is_settled(*date) && !self.is_suppressed_by_past_pause(*date))
+            .take(limit)
+            .map(|date| self.occurrence(date))
+            .collect()
+    }
+
+    pub fn submitted_manual_transaction_ids(&self) -> Vec<ManualTransactionId> {
+        self.events
+            .iter_all()
+            .filter_map(|e| match e {
+                RecurringJournalEntryEvent::OccurrenceSubmitted {
+                    manual_transaction_id,
+                    ..
+                } => Some(*manual_transaction_id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn pause(
+        &mut self,
+        paused_on: NaiveDate,
+        paused_by: impl Into<String>,
+    ) -> Result<Idempotent<()>, RecurringJournalEntryError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            RecurringJournalEntryEvent::Paused { .. },
+            => RecurringJournalEntryEvent::Resumed { .. }
+        );
+        if self.status() == RecurringJournalEntryStatus::Completed {
+            return Err(RecurringJournalEntryError::AlreadyCompleted(self.id));
+        }
+
+        self.events.push(RecurringJournalEntryEvent::Paused {
+            paused_on,
+            paused_by: paused_by.into(),
+        });
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn resume(
+        &mut self,
+        resumed_on: NaiveDate,
+        resumed_by: impl Into<String>,
+    ) -> Result<Idempotent<()>, RecurringJournalEntryError> {
+        if self.paused_on().is_none() {
+            return Ok(Idempotent::AlreadyApplied);
+        }
+
+        self.events.push(RecurringJournalEntryEvent::Resumed {
+            resumed_on,
+            resumed_by: resumed_by.into(),
+        });
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn skip_occurrence(
+        &mut self,
+        date: NaiveDate,
+        skipped_by: impl Into<String>,
+    ) -> Result<Idempotent<()>, RecurringJournalEntryError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            RecurringJournalEntryEvent::OccurrenceSkipped { date: skipped, .. } if *skipped == date
+        );
+        if !self.is_occurrence_date(date) {
+            return Err(RecurringJournalEntryError::NotAnOccurrence(date));
+        }
+        if self.is_settled(date) {
+            return Err(RecurringJournalEntryError::OccurrenceAlreadyPosted(date));
+        }
+
+        self.events
+            .push(RecurringJournalEntryEvent::OccurrenceSkipped {
+                date,
+                skipped_by: skipped_by.into(),
+            });
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn record_occurrence_submitted(
+        &mut self,
+        date: NaiveDate,
+        manual_transaction_id: ManualTransactionId,
+    ) -> Result<Idempotent<()>, RecurringJournalEntryError> {
+        if self.is_settled(date) {
+            return Ok(Idempotent::AlreadyApplied);
+        }
+        if self.is_skipped(date) {
+            return Err(RecurringJournalEntryError::OccurrenceSkipped(date));
+        }
+
+        self.events
+            .push(RecurringJournalEntryEvent::OccurrenceSubmitted {
+                date,
+                manual_transaction_id,
+            });
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn record_occurrence_failed(
+        &mut self,
+        date: NaiveDate,
+        reason: impl Into<String>,
+    ) -> Result<Idempotent<()>, RecurringJournalEntryError> {
+        if self.is_settled(date) {
+            return Ok(Idempotent::AlreadyApplied);
+        }
+
+        self.events
+            .push(RecurringJournalEntryEvent::OccurrenceFailed {
+                date,
+                reason: reason.into(),
+            });
+        Ok(Idempotent::Executed(()))
+    }
+
+    fn occurrence(&self, date: NaiveDate) -> RecurringJournalEntryOccurrence {
+        RecurringJournalEntryOccurrence {
+            date,
+            amount: self.amount_on(date),
+            skipped: self.is_skipped(date),
+        }
+    }
+
+    fn occurrence_dates(&self) -> impl Iterator<Item = NaiveDate> + '_ {
+        (0..)
+            .map_while(|n| self.frequency.nth_occurrence(self.start_date, n))
+            .take_while(|date| self.end_date.is_none_or(|end_date| *date <= end_date))
+    }
+
+    /// Whether the occurrence has been handed to the ledger, successfully
+    /// or not.
+    fn is_settled(&self, date: NaiveDate) -> bool {
+        self.events.iter_all().any(|e| match e {
+            RecurringJournalEntryEvent::OccurrenceSubmitted { date: d, .. }
+            | RecurringJournalEntryEvent::OccurrenceFailed { date: d, .. } => *d == date,
+            _ => false,
+        })
+    }
+
+    fn is_skipped(&self, date: NaiveDate) -> bool {
+        self.events.iter_all().any(|e| {
+            matches!(e, RecurringJournalEntryEvent::OccurrenceSkipped { date: d, .. } if *d == date)
+        })
+    }
+
+    /// Whether the occurrence fell due while the entry was paused. Such
+    /// occurrences are dropped rather than caught up on resumption.
+    fn is_suppressed(&self, date: NaiveDate) -> bool {
+        self.pauses().any(|(paused_on, resumed_on)| {
+            paused_on <= date && resumed_on.is_none_or(|resumed_on| date < resumed_on)
+        })
+    }
+
+    fn is_suppressed_by_past_pause(&self, date: NaiveDate) -> bool {
+        self.pauses().any(|(paused_on, resumed_on)| {
+            resumed_on.is_some_and(|resumed_on| paused_on <= date && date < resumed_on)
+        })
+    }
+
+    fn pauses(&self) -> impl Iterator<Item = (NaiveDate, Option<NaiveDate>)> + '_ {
+        let mut paused_on = None;
+        self.events
+            .iter_all()
+            .filter_map(move |e| match e {
+                RecurringJournalEntryEvent::Paused { paused_on: p, .. } => {
+                    paused_on = Some(*p);
+                    None
+                }
+                RecurringJournalEntryEvent::Resumed { resumed_on, .. } => {
+                    paused_on.take().map(|p| (p, Some(*resumed_on)))
+                }
+                _ => None,
+            })
+            .chain(self.paused_on().map(|p| (p, None)))
+    }
+}
+
+impl TryFromEvents<RecurringJournalEntryEvent> for RecurringJournalEntry {
+    fn try_from_events(
+        events: EntityEvents<RecurringJournalEntryEvent>,
+    ) -> Result<Self, EsEntityError> {
+        let mut builder = RecurringJournalEntryBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                RecurringJournalEntryEvent::Initialized {
+                    id,
+                    chart_id,
+                    reference,
+                    description,
+                    debit_account_id,
+                    credit_account_id,
+                    currency,
+                    amount,
+                    amount_schedule,
+                    frequency,
+                    start_date,
+                    end_date,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .chart_id(*chart_id)
+                        .reference(reference.clone())
+                        .description(description.clone())
+                        .debit_account_id(*debit_account_id)
+                        .credit_account_id(*credit_account_id)
+                        .currency(*currency)
+                        .amount(*amount)
+                        .amount_schedule(amount_schedule.clone())
+                        .frequency(*frequency)
+                        .start_date(*start_date)
+                        .end_date(*end_date)
+                }
+                RecurringJournalEntryEvent::Paused { .. }
+                | RecurringJournalEntryEvent::Resumed { .. }
+                | RecurringJournalEntryEvent::OccurrenceSkipped { .. }
+                | RecurringJournalEntryEvent::OccurrenceSubmitted { .. }
+                | RecurringJournalEntryEvent::OccurrenceFailed { .. } => {}
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct NewRecurringJournalEntry {
+    #[builder(setter(into))]
+    pub(super) id: RecurringJournalEntryId,
+    pub(super) chart_id: ChartId,
+    #[builder(setter(into))]
+    pub(super) reference: String,
+    #[builder(setter(into))]
+    description: String,
+    debit_account_id: LedgerAccountId,
+    credit_account_id: LedgerAccountId,
+    currency: Currency,
+    amount: Decimal,
+    #[builder(default)]
+    amount_schedule: Vec<ScheduledAmount>,
+    frequency: RecurringJournalEntryFrequency,
+    start_date: NaiveDate,
+    #[builder(default)]
+    end_date: Option<NaiveDate>,
+    #[builder(setter(into))]
+    created_by: String,
+}
+
+impl NewRecurringJournalEntry {
+    pub fn builder() -> NewRecurringJournalEntryBuilder {
+        NewRecurringJournalEntryBuilder::default()
+    }
+}
+
+impl NewRecurringJournalEntryBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if let (Some(start_date), Some(Some(end_date))) = (self.start_date, self.end_date)
+            && end_date < start_date
+        {
+            return Err("end date must not be before start date".to_string());
+        }
+        let amounts = self.amount.iter().chain(
+            self.amount_schedule
+                .iter()
+                .flatten()
+                .map(|step| &step.amount),
+        );
+        if amounts.into_iter().any(|amount| *amount <= Decimal::ZERO) {
+            return Err("amounts must be positive".to_string());
+        }
+        if self.debit_account_id.is_some() && self.debit_account_id == self.credit_account_id {
+            return Err("debit and credit account must differ".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl IntoEvents<RecurringJournalEntryEvent> for NewRecurringJournalEntry {
+    fn into_events(self) -> EntityEvents<RecurringJournalEntryEvent> {
+        EntityEvents::init(
+            self.id,
+            [RecurringJournalEntryEvent::Initialized {
+                id: self.id,
+                chart_id: self.chart_id,
+                reference: self.reference,
+                description: self.description,
+                debit_account_id: self.debit_account_id,
+                credit_account_id: self.credit_account_id,
+                currency: self.currency,
+                amount: self.amount,
+                amount_schedule: self.amount_schedule,
+                frequency: self.frequency,
+                start_date: self.start_date,
+                end_date: self.end_date,
+                created_by: self.created_by,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    fn monthly_rent(end_date: Option<NaiveDate>) -> RecurringJournalEntry {
+        let new_entry = NewRecurringJournalEntry::builder()
+            .id(RecurringJournalEntryId::new())
+            .chart_id(ChartId::new())
+            .reference("rent")
+            .description("Monthly rent accrual")
+            .debit_account_id(LedgerAccountId::new())
+            .credit_account_id(LedgerAccountId::new())
+            .currency(Currency::USD)
+            .amount(dec!(1000))
+            .amount_schedule(vec![ScheduledAmount {
+                starting_on: date("2021-04-01"),
+                amount: dec!(1100),
+            }])
+            .frequency(RecurringJournalEntryFrequency::Monthly)
+            .start_date(date("2021-01-31"))
+            .end_date(end_date)
+            .created_by("maker")
+            .build()
+            .unwrap();
+        RecurringJournalEntry::try_from_events(new_entry.into_events()).unwrap()
+    }
+
+    #[test]
+    fn monthly_occurrences_clamp_to_end_of_month() {
+        let entry = monthly_rent(None);
+        let dates: Vec<_> = entry
+            .upcoming_occurrences(date("2021-01-01"), 4)
+            .into_iter()
+            .map(|o| o.date)
+            .collect();
+        assert_eq!(
+            dates,
+            vec![
+                date("2021-01-31"),
+                date("2021-02-28"),
+                date("2021-03-31"),
+                date("2021-04-30")
+            ]
+        );
+    }
+
+    #[test]
+    fn amount_follows_schedule() {
+        let entry = monthly_rent(None);
+        assert_eq!(entry.amount_on(date("2021-03-31")), dec!(1000));
+        assert_eq!(entry.amount_on(date("2021-04-30")), dec!(1100));
+    }
+
+    #[test]
+    fn submitted_and_skipped_occurrences_are_not_due() {
+        let mut entry = monthly_rent(None);
+        assert_eq!(entry.due_occurrences(date("2021-03-31")).len(), 3);
+
+        assert!(
+            entry
+                .record_occurrence_submitted(date("2021-01-31"), ManualTransactionId::new())
+                .unwrap()
+                .did_execute()
+        );
+        assert!(
+            entry
+                .skip_occurrence(date("2021-02-28"), "maker")
+                .unwrap()
+                .did_execute()
+        );
+        assert!(
+            entry
+                .skip_occurrence(date("2021-02-28"), "maker")
+                .unwrap()
+                .was_already_applied()
+        );
+
+        let due = entry.due_occurrences(date("2021-03-31"));
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].date, date("2021-03-31"));
+
+        let upcoming = entry.upcoming_occurrences(date("2021-02-01"), 2);
+        assert!(upcoming[0].skipped);
+        assert!(!upcoming[1].skipped);
+    }
+
+    #[test]
+    fn cannot_skip_a_date_that_is_not_an_occurrence() {
+        let mut entry = monthly_rent(None);
+        assert!(matches!(
+            entry.skip_occurrence(date("2021-02-15"), "maker"),
+            Err(RecurringJournalEntryError::NotAnOccurrence(_))
+        ));
+    }
+
+    #[test]
+    fn occurrences_while_paused_are_dropped() {
+        let mut entry = monthly_rent(None);
+        assert!(
+            entry
+                .pause(date("2021-02-01"), "maker")
+                .unwrap()
+                .did_execute()
+        );
+        assert!(
+            entry
+                .pause(date("2021-02-02"), "maker")
+                .unwrap()
+                .was_already_applied()
+        );
+        assert_eq!(entry.status(), RecurringJournalEntryStatus::Paused);
+
+        let due: Vec<_> = entry
+            .due_occurrences(date("2021-03-15"))
+            .into_iter()
+            .map(|o| o.date)
+            .collect();
+        assert_eq!(due, vec![date("2021-01-31")]);
+
+        assert!(
+            entry
+                .resume(date("2021-03-15"), "maker")
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(entry.status(), RecurringJournalEntryStatus::Active);
+        let due: Vec<_> = entry
+            .due_occurrences(date("2021-03-31"))
+            .into_iter()
+            .map(|o| o.date)
+            .collect();
+        assert_eq!(due, vec![date("2021-01-31"), date("2021-03-31")]);
+    }
+
+    #[test]
+    fn completes_once_last_occurrence_is_posted() {
+        let mut entry = monthly_rent(Some(date("2021-02-28")));
+        assert_eq!(entry.status(), RecurringJournalEntryStatus::Active);
+        for occurrence in entry.due_occurrences(date("2021-12-31")) {
+            let _ = entry
+                .record_occurrence_submitted(occurrence.date, ManualTransactionId::new())
+                .unwrap();
+        }
+        assert_eq!(entry.status(), RecurringJournalEntryStatus::Completed);
+        assert!(
+            entry
+                .upcoming_occurrences(date("2021-01-01"), 10)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn schedule_ends_at_last_representable_date() {
+        let start_date = NaiveDate::MAX
+            .checked_sub_months(chrono::Months::new(2))
+            .unwrap();
+        let new_entry = NewRecurringJournalEntry::builder()
+            .id(RecurringJournalEntryId::new())
+            .chart_id(ChartId::new())
+            .reference("rent")
+            .description("Monthly rent accrual")
+            .debit_account_id(LedgerAccountId::new())
+            .credit_account_id(LedgerAccountId::new())
+            .currency(Currency::USD)
+            .amount(dec!(1000))
+            .frequency(RecurringJournalEntryFrequency::Monthly)
+            .start_date(start_date)
+            .end_date(None)
+            .created_by("maker")
+            .build()
+            .unwrap();
+        let entry = RecurringJournalEntry::try_from_events(new_entry.into_events()).unwrap();
+        assert_eq!(entry.upcoming_occurrences(start_date, 10).len(), 3);
+        assert_eq!(entry.due_occurrences(NaiveDate::MAX).len(), 3);
+    }
+
+    #[test]
+    fn end_date_before_start_date_is_rejected() {
+        let res = NewRecurringJournalEntry::builder()
+            .id(RecurringJournalEntryId::new())
+            .chart_id(ChartId::new())
+            .reference("rent")
+            .description("Monthly rent accrual")
+            .debit_account_id(LedgerAccountId::new())
+            .credit_account_id(LedgerAccountId::new())
+            .currency(Currency::USD)
+            .amount(dec!(1000))
+            .frequency(RecurringJournalEntryFrequency::Monthly)
+            .start_date(date("2021-01-31"))
+            .end_date(Some(date("2021-01-01")))
+            .created_by("maker")
+            .build();
+        assert!(res.is_err());
+    }
+}
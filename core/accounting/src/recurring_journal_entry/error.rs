@@ -0,0 +1,56 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+use crate::{chart_of_accounts, manual_transaction, primitives::RecurringJournalEntryId};
+
+#[derive(Error, Debug)]
+pub enum RecurringJournalEntryError {
+    #[error("RecurringJournalEntryError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("RecurringJournalEntryError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("RecurringJournalEntryError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("RecurringJournalEntryError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("RecurringJournalEntryError - AuditError: {0}")]
+    AuditError(#[from] audit::error::AuditError),
+    #[error("RecurringJournalEntryError - ChartOfAccounts: {0}")]
+    ChartOfAccountsError(#[from] chart_of_accounts::error::ChartOfAccountsError),
+    #[error("RecurringJournalEntryError - ManualTransactionError: {0}")]
+    ManualTransactionError(#[from] manual_transaction::error::ManualTransactionError),
+    #[error("RecurringJournalEntryError - NewRecurringJournalEntryBuilderError: {0}")]
+    NewRecurringJournalEntryBuilderError(
+        #[from] super::entity::NewRecurringJournalEntryBuilderError,
+    ),
+    #[error("RecurringJournalEntryError - NotAnOccurrence: {0} is not an occurrence date")]
+    NotAnOccurrence(chrono::NaiveDate),
+    #[error("RecurringJournalEntryError - OccurrenceAlreadyPosted: {0}")]
+    OccurrenceAlreadyPosted(chrono::NaiveDate),
+    #[error("RecurringJournalEntryError - OccurrenceSkipped: {0}")]
+    OccurrenceSkipped(chrono::NaiveDate),
+    #[error("RecurringJournalEntryError - AlreadyCompleted: {0}")]
+    AlreadyCompleted(RecurringJournalEntryId),
+}
+
+es_entity::from_es_entity_error!(RecurringJournalEntryError);
+
+impl ErrorSeverity for RecurringJournalEntryError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::Sqlx(_) => Level::ERROR,
+            Self::EsEntityError(e) => e.severity(),
+            Self::CursorDestructureError(_) => Level::ERROR,
+            Self::AuthorizationError(e) => e.severity(),
+            Self::AuditError(e) => e.severity(),
+            Self::ChartOfAccountsError(e) => e.severity(),
+            Self::ManualTransactionError(e) => e.severity(),
+            Self::NewRecurringJournalEntryBuilderError(_) => Level::WARN,
+            Self::NotAnOccurrence(_) => Level::WARN,
+            Self::OccurrenceAlreadyPosted(_) => Level::WARN,
+            Self::OccurrenceSkipped(_) => Level::WARN,
+            Self::AlreadyCompleted(_) => Level::WARN,
+        }
+    }
+}
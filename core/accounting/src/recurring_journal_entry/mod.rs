@@ -0,0 +1,389 @@
+mod entity;
+pub mod error;
+mod primitives;
+mod repo;
+
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use tracing::instrument;
+
+use audit::{AuditSvc, SystemSubject};
+use authz::PermissionCheck;
+use es_entity::clock::ClockHandle;
+use governance::GovernanceEvent;
+use obix::out::OutboxEventMarker;
+use tracing_macros::record_error_severity;
+
+use crate::{
+    chart_of_accounts::ChartOfAccounts,
+    manual_transaction::{
+        ManualTransactionEntry, ManualTransactions, error::ManualTransactionError,
+    },
+    primitives::{
+        CoreAccountingAction, CoreAccountingObject, DebitOrCredit, GovernanceAction,
+        GovernanceObject, RECURRING_JOURNAL_ENTRY_POSTING, RecurringJournalEntryId,
+    },
+};
+use error::*;
+
+pub use entity::RecurringJournalEntry;
+#[cfg(feature = "json-schema")]
+pub use entity::RecurringJournalEntryEvent;
+pub(super) use entity::*;
+pub use primitives::*;
+pub use repo::recurring_journal_entry_cursor::RecurringJournalEntriesByCreatedAtCursor;
+use repo::*;
+
+const POSTING_FAILED_REASON: &str = "The accounting period of the occurrence was already closed";
+
+pub struct RecurringJournalEntries<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    repo: RecurringJournalEntryRepo,
+    authz: Perms,
+    chart_of_accounts: ChartOfAccounts<Perms>,
+    manual_transactions: ManualTransactions<Perms, E>,
+    clock: ClockHandle,
+}
+
+impl<Perms, E> Clone for RecurringJournalEntries<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            repo: self.repo.clone(),
+            authz: self.authz.clone(),
+            chart_of_accounts: self.chart_of_accounts.clone(),
+            manual_transactions: self.manual_transactions.clone(),
+            clock: self.clock.clone(),
+        }
+    }
+}
+
+impl<Perms, E> RecurringJournalEntries<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    pub(crate) fn new(
+        pool: &sqlx::PgPool,
+        authz: &Perms,
+        chart_of_accounts: &ChartOfAccounts<Perms>,
+        manual_transactions: &ManualTransactions<Perms, E>,
+        clock: ClockHandle,
+    ) -> Self {
+        Self {
+            repo: RecurringJournalEntryRepo::new(pool, clock.clone()),
+            authz: authz.clone(),
+            chart_of_accounts: chart_of_accounts.clone(),
+            manual_transactions: manual_transactions.clone(),
+            clock,
+        }
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "core_accounting.recurring_journal_entry.create", skip(self, input), fields(subject = %sub, chart_ref = %chart_ref))]
+    pub async fn create(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        input: NewRecurringJournalEntryInput,
+    ) -> Result<RecurringJournalEntry, RecurringJournalEntryError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_recurring_journal_entries(),
+                CoreAccountingAction::RECURRING_JOURNAL_ENTRY_CREATE,
+            )
+            .await?;
+
+        let chart = self.chart_of_accounts.find_by_reference(chart_ref).await?;
+        let debit_account_id = self
+            .chart_of_accounts
+            .manual_transaction_account_id_for_account_id_or_code(
+                sub,
+                chart_ref,
+                input.debit_account,
+            )
+            .await?;
+        let credit_account_id = self
+            .chart_of_accounts
+            .manual_transaction_account_id_for_account_id_or_code(
+                sub,
+                chart_ref,
+                input.credit_account,
+            )
+            .await?;
+
+        let new_entry = NewRecurringJournalEntry::builder()
+            .id(RecurringJournalEntryId::new())
+            .chart_id(chart.id)
+            .reference(input.reference)
+            .description(input.description)
+            .debit_account_id(debit_account_id)
+            .credit_account_id(credit_account_id)
+            .currency(input.currency)
+            .amount(input.amount)
+            .amount_schedule(input.amount_schedule)
+            .frequency(input.frequency)
+            .start_date(input.start_date)
+            .end_date(input.end_date)
+            .created_by(sub.to_string())
+            .build()?;
+
+        self.repo.create(new_entry).await
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "core_accounting.recurring_journal_entry.find_by_id",
+        skip(self)
+    )]
+    pub async fn find_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<RecurringJournalEntryId> + std::fmt::Debug,
+    ) -> Result<Option<RecurringJournalEntry>, RecurringJournalEntryError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::recurring_journal_entry(id),
+                CoreAccountingAction::RECURRING_JOURNAL_ENTRY_READ,
+            )
+            .await?;
+
+        self.repo.maybe_find_by_id(id).await
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "core_accounting.recurring_journal_entry.list", skip(self))]
+    pub async fn list(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<RecurringJournalEntriesByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<
+            RecurringJournalEntry,
+            RecurringJournalEntriesByCreatedAtCursor,
+        >,
+        RecurringJournalEntryError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_recurring_journal_entries(),
+                CoreAccountingAction::RECURRING_JOURNAL_ENTRY_LIST,
+            )
+            .await?;
+
+        self.repo
+            .list_by_created_at(query, es_entity::ListDirection::Descending)
+            .await
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "core_accounting.recurring_journal_entry.find_all", skip(self))]
+    pub async fn find_all<T: From<RecurringJournalEntry>>(
+        &self,
+        ids: &[RecurringJournalEntryId],
+    ) -> Result<HashMap<RecurringJournalEntryId, T>, RecurringJournalEntryError> {
+        self.repo.find_all(ids).await
+    }
+
+    /// Preview of the next `limit` occurrences from today on.
+    pub fn upcoming_occurrences(
+        &self,
+        entry: &RecurringJournalEntry,
+        limit: usize,
+    ) -> Vec<RecurringJournalEntryOccurrence> {
+        entry.upcoming_occurrences(self.clock.today(), limit)
+    }
+
+    /// Stops occurrences from being posted from today on. Occurrences that
+    /// fall due while paused are dropped, not caught up after resuming.
+    #[record_error_severity]
+    #[instrument(name = "core_accounting.recurring_journal_entry.pause", skip(self))]
+    pub async fn pause(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<RecurringJournalEntryId> + std::fmt::Debug,
+    ) -> Result<RecurringJournalEntry, RecurringJournalEntryError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::recurring_journal_entry(id),
+                CoreAccountingAction::RECURRING_JOURNAL_ENTRY_UPDATE,
+            )
+            .await?;
+
+        let mut entry = self.repo.find_by_id(id).await?;
+        if entry
+            .pause(self.clock.today(), sub.to_string())?
+            .did_execute()
+        {
+            self.repo.update(&mut entry).await?;
+        }
+        Ok(entry)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "core_accounting.recurring_journal_entry.resume", skip(self))]
+    pub async fn resume(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<RecurringJournalEntryId> + std::fmt::Debug,
+    ) -> Result<RecurringJournalEntry, RecurringJournalEntryError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::recurring_journal_entry(id),
+                CoreAccountingAction::RECURRING_JOURNAL_ENTRY_UPDATE,
+            )
+            .await?;
+
+        let mut entry = self.repo.find_by_id(id).await?;
+        if entry
+            .resume(self.clock.today(), sub.to_string())?
+            .did_execute()
+        {
+            self.repo.update(&mut entry).await?;
+        }
+        Ok(entry)
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "core_accounting.recurring_journal_entry.skip_occurrence",
+        skip(self)
+    )]
+    pub async fn skip_occurrence(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<RecurringJournalEntryId> + std::fmt::Debug,
+        date: NaiveDate,
+    ) -> Result<RecurringJournalEntry, RecurringJournalEntryError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::recurring_journal_entry(id),
+                CoreAccountingAction::RECURRING_JOURNAL_ENTRY_UPDATE,
+            )
+            .await?;
+
+        let mut entry = self.repo.find_by_id(id).await?;
+        if entry.skip_occurrence(date, sub.to_string())?.did_execute() {
+            self.repo.update(&mut entry).await?;
+        }
+        Ok(entry)
+    }
+
+    /// Submits every occurrence that has fallen due by `day` as a manual
+    /// transaction, which then goes through the usual approval process.
+    #[record_error_severity]
+    #[instrument(
+        name = "core_accounting.recurring_journal_entry.post_due_occurrences",
+        skip(self)
+    )]
+    pub async fn post_due_occurrences(
+        &self,
+        day: NaiveDate,
+    ) -> Result<(), RecurringJournalEntryError> {
+        let mut query = Default::default();
+        loop {
+            let mut res = self
+                .repo
+                .list_by_created_at(query, es_entity::ListDirection::Ascending)
+                .await?;
+
+            for entry in std::mem::take(&mut res.entities) {
+                if entry.status() == RecurringJournalEntryStatus::Active {
+                    self.post_due_occurrences_for(entry, day).await?;
+                }
+            }
+
+            if let Some(q) = res.into_next_query() {
+                query = q;
+            } else {
+                break;
+            };
+        }
+        Ok(())
+    }
+
+    async fn post_due_occurrences_for(
+        &self,
+        mut entry: RecurringJournalEntry,
+        day: NaiveDate,
+    ) -> Result<(), RecurringJournalEntryError> {
+        let sub = <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(
+            RECURRING_JOURNAL_ENTRY_POSTING,
+        );
+        for occurrence in entry.due_occurrences(day) {
+            let mut op = self.repo.begin_op().await?;
+            self.authz
+                .audit()
+                .record_system_entry_in_op(
+                    &mut op,
+                    RECURRING_JOURNAL_ENTRY_POSTING,
+                    CoreAccountingObject::recurring_journal_entry(entry.id),
+                    CoreAccountingAction::RECURRING_JOURNAL_ENTRY_POST_OCCURRENCE,
+                )
+                .await?;
+
+            let entries = [
+                (entry.debit_account_id, DebitOrCredit::Debit),
+                (entry.credit_account_id, DebitOrCredit::Credit),
+            ]
+            .into_iter()
+            .map(|(account_id, direction)| ManualTransactionEntry {
+                account_id,
+                amount: occurrence.amount,
+                currency: entry.currency,
+                direction,
+                description: entry.description.clone(),
+            })
+            .collect();
+
+            let recorded = match self
+                .manual_transactions
+                .create_in_op(
+                    &mut op,
+                    &sub,
+                    Some(format!("{}-{}", entry.reference, occurrence.date)),
+                    entry.description.clone(),
+                    occurrence.date,
+                    entries,
+                )
+                .await
+            {
+                Ok(manual_transaction) => {
+                    entry.record_occurrence_submitted(occurrence.date, manual_transaction.id)?
+                }
+                Err(ManualTransactionError::ManualTransactionLedgerError(e))
+                    if e.is_period_closed() =>
+                {
+                    entry.record_occurrence_failed(occurrence.date, POSTING_FAILED_REASON)?
+                }
+                Err(e) => return Err(e.into()),
+            };
+            if recorded.did_execute() {
+                self.repo.update_in_op(&mut op, &mut entry).await?;
+            }
+            op.commit().await?;
+        }
+        Ok(())
+    }
+}
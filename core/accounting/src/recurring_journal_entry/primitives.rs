@@ -0,0 +1,72 @@
+use chrono::{Days, Months, NaiveDate};
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use cala_ledger::Currency;
+use rust_decimal::Decimal;
+
+use crate::primitives::AccountIdOrCode;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum RecurringJournalEntryFrequency {
+    Weekly,
+    Monthly,
+    Quarterly,
+    Yearly,
+}
+
+impl RecurringJournalEntryFrequency {
+    /// The date of the `n`th occurrence of a schedule starting on `start_date`.
+    /// Monthly based frequencies keep the day of month of the start date and
+    /// fall back to the last day of shorter months. Returns `None` once the
+    /// date is out of the representable range, which ends the schedule.
+    pub fn nth_occurrence(&self, start_date: NaiveDate, n: u32) -> Option<NaiveDate> {
+        match self {
+            Self::Weekly => start_date.checked_add_days(Days::new(7 * u64::from(n))),
+            Self::Monthly => start_date.checked_add_months(Months::new(n)),
+            Self::Quarterly => start_date.checked_add_months(Months::new(n.checked_mul(3)?)),
+            Self::Yearly => start_date.checked_add_months(Months::new(n.checked_mul(12)?)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum RecurringJournalEntryStatus {
+    Active,
+    Paused,
+    Completed,
+}
+
+/// Changes the amount posted from `starting_on` onwards, e.g. for a rent
+/// increase or a declining amortization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct ScheduledAmount {
+    pub starting_on: NaiveDate,
+    pub amount: Decimal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecurringJournalEntryOccurrence {
+    pub date: NaiveDate,
+    pub amount: Decimal,
+    pub skipped: bool,
+}
+
+pub struct NewRecurringJournalEntryInput {
+    pub reference: String,
+    pub description: String,
+    pub debit_account: AccountIdOrCode,
+    pub credit_account: AccountIdOrCode,
+    pub currency: Currency,
+    pub amount: Decimal,
+    pub amount_schedule: Vec<ScheduledAmount>,
+    pub frequency: RecurringJournalEntryFrequency,
+    pub start_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+}
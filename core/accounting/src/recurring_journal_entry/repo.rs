@@ -0,0 +1,28 @@
+use es_entity::clock::ClockHandle;
+use es_entity::*;
+use sqlx::PgPool;
+
+use crate::primitives::RecurringJournalEntryId;
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "RecurringJournalEntry",
+    err = "RecurringJournalEntryError",
+    columns(reference(ty = "String", update(persist = false))),
+    tbl_prefix = "core"
+)]
+pub struct RecurringJournalEntryRepo {
+    pool: PgPool,
+    clock: ClockHandle,
+}
+
+impl RecurringJournalEntryRepo {
+    pub fn new(pool: &PgPool, clock: ClockHandle) -> Self {
+        Self {
+            pool: pool.clone(),
+            clock,
+        }
+    }
+}
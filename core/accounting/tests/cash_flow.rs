@@ -0,0 +1,126 @@
+mod helpers;
+
+use authz::dummy::DummySubject;
+use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use chrono::{TimeZone, Utc};
+use cloud_storage::{Storage, config::StorageConfig};
+use document_storage::DocumentStorage;
+use es_entity::clock::{ArtificialClockConfig, ClockHandle};
+use job::{JobSvcConfig, Jobs};
+use rust_decimal::Decimal;
+
+use core_accounting::*;
+use helpers::{BASE_ACCOUNTS_CSV, action, default_accounting_base_config, object};
+
+#[tokio::test]
+async fn cash_flow_statement_reports_mapped_accounts() -> anyhow::Result<()> {
+    use rand::RngExt;
+
+    let pool = helpers::init_pool().await?;
+    let start_time = Utc.with_ymd_and_hms(2024, 6, 15, 12, 0, 0).unwrap();
+    let (clock, _ctrl) = ClockHandle::artificial(ArtificialClockConfig::manual_at(start_time));
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let journal_id = helpers::init_journal(&cala).await?;
+    let outbox = helpers::init_outbox(&pool).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let document_storage = DocumentStorage::new(&pool, &storage, clock.clone());
+    let mut jobs = Jobs::init(JobSvcConfig::builder().pool(pool.clone()).build().unwrap()).await?;
+
+    let governance = governance::Governance::new(&pool, &authz, &outbox, clock.clone());
+    let accounting = CoreAccounting::init(
+        &pool,
+        &authz,
+        &cala,
+        journal_id,
+        document_storage,
+        &mut jobs,
+        &outbox,
+        &governance,
+    )
+    .await?;
+
+    let chart_ref = format!("ref-{:010}", rand::rng().random_range(0..10_000_000_000u64));
+    accounting
+        .chart_of_accounts()
+        .create_chart(&DummySubject, "Test chart".to_string(), chart_ref.clone())
+        .await?;
+
+    let (balance_sheet_name, pl_name, trial_balance_name) =
+        helpers::create_test_statements(&accounting).await?;
+
+    let import = format!(
+        r#"{base}
+    11,,,Current Assets,,
+    ,01,,Cash,,
+    ,02,,Receivables,,
+    21,,,Payables,,
+    "#,
+        base = BASE_ACCOUNTS_CSV,
+    );
+    accounting
+        .import_csv_with_base_config(
+            &DummySubject,
+            &chart_ref,
+            import,
+            default_accounting_base_config(),
+            &balance_sheet_name,
+            &pl_name,
+            &trial_balance_name,
+        )
+        .await?;
+
+    let res = accounting
+        .chart_of_accounts()
+        .update_cash_flow_mapping(
+            &DummySubject,
+            &chart_ref,
+            vec![CashFlowMapping {
+                code: "4".parse().unwrap(),
+                category: CashFlowCategory::Operating,
+            }],
+        )
+        .await;
+    assert!(res.is_err());
+
+    let mapping = vec![
+        CashFlowMapping {
+            code: "11.01".parse().unwrap(),
+            category: CashFlowCategory::Cash,
+        },
+        CashFlowMapping {
+            code: "11.02".parse().unwrap(),
+            category: CashFlowCategory::Operating,
+        },
+        CashFlowMapping {
+            code: "21".parse().unwrap(),
+            category: CashFlowCategory::Financing,
+        },
+    ];
+    let chart = accounting
+        .chart_of_accounts()
+        .update_cash_flow_mapping(&DummySubject, &chart_ref, mapping.clone())
+        .await?;
+    assert_eq!(
+        chart.base_config.as_ref().unwrap().cash_flow_mapping,
+        mapping
+    );
+
+    let statement = accounting
+        .cash_flow_statements()
+        .cash_flow_statement(&DummySubject, &chart_ref, clock.today(), None)
+        .await?;
+    assert_eq!(statement.operating.lines.len(), 1);
+    assert_eq!(statement.operating.lines[0].code.to_string(), "11.02");
+    assert_eq!(statement.financing.lines.len(), 1);
+    assert!(statement.investing.lines.is_empty());
+    assert_eq!(statement.net_change_in_cash(), Decimal::ZERO);
+    assert_eq!(statement.unreconciled(), Decimal::ZERO);
+
+    Ok(())
+}
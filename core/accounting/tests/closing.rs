@@ -17,7 +17,9 @@ use cala_ledger::{
 };
 use core_accounting::{
     AccountIdOrCode, CalaTxId, Chart, ClosingTxDetails, CoreAccounting, LedgerAccountId,
-    ManualEntryInput, ProfitAndLossStatement, fiscal_year::FiscalYear, fiscal_year::FiscalYearRepo,
+    ManualEntryInput, ManualTransaction, ManualTransactionId, ManualTransactionStatus,
+    ProfitAndLossStatement, ReversalEffective, error::CoreAccountingError, fiscal_year::FiscalYear,
+    fiscal_year::FiscalYearRepo, manual_transaction::error::ManualTransactionError,
 };
 
 use helpers::{action, default_accounting_base_config, object};
@@ -35,6 +37,7 @@ const CHILD_ACCOUNT_SETS_CSV: &str = r#"
 "#;
 
 #[tokio::test]
+#[serial_test::file_serial(core_accounting_shared_jobs)]
 async fn post_closing_tx_with_gain() -> Result<()> {
     const EXPECTED_CREDIT_NORMAL_NET_INCOME: i32 = 100;
     let mut test = setup_test().await?;
@@ -125,10 +128,12 @@ async fn post_closing_tx_with_gain() -> Result<()> {
         Decimal::ZERO
     );
 
+    test.jobs.shutdown().await?;
     Ok(())
 }
 
 #[tokio::test]
+#[serial_test::file_serial(core_accounting_shared_jobs)]
 async fn post_closing_tx_with_loss() -> Result<()> {
     const EXPECTED_DEBIT_NORMAL_NET_INCOME: i32 = 100;
     let expected_credit_normal_net_income: i32 = -100;
@@ -219,6 +224,83 @@ async fn post_closing_tx_with_loss() -> Result<()> {
         profit_and_loss_normal_balance_after_close_tx.settled(),
         Decimal::ZERO
     );
+
+    test.jobs.shutdown().await?;
+    Ok(())
+}
+
+#[tokio::test]
+#[serial_test::file_serial(core_accounting_shared_jobs)]
+async fn reversal_respects_closed_months() -> Result<()> {
+    let mut test = setup_test().await?;
+
+    let manual_transaction_id = test.add_account_with_balance("4", 100, Credit).await;
+    assert_eq!(test.balance(REVENUES).await?, Decimal::from(100));
+
+    // Close January and February 2021
+    for _ in 0..2 {
+        test.fiscal_year = test
+            .accounting
+            .fiscal_year()
+            .close_month(&DummySubject, test.fiscal_year.id)
+            .await?;
+    }
+
+    let res = test
+        .accounting
+        .reverse_manual_transaction(
+            &DummySubject,
+            &test.chart.reference,
+            manual_transaction_id,
+            ReversalEffective::On("2021-02-15".parse().unwrap()),
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(CoreAccountingError::ManualTransactionError(
+            ManualTransactionError::PeriodClosed(_)
+        ))
+    ));
+    assert_eq!(test.balance(REVENUES).await?, Decimal::from(100));
+
+    let requested = test
+        .accounting
+        .reverse_manual_transaction(
+            &DummySubject,
+            &test.chart.reference,
+            manual_transaction_id,
+            ReversalEffective::NextOpenPeriod,
+        )
+        .await?;
+    assert_eq!(
+        requested.status(),
+        ManualTransactionStatus::ReversalPendingApproval
+    );
+    assert_eq!(test.balance(REVENUES).await?, Decimal::from(100));
+
+    let reversed = test.wait_for_reversal(manual_transaction_id).await?;
+    assert_eq!(
+        reversed.reversal_effective(),
+        Some("2021-03-01".parse::<NaiveDate>().unwrap())
+    );
+    assert_eq!(test.balance(REVENUES).await?, Decimal::ZERO);
+
+    let reversed_again = test
+        .accounting
+        .reverse_manual_transaction(
+            &DummySubject,
+            &test.chart.reference,
+            manual_transaction_id,
+            ReversalEffective::Today,
+        )
+        .await?;
+    assert_eq!(
+        reversed_again.reversal_ledger_transaction_id(),
+        reversed.reversal_ledger_transaction_id()
+    );
+    assert_eq!(test.balance(REVENUES).await?, Decimal::ZERO);
+
+    test.jobs.shutdown().await?;
     Ok(())
 }
 
@@ -241,7 +323,8 @@ async fn setup_test() -> anyhow::Result<Test> {
     let mut jobs = Jobs::init(JobSvcConfig::builder().pool(pool.clone()).build().unwrap()).await?;
 
     let fiscal_year_repo = FiscalYearRepo::new(&pool, clock.clone());
-    let accounting = CoreAccounting::new(
+    let governance = governance::Governance::new(&pool, &authz, &outbox, clock.clone());
+    let accounting = CoreAccounting::init(
         &pool,
         &authz,
         &cala,
@@ -249,7 +332,10 @@ async fn setup_test() -> anyhow::Result<Test> {
         document_storage,
         &mut jobs,
         &outbox,
-    );
+        &governance,
+    )
+    .await?;
+    jobs.start_poll().await?;
     let chart_ref = format!("ref-{:010}", rand::rng().random_range(0..10_000_000_000u64));
     let _ = accounting
         .chart_of_accounts()
@@ -286,6 +372,7 @@ async fn setup_test() -> anyhow::Result<Test> {
         fiscal_year_repo,
         accounts: vec![],
         pl_statement_name,
+        jobs,
     })
 }
 
@@ -300,6 +387,7 @@ struct Test {
     pub fiscal_year_repo: FiscalYearRepo,
     pub accounts: Vec<AccountId>,
     pub pl_statement_name: String,
+    pub jobs: Jobs,
 }
 
 impl Test {
@@ -308,7 +396,7 @@ impl Test {
         parent: &str,
         funds: i32,
         balance_type: DebitOrCredit,
-    ) {
+    ) -> ManualTransactionId {
         let account_id = AccountId::new();
         let _ = self
             .cala
@@ -348,7 +436,8 @@ impl Test {
             .opened_as_of
             .checked_add_days(Days::new(15))
             .unwrap();
-        self.accounting
+        let manual_transaction = self
+            .accounting
             .execute_manual_transaction(
                 &DummySubject,
                 &self.chart.reference,
@@ -376,8 +465,46 @@ impl Test {
             )
             .await
             .unwrap();
+        self.wait_for_posting(manual_transaction.id).await.unwrap();
 
         self.accounts.push(account_id);
+        manual_transaction.id
+    }
+
+    async fn wait_for_posting(&self, id: ManualTransactionId) -> Result<()> {
+        for _ in 0..100 {
+            let manual_transaction = self
+                .accounting
+                .manual_transactions()
+                .find_manual_transaction_by_id(&DummySubject, id)
+                .await?
+                .ok_or_else(|| anyhow!("manual transaction not found"))?;
+            match manual_transaction.status() {
+                ManualTransactionStatus::Posted => return Ok(()),
+                ManualTransactionStatus::PendingApproval => {}
+                status => return Err(anyhow!("manual transaction {status:?}")),
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+        Err(anyhow!("manual transaction not posted in time"))
+    }
+
+    async fn wait_for_reversal(&self, id: ManualTransactionId) -> Result<ManualTransaction> {
+        for _ in 0..100 {
+            let manual_transaction = self
+                .accounting
+                .manual_transactions()
+                .find_manual_transaction_by_id(&DummySubject, id)
+                .await?
+                .ok_or_else(|| anyhow!("manual transaction not found"))?;
+            match manual_transaction.status() {
+                ManualTransactionStatus::Reversed => return Ok(manual_transaction),
+                ManualTransactionStatus::ReversalPendingApproval => {}
+                status => return Err(anyhow!("manual transaction {status:?}")),
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+        Err(anyhow!("manual transaction not reversed in time"))
     }
 
     pub async fn balance(&self, code: &str) -> Result<Decimal> {
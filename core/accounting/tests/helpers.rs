@@ -61,10 +61,11 @@ pub async fn create_test_statements<Perms, E>(
 where
     Perms: authz::PermissionCheck,
     <<Perms as authz::PermissionCheck>::Audit as audit::AuditSvc>::Action:
-        From<core_accounting::CoreAccountingAction>,
+        From<core_accounting::CoreAccountingAction> + From<governance::GovernanceAction>,
     <<Perms as authz::PermissionCheck>::Audit as audit::AuditSvc>::Object:
-        From<core_accounting::CoreAccountingObject>,
-    E: obix::out::OutboxEventMarker<core_accounting::CoreAccountingEvent>,
+        From<core_accounting::CoreAccountingObject> + From<governance::GovernanceObject>,
+    E: obix::out::OutboxEventMarker<core_accounting::CoreAccountingEvent>
+        + obix::out::OutboxEventMarker<governance::GovernanceEvent>,
 {
     let bs = format!("BS-{:010}", rand::rng().random_range(0..10_000_000_000u64));
     let pl = format!("PL-{:010}", rand::rng().random_range(0..10_000_000_000u64));
@@ -88,6 +89,7 @@ where
 
 pub mod action {
     use core_accounting::CoreAccountingAction;
+    use governance::GovernanceAction;
 
     #[derive(Clone, Copy, Debug, PartialEq)]
     pub struct DummyAction;
@@ -98,6 +100,12 @@ pub mod action {
         }
     }
 
+    impl From<GovernanceAction> for DummyAction {
+        fn from(_: GovernanceAction) -> Self {
+            Self
+        }
+    }
+
     impl std::fmt::Display for DummyAction {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             write!(f, "dummy")?;
@@ -116,6 +124,7 @@ pub mod action {
 
 pub mod object {
     use core_accounting::CoreAccountingObject;
+    use governance::GovernanceObject;
 
     #[derive(Clone, Copy, Debug, PartialEq)]
     pub struct DummyObject;
@@ -126,6 +135,12 @@ pub mod object {
         }
     }
 
+    impl From<GovernanceObject> for DummyObject {
+        fn from(_: GovernanceObject) -> Self {
+            Self
+        }
+    }
+
     impl std::fmt::Display for DummyObject {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             write!(f, "Dummy")?;
@@ -144,12 +159,14 @@ pub mod object {
 
 pub mod event {
     use core_accounting::CoreAccountingEvent;
+    use governance::GovernanceEvent;
     use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, Serialize, Deserialize, obix::OutboxEvent)]
+    #[derive(Debug, Serialize, Deserialize, obix::OutboxEvent)]
     #[serde(tag = "module")]
     pub enum TestEvent {
         Accounting(CoreAccountingEvent),
+        Governance(GovernanceEvent),
         #[serde(other)]
         Unknown,
     }
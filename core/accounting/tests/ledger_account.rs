@@ -31,7 +31,8 @@ async fn ledger_account_ancestors() -> anyhow::Result<()> {
     let document_storage = DocumentStorage::new(&pool, &storage, clock.clone());
     let mut jobs = Jobs::init(JobSvcConfig::builder().pool(pool.clone()).build().unwrap()).await?;
 
-    let accounting = CoreAccounting::new(
+    let governance = governance::Governance::new(&pool, &authz, &outbox, clock.clone());
+    let accounting = CoreAccounting::init(
         &pool,
         &authz,
         &cala,
@@ -39,7 +40,9 @@ async fn ledger_account_ancestors() -> anyhow::Result<()> {
         document_storage,
         &mut jobs,
         &outbox,
-    );
+        &governance,
+    )
+    .await?;
     let chart_ref = format!("ref-{:010}", rand::rng().random_range(0..10_000_000_000u64));
     accounting
         .chart_of_accounts()
@@ -179,7 +182,8 @@ async fn ledger_account_children() -> anyhow::Result<()> {
     let document_storage = DocumentStorage::new(&pool, &storage, clock.clone());
     let mut jobs = Jobs::init(JobSvcConfig::builder().pool(pool.clone()).build().unwrap()).await?;
 
-    let accounting = CoreAccounting::new(
+    let governance = governance::Governance::new(&pool, &authz, &outbox, clock.clone());
+    let accounting = CoreAccounting::init(
         &pool,
         &authz,
         &cala,
@@ -187,7 +191,9 @@ async fn ledger_account_children() -> anyhow::Result<()> {
         document_storage,
         &mut jobs,
         &outbox,
-    );
+        &governance,
+    )
+    .await?;
     let chart_ref = format!("ref-{:010}", rand::rng().random_range(0..10_000_000_000u64));
     accounting
         .chart_of_accounts()
@@ -294,7 +300,8 @@ async fn internal_account_contains_coa_account() -> anyhow::Result<()> {
     let document_storage = DocumentStorage::new(&pool, &storage, clock.clone());
     let mut jobs = Jobs::init(JobSvcConfig::builder().pool(pool.clone()).build().unwrap()).await?;
 
-    let accounting = CoreAccounting::new(
+    let governance = governance::Governance::new(&pool, &authz, &outbox, clock.clone());
+    let accounting = CoreAccounting::init(
         &pool,
         &authz,
         &cala,
@@ -302,7 +309,9 @@ async fn internal_account_contains_coa_account() -> anyhow::Result<()> {
         document_storage,
         &mut jobs,
         &outbox,
-    );
+        &governance,
+    )
+    .await?;
     let chart_ref = format!("ref-{:010}", rand::rng().random_range(0..10_000_000_000u64));
     accounting
         .chart_of_accounts()
@@ -11,7 +11,8 @@ use cala_ledger::{
     velocity::error::VelocityError,
 };
 use core_accounting::{
-    AccountIdOrCode, Chart, CoreAccounting, ManualEntryInput,
+    AccountIdOrCode, Chart, CoreAccounting, ManualEntryInput, ManualTransaction,
+    ManualTransactionId, ManualTransactionStatus, ReversalEffective,
     error::CoreAccountingError,
     manual_transaction::{
         error::ManualTransactionError, ledger::error::ManualTransactionLedgerError,
@@ -22,7 +23,7 @@ use rust_decimal_macros::dec;
 
 #[tokio::test]
 async fn manual_transaction_fails_for_unopened_fiscal_year() -> anyhow::Result<()> {
-    let (accounting, chart) = prepare_test().await?;
+    let (accounting, chart, _jobs) = prepare_test().await?;
 
     let to: AccountIdOrCode = "1".parse().unwrap();
     let from: AccountIdOrCode = "2".parse().unwrap();
@@ -71,8 +72,10 @@ async fn manual_transaction_fails_for_unopened_fiscal_year() -> anyhow::Result<(
 }
 
 #[tokio::test]
+#[serial_test::file_serial(core_accounting_shared_jobs)]
 async fn manual_transaction() -> anyhow::Result<()> {
-    let (accounting, chart) = prepare_test().await?;
+    let (accounting, chart, mut jobs) = prepare_test().await?;
+    jobs.start_poll().await?;
 
     let opened_as_of = "2021-01-01".parse::<chrono::NaiveDate>().unwrap();
     accounting
@@ -101,7 +104,7 @@ async fn manual_transaction() -> anyhow::Result<()> {
             .build()
             .unwrap(),
     ];
-    accounting
+    let manual_tx = accounting
         .execute_manual_transaction(
             &DummySubject,
             &chart.reference,
@@ -111,6 +114,8 @@ async fn manual_transaction() -> anyhow::Result<()> {
             entries,
         )
         .await?;
+    assert_eq!(manual_tx.status(), ManualTransactionStatus::PendingApproval);
+    wait_for_posting(&accounting, manual_tx.id).await?;
 
     let account = accounting
         .find_ledger_account_by_code(&DummySubject, &chart.reference, "2".to_string())
@@ -126,12 +131,15 @@ async fn manual_transaction() -> anyhow::Result<()> {
         dec!(100)
     );
 
+    jobs.shutdown().await?;
     Ok(())
 }
 
 #[tokio::test]
+#[serial_test::file_serial(core_accounting_shared_jobs)]
 async fn ledger_transactions_by_template_code() -> anyhow::Result<()> {
-    let (accounting, chart) = prepare_test().await?;
+    let (accounting, chart, mut jobs) = prepare_test().await?;
+    jobs.start_poll().await?;
 
     let opened_as_of = "2021-01-01".parse::<chrono::NaiveDate>().unwrap();
     accounting
@@ -171,20 +179,181 @@ async fn ledger_transactions_by_template_code() -> anyhow::Result<()> {
             entries,
         )
         .await?;
+    let manual_tx = wait_for_posting(&accounting, manual_tx.id).await?;
 
     let template_txs = accounting
         .ledger_transactions()
         .list_for_template_code(&DummySubject, "MANUAL_TRANSACTION_2", Default::default())
         .await?
         .entities;
-    assert!(template_txs.iter().any(|tx| tx.id == manual_tx.id));
+    assert!(
+        template_txs
+            .iter()
+            .any(|tx| tx.id == manual_tx.ledger_transaction_id)
+    );
+
+    jobs.shutdown().await?;
+    Ok(())
+}
+
+#[tokio::test]
+#[serial_test::file_serial(core_accounting_shared_jobs)]
+async fn manual_transaction_reversal() -> anyhow::Result<()> {
+    let (accounting, chart, mut jobs) = prepare_test().await?;
+    jobs.start_poll().await?;
+
+    let opened_as_of = "2021-01-01".parse::<chrono::NaiveDate>().unwrap();
+    accounting
+        .fiscal_year()
+        .init_for_chart(&DummySubject, opened_as_of, chart.id)
+        .await?;
+
+    let to: AccountIdOrCode = "1".parse().unwrap();
+    let from: AccountIdOrCode = "2".parse().unwrap();
+
+    let entries = vec![
+        ManualEntryInput::builder()
+            .account_id_or_code(to.clone())
+            .amount(dec!(100))
+            .currency(Currency::USD)
+            .direction(DebitOrCredit::Debit)
+            .description("test 1 debit")
+            .build()
+            .unwrap(),
+        ManualEntryInput::builder()
+            .account_id_or_code(from.clone())
+            .amount(dec!(100))
+            .currency(Currency::USD)
+            .direction(DebitOrCredit::Credit)
+            .description("test 1 credit")
+            .build()
+            .unwrap(),
+    ];
+    let manual_tx = accounting
+        .execute_manual_transaction(
+            &DummySubject,
+            &chart.reference,
+            None,
+            "Test transaction 1".to_string(),
+            None,
+            entries,
+        )
+        .await?;
+
+    let res = accounting
+        .reverse_manual_transaction(
+            &DummySubject,
+            &chart.reference,
+            manual_tx.id,
+            ReversalEffective::Today,
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(CoreAccountingError::ManualTransactionError(
+            ManualTransactionError::NotPosted(_)
+        ))
+    ));
+
+    let manual_tx = wait_for_posting(&accounting, manual_tx.id).await?;
+    let requested = accounting
+        .reverse_manual_transaction(
+            &DummySubject,
+            &chart.reference,
+            manual_tx.id,
+            ReversalEffective::Today,
+        )
+        .await?;
+    assert_eq!(
+        requested.status(),
+        ManualTransactionStatus::ReversalPendingApproval
+    );
+    assert_eq!(requested.reversal_ledger_transaction_id(), None);
+
+    let reversed = wait_for_reversal(&accounting, manual_tx.id).await?;
+
+    let reversal_tx = accounting
+        .ledger_transactions()
+        .find_by_id(
+            &DummySubject,
+            reversed
+                .reversal_ledger_transaction_id()
+                .expect("reversal not recorded"),
+        )
+        .await?
+        .expect("reversal transaction not found");
+    assert_eq!(reversal_tx.entries.len(), 2);
+    assert_eq!(reversal_tx.entries[0].direction, DebitOrCredit::Credit);
+    assert_eq!(reversal_tx.entries[1].direction, DebitOrCredit::Debit);
+
+    let account = accounting
+        .find_ledger_account_by_code(&DummySubject, &chart.reference, "2".to_string())
+        .await?
+        .unwrap();
+    assert_eq!(
+        account
+            .usd_balance_range
+            .expect("should have balance")
+            .close
+            .expect("balance missing")
+            .settled(),
+        dec!(0)
+    );
 
+    jobs.shutdown().await?;
     Ok(())
 }
 
+async fn wait_for_posting(
+    accounting: &CoreAccounting<
+        DummyPerms<action::DummyAction, object::DummyObject>,
+        helpers::event::TestEvent,
+    >,
+    id: ManualTransactionId,
+) -> anyhow::Result<ManualTransaction> {
+    for _ in 0..100 {
+        let manual_tx = accounting
+            .manual_transactions()
+            .find_manual_transaction_by_id(&DummySubject, id)
+            .await?
+            .expect("manual transaction not found");
+        match manual_tx.status() {
+            ManualTransactionStatus::Posted => return Ok(manual_tx),
+            ManualTransactionStatus::PendingApproval => {}
+            status => anyhow::bail!("manual transaction {status:?}"),
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+    anyhow::bail!("manual transaction not posted in time")
+}
+
+async fn wait_for_reversal(
+    accounting: &CoreAccounting<
+        DummyPerms<action::DummyAction, object::DummyObject>,
+        helpers::event::TestEvent,
+    >,
+    id: ManualTransactionId,
+) -> anyhow::Result<ManualTransaction> {
+    for _ in 0..100 {
+        let manual_tx = accounting
+            .manual_transactions()
+            .find_manual_transaction_by_id(&DummySubject, id)
+            .await?
+            .expect("manual transaction not found");
+        match manual_tx.status() {
+            ManualTransactionStatus::Reversed => return Ok(manual_tx),
+            ManualTransactionStatus::ReversalPendingApproval => {}
+            status => anyhow::bail!("manual transaction {status:?}"),
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+    anyhow::bail!("manual transaction not reversed in time")
+}
+
 async fn prepare_test() -> anyhow::Result<(
     CoreAccounting<DummyPerms<action::DummyAction, object::DummyObject>, helpers::event::TestEvent>,
     Chart,
+    Jobs,
 )> {
     use rand::RngExt;
     let pool = helpers::init_pool().await?;
@@ -202,7 +371,8 @@ async fn prepare_test() -> anyhow::Result<(
     let document_storage = DocumentStorage::new(&pool, &storage, clock.clone());
     let mut jobs = Jobs::init(JobSvcConfig::builder().pool(pool.clone()).build().unwrap()).await?;
 
-    let accounting = CoreAccounting::new(
+    let governance = governance::Governance::new(&pool, &authz, &outbox, clock.clone());
+    let accounting = CoreAccounting::init(
         &pool,
         &authz,
         &cala,
@@ -210,7 +380,9 @@ async fn prepare_test() -> anyhow::Result<(
         document_storage,
         &mut jobs,
         &outbox,
-    );
+        &governance,
+    )
+    .await?;
     let chart_ref = format!("ref-{:010}", rand::rng().random_range(0..10_000_000_000u64));
     accounting
         .chart_of_accounts()
@@ -233,5 +405,5 @@ async fn prepare_test() -> anyhow::Result<(
         )
         .await?;
 
-    Ok((accounting, chart))
+    Ok((accounting, chart, jobs))
 }
@@ -0,0 +1,233 @@
+mod helpers;
+
+use authz::dummy::{DummyPerms, DummySubject};
+use cloud_storage::{Storage, config::StorageConfig};
+use document_storage::DocumentStorage;
+use es_entity::clock::{ArtificialClockConfig, ClockHandle};
+use job::{JobSvcConfig, Jobs};
+
+use cala_ledger::{CalaLedger, CalaLedgerConfig, Currency};
+use core_accounting::{
+    Chart, CoreAccounting, ManualTransactionId, ManualTransactionStatus,
+    recurring_journal_entry::{
+        NewRecurringJournalEntryInput, RecurringJournalEntryFrequency, RecurringJournalEntryStatus,
+        ScheduledAmount,
+    },
+};
+use helpers::{action, default_accounting_base_config, object};
+use rust_decimal_macros::dec;
+
+#[tokio::test]
+#[serial_test::file_serial(core_accounting_shared_jobs)]
+async fn recurring_journal_entry_posts_due_occurrences() -> anyhow::Result<()> {
+    let (accounting, chart, mut jobs) = prepare_test().await?;
+    jobs.start_poll().await?;
+
+    accounting
+        .fiscal_year()
+        .init_for_chart(
+            &DummySubject,
+            "2021-01-01".parse::<chrono::NaiveDate>()?,
+            chart.id,
+        )
+        .await?;
+
+    let recurring = accounting.recurring_journal_entries();
+    let entry = recurring
+        .create(
+            &DummySubject,
+            &chart.reference,
+            NewRecurringJournalEntryInput {
+                reference: format!("rent-{}", chart.reference),
+                description: "Monthly rent accrual".to_string(),
+                debit_account: "1".parse().unwrap(),
+                credit_account: "2".parse().unwrap(),
+                currency: Currency::USD,
+                amount: dec!(100),
+                amount_schedule: vec![ScheduledAmount {
+                    starting_on: "2021-03-01".parse().unwrap(),
+                    amount: dec!(150),
+                }],
+                frequency: RecurringJournalEntryFrequency::Monthly,
+                start_date: "2021-01-31".parse().unwrap(),
+                end_date: Some("2021-03-31".parse().unwrap()),
+            },
+        )
+        .await?;
+    let entry = recurring
+        .skip_occurrence(&DummySubject, entry.id, "2021-02-28".parse().unwrap())
+        .await?;
+    assert_eq!(
+        entry
+            .upcoming_occurrences("2021-01-01".parse().unwrap(), 5)
+            .len(),
+        3
+    );
+
+    recurring
+        .post_due_occurrences("2021-03-31".parse().unwrap())
+        .await?;
+    recurring
+        .post_due_occurrences("2021-03-31".parse().unwrap())
+        .await?;
+
+    let entry = recurring
+        .find_by_id(&DummySubject, entry.id)
+        .await?
+        .expect("recurring journal entry not found");
+    assert_eq!(entry.status(), RecurringJournalEntryStatus::Completed);
+    let manual_transaction_ids = entry.submitted_manual_transaction_ids();
+    assert_eq!(manual_transaction_ids.len(), 2);
+    for id in manual_transaction_ids {
+        wait_for_posting(&accounting, id).await?;
+    }
+
+    let account = accounting
+        .find_ledger_account_by_code(&DummySubject, &chart.reference, "2".to_string())
+        .await?
+        .unwrap();
+    assert_eq!(
+        account
+            .usd_balance_range
+            .expect("should have balance")
+            .close
+            .expect("balance missing")
+            .settled(),
+        dec!(250)
+    );
+
+    jobs.shutdown().await?;
+    Ok(())
+}
+
+#[tokio::test]
+#[serial_test::file_serial(core_accounting_shared_jobs)]
+async fn paused_recurring_journal_entry_is_not_posted() -> anyhow::Result<()> {
+    let (accounting, chart, mut jobs) = prepare_test().await?;
+    jobs.start_poll().await?;
+
+    accounting
+        .fiscal_year()
+        .init_for_chart(
+            &DummySubject,
+            "2021-01-01".parse::<chrono::NaiveDate>()?,
+            chart.id,
+        )
+        .await?;
+
+    let recurring = accounting.recurring_journal_entries();
+    let entry = recurring
+        .create(
+            &DummySubject,
+            &chart.reference,
+            NewRecurringJournalEntryInput {
+                reference: format!("depreciation-{}", chart.reference),
+                description: "Depreciation".to_string(),
+                debit_account: "1".parse().unwrap(),
+                credit_account: "2".parse().unwrap(),
+                currency: Currency::USD,
+                amount: dec!(10),
+                amount_schedule: vec![],
+                frequency: RecurringJournalEntryFrequency::Weekly,
+                start_date: "2021-01-04".parse().unwrap(),
+                end_date: None,
+            },
+        )
+        .await?;
+    let entry = recurring.pause(&DummySubject, entry.id).await?;
+    assert_eq!(entry.status(), RecurringJournalEntryStatus::Paused);
+
+    recurring
+        .post_due_occurrences("2021-01-31".parse().unwrap())
+        .await?;
+
+    let entry = recurring
+        .find_by_id(&DummySubject, entry.id)
+        .await?
+        .expect("recurring journal entry not found");
+    assert!(entry.submitted_manual_transaction_ids().is_empty());
+
+    jobs.shutdown().await?;
+    Ok(())
+}
+
+async fn wait_for_posting(
+    accounting: &CoreAccounting<
+        DummyPerms<action::DummyAction, object::DummyObject>,
+        helpers::event::TestEvent,
+    >,
+    id: ManualTransactionId,
+) -> anyhow::Result<()> {
+    for _ in 0..100 {
+        let manual_tx = accounting
+            .manual_transactions()
+            .find_manual_transaction_by_id(&DummySubject, id)
+            .await?
+            .expect("manual transaction not found");
+        match manual_tx.status() {
+            ManualTransactionStatus::Posted => return Ok(()),
+            ManualTransactionStatus::PendingApproval => {}
+            status => anyhow::bail!("manual transaction {status:?}"),
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+    anyhow::bail!("manual transaction not posted in time")
+}
+
+async fn prepare_test() -> anyhow::Result<(
+    CoreAccounting<DummyPerms<action::DummyAction, object::DummyObject>, helpers::event::TestEvent>,
+    Chart,
+    Jobs,
+)> {
+    use rand::RngExt;
+    let pool = helpers::init_pool().await?;
+    let (clock, _) = ClockHandle::artificial(ArtificialClockConfig::manual());
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let journal_id = helpers::init_journal(&cala).await?;
+    let outbox = helpers::init_outbox(&pool).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let document_storage = DocumentStorage::new(&pool, &storage, clock.clone());
+    let mut jobs = Jobs::init(JobSvcConfig::builder().pool(pool.clone()).build().unwrap()).await?;
+
+    let governance = governance::Governance::new(&pool, &authz, &outbox, clock.clone());
+    let accounting = CoreAccounting::init(
+        &pool,
+        &authz,
+        &cala,
+        journal_id,
+        document_storage,
+        &mut jobs,
+        &outbox,
+        &governance,
+    )
+    .await?;
+    let chart_ref = format!("ref-{:010}", rand::rng().random_range(0..10_000_000_000u64));
+    accounting
+        .chart_of_accounts()
+        .create_chart(&DummySubject, "Test chart".to_string(), chart_ref.clone())
+        .await?;
+
+    let (balance_sheet_name, pl_name, tb_name) =
+        helpers::create_test_statements(&accounting).await?;
+
+    let base_config = default_accounting_base_config();
+    let chart = accounting
+        .import_csv_with_base_config(
+            &DummySubject,
+            &chart_ref,
+            helpers::BASE_ACCOUNTS_CSV.to_string(),
+            base_config,
+            &balance_sheet_name,
+            &pl_name,
+            &tb_name,
+        )
+        .await?;
+
+    Ok((accounting, chart, jobs))
+}
@@ -31,7 +31,8 @@ async fn atomic_import_adds_accounts_to_trial_balance() -> anyhow::Result<()> {
     let document_storage = DocumentStorage::new(&pool, &storage, clock.clone());
     let mut jobs = Jobs::init(JobSvcConfig::builder().pool(pool.clone()).build().unwrap()).await?;
 
-    let accounting = CoreAccounting::new(
+    let governance = governance::Governance::new(&pool, &authz, &outbox, clock.clone());
+    let accounting = CoreAccounting::init(
         &pool,
         &authz,
         &cala,
@@ -39,7 +40,9 @@ async fn atomic_import_adds_accounts_to_trial_balance() -> anyhow::Result<()> {
         document_storage,
         &mut jobs,
         &outbox,
-    );
+        &governance,
+    )
+    .await?;
 
     let chart_ref = format!("ref-{:010}", rand::rng().random_range(0..10_000_000_000u64));
     accounting
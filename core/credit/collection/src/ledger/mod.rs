@@ -16,7 +16,8 @@ pub use error::CollectionLedgerError;
 use crate::{
     obligation::{
         ObligationDefaultedReallocationData, ObligationDueReallocationData,
-        ObligationOverdueReallocationData,
+        ObligationOverdueReallocationData, ObligationRescheduleReallocationData,
+        ObligationWriteOffReallocationData,
     },
     payment::Payment,
     payment_allocation::PaymentAllocation,
@@ -47,6 +48,9 @@ impl CollectionLedger {
         templates::RecordObligationDueBalance::init(cala).await?;
         templates::RecordObligationOverdueBalance::init(cala).await?;
         templates::RecordObligationDefaultedBalance::init(cala).await?;
+        templates::RescheduleObligationBalance::init(cala).await?;
+        templates::WriteOffObligationBalance::init(cala).await?;
+        templates::RecordObligationRecovery::init(cala).await?;
 
         Ok(Self {
             cala: cala.clone(),
@@ -229,6 +233,76 @@ impl CollectionLedger {
         Ok(())
     }
 
+    #[record_error_severity]
+    #[instrument(
+        name = "collection.ledger.record_obligation_reschedule_in_op",
+        skip(self, op)
+    )]
+    pub async fn record_obligation_reschedule_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        ObligationRescheduleReallocationData {
+            tx_id,
+            amount: outstanding_amount,
+            source_account_id,
+            not_yet_due_account_id,
+            effective,
+        }: ObligationRescheduleReallocationData,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), CollectionLedgerError> {
+        self.cala
+            .post_transaction_in_op(
+                op,
+                tx_id,
+                templates::RESCHEDULE_OBLIGATION_BALANCE_CODE,
+                templates::RescheduleObligationBalanceParams {
+                    journal_id: self.journal_id,
+                    amount: outstanding_amount.to_usd(),
+                    rescheduled_not_yet_due_account_id: not_yet_due_account_id,
+                    source_account_id,
+                    effective,
+                    initiated_by,
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "collection.ledger.record_obligation_write_off_in_op",
+        skip(self, op)
+    )]
+    pub async fn record_obligation_write_off_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        ObligationWriteOffReallocationData {
+            tx_id,
+            amount: outstanding_amount,
+            defaulted_account_id,
+            loan_loss_account_id,
+            effective,
+        }: ObligationWriteOffReallocationData,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), CollectionLedgerError> {
+        self.cala
+            .post_transaction_in_op(
+                op,
+                tx_id,
+                templates::WRITE_OFF_OBLIGATION_BALANCE_CODE,
+                templates::WriteOffObligationBalanceParams {
+                    journal_id: self.journal_id,
+                    amount: outstanding_amount.to_usd(),
+                    defaulted_account_id,
+                    loan_loss_account_id,
+                    effective,
+                    initiated_by,
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
     async fn record_obligation_repayment_in_op(
         &self,
         op: &mut es_entity::DbOp<'_>,
@@ -238,10 +312,33 @@ impl CollectionLedger {
             payment_holding_account_id,
             receivable_account_id,
             effective,
+            recovery,
             ..
         }: PaymentAllocation,
         initiated_by: &impl SystemSubject,
     ) -> Result<(), CollectionLedgerError> {
+        if recovery {
+            let params = templates::RecordObligationRecoveryParams {
+                journal_id: self.journal_id,
+                currency: self.usd,
+                amount: amount.to_usd(),
+                recovery_account_id: receivable_account_id,
+                payment_holding_account_id,
+                tx_ref: allocation.tx_ref(),
+                effective,
+                initiated_by,
+            };
+            self.cala
+                .post_transaction_in_op(
+                    op,
+                    ledger_tx_id,
+                    templates::RECORD_OBLIGATION_RECOVERY_CODE,
+                    params,
+                )
+                .await?;
+            return Ok(());
+        }
+
         let params = templates::RecordPaymentAllocationParams {
             journal_id: self.journal_id,
             currency: self.usd,
@@ -2,10 +2,16 @@ mod obligation_defaulted_balance;
 mod obligation_due_balance;
 mod obligation_overdue_balance;
 mod payment_allocation;
+mod record_obligation_recovery;
 mod record_payment;
+mod reschedule_obligation_balance;
+mod write_off_obligation_balance;
 
 pub use obligation_defaulted_balance::*;
 pub use obligation_due_balance::*;
 pub use obligation_overdue_balance::*;
 pub use payment_allocation::*;
+pub use record_obligation_recovery::*;
 pub use record_payment::*;
+pub use reschedule_obligation_balance::*;
+pub use write_off_obligation_balance::*;
@@ -0,0 +1,156 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+use tracing_macros::record_error_severity;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::primitives::CalaAccountId;
+
+use crate::ledger::error::CollectionLedgerError;
+
+pub const RECORD_OBLIGATION_RECOVERY_CODE: &str = "RECORD_OBLIGATION_RECOVERY";
+
+#[derive(Debug)]
+pub struct RecordObligationRecoveryParams<S: std::fmt::Display> {
+    pub journal_id: JournalId,
+    pub currency: Currency,
+    pub amount: Decimal,
+    pub payment_holding_account_id: CalaAccountId,
+    pub recovery_account_id: CalaAccountId,
+    pub tx_ref: String,
+    pub effective: chrono::NaiveDate,
+    pub initiated_by: S,
+}
+
+impl<S: std::fmt::Display> RecordObligationRecoveryParams<S> {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("external_id")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("currency")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("payment_holding_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("recovery_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("meta")
+                .r#type(ParamDataType::Json)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+impl<S: std::fmt::Display> From<RecordObligationRecoveryParams<S>> for Params {
+    fn from(
+        RecordObligationRecoveryParams {
+            journal_id,
+            currency,
+            amount,
+            payment_holding_account_id,
+            recovery_account_id,
+            tx_ref,
+            effective,
+            initiated_by,
+        }: RecordObligationRecoveryParams<S>,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("external_id", tx_ref);
+        params.insert("journal_id", journal_id);
+        params.insert("currency", currency);
+        params.insert("amount", amount);
+        params.insert("payment_holding_account_id", payment_holding_account_id);
+        params.insert("recovery_account_id", recovery_account_id);
+        params.insert("effective", effective);
+        params.insert(
+            "meta",
+            serde_json::json!({
+                "initiated_by": initiated_by.to_string(),
+            }),
+        );
+
+        params
+    }
+}
+
+pub struct RecordObligationRecovery;
+
+impl RecordObligationRecovery {
+    #[record_error_severity]
+    #[instrument(name = "collection.ledger.record_obligation_recovery.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), CollectionLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .external_id("params.external_id")
+            .metadata("params.meta")
+            .description("'Record a recovery on a written off obligation'")
+            .build()
+            .expect("Couldn't build TxInput");
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'RECORD_OBLIGATION_RECOVERY_DR'")
+                .currency("params.currency")
+                .account_id("params.payment_holding_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'RECORD_OBLIGATION_RECOVERY_CR'")
+                .currency("params.currency")
+                .account_id("params.recovery_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = RecordObligationRecoveryParams::<String>::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(RECORD_OBLIGATION_RECOVERY_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
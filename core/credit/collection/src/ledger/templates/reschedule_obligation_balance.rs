@@ -0,0 +1,145 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+use tracing_macros::record_error_severity;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::primitives::CalaAccountId;
+
+use crate::ledger::error::CollectionLedgerError;
+
+pub const RESCHEDULE_OBLIGATION_BALANCE_CODE: &str = "RESCHEDULE_OBLIGATION_BALANCE";
+
+#[derive(Debug)]
+pub struct RescheduleObligationBalanceParams<S: std::fmt::Display> {
+    pub journal_id: JournalId,
+    pub amount: Decimal,
+    pub rescheduled_not_yet_due_account_id: CalaAccountId,
+    pub source_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
+    pub initiated_by: S,
+}
+
+impl<S: std::fmt::Display> RescheduleObligationBalanceParams<S> {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("rescheduled_not_yet_due_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("source_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("meta")
+                .r#type(ParamDataType::Json)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+impl<S: std::fmt::Display> From<RescheduleObligationBalanceParams<S>> for Params {
+    fn from(
+        RescheduleObligationBalanceParams {
+            journal_id,
+            amount,
+            rescheduled_not_yet_due_account_id,
+            source_account_id,
+            effective,
+            initiated_by,
+        }: RescheduleObligationBalanceParams<S>,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("amount", amount);
+        params.insert(
+            "rescheduled_not_yet_due_account_id",
+            rescheduled_not_yet_due_account_id,
+        );
+        params.insert("source_account_id", source_account_id);
+        params.insert("effective", effective);
+        params.insert(
+            "meta",
+            serde_json::json!({
+                "initiated_by": initiated_by.to_string(),
+            }),
+        );
+
+        params
+    }
+}
+
+pub struct RescheduleObligationBalance;
+
+impl RescheduleObligationBalance {
+    #[record_error_severity]
+    #[instrument(
+        name = "collection.ledger.reschedule_obligation_balance.init",
+        skip_all
+    )]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), CollectionLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .metadata("params.meta")
+            .description("'Move a rescheduled obligation balance to not yet due'")
+            .build()
+            .expect("Couldn't build TxInput");
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'RESCHEDULE_OBLIGATION_BALANCE_CR'")
+                .currency("'USD'")
+                .account_id("params.source_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'RESCHEDULE_OBLIGATION_BALANCE_DR'")
+                .currency("'USD'")
+                .account_id("params.rescheduled_not_yet_due_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = RescheduleObligationBalanceParams::<String>::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(RESCHEDULE_OBLIGATION_BALANCE_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+use tracing_macros::record_error_severity;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::primitives::CalaAccountId;
+
+use crate::ledger::error::CollectionLedgerError;
+
+pub const WRITE_OFF_OBLIGATION_BALANCE_CODE: &str = "WRITE_OFF_OBLIGATION_BALANCE";
+
+#[derive(Debug)]
+pub struct WriteOffObligationBalanceParams<S: std::fmt::Display> {
+    pub journal_id: JournalId,
+    pub amount: Decimal,
+    pub defaulted_account_id: CalaAccountId,
+    pub loan_loss_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
+    pub initiated_by: S,
+}
+
+impl<S: std::fmt::Display> WriteOffObligationBalanceParams<S> {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("defaulted_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("loan_loss_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("meta")
+                .r#type(ParamDataType::Json)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+impl<S: std::fmt::Display> From<WriteOffObligationBalanceParams<S>> for Params {
+    fn from(
+        WriteOffObligationBalanceParams {
+            journal_id,
+            amount,
+            defaulted_account_id,
+            loan_loss_account_id,
+            effective,
+            initiated_by,
+        }: WriteOffObligationBalanceParams<S>,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("amount", amount);
+        params.insert("defaulted_account_id", defaulted_account_id);
+        params.insert("loan_loss_account_id", loan_loss_account_id);
+        params.insert("effective", effective);
+        params.insert(
+            "meta",
+            serde_json::json!({
+                "initiated_by": initiated_by.to_string(),
+            }),
+        );
+
+        params
+    }
+}
+
+pub struct WriteOffObligationBalance;
+
+impl WriteOffObligationBalance {
+    #[record_error_severity]
+    #[instrument(name = "collection.ledger.write_off_obligation_balance.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), CollectionLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .metadata("params.meta")
+            .description("'Write off a defaulted obligation balance'")
+            .build()
+            .expect("Couldn't build TxInput");
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'WRITE_OFF_OBLIGATION_BALANCE_CR'")
+                .currency("'USD'")
+                .account_id("params.defaulted_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'WRITE_OFF_OBLIGATION_BALANCE_DR'")
+                .currency("'USD'")
+                .account_id("params.loan_loss_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = WriteOffObligationBalanceParams::<String>::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(WRITE_OFF_OBLIGATION_BALANCE_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
@@ -19,19 +19,20 @@ use obix::out::OutboxEventMarker;
 
 pub use error::CoreCreditCollectionError;
 pub use obligation::{
-    NewObligation, Obligation, ObligationEvent, Obligations, error::ObligationError,
+    NewObligation, Obligation, ObligationEvent, ObligationLifecycleDates, Obligations,
+    error::ObligationError,
 };
 pub use payment::{Payment, PaymentEvent, PaymentLedgerAccountIds, Payments, error::PaymentError};
 pub use payment_allocation::{
     PaymentAllocation, PaymentAllocationEvent, error::PaymentAllocationError,
 };
 pub use primitives::{
-    BalanceUpdateData, BalanceUpdatedSource, BeneficiaryId, CalaAccountId,
+    AllocationStrategy, BalanceUpdateData, BalanceUpdatedSource, BeneficiaryId, CalaAccountId,
     CoreCreditCollectionAction, CoreCreditCollectionObject, ObligationAction, ObligationAllOrOne,
     ObligationId, ObligationReceivableAccountIds, ObligationStatus, ObligationType,
     ObligationsAmounts, PERMISSION_SET_COLLECTION_PAYMENT_DATE, PERMISSION_SET_COLLECTION_VIEWER,
     PERMISSION_SET_COLLECTION_WRITER, PaymentAllocationId, PaymentDetailsForAllocation, PaymentId,
-    PaymentSourceAccountId,
+    PaymentSourceAccountId, PaymentType, PenaltyAllocation,
 };
 pub use public::*;
 pub use publisher::CollectionPublisher;
@@ -35,6 +35,22 @@ pub(crate) struct ObligationDefaultedReallocationData {
     pub effective: chrono::NaiveDate,
 }
 
+pub(crate) struct ObligationWriteOffReallocationData {
+    pub tx_id: LedgerTxId,
+    pub amount: UsdCents,
+    pub defaulted_account_id: CalaAccountId,
+    pub loan_loss_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
+}
+
+pub(crate) struct ObligationRescheduleReallocationData {
+    pub tx_id: LedgerTxId,
+    pub amount: UsdCents,
+    pub source_account_id: CalaAccountId,
+    pub not_yet_due_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
+}
+
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -76,6 +92,19 @@ pub enum ObligationEvent {
     Completed {
         effective: chrono::NaiveDate,
     },
+    Rescheduled {
+        ledger_tx_id: LedgerTxId,
+        rescheduled_obligation_id: ObligationId,
+        rescheduled_amount: UsdCents,
+        effective: chrono::NaiveDate,
+    },
+    WrittenOff {
+        ledger_tx_id: LedgerTxId,
+        written_off_amount: UsdCents,
+        loan_loss_account_id: CalaAccountId,
+        recovery_account_id: CalaAccountId,
+        effective: chrono::NaiveDate,
+    },
 }
 
 #[derive(EsEntity, Builder)]
@@ -165,7 +194,21 @@ impl Obligation {
             .expect("Entity was not Initialized")
     }
 
+    /// Account crediting payments received after the obligation was written off.
+    pub fn recovery_account_id(&self) -> Option<CalaAccountId> {
+        self.events.iter_all().rev().find_map(|e| match e {
+            ObligationEvent::WrittenOff {
+                recovery_account_id,
+                ..
+            } => Some(*recovery_account_id),
+            _ => None,
+        })
+    }
+
     pub fn receivable_account_id(&self) -> Option<CalaAccountId> {
+        if self.status() == ObligationStatus::WrittenOff {
+            return self.recovery_account_id();
+        }
         self.events
             .iter_all()
             .find_map(|e| match e {
@@ -180,7 +223,10 @@ impl Obligation {
 
     fn expected_status(&self, now: DateTime<Utc>) -> ObligationStatus {
         let status = self.status();
-        if status == ObligationStatus::Paid {
+        if matches!(
+            status,
+            ObligationStatus::Paid | ObligationStatus::Rescheduled | ObligationStatus::WrittenOff
+        ) {
             return status;
         }
 
@@ -205,6 +251,8 @@ impl Obligation {
                 ObligationEvent::OverdueRecorded { .. } => Some(ObligationStatus::Overdue),
                 ObligationEvent::DefaultedRecorded { .. } => Some(ObligationStatus::Defaulted),
                 ObligationEvent::Completed { .. } => Some(ObligationStatus::Paid),
+                ObligationEvent::Rescheduled { .. } => Some(ObligationStatus::Rescheduled),
+                ObligationEvent::WrittenOff { .. } => Some(ObligationStatus::WrittenOff),
                 _ => None,
             })
             .unwrap_or(ObligationStatus::NotYetDue)
@@ -225,6 +273,10 @@ impl Obligation {
                     ObligationEvent::PaymentAllocated {
                         payment_allocation_amount: amount,
                         ..
+                    }
+                    | ObligationEvent::Rescheduled {
+                        rescheduled_amount: amount,
+                        ..
                     } => {
                         total_sum -= *amount;
                     }
@@ -334,6 +386,44 @@ impl Obligation {
         Ok(Idempotent::Executed(res))
     }
 
+    /// Moves the defaulted balance to the loan-loss account. The borrower still owes the
+    /// outstanding amount, payments allocated afterwards are booked as recoveries.
+    pub(crate) fn write_off(
+        &mut self,
+        loan_loss_account_id: CalaAccountId,
+        recovery_account_id: CalaAccountId,
+        effective: chrono::NaiveDate,
+    ) -> Result<Idempotent<ObligationWriteOffReallocationData>, ObligationError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            ObligationEvent::WrittenOff { .. }
+        );
+
+        match self.status() {
+            ObligationStatus::Defaulted => (),
+            ObligationStatus::Paid => return Ok(Idempotent::AlreadyApplied),
+            _ => return Err(ObligationError::InvalidStatusTransitionToWrittenOff),
+        }
+
+        let res = ObligationWriteOffReallocationData {
+            tx_id: LedgerTxId::new(),
+            amount: self.outstanding(),
+            defaulted_account_id: self.defaulted_account(),
+            loan_loss_account_id,
+            effective,
+        };
+
+        self.events.push(ObligationEvent::WrittenOff {
+            ledger_tx_id: res.tx_id,
+            written_off_amount: res.amount,
+            loan_loss_account_id,
+            recovery_account_id,
+            effective,
+        });
+
+        Ok(Idempotent::Executed(res))
+    }
+
     pub(crate) fn allocate_payment(
         &mut self,
         amount: UsdCents,
@@ -343,6 +433,7 @@ impl Obligation {
             effective,
             ..
         }: PaymentDetailsForAllocation,
+        allocation_strategy: AllocationStrategy,
     ) -> Idempotent<NewPaymentAllocation> {
         idempotency_guard!(
             self.events.iter_all().rev(),
@@ -353,6 +444,7 @@ impl Obligation {
             return Idempotent::AlreadyApplied;
         }
 
+        let recovery = self.status() == ObligationStatus::WrittenOff;
         let payment_amount = std::cmp::min(pre_payment_outstanding, amount);
         let allocation_id = PaymentAllocationId::new();
         self.events.push(ObligationEvent::PaymentAllocated {
@@ -381,6 +473,8 @@ impl Obligation {
             .payment_holding_account_id(payment_holding_account_id)
             .effective(effective)
             .amount(payment_amount)
+            .allocation_strategy(allocation_strategy)
+            .recovery(recovery)
             .build()
             .expect("could not build new payment allocation");
 
@@ -390,6 +484,68 @@ impl Obligation {
 
         Idempotent::Executed(allocation)
     }
+
+    /// Closes the outstanding balance of an overdue or defaulted obligation and
+    /// returns a replacement obligation carrying that balance on the new `dates`.
+    pub(crate) fn reschedule(
+        &mut self,
+        dates: ObligationLifecycleDates,
+        effective: chrono::NaiveDate,
+    ) -> Result<Idempotent<(NewObligation, ObligationRescheduleReallocationData)>, ObligationError>
+    {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            ObligationEvent::Rescheduled { .. }
+        );
+
+        let source_account_id = match self.status() {
+            ObligationStatus::Overdue => self.receivable_accounts().overdue,
+            ObligationStatus::Defaulted => self.defaulted_account(),
+            ObligationStatus::Paid => return Ok(Idempotent::AlreadyApplied),
+            _ => return Err(ObligationError::InvalidStatusTransitionToRescheduled),
+        };
+
+        let tx_id = LedgerTxId::new();
+        let amount = self.outstanding();
+        let mut builder = NewObligation::builder();
+        builder
+            .id(ObligationId::new())
+            .tx_id(tx_id)
+            .beneficiary_id(self.beneficiary_id)
+            .obligation_type(self.obligation_type)
+            .reference(format!("{}-rescheduled", self.reference))
+            .amount(amount)
+            .receivable_account_ids(*self.receivable_accounts())
+            .defaulted_account_id(self.defaulted_account())
+            .due_date(dates.due)
+            .overdue_date(dates.overdue)
+            .liquidation_date(dates.liquidation)
+            .effective(effective);
+        if let Some(defaulted) = dates.defaulted {
+            builder.defaulted_date(defaulted);
+        }
+        let new_obligation = builder
+            .build()
+            .expect("could not build rescheduled obligation");
+
+        self.events.push(ObligationEvent::Rescheduled {
+            ledger_tx_id: tx_id,
+            rescheduled_obligation_id: new_obligation.id(),
+            rescheduled_amount: amount,
+            effective,
+        });
+
+        Ok(Idempotent::Executed((
+            new_obligation,
+            ObligationRescheduleReallocationData {
+                tx_id,
+                amount,
+                source_account_id,
+                not_yet_due_account_id: self.receivable_accounts().not_yet_due,
+                effective,
+            },
+        )))
+    }
 }
 
 impl TryFromEvents<ObligationEvent> for Obligation {
@@ -421,6 +577,8 @@ impl TryFromEvents<ObligationEvent> for Obligation {
                 ObligationEvent::DefaultedRecorded { .. } => (),
                 ObligationEvent::PaymentAllocated { .. } => (),
                 ObligationEvent::Completed { .. } => (),
+                ObligationEvent::Rescheduled { .. } => (),
+                ObligationEvent::WrittenOff { .. } => (),
             }
         }
         builder.events(events).build()
@@ -502,16 +660,41 @@ impl IntoEvents<ObligationEvent> for NewObligation {
     }
 }
 
+impl Obligation {
+    pub(crate) fn allocation_order(
+        &self,
+        other: &Self,
+        strategy: AllocationStrategy,
+        penalty_allocation: PenaltyAllocation,
+    ) -> Ordering {
+        let by_due_date = match strategy {
+            AllocationStrategy::OldestFirst => {
+                self.lifecycle_dates().due.cmp(&other.lifecycle_dates().due)
+            }
+            _ => Ordering::Equal,
+        };
+        by_due_date
+            .then_with(|| {
+                self.obligation_type
+                    .allocation_priority(strategy, penalty_allocation)
+                    .cmp(
+                        &other
+                            .obligation_type
+                            .allocation_priority(strategy, penalty_allocation),
+                    )
+            })
+            .then_with(|| self.effective.cmp(&other.effective))
+            .then_with(|| self.created_at().cmp(&other.created_at()))
+    }
+}
+
 impl Ord for Obligation {
     fn cmp(&self, other: &Self) -> Ordering {
-        match (&self.obligation_type, &other.obligation_type) {
-            (ObligationType::Interest, ObligationType::Disbursal) => Ordering::Less,
-            (ObligationType::Disbursal, ObligationType::Interest) => Ordering::Greater,
-            _ => self
-                .effective
-                .cmp(&other.effective)
-                .then_with(|| self.created_at().cmp(&other.created_at())),
-        }
+        self.allocation_order(
+            other,
+            AllocationStrategy::default(),
+            PenaltyAllocation::default(),
+        )
     }
 }
 impl PartialOrd for Obligation {
@@ -526,6 +709,7 @@ impl PartialEq for Obligation {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
 pub struct ObligationLifecycleDates {
     pub due: EffectiveDate,
     pub overdue: Option<EffectiveDate>,
@@ -560,10 +744,14 @@ mod test {
     }
 
     fn initial_events() -> Vec<ObligationEvent> {
+        initial_events_with_type(ObligationType::Disbursal)
+    }
+
+    fn initial_events_with_type(obligation_type: ObligationType) -> Vec<ObligationEvent> {
         vec![ObligationEvent::Initialized {
             id: ObligationId::new(),
             beneficiary_id: BeneficiaryId::new(),
-            obligation_type: ObligationType::Disbursal,
+            obligation_type,
             amount: UsdCents::from(10),
             reference: "ref-01".to_string(),
             ledger_tx_id: LedgerTxId::new(),
@@ -584,6 +772,7 @@ mod test {
             beneficiary_id: BeneficiaryId::new(),
             facility_payment_holding_account_id: CalaAccountId::new(),
             effective: Utc::now().date_naive(),
+            payment_type: PaymentType::Regular,
         }
     }
 
@@ -607,13 +796,267 @@ mod test {
     fn completes_on_final_payment_allocation() {
         let mut obligation = obligation_from(initial_events());
         obligation
-            .allocate_payment(UsdCents::ONE, dummy_payment_details())
+            .allocate_payment(
+                UsdCents::ONE,
+                dummy_payment_details(),
+                AllocationStrategy::default(),
+            )
             .unwrap();
         assert_eq!(obligation.status(), ObligationStatus::NotYetDue);
 
         obligation
-            .allocate_payment(obligation.outstanding(), dummy_payment_details())
+            .allocate_payment(
+                obligation.outstanding(),
+                dummy_payment_details(),
+                AllocationStrategy::default(),
+            )
             .unwrap();
         assert_eq!(obligation.status(), ObligationStatus::Paid);
     }
+
+    #[test]
+    fn allocation_order_places_penalties_per_terms() {
+        let disbursal = obligation_from(initial_events_with_type(ObligationType::Disbursal));
+        let interest = obligation_from(initial_events_with_type(ObligationType::Interest));
+        let penalty = obligation_from(initial_events_with_type(ObligationType::Penalty));
+
+        let ordered_types = |penalty_allocation: PenaltyAllocation| {
+            let mut obligations = [&disbursal, &penalty, &interest];
+            obligations.sort_by(|a, b| {
+                a.allocation_order(
+                    b,
+                    AllocationStrategy::InterestBeforePrincipal,
+                    penalty_allocation,
+                )
+            });
+            obligations.map(|o| o.obligation_type)
+        };
+
+        assert_eq!(
+            ordered_types(PenaltyAllocation::BeforeInterest),
+            [
+                ObligationType::Penalty,
+                ObligationType::Interest,
+                ObligationType::Disbursal
+            ]
+        );
+        assert_eq!(
+            ordered_types(PenaltyAllocation::BeforePrincipal),
+            [
+                ObligationType::Interest,
+                ObligationType::Penalty,
+                ObligationType::Disbursal
+            ]
+        );
+        assert_eq!(
+            ordered_types(PenaltyAllocation::AfterPrincipal),
+            [
+                ObligationType::Interest,
+                ObligationType::Disbursal,
+                ObligationType::Penalty
+            ]
+        );
+    }
+
+    #[test]
+    fn principal_first_settles_disbursals_before_interest() {
+        let disbursal = obligation_from(initial_events_with_type(ObligationType::Disbursal));
+        let interest = obligation_from(initial_events_with_type(ObligationType::Interest));
+
+        let mut obligations = [&interest, &disbursal];
+        obligations.sort_by(|a, b| {
+            a.allocation_order(
+                b,
+                AllocationStrategy::PrincipalFirst,
+                PenaltyAllocation::default(),
+            )
+        });
+        assert_eq!(
+            obligations.map(|o| o.obligation_type),
+            [ObligationType::Disbursal, ObligationType::Interest]
+        );
+    }
+
+    #[test]
+    fn oldest_first_settles_by_due_date_across_types() {
+        let due_with_type = |obligation_type, due_date: DateTime<Utc>| {
+            let mut events = initial_events_with_type(obligation_type);
+            if let ObligationEvent::Initialized { due_date: due, .. } = &mut events[0] {
+                *due = due_date.into();
+            }
+            obligation_from(events)
+        };
+        let older_disbursal = due_with_type(
+            ObligationType::Disbursal,
+            Utc::now() - chrono::Duration::days(30),
+        );
+        let newer_interest = due_with_type(ObligationType::Interest, Utc::now());
+
+        let mut obligations = [&newer_interest, &older_disbursal];
+        obligations.sort_by(|a, b| {
+            a.allocation_order(
+                b,
+                AllocationStrategy::OldestFirst,
+                PenaltyAllocation::default(),
+            )
+        });
+        assert_eq!(
+            obligations.map(|o| o.obligation_type),
+            [ObligationType::Disbursal, ObligationType::Interest]
+        );
+    }
+
+    fn overdue_obligation() -> Obligation {
+        let mut obligation = obligation_from(initial_events());
+        obligation.record_due(Utc::now().date_naive()).unwrap();
+        obligation
+            .record_overdue(Utc::now().date_naive())
+            .unwrap()
+            .unwrap();
+        obligation
+    }
+
+    fn rescheduled_dates() -> ObligationLifecycleDates {
+        let due = Utc::now() + chrono::Duration::days(90);
+        ObligationLifecycleDates {
+            due: due.into(),
+            overdue: Some((due + chrono::Duration::days(30)).into()),
+            liquidation: None,
+            defaulted: None,
+        }
+    }
+
+    #[test]
+    fn reschedule_moves_outstanding_to_new_obligation() {
+        let mut obligation = overdue_obligation();
+        let outstanding = obligation.outstanding();
+        let overdue_account = obligation.receivable_accounts().overdue;
+        let dates = rescheduled_dates();
+
+        let (new_obligation, reallocation) = obligation
+            .reschedule(dates, Utc::now().date_naive())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(obligation.status(), ObligationStatus::Rescheduled);
+        assert!(obligation.outstanding().is_zero());
+        assert_eq!(new_obligation.amount, outstanding);
+        assert_eq!(new_obligation.obligation_type, obligation.obligation_type);
+        assert_eq!(new_obligation.due_date, dates.due);
+        assert_eq!(reallocation.amount, outstanding);
+        assert_eq!(reallocation.source_account_id, overdue_account);
+        assert_eq!(
+            reallocation.not_yet_due_account_id,
+            obligation.receivable_accounts().not_yet_due
+        );
+    }
+
+    #[test]
+    fn reschedule_is_idempotent() {
+        let mut obligation = overdue_obligation();
+        obligation
+            .reschedule(rescheduled_dates(), Utc::now().date_naive())
+            .unwrap()
+            .unwrap();
+
+        assert!(
+            obligation
+                .reschedule(rescheduled_dates(), Utc::now().date_naive())
+                .unwrap()
+                .was_already_applied()
+        );
+    }
+
+    fn defaulted_obligation() -> Obligation {
+        let mut obligation = overdue_obligation();
+        obligation
+            .record_defaulted(Utc::now().date_naive())
+            .unwrap()
+            .unwrap();
+        obligation
+    }
+
+    #[test]
+    fn write_off_moves_defaulted_balance_to_loan_loss() {
+        let mut obligation = defaulted_obligation();
+        let loan_loss_account_id = CalaAccountId::new();
+
+        let reallocation = obligation
+            .write_off(
+                loan_loss_account_id,
+                CalaAccountId::new(),
+                Utc::now().date_naive(),
+            )
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(obligation.status(), ObligationStatus::WrittenOff);
+        assert_eq!(obligation.outstanding(), obligation.initial_amount);
+        assert_eq!(reallocation.amount, obligation.initial_amount);
+        assert_eq!(
+            reallocation.defaulted_account_id,
+            obligation.defaulted_account()
+        );
+        assert_eq!(reallocation.loan_loss_account_id, loan_loss_account_id);
+        assert!(
+            obligation
+                .write_off(
+                    loan_loss_account_id,
+                    CalaAccountId::new(),
+                    Utc::now().date_naive()
+                )
+                .unwrap()
+                .was_already_applied()
+        );
+    }
+
+    #[test]
+    fn write_off_errors_if_not_defaulted() {
+        let mut obligation = overdue_obligation();
+
+        assert!(matches!(
+            obligation.write_off(
+                CalaAccountId::new(),
+                CalaAccountId::new(),
+                Utc::now().date_naive()
+            ),
+            Err(ObligationError::InvalidStatusTransitionToWrittenOff)
+        ));
+    }
+
+    #[test]
+    fn payments_after_write_off_are_recoveries() {
+        let mut obligation = defaulted_obligation();
+        let recovery_account_id = CalaAccountId::new();
+        obligation
+            .write_off(
+                CalaAccountId::new(),
+                recovery_account_id,
+                Utc::now().date_naive(),
+            )
+            .unwrap()
+            .unwrap();
+
+        let allocation = obligation
+            .allocate_payment(
+                obligation.outstanding(),
+                dummy_payment_details(),
+                AllocationStrategy::default(),
+            )
+            .unwrap();
+
+        assert!(allocation.recovery);
+        assert_eq!(allocation.receivable_account_id, recovery_account_id);
+        assert_eq!(obligation.status(), ObligationStatus::Paid);
+    }
+
+    #[test]
+    fn reschedule_errors_if_not_overdue() {
+        let mut obligation = obligation_from(initial_events());
+
+        assert!(matches!(
+            obligation.reschedule(rescheduled_dates(), Utc::now().date_naive()),
+            Err(ObligationError::InvalidStatusTransitionToRescheduled)
+        ));
+    }
 }
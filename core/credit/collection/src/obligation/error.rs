@@ -20,6 +20,10 @@ pub enum ObligationError {
     InvalidStatusTransitionToOverdue,
     #[error("ObligationError - InvalidStatusTransitionToDefaulted")]
     InvalidStatusTransitionToDefaulted,
+    #[error("ObligationError - InvalidStatusTransitionToRescheduled")]
+    InvalidStatusTransitionToRescheduled,
+    #[error("ObligationError - InvalidStatusTransitionToWrittenOff")]
+    InvalidStatusTransitionToWrittenOff,
     #[error("ObligationError - PaymentAllocationError: {0}")]
     PaymentAllocationError(#[from] crate::payment_allocation::error::PaymentAllocationError),
     #[error("ObligationError - CollectionLedgerError: {0}")]
@@ -37,6 +41,8 @@ impl ErrorSeverity for ObligationError {
             Self::JobError(_) => Level::ERROR,
             Self::InvalidStatusTransitionToOverdue => Level::ERROR,
             Self::InvalidStatusTransitionToDefaulted => Level::ERROR,
+            Self::InvalidStatusTransitionToRescheduled => Level::WARN,
+            Self::InvalidStatusTransitionToWrittenOff => Level::WARN,
             Self::PaymentAllocationError(e) => e.severity(),
             Self::CollectionLedgerError(e) => e.severity(),
         }
@@ -29,7 +29,10 @@ pub use entity::NewObligation;
 pub(crate) use entity::ObligationDefaultedReallocationData;
 pub(crate) use entity::ObligationDueReallocationData;
 pub use entity::ObligationEvent;
+pub use entity::ObligationLifecycleDates;
 pub(crate) use entity::ObligationOverdueReallocationData;
+pub(crate) use entity::ObligationRescheduleReallocationData;
+pub(crate) use entity::ObligationWriteOffReallocationData;
 use error::ObligationError;
 pub(crate) use repo::ObligationRepo;
 
@@ -160,22 +163,29 @@ where
         payment_details @ PaymentDetailsForAllocation {
             beneficiary_id,
             amount,
+            payment_type,
             ..
         }: PaymentDetailsForAllocation,
+        allocation_strategy: AllocationStrategy,
+        penalty_allocation: PenaltyAllocation,
         initiated_by: &impl SystemSubject,
     ) -> Result<(), ObligationError> {
         let span = Span::current();
         span.record("beneficiary_id", tracing::field::display(beneficiary_id));
         let mut obligations = self.beneficiary_obligations(beneficiary_id).await?;
+        if payment_type == PaymentType::Prepayment {
+            obligations
+                .retain(|obligation| obligation.obligation_type == ObligationType::Disbursal);
+        }
         span.record("n_beneficiary_obligations", obligations.len());
 
-        obligations.sort();
+        obligations.sort_by(|a, b| a.allocation_order(b, allocation_strategy, penalty_allocation));
 
         let mut remaining = amount;
         let mut new_allocations = Vec::new();
         for obligation in obligations.iter_mut() {
             if let es_entity::Idempotent::Executed(new_allocation) =
-                obligation.allocate_payment(remaining, payment_details)
+                obligation.allocate_payment(remaining, payment_details, allocation_strategy)
             {
                 remaining -= new_allocation.amount;
                 new_allocations.push(new_allocation);
@@ -206,6 +216,98 @@ where
         Ok(())
     }
 
+    /// Moves the outstanding balance of every overdue or defaulted obligation of
+    /// `beneficiary_id` onto a new obligation falling due on `dates`.
+    #[record_error_severity]
+    #[instrument(
+        name = "collections.obligation.reschedule_overdue_in_op",
+        skip(self, op, dates),
+        fields(n_rescheduled, beneficiary_id = %beneficiary_id)
+    )]
+    pub async fn reschedule_overdue_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        beneficiary_id: BeneficiaryId,
+        dates: ObligationLifecycleDates,
+        effective: chrono::NaiveDate,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<Vec<Obligation>, ObligationError> {
+        let mut obligations = self
+            .beneficiary_obligations_in_op(&mut *op, beneficiary_id)
+            .await?;
+        obligations.retain(|obligation| {
+            matches!(
+                obligation.status(),
+                ObligationStatus::Overdue | ObligationStatus::Defaulted
+            )
+        });
+
+        let mut reschedules = Vec::new();
+        for obligation in obligations.iter_mut() {
+            if let es_entity::Idempotent::Executed(reschedule) =
+                obligation.reschedule(dates, effective)?
+            {
+                reschedules.push(reschedule);
+            }
+        }
+        self.repo.update_all_in_op(op, &mut obligations).await?;
+
+        Span::current().record("n_rescheduled", reschedules.len());
+
+        let mut rescheduled = Vec::new();
+        for (new_obligation, reallocation) in reschedules {
+            self.ledger
+                .record_obligation_reschedule_in_op(op, reallocation, initiated_by)
+                .await?;
+            rescheduled.push(self.create_with_jobs_in_op(op, new_obligation).await?);
+        }
+
+        Ok(rescheduled)
+    }
+
+    /// Writes off every defaulted obligation of `beneficiary_id` and returns the
+    /// obligations that were written off.
+    #[record_error_severity]
+    #[instrument(
+        name = "collections.obligation.write_off_defaulted_in_op",
+        skip(self, op),
+        fields(n_written_off, beneficiary_id = %beneficiary_id)
+    )]
+    pub async fn write_off_defaulted_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        beneficiary_id: BeneficiaryId,
+        loan_loss_account_id: CalaAccountId,
+        recovery_account_id: CalaAccountId,
+        effective: chrono::NaiveDate,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<Vec<Obligation>, ObligationError> {
+        let mut obligations = self
+            .beneficiary_obligations_in_op(&mut *op, beneficiary_id)
+            .await?;
+        obligations.retain(|obligation| obligation.status() == ObligationStatus::Defaulted);
+
+        let mut write_offs = Vec::new();
+        for obligation in obligations.iter_mut() {
+            if let es_entity::Idempotent::Executed(write_off) =
+                obligation.write_off(loan_loss_account_id, recovery_account_id, effective)?
+            {
+                write_offs.push(write_off);
+            }
+        }
+        self.repo.update_all_in_op(op, &mut obligations).await?;
+
+        Span::current().record("n_written_off", write_offs.len());
+
+        for write_off in write_offs {
+            self.ledger
+                .record_obligation_write_off_in_op(op, write_off, initiated_by)
+                .await?;
+        }
+
+        Ok(obligations)
+    }
+
     pub async fn find_allocation_by_id_without_audit(
         &self,
         allocation_id: impl Into<PaymentAllocationId> + std::fmt::Debug,
@@ -284,4 +386,42 @@ where
 
         Ok(obligations)
     }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "collections.obligation.beneficiary_obligations_in_op",
+        skip(self, op),
+        fields(beneficiary_id = %beneficiary_id, n_obligations)
+    )]
+    async fn beneficiary_obligations_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        beneficiary_id: BeneficiaryId,
+    ) -> Result<Vec<Obligation>, ObligationError> {
+        let mut obligations = Vec::new();
+        let mut query = Default::default();
+        loop {
+            let mut res = self
+                .repo
+                .list_for_beneficiary_id_by_created_at_in_op(
+                    &mut *op,
+                    beneficiary_id,
+                    query,
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+
+            obligations.append(&mut res.entities);
+
+            if let Some(q) = res.into_next_query() {
+                query = q;
+            } else {
+                break;
+            };
+        }
+
+        Span::current().record("n_obligations", obligations.len());
+
+        Ok(obligations)
+    }
 }
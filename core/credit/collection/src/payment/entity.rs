@@ -22,6 +22,8 @@ pub enum PaymentEvent {
         payment_source_account_id: CalaAccountId,
         amount: UsdCents,
         effective: chrono::NaiveDate,
+        #[serde(default)]
+        payment_type: PaymentType,
     },
 }
 
@@ -36,6 +38,7 @@ pub struct Payment {
     pub payment_source_account_id: CalaAccountId,
     pub amount: UsdCents,
     pub effective: chrono::NaiveDate,
+    pub payment_type: PaymentType,
 
     events: EntityEvents<PaymentEvent>,
 }
@@ -54,6 +57,7 @@ impl TryFromEvents<PaymentEvent> for Payment {
                     payment_source_account_id,
                     amount,
                     effective,
+                    payment_type,
                     ..
                 } => {
                     builder = builder
@@ -67,6 +71,7 @@ impl TryFromEvents<PaymentEvent> for Payment {
                         .payment_source_account_id(*payment_source_account_id)
                         .amount(*amount)
                         .effective(*effective)
+                        .payment_type(*payment_type)
                 }
             }
         }
@@ -94,6 +99,7 @@ impl From<Payment> for PaymentDetailsForAllocation {
             beneficiary_id: payment.beneficiary_id,
             facility_payment_holding_account_id: payment.facility_payment_holding_account_id,
             effective: payment.effective,
+            payment_type: payment.payment_type,
         }
     }
 }
@@ -109,6 +115,8 @@ pub struct NewPayment {
     pub(super) payment_ledger_account_ids: PaymentLedgerAccountIds,
     pub(super) amount: UsdCents,
     pub(crate) effective: chrono::NaiveDate,
+    #[builder(default)]
+    pub(super) payment_type: PaymentType,
 }
 
 impl NewPayment {
@@ -134,6 +142,7 @@ impl IntoEvents<PaymentEvent> for NewPayment {
                 payment_source_account_id: payment_source_account_id.into(),
                 amount: self.amount,
                 effective: self.effective,
+                payment_type: self.payment_type,
             }],
         )
     }
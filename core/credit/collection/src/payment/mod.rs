@@ -99,6 +99,62 @@ where
         amount: UsdCents,
         effective: chrono::NaiveDate,
         initiated_by: &impl SystemSubject,
+    ) -> Result<Option<Payment>, PaymentError> {
+        self.record_with_type_in_op(
+            db,
+            payment_id,
+            beneficiary_id,
+            payment_ledger_account_ids,
+            amount,
+            effective,
+            PaymentType::Regular,
+            initiated_by,
+        )
+        .await
+    }
+
+    /// Same as [`Self::record_in_op`] but marks the Payment as a
+    /// prepayment, so that it is only allocated to disbursal
+    /// obligations and pays down principal ahead of schedule.
+    ///
+    /// # Idempotency
+    ///
+    /// Idempotent via `payment_id`.
+    #[instrument(name = "collection.payment.record_prepayment_in_op", skip(self, db))]
+    pub async fn record_prepayment_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        payment_id: PaymentId,
+        beneficiary_id: BeneficiaryId,
+        payment_ledger_account_ids: PaymentLedgerAccountIds,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<Option<Payment>, PaymentError> {
+        self.record_with_type_in_op(
+            db,
+            payment_id,
+            beneficiary_id,
+            payment_ledger_account_ids,
+            amount,
+            effective,
+            PaymentType::Prepayment,
+            initiated_by,
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn record_with_type_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        payment_id: PaymentId,
+        beneficiary_id: BeneficiaryId,
+        payment_ledger_account_ids: PaymentLedgerAccountIds,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        payment_type: PaymentType,
+        initiated_by: &impl SystemSubject,
     ) -> Result<Option<Payment>, PaymentError> {
         let new_payment = NewPayment::builder()
             .id(payment_id)
@@ -107,6 +163,7 @@ where
             .beneficiary_id(beneficiary_id)
             .payment_ledger_account_ids(payment_ledger_account_ids)
             .effective(effective)
+            .payment_type(payment_type)
             .build()
             .expect("could not build new payment");
 
@@ -25,6 +25,10 @@ pub enum PaymentAllocationEvent {
         receivable_account_id: CalaAccountId,
         payment_holding_account_id: CalaAccountId,
         effective: chrono::NaiveDate,
+        #[serde(default)]
+        allocation_strategy: AllocationStrategy,
+        #[serde(default)]
+        recovery: bool,
     },
 }
 
@@ -41,6 +45,9 @@ pub struct PaymentAllocation {
     pub payment_holding_account_id: CalaAccountId,
     pub receivable_account_id: CalaAccountId,
     pub effective: chrono::NaiveDate,
+    pub allocation_strategy: AllocationStrategy,
+    /// Settles an obligation that was already written off.
+    pub recovery: bool,
 
     events: EntityEvents<PaymentAllocationEvent>,
 }
@@ -72,6 +79,8 @@ impl TryFromEvents<PaymentAllocationEvent> for PaymentAllocation {
                     payment_holding_account_id,
                     receivable_account_id,
                     effective,
+                    allocation_strategy,
+                    recovery,
                     ..
                 } => {
                     builder = builder
@@ -85,6 +94,8 @@ impl TryFromEvents<PaymentAllocationEvent> for PaymentAllocation {
                         .payment_holding_account_id(*payment_holding_account_id)
                         .receivable_account_id(*receivable_account_id)
                         .effective(*effective)
+                        .allocation_strategy(*allocation_strategy)
+                        .recovery(*recovery)
                 }
             }
         }
@@ -114,6 +125,9 @@ pub struct NewPaymentAllocation {
     pub(crate) effective: chrono::NaiveDate,
     #[builder(setter(into))]
     pub(crate) amount: UsdCents,
+    pub(crate) allocation_strategy: AllocationStrategy,
+    #[builder(default)]
+    pub(crate) recovery: bool,
 }
 
 impl NewPaymentAllocation {
@@ -137,6 +151,8 @@ impl IntoEvents<PaymentAllocationEvent> for NewPaymentAllocation {
                 payment_holding_account_id: self.payment_holding_account_id,
                 effective: self.effective,
                 receivable_account_id: self.receivable_account_id,
+                allocation_strategy: self.allocation_strategy,
+                recovery: self.recovery,
             }],
         )
     }
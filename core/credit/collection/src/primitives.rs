@@ -11,7 +11,7 @@ use authz::{ActionPermission, AllOrOne, action_description::*, map_action};
 pub const OBLIGATION_SYNC: audit::SystemActor = audit::SystemActor::new("obligation-sync");
 
 pub use cala_ledger::primitives::{AccountId as CalaAccountId, TransactionId as LedgerTxId};
-pub use core_credit_terms::EffectiveDate;
+pub use core_credit_terms::{AllocationStrategy, EffectiveDate, PenaltyAllocation};
 pub use money::*;
 
 es_entity::entity_id! {
@@ -32,6 +32,8 @@ pub enum ObligationStatus {
     Overdue,
     Defaulted,
     Paid,
+    Rescheduled,
+    WrittenOff,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
@@ -39,6 +41,35 @@ pub enum ObligationStatus {
 pub enum ObligationType {
     Disbursal,
     Interest,
+    Penalty,
+}
+
+impl ObligationType {
+    /// Position of this type in the payment waterfall, lower ranks are settled first.
+    pub fn allocation_priority(
+        &self,
+        strategy: AllocationStrategy,
+        penalty_allocation: PenaltyAllocation,
+    ) -> u8 {
+        match strategy {
+            AllocationStrategy::InterestBeforePrincipal | AllocationStrategy::OldestFirst => {
+                match (self, penalty_allocation) {
+                    (Self::Penalty, PenaltyAllocation::BeforeInterest) => 0,
+                    (Self::Interest, _) => 1,
+                    (Self::Penalty, PenaltyAllocation::BeforePrincipal) => 2,
+                    (Self::Disbursal, _) => 3,
+                    (Self::Penalty, PenaltyAllocation::AfterPrincipal) => 4,
+                }
+            }
+            AllocationStrategy::PrincipalFirst => match (self, penalty_allocation) {
+                (Self::Penalty, PenaltyAllocation::BeforePrincipal) => 0,
+                (Self::Disbursal, _) => 1,
+                (Self::Penalty, PenaltyAllocation::AfterPrincipal) => 2,
+                (Self::Penalty, PenaltyAllocation::BeforeInterest) => 2,
+                (Self::Interest, _) => 3,
+            },
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -73,6 +104,16 @@ impl ObligationsAmounts {
     }
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum PaymentType {
+    /// Settles outstanding obligations following the facility's waterfall.
+    #[default]
+    Regular,
+    /// Pays down principal ahead of schedule, only settling disbursal obligations.
+    Prepayment,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct PaymentDetailsForAllocation {
     pub payment_id: PaymentId,
@@ -80,6 +121,7 @@ pub struct PaymentDetailsForAllocation {
     pub beneficiary_id: BeneficiaryId,
     pub facility_payment_holding_account_id: CalaAccountId,
     pub effective: chrono::NaiveDate,
+    pub payment_type: PaymentType,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -125,7 +167,9 @@ impl ObligationReceivableAccountIds {
             ObligationStatus::NotYetDue => Some(self.not_yet_due),
             ObligationStatus::Due => Some(self.due),
             ObligationStatus::Overdue | ObligationStatus::Defaulted => Some(self.overdue),
-            ObligationStatus::Paid => None,
+            ObligationStatus::Paid
+            | ObligationStatus::Rescheduled
+            | ObligationStatus::WrittenOff => None,
         }
     }
 }
@@ -16,4 +16,6 @@ pub enum CoreCreditCollectionEvent {
     ObligationOverdue { entity: PublicObligation },
     ObligationDefaulted { entity: PublicObligation },
     ObligationCompleted { entity: PublicObligation },
+    ObligationRescheduled { entity: PublicObligation },
+    ObligationWrittenOff { entity: PublicObligation },
 }
@@ -6,7 +6,7 @@ use schemars::JsonSchema;
 
 use crate::{
     payment::Payment,
-    primitives::{BeneficiaryId, PaymentId, UsdCents},
+    primitives::{BeneficiaryId, PaymentId, PaymentType, UsdCents},
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,6 +17,8 @@ pub struct PublicPayment {
     pub amount: UsdCents,
     pub recorded_at: DateTime<Utc>,
     pub effective: chrono::NaiveDate,
+    #[serde(default)]
+    pub payment_type: PaymentType,
 }
 
 impl From<&Payment> for PublicPayment {
@@ -27,6 +29,7 @@ impl From<&Payment> for PublicPayment {
             amount: entity.amount,
             recorded_at: entity.created_at(),
             effective: entity.effective,
+            payment_type: entity.payment_type,
         }
     }
 }
@@ -6,7 +6,10 @@ use schemars::JsonSchema;
 
 use crate::{
     payment_allocation::PaymentAllocation,
-    primitives::{BeneficiaryId, ObligationId, ObligationType, PaymentAllocationId, UsdCents},
+    primitives::{
+        AllocationStrategy, BeneficiaryId, ObligationId, ObligationType, PaymentAllocationId,
+        UsdCents,
+    },
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,6 +22,10 @@ pub struct PublicPaymentAllocation {
     pub amount: UsdCents,
     pub recorded_at: DateTime<Utc>,
     pub effective: chrono::NaiveDate,
+    #[serde(default)]
+    pub allocation_strategy: AllocationStrategy,
+    #[serde(default)]
+    pub recovery: bool,
 }
 
 impl From<&PaymentAllocation> for PublicPaymentAllocation {
@@ -31,6 +38,8 @@ impl From<&PaymentAllocation> for PublicPaymentAllocation {
             amount: entity.amount,
             recorded_at: entity.created_at(),
             effective: entity.effective,
+            allocation_strategy: entity.allocation_strategy,
+            recovery: entity.recovery,
         }
     }
 }
@@ -112,6 +112,12 @@ where
                 Completed { .. } => Some(CoreCreditCollectionEvent::ObligationCompleted {
                     entity: PublicObligation::from(entity),
                 }),
+                Rescheduled { .. } => Some(CoreCreditCollectionEvent::ObligationRescheduled {
+                    entity: PublicObligation::from(entity),
+                }),
+                WrittenOff { .. } => Some(CoreCreditCollectionEvent::ObligationWrittenOff {
+                    entity: PublicObligation::from(entity),
+                }),
                 _ => None,
             })
             .collect::<Vec<_>>();
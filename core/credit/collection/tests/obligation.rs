@@ -8,7 +8,7 @@ use core_credit_collection::{
     ObligationType, PaymentDetailsForAllocation, PaymentId, PaymentLedgerAccountIds,
     PaymentSourceAccountId,
 };
-use core_credit_terms::EffectiveDate;
+use core_credit_terms::{AllocationStrategy, EffectiveDate, PenaltyAllocation};
 use es_entity::DbOp;
 use helpers::event::{DummyEvent, expect_event};
 use money::UsdCents;
@@ -352,6 +352,8 @@ async fn obligation_completed_event_on_full_payment() -> anyhow::Result<()> {
                 .allocate_payment_in_op(
                     &mut op,
                     payment_details,
+                    AllocationStrategy::default(),
+                    PenaltyAllocation::default(),
                     &DummySubject::system(audit::SystemActor::new("test")),
                 )
                 .await?;
@@ -7,7 +7,7 @@ use core_credit_collection::{
     BeneficiaryId, CoreCreditCollectionEvent, NewObligation, ObligationId, ObligationType,
     PaymentDetailsForAllocation, PaymentId, PaymentLedgerAccountIds, PaymentSourceAccountId,
 };
-use core_credit_terms::EffectiveDate;
+use core_credit_terms::{AllocationStrategy, EffectiveDate, PenaltyAllocation};
 use es_entity::DbOp;
 use helpers::event::{DummyEvent, expect_event};
 use money::UsdCents;
@@ -59,7 +59,7 @@ async fn create_obligation(ctx: &TestContext, beneficiary_id: BeneficiaryId, amo
 /// # Event Contents
 /// - `id`: Unique allocation identifier
 /// - `obligation_id`: The obligation the payment was allocated to
-/// - `obligation_type`: `Disbursal` or `Interest`
+/// - `obligation_type`: `Disbursal`, `Interest` or `Penalty`
 /// - `beneficiary_id`: Beneficiary identifier
 /// - `amount`: Allocated amount
 /// - `recorded_at`: Timestamp of allocation creation
@@ -108,6 +108,8 @@ async fn payment_allocation_created_event_on_allocate() -> anyhow::Result<()> {
                 .allocate_payment_in_op(
                     &mut op,
                     payment_details,
+                    AllocationStrategy::OldestFirst,
+                    PenaltyAllocation::default(),
                     &DummySubject::system(audit::SystemActor::new("test")),
                 )
                 .await?;
@@ -126,6 +128,10 @@ async fn payment_allocation_created_event_on_allocate() -> anyhow::Result<()> {
     assert_eq!(recorded.beneficiary_id, beneficiary_id);
     assert_eq!(recorded.obligation_type, ObligationType::Disbursal);
     assert_eq!(recorded.amount, amount);
+    assert_eq!(
+        recorded.allocation_strategy,
+        AllocationStrategy::OldestFirst
+    );
 
     Ok(())
 }
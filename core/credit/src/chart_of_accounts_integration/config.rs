@@ -34,6 +34,16 @@ pub struct ChartOfAccountsIntegrationConfig {
     pub chart_of_account_uncovered_outstanding_parent_code: AccountCode,
     pub chart_of_account_disbursed_defaulted_parent_code: AccountCode,
     pub chart_of_account_interest_defaulted_parent_code: AccountCode,
+    #[serde(default)]
+    pub chart_of_account_penalty_receivable_parent_code: Option<AccountCode>,
+    #[serde(default)]
+    pub chart_of_account_penalty_defaulted_parent_code: Option<AccountCode>,
+    #[serde(default)]
+    pub chart_of_account_penalty_income_parent_code: Option<AccountCode>,
+    #[serde(default)]
+    pub chart_of_account_loan_loss_expense_parent_code: Option<AccountCode>,
+    #[serde(default)]
+    pub chart_of_account_recovery_income_parent_code: Option<AccountCode>,
 
     pub chart_of_account_short_term_individual_disbursed_receivable_parent_code: AccountCode,
     pub chart_of_account_short_term_government_entity_disbursed_receivable_parent_code: AccountCode,
@@ -113,6 +123,16 @@ define_internal_config! {
         pub(crate) uncovered_outstanding_parent_account_set_id: CalaAccountSetId,
         pub(crate) disbursed_defaulted_parent_account_set_id: CalaAccountSetId,
         pub(crate) interest_defaulted_parent_account_set_id: CalaAccountSetId,
+        #[serde(default)]
+        pub(crate) penalty_receivable_parent_account_set_id: Option<CalaAccountSetId>,
+        #[serde(default)]
+        pub(crate) penalty_defaulted_parent_account_set_id: Option<CalaAccountSetId>,
+        #[serde(default)]
+        pub(crate) penalty_income_parent_account_set_id: Option<CalaAccountSetId>,
+        #[serde(default)]
+        pub(crate) loan_loss_expense_parent_account_set_id: Option<CalaAccountSetId>,
+        #[serde(default)]
+        pub(crate) recovery_income_parent_account_set_id: Option<CalaAccountSetId>,
 
         pub(crate) short_term_disbursed_integration_meta: ShortTermDisbursedIntegrationMeta,
         pub(crate) long_term_disbursed_integration_meta: LongTermDisbursedIntegrationMeta,
@@ -149,6 +169,11 @@ impl ResolvedChartOfAccountsIntegrationConfig {
             chart_of_account_uncovered_outstanding_parent_code,
             chart_of_account_disbursed_defaulted_parent_code,
             chart_of_account_interest_defaulted_parent_code,
+            chart_of_account_penalty_receivable_parent_code,
+            chart_of_account_penalty_defaulted_parent_code,
+            chart_of_account_penalty_income_parent_code,
+            chart_of_account_loan_loss_expense_parent_code,
+            chart_of_account_recovery_income_parent_code,
             chart_of_account_short_term_individual_disbursed_receivable_parent_code,
             chart_of_account_short_term_government_entity_disbursed_receivable_parent_code,
             chart_of_account_short_term_private_company_disbursed_receivable_parent_code,
@@ -285,6 +310,55 @@ impl ResolvedChartOfAccountsIntegrationConfig {
             summary.credit_interest_defaulted.account_category,
         )?;
 
+        let penalty_receivable_parent_account_set_id =
+            chart_of_account_penalty_receivable_parent_code
+                .as_ref()
+                .map(|code| {
+                    category_account_set_member_parent_id(
+                        code,
+                        summary.credit_penalty_receivable.account_category,
+                    )
+                })
+                .transpose()?;
+        let penalty_defaulted_parent_account_set_id =
+            chart_of_account_penalty_defaulted_parent_code
+                .as_ref()
+                .map(|code| {
+                    category_account_set_member_parent_id(
+                        code,
+                        summary.credit_penalty_defaulted.account_category,
+                    )
+                })
+                .transpose()?;
+        let penalty_income_parent_account_set_id = chart_of_account_penalty_income_parent_code
+            .as_ref()
+            .map(|code| {
+                category_account_set_member_parent_id(
+                    code,
+                    summary.credit_penalty_income.account_category,
+                )
+            })
+            .transpose()?;
+        let loan_loss_expense_parent_account_set_id =
+            chart_of_account_loan_loss_expense_parent_code
+                .as_ref()
+                .map(|code| {
+                    category_account_set_member_parent_id(
+                        code,
+                        summary.credit_loan_loss_expense.account_category,
+                    )
+                })
+                .transpose()?;
+        let recovery_income_parent_account_set_id = chart_of_account_recovery_income_parent_code
+            .as_ref()
+            .map(|code| {
+                category_account_set_member_parent_id(
+                    code,
+                    summary.credit_recovery_income.account_category,
+                )
+            })
+            .transpose()?;
+
         let short_term_disbursed_integration_meta = ShortTermDisbursedIntegrationMeta {
             short_term_individual_disbursed_receivable_parent_account_set_id:
                 category_account_set_member_parent_id(
@@ -548,6 +622,11 @@ impl ResolvedChartOfAccountsIntegrationConfig {
             uncovered_outstanding_parent_account_set_id,
             disbursed_defaulted_parent_account_set_id,
             interest_defaulted_parent_account_set_id,
+            penalty_receivable_parent_account_set_id,
+            penalty_defaulted_parent_account_set_id,
+            penalty_income_parent_account_set_id,
+            loan_loss_expense_parent_account_set_id,
+            recovery_income_parent_account_set_id,
 
             short_term_disbursed_integration_meta,
             long_term_disbursed_integration_meta,
@@ -68,6 +68,9 @@ pub enum CollateralEvent {
     LiquidationCompleted {
         liquidation_id: LiquidationId,
     },
+    TopUpIntentRecorded {
+        amount: Satoshis,
+    },
 }
 
 #[derive(EsEntity, Builder)]
@@ -134,6 +137,39 @@ impl Collateral {
         self.account_ids.collateral_account_id
     }
 
+    /// Amount the customer announced they will add, until collateral is next added.
+    pub fn pending_top_up_intent(&self) -> Option<Satoshis> {
+        self.events.iter_all().rev().find_map(|event| match event {
+            CollateralEvent::TopUpIntentRecorded { amount } => Some(Some(*amount)),
+            CollateralEvent::UpdatedViaManualInput {
+                direction: CollateralDirection::Add,
+                ..
+            }
+            | CollateralEvent::UpdatedViaCustodianSync {
+                direction: CollateralDirection::Add,
+                ..
+            } => Some(None),
+            _ => None,
+        })?
+    }
+
+    pub(super) fn record_top_up_intent(
+        &mut self,
+        amount: Satoshis,
+    ) -> Result<Idempotent<()>, CollateralError> {
+        if amount == Satoshis::ZERO {
+            return Err(CollateralError::InvalidTopUpIntentAmount);
+        }
+        if self.pending_top_up_intent() == Some(amount) {
+            return Ok(Idempotent::AlreadyApplied);
+        }
+
+        self.events
+            .push(CollateralEvent::TopUpIntentRecorded { amount });
+
+        Ok(Idempotent::Executed(()))
+    }
+
     pub fn record_collateral_update_via_custodian_sync(
         &mut self,
         new_amount: Satoshis,
@@ -399,6 +435,7 @@ impl TryFromEvents<CollateralEvent> for Collateral {
                 CollateralEvent::LiquidationStarted { .. } => {}
                 CollateralEvent::LiquidationProceedsReceived { .. } => {}
                 CollateralEvent::LiquidationCompleted { .. } => {}
+                CollateralEvent::TopUpIntentRecorded { .. } => {}
             }
         }
         builder.events(events).build()
@@ -627,4 +664,49 @@ mod tests {
             assert!(matches!(result, Err(CollateralError::NoActiveLiquidation)));
         }
     }
+
+    mod record_top_up_intent {
+        use super::*;
+
+        #[test]
+        fn records_pending_intent() {
+            let mut collateral = collateral_from(default_new_collateral());
+
+            let result = collateral.record_top_up_intent(Satoshis::from(50000));
+            assert!(result.unwrap().did_execute());
+            assert_eq!(
+                collateral.pending_top_up_intent(),
+                Some(Satoshis::from(50000))
+            );
+
+            let result = collateral.record_top_up_intent(Satoshis::from(50000));
+            assert!(result.unwrap().was_already_applied());
+        }
+
+        #[test]
+        fn fails_for_zero_amount() {
+            let mut collateral = collateral_from(default_new_collateral());
+
+            let result = collateral.record_top_up_intent(Satoshis::ZERO);
+            assert!(matches!(
+                result,
+                Err(CollateralError::InvalidTopUpIntentAmount)
+            ));
+        }
+
+        #[test]
+        fn intent_is_cleared_when_collateral_is_added() {
+            let mut collateral = collateral_from(default_new_collateral());
+
+            let _ = collateral.record_top_up_intent(Satoshis::from(50000));
+            let _ = collateral
+                .record_collateral_update_via_manual_input(
+                    Satoshis::from(50000),
+                    chrono::Utc::now().date_naive(),
+                )
+                .unwrap();
+
+            assert_eq!(collateral.pending_top_up_intent(), None);
+        }
+    }
 }
@@ -15,6 +15,8 @@ pub enum CollateralError {
     ManualUpdateError,
     #[error("CollateralError - NoActiveLiquidation")]
     NoActiveLiquidation,
+    #[error("CollateralError - InvalidTopUpIntentAmount")]
+    InvalidTopUpIntentAmount,
     #[error("CollateralError - JobError: {0}")]
     JobError(#[from] job::error::JobError),
     #[error("CollateralError - RegisterEventHandler: {0}")]
@@ -34,6 +36,7 @@ impl ErrorSeverity for CollateralError {
             Self::CollateralLedgerError(e) => e.severity(),
             Self::ManualUpdateError => Level::WARN,
             Self::NoActiveLiquidation => Level::WARN,
+            Self::InvalidTopUpIntentAmount => Level::WARN,
             Self::JobError(_) => Level::ERROR,
             Self::RegisterEventHandler(_) => Level::ERROR,
             Self::LiquidationError(e) => e.severity(),
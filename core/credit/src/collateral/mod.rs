@@ -282,6 +282,26 @@ where
         Ok(collateral)
     }
 
+    #[record_error_severity]
+    #[instrument(
+        name = "collateral.record_top_up_intent_without_audit",
+        skip(self),
+        err
+    )]
+    pub(crate) async fn record_top_up_intent_without_audit(
+        &self,
+        collateral_id: CollateralId,
+        amount: money::Satoshis,
+    ) -> Result<Collateral, CollateralError> {
+        let mut collateral = self.repo.find_by_id(collateral_id).await?;
+
+        if collateral.record_top_up_intent(amount)?.did_execute() {
+            self.repo.update(&mut collateral).await?;
+        }
+
+        Ok(collateral)
+    }
+
     #[record_error_severity]
     #[instrument(
         name = "collateral.record_collateral_update_via_liquidation",
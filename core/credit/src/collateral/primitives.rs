@@ -91,6 +91,8 @@ impl CoreCreditCollateralAction {
         CoreCreditCollateralAction::Collateral(
             CollateralAction::RecordPaymentReceivedFromLiquidation,
         );
+    pub const COLLATERAL_RECORD_TOP_UP_INTENT: Self =
+        CoreCreditCollateralAction::Collateral(CollateralAction::RecordTopUpIntent);
     pub const LIQUIDATION_READ: Self =
         CoreCreditCollateralAction::Liquidation(LiquidationAction::Read);
     pub const LIQUIDATION_LIST: Self =
@@ -148,6 +150,7 @@ pub enum CollateralAction {
     RecordCustodianSync,
     RecordLiquidationUpdate,
     RecordPaymentReceivedFromLiquidation,
+    RecordTopUpIntent,
 }
 
 impl ActionPermission for CollateralAction {
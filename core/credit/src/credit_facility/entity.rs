@@ -65,6 +65,58 @@ pub enum CreditFacilityEvent {
     ProceedsFromPartialLiquidationApplied {
         liquidation_id: LiquidationId,
     },
+    PenaltyAccountsCreated {
+        penalty_account_ids: PenaltyLedgerAccountIds,
+    },
+    PenaltyRecorded {
+        obligation_id: ObligationId,
+        overdue_obligation_id: ObligationId,
+        ledger_tx_id: LedgerTxId,
+        tx_ref: String,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+    },
+    PrepaymentRecorded {
+        payment_id: PaymentId,
+        amount: UsdCents,
+        fee: UsdCents,
+        fee_tx_id: Option<LedgerTxId>,
+        effective: chrono::NaiveDate,
+    },
+    AmendmentProposed {
+        approval_process_id: ApprovalProcessId,
+        terms: TermValues,
+        maturity_date: EffectiveDate,
+        reschedule_overdue_obligations: bool,
+    },
+    AmendmentApprovalConcluded {
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+    },
+    Amended {
+        approval_process_id: ApprovalProcessId,
+        previous_terms: TermValues,
+        terms: TermValues,
+        previous_maturity_date: EffectiveDate,
+        maturity_date: EffectiveDate,
+        reschedule_overdue_obligations: bool,
+        effective: chrono::NaiveDate,
+    },
+    WriteOffProposed {
+        approval_process_id: ApprovalProcessId,
+    },
+    WriteOffApprovalConcluded {
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+    },
+    WriteOffAccountsCreated {
+        write_off_account_ids: WriteOffLedgerAccountIds,
+    },
+    WrittenOff {
+        approval_process_id: ApprovalProcessId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+    },
     Matured {},
     Completed {},
 }
@@ -213,6 +265,34 @@ pub struct LiquidationTrigger {
     pub initially_estimated_to_liquidate: Satoshis,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct CreditFacilityAmendment {
+    pub approval_process_id: ApprovalProcessId,
+    pub previous_terms: TermValues,
+    pub terms: TermValues,
+    pub previous_maturity_date: EffectiveDate,
+    pub maturity_date: EffectiveDate,
+    pub reschedule_overdue_obligations: bool,
+    pub effective: chrono::NaiveDate,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct CreditFacilityWriteOff {
+    pub approval_process_id: ApprovalProcessId,
+    pub amount: UsdCents,
+    pub effective: chrono::NaiveDate,
+}
+
+#[derive(Debug, Clone)]
+pub struct PendingCreditFacilityAmendment {
+    pub approval_process_id: ApprovalProcessId,
+    pub terms: TermValues,
+    pub maturity_date: EffectiveDate,
+    pub reschedule_overdue_obligations: bool,
+}
+
 impl CreditFacility {
     pub(crate) fn activation_data(
         &self,
@@ -318,11 +398,16 @@ impl CreditFacility {
         self.terms.is_single_disbursal()
     }
 
-    fn is_matured(&self) -> bool {
+    pub(super) fn is_matured(&self) -> bool {
         self.events
             .iter_all()
             .rev()
-            .any(|event| matches!(event, CreditFacilityEvent::Matured { .. }))
+            .find_map(|event| match event {
+                CreditFacilityEvent::Matured { .. } => Some(true),
+                CreditFacilityEvent::Amended { .. } => Some(false),
+                _ => None,
+            })
+            .unwrap_or(false)
     }
 
     pub fn status(&self) -> CreditFacilityStatus {
@@ -424,7 +509,11 @@ impl CreditFacility {
     }
 
     pub(crate) fn mature(&mut self) -> Idempotent<()> {
-        idempotency_guard!(self.events.iter_all(), CreditFacilityEvent::Matured { .. });
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CreditFacilityEvent::Matured { .. },
+            => CreditFacilityEvent::Amended { .. }
+        );
 
         if self.status() == CreditFacilityStatus::Closed {
             return Idempotent::AlreadyApplied;
@@ -470,13 +559,12 @@ impl CreditFacility {
     fn next_interest_accrual_cycle_period(
         &self,
     ) -> Result<Option<InterestPeriod>, CreditFacilityError> {
-        let last_accrual_start_date = self
-            .last_started_accrual_cycle()
-            .map(|cycle| cycle.period.start);
-
         let interval = self.terms.accrual_cycle_interval;
-        let full_period = match last_accrual_start_date {
-            Some(last_accrual_start_date) => interval.period_from(last_accrual_start_date).next(),
+        let full_period = match self.last_started_accrual_cycle() {
+            Some(last_cycle) => interval.period_after(
+                self.activated_at,
+                &interval.period_containing(self.activated_at, last_cycle.period.start),
+            ),
             None => interval.period_from(self.activated_at),
         };
 
@@ -578,6 +666,420 @@ impl CreditFacility {
         Ok(Idempotent::Executed((accrual_cycle_data, new_obligation)))
     }
 
+    pub(crate) fn create_penalty_accounts(&mut self) -> Idempotent<PenaltyLedgerAccountIds> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            CreditFacilityEvent::PenaltyAccountsCreated { .. }
+        );
+
+        let penalty_account_ids = PenaltyLedgerAccountIds::new();
+        self.account_ids.penalty_account_ids = Some(penalty_account_ids);
+        self.events
+            .push(CreditFacilityEvent::PenaltyAccountsCreated {
+                penalty_account_ids,
+            });
+
+        Idempotent::Executed(penalty_account_ids)
+    }
+
+    pub(crate) fn record_penalty(
+        &mut self,
+        overdue_obligation_id: ObligationId,
+        tx_ref: String,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+    ) -> Idempotent<(NewObligation, CreditFacilityPenalty)> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CreditFacilityEvent::PenaltyRecorded { tx_ref: existing, .. } if existing == &tx_ref
+        );
+
+        let account_ids = self
+            .account_ids
+            .penalty_account_ids
+            .expect("penalty accounts must be created before recording a penalty");
+
+        let tx_id = LedgerTxId::new();
+        let due_date = EffectiveDate::from(effective);
+        let overdue_date = self
+            .terms
+            .obligation_overdue_duration_from_due
+            .map(|d| d.end_date(due_date));
+        let liquidation_date = self
+            .terms
+            .obligation_liquidation_duration_from_due
+            .map(|d| d.end_date(due_date));
+        let new_obligation = NewObligation::builder()
+            .id(ObligationId::new())
+            .beneficiary_id(self.id)
+            .obligation_type(ObligationType::Penalty)
+            .reference(tx_ref.clone())
+            .amount(amount)
+            .tx_id(tx_id)
+            .receivable_account_ids(account_ids.into())
+            .defaulted_account_id(account_ids.defaulted_account_id)
+            .due_date(due_date)
+            .overdue_date(overdue_date)
+            .liquidation_date(liquidation_date)
+            .effective(effective)
+            .build()
+            .expect("could not build new penalty obligation");
+
+        self.events.push(CreditFacilityEvent::PenaltyRecorded {
+            obligation_id: new_obligation.id(),
+            overdue_obligation_id,
+            ledger_tx_id: tx_id,
+            tx_ref: tx_ref.clone(),
+            amount,
+            effective,
+        });
+
+        Idempotent::Executed((
+            new_obligation,
+            CreditFacilityPenalty {
+                tx_id,
+                tx_ref,
+                amount,
+                effective,
+                account_ids,
+                uncovered_outstanding_account_id: self.account_ids.uncovered_outstanding_account_id,
+            },
+        ))
+    }
+
+    /// Records a voluntary prepayment of `amount` principal ahead of maturity.
+    /// Returns the prepayment fee to be charged, if the terms define one.
+    pub(crate) fn record_prepayment(
+        &mut self,
+        payment_id: PaymentId,
+        amount: UsdCents,
+        principal_outstanding: UsdCents,
+        payment_source_account_id: CalaAccountId,
+        effective: chrono::NaiveDate,
+    ) -> Result<Idempotent<Option<CreditFacilityPrepaymentFee>>, CreditFacilityError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CreditFacilityEvent::PrepaymentRecorded { payment_id: existing, .. } if existing == &payment_id
+        );
+
+        match self.status() {
+            CreditFacilityStatus::Closed => {
+                return Err(CreditFacilityError::PrepaymentOnInactiveFacility);
+            }
+            CreditFacilityStatus::Matured => {
+                return Err(CreditFacilityError::PrepaymentPastMaturityDate);
+            }
+            CreditFacilityStatus::Active => {}
+        }
+        if EffectiveDate::from(effective) >= self.maturity_date {
+            return Err(CreditFacilityError::PrepaymentPastMaturityDate);
+        }
+        if amount > principal_outstanding {
+            return Err(CreditFacilityError::PrepaymentExceedsPrincipalOutstanding(
+                amount,
+                principal_outstanding,
+            ));
+        }
+
+        let fee = self.terms.prepayment_fee(amount);
+        let fee_tx_id = (!fee.is_zero()).then(LedgerTxId::new);
+        self.events.push(CreditFacilityEvent::PrepaymentRecorded {
+            payment_id,
+            amount,
+            fee,
+            fee_tx_id,
+            effective,
+        });
+
+        Ok(Idempotent::Executed(fee_tx_id.map(|tx_id| {
+            CreditFacilityPrepaymentFee {
+                tx_id,
+                tx_ref: format!("{}-prepayment-fee-{}", self.id, payment_id),
+                amount: fee,
+                effective,
+                payment_source_account_id,
+                fee_income_account_id: self.account_ids.fee_income_account_id,
+            }
+        })))
+    }
+
+    pub fn pending_amendment(&self) -> Option<PendingCreditFacilityAmendment> {
+        self.events.iter_all().rev().find_map(|event| match event {
+            CreditFacilityEvent::AmendmentApprovalConcluded { .. } => Some(None),
+            CreditFacilityEvent::AmendmentProposed {
+                approval_process_id,
+                terms,
+                maturity_date,
+                reschedule_overdue_obligations,
+            } => Some(Some(PendingCreditFacilityAmendment {
+                approval_process_id: *approval_process_id,
+                terms: *terms,
+                maturity_date: *maturity_date,
+                reschedule_overdue_obligations: *reschedule_overdue_obligations,
+            })),
+            _ => None,
+        })?
+    }
+
+    pub fn amendments(&self) -> Vec<CreditFacilityAmendment> {
+        self.events
+            .iter_all()
+            .filter_map(|event| match event {
+                CreditFacilityEvent::Amended {
+                    approval_process_id,
+                    previous_terms,
+                    terms,
+                    previous_maturity_date,
+                    maturity_date,
+                    reschedule_overdue_obligations,
+                    effective,
+                } => Some(CreditFacilityAmendment {
+                    approval_process_id: *approval_process_id,
+                    previous_terms: *previous_terms,
+                    terms: *terms,
+                    previous_maturity_date: *previous_maturity_date,
+                    maturity_date: *maturity_date,
+                    reschedule_overdue_obligations: *reschedule_overdue_obligations,
+                    effective: *effective,
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn last_amendment(&self) -> Option<CreditFacilityAmendment> {
+        self.amendments().pop()
+    }
+
+    /// Proposes replacing the facility's terms with `terms`. The maturity date is
+    /// recalculated from the activation date using the new duration, and the
+    /// amendment only takes effect once its approval process concludes.
+    pub(crate) fn propose_amendment(
+        &mut self,
+        terms: TermValues,
+        reschedule_overdue_obligations: bool,
+        now: DateTime<Utc>,
+    ) -> Result<ApprovalProcessId, CreditFacilityError> {
+        if self.status() == CreditFacilityStatus::Closed {
+            return Err(CreditFacilityError::AmendmentOnClosedFacility);
+        }
+        if self.pending_amendment().is_some() {
+            return Err(CreditFacilityError::AmendmentAlreadyPending);
+        }
+
+        let maturity_date = terms.maturity_date(self.activated_at);
+        if maturity_date.start_of_day() <= now {
+            return Err(CreditFacilityError::AmendedMaturityDateInPast);
+        }
+
+        let approval_process_id = ApprovalProcessId::new();
+        self.events.push(CreditFacilityEvent::AmendmentProposed {
+            approval_process_id,
+            terms,
+            maturity_date,
+            reschedule_overdue_obligations,
+        });
+
+        Ok(approval_process_id)
+    }
+
+    /// Concludes the approval of a proposed amendment and, if approved, swaps the
+    /// facility over to the amended terms and maturity date. Interest accrual
+    /// cycles already in progress complete under the terms they started with.
+    pub(crate) fn conclude_amendment_approval(
+        &mut self,
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        effective: chrono::NaiveDate,
+    ) -> Result<Idempotent<Option<CreditFacilityAmendment>>, CreditFacilityError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CreditFacilityEvent::AmendmentApprovalConcluded { approval_process_id: existing, .. }
+                if existing == &approval_process_id
+        );
+
+        let pending = self
+            .pending_amendment()
+            .filter(|pending| pending.approval_process_id == approval_process_id)
+            .ok_or(CreditFacilityError::NoSuchAmendmentProposed(
+                approval_process_id,
+            ))?;
+
+        self.events
+            .push(CreditFacilityEvent::AmendmentApprovalConcluded {
+                approval_process_id,
+                approved,
+            });
+
+        if !approved || self.status() == CreditFacilityStatus::Closed {
+            return Ok(Idempotent::Executed(None));
+        }
+
+        let amendment = CreditFacilityAmendment {
+            approval_process_id,
+            previous_terms: self.terms,
+            terms: pending.terms,
+            previous_maturity_date: self.maturity_date,
+            maturity_date: pending.maturity_date,
+            reschedule_overdue_obligations: pending.reschedule_overdue_obligations,
+            effective,
+        };
+        self.events.push(CreditFacilityEvent::Amended {
+            approval_process_id,
+            previous_terms: amendment.previous_terms,
+            terms: amendment.terms,
+            previous_maturity_date: amendment.previous_maturity_date,
+            maturity_date: amendment.maturity_date,
+            reschedule_overdue_obligations: amendment.reschedule_overdue_obligations,
+            effective,
+        });
+        self.terms = amendment.terms;
+        self.maturity_date = amendment.maturity_date;
+
+        Ok(Idempotent::Executed(Some(amendment)))
+    }
+
+    pub fn pending_write_off(&self) -> Option<ApprovalProcessId> {
+        self.events.iter_all().rev().find_map(|event| match event {
+            CreditFacilityEvent::WriteOffApprovalConcluded { .. } => Some(None),
+            CreditFacilityEvent::WriteOffProposed {
+                approval_process_id,
+            } => Some(Some(*approval_process_id)),
+            _ => None,
+        })?
+    }
+
+    pub fn write_offs(&self) -> Vec<CreditFacilityWriteOff> {
+        self.events
+            .iter_all()
+            .filter_map(|event| match event {
+                CreditFacilityEvent::WrittenOff {
+                    approval_process_id,
+                    amount,
+                    effective,
+                } => Some(CreditFacilityWriteOff {
+                    approval_process_id: *approval_process_id,
+                    amount: *amount,
+                    effective: *effective,
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn last_write_off(&self) -> Option<CreditFacilityWriteOff> {
+        self.write_offs().pop()
+    }
+
+    /// Proposes writing off the facility's defaulted balance. Nothing is moved
+    /// until the write-off approval process concludes.
+    pub(crate) fn propose_write_off(
+        &mut self,
+        defaulted: UsdCents,
+    ) -> Result<ApprovalProcessId, CreditFacilityError> {
+        if self.status() == CreditFacilityStatus::Closed {
+            return Err(CreditFacilityError::WriteOffOnClosedFacility);
+        }
+        if self.pending_write_off().is_some() {
+            return Err(CreditFacilityError::WriteOffAlreadyPending);
+        }
+        if defaulted.is_zero() {
+            return Err(CreditFacilityError::NoDefaultedBalanceToWriteOff);
+        }
+
+        let approval_process_id = ApprovalProcessId::new();
+        self.events.push(CreditFacilityEvent::WriteOffProposed {
+            approval_process_id,
+        });
+
+        Ok(approval_process_id)
+    }
+
+    /// Concludes the approval of a proposed write-off, returning whether the
+    /// defaulted obligations should now be written off.
+    pub(crate) fn conclude_write_off_approval(
+        &mut self,
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+    ) -> Result<Idempotent<bool>, CreditFacilityError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CreditFacilityEvent::WriteOffApprovalConcluded { approval_process_id: existing, .. }
+                if existing == &approval_process_id
+        );
+
+        if self.pending_write_off() != Some(approval_process_id) {
+            return Err(CreditFacilityError::NoSuchWriteOffProposed(
+                approval_process_id,
+            ));
+        }
+
+        self.events
+            .push(CreditFacilityEvent::WriteOffApprovalConcluded {
+                approval_process_id,
+                approved,
+            });
+
+        Ok(Idempotent::Executed(
+            approved && self.status() != CreditFacilityStatus::Closed,
+        ))
+    }
+
+    pub(crate) fn create_write_off_accounts(&mut self) -> Idempotent<WriteOffLedgerAccountIds> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            CreditFacilityEvent::WriteOffAccountsCreated { .. }
+        );
+
+        let write_off_account_ids = WriteOffLedgerAccountIds::new();
+        self.account_ids.write_off_account_ids = Some(write_off_account_ids);
+        self.events
+            .push(CreditFacilityEvent::WriteOffAccountsCreated {
+                write_off_account_ids,
+            });
+
+        Idempotent::Executed(write_off_account_ids)
+    }
+
+    pub(crate) fn record_write_off(
+        &mut self,
+        approval_process_id: ApprovalProcessId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+    ) -> Idempotent<CreditFacilityWriteOff> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CreditFacilityEvent::WrittenOff { approval_process_id: existing, .. }
+                if existing == &approval_process_id
+        );
+
+        self.events.push(CreditFacilityEvent::WrittenOff {
+            approval_process_id,
+            amount,
+            effective,
+        });
+
+        Idempotent::Executed(CreditFacilityWriteOff {
+            approval_process_id,
+            amount,
+            effective,
+        })
+    }
+
+    /// Starts a new interest accrual cycle when none is in progress, e.g. after
+    /// the maturity date of a matured facility has been extended.
+    pub(crate) fn resume_interest_accrual(
+        &mut self,
+    ) -> Result<Option<NewAccrualPeriods>, CreditFacilityError> {
+        if self.in_progress_accrual_cycle_id().is_some() {
+            return Ok(None);
+        }
+
+        Ok(self
+            .start_interest_accrual_cycle()?
+            .expect("start_interest_accrual_cycle always returns Executed"))
+    }
+
     pub fn interest_accrual_cycle_in_progress(&self) -> Option<&InterestAccrualCycle> {
         self.in_progress_accrual_cycle_id().map(|cycle_id| {
             self.interest_accruals
@@ -762,6 +1264,7 @@ impl CreditFacility {
 impl TryFromEvents<CreditFacilityEvent> for CreditFacility {
     fn try_from_events(events: EntityEvents<CreditFacilityEvent>) -> Result<Self, EsEntityError> {
         let mut builder = CreditFacilityBuilder::default();
+        let mut facility_account_ids = None;
         for event in events.iter_all() {
             match event {
                 CreditFacilityEvent::Initialized {
@@ -786,12 +1289,12 @@ impl TryFromEvents<CreditFacilityEvent> for CreditFacility {
                         .customer_id(*customer_id)
                         .collateral_id(*collateral_id)
                         .terms(*t)
-                        .account_ids(*account_ids)
                         .disbursal_credit_account_id(*disbursal_credit_account_id)
                         .structuring_fee_tx_id(*structuring_fee_tx_id)
                         .public_id(public_id.clone())
                         .activated_at(*activated_at)
                         .maturity_date(*maturity_date);
+                    facility_account_ids = Some(*account_ids);
                 }
                 CreditFacilityEvent::InterestAccrualCycleStarted { .. } => (),
                 CreditFacilityEvent::InterestAccrualCycleConcluded { .. } => (),
@@ -801,8 +1304,39 @@ impl TryFromEvents<CreditFacilityEvent> for CreditFacility {
                 CreditFacilityEvent::Completed { .. } => (),
                 CreditFacilityEvent::PartialLiquidationInitiated { .. } => {}
                 CreditFacilityEvent::ProceedsFromPartialLiquidationApplied { .. } => {}
+                CreditFacilityEvent::PenaltyAccountsCreated {
+                    penalty_account_ids,
+                } => {
+                    if let Some(account_ids) = facility_account_ids.as_mut() {
+                        account_ids.penalty_account_ids = Some(*penalty_account_ids);
+                    }
+                }
+                CreditFacilityEvent::PenaltyRecorded { .. } => {}
+                CreditFacilityEvent::WriteOffAccountsCreated {
+                    write_off_account_ids,
+                } => {
+                    if let Some(account_ids) = facility_account_ids.as_mut() {
+                        account_ids.write_off_account_ids = Some(*write_off_account_ids);
+                    }
+                }
+                CreditFacilityEvent::WriteOffProposed { .. } => {}
+                CreditFacilityEvent::WriteOffApprovalConcluded { .. } => {}
+                CreditFacilityEvent::WrittenOff { .. } => {}
+                CreditFacilityEvent::PrepaymentRecorded { .. } => {}
+                CreditFacilityEvent::AmendmentProposed { .. } => {}
+                CreditFacilityEvent::AmendmentApprovalConcluded { .. } => {}
+                CreditFacilityEvent::Amended {
+                    terms,
+                    maturity_date,
+                    ..
+                } => {
+                    builder = builder.terms(*terms).maturity_date(*maturity_date);
+                }
             }
         }
+        if let Some(account_ids) = facility_account_ids {
+            builder = builder.account_ids(account_ids);
+        }
         builder.events(events).build()
     }
 }
@@ -1136,6 +1670,451 @@ mod test {
         );
     }
 
+    mod penalty {
+        use super::*;
+
+        #[test]
+        fn penalty_accounts_are_created_once() {
+            let mut credit_facility = facility_from(initial_events());
+            assert!(credit_facility.account_ids.penalty_account_ids.is_none());
+
+            let account_ids = credit_facility
+                .create_penalty_accounts()
+                .expect("should create penalty accounts");
+            assert_eq!(
+                credit_facility
+                    .account_ids
+                    .penalty_account_ids
+                    .map(|ids| ids.income_account_id),
+                Some(account_ids.income_account_id)
+            );
+            assert!(
+                credit_facility
+                    .create_penalty_accounts()
+                    .was_already_applied()
+            );
+        }
+
+        #[test]
+        fn penalty_accounts_are_hydrated_from_events() {
+            let account_ids = PenaltyLedgerAccountIds::new();
+            let mut events = initial_events();
+            events.push(CreditFacilityEvent::PenaltyAccountsCreated {
+                penalty_account_ids: account_ids,
+            });
+            let credit_facility = facility_from(events);
+
+            assert_eq!(
+                credit_facility
+                    .account_ids
+                    .penalty_account_ids
+                    .map(|ids| ids.receivable_due_account_id),
+                Some(account_ids.receivable_due_account_id)
+            );
+        }
+
+        #[test]
+        fn record_penalty_is_idempotent_per_reference() {
+            let mut credit_facility = facility_from(initial_events());
+            let _ = credit_facility.create_penalty_accounts();
+            let overdue_obligation_id = ObligationId::new();
+            let effective = activated_at().date_naive();
+
+            let (new_obligation, penalty) = credit_facility
+                .record_penalty(
+                    overdue_obligation_id,
+                    "late-fee".to_string(),
+                    UsdCents::from(2_500),
+                    effective,
+                )
+                .expect("should record penalty");
+            assert_eq!(new_obligation.reference(), "late-fee");
+            assert_eq!(penalty.amount, UsdCents::from(2_500));
+
+            assert!(
+                credit_facility
+                    .record_penalty(
+                        overdue_obligation_id,
+                        "late-fee".to_string(),
+                        UsdCents::from(2_500),
+                        effective,
+                    )
+                    .was_already_applied()
+            );
+        }
+    }
+
+    mod prepayment {
+        use super::*;
+
+        fn facility_with_prepayment_fee(rate: u64) -> CreditFacility {
+            let mut events = initial_events();
+            if let CreditFacilityEvent::Initialized { terms, .. } = &mut events[0] {
+                terms.prepayment_fee_rate = Some(OneTimeFeeRatePct::new(rate));
+            }
+            facility_from(events)
+        }
+
+        #[test]
+        fn prepayment_without_fee_rate_charges_no_fee() {
+            let mut credit_facility = facility_from(initial_events());
+            let fee = credit_facility
+                .record_prepayment(
+                    PaymentId::new(),
+                    UsdCents::from(5_00),
+                    default_facility(),
+                    CalaAccountId::new(),
+                    activated_at().date_naive(),
+                )
+                .unwrap()
+                .unwrap();
+            assert!(fee.is_none());
+        }
+
+        #[test]
+        fn prepayment_fee_is_charged_on_prepaid_principal() {
+            let mut credit_facility = facility_with_prepayment_fee(2);
+            let fee = credit_facility
+                .record_prepayment(
+                    PaymentId::new(),
+                    UsdCents::from(5_00),
+                    default_facility(),
+                    CalaAccountId::new(),
+                    activated_at().date_naive(),
+                )
+                .unwrap()
+                .unwrap()
+                .expect("fee should be charged");
+            assert_eq!(fee.amount, UsdCents::from(10));
+            assert_eq!(
+                fee.fee_income_account_id,
+                credit_facility.account_ids.fee_income_account_id
+            );
+        }
+
+        #[test]
+        fn prepayment_is_idempotent_per_payment() {
+            let mut credit_facility = facility_with_prepayment_fee(2);
+            let payment_id = PaymentId::new();
+            let _ = credit_facility.record_prepayment(
+                payment_id,
+                UsdCents::from(5_00),
+                default_facility(),
+                CalaAccountId::new(),
+                activated_at().date_naive(),
+            );
+            assert!(
+                credit_facility
+                    .record_prepayment(
+                        payment_id,
+                        UsdCents::from(5_00),
+                        default_facility(),
+                        CalaAccountId::new(),
+                        activated_at().date_naive(),
+                    )
+                    .unwrap()
+                    .was_already_applied()
+            );
+        }
+
+        #[test]
+        fn prepayment_cannot_exceed_principal_outstanding() {
+            let mut credit_facility = facility_from(initial_events());
+            let res = credit_facility.record_prepayment(
+                PaymentId::new(),
+                default_facility() + UsdCents::ONE,
+                default_facility(),
+                CalaAccountId::new(),
+                activated_at().date_naive(),
+            );
+            assert!(matches!(
+                res,
+                Err(CreditFacilityError::PrepaymentExceedsPrincipalOutstanding(
+                    _,
+                    _
+                ))
+            ));
+        }
+
+        #[test]
+        fn prepayment_is_rejected_at_maturity() {
+            let mut credit_facility = facility_from(initial_events());
+            let res = credit_facility.record_prepayment(
+                PaymentId::new(),
+                UsdCents::from(5_00),
+                default_facility(),
+                CalaAccountId::new(),
+                credit_facility.maturity_date.start_of_day().date_naive(),
+            );
+            assert!(matches!(
+                res,
+                Err(CreditFacilityError::PrepaymentPastMaturityDate)
+            ));
+        }
+
+        #[test]
+        fn prepayment_is_rejected_on_closed_facility() {
+            let mut events = initial_events();
+            events.push(CreditFacilityEvent::Completed {});
+            let mut credit_facility = facility_from(events);
+            let res = credit_facility.record_prepayment(
+                PaymentId::new(),
+                UsdCents::from(5_00),
+                default_facility(),
+                CalaAccountId::new(),
+                activated_at().date_naive(),
+            );
+            assert!(matches!(
+                res,
+                Err(CreditFacilityError::PrepaymentOnInactiveFacility)
+            ));
+        }
+    }
+
+    mod amendment {
+        use super::*;
+
+        fn extended_terms() -> TermValues {
+            let mut terms = default_terms();
+            terms.duration = FacilityDuration::Months(6);
+            terms
+        }
+
+        #[test]
+        fn proposal_recalculates_maturity_from_activation() {
+            let mut credit_facility = facility_from(initial_events());
+            let approval_process_id = credit_facility
+                .propose_amendment(extended_terms(), false, activated_at())
+                .unwrap();
+
+            let pending = credit_facility.pending_amendment().unwrap();
+            assert_eq!(pending.approval_process_id, approval_process_id);
+            assert_eq!(
+                pending.maturity_date,
+                extended_terms().maturity_date(activated_at())
+            );
+            assert_eq!(credit_facility.terms, default_terms());
+        }
+
+        #[test]
+        fn errors_if_amendment_already_pending() {
+            let mut credit_facility = facility_from(initial_events());
+            credit_facility
+                .propose_amendment(extended_terms(), false, activated_at())
+                .unwrap();
+            assert!(matches!(
+                credit_facility.propose_amendment(extended_terms(), false, activated_at()),
+                Err(CreditFacilityError::AmendmentAlreadyPending)
+            ));
+        }
+
+        #[test]
+        fn errors_if_amended_maturity_in_past() {
+            let mut credit_facility = facility_from(initial_events());
+            let now = extended_terms()
+                .maturity_date(activated_at())
+                .start_of_day();
+            assert!(matches!(
+                credit_facility.propose_amendment(extended_terms(), false, now),
+                Err(CreditFacilityError::AmendedMaturityDateInPast)
+            ));
+        }
+
+        #[test]
+        fn approval_applies_new_terms() {
+            let mut credit_facility = facility_from(initial_events());
+            let previous_maturity_date = credit_facility.maturity_date;
+            let approval_process_id = credit_facility
+                .propose_amendment(extended_terms(), true, activated_at())
+                .unwrap();
+
+            let amendment = credit_facility
+                .conclude_amendment_approval(approval_process_id, true, activated_at().date_naive())
+                .unwrap()
+                .unwrap()
+                .expect("amendment should be applied");
+
+            assert_eq!(amendment.previous_terms, default_terms());
+            assert_eq!(amendment.previous_maturity_date, previous_maturity_date);
+            assert!(amendment.reschedule_overdue_obligations);
+            assert_eq!(credit_facility.terms, extended_terms());
+            assert_eq!(credit_facility.maturity_date, amendment.maturity_date);
+            assert!(credit_facility.pending_amendment().is_none());
+            assert_eq!(credit_facility.amendments().len(), 1);
+        }
+
+        #[test]
+        fn denial_keeps_existing_terms() {
+            let mut credit_facility = facility_from(initial_events());
+            let approval_process_id = credit_facility
+                .propose_amendment(extended_terms(), false, activated_at())
+                .unwrap();
+
+            let amendment = credit_facility
+                .conclude_amendment_approval(
+                    approval_process_id,
+                    false,
+                    activated_at().date_naive(),
+                )
+                .unwrap()
+                .unwrap();
+
+            assert!(amendment.is_none());
+            assert_eq!(credit_facility.terms, default_terms());
+            assert!(credit_facility.pending_amendment().is_none());
+            assert!(credit_facility.last_amendment().is_none());
+        }
+
+        #[test]
+        fn conclusion_is_idempotent() {
+            let mut credit_facility = facility_from(initial_events());
+            let approval_process_id = credit_facility
+                .propose_amendment(extended_terms(), false, activated_at())
+                .unwrap();
+            let _ = credit_facility.conclude_amendment_approval(
+                approval_process_id,
+                true,
+                activated_at().date_naive(),
+            );
+
+            assert!(
+                credit_facility
+                    .conclude_amendment_approval(
+                        approval_process_id,
+                        true,
+                        activated_at().date_naive()
+                    )
+                    .unwrap()
+                    .was_already_applied()
+            );
+        }
+
+        #[test]
+        fn extending_matured_facility_allows_maturing_again() {
+            let mut events = initial_events();
+            events.push(CreditFacilityEvent::Matured {});
+            let mut credit_facility = facility_from(events);
+            assert!(credit_facility.is_matured());
+
+            let approval_process_id = credit_facility
+                .propose_amendment(extended_terms(), false, activated_at())
+                .unwrap();
+            let _ = credit_facility.conclude_amendment_approval(
+                approval_process_id,
+                true,
+                activated_at().date_naive(),
+            );
+
+            assert!(!credit_facility.is_matured());
+            assert!(credit_facility.mature().did_execute());
+        }
+    }
+
+    mod write_off {
+        use super::*;
+
+        #[test]
+        fn errors_without_defaulted_balance() {
+            let mut credit_facility = facility_from(initial_events());
+            assert!(matches!(
+                credit_facility.propose_write_off(UsdCents::ZERO),
+                Err(CreditFacilityError::NoDefaultedBalanceToWriteOff)
+            ));
+        }
+
+        #[test]
+        fn errors_if_write_off_already_pending() {
+            let mut credit_facility = facility_from(initial_events());
+            credit_facility
+                .propose_write_off(UsdCents::from(100))
+                .unwrap();
+            assert!(matches!(
+                credit_facility.propose_write_off(UsdCents::from(100)),
+                Err(CreditFacilityError::WriteOffAlreadyPending)
+            ));
+        }
+
+        #[test]
+        fn approval_allows_write_off() {
+            let mut credit_facility = facility_from(initial_events());
+            let approval_process_id = credit_facility
+                .propose_write_off(UsdCents::from(100))
+                .unwrap();
+            assert_eq!(
+                credit_facility.pending_write_off(),
+                Some(approval_process_id)
+            );
+
+            assert!(
+                credit_facility
+                    .conclude_write_off_approval(approval_process_id, true)
+                    .unwrap()
+                    .unwrap()
+            );
+            assert!(credit_facility.pending_write_off().is_none());
+            assert!(
+                credit_facility
+                    .conclude_write_off_approval(approval_process_id, true)
+                    .unwrap()
+                    .was_already_applied()
+            );
+
+            credit_facility
+                .record_write_off(
+                    approval_process_id,
+                    UsdCents::from(100),
+                    activated_at().date_naive(),
+                )
+                .unwrap();
+            assert_eq!(
+                credit_facility.last_write_off().map(|w| w.amount),
+                Some(UsdCents::from(100))
+            );
+        }
+
+        #[test]
+        fn denial_does_not_write_off() {
+            let mut credit_facility = facility_from(initial_events());
+            let approval_process_id = credit_facility
+                .propose_write_off(UsdCents::from(100))
+                .unwrap();
+
+            assert!(
+                !credit_facility
+                    .conclude_write_off_approval(approval_process_id, false)
+                    .unwrap()
+                    .unwrap()
+            );
+            assert!(credit_facility.pending_write_off().is_none());
+        }
+
+        #[test]
+        fn write_off_accounts_are_hydrated_from_events() {
+            let mut credit_facility = facility_from(initial_events());
+            let account_ids = credit_facility
+                .create_write_off_accounts()
+                .expect("should create write-off accounts");
+            assert!(
+                credit_facility
+                    .create_write_off_accounts()
+                    .was_already_applied()
+            );
+
+            let mut events = initial_events();
+            events.push(CreditFacilityEvent::WriteOffAccountsCreated {
+                write_off_account_ids: account_ids,
+            });
+            let credit_facility = facility_from(events);
+            assert_eq!(
+                credit_facility
+                    .account_ids
+                    .write_off_account_ids
+                    .map(|ids| ids.recovery_income_account_id),
+                Some(account_ids.recovery_income_account_id)
+            );
+        }
+    }
+
     mod partial_liquidation {
         use super::*;
 
@@ -1152,6 +2131,8 @@ mod test {
                 due_interest_outstanding: UsdCents::ZERO,
                 overdue_interest_outstanding: UsdCents::ZERO,
                 interest_defaulted: UsdCents::ZERO,
+                penalty_outstanding: UsdCents::ZERO,
+                penalty_defaulted: UsdCents::ZERO,
 
                 facility: UsdCents::from(100000000),
                 facility_remaining: UsdCents::ZERO,
@@ -1300,6 +2281,8 @@ mod test {
                         due_interest_outstanding: UsdCents::ZERO,
                         overdue_interest_outstanding: UsdCents::ZERO,
                         interest_defaulted: UsdCents::ZERO,
+                        penalty_outstanding: UsdCents::ZERO,
+                        penalty_defaulted: UsdCents::ZERO,
 
                         facility: UsdCents::from(2),
                         facility_remaining: UsdCents::from(1),
@@ -1331,6 +2314,8 @@ mod test {
                     due_interest_outstanding: UsdCents::ZERO,
                     overdue_interest_outstanding: UsdCents::ZERO,
                     interest_defaulted: UsdCents::ZERO,
+                    penalty_outstanding: UsdCents::ZERO,
+                    penalty_defaulted: UsdCents::ZERO,
 
                     facility: UsdCents::from(2),
                     facility_remaining: UsdCents::from(1),
@@ -1358,6 +2343,8 @@ mod test {
                     due_interest_outstanding: UsdCents::ZERO,
                     overdue_interest_outstanding: UsdCents::ZERO,
                     interest_defaulted: UsdCents::ZERO,
+                    penalty_outstanding: UsdCents::ZERO,
+                    penalty_defaulted: UsdCents::ZERO,
 
                     facility: UsdCents::from(2),
                     facility_remaining: UsdCents::from(1),
@@ -1390,6 +2377,8 @@ mod test {
                     not_yet_due_interest_outstanding: UsdCents::ZERO,
                     overdue_interest_outstanding: UsdCents::ZERO,
                     interest_defaulted: UsdCents::ZERO,
+                    penalty_outstanding: UsdCents::ZERO,
+                    penalty_defaulted: UsdCents::ZERO,
 
                     facility: UsdCents::from(2),
                     facility_remaining: UsdCents::from(1),
@@ -1417,6 +2406,8 @@ mod test {
                     not_yet_due_interest_outstanding: UsdCents::ZERO,
                     overdue_interest_outstanding: UsdCents::ZERO,
                     interest_defaulted: UsdCents::ZERO,
+                    penalty_outstanding: UsdCents::ZERO,
+                    penalty_defaulted: UsdCents::ZERO,
 
                     facility: UsdCents::from(2),
                     facility_remaining: UsdCents::from(1),
@@ -1449,6 +2440,8 @@ mod test {
                     not_yet_due_interest_outstanding: UsdCents::ZERO,
                     due_interest_outstanding: UsdCents::ZERO,
                     interest_defaulted: UsdCents::ZERO,
+                    penalty_outstanding: UsdCents::ZERO,
+                    penalty_defaulted: UsdCents::ZERO,
 
                     facility: UsdCents::from(2),
                     facility_remaining: UsdCents::from(1),
@@ -1476,6 +2469,8 @@ mod test {
                     not_yet_due_interest_outstanding: UsdCents::ZERO,
                     due_interest_outstanding: UsdCents::ZERO,
                     interest_defaulted: UsdCents::ZERO,
+                    penalty_outstanding: UsdCents::ZERO,
+                    penalty_defaulted: UsdCents::ZERO,
 
                     facility: UsdCents::from(2),
                     facility_remaining: UsdCents::from(1),
@@ -1500,6 +2495,8 @@ mod test {
                 CreditFacilityBalanceSummary {
                     disbursed_defaulted: UsdCents::from(1),
                     interest_defaulted: UsdCents::ZERO,
+                    penalty_outstanding: UsdCents::ZERO,
+                    penalty_defaulted: UsdCents::ZERO,
 
                     collateral: Satoshis::ZERO,
                     not_yet_due_disbursed_outstanding: UsdCents::ZERO,
@@ -1527,6 +2524,8 @@ mod test {
                 CreditFacilityBalanceSummary {
                     disbursed_defaulted: UsdCents::ZERO,
                     interest_defaulted: UsdCents::from(1),
+                    penalty_outstanding: UsdCents::ZERO,
+                    penalty_defaulted: UsdCents::ZERO,
 
                     collateral: Satoshis::ZERO,
                     not_yet_due_disbursed_outstanding: UsdCents::ZERO,
@@ -26,6 +26,30 @@ pub enum CreditFacilityError {
     Denied,
     #[error("CreditFacilityError - DisbursalPastMaturityDate")]
     DisbursalPastMaturityDate,
+    #[error("CreditFacilityError - PrepaymentPastMaturityDate")]
+    PrepaymentPastMaturityDate,
+    #[error("CreditFacilityError - PrepaymentOnInactiveFacility")]
+    PrepaymentOnInactiveFacility,
+    #[error(
+        "CreditFacilityError - PrepaymentExceedsPrincipalOutstanding: amount '{0}' is larger than principal outstanding '{1}'"
+    )]
+    PrepaymentExceedsPrincipalOutstanding(UsdCents, UsdCents),
+    #[error("CreditFacilityError - AmendmentOnClosedFacility")]
+    AmendmentOnClosedFacility,
+    #[error("CreditFacilityError - AmendmentAlreadyPending")]
+    AmendmentAlreadyPending,
+    #[error("CreditFacilityError - AmendedMaturityDateInPast")]
+    AmendedMaturityDateInPast,
+    #[error("CreditFacilityError - NoSuchAmendmentProposed: {0}")]
+    NoSuchAmendmentProposed(crate::primitives::ApprovalProcessId),
+    #[error("CreditFacilityError - WriteOffOnClosedFacility")]
+    WriteOffOnClosedFacility,
+    #[error("CreditFacilityError - WriteOffAlreadyPending")]
+    WriteOffAlreadyPending,
+    #[error("CreditFacilityError - NoDefaultedBalanceToWriteOff")]
+    NoDefaultedBalanceToWriteOff,
+    #[error("CreditFacilityError - NoSuchWriteOffProposed: {0}")]
+    NoSuchWriteOffProposed(crate::primitives::ApprovalProcessId),
     #[error("CreditFacilityError - OnlyOneDisbursalAllowed")]
     OnlyOneDisbursalAllowed,
     #[error("CreditFacilityError - NotActivatedYet")]
@@ -98,6 +122,17 @@ impl ErrorSeverity for CreditFacilityError {
             Self::ApprovalInProgress => Level::WARN,
             Self::Denied => Level::WARN,
             Self::DisbursalPastMaturityDate => Level::WARN,
+            Self::PrepaymentPastMaturityDate => Level::WARN,
+            Self::PrepaymentOnInactiveFacility => Level::WARN,
+            Self::PrepaymentExceedsPrincipalOutstanding(_, _) => Level::WARN,
+            Self::AmendmentOnClosedFacility => Level::WARN,
+            Self::AmendmentAlreadyPending => Level::WARN,
+            Self::AmendedMaturityDateInPast => Level::WARN,
+            Self::NoSuchAmendmentProposed(_) => Level::ERROR,
+            Self::WriteOffOnClosedFacility => Level::WARN,
+            Self::WriteOffAlreadyPending => Level::WARN,
+            Self::NoDefaultedBalanceToWriteOff => Level::WARN,
+            Self::NoSuchWriteOffProposed(_) => Level::ERROR,
             Self::OnlyOneDisbursalAllowed => Level::WARN,
             Self::NotActivatedYet => Level::WARN,
             Self::InterestAccrualNotCompletedYet => Level::WARN,
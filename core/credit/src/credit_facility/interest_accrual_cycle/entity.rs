@@ -121,9 +121,7 @@ pub struct AccrualPosting {
 
 impl InterestAccrualCycle {
     fn accrual_cycle_ends_at(&self) -> EffectiveDate {
-        self.terms
-            .accrual_cycle_interval
-            .period_from(self.period.start)
+        self.period
             .truncate(self.facility_maturity_date.start_of_day())
             .expect("'period.start' should be before 'facility_maturity_date'")
             .end
@@ -215,11 +213,7 @@ impl InterestAccrualCycle {
             .next_accrual_period()
             .ok_or(InterestAccrualCycleError::NoNextAccrualPeriod)?;
 
-        let days_in_interest_period = accrual_period.days();
-        let interest_for_period = self
-            .terms
-            .annual_rate
-            .interest_for_time_period(amount, days_in_interest_period);
+        let interest_for_period = self.terms.interest_for_period(amount, &accrual_period);
 
         let accrual_tx_ref = format!("{}-interest-accrual-{}", self.id, self.count_accrued() + 1);
         let interest_accrual = InterestAccrualData {
@@ -424,7 +418,9 @@ mod test {
     }
 
     fn default_period() -> InterestPeriod {
-        InterestInterval::EndOfDay.period_from(default_started_at())
+        default_terms()
+            .accrual_cycle_interval
+            .period_from(default_started_at())
     }
 
     fn accrual_from(events: Vec<InterestAccrualCycleEvent>) -> InterestAccrualCycle {
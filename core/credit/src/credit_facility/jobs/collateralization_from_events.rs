@@ -106,8 +106,7 @@ where
                 tracing::field::display(entity.secured_loan_id),
             );
 
-            self.update_collateralization_from_events(entity.secured_loan_id)
-                .await?;
+            Box::pin(self.update_collateralization_from_events(entity.secured_loan_id)).await?;
         }
 
         if let Some(
@@ -127,8 +126,7 @@ where
                 tracing::field::display(beneficiary_id),
             );
 
-            self.update_collateralization_from_events(*beneficiary_id)
-                .await?;
+            Box::pin(self.update_collateralization_from_events(*beneficiary_id)).await?;
         }
 
         Ok(())
@@ -219,7 +217,7 @@ where
                 collateral_account_id,
             )
             .await?;
-        let price = self.price.usd_cents_per_btc().await;
+        let price = self.price.fresh_usd_cents_per_btc().await?;
 
         if credit_facility
             .update_collateralization(price, CVLPct::UPGRADE_BUFFER, balances)
@@ -109,14 +109,21 @@ where
     async fn mark_facility_as_matured(
         &self,
         credit_facility_id: CreditFacilityId,
-    ) -> Result<(), CreditFacilityError> {
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Option<chrono::DateTime<chrono::Utc>>, CreditFacilityError> {
         let mut facility = self.repo.find_by_id(credit_facility_id).await?;
 
+        // The maturity date may have been pushed out by an amendment after this job was scheduled.
+        // Amendments that bring it forward spawn a separate job instead.
+        if facility.matures_at() > now {
+            return Ok(Some(facility.matures_at()));
+        }
+
         if facility.mature().did_execute() {
             self.repo.update(&mut facility).await?;
         }
 
-        Ok(())
+        Ok(None)
     }
 }
 
@@ -130,11 +137,16 @@ where
 {
     async fn run(
         &self,
-        _current_job: CurrentJob,
+        current_job: CurrentJob,
     ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
-        self.mark_facility_as_matured(self.config.credit_facility_id)
-            .await?;
-        Ok(JobCompletion::Complete)
+        let now = current_job.clock().now();
+        match self
+            .mark_facility_as_matured(self.config.credit_facility_id, now)
+            .await?
+        {
+            Some(matures_at) => Ok(JobCompletion::RescheduleAt(matures_at)),
+            None => Ok(JobCompletion::Complete),
+        }
     }
 }
 
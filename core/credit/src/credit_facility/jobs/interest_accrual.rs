@@ -256,11 +256,11 @@ where
         tracing::debug!(?state, "Executing interest accrual state");
 
         match state {
-            InterestAccrualState::AccruePeriod => self.accrue_period(current_job).await,
+            InterestAccrualState::AccruePeriod => Box::pin(self.accrue_period(current_job)).await,
             InterestAccrualState::AwaitObligationsSync => {
-                self.await_obligations_sync(current_job).await
+                Box::pin(self.await_obligations_sync(current_job)).await
             }
-            InterestAccrualState::CompleteCycle => self.complete_cycle(current_job).await,
+            InterestAccrualState::CompleteCycle => Box::pin(self.complete_cycle(current_job)).await,
         }
     }
 }
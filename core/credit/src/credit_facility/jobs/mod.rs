@@ -3,3 +3,5 @@ pub mod collateralization_from_events;
 pub mod credit_facility_maturity;
 pub mod interest_accrual;
 pub mod liquidation_payment;
+pub mod obligation_penalties;
+pub mod penalty_accrual;
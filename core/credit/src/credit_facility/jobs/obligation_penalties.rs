@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use tracing::{Span, instrument};
+
+use authz::PermissionCheck;
+use core_custody::CoreCustodyEvent;
+use core_price::CorePriceEvent;
+use es_entity::DbOp;
+use governance::GovernanceEvent;
+use obix::out::{OutboxEventHandler, OutboxEventMarker, PersistentOutboxEvent};
+
+use job::JobType;
+
+use super::penalty_accrual::{PenaltyAccrualJobConfig, PenaltyAccrualJobSpawner};
+use crate::{
+    CoreCreditCollectionEvent, CoreCreditEvent, CreditFacilityId, ObligationType,
+    collateral::public::CoreCreditCollateralEvent, credit_facility::CreditFacilityRepo,
+};
+
+use core_credit_collection::PublicObligation;
+
+pub const CREDIT_FACILITY_OBLIGATION_PENALTIES_JOB: JobType =
+    JobType::new("outbox.credit-facility-obligation-penalties");
+
+pub struct CreditFacilityObligationPenaltiesHandler<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<CoreCreditCollateralEvent>
+        + OutboxEventMarker<CoreCreditCollectionEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustodyEvent>
+        + OutboxEventMarker<CorePriceEvent>,
+{
+    repo: Arc<CreditFacilityRepo<E>>,
+    penalty_accrual_job_spawner: PenaltyAccrualJobSpawner<Perms, E>,
+}
+
+impl<Perms, E> CreditFacilityObligationPenaltiesHandler<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<CoreCreditCollateralEvent>
+        + OutboxEventMarker<CoreCreditCollectionEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustodyEvent>
+        + OutboxEventMarker<CorePriceEvent>,
+{
+    pub fn new(
+        repo: Arc<CreditFacilityRepo<E>>,
+        penalty_accrual_job_spawner: PenaltyAccrualJobSpawner<Perms, E>,
+    ) -> Self {
+        Self {
+            repo,
+            penalty_accrual_job_spawner,
+        }
+    }
+}
+
+impl<Perms, E> OutboxEventHandler<E> for CreditFacilityObligationPenaltiesHandler<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<CoreCreditCollateralEvent>
+        + OutboxEventMarker<CoreCreditCollectionEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustodyEvent>
+        + OutboxEventMarker<CorePriceEvent>,
+{
+    #[instrument(name = "outbox.core_credit.obligation_penalties.process_message_in_op", parent = None, skip(self, op, event), fields(seq = %event.sequence, handled = false, event_type = tracing::field::Empty, credit_facility_id = tracing::field::Empty))]
+    async fn handle_persistent(
+        &self,
+        op: &mut DbOp<'_>,
+        event: &PersistentOutboxEvent<E>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(
+            e @ CoreCreditCollectionEvent::ObligationOverdue {
+                entity:
+                    PublicObligation {
+                        id,
+                        obligation_type,
+                        beneficiary_id,
+                        ..
+                    },
+            },
+        ) = event.as_event()
+        {
+            // Penalties do not compound on overdue penalties
+            if *obligation_type == ObligationType::Penalty {
+                return Ok(());
+            }
+
+            let credit_facility_id: CreditFacilityId = (*beneficiary_id).into();
+            let credit_facility = self
+                .repo
+                .find_by_id_in_op(&mut *op, credit_facility_id)
+                .await?;
+            if !credit_facility.terms.has_penalties() {
+                return Ok(());
+            }
+
+            event.inject_trace_parent();
+            Span::current().record("handled", true);
+            Span::current().record("event_type", e.as_ref());
+            Span::current().record(
+                "credit_facility_id",
+                tracing::field::display(credit_facility_id),
+            );
+
+            self.penalty_accrual_job_spawner
+                .spawn_in_op(
+                    op,
+                    job::JobId::from(*id),
+                    PenaltyAccrualJobConfig::<Perms, E> {
+                        credit_facility_id,
+                        obligation_id: *id,
+                        _phantom: std::marker::PhantomData,
+                    },
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,407 @@
+//! Penalty Accrual Job
+//!
+//! Spawned once per overdue obligation on a facility whose terms carry penalties.
+//!
+//! ```text
+//! ┌─────────────────────────────────────────────────────────────────────────┐
+//! │  First run                                                               │
+//! │    • Create the facility's penalty accounts (if not yet created)         │
+//! │    • Charge the flat late fee as a Penalty obligation (if configured)    │
+//! │    → no penalty rate: complete                                           │
+//! ├─────────────────────────────────────────────────────────────────────────┤
+//! │  Each day while the obligation is overdue                                │
+//! │    • Accrue the penalty rate on the obligation's outstanding amount      │
+//! │    • At the end of each accrual cycle, record the accrued amount as a    │
+//! │      Penalty obligation                                                  │
+//! │    → RescheduleAt(next day end)                                          │
+//! ├─────────────────────────────────────────────────────────────────────────┤
+//! │  Obligation paid or defaulted                                            │
+//! │    • Record any remaining accrued penalty                                │
+//! │    → complete                                                            │
+//! └─────────────────────────────────────────────────────────────────────────┘
+//! ```
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+use tracing_macros::record_error_severity;
+
+use std::sync::Arc;
+
+use audit::{AuditSvc, SystemSubject};
+use authz::PermissionCheck;
+use es_entity::Idempotent;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use job::*;
+use obix::out::OutboxEventMarker;
+
+use core_custody::{CoreCustodyAction, CoreCustodyEvent, CoreCustodyObject};
+use core_price::CorePriceEvent;
+
+use crate::{
+    CoreCreditAction, CoreCreditCollectionAction, CoreCreditCollectionEvent,
+    CoreCreditCollectionObject, CoreCreditEvent, CoreCreditObject, CreditFacilityId,
+    InterestInterval, ObligationId, ObligationStatus, UsdCents,
+    collateral::public::CoreCreditCollateralEvent,
+    credit_facility::{CreditFacility, CreditFacilityRepo, error::CreditFacilityError},
+    ledger::*,
+};
+
+use core_credit_collection::CoreCreditCollection;
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct PenaltyAccrualState {
+    late_fee_charged: bool,
+    next_accrual_starts_at: Option<DateTime<Utc>>,
+    cycle_ends_at: Option<DateTime<Utc>>,
+    cycle_idx: usize,
+    accrued: UsdCents,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PenaltyAccrualJobConfig<Perms, E> {
+    pub credit_facility_id: CreditFacilityId,
+    pub obligation_id: ObligationId,
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+
+impl<Perms, E> Clone for PenaltyAccrualJobConfig<Perms, E> {
+    fn clone(&self) -> Self {
+        Self {
+            credit_facility_id: self.credit_facility_id,
+            obligation_id: self.obligation_id,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+pub struct PenaltyAccrualJobInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<CoreCreditCollateralEvent>
+        + OutboxEventMarker<CoreCreditCollectionEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustodyEvent>
+        + OutboxEventMarker<CorePriceEvent>,
+{
+    ledger: Arc<CreditLedger>,
+    collections: Arc<CoreCreditCollection<Perms, E>>,
+    credit_facility_repo: Arc<CreditFacilityRepo<E>>,
+    authz: Arc<Perms>,
+}
+
+impl<Perms, E> PenaltyAccrualJobInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<CoreCreditCollateralEvent>
+        + OutboxEventMarker<CoreCreditCollectionEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustodyEvent>
+        + OutboxEventMarker<CorePriceEvent>,
+{
+    pub fn new(
+        ledger: Arc<CreditLedger>,
+        collections: Arc<CoreCreditCollection<Perms, E>>,
+        credit_facility_repo: Arc<CreditFacilityRepo<E>>,
+        authz: Arc<Perms>,
+    ) -> Self {
+        Self {
+            ledger,
+            collections,
+            credit_facility_repo,
+            authz,
+        }
+    }
+}
+
+const PENALTY_ACCRUAL_JOB: JobType = JobType::new("task.penalty-accrual");
+
+impl<Perms, E> JobInitializer for PenaltyAccrualJobInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>
+        + From<CoreCreditCollectionAction>
+        + From<GovernanceAction>
+        + From<CoreCustodyAction>
+        + From<crate::collateral::primitives::CoreCreditCollateralAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>
+        + From<CoreCreditCollectionObject>
+        + From<GovernanceObject>
+        + From<CoreCustodyObject>
+        + From<crate::collateral::primitives::CoreCreditCollateralObject>,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<CoreCreditCollateralEvent>
+        + OutboxEventMarker<CoreCreditCollectionEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustodyEvent>
+        + OutboxEventMarker<CorePriceEvent>,
+{
+    type Config = PenaltyAccrualJobConfig<Perms, E>;
+    fn job_type(&self) -> JobType {
+        PENALTY_ACCRUAL_JOB
+    }
+
+    fn init(
+        &self,
+        job: &Job,
+        _: JobSpawner<Self::Config>,
+    ) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(PenaltyAccrualJobRunner::<Perms, E> {
+            config: job.config()?,
+            collections: self.collections.clone(),
+            credit_facility_repo: self.credit_facility_repo.clone(),
+            ledger: self.ledger.clone(),
+            authz: self.authz.clone(),
+        }))
+    }
+}
+
+struct PenaltyAccrualJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<CoreCreditCollateralEvent>
+        + OutboxEventMarker<CoreCreditCollectionEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustodyEvent>
+        + OutboxEventMarker<CorePriceEvent>,
+{
+    config: PenaltyAccrualJobConfig<Perms, E>,
+    collections: Arc<CoreCreditCollection<Perms, E>>,
+    credit_facility_repo: Arc<CreditFacilityRepo<E>>,
+    ledger: Arc<CreditLedger>,
+    authz: Arc<Perms>,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for PenaltyAccrualJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>
+        + From<CoreCreditCollectionAction>
+        + From<GovernanceAction>
+        + From<CoreCustodyAction>
+        + From<crate::collateral::primitives::CoreCreditCollateralAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>
+        + From<CoreCreditCollectionObject>
+        + From<GovernanceObject>
+        + From<CoreCustodyObject>
+        + From<crate::collateral::primitives::CoreCreditCollateralObject>,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<CoreCreditCollateralEvent>
+        + OutboxEventMarker<CoreCreditCollectionEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustodyEvent>
+        + OutboxEventMarker<CorePriceEvent>,
+{
+    #[tracing::instrument(
+        name = "penalty_accrual.run",
+        skip(self, current_job),
+        fields(
+            credit_facility_id = %self.config.credit_facility_id,
+            obligation_id = %self.config.obligation_id
+        )
+    )]
+    async fn run(
+        &self,
+        mut current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut state = current_job
+            .execution_state::<PenaltyAccrualState>()?
+            .unwrap_or_default();
+        let now = current_job.clock().now();
+
+        let obligation = self
+            .collections
+            .obligations()
+            .find_by_id_without_audit(self.config.obligation_id)
+            .await?;
+
+        let mut op = self.credit_facility_repo.begin_op().await?;
+        self.authz
+            .audit()
+            .record_system_entry_in_op(
+                &mut op,
+                crate::primitives::PENALTY_ACCRUAL,
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::CREDIT_FACILITY_RECORD_INTEREST,
+            )
+            .await?;
+
+        let mut credit_facility = self
+            .credit_facility_repo
+            .find_by_id_in_op(&mut op, self.config.credit_facility_id)
+            .await?;
+
+        if let Idempotent::Executed(account_ids) = credit_facility.create_penalty_accounts() {
+            self.ledger
+                .create_penalty_accounts_in_op(&mut op, credit_facility.id, account_ids)
+                .await?;
+        }
+
+        if !state.late_fee_charged {
+            if let Some(late_fee) = credit_facility.terms.late_fee {
+                self.record_penalty_in_op(
+                    &mut op,
+                    &mut credit_facility,
+                    format!("{}-late-fee", self.config.obligation_id),
+                    late_fee,
+                    now.date_naive(),
+                )
+                .await?;
+            }
+            state.late_fee_charged = true;
+        }
+
+        if credit_facility.terms.penalty_rate.is_none() {
+            self.credit_facility_repo
+                .update_in_op(&mut op, &mut credit_facility)
+                .await?;
+            return Ok(JobCompletion::CompleteWithOp(op));
+        }
+
+        let obligation_closed = matches!(
+            obligation.status(),
+            ObligationStatus::Paid
+                | ObligationStatus::Defaulted
+                | ObligationStatus::Rescheduled
+                | ObligationStatus::WrittenOff
+        );
+        let period = InterestInterval::EndOfDay.period_from(
+            state
+                .next_accrual_starts_at
+                .or(obligation.overdue_at())
+                .unwrap_or(now),
+        );
+        let cycle_ends_at = *state.cycle_ends_at.get_or_insert(
+            credit_facility
+                .terms
+                .accrual_cycle_interval
+                .period_containing(credit_facility.activated_at, period.start)
+                .end,
+        );
+
+        if !obligation_closed {
+            if now < period.end {
+                self.credit_facility_repo
+                    .update_in_op(&mut op, &mut credit_facility)
+                    .await?;
+                current_job
+                    .update_execution_state_in_op(&mut op, &state)
+                    .await?;
+                return Ok(JobCompletion::RescheduleAtWithOp(op, period.end));
+            }
+
+            state.accrued += credit_facility
+                .terms
+                .penalty_for_period(obligation.outstanding(), &period);
+            state.next_accrual_starts_at = Some(period.next().start);
+        }
+
+        if (obligation_closed || period.end >= cycle_ends_at) && !state.accrued.is_zero() {
+            let effective = if obligation_closed {
+                now.date_naive()
+            } else {
+                period.end.date_naive()
+            };
+            self.record_penalty_in_op(
+                &mut op,
+                &mut credit_facility,
+                format!(
+                    "{}-penalty-accrual-cycle-{}",
+                    self.config.obligation_id, state.cycle_idx
+                ),
+                state.accrued,
+                effective,
+            )
+            .await?;
+            state.accrued = UsdCents::ZERO;
+            state.cycle_idx += 1;
+            state.cycle_ends_at = None;
+        } else if period.end >= cycle_ends_at {
+            state.cycle_ends_at = None;
+        }
+
+        self.credit_facility_repo
+            .update_in_op(&mut op, &mut credit_facility)
+            .await?;
+
+        if obligation_closed {
+            tracing::info!(
+                cycles = %state.cycle_idx,
+                "Obligation no longer overdue, penalty accrual completed"
+            );
+            return Ok(JobCompletion::CompleteWithOp(op));
+        }
+
+        current_job
+            .update_execution_state_in_op(&mut op, &state)
+            .await?;
+        Ok(JobCompletion::RescheduleAtWithOp(op, period.next().end))
+    }
+}
+
+impl<Perms, E> PenaltyAccrualJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>
+        + From<CoreCreditCollectionAction>
+        + From<GovernanceAction>
+        + From<CoreCustodyAction>
+        + From<crate::collateral::primitives::CoreCreditCollateralAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>
+        + From<CoreCreditCollectionObject>
+        + From<GovernanceObject>
+        + From<CoreCustodyObject>
+        + From<crate::collateral::primitives::CoreCreditCollateralObject>,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<CoreCreditCollateralEvent>
+        + OutboxEventMarker<CoreCreditCollectionEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustodyEvent>
+        + OutboxEventMarker<CorePriceEvent>,
+{
+    #[record_error_severity]
+    #[instrument(
+        name = "credit.credit_facility.record_penalty_in_op",
+        skip(self, op, credit_facility),
+        fields(credit_facility_id = %credit_facility.id)
+    )]
+    async fn record_penalty_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        credit_facility: &mut CreditFacility,
+        tx_ref: String,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+    ) -> Result<(), CreditFacilityError> {
+        if amount.is_zero() {
+            return Ok(());
+        }
+
+        if let Idempotent::Executed((new_obligation, penalty)) =
+            credit_facility.record_penalty(self.config.obligation_id, tx_ref, amount, effective)
+        {
+            self.collections
+                .obligations()
+                .create_with_jobs_in_op(op, new_obligation)
+                .await?;
+            self.ledger
+                .record_penalty_in_op(
+                    op,
+                    penalty,
+                    &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(
+                        crate::primitives::PENALTY_ACCRUAL,
+                    ),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+pub type PenaltyAccrualJobSpawner<Perms, E> = JobSpawner<PenaltyAccrualJobConfig<Perms, E>>;
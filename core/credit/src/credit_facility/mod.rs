@@ -34,7 +34,10 @@ use core_credit_collection::CoreCreditCollection;
 use core_custody::{CoreCustodyAction, CoreCustodyEvent, CoreCustodyObject};
 
 pub(crate) use entity::*;
-pub use entity::{CreditFacility, FacilityCollateralization, LiquidationTrigger};
+pub use entity::{
+    CreditFacility, CreditFacilityAmendment, CreditFacilityWriteOff, FacilityCollateralization,
+    LiquidationTrigger, PendingCreditFacilityAmendment,
+};
 use interest_accrual_cycle::NewInterestAccrualCycleData;
 
 #[cfg(feature = "json-schema")]
@@ -68,6 +71,7 @@ where
     credit_facility_maturity_job_spawner:
         jobs::credit_facility_maturity::CreditFacilityMaturityJobSpawner<E>,
     interest_accrual_job_spawner: jobs::interest_accrual::InterestAccrualJobSpawner<Perms, E>,
+    clock: ClockHandle,
 }
 
 impl<Perms, E> Clone for CreditFacilities<Perms, E>
@@ -94,6 +98,7 @@ where
             public_ids: self.public_ids.clone(),
             credit_facility_maturity_job_spawner: self.credit_facility_maturity_job_spawner.clone(),
             interest_accrual_job_spawner: self.interest_accrual_job_spawner.clone(),
+            clock: self.clock.clone(),
         }
     }
 }
@@ -141,6 +146,13 @@ where
     ) -> Result<Self, CreditFacilityError> {
         let repo = Arc::new(CreditFacilityRepo::new(pool, publisher, clock.clone()));
 
+        governance
+            .init_policy(crate::APPROVE_CREDIT_FACILITY_AMENDMENT_PROCESS)
+            .await?;
+        governance
+            .init_policy(crate::APPROVE_CREDIT_FACILITY_WRITE_OFF_PROCESS)
+            .await?;
+
         outbox
             .register_event_handler(
                 jobs,
@@ -174,6 +186,28 @@ where
             ),
         );
 
+        let penalty_accrual_job_spawner = jobs.add_initializer(
+            jobs::penalty_accrual::PenaltyAccrualJobInit::<Perms, E>::new(
+                ledger.clone(),
+                collections.clone(),
+                repo.clone(),
+                authz.clone(),
+            ),
+        );
+
+        outbox
+            .register_event_handler(
+                jobs,
+                OutboxEventJobConfig::new(
+                    jobs::obligation_penalties::CREDIT_FACILITY_OBLIGATION_PENALTIES_JOB,
+                ),
+                jobs::obligation_penalties::CreditFacilityObligationPenaltiesHandler::<Perms, E>::new(
+                    repo.clone(),
+                    penalty_accrual_job_spawner,
+                ),
+            )
+            .await?;
+
         let liquidation_payment_job_spawner =
             jobs.add_initializer(jobs::liquidation_payment::LiquidationPaymentInit::new(
                 outbox,
@@ -209,9 +243,14 @@ where
             public_ids,
             credit_facility_maturity_job_spawner,
             interest_accrual_job_spawner,
+            clock,
         })
     }
 
+    pub(super) fn credit_facility_repo(&self) -> Arc<CreditFacilityRepo<E>> {
+        self.repo.clone()
+    }
+
     pub(super) async fn begin_op(&self) -> Result<es_entity::DbOp<'static>, CreditFacilityError> {
         Ok(self.repo.begin_op().await?)
     }
@@ -352,7 +391,7 @@ where
         credit_facility_id: CreditFacilityId,
         upgrade_buffer_cvl_pct: CVLPct,
     ) -> Result<CompletionOutcome, CreditFacilityError> {
-        let price = self.price.usd_cents_per_btc().await;
+        let price = self.price.fresh_usd_cents_per_btc().await?;
 
         let mut credit_facility = self.repo.find_by_id_in_op(db, credit_facility_id).await?;
 
@@ -384,6 +423,348 @@ where
         Ok(CompletionOutcome::Completed((credit_facility, completion)))
     }
 
+    #[record_error_severity]
+    #[instrument(
+        name = "credit.credit_facility.record_prepayment_in_op",
+        skip(self, db, initiated_by),
+        fields(credit_facility_id = %credit_facility_id)
+    )]
+    pub(super) async fn record_prepayment_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        credit_facility_id: CreditFacilityId,
+        payment_id: PaymentId,
+        amount: UsdCents,
+        payment_source_account_id: CalaAccountId,
+        effective: chrono::NaiveDate,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<CreditFacility, CreditFacilityError> {
+        let mut credit_facility = self
+            .repo
+            .find_by_id_in_op(&mut *db, credit_facility_id)
+            .await?;
+
+        let collateral_account_id = self
+            .collaterals
+            .collateral_ledger_account_ids_in_op(db, credit_facility.collateral_id)
+            .await?
+            .collateral_account_id;
+
+        let balances = self
+            .ledger
+            .get_credit_facility_balance_in_op(
+                db,
+                credit_facility.account_ids,
+                collateral_account_id,
+            )
+            .await?;
+
+        let es_entity::Idempotent::Executed(fee) = credit_facility.record_prepayment(
+            payment_id,
+            amount,
+            balances.disbursed_outstanding(),
+            payment_source_account_id,
+            effective,
+        )?
+        else {
+            return Ok(credit_facility);
+        };
+
+        self.repo.update_in_op(db, &mut credit_facility).await?;
+
+        if let Some(fee) = fee {
+            self.ledger
+                .record_prepayment_fee_in_op(db, fee, initiated_by)
+                .await?;
+        }
+
+        Ok(credit_facility)
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "credit.credit_facility.propose_amendment",
+        skip(self, terms),
+        fields(credit_facility_id = %credit_facility_id)
+    )]
+    pub(super) async fn propose_amendment(
+        &self,
+        credit_facility_id: CreditFacilityId,
+        terms: TermValues,
+        reschedule_overdue_obligations: bool,
+    ) -> Result<CreditFacility, CreditFacilityError> {
+        let mut db = self.repo.begin_op().await?;
+        let mut credit_facility = self
+            .repo
+            .find_by_id_in_op(&mut db, credit_facility_id)
+            .await?;
+
+        let approval_process_id = credit_facility.propose_amendment(
+            terms,
+            reschedule_overdue_obligations,
+            self.clock.now(),
+        )?;
+
+        self.governance
+            .start_process_in_op(
+                &mut db,
+                approval_process_id,
+                credit_facility_id.to_string(),
+                crate::APPROVE_CREDIT_FACILITY_AMENDMENT_PROCESS,
+            )
+            .await?;
+        self.repo
+            .update_in_op(&mut db, &mut credit_facility)
+            .await?;
+        db.commit().await?;
+
+        Ok(credit_facility)
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "credit.credit_facility.conclude_amendment_approval_in_op",
+        skip(self, db),
+        fields(credit_facility_id = %credit_facility_id, already_applied)
+    )]
+    pub(super) async fn conclude_amendment_approval_in_op(
+        &self,
+        db: &mut es_entity::DbOpWithTime<'_>,
+        credit_facility_id: CreditFacilityId,
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+    ) -> Result<CreditFacility, CreditFacilityError> {
+        self.authz
+            .audit()
+            .record_system_entry_in_op(
+                db,
+                CREDIT_FACILITY_AMENDMENT_APPROVAL,
+                CoreCreditObject::credit_facility(credit_facility_id),
+                CoreCreditAction::CREDIT_FACILITY_AMEND,
+            )
+            .await?;
+
+        let mut credit_facility = self
+            .repo
+            .find_by_id_in_op(&mut *db, credit_facility_id)
+            .await?;
+        let was_matured = credit_facility.is_matured();
+        let previous_matures_at = credit_facility.matures_at();
+        let effective = db.now().date_naive();
+
+        let amendment = match credit_facility.conclude_amendment_approval(
+            approval_process_id,
+            approved,
+            effective,
+        )? {
+            es_entity::Idempotent::AlreadyApplied => {
+                tracing::Span::current().record("already_applied", true);
+                return Ok(credit_facility);
+            }
+            es_entity::Idempotent::Executed(amendment) => {
+                tracing::Span::current().record("already_applied", false);
+                amendment
+            }
+        };
+
+        let Some(amendment) = amendment else {
+            self.repo.update_in_op(db, &mut credit_facility).await?;
+            return Ok(credit_facility);
+        };
+
+        let periods = credit_facility.resume_interest_accrual()?;
+        self.repo.update_in_op(db, &mut credit_facility).await?;
+
+        if let Some(periods) = periods {
+            let accrual_id = credit_facility
+                .interest_accrual_cycle_in_progress()
+                .expect("Resumed accrual not found")
+                .id;
+            self.interest_accrual_job_spawner
+                .spawn_at_in_op(
+                    db,
+                    accrual_id,
+                    jobs::interest_accrual::InterestAccrualJobConfig::<Perms, E> {
+                        credit_facility_id,
+                        _phantom: std::marker::PhantomData,
+                    },
+                    periods.accrual.end,
+                )
+                .await?;
+        }
+
+        // A pending maturity job only ever reschedules itself later, so an earlier
+        // maturity date needs a job of its own. The superseded job then finds the
+        // facility already matured and completes without effect.
+        if was_matured || credit_facility.matures_at() < previous_matures_at {
+            self.credit_facility_maturity_job_spawner
+                .spawn_at_in_op(
+                    db,
+                    JobId::new(),
+                    jobs::credit_facility_maturity::CreditFacilityMaturityJobConfig::<E> {
+                        credit_facility_id,
+                        _phantom: std::marker::PhantomData,
+                    },
+                    credit_facility.matures_at(),
+                )
+                .await?;
+        }
+
+        if amendment.reschedule_overdue_obligations {
+            let due = amendment.maturity_date;
+            let dates = core_credit_collection::ObligationLifecycleDates {
+                due,
+                overdue: amendment.terms.get_overdue_date_from_due_date(due),
+                liquidation: amendment.terms.get_liquidation_date_from_due_date(due),
+                defaulted: None,
+            };
+            self.collections
+                .obligations()
+                .reschedule_overdue_in_op(
+                    db,
+                    credit_facility_id.into(),
+                    dates,
+                    effective,
+                    &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(
+                        CREDIT_FACILITY_AMENDMENT_APPROVAL,
+                    ),
+                )
+                .await?;
+        }
+
+        Ok(credit_facility)
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "credit.credit_facility.propose_write_off",
+        skip(self),
+        fields(credit_facility_id = %credit_facility_id)
+    )]
+    pub(super) async fn propose_write_off(
+        &self,
+        credit_facility_id: CreditFacilityId,
+    ) -> Result<CreditFacility, CreditFacilityError> {
+        let mut db = self.repo.begin_op().await?;
+        let mut credit_facility = self
+            .repo
+            .find_by_id_in_op(&mut db, credit_facility_id)
+            .await?;
+
+        let collateral_account_id = self
+            .collaterals
+            .collateral_ledger_account_ids_in_op(&mut db, credit_facility.collateral_id)
+            .await?
+            .collateral_account_id;
+        let balances = self
+            .ledger
+            .get_credit_facility_balance_in_op(
+                &mut db,
+                credit_facility.account_ids,
+                collateral_account_id,
+            )
+            .await?;
+
+        let approval_process_id = credit_facility.propose_write_off(balances.total_defaulted())?;
+
+        self.governance
+            .start_process_in_op(
+                &mut db,
+                approval_process_id,
+                credit_facility_id.to_string(),
+                crate::APPROVE_CREDIT_FACILITY_WRITE_OFF_PROCESS,
+            )
+            .await?;
+        self.repo
+            .update_in_op(&mut db, &mut credit_facility)
+            .await?;
+        db.commit().await?;
+
+        Ok(credit_facility)
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "credit.credit_facility.conclude_write_off_approval_in_op",
+        skip(self, db),
+        fields(credit_facility_id = %credit_facility_id, already_applied, n_written_off)
+    )]
+    pub(super) async fn conclude_write_off_approval_in_op(
+        &self,
+        db: &mut es_entity::DbOpWithTime<'_>,
+        credit_facility_id: CreditFacilityId,
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+    ) -> Result<CreditFacility, CreditFacilityError> {
+        self.authz
+            .audit()
+            .record_system_entry_in_op(
+                db,
+                CREDIT_FACILITY_WRITE_OFF_APPROVAL,
+                CoreCreditObject::credit_facility(credit_facility_id),
+                CoreCreditAction::CREDIT_FACILITY_WRITE_OFF,
+            )
+            .await?;
+
+        let mut credit_facility = self
+            .repo
+            .find_by_id_in_op(&mut *db, credit_facility_id)
+            .await?;
+
+        match credit_facility.conclude_write_off_approval(approval_process_id, approved)? {
+            es_entity::Idempotent::AlreadyApplied => {
+                tracing::Span::current().record("already_applied", true);
+                return Ok(credit_facility);
+            }
+            es_entity::Idempotent::Executed(write_off) => {
+                tracing::Span::current().record("already_applied", false);
+                if !write_off {
+                    self.repo.update_in_op(db, &mut credit_facility).await?;
+                    return Ok(credit_facility);
+                }
+            }
+        }
+
+        if let es_entity::Idempotent::Executed(account_ids) =
+            credit_facility.create_write_off_accounts()
+        {
+            self.ledger
+                .create_write_off_accounts_in_op(db, credit_facility_id, account_ids)
+                .await?;
+        }
+        let account_ids = credit_facility
+            .account_ids
+            .write_off_account_ids
+            .expect("write-off accounts were just created");
+
+        let effective = db.now().date_naive();
+        let written_off = self
+            .collections
+            .obligations()
+            .write_off_defaulted_in_op(
+                db,
+                credit_facility_id.into(),
+                account_ids.loan_loss_expense_account_id,
+                account_ids.recovery_income_account_id,
+                effective,
+                &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(
+                    CREDIT_FACILITY_WRITE_OFF_APPROVAL,
+                ),
+            )
+            .await?;
+        tracing::Span::current().record("n_written_off", written_off.len());
+
+        let amount = written_off
+            .iter()
+            .fold(UsdCents::ZERO, |total, obligation| {
+                total + obligation.outstanding()
+            });
+        let _ = credit_facility.record_write_off(approval_process_id, amount, effective);
+        self.repo.update_in_op(db, &mut credit_facility).await?;
+
+        Ok(credit_facility)
+    }
+
     pub async fn find_by_id_without_audit(
         &self,
         id: impl Into<CreditFacilityId> + std::fmt::Debug,
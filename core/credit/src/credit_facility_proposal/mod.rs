@@ -117,6 +117,20 @@ where
             )
             .await?;
 
+        self.conclude_customer_approval_without_audit(id, approved)
+            .await
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "credit.credit_facility_proposals.conclude_customer_approval_without_audit",
+        skip(self)
+    )]
+    pub(crate) async fn conclude_customer_approval_without_audit(
+        &self,
+        id: CreditFacilityProposalId,
+        approved: bool,
+    ) -> Result<CreditFacilityProposal, CreditFacilityProposalError> {
         let mut proposal = self.repo.find_by_id(id).await?;
 
         match proposal.conclude_customer_approval(approved) {
@@ -125,11 +139,12 @@ where
 
                 if approved {
                     self.governance
-                        .start_process_in_op(
+                        .start_process_with_amount_in_op(
                             &mut db,
                             id,
                             id.to_string(),
                             crate::APPROVE_CREDIT_FACILITY_PROPOSAL_PROCESS,
+                            Some(proposal.amount.into_inner()),
                         )
                         .await?;
                 }
@@ -176,6 +191,13 @@ where
         }
     }
 
+    pub(crate) async fn find_by_id_without_audit(
+        &self,
+        id: CreditFacilityProposalId,
+    ) -> Result<CreditFacilityProposal, CreditFacilityProposalError> {
+        self.repo.find_by_id(id).await
+    }
+
     #[record_error_severity]
     #[instrument(name = "credit.credit_facility_proposals.find_all", skip(self, ids))]
     pub async fn find_all<T: From<CreditFacilityProposal>>(
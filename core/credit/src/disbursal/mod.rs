@@ -103,11 +103,12 @@ where
         new_disbursal: NewDisbursal,
     ) -> Result<Disbursal, DisbursalError> {
         self.governance
-            .start_process_in_op(
+            .start_process_with_amount_in_op(
                 db,
                 new_disbursal.approval_process_id,
                 new_disbursal.approval_process_id.to_string(),
                 crate::APPROVE_DISBURSAL_PROCESS,
+                Some(new_disbursal.amount.into_inner()),
             )
             .await?;
         let disbursal = self.repo.create_in_op(db, new_disbursal).await?;
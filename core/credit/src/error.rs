@@ -62,6 +62,8 @@ pub enum CoreCreditError {
     RegisterEventHandler(#[from] Box<dyn std::error::Error + Send + Sync>),
     #[error("CoreCreditError - CustomerMismatchForCreditFacility")]
     CustomerMismatchForCreditFacility,
+    #[error("CoreCreditError - CustomerMismatchForCreditFacilityProposal")]
+    CustomerMismatchForCreditFacilityProposal,
     #[error("CoreCreditError - SubjectIsNotCustomer")]
     SubjectIsNotCustomer,
     #[error("CoreCreditError - CustomerIsNotVerified")]
@@ -102,6 +104,7 @@ impl ErrorSeverity for CoreCreditError {
             Self::JobError(_) => Level::ERROR,
             Self::RegisterEventHandler(_) => Level::ERROR,
             Self::CustomerMismatchForCreditFacility => Level::ERROR,
+            Self::CustomerMismatchForCreditFacilityProposal => Level::ERROR,
             Self::SubjectIsNotCustomer => Level::WARN,
             Self::CustomerNotVerified => Level::WARN,
             Self::DisbursalBuilderError(_) => Level::ERROR,
@@ -19,10 +19,12 @@ where
         + OutboxEventMarker<CoreCreditCollectionEvent>
         + OutboxEventMarker<GovernanceEvent>
         + OutboxEventMarker<CoreCustodyEvent>
-        + OutboxEventMarker<CorePriceEvent>,
+        + OutboxEventMarker<CorePriceEvent>
+        + OutboxEventMarker<CoreCustomerEvent>,
 {
     customer_id: CustomerId,
     subject: &'a <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+    credit: &'a CoreCredit<Perms, E>,
     authz: &'a Perms,
     credit_facilities: &'a CreditFacilities<Perms, E>,
     collaterals: &'a Collaterals<Perms, E>,
@@ -39,11 +41,13 @@ where
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>
         + From<CoreCreditCollectionAction>
         + From<GovernanceAction>
+        + From<CoreCustomerAction>
         + From<CoreCustodyAction>
         + From<crate::collateral::primitives::CoreCreditCollateralAction>,
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>
         + From<CoreCreditCollectionObject>
         + From<GovernanceObject>
+        + From<CustomerObject>
         + From<CoreCustodyObject>
         + From<crate::collateral::primitives::CoreCreditCollateralObject>,
     E: OutboxEventMarker<CoreCreditEvent>
@@ -51,11 +55,13 @@ where
         + OutboxEventMarker<CoreCreditCollectionEvent>
         + OutboxEventMarker<GovernanceEvent>
         + OutboxEventMarker<CoreCustodyEvent>
-        + OutboxEventMarker<CorePriceEvent>,
+        + OutboxEventMarker<CorePriceEvent>
+        + OutboxEventMarker<CoreCustomerEvent>,
 {
     pub(super) fn new(
         subject: &'a <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         customer_id: CustomerId,
+        credit: &'a CoreCredit<Perms, E>,
         authz: &'a Perms,
         credit_facilities: &'a CreditFacilities<Perms, E>,
         collaterals: &'a Collaterals<Perms, E>,
@@ -68,6 +74,7 @@ where
         Self {
             customer_id,
             subject,
+            credit,
             authz,
             credit_facilities,
             collaterals,
@@ -99,7 +106,7 @@ where
         let credit_facility = self.credit_facilities.find_by_id_without_audit(id).await?;
 
         self.ensure_credit_facility_access(
-            &credit_facility,
+            credit_facility.customer_id,
             CoreCreditObject::credit_facility(id),
             CoreCreditAction::CREDIT_FACILITY_READ,
         )
@@ -121,7 +128,7 @@ where
         let credit_facility = self.credit_facilities.find_by_id_without_audit(id).await?;
 
         self.ensure_credit_facility_access(
-            &credit_facility,
+            credit_facility.customer_id,
             CoreCreditObject::credit_facility(id),
             CoreCreditAction::CREDIT_FACILITY_READ,
         )
@@ -141,7 +148,7 @@ where
         let credit_facility = self.credit_facilities.find_by_id_without_audit(id).await?;
 
         self.ensure_credit_facility_access(
-            &credit_facility,
+            credit_facility.customer_id,
             CoreCreditObject::credit_facility(id),
             CoreCreditAction::CREDIT_FACILITY_READ,
         )
@@ -169,7 +176,7 @@ where
         match self.credit_facilities.find_by_id_without_audit(id).await {
             Ok(cf) => {
                 self.ensure_credit_facility_access(
-                    &cf,
+                    cf.customer_id,
                     CoreCreditObject::credit_facility(id),
                     CoreCreditAction::CREDIT_FACILITY_READ,
                 )
@@ -181,13 +188,95 @@ where
         }
     }
 
+    pub async fn create_facility_proposal(
+        &self,
+        deposit_account_holder_id: impl Into<CustomerId> + std::fmt::Debug,
+        deposit_account_id: impl Into<CalaAccountId> + std::fmt::Debug + Copy,
+        amount: UsdCents,
+        terms: TermValues,
+    ) -> Result<CreditFacilityProposal, CoreCreditError> {
+        self.ensure_credit_facility_access(
+            deposit_account_holder_id.into(),
+            CoreCreditObject::all_credit_facilities(),
+            CoreCreditAction::CREDIT_FACILITY_CREATE,
+        )
+        .await?;
+
+        self.credit
+            .create_facility_proposal_without_audit(
+                self.customer_id,
+                deposit_account_id,
+                amount,
+                terms,
+                None::<CustodianId>,
+            )
+            .await
+    }
+
+    pub async fn conclude_proposal_customer_approval(
+        &self,
+        id: impl Into<CreditFacilityProposalId> + std::fmt::Debug,
+        approved: bool,
+    ) -> Result<CreditFacilityProposal, CoreCreditError> {
+        let id = id.into();
+        let proposal = self
+            .credit
+            .credit_facility_proposals
+            .find_by_id_without_audit(id)
+            .await?;
+
+        let authorized = proposal.customer_id == self.customer_id;
+        self.authz
+            .audit()
+            .record_entry(
+                self.subject,
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::CREDIT_FACILITY_CUSTOMER_APPROVE,
+                authorized,
+            )
+            .await?;
+        if !authorized {
+            return Err(CoreCreditError::CustomerMismatchForCreditFacilityProposal);
+        }
+
+        Ok(self
+            .credit
+            .credit_facility_proposals
+            .conclude_customer_approval_without_audit(id, approved)
+            .await?)
+    }
+
+    pub async fn record_collateral_top_up_intent(
+        &self,
+        id: impl Into<CreditFacilityId> + std::fmt::Debug,
+        amount: Satoshis,
+    ) -> Result<CreditFacility, CoreCreditError> {
+        let credit_facility = self
+            .credit_facilities
+            .find_by_id_without_audit(id.into())
+            .await?;
+
+        self.ensure_credit_facility_access(
+            credit_facility.customer_id,
+            CoreCreditCollateralObject::collateral(credit_facility.collateral_id),
+            CoreCreditCollateralAction::COLLATERAL_RECORD_TOP_UP_INTENT,
+        )
+        .await?;
+
+        self.collaterals
+            .record_top_up_intent_without_audit(credit_facility.collateral_id, amount)
+            .await?;
+
+        Ok(credit_facility)
+    }
+
     async fn ensure_credit_facility_access(
         &self,
-        credit_facility: &CreditFacility,
-        object: CoreCreditObject,
-        action: CoreCreditAction,
+        owner_id: CustomerId,
+        object: impl Into<<<Perms as PermissionCheck>::Audit as AuditSvc>::Object> + Copy + Send,
+        action: impl Into<<<Perms as PermissionCheck>::Audit as AuditSvc>::Action> + Copy + Send,
     ) -> Result<(), CoreCreditError> {
-        if credit_facility.customer_id != self.customer_id {
+        if owner_id != self.customer_id {
             self.authz
                 .audit()
                 .record_entry(self.subject, object, action, false)
@@ -210,7 +299,7 @@ where
     ) -> Result<es_entity::PaginatedQueryRet<Disbursal, DisbursalsCursor>, CoreCreditError> {
         let credit_facility = self.credit_facilities.find_by_id_without_audit(id).await?;
         self.ensure_credit_facility_access(
-            &credit_facility,
+            credit_facility.customer_id,
             CoreCreditObject::all_credit_facilities(),
             CoreCreditAction::DISBURSAL_LIST,
         )
@@ -238,7 +327,7 @@ where
             .find_by_id_without_audit(disbursal.facility_id)
             .await?;
         self.ensure_credit_facility_access(
-            &credit_facility,
+            credit_facility.customer_id,
             CoreCreditObject::all_credit_facilities(),
             CoreCreditAction::CREDIT_FACILITY_READ,
         )
@@ -263,7 +352,7 @@ where
             .await?;
 
         self.ensure_credit_facility_access(
-            &credit_facility,
+            credit_facility.customer_id,
             CoreCreditObject::all_credit_facilities(),
             CoreCreditAction::CREDIT_FACILITY_READ,
         )
@@ -16,6 +16,8 @@ pub struct IncrementalPayment {
     pub recorded_at: DateTime<Utc>,
     pub effective: chrono::NaiveDate,
     pub payment_id: PaymentAllocationId,
+    #[serde(default)]
+    pub allocation_strategy: AllocationStrategy,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -80,6 +82,24 @@ pub struct ProceedsFromLiquidationReceived {
     pub tx_id: LedgerTxId,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct TermsAmended {
+    pub previous_terms: TermValues,
+    pub terms: TermValues,
+    pub previous_maturity_date: EffectiveDate,
+    pub maturity_date: EffectiveDate,
+    pub rescheduled_overdue_obligations: bool,
+    pub recorded_at: DateTime<Utc>,
+    pub effective: chrono::NaiveDate,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct WrittenOff {
+    pub amount: UsdCents,
+    pub recorded_at: DateTime<Utc>,
+    pub effective: chrono::NaiveDate,
+}
+
 /// Represents an entry in Credit Facility history as it is stored in a database.
 /// The entries contain no running sums; if needed, they have to be calculated
 /// during replaying.
@@ -95,4 +115,6 @@ pub enum CreditFacilityHistoryEntry {
     Payment(IncrementalPayment),
     Disbursal(DisbursalExecuted),
     Interest(InterestAccrualsPosted),
+    TermsAmended(TermsAmended),
+    WrittenOff(WrittenOff),
 }
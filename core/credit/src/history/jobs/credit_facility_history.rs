@@ -86,6 +86,9 @@ where
             Some(e @ FacilityCollateralizationChanged { entity }) => {
                 self.handle_credit_event(db, event, e, entity.id).await?;
             }
+            Some(e @ FacilityAmended { entity }) | Some(e @ FacilityWrittenOff { entity }) => {
+                self.handle_credit_event(db, event, e, entity.id).await?;
+            }
 
             _ => {}
         }
@@ -117,6 +117,34 @@ impl CreditFacilityHistory {
             PendingCreditFacilityCompleted { .. } => {}
             FacilityCompleted { .. } => {}
             PartialLiquidationInitiated { .. } => {}
+            FacilityAmended { entity } => {
+                let amendment = entity
+                    .amendment
+                    .as_ref()
+                    .expect("amendment must be set for FacilityAmended");
+                self.entries
+                    .push(CreditFacilityHistoryEntry::TermsAmended(TermsAmended {
+                        previous_terms: amendment.previous_terms,
+                        terms: amendment.terms,
+                        previous_maturity_date: amendment.previous_maturity_date,
+                        maturity_date: amendment.maturity_date,
+                        rescheduled_overdue_obligations: amendment.reschedule_overdue_obligations,
+                        recorded_at: message_recorded_at,
+                        effective: amendment.effective,
+                    }));
+            }
+            FacilityWrittenOff { entity } => {
+                let write_off = entity
+                    .write_off
+                    .as_ref()
+                    .expect("write_off must be set for FacilityWrittenOff");
+                self.entries
+                    .push(CreditFacilityHistoryEntry::WrittenOff(WrittenOff {
+                        amount: write_off.amount,
+                        recorded_at: message_recorded_at,
+                        effective: write_off.effective,
+                    }));
+            }
         }
     }
 
@@ -180,6 +208,7 @@ impl CreditFacilityHistory {
                     effective: entity.effective,
                     cents: entity.amount,
                     payment_id: entity.id,
+                    allocation_strategy: entity.allocation_strategy,
                 }));
         }
     }
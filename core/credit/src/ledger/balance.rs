@@ -25,6 +25,10 @@ pub struct CreditFacilityBalanceSummary {
     pub(super) due_interest_outstanding: UsdCents,
     pub(super) overdue_interest_outstanding: UsdCents,
     pub(super) interest_defaulted: UsdCents,
+    #[serde(default)]
+    pub(super) penalty_outstanding: UsdCents,
+    #[serde(default)]
+    pub(super) penalty_defaulted: UsdCents,
     pub(super) payments_unapplied: UsdCents,
 }
 
@@ -46,6 +50,10 @@ pub struct CreditFacilityBalanceSummary {
     pub due_interest_outstanding: UsdCents,
     pub overdue_interest_outstanding: UsdCents,
     pub interest_defaulted: UsdCents,
+    #[serde(default)]
+    pub penalty_outstanding: UsdCents,
+    #[serde(default)]
+    pub penalty_defaulted: UsdCents,
     pub payments_unapplied: UsdCents,
 }
 
@@ -86,8 +94,12 @@ impl CreditFacilityBalanceSummary {
         self.not_yet_due_interest_outstanding + self.interest_outstanding_payable()
     }
 
+    pub fn penalty_outstanding(&self) -> UsdCents {
+        self.penalty_outstanding
+    }
+
     pub fn total_outstanding(&self) -> UsdCents {
-        self.disbursed_outstanding() + self.interest_outstanding()
+        self.disbursed_outstanding() + self.interest_outstanding() + self.penalty_outstanding
     }
 
     pub fn interest_posted(&self) -> UsdCents {
@@ -118,13 +130,14 @@ impl CreditFacilityBalanceSummary {
         self.overdue_disbursed_outstanding + self.overdue_interest_outstanding
     }
 
-    fn total_defaulted(&self) -> UsdCents {
-        self.disbursed_defaulted + self.interest_defaulted
+    pub fn total_defaulted(&self) -> UsdCents {
+        self.disbursed_defaulted + self.interest_defaulted + self.penalty_defaulted
     }
 
     pub fn any_outstanding_or_defaulted(&self) -> bool {
         !(self.total_outstanding_not_yet_payable().is_zero()
             && self.total_outstanding_payable().is_zero()
+            && self.penalty_outstanding.is_zero()
             && self.total_defaulted().is_zero())
     }
 
@@ -230,6 +243,8 @@ mod test {
             due_interest_outstanding: UsdCents::ZERO,
             overdue_interest_outstanding: UsdCents::ZERO,
             interest_defaulted: UsdCents::ZERO,
+            penalty_outstanding: UsdCents::ZERO,
+            penalty_defaulted: UsdCents::ZERO,
 
             facility_remaining: UsdCents::from(1),
             interest_posted: UsdCents::from(1),
@@ -255,6 +270,8 @@ mod test {
             due_interest_outstanding: UsdCents::ZERO,
             overdue_interest_outstanding: UsdCents::ZERO,
             interest_defaulted: UsdCents::ZERO,
+            penalty_outstanding: UsdCents::ZERO,
+            penalty_defaulted: UsdCents::ZERO,
 
             facility_remaining: UsdCents::from(1),
             interest_posted: UsdCents::from(1),
@@ -281,6 +298,8 @@ mod test {
             due_interest_outstanding: UsdCents::ZERO,
             overdue_interest_outstanding: UsdCents::ZERO,
             interest_defaulted: UsdCents::ZERO,
+            penalty_outstanding: UsdCents::ZERO,
+            penalty_defaulted: UsdCents::ZERO,
 
             facility_remaining: UsdCents::from(1),
             interest_posted: UsdCents::from(1),
@@ -306,6 +325,8 @@ mod test {
             due_interest_outstanding: UsdCents::ZERO,
             overdue_interest_outstanding: UsdCents::ZERO,
             interest_defaulted: UsdCents::ZERO,
+            penalty_outstanding: UsdCents::ZERO,
+            penalty_defaulted: UsdCents::ZERO,
 
             facility_remaining: UsdCents::from(1),
             interest_posted: UsdCents::from(1),
@@ -333,6 +354,8 @@ mod test {
             due_interest_outstanding: UsdCents::ZERO,
             overdue_interest_outstanding: UsdCents::ZERO,
             interest_defaulted: UsdCents::ZERO,
+            penalty_outstanding: UsdCents::ZERO,
+            penalty_defaulted: UsdCents::ZERO,
 
             facility_remaining: UsdCents::from(1),
             interest_posted: UsdCents::from(1),
@@ -34,6 +34,14 @@ pub struct CreditFacilityLedgerAccountIds {
 
     /// Holds outstanding not yet covered by an unallocated payment.
     pub uncovered_outstanding_account_id: CalaAccountId,
+
+    /// Created the first time a penalty is charged on the facility.
+    #[serde(default)]
+    pub penalty_account_ids: Option<PenaltyLedgerAccountIds>,
+
+    /// Created the first time defaulted obligations of the facility are written off.
+    #[serde(default)]
+    pub write_off_account_ids: Option<WriteOffLedgerAccountIds>,
 }
 
 impl CreditFacilityLedgerAccountIds {
@@ -55,6 +63,8 @@ impl CreditFacilityLedgerAccountIds {
             fee_income_account_id: CalaAccountId::new(),
             uncovered_outstanding_account_id: CalaAccountId::new(),
             payment_holding_account_id: CalaAccountId::new(),
+            penalty_account_ids: None,
+            write_off_account_ids: None,
         }
     }
 }
@@ -84,6 +94,8 @@ impl From<PendingCreditFacilityAccountIds> for CreditFacilityLedgerAccountIds {
             interest_defaulted_account_id: CalaAccountId::new(),
             interest_income_account_id: CalaAccountId::new(),
             fee_income_account_id: CalaAccountId::new(),
+            penalty_account_ids: None,
+            write_off_account_ids: None,
         }
     }
 }
@@ -166,6 +178,56 @@ impl From<InterestAccrualCycleLedgerAccountIds> for InterestPostingAccountIds {
     }
 }
 
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct PenaltyLedgerAccountIds {
+    pub receivable_not_yet_due_account_id: CalaAccountId,
+    pub receivable_due_account_id: CalaAccountId,
+    pub receivable_overdue_account_id: CalaAccountId,
+    pub defaulted_account_id: CalaAccountId,
+    pub income_account_id: CalaAccountId,
+}
+
+impl PenaltyLedgerAccountIds {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            receivable_not_yet_due_account_id: CalaAccountId::new(),
+            receivable_due_account_id: CalaAccountId::new(),
+            receivable_overdue_account_id: CalaAccountId::new(),
+            defaulted_account_id: CalaAccountId::new(),
+            income_account_id: CalaAccountId::new(),
+        }
+    }
+}
+
+impl From<PenaltyLedgerAccountIds> for core_credit_collection::ObligationReceivableAccountIds {
+    fn from(account_ids: PenaltyLedgerAccountIds) -> Self {
+        Self {
+            not_yet_due: account_ids.receivable_not_yet_due_account_id,
+            due: account_ids.receivable_due_account_id,
+            overdue: account_ids.receivable_overdue_account_id,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct WriteOffLedgerAccountIds {
+    pub loan_loss_expense_account_id: CalaAccountId,
+    pub recovery_income_account_id: CalaAccountId,
+}
+
+impl WriteOffLedgerAccountIds {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            loan_loss_expense_account_id: CalaAccountId::new(),
+            recovery_income_account_id: CalaAccountId::new(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct InterestPostingAccountIds {
     pub receivable_not_yet_due: CalaAccountId,
@@ -218,6 +280,26 @@ pub struct CreditFacilityInterestAccrual {
     pub account_ids: InterestAccrualCycleLedgerAccountIds,
 }
 
+#[derive(Debug, Clone)]
+pub struct CreditFacilityPenalty {
+    pub tx_id: LedgerTxId,
+    pub tx_ref: String,
+    pub amount: UsdCents,
+    pub effective: chrono::NaiveDate,
+    pub account_ids: PenaltyLedgerAccountIds,
+    pub uncovered_outstanding_account_id: CalaAccountId,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreditFacilityPrepaymentFee {
+    pub tx_id: LedgerTxId,
+    pub tx_ref: String,
+    pub amount: UsdCents,
+    pub effective: chrono::NaiveDate,
+    pub payment_source_account_id: CalaAccountId,
+    pub fee_income_account_id: CalaAccountId,
+}
+
 #[derive(Debug, Clone)]
 pub struct CreditFacilityInterestAccrualCycle {
     pub tx_id: LedgerTxId,
@@ -110,6 +110,11 @@ pub struct CreditFacilityInternalAccountSets {
     pub fee_income: InternalAccountSetDetails,
     pub uncovered_outstanding: InternalAccountSetDetails,
     pub payment_holding: InternalAccountSetDetails,
+    pub penalty_receivable: InternalAccountSetDetails,
+    pub penalty_defaulted: InternalAccountSetDetails,
+    pub penalty_income: InternalAccountSetDetails,
+    pub loan_loss_expense: InternalAccountSetDetails,
+    pub recovery_income: InternalAccountSetDetails,
 }
 
 struct FacilityBalanceIds {
@@ -124,11 +129,13 @@ struct FacilityBalanceIds {
     interest_receivable_due: BalanceId,
     interest_receivable_overdue: BalanceId,
     interest_defaulted: BalanceId,
+    penalty_receivable: Option<[BalanceId; 3]>,
+    penalty_defaulted: Option<BalanceId>,
 }
 
 impl FacilityBalanceIds {
-    fn all(&self) -> [BalanceId; 11] {
-        [
+    fn all(&self) -> Vec<BalanceId> {
+        let mut ids = vec![
             self.facility,
             self.collateral,
             self.disbursed_receivable_not_yet_due,
@@ -140,7 +147,10 @@ impl FacilityBalanceIds {
             self.interest_receivable_overdue,
             self.interest_defaulted,
             self.payment_holding,
-        ]
+        ];
+        ids.extend(self.penalty_receivable.into_iter().flatten());
+        ids.extend(self.penalty_defaulted);
+        ids
     }
 }
 
@@ -172,6 +182,8 @@ impl CreditLedger {
         templates::ActivateCreditFacility::init(cala).await?;
         templates::CreditFacilityAccrueInterest::init(cala).await?;
         templates::CreditFacilityPostAccruedInterest::init(cala).await?;
+        templates::CreditFacilityRecordPenalty::init(cala).await?;
+        templates::CreditFacilityRecordPrepaymentFee::init(cala).await?;
         templates::InitiateDisbursal::init(cala).await?;
         templates::CancelDisbursal::init(cala).await?;
         templates::ConfirmDisbursal::init(cala).await?;
@@ -316,6 +328,11 @@ impl CreditLedger {
             fee_income: get(&summary.credit_fee_income),
             uncovered_outstanding: get(&summary.credit_uncovered_outstanding),
             payment_holding: get(&summary.credit_payment_holding),
+            penalty_receivable: get(&summary.credit_penalty_receivable),
+            penalty_defaulted: get(&summary.credit_penalty_defaulted),
+            penalty_income: get(&summary.credit_penalty_income),
+            loan_loss_expense: get(&summary.credit_loan_loss_expense),
+            recovery_income: get(&summary.credit_recovery_income),
         };
 
         let disbursal_limit_id = velocity::DisbursalLimit::init(cala).await?;
@@ -598,6 +615,26 @@ impl CreditLedger {
         Self::build_facility_balance_summary(balances, &ids)
     }
 
+    /// Facility balances as of the end of `date`, built from effective-date balances.
+    pub async fn get_credit_facility_balance_as_of(
+        &self,
+        account_ids: CreditFacilityLedgerAccountIds,
+        collateral_account_id: CalaAccountId,
+        date: chrono::NaiveDate,
+    ) -> Result<CreditFacilityBalanceSummary, CreditLedgerError> {
+        let ids = self.facility_balance_ids(&account_ids, collateral_account_id);
+        let balances = self
+            .cala
+            .balances()
+            .effective()
+            .find_all_in_range(&ids.all(), date, Some(date))
+            .await?
+            .into_iter()
+            .map(|(id, range)| (id, range.close))
+            .collect();
+        Self::build_facility_balance_summary(balances, &ids)
+    }
+
     fn facility_balance_ids(
         &self,
         account_ids: &CreditFacilityLedgerAccountIds,
@@ -615,7 +652,9 @@ impl CreditLedger {
             interest_receivable_overdue_account_id,
             interest_defaulted_account_id,
             payment_holding_account_id,
+            penalty_account_ids,
 
+            write_off_account_ids: _,
             fee_income_account_id: _,
             interest_income_account_id: _,
             uncovered_outstanding_account_id: _,
@@ -658,6 +697,16 @@ impl CreditLedger {
                 self.usd,
             ),
             interest_defaulted: (self.journal_id, interest_defaulted_account_id, self.usd),
+            penalty_receivable: penalty_account_ids.map(|ids| {
+                [
+                    ids.receivable_not_yet_due_account_id,
+                    ids.receivable_due_account_id,
+                    ids.receivable_overdue_account_id,
+                ]
+                .map(|account_id| (self.journal_id, account_id, self.usd))
+            }),
+            penalty_defaulted: penalty_account_ids
+                .map(|ids| (self.journal_id, ids.defaulted_account_id, self.usd)),
         }
     }
 
@@ -732,6 +781,22 @@ impl CreditLedger {
             UsdCents::ZERO
         };
 
+        let mut penalty_outstanding = UsdCents::ZERO;
+        for id in ids.penalty_receivable.iter().flatten() {
+            if let Some(b) = balances.get(id) {
+                penalty_outstanding += UsdCents::try_from_usd(b.settled())?;
+            }
+        }
+        let penalty_defaulted = if let Some(b) = ids
+            .penalty_defaulted
+            .as_ref()
+            .and_then(|id| balances.get(id))
+        {
+            UsdCents::try_from_usd(b.settled())?
+        } else {
+            UsdCents::ZERO
+        };
+
         let collateral = if let Some(b) = balances.get(&ids.collateral) {
             Satoshis::try_from_btc(b.settled())?
         } else {
@@ -760,6 +825,9 @@ impl CreditLedger {
             overdue_interest_outstanding,
             interest_defaulted,
 
+            penalty_outstanding,
+            penalty_defaulted,
+
             payments_unapplied,
         })
     }
@@ -1036,6 +1104,192 @@ impl CreditLedger {
         Ok(())
     }
 
+    pub async fn create_penalty_accounts_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        credit_facility_id: CreditFacilityId,
+        PenaltyLedgerAccountIds {
+            receivable_not_yet_due_account_id,
+            receivable_due_account_id,
+            receivable_overdue_account_id,
+            defaulted_account_id,
+            income_account_id,
+        }: PenaltyLedgerAccountIds,
+    ) -> Result<(), CreditLedgerError> {
+        let entity_ref = EntityRef::new(CREDIT_FACILITY_ENTITY_TYPE, credit_facility_id);
+
+        let receivable_accounts = [
+            (
+                receivable_not_yet_due_account_id,
+                "not-yet-due",
+                "Not Yet Due",
+            ),
+            (receivable_due_account_id, "due", "Due"),
+            (receivable_overdue_account_id, "overdue", "Overdue"),
+        ];
+        for (account_id, reference_status, name_status) in receivable_accounts {
+            let reference = &format!(
+                "credit-facility-penalty-{reference_status}-receivable:{credit_facility_id}"
+            );
+            let name = &format!(
+                "Penalty Receivable {name_status} Account for Credit Facility {credit_facility_id}"
+            );
+            self.create_account_in_op(
+                op,
+                account_id,
+                self.internal_account_sets.penalty_receivable,
+                reference,
+                name,
+                name,
+                entity_ref.clone(),
+            )
+            .await?;
+        }
+
+        let defaulted_reference =
+            &format!("credit-facility-penalty-defaulted:{credit_facility_id}");
+        let defaulted_name =
+            &format!("Penalty Defaulted Account for Credit Facility {credit_facility_id}");
+        self.create_account_in_op(
+            op,
+            defaulted_account_id,
+            self.internal_account_sets.penalty_defaulted,
+            defaulted_reference,
+            defaulted_name,
+            defaulted_name,
+            entity_ref.clone(),
+        )
+        .await?;
+
+        let income_reference = &format!("credit-facility-penalty-income:{credit_facility_id}");
+        let income_name =
+            &format!("Penalty Income Account for Credit Facility {credit_facility_id}");
+        self.create_account_in_op(
+            op,
+            income_account_id,
+            self.internal_account_sets.penalty_income,
+            income_reference,
+            income_name,
+            income_name,
+            entity_ref,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn create_write_off_accounts_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        credit_facility_id: CreditFacilityId,
+        WriteOffLedgerAccountIds {
+            loan_loss_expense_account_id,
+            recovery_income_account_id,
+        }: WriteOffLedgerAccountIds,
+    ) -> Result<(), CreditLedgerError> {
+        let entity_ref = EntityRef::new(CREDIT_FACILITY_ENTITY_TYPE, credit_facility_id);
+
+        let loan_loss_reference =
+            &format!("credit-facility-loan-loss-expense:{credit_facility_id}");
+        let loan_loss_name =
+            &format!("Loan Loss Expense Account for Credit Facility {credit_facility_id}");
+        self.create_account_in_op(
+            op,
+            loan_loss_expense_account_id,
+            self.internal_account_sets.loan_loss_expense,
+            loan_loss_reference,
+            loan_loss_name,
+            loan_loss_name,
+            entity_ref.clone(),
+        )
+        .await?;
+
+        let recovery_reference = &format!("credit-facility-recovery-income:{credit_facility_id}");
+        let recovery_name =
+            &format!("Recovery Income Account for Credit Facility {credit_facility_id}");
+        self.create_account_in_op(
+            op,
+            recovery_income_account_id,
+            self.internal_account_sets.recovery_income,
+            recovery_reference,
+            recovery_name,
+            recovery_name,
+            entity_ref,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn record_penalty_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        CreditFacilityPenalty {
+            tx_id,
+            tx_ref,
+            amount,
+            effective,
+            account_ids,
+            uncovered_outstanding_account_id,
+        }: CreditFacilityPenalty,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), CreditLedgerError> {
+        self.cala
+            .post_transaction_in_op(
+                op,
+                tx_id,
+                templates::CREDIT_FACILITY_RECORD_PENALTY_CODE,
+                templates::CreditFacilityRecordPenaltyParams {
+                    journal_id: self.journal_id,
+                    credit_facility_penalty_receivable_account: account_ids
+                        .receivable_not_yet_due_account_id,
+                    credit_facility_penalty_income_account: account_ids.income_account_id,
+                    interest_added_to_obligations_omnibus_account: self
+                        .interest_added_to_obligations_omnibus_account_ids
+                        .account_id,
+                    credit_facility_uncovered_outstanding_account: uncovered_outstanding_account_id,
+                    penalty_amount: amount.to_usd(),
+                    external_id: tx_ref,
+                    effective,
+                    initiated_by,
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub async fn record_prepayment_fee_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        CreditFacilityPrepaymentFee {
+            tx_id,
+            tx_ref,
+            amount,
+            effective,
+            payment_source_account_id,
+            fee_income_account_id,
+        }: CreditFacilityPrepaymentFee,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), CreditLedgerError> {
+        self.cala
+            .post_transaction_in_op(
+                op,
+                tx_id,
+                templates::CREDIT_FACILITY_RECORD_PREPAYMENT_FEE_CODE,
+                templates::CreditFacilityRecordPrepaymentFeeParams {
+                    journal_id: self.journal_id,
+                    payment_source_account: payment_source_account_id,
+                    credit_facility_fee_income_account: fee_income_account_id,
+                    fee_amount: amount.to_usd(),
+                    external_id: tx_ref,
+                    effective,
+                    initiated_by,
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
     pub async fn initiate_disbursal_in_op(
         &self,
         op: &mut es_entity::DbOp<'_>,
@@ -1394,6 +1648,12 @@ impl CreditLedger {
             interest_income_account_id,
             fee_income_account_id,
 
+            // these accounts are created when the first penalty is charged
+            penalty_account_ids: _,
+
+            // these accounts are created when defaulted obligations are first written off
+            write_off_account_ids: _,
+
             // these accounts are created during proposal creation
             facility_account_id: _facility_account_id,
             uncovered_outstanding_account_id: _uncovered_outstanding_account_id,
@@ -1610,6 +1870,11 @@ impl CreditLedger {
             uncovered_outstanding_parent_account_set_id,
             disbursed_defaulted_parent_account_set_id,
             interest_defaulted_parent_account_set_id,
+            penalty_receivable_parent_account_set_id,
+            penalty_defaulted_parent_account_set_id,
+            penalty_income_parent_account_set_id,
+            loan_loss_expense_parent_account_set_id,
+            recovery_income_parent_account_set_id,
             short_term_disbursed_integration_meta,
             long_term_disbursed_integration_meta,
             short_term_interest_integration_meta,
@@ -1746,6 +2011,57 @@ impl CreditLedger {
         )
         .await?;
 
+        if let Some(parent_account_set_id) = penalty_receivable_parent_account_set_id {
+            self.attach_charts_account_set_in_op(
+                op,
+                self.internal_account_sets.penalty_receivable.id,
+                *parent_account_set_id,
+                old_integration_config
+                    .and_then(|config| config.penalty_receivable_parent_account_set_id),
+            )
+            .await?;
+        }
+        if let Some(parent_account_set_id) = penalty_defaulted_parent_account_set_id {
+            self.attach_charts_account_set_in_op(
+                op,
+                self.internal_account_sets.penalty_defaulted.id,
+                *parent_account_set_id,
+                old_integration_config
+                    .and_then(|config| config.penalty_defaulted_parent_account_set_id),
+            )
+            .await?;
+        }
+        if let Some(parent_account_set_id) = penalty_income_parent_account_set_id {
+            self.attach_charts_account_set_in_op(
+                op,
+                self.internal_account_sets.penalty_income.id,
+                *parent_account_set_id,
+                old_integration_config
+                    .and_then(|config| config.penalty_income_parent_account_set_id),
+            )
+            .await?;
+        }
+        if let Some(parent_account_set_id) = loan_loss_expense_parent_account_set_id {
+            self.attach_charts_account_set_in_op(
+                op,
+                self.internal_account_sets.loan_loss_expense.id,
+                *parent_account_set_id,
+                old_integration_config
+                    .and_then(|config| config.loan_loss_expense_parent_account_set_id),
+            )
+            .await?;
+        }
+        if let Some(parent_account_set_id) = recovery_income_parent_account_set_id {
+            self.attach_charts_account_set_in_op(
+                op,
+                self.internal_account_sets.recovery_income.id,
+                *parent_account_set_id,
+                old_integration_config
+                    .and_then(|config| config.recovery_income_parent_account_set_id),
+            )
+            .await?;
+        }
+
         self.attach_short_term_disbursed_receivable_account_sets_in_op(
             op,
             short_term_disbursed_integration_meta,
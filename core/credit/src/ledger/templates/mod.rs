@@ -7,6 +7,8 @@ mod create_credit_facility_proposal;
 mod initial_disbursal;
 mod initiate_disbursal;
 mod post_accrued_interest;
+mod record_penalty;
+mod record_prepayment_fee;
 
 pub use accrue_interest::*;
 pub use activate_credit_facility::*;
@@ -17,3 +19,5 @@ pub use create_credit_facility_proposal::*;
 pub use initial_disbursal::*;
 pub use initiate_disbursal::*;
 pub use post_accrued_interest::*;
+pub use record_penalty::*;
+pub use record_prepayment_fee::*;
@@ -0,0 +1,194 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+use tracing_macros::record_error_severity;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{ledger::error::*, primitives::CalaAccountId};
+
+pub const CREDIT_FACILITY_RECORD_PENALTY_CODE: &str = "CREDIT_FACILITY_RECORD_PENALTY";
+
+#[derive(Debug)]
+pub struct CreditFacilityRecordPenaltyParams<S: std::fmt::Display> {
+    pub journal_id: JournalId,
+    pub credit_facility_penalty_receivable_account: CalaAccountId,
+    pub credit_facility_penalty_income_account: CalaAccountId,
+    pub interest_added_to_obligations_omnibus_account: CalaAccountId,
+    pub credit_facility_uncovered_outstanding_account: CalaAccountId,
+    pub penalty_amount: Decimal,
+    pub external_id: String,
+    pub effective: chrono::NaiveDate,
+    pub initiated_by: S,
+}
+
+impl<S: std::fmt::Display> CreditFacilityRecordPenaltyParams<S> {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("credit_facility_penalty_receivable_account")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("credit_facility_penalty_income_account")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("interest_added_to_obligations_omnibus_account")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("credit_facility_uncovered_outstanding_account")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("penalty_amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("external_id")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("meta")
+                .r#type(ParamDataType::Json)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl<S: std::fmt::Display> From<CreditFacilityRecordPenaltyParams<S>> for Params {
+    fn from(
+        CreditFacilityRecordPenaltyParams {
+            journal_id,
+            credit_facility_penalty_receivable_account,
+            credit_facility_penalty_income_account,
+            interest_added_to_obligations_omnibus_account,
+            credit_facility_uncovered_outstanding_account,
+            penalty_amount,
+            external_id,
+            effective,
+            initiated_by,
+        }: CreditFacilityRecordPenaltyParams<S>,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert(
+            "credit_facility_penalty_receivable_account",
+            credit_facility_penalty_receivable_account,
+        );
+        params.insert(
+            "credit_facility_penalty_income_account",
+            credit_facility_penalty_income_account,
+        );
+        params.insert(
+            "interest_added_to_obligations_omnibus_account",
+            interest_added_to_obligations_omnibus_account,
+        );
+        params.insert(
+            "credit_facility_uncovered_outstanding_account",
+            credit_facility_uncovered_outstanding_account,
+        );
+        params.insert("penalty_amount", penalty_amount);
+        params.insert("external_id", external_id);
+        params.insert("effective", effective);
+        params.insert(
+            "meta",
+            serde_json::json!({
+                "initiated_by": initiated_by.to_string(),
+            }),
+        );
+        params
+    }
+}
+
+pub struct CreditFacilityRecordPenalty;
+
+impl CreditFacilityRecordPenalty {
+    #[record_error_severity]
+    #[instrument(name = "ledger.credit_facility_record_penalty.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), CreditLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .external_id("params.external_id")
+            .metadata("params.meta")
+            .description("'Record penalty on overdue obligation for credit facility'")
+            .build()
+            .expect("Couldn't build TxInput");
+
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .account_id("params.credit_facility_penalty_receivable_account")
+                .units("params.penalty_amount")
+                .currency("'USD'")
+                .entry_type("'RECORD_PENALTY_SETTLED_DR'")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .account_id("params.credit_facility_penalty_income_account")
+                .units("params.penalty_amount")
+                .currency("'USD'")
+                .entry_type("'RECORD_PENALTY_SETTLED_CR'")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .account_id("params.interest_added_to_obligations_omnibus_account")
+                .units("params.penalty_amount")
+                .currency("'USD'")
+                .entry_type("'RECORD_PENALTY_UNCOVERED_OBLIGATION_SETTLED_DR'")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .account_id("params.credit_facility_uncovered_outstanding_account")
+                .units("params.penalty_amount")
+                .currency("'USD'")
+                .entry_type("'RECORD_PENALTY_UNCOVERED_OBLIGATION_SETTLED_CR'")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = CreditFacilityRecordPenaltyParams::<String>::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(CREDIT_FACILITY_RECORD_PENALTY_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
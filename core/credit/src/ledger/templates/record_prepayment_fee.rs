@@ -0,0 +1,152 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+use tracing_macros::record_error_severity;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{ledger::error::*, primitives::CalaAccountId};
+
+pub const CREDIT_FACILITY_RECORD_PREPAYMENT_FEE_CODE: &str =
+    "CREDIT_FACILITY_RECORD_PREPAYMENT_FEE";
+
+#[derive(Debug)]
+pub struct CreditFacilityRecordPrepaymentFeeParams<S: std::fmt::Display> {
+    pub journal_id: JournalId,
+    pub payment_source_account: CalaAccountId,
+    pub credit_facility_fee_income_account: CalaAccountId,
+    pub fee_amount: Decimal,
+    pub external_id: String,
+    pub effective: chrono::NaiveDate,
+    pub initiated_by: S,
+}
+
+impl<S: std::fmt::Display> CreditFacilityRecordPrepaymentFeeParams<S> {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("payment_source_account")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("credit_facility_fee_income_account")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("fee_amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("external_id")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("meta")
+                .r#type(ParamDataType::Json)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl<S: std::fmt::Display> From<CreditFacilityRecordPrepaymentFeeParams<S>> for Params {
+    fn from(
+        CreditFacilityRecordPrepaymentFeeParams {
+            journal_id,
+            payment_source_account,
+            credit_facility_fee_income_account,
+            fee_amount,
+            external_id,
+            effective,
+            initiated_by,
+        }: CreditFacilityRecordPrepaymentFeeParams<S>,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("payment_source_account", payment_source_account);
+        params.insert(
+            "credit_facility_fee_income_account",
+            credit_facility_fee_income_account,
+        );
+        params.insert("fee_amount", fee_amount);
+        params.insert("external_id", external_id);
+        params.insert("effective", effective);
+        params.insert(
+            "meta",
+            serde_json::json!({
+                "initiated_by": initiated_by.to_string(),
+            }),
+        );
+        params
+    }
+}
+
+pub struct CreditFacilityRecordPrepaymentFee;
+
+impl CreditFacilityRecordPrepaymentFee {
+    #[record_error_severity]
+    #[instrument(name = "ledger.credit_facility_record_prepayment_fee.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), CreditLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .external_id("params.external_id")
+            .metadata("params.meta")
+            .description("'Record prepayment fee for credit facility'")
+            .build()
+            .expect("Couldn't build TxInput");
+
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .account_id("params.payment_source_account")
+                .units("params.fee_amount")
+                .currency("'USD'")
+                .entry_type("'RECORD_PREPAYMENT_FEE_DR'")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .account_id("params.credit_facility_fee_income_account")
+                .units("params.fee_amount")
+                .currency("'USD'")
+                .entry_type("'RECORD_PREPAYMENT_FEE_CR'")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = CreditFacilityRecordPrepaymentFeeParams::<String>::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(CREDIT_FACILITY_RECORD_PREPAYMENT_FEE_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
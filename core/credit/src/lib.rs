@@ -59,7 +59,8 @@ pub use pending_credit_facility::*;
 pub use primitives::*;
 pub use processes::{
     activate_credit_facility::*, allocate_credit_facility_payment::*,
-    approve_credit_facility_proposal::*, approve_disbursal::*,
+    approve_credit_facility_amendment::*, approve_credit_facility_proposal::*,
+    approve_credit_facility_write_off::*, approve_disbursal::*,
 };
 pub use public::*;
 use publisher::CreditFacilityPublisher;
@@ -344,8 +345,13 @@ where
         );
         let activate_credit_facility_arc = Arc::new(activate_credit_facility);
 
-        let allocate_credit_facility_payment =
-            AllocateCreditFacilityPayment::new(collections_arc.clone());
+        let approve_amendment = ApproveCreditFacilityAmendment::new(facilities_arc.clone());
+        let approve_write_off = ApproveCreditFacilityWriteOff::new(facilities_arc.clone());
+
+        let allocate_credit_facility_payment = AllocateCreditFacilityPayment::new(
+            collections_arc.clone(),
+            facilities_arc.credit_facility_repo(),
+        );
         let allocate_credit_facility_payment_arc = Arc::new(allocate_credit_facility_payment);
 
         outbox
@@ -389,6 +395,22 @@ where
             )
             .await?;
 
+        outbox
+            .register_event_handler(
+                jobs,
+                OutboxEventJobConfig::new(CREDIT_FACILITY_AMENDMENT_APPROVE_JOB),
+                CreditFacilityAmendmentApprovalHandler::new(&approve_amendment),
+            )
+            .await?;
+
+        outbox
+            .register_event_handler(
+                jobs,
+                OutboxEventJobConfig::new(CREDIT_FACILITY_WRITE_OFF_APPROVE_JOB),
+                CreditFacilityWriteOffApprovalHandler::new(&approve_write_off),
+            )
+            .await?;
+
         Ok(Self {
             clock,
             authz: authz_arc,
@@ -480,6 +502,7 @@ where
         Ok(CreditFacilitiesForSubject::new(
             sub,
             customer_id,
+            self,
             &self.authz,
             &self.facilities,
             &self.collaterals,
@@ -518,6 +541,24 @@ where
             .await?
             .expect("audit info missing");
 
+        self.create_facility_proposal_without_audit(
+            customer_id,
+            deposit_account_id,
+            amount,
+            terms,
+            custodian_id,
+        )
+        .await
+    }
+
+    pub(crate) async fn create_facility_proposal_without_audit(
+        &self,
+        customer_id: impl Into<CustomerId> + std::fmt::Debug + Copy,
+        deposit_account_id: impl Into<CalaAccountId> + std::fmt::Debug + Copy,
+        amount: UsdCents,
+        terms: TermValues,
+        custodian_id: Option<impl Into<CustodianId> + std::fmt::Debug + Copy>,
+    ) -> Result<CreditFacilityProposal, CoreCreditError> {
         let customer = self.customer.find_by_id_without_audit(customer_id).await?;
         let party = self
             .customer
@@ -624,7 +665,7 @@ where
             .get_credit_facility_balance(facility.account_ids, collateral_account_id)
             .await?;
 
-        let price = self.price.usd_cents_per_btc().await;
+        let price = self.price.fresh_usd_cents_per_btc().await?;
         let cvl = balance.with_added_disbursal(amount).current_cvl(price);
         if !facility.terms.is_disbursal_allowed(cvl) {
             return Err(CreditFacilityError::BelowMarginLimit.into());
@@ -678,6 +719,86 @@ where
         Ok(disbursal)
     }
 
+    pub async fn subject_can_amend_facility(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        enforce: bool,
+    ) -> Result<Option<AuditInfo>, CoreCreditError> {
+        Ok(self
+            .authz
+            .evaluate_permission(
+                sub,
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::CREDIT_FACILITY_AMEND,
+                enforce,
+            )
+            .await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "credit.propose_facility_amendment", skip(self, credit_facility_id, terms), fields(credit_facility_id = tracing::field::Empty))]
+    pub async fn propose_facility_amendment(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug,
+        terms: TermValues,
+        reschedule_overdue_obligations: bool,
+    ) -> Result<CreditFacility, CoreCreditError> {
+        self.subject_can_amend_facility(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let credit_facility_id = credit_facility_id.into();
+        tracing::Span::current().record(
+            "credit_facility_id",
+            tracing::field::display(credit_facility_id),
+        );
+
+        Ok(self
+            .facilities
+            .propose_amendment(credit_facility_id, terms, reschedule_overdue_obligations)
+            .await?)
+    }
+
+    pub async fn subject_can_write_off_facility(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        enforce: bool,
+    ) -> Result<Option<AuditInfo>, CoreCreditError> {
+        Ok(self
+            .authz
+            .evaluate_permission(
+                sub,
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::CREDIT_FACILITY_WRITE_OFF,
+                enforce,
+            )
+            .await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "credit.propose_facility_write_off", skip(self, credit_facility_id), fields(credit_facility_id = tracing::field::Empty))]
+    pub async fn propose_facility_write_off(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug,
+    ) -> Result<CreditFacility, CoreCreditError> {
+        self.subject_can_write_off_facility(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let credit_facility_id = credit_facility_id.into();
+        tracing::Span::current().record(
+            "credit_facility_id",
+            tracing::field::display(credit_facility_id),
+        );
+
+        Ok(self
+            .facilities
+            .propose_write_off(credit_facility_id)
+            .await?)
+    }
+
     pub async fn subject_can_record_payment(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
@@ -807,6 +928,69 @@ where
         Ok(credit_facility)
     }
 
+    #[record_error_severity]
+    #[instrument(name = "credit.record_prepayment", skip(self, credit_facility_id, payment_source_account_id), fields(credit_facility_id = tracing::field::Empty))]
+    pub async fn record_prepayment(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: impl es_entity::RetryableInto<CreditFacilityId>,
+        payment_source_account_id: impl es_entity::RetryableInto<PaymentSourceAccountId>,
+        amount: UsdCents,
+    ) -> Result<CreditFacility, CoreCreditError> {
+        self.subject_can_record_payment(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let credit_facility_id = credit_facility_id.into();
+        let payment_source_account_id = payment_source_account_id.into();
+
+        tracing::Span::current().record(
+            "credit_facility_id",
+            tracing::field::display(credit_facility_id),
+        );
+
+        let payment_id = PaymentId::new();
+        let effective = self.clock.today();
+        let initiated_by = sub;
+
+        let mut db = self.facilities.begin_op().await?;
+        let credit_facility = self
+            .facilities
+            .record_prepayment_in_op(
+                &mut db,
+                credit_facility_id,
+                payment_id,
+                amount,
+                payment_source_account_id.into(),
+                effective,
+                initiated_by,
+            )
+            .await?;
+
+        self.collections
+            .payments()
+            .record_prepayment_in_op(
+                &mut db,
+                payment_id,
+                credit_facility_id.into(),
+                PaymentLedgerAccountIds {
+                    facility_payment_holding_account_id: credit_facility
+                        .payment_holding_account_id(),
+                    facility_uncovered_outstanding_account_id: credit_facility
+                        .uncovered_outstanding_account_id(),
+                    payment_source_account_id,
+                },
+                amount,
+                effective,
+                initiated_by,
+            )
+            .await?;
+
+        db.commit().await?;
+
+        Ok(credit_facility)
+    }
+
     pub async fn subject_can_complete(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
@@ -886,7 +1070,28 @@ where
             .ledger
             .get_credit_facility_balance(entity.account_ids, collateral_account_id)
             .await?;
-        let price = self.price.usd_cents_per_btc().await;
+        let price = self.price.fresh_usd_cents_per_btc().await?;
+        Ok(balances.current_cvl(price))
+    }
+
+    /// CVL of the facility at the end of `date`, using the ledger balances and the
+    /// BTC price as they were known at that point in time.
+    pub async fn cvl_as_of(
+        &self,
+        entity: &CreditFacility,
+        date: chrono::NaiveDate,
+    ) -> Result<CVLPct, CoreCreditError> {
+        let collateral = self
+            .collaterals
+            .find_by_id_without_audit(entity.collateral_id)
+            .await?;
+        let collateral_account_id = collateral.account_id();
+
+        let balances = self
+            .ledger
+            .get_credit_facility_balance_as_of(entity.account_ids, collateral_account_id, date)
+            .await?;
+        let price = self.price.usd_cents_per_btc_at_close_of(date).await?;
         Ok(balances.current_cvl(price))
     }
 
@@ -191,7 +191,7 @@ where
             )
             .await?;
 
-        let price = self.price.usd_cents_per_btc().await;
+        let price = self.price.fresh_usd_cents_per_btc().await?;
 
         if pending_facility
             .update_collateralization(price, balances)
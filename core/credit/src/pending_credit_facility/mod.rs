@@ -255,7 +255,7 @@ where
     ) -> Result<PendingCreditFacilityCompletionOutcome, PendingCreditFacilityError> {
         let mut pending_facility = self.repo.find_by_id(pending_credit_facility_id).await?;
 
-        let price = self.price.usd_cents_per_btc().await;
+        let price = self.price.fresh_usd_cents_per_btc().await?;
 
         let collateral = self
             .collaterals
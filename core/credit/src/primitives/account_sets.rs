@@ -139,6 +139,11 @@ pub struct CreditSummaryAccountSetCatalog {
     pub credit_fee_income: CreditSummaryAccountSetSpec,
     pub credit_uncovered_outstanding: CreditSummaryAccountSetSpec,
     pub credit_payment_holding: CreditSummaryAccountSetSpec,
+    pub credit_penalty_receivable: CreditSummaryAccountSetSpec,
+    pub credit_penalty_defaulted: CreditSummaryAccountSetSpec,
+    pub credit_penalty_income: CreditSummaryAccountSetSpec,
+    pub credit_loan_loss_expense: CreditSummaryAccountSetSpec,
+    pub credit_recovery_income: CreditSummaryAccountSetSpec,
 }
 
 impl CreditAccountSetCatalog {
@@ -160,7 +165,7 @@ impl CreditAccountSetCatalog {
         ]
     }
 
-    pub fn summary_specs(&self) -> [CreditSummaryAccountSetSpec; 46] {
+    pub fn summary_specs(&self) -> [CreditSummaryAccountSetSpec; 51] {
         [
             self.summary.credit_facility_remaining,
             self.summary.credit_collateral,
@@ -226,6 +231,11 @@ impl CreditAccountSetCatalog {
             self.summary.credit_fee_income,
             self.summary.credit_uncovered_outstanding,
             self.summary.credit_payment_holding,
+            self.summary.credit_penalty_receivable,
+            self.summary.credit_penalty_defaulted,
+            self.summary.credit_penalty_income,
+            self.summary.credit_loan_loss_expense,
+            self.summary.credit_recovery_income,
         ]
     }
 }
@@ -796,6 +806,51 @@ const CREDIT_INTEREST_INCOME: CreditSummaryAccountSetSpec = CreditSummaryAccount
     DebitOrCredit::Credit,
 );
 
+const CREDIT_PENALTY_RECEIVABLE_NAME: &str = "Credit Penalty Receivable Account Set";
+const CREDIT_PENALTY_RECEIVABLE_REF: &str = "credit-penalty-receivable-account-set";
+const CREDIT_PENALTY_RECEIVABLE: CreditSummaryAccountSetSpec = CreditSummaryAccountSetSpec::new(
+    CREDIT_PENALTY_RECEIVABLE_NAME,
+    CREDIT_PENALTY_RECEIVABLE_REF,
+    CreditAccountCategory::Asset,
+    DebitOrCredit::Debit,
+);
+
+const CREDIT_PENALTY_DEFAULTED_NAME: &str = "Credit Penalty Defaulted Account Set";
+const CREDIT_PENALTY_DEFAULTED_REF: &str = "credit-penalty-defaulted-account-set";
+const CREDIT_PENALTY_DEFAULTED: CreditSummaryAccountSetSpec = CreditSummaryAccountSetSpec::new(
+    CREDIT_PENALTY_DEFAULTED_NAME,
+    CREDIT_PENALTY_DEFAULTED_REF,
+    CreditAccountCategory::Asset,
+    DebitOrCredit::Debit,
+);
+
+const CREDIT_PENALTY_INCOME_NAME: &str = "Credit Penalty Income Account Set";
+const CREDIT_PENALTY_INCOME_REF: &str = "credit-penalty-income-account-set";
+const CREDIT_PENALTY_INCOME: CreditSummaryAccountSetSpec = CreditSummaryAccountSetSpec::new(
+    CREDIT_PENALTY_INCOME_NAME,
+    CREDIT_PENALTY_INCOME_REF,
+    CreditAccountCategory::Revenue,
+    DebitOrCredit::Credit,
+);
+
+const CREDIT_LOAN_LOSS_EXPENSE_NAME: &str = "Credit Loan Loss Expense Account Set";
+const CREDIT_LOAN_LOSS_EXPENSE_REF: &str = "credit-loan-loss-expense-account-set";
+const CREDIT_LOAN_LOSS_EXPENSE: CreditSummaryAccountSetSpec = CreditSummaryAccountSetSpec::new(
+    CREDIT_LOAN_LOSS_EXPENSE_NAME,
+    CREDIT_LOAN_LOSS_EXPENSE_REF,
+    CreditAccountCategory::Expenses,
+    DebitOrCredit::Debit,
+);
+
+const CREDIT_RECOVERY_INCOME_NAME: &str = "Credit Recovery Income Account Set";
+const CREDIT_RECOVERY_INCOME_REF: &str = "credit-recovery-income-account-set";
+const CREDIT_RECOVERY_INCOME: CreditSummaryAccountSetSpec = CreditSummaryAccountSetSpec::new(
+    CREDIT_RECOVERY_INCOME_NAME,
+    CREDIT_RECOVERY_INCOME_REF,
+    CreditAccountCategory::Revenue,
+    DebitOrCredit::Credit,
+);
+
 const CREDIT_FEE_INCOME_NAME: &str = "Credit Fee Income Account Set";
 const CREDIT_FEE_INCOME_REF: &str = "credit-fee-income-account-set";
 const CREDIT_FEE_INCOME: CreditSummaryAccountSetSpec = CreditSummaryAccountSetSpec::new(
@@ -904,5 +959,10 @@ pub const CREDIT_ACCOUNT_SET_CATALOG: CreditAccountSetCatalog = CreditAccountSet
         credit_fee_income: CREDIT_FEE_INCOME,
         credit_uncovered_outstanding: CREDIT_UNCOVERED_OUTSTANDING,
         credit_payment_holding: CREDIT_PAYMENT_HOLDING,
+        credit_penalty_receivable: CREDIT_PENALTY_RECEIVABLE,
+        credit_penalty_defaulted: CREDIT_PENALTY_DEFAULTED,
+        credit_penalty_income: CREDIT_PENALTY_INCOME,
+        credit_loan_loss_expense: CREDIT_LOAN_LOSS_EXPENSE,
+        credit_recovery_income: CREDIT_RECOVERY_INCOME,
     },
 };
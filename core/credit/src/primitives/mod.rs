@@ -15,6 +15,7 @@ use std::str::FromStr;
 use authz::{ActionPermission, AllOrOne, action_description::*, map_action};
 
 pub const INTEREST_ACCRUAL: audit::SystemActor = audit::SystemActor::new("interest-accrual");
+pub const PENALTY_ACCRUAL: audit::SystemActor = audit::SystemActor::new("penalty-accrual");
 pub const COLLATERALIZATION_SYNC: audit::SystemActor =
     audit::SystemActor::new("collateralization-sync");
 pub const CREDIT_FACILITY_ACTIVATION: audit::SystemActor =
@@ -24,6 +25,10 @@ pub const PENDING_FACILITY_CREATION: audit::SystemActor =
 pub const CREDIT_FACILITY_PAYMENT_ALLOCATION: audit::SystemActor =
     audit::SystemActor::new("credit-facility-payment-allocation");
 pub const DISBURSAL_APPROVAL: audit::SystemActor = audit::SystemActor::new("disbursal-approval");
+pub const CREDIT_FACILITY_AMENDMENT_APPROVAL: audit::SystemActor =
+    audit::SystemActor::new("credit-facility-amendment-approval");
+pub const CREDIT_FACILITY_WRITE_OFF_APPROVAL: audit::SystemActor =
+    audit::SystemActor::new("credit-facility-write-off-approval");
 
 pub use cala_ledger::primitives::{
     AccountId as CalaAccountId, AccountSetId as CalaAccountSetId, Currency,
@@ -38,9 +43,9 @@ pub use money::*;
 pub use public_id::PublicId;
 
 pub use core_credit_terms::{
-    AnnualRatePct, CVLPct, DisbursalPolicy, EffectiveDate, FacilityDuration, FacilityDurationType,
-    InterestInterval, InterestPeriod, ObligationDuration, OneTimeFeeRatePct, TermValues,
-    TermValuesBuilder, TermsTemplateId,
+    AllocationStrategy, AnnualRatePct, CVLPct, DayCountConvention, DisbursalPolicy, EffectiveDate,
+    FacilityDuration, FacilityDurationType, InterestInterval, InterestPeriod, ObligationDuration,
+    OneTimeFeeRatePct, PenaltyAllocation, TermValues, TermValuesBuilder, TermsTemplateId,
     collateralization::{
         CollateralizationRatio, CollateralizationState, PendingCreditFacilityCollateralizationState,
     },
@@ -52,7 +57,7 @@ pub use core_credit_collection::{
     ObligationType, Obligations, ObligationsAmounts, PERMISSION_SET_COLLECTION_PAYMENT_DATE,
     PERMISSION_SET_COLLECTION_VIEWER, PERMISSION_SET_COLLECTION_WRITER, Payment, PaymentAllocation,
     PaymentAllocationError, PaymentAllocationId, PaymentError, PaymentId, PaymentSourceAccountId,
-    Payments,
+    PaymentType, Payments,
 };
 
 impl From<FacilityDurationType> for DisbursedReceivableAccountCategory {
@@ -235,6 +240,10 @@ impl CoreCreditAction {
         CoreCreditAction::CreditFacility(CreditFacilityAction::RecordInterest);
     pub const CREDIT_FACILITY_COMPLETE: Self =
         CoreCreditAction::CreditFacility(CreditFacilityAction::Complete);
+    pub const CREDIT_FACILITY_AMEND: Self =
+        CoreCreditAction::CreditFacility(CreditFacilityAction::Amend);
+    pub const CREDIT_FACILITY_WRITE_OFF: Self =
+        CoreCreditAction::CreditFacility(CreditFacilityAction::WriteOff);
     pub const CREDIT_FACILITY_UPDATE_COLLATERALIZATION_STATE: Self =
         CoreCreditAction::CreditFacility(CreditFacilityAction::UpdateCollateralizationState);
 
@@ -323,6 +332,8 @@ pub enum CreditFacilityAction {
     Activate,
     RecordInterest,
     Complete,
+    Amend,
+    WriteOff,
     UpdateCollateralizationState,
     CustomerApprove,
 }
@@ -336,6 +347,8 @@ impl ActionPermission for CreditFacilityAction {
             | Self::Activate
             | Self::RecordInterest
             | Self::Complete
+            | Self::Amend
+            | Self::WriteOff
             | Self::CustomerApprove
             | Self::UpdateCollateralizationState => PERMISSION_SET_CREDIT_WRITER,
         }
@@ -99,9 +99,7 @@ where
             );
             Span::current().record("event_type", e.as_ref());
 
-            self.process
-                .execute_activate_credit_facility(entity.id)
-                .await?;
+            Box::pin(self.process.execute_activate_credit_facility(entity.id)).await?;
         }
         Ok(())
     }
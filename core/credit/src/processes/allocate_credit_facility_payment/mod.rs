@@ -12,8 +12,8 @@ use obix::out::OutboxEventMarker;
 
 use crate::{
     CoreCreditAction, CoreCreditCollectionAction, CoreCreditCollectionEvent,
-    CoreCreditCollectionObject, CoreCreditEvent, CoreCreditObject, error::CoreCreditError,
-    primitives::PaymentId,
+    CoreCreditCollectionObject, CoreCreditEvent, CoreCreditObject, CreditFacilityId,
+    credit_facility::CreditFacilityRepo, error::CoreCreditError, primitives::PaymentId,
 };
 
 pub use job::*;
@@ -24,6 +24,7 @@ where
     E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<CoreCreditCollectionEvent>,
 {
     collections: Arc<CoreCreditCollection<Perms, E>>,
+    credit_facility_repo: Arc<CreditFacilityRepo<E>>,
 }
 
 impl<Perms, E> Clone for AllocateCreditFacilityPayment<Perms, E>
@@ -34,6 +35,7 @@ where
     fn clone(&self) -> Self {
         Self {
             collections: self.collections.clone(),
+            credit_facility_repo: self.credit_facility_repo.clone(),
         }
     }
 }
@@ -47,8 +49,14 @@ where
         From<CoreCreditObject> + From<CoreCreditCollectionObject>,
     E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<CoreCreditCollectionEvent>,
 {
-    pub fn new(collections: Arc<CoreCreditCollection<Perms, E>>) -> Self {
-        Self { collections }
+    pub fn new(
+        collections: Arc<CoreCreditCollection<Perms, E>>,
+        credit_facility_repo: Arc<CreditFacilityRepo<E>>,
+    ) -> Self {
+        Self {
+            collections,
+            credit_facility_repo,
+        }
     }
 
     #[instrument(
@@ -62,9 +70,21 @@ where
         initiated_by: &impl SystemSubject,
     ) -> Result<(), CoreCreditError> {
         if let Some(payment) = self.collections.payments().find_by_id(payment_id).await? {
+            let credit_facility_id: CreditFacilityId = payment.beneficiary_id.into();
+            let terms = self
+                .credit_facility_repo
+                .find_by_id_in_op(&mut *db, credit_facility_id)
+                .await?
+                .terms;
             self.collections
                 .obligations()
-                .allocate_payment_in_op(db, payment.into(), initiated_by)
+                .allocate_payment_in_op(
+                    db,
+                    payment.into(),
+                    terms.allocation_strategy,
+                    terms.penalty_allocation,
+                    initiated_by,
+                )
                 .await?;
         }
         Ok(())
@@ -113,10 +113,11 @@ where
         let id = credit_facility_proposal_id.into();
         tracing::Span::current()
             .record("credit_facility_proposal_id", tracing::field::display(&id));
-        let proposal = self
-            .pending_credit_facilities
-            .transition_from_proposal(id, approved)
-            .await?;
+        let proposal = Box::pin(
+            self.pending_credit_facilities
+                .transition_from_proposal(id, approved),
+        )
+        .await?;
 
         Ok(proposal)
     }
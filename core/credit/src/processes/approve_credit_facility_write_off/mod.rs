@@ -0,0 +1,111 @@
+mod job;
+
+use std::sync::Arc;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{ApprovalProcessType, GovernanceAction, GovernanceEvent, GovernanceObject};
+use tracing::instrument;
+use tracing_macros::record_error_severity;
+
+use core_custody::{CoreCustodyAction, CoreCustodyEvent, CoreCustodyObject};
+use core_price::CorePriceEvent;
+use obix::out::OutboxEventMarker;
+
+use crate::{
+    CoreCreditAction, CoreCreditError, CoreCreditEvent, CoreCreditObject, CreditFacility,
+    collateral::{
+        CoreCreditCollateralAction, CoreCreditCollateralObject, public::CoreCreditCollateralEvent,
+    },
+    credit_facility::CreditFacilities,
+    primitives::{ApprovalProcessId, CreditFacilityId},
+};
+
+pub use job::*;
+pub const APPROVE_CREDIT_FACILITY_WRITE_OFF_PROCESS: ApprovalProcessType =
+    ApprovalProcessType::new("credit-facility-write-off");
+
+pub struct ApproveCreditFacilityWriteOff<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<CoreCreditCollateralEvent>
+        + OutboxEventMarker<crate::CoreCreditCollectionEvent>
+        + OutboxEventMarker<CoreCustodyEvent>
+        + OutboxEventMarker<CorePriceEvent>,
+{
+    credit_facilities: Arc<CreditFacilities<Perms, E>>,
+}
+
+impl<Perms, E> Clone for ApproveCreditFacilityWriteOff<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<CoreCreditCollateralEvent>
+        + OutboxEventMarker<crate::CoreCreditCollectionEvent>
+        + OutboxEventMarker<CoreCustodyEvent>
+        + OutboxEventMarker<CorePriceEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            credit_facilities: self.credit_facilities.clone(),
+        }
+    }
+}
+
+impl<Perms, E> ApproveCreditFacilityWriteOff<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>
+        + From<crate::CoreCreditCollectionAction>
+        + From<CoreCreditCollateralAction>
+        + From<GovernanceAction>
+        + From<CoreCustodyAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>
+        + From<crate::CoreCreditCollectionObject>
+        + From<CoreCreditCollateralObject>
+        + From<GovernanceObject>
+        + From<CoreCustodyObject>,
+    E: OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<CoreCreditCollateralEvent>
+        + OutboxEventMarker<crate::CoreCreditCollectionEvent>
+        + OutboxEventMarker<CoreCustodyEvent>
+        + OutboxEventMarker<CorePriceEvent>,
+{
+    pub fn new(credit_facilities: Arc<CreditFacilities<Perms, E>>) -> Self {
+        Self { credit_facilities }
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "credit_facility.approve_write_off", skip(self))]
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    pub async fn execute_approve_write_off(
+        &self,
+        credit_facility_id: impl es_entity::RetryableInto<CreditFacilityId>,
+        approval_process_id: impl es_entity::RetryableInto<ApprovalProcessId>,
+        approved: bool,
+    ) -> Result<CreditFacility, CoreCreditError> {
+        let mut op = self
+            .credit_facilities
+            .begin_op()
+            .await?
+            .with_db_time()
+            .await?;
+
+        let credit_facility = self
+            .credit_facilities
+            .conclude_write_off_approval_in_op(
+                &mut op,
+                credit_facility_id.into(),
+                approval_process_id.into(),
+                approved,
+            )
+            .await?;
+        op.commit().await?;
+
+        Ok(credit_facility)
+    }
+}
@@ -1,4 +1,6 @@
 pub mod activate_credit_facility;
 pub mod allocate_credit_facility_payment;
+pub mod approve_credit_facility_amendment;
 pub mod approve_credit_facility_proposal;
+pub mod approve_credit_facility_write_off;
 pub mod approve_disbursal;
@@ -6,7 +6,9 @@ use schemars::JsonSchema;
 
 use money::UsdCents;
 
-pub use crate::credit_facility::{FacilityCollateralization, LiquidationTrigger};
+pub use crate::credit_facility::{
+    CreditFacilityAmendment, CreditFacilityWriteOff, FacilityCollateralization, LiquidationTrigger,
+};
 use crate::{
     credit_facility::CreditFacility,
     primitives::{CollateralId, CreditFacilityId, CustomerId, LedgerTxId},
@@ -24,6 +26,10 @@ pub struct PublicCreditFacility {
     pub completed_at: Option<DateTime<Utc>>,
     pub liquidation_trigger: Option<LiquidationTrigger>,
     pub collateralization: FacilityCollateralization,
+    #[serde(default)]
+    pub amendment: Option<CreditFacilityAmendment>,
+    #[serde(default)]
+    pub write_off: Option<CreditFacilityWriteOff>,
 }
 
 impl From<&CreditFacility> for PublicCreditFacility {
@@ -38,6 +44,8 @@ impl From<&CreditFacility> for PublicCreditFacility {
             completed_at: entity.completed_at(),
             liquidation_trigger: entity.last_liquidation_trigger(),
             collateralization: entity.last_collateralization(),
+            amendment: entity.last_amendment(),
+            write_off: entity.last_write_off(),
         }
     }
 }
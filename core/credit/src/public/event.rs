@@ -42,4 +42,10 @@ pub enum CoreCreditEvent {
     PartialLiquidationInitiated {
         entity: PublicCreditFacility,
     },
+    FacilityAmended {
+        entity: PublicCreditFacility,
+    },
+    FacilityWrittenOff {
+        entity: PublicCreditFacility,
+    },
 }
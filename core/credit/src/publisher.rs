@@ -75,6 +75,12 @@ where
                         entity: PublicCreditFacility::from(entity),
                     })
                 }
+                Amended { .. } => Some(CoreCreditEvent::FacilityAmended {
+                    entity: PublicCreditFacility::from(entity),
+                }),
+                WrittenOff { .. } => Some(CoreCreditEvent::FacilityWrittenOff {
+                    entity: PublicCreditFacility::from(entity),
+                }),
 
                 _ => None,
             })
@@ -7,6 +7,17 @@ use crate::primitives::*;
 pub enum RepaymentType {
     Disbursal,
     Interest,
+    Penalty,
+}
+
+impl From<RepaymentType> for ObligationType {
+    fn from(value: RepaymentType) -> Self {
+        match value {
+            RepaymentType::Disbursal => Self::Disbursal,
+            RepaymentType::Interest => Self::Interest,
+            RepaymentType::Penalty => Self::Penalty,
+        }
+    }
 }
 
 impl From<&ObligationType> for RepaymentType {
@@ -14,6 +25,7 @@ impl From<&ObligationType> for RepaymentType {
         match value {
             ObligationType::Disbursal => Self::Disbursal,
             ObligationType::Interest => Self::Interest,
+            ObligationType::Penalty => Self::Penalty,
         }
     }
 }
@@ -44,17 +56,32 @@ impl PartialOrd for CreditFacilityRepaymentPlanEntry {
 
 impl Ord for CreditFacilityRepaymentPlanEntry {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.due_at.cmp(&other.due_at).then_with(|| {
-            match (self.repayment_type, other.repayment_type) {
-                (RepaymentType::Interest, RepaymentType::Disbursal) => std::cmp::Ordering::Less,
-                (RepaymentType::Disbursal, RepaymentType::Interest) => std::cmp::Ordering::Greater,
-                _ => std::cmp::Ordering::Equal,
-            }
-        })
+        self.waterfall_order(
+            other,
+            AllocationStrategy::default(),
+            PenaltyAllocation::default(),
+        )
     }
 }
 
 impl CreditFacilityRepaymentPlanEntry {
+    /// Orders entries by due date and, within the same date, in the order the
+    /// facility's waterfall would settle them.
+    pub(super) fn waterfall_order(
+        &self,
+        other: &Self,
+        strategy: AllocationStrategy,
+        penalty_allocation: PenaltyAllocation,
+    ) -> std::cmp::Ordering {
+        let priority = |entry: &Self| {
+            ObligationType::from(entry.repayment_type)
+                .allocation_priority(strategy, penalty_allocation)
+        };
+        self.due_at
+            .cmp(&other.due_at)
+            .then_with(|| priority(self).cmp(&priority(other)))
+    }
+
     pub fn is_not_upcoming(&self) -> bool {
         self.status != RepaymentStatus::Upcoming
     }
@@ -68,6 +95,8 @@ pub enum RepaymentStatus {
     Overdue,
     Defaulted,
     Paid,
+    Rescheduled,
+    WrittenOff,
 }
 
 impl From<ObligationStatus> for RepaymentStatus {
@@ -78,6 +107,8 @@ impl From<ObligationStatus> for RepaymentStatus {
             ObligationStatus::Overdue => RepaymentStatus::Overdue,
             ObligationStatus::Defaulted => RepaymentStatus::Defaulted,
             ObligationStatus::Paid => RepaymentStatus::Paid,
+            ObligationStatus::Rescheduled => RepaymentStatus::Rescheduled,
+            ObligationStatus::WrittenOff => RepaymentStatus::WrittenOff,
         }
     }
 }
@@ -63,7 +63,8 @@ where
             }
             Some(e @ FacilityActivated { entity })
             | Some(e @ FacilityCompleted { entity })
-            | Some(e @ PartialLiquidationInitiated { entity }) => {
+            | Some(e @ PartialLiquidationInitiated { entity })
+            | Some(e @ FacilityAmended { entity }) => {
                 self.handle_credit_event(db, event, e, entity.id, sequence, &clock)
                     .await?;
             }
@@ -114,6 +115,16 @@ where
                 e @ ObligationCompleted {
                     entity: PublicObligation { beneficiary_id, .. },
                 },
+            )
+            | Some(
+                e @ ObligationWrittenOff {
+                    entity: PublicObligation { beneficiary_id, .. },
+                },
+            )
+            | Some(
+                e @ ObligationRescheduled {
+                    entity: PublicObligation { beneficiary_id, .. },
+                },
             ) => {
                 self.handle_collection_event(db, event, e, *beneficiary_id, sequence, &clock)
                     .await?;
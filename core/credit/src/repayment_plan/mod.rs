@@ -104,8 +104,10 @@ impl CreditFacilityRepaymentPlan {
             if let Some(last_interest_payment) = self.last_interest_accrual_at {
                 terms
                     .accrual_cycle_interval
-                    .period_from(last_interest_payment)
-                    .next()
+                    .period_containing(
+                        activated_at,
+                        last_interest_payment + chrono::Duration::seconds(1),
+                    )
                     .truncate(maturity_date.start_of_day())
             } else {
                 terms
@@ -128,9 +130,7 @@ impl CreditFacilityRepaymentPlan {
 
         let mut planned_interest_entries = vec![];
         while let Some(period) = next_interest_period {
-            let interest = terms
-                .annual_rate
-                .interest_for_time_period(disbursed_outstanding, period.days());
+            let interest = terms.interest_for_period(disbursed_outstanding, &period);
 
             planned_interest_entries.push(CreditFacilityRepaymentPlanEntry {
                 repayment_type: RepaymentType::Interest,
@@ -146,7 +146,10 @@ impl CreditFacilityRepaymentPlan {
                 effective: period.end.date_naive(),
             });
 
-            next_interest_period = period.next().truncate(maturity_date.start_of_day());
+            next_interest_period = terms
+                .accrual_cycle_interval
+                .period_after(activated_at, &period)
+                .truncate(maturity_date.start_of_day());
         }
 
         planned_interest_entries
@@ -171,6 +174,16 @@ impl CreditFacilityRepaymentPlan {
             CoreCreditEvent::FacilityActivated { entity } => {
                 self.activated_at = Some(entity.activated_at);
             }
+            CoreCreditEvent::FacilityAmended { entity } => {
+                let amendment = entity
+                    .amendment
+                    .as_ref()
+                    .expect("amendment must be set for FacilityAmended");
+                if self.terms == Some(amendment.terms) {
+                    return false;
+                }
+                self.terms = Some(amendment.terms);
+            }
             CoreCreditEvent::AccrualPosted { entity } => {
                 let posting = entity
                     .posting
@@ -264,9 +277,21 @@ impl CreditFacilityRepaymentPlan {
                     return false;
                 }
             }
+            CoreCreditCollectionEvent::ObligationRescheduled { entity } => {
+                if let Some(entry) = existing_obligations
+                    .iter_mut()
+                    .find_map(|entry| (entry.obligation_id == Some(entity.id)).then_some(entry))
+                {
+                    entry.status = RepaymentStatus::Rescheduled;
+                    entry.outstanding = UsdCents::ZERO;
+                } else {
+                    return false;
+                }
+            }
             CoreCreditCollectionEvent::ObligationDue { entity }
             | CoreCreditCollectionEvent::ObligationOverdue { entity }
             | CoreCreditCollectionEvent::ObligationDefaulted { entity }
+            | CoreCreditCollectionEvent::ObligationWrittenOff { entity }
             | CoreCreditCollectionEvent::ObligationCompleted { entity } => {
                 if let Some(entry) = existing_obligations
                     .iter_mut()
@@ -280,6 +305,9 @@ impl CreditFacilityRepaymentPlan {
                         CoreCreditCollectionEvent::ObligationDefaulted { .. } => {
                             RepaymentStatus::Defaulted
                         }
+                        CoreCreditCollectionEvent::ObligationWrittenOff { .. } => {
+                            RepaymentStatus::WrittenOff
+                        }
                         CoreCreditCollectionEvent::ObligationCompleted { .. } => {
                             RepaymentStatus::Paid
                         }
@@ -313,7 +341,10 @@ impl CreditFacilityRepaymentPlan {
             .into_iter()
             .chain(planned_interest_entries)
             .collect();
-        self.entries.sort();
+        let terms = self.terms.expect("Missing FacilityCreated event");
+        self.entries.sort_by(|a, b| {
+            a.waterfall_order(b, terms.allocation_strategy, terms.penalty_allocation)
+        });
     }
 }
 
@@ -575,6 +606,10 @@ mod tests {
                     repayment_type: RepaymentType::Interest,
                     ..
                 } => res.interest_unpaid += 1,
+                CreditFacilityRepaymentPlanEntry {
+                    repayment_type: RepaymentType::Penalty,
+                    ..
+                } => {}
             }
         }
 
@@ -598,6 +633,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn entries_due_together_follow_allocation_strategy() {
+        let types_due_at_maturity = |allocation_strategy: AllocationStrategy| {
+            let mut terms = terms(5);
+            terms.allocation_strategy = allocation_strategy;
+            let plan = plan(terms);
+            let maturity = plan.entries.last().unwrap().due_at;
+            plan.entries
+                .iter()
+                .filter(|entry| entry.due_at == maturity)
+                .map(|entry| entry.repayment_type)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            types_due_at_maturity(AllocationStrategy::InterestBeforePrincipal),
+            vec![
+                RepaymentType::Interest,
+                RepaymentType::Disbursal,
+                RepaymentType::Disbursal
+            ]
+        );
+        assert_eq!(
+            types_due_at_maturity(AllocationStrategy::PrincipalFirst),
+            vec![
+                RepaymentType::Disbursal,
+                RepaymentType::Disbursal,
+                RepaymentType::Interest
+            ]
+        );
+    }
+
     #[test]
     fn with_zero_structuring_fee() {
         let mut plan = initial_plan_no_structuring_fee();
@@ -613,6 +680,8 @@ mod tests {
                 completed_at: None,
                 liquidation_trigger: None,
                 collateralization: default_collateralization(),
+                amendment: None,
+                write_off: None,
             },
         }];
         process_credit_events(&mut plan, events);
@@ -648,6 +717,8 @@ mod tests {
                     completed_at: None,
                     liquidation_trigger: None,
                     collateralization: default_collateralization(),
+                    amendment: None,
+                    write_off: None,
                 },
             },
             accrual_posted_event(period),
@@ -686,6 +757,8 @@ mod tests {
                     completed_at: None,
                     liquidation_trigger: None,
                     collateralization: default_collateralization(),
+                    amendment: None,
+                    write_off: None,
                 },
             },
             accrual_posted_event(period_1),
@@ -707,6 +780,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn monthly_anniversary_cycle_follows_activation_day() {
+        let mut terms = terms(0);
+        terms.accrual_cycle_interval = InterestInterval::MonthlyAnniversary;
+        let mut plan = plan(terms);
+
+        let activated_at = "2021-01-15T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let period = InterestInterval::MonthlyAnniversary.period_from(activated_at);
+        let events = vec![
+            CoreCreditEvent::FacilityActivated {
+                entity: crate::PublicCreditFacility {
+                    id: CreditFacilityId::new(),
+                    customer_id: CustomerId::new(),
+                    collateral_id: CollateralId::new(),
+                    activation_tx_id: LedgerTxId::new(),
+                    activated_at,
+                    amount: default_facility_amount(),
+                    completed_at: None,
+                    liquidation_trigger: None,
+                    collateralization: default_collateralization(),
+                    amendment: None,
+                    write_off: None,
+                },
+            },
+            accrual_posted_event(period),
+        ];
+        process_credit_events(&mut plan, events);
+
+        let interest_dates: Vec<_> = plan
+            .entries
+            .iter()
+            .filter(|entry| entry.repayment_type == RepaymentType::Interest)
+            .map(|entry| entry.effective.to_string())
+            .collect();
+        assert_eq!(
+            interest_dates,
+            vec!["2021-02-14", "2021-03-14", "2021-04-14", "2021-04-15"]
+        );
+    }
+
     #[test]
     fn with_first_disbursal_obligation_created() {
         let mut plan = initial_plan();
@@ -724,6 +837,8 @@ mod tests {
                     completed_at: None,
                     liquidation_trigger: None,
                     collateralization: default_collateralization(),
+                    amendment: None,
+                    write_off: None,
                 },
             }),
             TestEvent::Collection(CoreCreditCollectionEvent::ObligationCreated {
@@ -775,6 +890,8 @@ mod tests {
                     completed_at: None,
                     liquidation_trigger: None,
                     collateralization: default_collateralization(),
+                    amendment: None,
+                    write_off: None,
                 },
             }),
             TestEvent::Collection(CoreCreditCollectionEvent::ObligationCreated {
@@ -842,6 +959,8 @@ mod tests {
                     completed_at: None,
                     liquidation_trigger: None,
                     collateralization: default_collateralization(),
+                    amendment: None,
+                    write_off: None,
                 },
             }),
             TestEvent::Collection(CoreCreditCollectionEvent::ObligationCreated {
@@ -881,6 +1000,8 @@ mod tests {
                     amount: UsdCents::from(400_00),
                     recorded_at: interest_recorded_at,
                     effective: interest_recorded_at.date_naive(),
+                    allocation_strategy: AllocationStrategy::default(),
+                    recovery: false,
                 },
             }),
         ];
@@ -935,6 +1056,8 @@ mod tests {
                     completed_at: None,
                     liquidation_trigger: None,
                     collateralization: default_collateralization(),
+                    amendment: None,
+                    write_off: None,
                 },
             }),
             TestEvent::Collection(CoreCreditCollectionEvent::ObligationCreated {
@@ -974,6 +1097,8 @@ mod tests {
                     amount: UsdCents::from(1_000_00),
                     recorded_at: interest_recorded_at,
                     effective: interest_recorded_at.date_naive(),
+                    allocation_strategy: AllocationStrategy::default(),
+                    recovery: false,
                 },
             }),
         ];
@@ -1064,6 +1189,8 @@ mod tests {
                     completed_at: None,
                     liquidation_trigger: None,
                     collateralization: default_collateralization(),
+                    amendment: None,
+                    write_off: None,
                 },
             }),
             TestEvent::Collection(CoreCreditCollectionEvent::ObligationCreated {
@@ -1175,6 +1302,8 @@ mod tests {
                     completed_at: None,
                     liquidation_trigger: None,
                     collateralization: default_collateralization(),
+                    amendment: None,
+                    write_off: None,
                 },
             }),
             TestEvent::Collection(CoreCreditCollectionEvent::ObligationCreated {
@@ -1217,6 +1346,8 @@ mod tests {
                 amount: UsdCents::from(1_000_00),
                 recorded_at: interest_recorded_at,
                 effective: interest_recorded_at.date_naive(),
+                allocation_strategy: AllocationStrategy::default(),
+                recovery: false,
             },
         };
 
@@ -1280,6 +1411,8 @@ mod tests {
                 completed_at: None,
                 liquidation_trigger: None,
                 collateralization: default_collateralization(),
+                amendment: None,
+                write_off: None,
             },
         };
         plan.process_credit_event(
@@ -1350,6 +1483,8 @@ mod tests {
                 completed_at: None,
                 liquidation_trigger: None,
                 collateralization: default_collateralization(),
+                amendment: None,
+                write_off: None,
             },
         };
         plan.process_credit_event(
@@ -1455,6 +1590,8 @@ mod tests {
                 completed_at: None,
                 liquidation_trigger: None,
                 collateralization: default_collateralization(),
+                amendment: None,
+                write_off: None,
             },
         };
 
@@ -1510,6 +1647,8 @@ mod tests {
                     completed_at: None,
                     liquidation_trigger: None,
                     collateralization: default_collateralization(),
+                    amendment: None,
+                    write_off: None,
                 },
             }),
             TestEvent::Collection(CoreCreditCollectionEvent::ObligationCreated {
@@ -1583,4 +1722,144 @@ mod tests {
             .status;
         assert_eq!(status, RepaymentStatus::Paid);
     }
+
+    #[test]
+    fn amended_terms_replan_upcoming_entries() {
+        let mut plan = initial_plan_no_structuring_fee();
+        let facility = crate::PublicCreditFacility {
+            id: CreditFacilityId::new(),
+            customer_id: CustomerId::new(),
+            collateral_id: CollateralId::new(),
+            activation_tx_id: LedgerTxId::new(),
+            activated_at: default_start_date(),
+            amount: default_facility_amount(),
+            completed_at: None,
+            liquidation_trigger: None,
+            collateralization: default_collateralization(),
+            amendment: None,
+            write_off: None,
+        };
+        let mut amended_terms = terms(0);
+        amended_terms.duration = FacilityDuration::Months(6);
+        let amendment = crate::CreditFacilityAmendment {
+            approval_process_id: ApprovalProcessId::new(),
+            previous_terms: terms(0),
+            terms: amended_terms,
+            previous_maturity_date: terms(0).maturity_date(default_start_date()),
+            maturity_date: amended_terms.maturity_date(default_start_date()),
+            reschedule_overdue_obligations: false,
+            effective: default_start_date().date_naive(),
+        };
+
+        let events = vec![
+            CoreCreditEvent::FacilityActivated {
+                entity: facility.clone(),
+            },
+            CoreCreditEvent::FacilityAmended {
+                entity: crate::PublicCreditFacility {
+                    amendment: Some(amendment.clone()),
+                    ..facility
+                },
+            },
+        ];
+        process_credit_events(&mut plan, events);
+
+        let counts = count_entries(&plan);
+        assert_eq!(
+            counts,
+            EntriesCount {
+                interest_unpaid: 0,
+                interest_paid: 0,
+                interest_upcoming: 7,
+                disbursals_unpaid: 0,
+                disbursals_paid: 0,
+                disbursals_upcoming: 1,
+            }
+        );
+        assert!(
+            plan.entries
+                .iter()
+                .all(|e| e.due_at <= amendment.maturity_date)
+        );
+    }
+
+    #[test]
+    fn rescheduled_obligation_is_closed_out() {
+        let obligation_id = ObligationId::new();
+        let mut plan = initial_plan();
+        let recorded_at = default_start_date();
+        let obligation_entity = PublicObligation {
+            id: obligation_id,
+            obligation_type: ObligationType::Disbursal,
+            beneficiary_id: CreditFacilityId::new().into(),
+            initial_amount: UsdCents::from(100_000_00),
+            outstanding_amount: UsdCents::from(100_000_00),
+            due_at: EffectiveDate::from(recorded_at),
+            overdue_at: None,
+            defaulted_at: None,
+            recorded_at,
+            effective: recorded_at.date_naive(),
+        };
+
+        let events = vec![
+            TestEvent::Collection(CoreCreditCollectionEvent::ObligationCreated {
+                entity: obligation_entity.clone(),
+            }),
+            TestEvent::Collection(CoreCreditCollectionEvent::ObligationOverdue {
+                entity: obligation_entity.clone(),
+            }),
+            TestEvent::Collection(CoreCreditCollectionEvent::ObligationRescheduled {
+                entity: obligation_entity,
+            }),
+        ];
+        process_test_events(&mut plan, events);
+
+        let entry = plan
+            .entries
+            .iter()
+            .find(|e| e.obligation_id == Some(obligation_id))
+            .unwrap();
+        assert_eq!(entry.status, RepaymentStatus::Rescheduled);
+        assert_eq!(entry.outstanding, UsdCents::ZERO);
+    }
+
+    #[test]
+    fn written_off_obligation_keeps_outstanding() {
+        let obligation_id = ObligationId::new();
+        let mut plan = initial_plan();
+        let recorded_at = default_start_date();
+        let obligation_entity = PublicObligation {
+            id: obligation_id,
+            obligation_type: ObligationType::Disbursal,
+            beneficiary_id: CreditFacilityId::new().into(),
+            initial_amount: UsdCents::from(100_000_00),
+            outstanding_amount: UsdCents::from(100_000_00),
+            due_at: EffectiveDate::from(recorded_at),
+            overdue_at: None,
+            defaulted_at: None,
+            recorded_at,
+            effective: recorded_at.date_naive(),
+        };
+
+        let events = vec![
+            TestEvent::Collection(CoreCreditCollectionEvent::ObligationCreated {
+                entity: obligation_entity.clone(),
+            }),
+            TestEvent::Collection(CoreCreditCollectionEvent::ObligationDefaulted {
+                entity: obligation_entity.clone(),
+            }),
+            TestEvent::Collection(CoreCreditCollectionEvent::ObligationWrittenOff {
+                entity: obligation_entity,
+            }),
+        ];
+        process_test_events(&mut plan, events);
+
+        let entry = plan
+            .entries
+            .iter()
+            .find(|e| e.obligation_id == Some(obligation_id))
+            .unwrap();
+        assert_eq!(entry.status, RepaymentStatus::WrittenOff);
+        assert_eq!(entry.outstanding, UsdCents::from(100_000_00));
+    }
 }
@@ -0,0 +1,181 @@
+use chrono::{Datelike, NaiveDate};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+
+const DAYS_IN_YEAR_365: u32 = 365;
+const DAYS_IN_YEAR_360: u32 = 360;
+const DAYS_IN_LEAP_YEAR: u32 = 366;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum DayCountConvention {
+    #[default]
+    Actual365,
+    Actual360,
+    Thirty360Us,
+    Thirty360European,
+    ActualActualIsda,
+}
+
+impl DayCountConvention {
+    /// Fraction of a year between `start` (inclusive) and `end` (exclusive).
+    pub fn year_fraction(&self, start: NaiveDate, end: NaiveDate) -> Decimal {
+        self.prorate(Decimal::ONE, start, end)
+    }
+
+    /// Portion of `annual_amount` accruing between `start` (inclusive) and `end` (exclusive).
+    pub fn prorate(&self, annual_amount: Decimal, start: NaiveDate, end: NaiveDate) -> Decimal {
+        if end <= start {
+            return Decimal::ZERO;
+        }
+
+        match self {
+            Self::Actual365 => {
+                annual_amount * Decimal::from(actual_days(start, end))
+                    / Decimal::from(DAYS_IN_YEAR_365)
+            }
+            Self::Actual360 => {
+                annual_amount * Decimal::from(actual_days(start, end))
+                    / Decimal::from(DAYS_IN_YEAR_360)
+            }
+            Self::Thirty360Us | Self::Thirty360European => {
+                annual_amount * Decimal::from(self.thirty_360_days(start, end))
+                    / Decimal::from(DAYS_IN_YEAR_360)
+            }
+            Self::ActualActualIsda => {
+                let mut amount = Decimal::ZERO;
+                let mut from = start;
+                while from < end {
+                    let next_year_start = NaiveDate::from_ymd_opt(from.year() + 1, 1, 1)
+                        .expect("should return a valid date");
+                    let to = end.min(next_year_start);
+                    let days_in_year = if from.leap_year() {
+                        DAYS_IN_LEAP_YEAR
+                    } else {
+                        DAYS_IN_YEAR_365
+                    };
+                    amount += annual_amount * Decimal::from(actual_days(from, to))
+                        / Decimal::from(days_in_year);
+                    from = to;
+                }
+                amount
+            }
+        }
+    }
+
+    fn thirty_360_days(&self, start: NaiveDate, end: NaiveDate) -> i64 {
+        let mut d1 = start.day();
+        let mut d2 = end.day();
+
+        match self {
+            Self::Thirty360Us => {
+                if d1 == 31 {
+                    d1 = 30;
+                }
+                if d2 == 31 && d1 >= 30 {
+                    d2 = 30;
+                }
+            }
+            _ => {
+                d1 = d1.min(30);
+                d2 = d2.min(30);
+            }
+        }
+
+        360 * i64::from(end.year() - start.year())
+            + 30 * (i64::from(end.month()) - i64::from(start.month()))
+            + (i64::from(d2) - i64::from(d1))
+    }
+}
+
+fn actual_days(start: NaiveDate, end: NaiveDate) -> i64 {
+    (end - start).num_days()
+}
+
+#[cfg(test)]
+mod test {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn actual_365() {
+        let fraction =
+            DayCountConvention::Actual365.year_fraction(date("2024-01-01"), date("2025-01-01"));
+        assert_eq!(fraction, dec!(366) / dec!(365));
+    }
+
+    #[test]
+    fn actual_360() {
+        let fraction =
+            DayCountConvention::Actual360.year_fraction(date("2024-01-01"), date("2024-01-31"));
+        assert_eq!(fraction, dec!(30) / dec!(360));
+    }
+
+    #[test]
+    fn thirty_360_full_months_are_thirty_days() {
+        for convention in [
+            DayCountConvention::Thirty360Us,
+            DayCountConvention::Thirty360European,
+        ] {
+            assert_eq!(
+                convention.year_fraction(date("2024-12-01"), date("2025-01-01")),
+                dec!(30) / dec!(360)
+            );
+            assert_eq!(
+                convention.year_fraction(date("2025-02-01"), date("2025-03-01")),
+                dec!(30) / dec!(360)
+            );
+        }
+    }
+
+    #[test]
+    fn thirty_360_us_and_european_differ_on_month_end() {
+        let start = date("2024-01-15");
+        let end = date("2024-03-31");
+        assert_eq!(
+            DayCountConvention::Thirty360Us.year_fraction(start, end),
+            dec!(76) / dec!(360)
+        );
+        assert_eq!(
+            DayCountConvention::Thirty360European.year_fraction(start, end),
+            dec!(75) / dec!(360)
+        );
+    }
+
+    #[test]
+    fn thirty_360_daily_accruals_sum_to_month() {
+        let convention = DayCountConvention::Thirty360Us;
+        let mut total = Decimal::ZERO;
+        let mut day = date("2024-12-01");
+        while day < date("2025-01-01") {
+            let next = day.succ_opt().unwrap();
+            total += convention.prorate(dec!(360), day, next);
+            day = next;
+        }
+        assert_eq!(total, dec!(30));
+    }
+
+    #[test]
+    fn actual_actual_isda_splits_across_years() {
+        let fraction = DayCountConvention::ActualActualIsda
+            .year_fraction(date("2023-12-01"), date("2024-02-01"));
+        assert_eq!(fraction, dec!(31) / dec!(365) + dec!(31) / dec!(366));
+    }
+
+    #[test]
+    fn empty_period_is_zero() {
+        let fraction =
+            DayCountConvention::Actual365.year_fraction(date("2024-01-01"), date("2024-01-01"));
+        assert_eq!(fraction, Decimal::ZERO);
+    }
+}
@@ -19,6 +19,10 @@ pub enum TermsError {
         "TermsError - MarginCallBelowLiquidationLimit: margin_call_cvl {0} <= liquidation_cvl {1}"
     )]
     MarginCallBelowLiquidationLimit(CVLPct, CVLPct),
+    #[error(
+        "TermsError - UnsupportedAccrualInterval: {0:?} can only be used as accrual cycle interval"
+    )]
+    UnsupportedAccrualInterval(super::value::InterestInterval),
     #[error("TermsError - UninitializedField: {0}")]
     UninitializedField(#[from] derive_builder::UninitializedFieldError),
 }
@@ -29,6 +33,7 @@ impl ErrorSeverity for TermsError {
             Self::InvalidFutureDateComparisonForAccrualDate(_, _) => Level::WARN,
             Self::MarginCallAboveInitialLimit(_, _) => Level::WARN,
             Self::MarginCallBelowLiquidationLimit(_, _) => Level::WARN,
+            Self::UnsupportedAccrualInterval(_) => Level::WARN,
             Self::UninitializedField(_) => Level::ERROR,
         }
     }
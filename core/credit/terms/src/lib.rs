@@ -2,6 +2,7 @@
 
 pub mod collateralization;
 mod cvl;
+mod day_count;
 mod effective_date;
 mod error;
 pub mod primitives;
@@ -9,6 +10,7 @@ pub mod terms_template;
 mod value;
 
 pub use cvl::CVLPct;
+pub use day_count::DayCountConvention;
 pub use effective_date::EffectiveDate;
 pub use error::TermsError;
 pub use primitives::{
@@ -20,6 +22,7 @@ pub use terms_template::{
     TermsTemplateError, TermsTemplateEvent, TermsTemplateId, TermsTemplateRepo, TermsTemplates,
 };
 pub use value::{
-    AnnualRatePct, DisbursalPolicy, FacilityDuration, FacilityDurationType, InterestInterval,
-    InterestPeriod, ObligationDuration, OneTimeFeeRatePct, TermValues, TermValuesBuilder,
+    AllocationStrategy, AnnualRatePct, DisbursalPolicy, FacilityDuration, FacilityDurationType,
+    InterestInterval, InterestPeriod, ObligationDuration, OneTimeFeeRatePct, PenaltyAllocation,
+    TermValues, TermValuesBuilder,
 };
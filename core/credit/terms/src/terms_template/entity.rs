@@ -21,6 +21,9 @@ pub enum TermsTemplateEvent {
     TermValuesUpdated {
         values: TermValues,
     },
+    CustomerSelectabilityUpdated {
+        customer_selectable: bool,
+    },
 }
 
 #[derive(EsEntity, Builder)]
@@ -29,6 +32,10 @@ pub struct TermsTemplate {
     pub id: TermsTemplateId,
     pub name: String,
     pub values: TermValues,
+    /// Whether customers may pick this template when proposing a facility
+    /// themselves.
+    #[builder(default)]
+    pub customer_selectable: bool,
     events: EntityEvents<TermsTemplateEvent>,
 }
 
@@ -49,6 +56,19 @@ impl TermsTemplate {
         self.values = new_values;
         Idempotent::Executed(())
     }
+
+    pub fn update_customer_selectability(&mut self, customer_selectable: bool) -> Idempotent<()> {
+        if self.customer_selectable == customer_selectable {
+            return Idempotent::AlreadyApplied;
+        }
+
+        self.events
+            .push(TermsTemplateEvent::CustomerSelectabilityUpdated {
+                customer_selectable,
+            });
+        self.customer_selectable = customer_selectable;
+        Idempotent::Executed(())
+    }
 }
 
 impl TryFromEvents<TermsTemplateEvent> for TermsTemplate {
@@ -65,6 +85,11 @@ impl TryFromEvents<TermsTemplateEvent> for TermsTemplate {
                 TermsTemplateEvent::TermValuesUpdated { values, .. } => {
                     builder = builder.values(*values);
                 }
+                TermsTemplateEvent::CustomerSelectabilityUpdated {
+                    customer_selectable,
+                } => {
+                    builder = builder.customer_selectable(*customer_selectable);
+                }
             }
         }
         builder.events(events).build()
@@ -20,6 +20,8 @@ pub enum TermsTemplateError {
     AuditError(#[from] audit::error::AuditError),
     #[error("TermsTemplateError - DuplicateTermsTemplateName")]
     DuplicateTermsTemplateName,
+    #[error("TermsTemplateError - NotCustomerSelectable: {0}")]
+    NotCustomerSelectable(TermsTemplateId),
 }
 
 es_entity::from_es_entity_error!(TermsTemplateError);
@@ -46,6 +48,7 @@ impl ErrorSeverity for TermsTemplateError {
             Self::AuthorizationError(e) => e.severity(),
             Self::AuditError(e) => e.severity(),
             Self::DuplicateTermsTemplateName => Level::WARN,
+            Self::NotCustomerSelectable(_) => Level::WARN,
         }
     }
 }
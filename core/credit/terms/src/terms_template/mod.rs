@@ -114,6 +114,32 @@ where
         Ok(terms_template)
     }
 
+    #[record_error_severity]
+    #[instrument(
+        name = "core_credit_terms.terms_template.update_customer_selectability",
+        skip(self)
+    )]
+    pub async fn update_customer_selectability(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: TermsTemplateId,
+        customer_selectable: bool,
+    ) -> Result<TermsTemplate, TermsTemplateError> {
+        self.subject_can_update_terms_template(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let mut terms_template = self.repo.find_by_id(id).await?;
+        if terms_template
+            .update_customer_selectability(customer_selectable)
+            .did_execute()
+        {
+            self.repo.update(&mut terms_template).await?;
+        }
+
+        Ok(terms_template)
+    }
+
     #[record_error_severity]
     #[instrument(name = "core_credit_terms.terms_template.find_by_id", skip(self))]
     pub async fn find_by_id(
@@ -135,6 +161,23 @@ where
         }
     }
 
+    #[record_error_severity]
+    #[instrument(
+        name = "core_credit_terms.terms_template.find_customer_selectable_by_id_without_audit",
+        skip(self)
+    )]
+    pub async fn find_customer_selectable_by_id_without_audit(
+        &self,
+        id: impl Into<TermsTemplateId> + std::fmt::Debug,
+    ) -> Result<TermsTemplate, TermsTemplateError> {
+        let id = id.into();
+        let terms_template = self.repo.find_by_id(id).await?;
+        if !terms_template.customer_selectable {
+            return Err(TermsTemplateError::NotCustomerSelectable(id));
+        }
+        Ok(terms_template)
+    }
+
     pub async fn list(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
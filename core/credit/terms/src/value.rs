@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "json-schema")]
 use schemars::JsonSchema;
 
-use crate::{collateralization::*, cvl::CVLPct, effective_date::*};
+use crate::{collateralization::*, cvl::CVLPct, day_count::DayCountConvention, effective_date::*};
 
 use core_price::PriceOfOneBTC;
 use money::{Satoshis, UsdCents};
@@ -29,6 +29,25 @@ impl AnnualRatePct {
         let cents = principal.to_usd() * Decimal::from(days) * self.0
             / Decimal::from(NUMBER_OF_DAYS_IN_YEAR);
 
+        Self::round_to_cents(cents)
+    }
+
+    pub fn interest_for_period(
+        &self,
+        principal: UsdCents,
+        period: &InterestPeriod,
+        day_count_convention: DayCountConvention,
+    ) -> UsdCents {
+        let cents = day_count_convention.prorate(
+            principal.to_usd() * self.0,
+            period.start.date_naive(),
+            period.end_exclusive_date(),
+        );
+
+        Self::round_to_cents(cents)
+    }
+
+    fn round_to_cents(cents: Decimal) -> UsdCents {
         UsdCents::from(
             cents
                 .round_dp_with_strategy(0, RoundingStrategy::AwayFromZero)
@@ -159,7 +178,15 @@ impl InterestPeriod {
     }
 
     pub fn days(&self) -> u32 {
-        self.end.day() - self.start.day() + 1
+        let days = (self.end.date_naive() - self.start.date_naive()).num_days() + 1;
+        u32::try_from(days).expect("period end should not be before its start")
+    }
+
+    fn end_exclusive_date(&self) -> chrono::NaiveDate {
+        self.end
+            .date_naive()
+            .succ_opt()
+            .expect("should return a valid date")
     }
 }
 
@@ -170,6 +197,12 @@ impl InterestPeriod {
 pub enum InterestInterval {
     EndOfMonth,
     EndOfDay,
+    EndOfWeek,
+    EndOfQuarter,
+    /// Periods run from the start date to the day before the same day of the
+    /// following month. Every anniversary is counted from the original start
+    /// date, so a day clamped to the end of a shorter month does not carry over.
+    MonthlyAnniversary,
 }
 
 impl InterestInterval {
@@ -177,6 +210,70 @@ impl InterestInterval {
         InterestPeriod::new(*self, start_date)
     }
 
+    /// The period of a schedule starting at `anchor` that contains `at`.
+    pub fn period_containing(&self, anchor: DateTime<Utc>, at: DateTime<Utc>) -> InterestPeriod {
+        if self.is_anchored_to_start_date() {
+            self.anniversary_period(anchor, Self::anniversaries_until(anchor, at))
+        } else {
+            self.period_from(at)
+        }
+    }
+
+    /// The period of a schedule starting at `anchor` that follows `previous`.
+    pub fn period_after(&self, anchor: DateTime<Utc>, previous: &InterestPeriod) -> InterestPeriod {
+        if self.is_anchored_to_start_date() {
+            self.anniversary_period(
+                anchor,
+                Self::anniversaries_until(anchor, previous.start) + 1,
+            )
+        } else {
+            previous.next()
+        }
+    }
+
+    fn is_anchored_to_start_date(&self) -> bool {
+        matches!(self, InterestInterval::MonthlyAnniversary)
+    }
+
+    fn anniversary_period(&self, anchor: DateTime<Utc>, n: u32) -> InterestPeriod {
+        let start = if n == 0 {
+            anchor
+        } else {
+            Self::anniversary(anchor, n)
+        };
+        InterestPeriod {
+            interval: *self,
+            start,
+            end: Self::anniversary(anchor, n + 1) - chrono::Duration::seconds(1),
+        }
+    }
+
+    /// Number of monthly anniversaries of `anchor` that have passed at `at`.
+    fn anniversaries_until(anchor: DateTime<Utc>, at: DateTime<Utc>) -> u32 {
+        let (anchor_date, at_date) = (anchor.date_naive(), at.date_naive());
+        let months = (at_date.year() - anchor_date.year()) * 12 + at_date.month() as i32
+            - anchor_date.month() as i32;
+        let Ok(months) = u32::try_from(months) else {
+            return 0;
+        };
+        if months > 0 && at < Self::anniversary(anchor, months) {
+            months - 1
+        } else {
+            months
+        }
+    }
+
+    fn anniversary(anchor: DateTime<Utc>, months: u32) -> DateTime<Utc> {
+        Utc.from_utc_datetime(
+            &anchor
+                .date_naive()
+                .checked_add_months(chrono::Months::new(months))
+                .expect("should return a valid date")
+                .and_hms_opt(0, 0, 0)
+                .expect("should return a valid date time"),
+        )
+    }
+
     fn end_date_starting_at(&self, current_date: DateTime<Utc>) -> DateTime<Utc> {
         match self {
             InterestInterval::EndOfMonth => {
@@ -189,24 +286,47 @@ impl InterestInterval {
                     (current_year, current_month + 1)
                 };
 
-                Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0)
-                    .single()
-                    .expect("should return a valid date time")
-                    - chrono::Duration::seconds(1)
+                Self::start_of_month(year, month) - chrono::Duration::seconds(1)
             }
-            InterestInterval::EndOfDay => Utc
-                .with_ymd_and_hms(
-                    current_date.year(),
-                    current_date.month(),
-                    current_date.day(),
-                    23,
-                    59,
-                    59,
+            InterestInterval::EndOfDay => Self::end_of_day(current_date.date_naive()),
+            InterestInterval::EndOfWeek => {
+                let days_until_sunday = 6 - current_date.weekday().num_days_from_monday();
+                Self::end_of_day(
+                    current_date
+                        .date_naive()
+                        .checked_add_days(chrono::Days::new(days_until_sunday.into()))
+                        .expect("should return a valid date"),
                 )
-                .single()
-                .expect("should return a valid date time"),
+            }
+            InterestInterval::EndOfQuarter => {
+                let next_quarter_first_month = (current_date.month0() / 3 + 1) * 3 + 1;
+                let (year, month) = if next_quarter_first_month > 12 {
+                    (current_date.year() + 1, next_quarter_first_month - 12)
+                } else {
+                    (current_date.year(), next_quarter_first_month)
+                };
+
+                Self::start_of_month(year, month) - chrono::Duration::seconds(1)
+            }
+            InterestInterval::MonthlyAnniversary => {
+                Self::anniversary(current_date, 1) - chrono::Duration::seconds(1)
+            }
         }
     }
+
+    fn start_of_month(year: i32, month: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0)
+            .single()
+            .expect("should return a valid date time")
+    }
+
+    fn end_of_day(date: chrono::NaiveDate) -> DateTime<Utc> {
+        Utc.from_utc_datetime(
+            &date
+                .and_hms_opt(23, 59, 59)
+                .expect("should return a valid date time"),
+        )
+    }
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -217,6 +337,33 @@ pub enum DisbursalPolicy {
     MultipleDisbursal,
 }
 
+/// Where penalty obligations are settled relative to interest and principal
+/// when allocating a payment.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum PenaltyAllocation {
+    BeforeInterest,
+    BeforePrincipal,
+    #[default]
+    AfterPrincipal,
+}
+
+/// Named waterfall deciding which outstanding obligations a payment settles
+/// first.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum AllocationStrategy {
+    /// Interest obligations are settled before principal, oldest first within each type.
+    #[default]
+    InterestBeforePrincipal,
+    /// Principal obligations are settled before interest, oldest first within each type.
+    PrincipalFirst,
+    /// Obligations are settled strictly by due date regardless of their type.
+    OldestFirst,
+}
+
 #[derive(Builder, Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 #[builder(build_fn(validate = "Self::validate", error = "TermsError"))]
@@ -245,6 +392,24 @@ pub struct TermValues {
     pub initial_cvl: CVLPct,
     #[builder(setter(into))]
     pub disbursal_policy: DisbursalPolicy,
+    #[builder(default)]
+    #[serde(default)]
+    pub day_count_convention: DayCountConvention,
+    #[builder(setter(into), default)]
+    #[serde(default)]
+    pub penalty_rate: Option<AnnualRatePct>,
+    #[builder(setter(into), default)]
+    #[serde(default)]
+    pub late_fee: Option<UsdCents>,
+    #[builder(default)]
+    #[serde(default)]
+    pub penalty_allocation: PenaltyAllocation,
+    #[builder(default)]
+    #[serde(default)]
+    pub allocation_strategy: AllocationStrategy,
+    #[builder(setter(into), default)]
+    #[serde(default)]
+    pub prepayment_fee_rate: Option<OneTimeFeeRatePct>,
 }
 
 impl TermValues {
@@ -268,6 +433,27 @@ impl TermValues {
         self.duration.maturity_date(start_date)
     }
 
+    pub fn interest_for_period(&self, principal: UsdCents, period: &InterestPeriod) -> UsdCents {
+        self.annual_rate
+            .interest_for_period(principal, period, self.day_count_convention)
+    }
+
+    pub fn has_penalties(&self) -> bool {
+        self.penalty_rate.is_some() || self.late_fee.is_some_and(|fee| !fee.is_zero())
+    }
+
+    pub fn penalty_for_period(&self, outstanding: UsdCents, period: &InterestPeriod) -> UsdCents {
+        self.penalty_rate
+            .map(|rate| rate.interest_for_period(outstanding, period, self.day_count_convention))
+            .unwrap_or(UsdCents::ZERO)
+    }
+
+    pub fn prepayment_fee(&self, prepaid_principal: UsdCents) -> UsdCents {
+        self.prepayment_fee_rate
+            .map(|rate| rate.apply(prepaid_principal))
+            .unwrap_or(UsdCents::ZERO)
+    }
+
     pub fn builder() -> TermValuesBuilder {
         TermValuesBuilder::default()
     }
@@ -401,6 +587,12 @@ impl TermValuesBuilder {
             ));
         }
 
+        if let Some(accrual_interval) = self.accrual_interval
+            && accrual_interval.is_anchored_to_start_date()
+        {
+            return Err(TermsError::UnsupportedAccrualInterval(accrual_interval));
+        }
+
         Ok(())
     }
 }
@@ -521,6 +713,153 @@ mod test {
         );
     }
 
+    #[test]
+    fn days_across_month_and_year_boundaries() {
+        let period = InterestPeriod {
+            interval: InterestInterval::MonthlyAnniversary,
+            start: "2024-12-15T00:00:00Z".parse::<DateTime<Utc>>().unwrap(),
+            end: "2025-01-14T23:59:59Z".parse::<DateTime<Utc>>().unwrap(),
+        };
+        assert_eq!(period.days(), 31);
+
+        let start_date = "2024-11-20T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            InterestInterval::EndOfQuarter
+                .period_from(start_date)
+                .days(),
+            42
+        );
+    }
+
+    #[test]
+    fn end_of_week() {
+        let wednesday = "2025-01-01T10:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let period = InterestInterval::EndOfWeek.period_from(wednesday);
+        assert_eq!(
+            period.end,
+            "2025-01-05T23:59:59Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        assert_eq!(period.days(), 5);
+
+        let next = period.next();
+        assert_eq!(
+            next.start,
+            "2025-01-06T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        assert_eq!(next.days(), 7);
+
+        let sunday = "2024-12-29T10:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            InterestInterval::EndOfWeek.period_from(sunday).end,
+            "2024-12-29T23:59:59Z".parse::<DateTime<Utc>>().unwrap()
+        );
+    }
+
+    #[test]
+    fn end_of_quarter() {
+        let start_date = "2024-11-20T10:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let period = InterestInterval::EndOfQuarter.period_from(start_date);
+        assert_eq!(
+            period.end,
+            "2024-12-31T23:59:59Z".parse::<DateTime<Utc>>().unwrap()
+        );
+
+        let next = period.next();
+        assert_eq!(
+            next.start,
+            "2025-01-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        assert_eq!(
+            next.end,
+            "2025-03-31T23:59:59Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        assert_eq!(next.days(), 90);
+    }
+
+    #[test]
+    fn monthly_anniversary() {
+        let activated_at = "2024-12-15T10:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let period = InterestInterval::MonthlyAnniversary.period_from(activated_at);
+        assert_eq!(
+            period.end,
+            "2025-01-14T23:59:59Z".parse::<DateTime<Utc>>().unwrap()
+        );
+
+        let next = period.next();
+        assert_eq!(
+            next.start,
+            "2025-01-15T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        assert_eq!(
+            next.end,
+            "2025-02-14T23:59:59Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        assert_eq!(next.days(), 31);
+
+        let activated_at = "2025-01-31T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            InterestInterval::MonthlyAnniversary
+                .period_from(activated_at)
+                .end,
+            "2025-02-27T23:59:59Z".parse::<DateTime<Utc>>().unwrap()
+        );
+    }
+
+    #[test]
+    fn monthly_anniversary_does_not_drift_after_short_months() {
+        let interval = InterestInterval::MonthlyAnniversary;
+        let activated_at = "2025-01-31T10:00:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        let first = interval.period_from(activated_at);
+        let second = interval.period_after(activated_at, &first);
+        assert_eq!(
+            second.start,
+            "2025-02-28T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        assert_eq!(
+            second.end,
+            "2025-03-30T23:59:59Z".parse::<DateTime<Utc>>().unwrap()
+        );
+
+        let third = interval.period_after(activated_at, &second);
+        assert_eq!(
+            third.start,
+            "2025-03-31T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        assert_eq!(
+            third.end,
+            "2025-04-29T23:59:59Z".parse::<DateTime<Utc>>().unwrap()
+        );
+
+        assert_eq!(
+            interval.period_containing(
+                activated_at,
+                "2025-03-15T12:00:00Z".parse::<DateTime<Utc>>().unwrap()
+            ),
+            second
+        );
+    }
+
+    #[test]
+    fn monthly_anniversary_is_not_a_valid_accrual_interval() {
+        let result = TermValues::builder()
+            .annual_rate(AnnualRatePct(dec!(12)))
+            .duration(FacilityDuration::Months(3))
+            .accrual_cycle_interval(InterestInterval::MonthlyAnniversary)
+            .accrual_interval(InterestInterval::MonthlyAnniversary)
+            .one_time_fee_rate(OneTimeFeeRatePct(dec!(1)))
+            .disbursal_policy(DisbursalPolicy::SingleDisbursal)
+            .liquidation_cvl(dec!(105))
+            .margin_call_cvl(dec!(125))
+            .initial_cvl(dec!(140))
+            .build();
+
+        assert!(matches!(
+            result.unwrap_err(),
+            TermsError::UnsupportedAccrualInterval(InterestInterval::MonthlyAnniversary)
+        ));
+    }
+
     #[test]
     fn truncate() {
         let start_date = "2024-12-03T14:00:00Z".parse::<DateTime<Utc>>().unwrap();
@@ -560,6 +899,116 @@ mod test {
         assert_eq!(interest, UsdCents::from(757));
     }
 
+    #[test]
+    fn interest_for_period_defaults_to_actual_365() {
+        let terms = terms();
+        let principal = UsdCents::try_from_usd(dec!(1000)).unwrap();
+        let start_date = "2024-12-03T14:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let period = InterestInterval::EndOfMonth.period_from(start_date);
+        assert_eq!(
+            terms.interest_for_period(principal, &period),
+            terms
+                .annual_rate
+                .interest_for_time_period(principal, period.days())
+        );
+    }
+
+    #[test]
+    fn interest_for_period_with_day_count_convention() {
+        let principal = UsdCents::try_from_usd(dec!(1000)).unwrap();
+        let rate = AnnualRatePct(dec!(12));
+        let start_date = "2025-02-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let period = InterestInterval::EndOfMonth.period_from(start_date);
+
+        assert_eq!(
+            rate.interest_for_period(principal, &period, DayCountConvention::Actual360),
+            UsdCents::from(934)
+        );
+        assert_eq!(
+            rate.interest_for_period(principal, &period, DayCountConvention::Thirty360Us),
+            UsdCents::from(1000)
+        );
+        assert_eq!(
+            rate.interest_for_period(principal, &period, DayCountConvention::Thirty360European),
+            UsdCents::from(1000)
+        );
+    }
+
+    #[test]
+    fn penalty_for_period() {
+        let principal = UsdCents::try_from_usd(dec!(1000)).unwrap();
+        let start_date = "2025-02-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let period = InterestInterval::EndOfDay.period_from(start_date);
+
+        let mut terms = terms();
+        assert!(!terms.has_penalties());
+        assert_eq!(terms.penalty_for_period(principal, &period), UsdCents::ZERO);
+
+        terms.penalty_rate = Some(AnnualRatePct(dec!(36.5)));
+        assert!(terms.has_penalties());
+        assert_eq!(
+            terms.penalty_for_period(principal, &period),
+            UsdCents::from(100)
+        );
+    }
+
+    #[test]
+    fn late_fee_alone_counts_as_penalty() {
+        let mut terms = terms();
+        terms.late_fee = Some(UsdCents::ZERO);
+        assert!(!terms.has_penalties());
+
+        terms.late_fee = Some(UsdCents::from(2500));
+        assert!(terms.has_penalties());
+    }
+
+    #[test]
+    fn term_values_without_penalties_deserialize_with_defaults() {
+        let mut json = serde_json::to_value(terms()).unwrap();
+        let values = json.as_object_mut().unwrap();
+        values.remove("penalty_rate");
+        values.remove("late_fee");
+        values.remove("penalty_allocation");
+        let terms: TermValues = serde_json::from_value(json).unwrap();
+        assert_eq!(terms.penalty_rate, None);
+        assert_eq!(terms.late_fee, None);
+        assert_eq!(terms.penalty_allocation, PenaltyAllocation::AfterPrincipal);
+    }
+
+    #[test]
+    fn prepayment_fee_applies_rate_to_prepaid_principal() {
+        let mut terms = terms();
+        assert_eq!(
+            terms.prepayment_fee(UsdCents::from(100_000)),
+            UsdCents::ZERO
+        );
+
+        terms.prepayment_fee_rate = Some(OneTimeFeeRatePct::new(2));
+        assert_eq!(
+            terms.prepayment_fee(UsdCents::from(100_000)),
+            UsdCents::from(2_000)
+        );
+    }
+
+    #[test]
+    fn term_values_without_allocation_strategy_deserialize_as_interest_before_principal() {
+        let mut json = serde_json::to_value(terms()).unwrap();
+        json.as_object_mut().unwrap().remove("allocation_strategy");
+        let terms: TermValues = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            terms.allocation_strategy,
+            AllocationStrategy::InterestBeforePrincipal
+        );
+    }
+
+    #[test]
+    fn term_values_without_day_count_convention_deserialize_as_actual_365() {
+        let mut json = serde_json::to_value(terms()).unwrap();
+        json.as_object_mut().unwrap().remove("day_count_convention");
+        let terms: TermValues = serde_json::from_value(json).unwrap();
+        assert_eq!(terms.day_count_convention, DayCountConvention::Actual365);
+    }
+
     #[test]
     fn maturity_date() {
         let start_date = "2024-12-03T14:00:00Z".parse::<DateTime<Utc>>().unwrap();
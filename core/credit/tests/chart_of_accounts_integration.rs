@@ -111,9 +111,14 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
     let journal_id = helpers::init_journal(&cala).await?;
     let public_ids = PublicIds::new(&pool);
     let price = core_price::Price::init(
-        core_price::PriceConfig { providers: vec![] },
+        &pool,
+        core_price::PriceConfig {
+            providers: vec![],
+            ..Default::default()
+        },
         &mut jobs,
         &outbox,
+        clock.clone(),
     )
     .await?;
     let exposed_domain_configs =
@@ -144,7 +149,7 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
     .await?;
 
     let accounting_document_storage = DocumentStorage::new(&pool, &storage, clock.clone());
-    let accounting = CoreAccounting::new(
+    let accounting = CoreAccounting::init(
         &pool,
         &authz,
         &cala,
@@ -152,7 +157,9 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         accounting_document_storage,
         &mut jobs,
         &outbox,
-    );
+        &governance,
+    )
+    .await?;
     let chart_ref = format!("ref-{:010}", rand::rng().random_range(0..10_000_000_000u64));
     let chart_id = accounting
         .chart_of_accounts()
@@ -301,6 +308,11 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         chart_of_account_overdue_non_domiciled_company_disbursed_receivable_parent_code: "11"
             .parse()
             .unwrap(),
+        chart_of_account_penalty_receivable_parent_code: None,
+        chart_of_account_penalty_defaulted_parent_code: None,
+        chart_of_account_penalty_income_parent_code: None,
+        chart_of_account_loan_loss_expense_parent_code: None,
+        chart_of_account_recovery_income_parent_code: None,
     };
 
     credit
@@ -788,6 +800,11 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         chart_of_account_overdue_non_domiciled_company_disbursed_receivable_parent_code: "11"
             .parse()
             .unwrap(),
+        chart_of_account_penalty_receivable_parent_code: None,
+        chart_of_account_penalty_defaulted_parent_code: None,
+        chart_of_account_penalty_income_parent_code: None,
+        chart_of_account_loan_loss_expense_parent_code: None,
+        chart_of_account_recovery_income_parent_code: None,
     };
     let res = credit
         .chart_of_accounts_integrations()
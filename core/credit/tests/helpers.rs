@@ -246,10 +246,11 @@ pub async fn create_test_statements<Perms, E>(
 where
     Perms: authz::PermissionCheck,
     <<Perms as authz::PermissionCheck>::Audit as audit::AuditSvc>::Action:
-        From<core_accounting::CoreAccountingAction>,
+        From<core_accounting::CoreAccountingAction> + From<governance::GovernanceAction>,
     <<Perms as authz::PermissionCheck>::Audit as audit::AuditSvc>::Object:
-        From<core_accounting::CoreAccountingObject>,
-    E: obix::out::OutboxEventMarker<core_accounting::CoreAccountingEvent>,
+        From<core_accounting::CoreAccountingObject> + From<governance::GovernanceObject>,
+    E: obix::out::OutboxEventMarker<core_accounting::CoreAccountingEvent>
+        + obix::out::OutboxEventMarker<governance::GovernanceEvent>,
 {
     let bs = format!("BS-{:010}", rand::rng().random_range(0..10_000_000_000u64));
     let pl = format!("PL-{:010}", rand::rng().random_range(0..10_000_000_000u64));
@@ -549,9 +550,14 @@ pub async fn setup() -> anyhow::Result<TestContext> {
     let journal_id = init_journal(&cala).await?;
     let credit_public_ids = PublicIds::new(&pool);
     let price = core_price::Price::init(
-        core_price::PriceConfig { providers: vec![] },
+        &pool,
+        core_price::PriceConfig {
+            providers: vec![],
+            ..Default::default()
+        },
         &mut jobs,
         &outbox,
+        clock.clone(),
     )
     .await?;
     let domain_configs = init_read_only_exposed_domain_configs(&pool, &authz).await?;
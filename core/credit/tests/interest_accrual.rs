@@ -73,9 +73,14 @@ async fn setup_with_clock_control()
     let journal_id = helpers::init_journal(&cala).await?;
     let credit_public_ids = PublicIds::new(&pool);
     let price = core_price::Price::init(
-        core_price::PriceConfig { providers: vec![] },
+        &pool,
+        core_price::PriceConfig {
+            providers: vec![],
+            ..Default::default()
+        },
         &mut jobs,
         &outbox,
+        clock.clone(),
     )
     .await?;
     let domain_configs = helpers::init_read_only_exposed_domain_configs(&pool, &authz).await?;
@@ -328,6 +333,80 @@ async fn accrual_posted_event_on_cycle_completion() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// An amendment that brings maturity forward must mature the facility on the
+/// amended date, not on the date its maturity job was originally scheduled for.
+#[tokio::test]
+#[serial_test::file_serial(core_credit_shared_jobs)]
+async fn shortened_maturity_matures_facility_on_amended_date() -> anyhow::Result<()> {
+    let (mut ctx, clock_ctrl, pool) = setup_with_clock_control().await?;
+    ctx.jobs.start_poll().await?;
+
+    let state = create_active_facility_with_clock(&ctx, &clock_ctrl, daily_cycle_terms()).await?;
+    let facility_id = state.facility_id;
+    let original_matures_at = ctx
+        .credit
+        .facilities()
+        .find_by_id(&DummySubject, facility_id)
+        .await?
+        .expect("facility should exist")
+        .matures_at();
+
+    let mut shortened_terms = daily_cycle_terms();
+    shortened_terms.duration = FacilityDuration::Months(1);
+    ctx.credit
+        .propose_facility_amendment(&DummySubject, facility_id, shortened_terms, false)
+        .await?;
+
+    // Wait for governance approval → amendment applied.
+    let amended_matures_at = tokio::time::timeout(Duration::from_secs(30), async {
+        loop {
+            let matures_at = ctx
+                .credit
+                .facilities()
+                .find_by_id(&DummySubject, facility_id)
+                .await?
+                .expect("facility should exist")
+                .matures_at();
+            if matures_at < original_matures_at {
+                return Ok::<_, anyhow::Error>(matures_at);
+            }
+            clock_ctrl.advance(Duration::from_secs(1)).await;
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    })
+    .await
+    .expect("Timed out waiting for amendment approval")?;
+
+    // Jump straight to the amended maturity rather than stepping through every
+    // day in between, then only nudge the clock so that the original maturity
+    // date is never reached.
+    clock_ctrl.set_time(amended_matures_at);
+    tokio::time::timeout(Duration::from_secs(30), async {
+        loop {
+            let facility = ctx
+                .credit
+                .facilities()
+                .find_by_id(&DummySubject, facility_id)
+                .await?
+                .expect("facility should exist");
+            if facility.status() == CreditFacilityStatus::Matured {
+                return Ok::<_, anyhow::Error>(());
+            }
+            clock_ctrl.advance(Duration::from_secs(1)).await;
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    })
+    .await
+    .expect("Timed out waiting for facility to mature on the amended date")?;
+
+    assert!(clock_ctrl.now() < original_matures_at);
+
+    clock_ctrl.transition_to_realtime();
+    ctx.jobs.shutdown().await?;
+    cleanup_stale_task_jobs(&pool).await?;
+    Ok(())
+}
+
 async fn cleanup_stale_task_jobs(pool: &sqlx::PgPool) -> anyhow::Result<()> {
     sqlx::query(
         "DELETE FROM job_executions
@@ -1,7 +1,7 @@
 pub mod error;
 
 use async_trait::async_trait;
-use bitgo::TransferState;
+use bitgo::{TransferState, TransferType};
 use bytes::Bytes;
 use chrono::Utc;
 
@@ -9,7 +9,7 @@ use money::Satoshis;
 
 use error::CustodianClientError;
 
-use crate::primitives::{ExternalWallet, WalletNetwork};
+use crate::primitives::{ExternalTransfer, ExternalWallet, WalletNetwork};
 
 use super::notification::CustodianNotification;
 
@@ -23,12 +23,23 @@ pub trait CustodianClient: Send {
     /// This call may or may not create new wallet.
     async fn initialize_wallet(&self, label: &str) -> Result<ExternalWallet, CustodianClientError>;
 
+    /// Requests an outbound transfer of `amount` from the wallet to
+    /// `destination_address`. `reference` uniquely identifies the transfer
+    /// on our side so that retried requests are not executed twice.
+    async fn send_transfer(
+        &self,
+        external_wallet_id: &str,
+        destination_address: &str,
+        amount: Satoshis,
+        reference: &str,
+    ) -> Result<ExternalTransfer, CustodianClientError>;
+
     /// Validates and parses webhook.
     async fn process_webhook(
         &self,
         headers: &http::HeaderMap,
         payload: Bytes,
-    ) -> Result<Option<CustodianNotification>, CustodianClientError>;
+    ) -> Result<Vec<CustodianNotification>, CustodianClientError>;
 }
 
 #[async_trait]
@@ -55,16 +66,40 @@ impl CustodianClient for bitgo::BitgoClient {
         })
     }
 
+    async fn send_transfer(
+        &self,
+        external_wallet_id: &str,
+        destination_address: &str,
+        amount: Satoshis,
+        reference: &str,
+    ) -> Result<ExternalTransfer, CustodianClientError> {
+        let (response, full_response) = self
+            .send_coins(
+                external_wallet_id,
+                destination_address,
+                amount.into_inner(),
+                reference,
+            )
+            .await?;
+
+        Ok(ExternalTransfer {
+            external_id: response.transfer.id,
+            full_response,
+        })
+    }
+
     async fn process_webhook(
         &self,
         headers: &http::HeaderMap,
         payload: Bytes,
-    ) -> Result<Option<CustodianNotification>, CustodianClientError> {
+    ) -> Result<Vec<CustodianNotification>, CustodianClientError> {
         let notification = self.validate_webhook_notification(headers, &payload)?;
 
         use bitgo::Notification;
 
-        let custodian_notification = match notification {
+        let mut custodian_notifications = vec![];
+
+        match notification {
             Notification::Transfer(transfer) if transfer.state == TransferState::Confirmed => {
                 let transfer = self
                     .get_transfer(&transfer.transfer, &transfer.wallet)
@@ -75,19 +110,47 @@ impl CustodianClient for bitgo::BitgoClient {
 
                     let changed_at = transfer.confirmed_time.unwrap_or_else(Utc::now);
 
-                    Some(CustodianNotification::WalletBalanceChanged {
+                    if transfer.transfer_type == TransferType::Send {
+                        custodian_notifications.push(CustodianNotification::TransferConfirmed {
+                            external_transfer_id: transfer.id,
+                            tx_hash: transfer.txid,
+                            confirmed_at: changed_at,
+                        });
+                    }
+
+                    custodian_notifications.push(CustodianNotification::WalletBalanceChanged {
                         external_wallet_id: transfer.wallet,
                         new_balance: wallet.confirmed_balance.into(),
                         changed_at,
-                    })
-                } else {
-                    None
+                    });
                 }
             }
-            _ => None,
+            Notification::Transfer(transfer)
+                if transfer.transfer_type == TransferType::Send
+                    && matches!(
+                        transfer.state,
+                        TransferState::Failed | TransferState::Rejected | TransferState::Removed
+                    ) =>
+            {
+                let transfer = self
+                    .get_transfer(&transfer.transfer, &transfer.wallet)
+                    .await?;
+
+                if matches!(
+                    transfer.state,
+                    TransferState::Failed | TransferState::Rejected | TransferState::Removed
+                ) {
+                    custodian_notifications.push(CustodianNotification::TransferFailed {
+                        external_transfer_id: transfer.id,
+                        reason: format!("{:?}", transfer.state),
+                        failed_at: Utc::now(),
+                    });
+                }
+            }
+            _ => (),
         };
 
-        Ok(custodian_notification)
+        Ok(custodian_notifications)
     }
 }
 
@@ -110,16 +173,40 @@ impl CustodianClient for komainu::KomainuClient {
         })
     }
 
+    async fn send_transfer(
+        &self,
+        external_wallet_id: &str,
+        destination_address: &str,
+        amount: Satoshis,
+        reference: &str,
+    ) -> Result<ExternalTransfer, CustodianClientError> {
+        let request = self
+            .create_transaction_request(
+                external_wallet_id,
+                destination_address,
+                amount.to_btc(),
+                reference,
+            )
+            .await?;
+
+        // Komainu creates the transaction only once the request is approved,
+        // so transaction updates are matched on our own reference.
+        Ok(ExternalTransfer {
+            external_id: reference.to_owned(),
+            full_response: serde_json::json!({ "request_id": request.id }),
+        })
+    }
+
     async fn process_webhook(
         &self,
         headers: &http::HeaderMap,
         payload: Bytes,
-    ) -> Result<Option<CustodianNotification>, CustodianClientError> {
+    ) -> Result<Vec<CustodianNotification>, CustodianClientError> {
         let notification = self.validate_webhook_notification(headers, &payload)?;
 
-        use komainu::{EntityType, EventType, Notification};
+        use komainu::{EntityType, EventType, Notification, TransactionStatus};
 
-        let custodian_notification = match notification {
+        let custodian_notifications = match notification {
             Notification {
                 event_type: EventType::BalanceUpdated,
                 entity: EntityType::Wallet,
@@ -131,15 +218,39 @@ impl CustodianClient for komainu::KomainuClient {
 
                 let changed_at = wallet.balance.balance_updated_at.unwrap_or_else(Utc::now);
 
-                Some(CustodianNotification::WalletBalanceChanged {
+                vec![CustodianNotification::WalletBalanceChanged {
                     external_wallet_id: wallet.id,
                     new_balance,
                     changed_at,
-                })
+                }]
             }
+            Notification {
+                event_type: EventType::TransactionUpdated,
+                entity: EntityType::Transaction,
+                entity_id: transaction_id,
+            } => {
+                let transaction = self.get_transaction(&transaction_id).await?;
+
+                match transaction.status {
+                    TransactionStatus::Confirmed => {
+                        vec![CustodianNotification::TransferConfirmed {
+                            external_transfer_id: transaction.external_reference,
+                            tx_hash: transaction.tx_hash,
+                            confirmed_at: Utc::now(),
+                        }]
+                    }
+                    TransactionStatus::Failed => vec![CustodianNotification::TransferFailed {
+                        external_transfer_id: transaction.external_reference,
+                        reason: "Failed".to_string(),
+                        failed_at: Utc::now(),
+                    }],
+                    _ => vec![],
+                }
+            }
+            _ => vec![],
         };
 
-        Ok(custodian_notification)
+        Ok(custodian_notifications)
     }
 }
 
@@ -153,9 +264,11 @@ pub mod mock {
     pub struct CustodianMock;
 
     #[derive(Deserialize)]
-    struct WalletBalanceChanged {
-        wallet: String,
-        balance: u64,
+    #[serde(untagged)]
+    enum MockNotification {
+        WalletBalanceChanged { wallet: String, balance: u64 },
+        TransferConfirmed { transfer: String, tx_hash: String },
+        TransferFailed { transfer: String, reason: String },
     }
 
     #[async_trait]
@@ -175,20 +288,51 @@ pub mod mock {
                 full_response: serde_json::Value::Null,
             })
         }
+
+        async fn send_transfer(
+            &self,
+            _external_wallet_id: &str,
+            _destination_address: &str,
+            _amount: Satoshis,
+            reference: &str,
+        ) -> Result<ExternalTransfer, CustodianClientError> {
+            Ok(ExternalTransfer {
+                external_id: reference.to_string(),
+                full_response: serde_json::Value::Null,
+            })
+        }
+
         async fn process_webhook(
             &self,
             _headers: &http::HeaderMap,
             payload: Bytes,
-        ) -> Result<Option<CustodianNotification>, CustodianClientError> {
-            if let Ok(WalletBalanceChanged { wallet, balance }) = serde_json::from_slice(&payload) {
-                Ok(Some(CustodianNotification::WalletBalanceChanged {
-                    external_wallet_id: wallet,
-                    new_balance: balance.into(),
-                    changed_at: Utc::now(),
-                }))
-            } else {
-                Ok(None)
-            }
+        ) -> Result<Vec<CustodianNotification>, CustodianClientError> {
+            let notification = match serde_json::from_slice(&payload) {
+                Ok(MockNotification::WalletBalanceChanged { wallet, balance }) => {
+                    CustodianNotification::WalletBalanceChanged {
+                        external_wallet_id: wallet,
+                        new_balance: balance.into(),
+                        changed_at: Utc::now(),
+                    }
+                }
+                Ok(MockNotification::TransferConfirmed { transfer, tx_hash }) => {
+                    CustodianNotification::TransferConfirmed {
+                        external_transfer_id: transfer,
+                        tx_hash,
+                        confirmed_at: Utc::now(),
+                    }
+                }
+                Ok(MockNotification::TransferFailed { transfer, reason }) => {
+                    CustodianNotification::TransferFailed {
+                        external_transfer_id: transfer,
+                        reason,
+                        failed_at: Utc::now(),
+                    }
+                }
+                Err(_) => return Ok(vec![]),
+            };
+
+            Ok(vec![notification])
         }
     }
 }
@@ -8,4 +8,14 @@ pub enum CustodianNotification {
         new_balance: Satoshis,
         changed_at: DateTime<Utc>,
     },
+    TransferConfirmed {
+        external_transfer_id: String,
+        tx_hash: String,
+        confirmed_at: DateTime<Utc>,
+    },
+    TransferFailed {
+        external_transfer_id: String,
+        reason: String,
+        failed_at: DateTime<Utc>,
+    },
 }
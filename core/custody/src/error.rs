@@ -18,8 +18,12 @@ pub enum CoreCustodyError {
     CustodianClient(#[from] crate::custodian::client::error::CustodianClientError),
     #[error("CoreCustodyError - WalletError: {0}")]
     Wallet(#[from] crate::wallet::error::WalletError),
+    #[error("CoreCustodyError - WalletTransferError: {0}")]
+    WalletTransfer(#[from] crate::wallet_transfer::error::WalletTransferError),
     #[error("CoreCustodyError - InboxError: {0}")]
     InboxError(#[from] obix::inbox::InboxError),
+    #[error("CoreCustodyError - JobError: {0}")]
+    JobError(#[from] job::error::JobError),
 }
 
 es_entity::from_es_entity_error!(CoreCustodyError);
@@ -34,7 +38,9 @@ impl ErrorSeverity for CoreCustodyError {
             Self::Custodian(e) => e.severity(),
             Self::CustodianClient(e) => e.severity(),
             Self::Wallet(e) => e.severity(),
+            Self::WalletTransfer(e) => e.severity(),
             Self::InboxError(_) => Level::ERROR,
+            Self::JobError(_) => Level::ERROR,
         }
     }
 }
@@ -8,6 +8,7 @@ mod primitives;
 pub mod public;
 mod publisher;
 pub mod wallet;
+pub mod wallet_transfer;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -27,10 +28,15 @@ pub use publisher::CustodyPublisher;
 use audit::AuditSvc;
 use authz::PermissionCheck;
 use encryption::{EncryptionConfig, EncryptionKey};
+use job::JobSpawner;
 use money::Satoshis;
 
 pub use custodian::*;
 pub use wallet::*;
+use wallet_transfer::jobs::send_wallet_transfer::{
+    SendWalletTransferConfig, SendWalletTransferJobInitializer,
+};
+pub use wallet_transfer::*;
 
 pub use config::CustodyConfig;
 use error::CoreCustodyError;
@@ -40,6 +46,7 @@ pub use primitives::*;
 pub mod event_schema {
     pub use crate::custodian::CustodianEvent;
     pub use crate::wallet::WalletEvent;
+    pub use crate::wallet_transfer::WalletTransferEvent;
 }
 
 #[derive(Serialize, Deserialize)]
@@ -58,6 +65,7 @@ where
     authz: Perms,
     custodians: CustodianRepo,
     wallets: WalletRepo<E>,
+    transfers: WalletTransferRepo<E>,
     encryption_config: EncryptionConfig,
     config: CustodyConfig,
 }
@@ -72,6 +80,7 @@ where
             authz: self.authz.clone(),
             custodians: self.custodians.clone(),
             wallets: self.wallets.clone(),
+            transfers: self.transfers.clone(),
             encryption_config: self.encryption_config.clone(),
             config: self.config.clone(),
         }
@@ -114,13 +123,16 @@ where
         clock: ClockHandle,
     ) -> Self {
         let custodians = CustodianRepo::new(pool, clock.clone());
-        let wallets = WalletRepo::new(pool, &CustodyPublisher::new(outbox), clock);
+        let publisher = CustodyPublisher::new(outbox);
+        let wallets = WalletRepo::new(pool, &publisher, clock.clone());
+        let transfers = WalletTransferRepo::new(pool, &publisher, clock);
         Self {
             authz: authz.clone(),
             encryption_config: encryption_config.clone(),
             config: config.clone(),
             custodians,
             wallets,
+            transfers,
         }
     }
 
@@ -143,12 +155,16 @@ where
             .filter_map(|(key, value)| Some((key.parse().ok()?, value.parse().ok()?)))
             .collect();
 
-        if let Ok(custodian) = custodian
-            && let Some(notification) = custodian
-                .custodian_client(&self.encryption_config.key, &self.config.custody_providers)?
-                .process_webhook(&header_map, payload)
-                .await?
-        {
+        let Ok(custodian) = custodian else {
+            return Ok(());
+        };
+
+        let notifications = custodian
+            .custodian_client(&self.encryption_config.key, &self.config.custody_providers)?
+            .process_webhook(&header_map, payload)
+            .await?;
+
+        for notification in notifications {
             match notification {
                 CustodianNotification::WalletBalanceChanged {
                     external_wallet_id,
@@ -163,6 +179,32 @@ where
                     )
                     .await?;
                 }
+                CustodianNotification::TransferConfirmed {
+                    external_transfer_id,
+                    tx_hash,
+                    confirmed_at,
+                } => {
+                    self.confirm_wallet_transfer(
+                        provider_name.clone(),
+                        external_transfer_id,
+                        tx_hash,
+                        confirmed_at,
+                    )
+                    .await?;
+                }
+                CustodianNotification::TransferFailed {
+                    external_transfer_id,
+                    reason,
+                    failed_at,
+                } => {
+                    self.fail_wallet_transfer(
+                        provider_name.clone(),
+                        external_transfer_id,
+                        reason,
+                        failed_at,
+                    )
+                    .await?;
+                }
             }
         }
 
@@ -206,6 +248,76 @@ where
 
         Ok(())
     }
+
+    #[record_error_severity]
+    #[instrument(name = "custody.confirm_wallet_transfer", skip(self))]
+    async fn confirm_wallet_transfer(
+        &self,
+        provider: String,
+        external_transfer_id: String,
+        tx_hash: String,
+        confirmed_at: DateTime<Utc>,
+    ) -> Result<(), CoreCustodyError> {
+        let mut db = self.transfers.begin_op().await?;
+
+        let mut transfer = self
+            .transfers
+            .find_by_external_transfer_id_in_op(&mut db, Some(external_transfer_id))
+            .await?;
+
+        self.authz
+            .audit()
+            .record_system_entry_in_op(
+                &mut db,
+                audit::SystemActor::from(provider),
+                CoreCustodyObject::wallet(transfer.wallet_id),
+                CoreCustodyAction::WALLET_UPDATE,
+            )
+            .await?;
+
+        if transfer.confirm(tx_hash, confirmed_at)?.did_execute() {
+            self.transfers.update_in_op(&mut db, &mut transfer).await?;
+        }
+
+        db.commit().await?;
+
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "custody.fail_wallet_transfer", skip(self))]
+    async fn fail_wallet_transfer(
+        &self,
+        provider: String,
+        external_transfer_id: String,
+        reason: String,
+        failed_at: DateTime<Utc>,
+    ) -> Result<(), CoreCustodyError> {
+        let mut db = self.transfers.begin_op().await?;
+
+        let mut transfer = self
+            .transfers
+            .find_by_external_transfer_id_in_op(&mut db, Some(external_transfer_id))
+            .await?;
+
+        self.authz
+            .audit()
+            .record_system_entry_in_op(
+                &mut db,
+                audit::SystemActor::from(provider),
+                CoreCustodyObject::wallet(transfer.wallet_id),
+                CoreCustodyAction::WALLET_UPDATE,
+            )
+            .await?;
+
+        if transfer.fail(reason, failed_at)?.did_execute() {
+            self.transfers.update_in_op(&mut db, &mut transfer).await?;
+        }
+
+        db.commit().await?;
+
+        Ok(())
+    }
 }
 
 const CUSTODY_INBOX_JOB: job::JobType = job::JobType::new("custody-inbox");
@@ -219,6 +331,8 @@ where
     encryption_config: EncryptionConfig,
     config: CustodyConfig,
     wallets: WalletRepo<E>,
+    transfers: WalletTransferRepo<E>,
+    send_wallet_transfer: JobSpawner<SendWalletTransferConfig>,
     inbox: Inbox,
     clock: ClockHandle,
 }
@@ -253,12 +367,27 @@ where
         let inbox_config = InboxConfig::new(CUSTODY_INBOX_JOB);
         let inbox = Inbox::new(pool, jobs, inbox_config, handler);
 
+        let publisher = CustodyPublisher::new(outbox);
+        let custodians = CustodianRepo::new(pool, clock.clone());
+        let wallets = WalletRepo::new(pool, &publisher, clock.clone());
+        let transfers = WalletTransferRepo::new(pool, &publisher, clock.clone());
+
+        let send_wallet_transfer = jobs.add_initializer(SendWalletTransferJobInitializer::new(
+            &transfers,
+            &wallets,
+            &custodians,
+            &encryption_config,
+            &config,
+        ));
+
         let custody = Self {
             authz: authz.clone(),
-            custodians: CustodianRepo::new(pool, clock.clone()),
+            custodians,
             encryption_config,
             config,
-            wallets: WalletRepo::new(pool, &CustodyPublisher::new(outbox), clock.clone()),
+            wallets,
+            transfers,
+            send_wallet_transfer,
             inbox,
             clock,
         };
@@ -459,6 +588,15 @@ where
         Ok(self.wallets.find_all(ids).await?)
     }
 
+    #[record_error_severity]
+    #[instrument(name = "core_custody.find_all_wallet_transfers", skip(self))]
+    pub async fn find_all_wallet_transfers<T: From<WalletTransfer>>(
+        &self,
+        ids: &[WalletTransferId],
+    ) -> Result<HashMap<WalletTransferId, T>, CoreCustodyError> {
+        Ok(self.transfers.find_all(ids).await?)
+    }
+
     #[record_error_severity]
     #[instrument(name = "core_custody.find_all_custodians", skip(self))]
     pub async fn find_all_custodians<T: From<Custodian>>(
@@ -522,6 +660,75 @@ where
         Ok(wallet)
     }
 
+    #[record_error_severity]
+    #[instrument(name = "custody.initiate_wallet_transfer", skip(self))]
+    pub async fn initiate_wallet_transfer(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        wallet_id: impl Into<WalletId> + std::fmt::Debug,
+        destination_address: impl Into<String> + std::fmt::Debug,
+        amount: Satoshis,
+    ) -> Result<WalletTransfer, CoreCustodyError> {
+        let mut db = self.transfers.begin_op().await?;
+
+        let transfer = self
+            .initiate_wallet_transfer_in_op(&mut db, sub, wallet_id, destination_address, amount)
+            .await?;
+
+        db.commit().await?;
+
+        Ok(transfer)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "custody.initiate_wallet_transfer_in_op", skip(self, db))]
+    pub async fn initiate_wallet_transfer_in_op(
+        &self,
+        db: &mut DbOp<'_>,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        wallet_id: impl Into<WalletId> + std::fmt::Debug,
+        destination_address: impl Into<String> + std::fmt::Debug,
+        amount: Satoshis,
+    ) -> Result<WalletTransfer, CoreCustodyError> {
+        let wallet_id = wallet_id.into();
+        let destination_address = destination_address.into();
+
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCustodyObject::wallet(wallet_id),
+                CoreCustodyAction::WALLET_TRANSFER,
+            )
+            .await?;
+
+        let wallet = self.wallets.find_by_id_in_op(&mut *db, &wallet_id).await?;
+
+        let new_transfer = NewWalletTransfer::builder()
+            .id(WalletTransferId::new())
+            .wallet_id(wallet_id)
+            .custodian_id(wallet.custodian_id)
+            .destination_address(destination_address)
+            .amount(amount)
+            .build()
+            .expect("all fields for new wallet transfer provided");
+
+        let transfer = self.transfers.create_in_op(&mut *db, new_transfer).await?;
+
+        // Sending happens in a job once the transfer is committed, so a
+        // rolled back transaction never leaves funds moved at the custodian.
+        self.send_wallet_transfer
+            .spawn_in_op(
+                db,
+                job::JobId::new(),
+                SendWalletTransferConfig {
+                    wallet_transfer_id: transfer.id,
+                },
+            )
+            .await?;
+
+        Ok(transfer)
+    }
+
     #[record_error_severity]
     #[instrument(name = "custody.handle_webhook", skip(self))]
     pub async fn handle_webhook(
@@ -596,6 +803,8 @@ where
             authz: self.authz.clone(),
             custodians: self.custodians.clone(),
             wallets: self.wallets.clone(),
+            transfers: self.transfers.clone(),
+            send_wallet_transfer: self.send_wallet_transfer.clone(),
             encryption_config: self.encryption_config.clone(),
             config: self.config.clone(),
             inbox: self.inbox.clone(),
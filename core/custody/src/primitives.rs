@@ -7,7 +7,8 @@ use schemars::JsonSchema;
 
 es_entity::entity_id! {
     CustodianId,
-    WalletId
+    WalletId,
+    WalletTransferId
 }
 
 #[cfg(feature = "mock-custodian")]
@@ -29,6 +30,22 @@ pub struct ExternalWallet {
     pub full_response: serde_json::Value,
 }
 
+#[derive(Debug, Clone)]
+pub struct ExternalTransfer {
+    pub external_id: String,
+    pub full_response: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum WalletTransferStatus {
+    Requested,
+    Pending,
+    Confirmed,
+    Failed,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
@@ -59,6 +76,7 @@ impl CoreCustodyAction {
         CoreCustodyAction::Custodian(CustodianAction::CreateWallet);
 
     pub const WALLET_UPDATE: Self = CoreCustodyAction::Wallet(WalletAction::Update);
+    pub const WALLET_TRANSFER: Self = CoreCustodyAction::Wallet(WalletAction::Transfer);
 
     pub fn actions() -> Vec<ActionMapping> {
         use CoreCustodyActionDiscriminants::*;
@@ -129,12 +147,13 @@ impl From<CustodianAction> for CoreCustodyAction {
 #[strum(serialize_all = "kebab-case")]
 pub enum WalletAction {
     Update,
+    Transfer,
 }
 
 impl ActionPermission for WalletAction {
     fn permission_set(&self) -> &'static str {
         match self {
-            Self::Update => PERMISSION_SET_CUSTODY_WRITER,
+            Self::Update | Self::Transfer => PERMISSION_SET_CUSTODY_WRITER,
         }
     }
 }
@@ -3,11 +3,13 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "json-schema")]
 use schemars::JsonSchema;
 
-use super::PublicWallet;
+use super::{PublicWallet, PublicWalletTransfer};
 
 #[derive(Debug, Serialize, Deserialize, strum::AsRefStr)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 #[serde(tag = "type")]
 pub enum CoreCustodyEvent {
     WalletBalanceUpdated { entity: PublicWallet },
+    WalletTransferConfirmed { entity: PublicWalletTransfer },
+    WalletTransferFailed { entity: PublicWalletTransfer },
 }
@@ -1,5 +1,7 @@
 mod event;
 mod wallet;
+mod wallet_transfer;
 
 pub use event::*;
 pub use wallet::*;
+pub use wallet_transfer::*;
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+
+use money::Satoshis;
+
+use crate::{
+    primitives::{WalletId, WalletTransferId, WalletTransferStatus},
+    wallet_transfer::WalletTransfer,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct PublicWalletTransfer {
+    pub id: WalletTransferId,
+    pub wallet_id: WalletId,
+    pub destination_address: String,
+    pub amount: Satoshis,
+    pub status: WalletTransferStatus,
+    pub tx_hash: Option<String>,
+}
+
+impl From<&WalletTransfer> for PublicWalletTransfer {
+    fn from(entity: &WalletTransfer) -> Self {
+        PublicWalletTransfer {
+            id: entity.id,
+            wallet_id: entity.wallet_id,
+            destination_address: entity.destination_address.clone(),
+            amount: entity.amount,
+            status: entity.status(),
+            tx_hash: entity.tx_hash().map(str::to_owned),
+        }
+    }
+}
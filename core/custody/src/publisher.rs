@@ -1,8 +1,9 @@
 use obix::out::{Outbox, OutboxEventMarker};
 
 use crate::{
-    CoreCustodyEvent, PublicWallet,
+    CoreCustodyEvent, PublicWallet, PublicWalletTransfer,
     wallet::{Wallet, WalletEvent, error::WalletError},
+    wallet_transfer::{WalletTransfer, WalletTransferEvent, error::WalletTransferError},
 };
 
 pub struct CustodyPublisher<E>
@@ -53,4 +54,28 @@ where
 
         Ok(())
     }
+
+    pub async fn publish_wallet_transfer_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        entity: &WalletTransfer,
+        new_events: es_entity::LastPersisted<'_, WalletTransferEvent>,
+    ) -> Result<(), WalletTransferError> {
+        use WalletTransferEvent::*;
+        let events = new_events
+            .filter_map(|event| match &event.event {
+                Initialized { .. } | Sent { .. } => None,
+                Confirmed { .. } => Some(CoreCustodyEvent::WalletTransferConfirmed {
+                    entity: PublicWalletTransfer::from(entity),
+                }),
+                Failed { .. } => Some(CoreCustodyEvent::WalletTransferFailed {
+                    entity: PublicWalletTransfer::from(entity),
+                }),
+            })
+            .collect::<Vec<_>>();
+
+        self.outbox.publish_all_persisted(op, events).await?;
+
+        Ok(())
+    }
 }
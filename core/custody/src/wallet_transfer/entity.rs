@@ -0,0 +1,297 @@
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use money::Satoshis;
+
+use crate::primitives::{CustodianId, WalletId, WalletTransferId, WalletTransferStatus};
+
+use super::error::WalletTransferError;
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "WalletTransferId")]
+pub enum WalletTransferEvent {
+    Initialized {
+        id: WalletTransferId,
+        wallet_id: WalletId,
+        custodian_id: CustodianId,
+        destination_address: String,
+        amount: Satoshis,
+    },
+    Sent {
+        external_transfer_id: String,
+        custodian_response: serde_json::Value,
+    },
+    Confirmed {
+        tx_hash: String,
+        confirmed_at: DateTime<Utc>,
+    },
+    Failed {
+        reason: String,
+        failed_at: DateTime<Utc>,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct WalletTransfer {
+    pub id: WalletTransferId,
+    pub wallet_id: WalletId,
+    pub custodian_id: CustodianId,
+    pub external_transfer_id: Option<String>,
+    pub destination_address: String,
+    pub amount: Satoshis,
+
+    events: EntityEvents<WalletTransferEvent>,
+}
+
+impl WalletTransfer {
+    pub fn status(&self) -> WalletTransferStatus {
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|event| match event {
+                WalletTransferEvent::Confirmed { .. } => Some(WalletTransferStatus::Confirmed),
+                WalletTransferEvent::Failed { .. } => Some(WalletTransferStatus::Failed),
+                WalletTransferEvent::Sent { .. } => Some(WalletTransferStatus::Pending),
+                _ => None,
+            })
+            .unwrap_or(WalletTransferStatus::Requested)
+    }
+
+    pub fn tx_hash(&self) -> Option<&str> {
+        self.events.iter_all().find_map(|event| match event {
+            WalletTransferEvent::Confirmed { tx_hash, .. } => Some(tx_hash.as_str()),
+            _ => None,
+        })
+    }
+
+    pub fn record_sent(
+        &mut self,
+        external_transfer_id: String,
+        custodian_response: serde_json::Value,
+    ) -> Result<Idempotent<()>, WalletTransferError> {
+        match self.status() {
+            WalletTransferStatus::Requested => (),
+            WalletTransferStatus::Failed => return Err(WalletTransferError::AlreadyFailed),
+            WalletTransferStatus::Pending | WalletTransferStatus::Confirmed => {
+                return Ok(Idempotent::AlreadyApplied);
+            }
+        }
+
+        self.external_transfer_id = Some(external_transfer_id.clone());
+        self.events.push(WalletTransferEvent::Sent {
+            external_transfer_id,
+            custodian_response,
+        });
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn confirm(
+        &mut self,
+        tx_hash: String,
+        confirmed_at: DateTime<Utc>,
+    ) -> Result<Idempotent<()>, WalletTransferError> {
+        match self.status() {
+            WalletTransferStatus::Confirmed => return Ok(Idempotent::AlreadyApplied),
+            WalletTransferStatus::Failed => return Err(WalletTransferError::AlreadyFailed),
+            WalletTransferStatus::Requested => return Err(WalletTransferError::NotSent),
+            WalletTransferStatus::Pending => (),
+        }
+
+        self.events.push(WalletTransferEvent::Confirmed {
+            tx_hash,
+            confirmed_at,
+        });
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn fail(
+        &mut self,
+        reason: String,
+        failed_at: DateTime<Utc>,
+    ) -> Result<Idempotent<()>, WalletTransferError> {
+        match self.status() {
+            WalletTransferStatus::Failed => return Ok(Idempotent::AlreadyApplied),
+            WalletTransferStatus::Confirmed => return Err(WalletTransferError::AlreadyConfirmed),
+            WalletTransferStatus::Requested | WalletTransferStatus::Pending => (),
+        }
+
+        self.events
+            .push(WalletTransferEvent::Failed { reason, failed_at });
+
+        Ok(Idempotent::Executed(()))
+    }
+}
+
+impl TryFromEvents<WalletTransferEvent> for WalletTransfer {
+    fn try_from_events(events: EntityEvents<WalletTransferEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = WalletTransferBuilder::default();
+        let mut external_transfer_id = None;
+        for event in events.iter_all() {
+            match event {
+                WalletTransferEvent::Initialized {
+                    id,
+                    wallet_id,
+                    custodian_id,
+                    destination_address,
+                    amount,
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .wallet_id(*wallet_id)
+                        .custodian_id(*custodian_id)
+                        .destination_address(destination_address.to_owned())
+                        .amount(*amount);
+                }
+                WalletTransferEvent::Sent {
+                    external_transfer_id: id,
+                    ..
+                } => external_transfer_id = Some(id.to_owned()),
+                _ => (),
+            }
+        }
+        builder = builder.external_transfer_id(external_transfer_id);
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewWalletTransfer {
+    #[builder(setter(into))]
+    pub(super) id: WalletTransferId,
+    #[builder(setter(into))]
+    pub(super) wallet_id: WalletId,
+    #[builder(setter(into))]
+    pub(super) custodian_id: CustodianId,
+    pub(super) destination_address: String,
+    pub(super) amount: Satoshis,
+}
+
+impl NewWalletTransfer {
+    pub fn builder() -> NewWalletTransferBuilder {
+        NewWalletTransferBuilder::default()
+    }
+}
+
+impl IntoEvents<WalletTransferEvent> for NewWalletTransfer {
+    fn into_events(self) -> EntityEvents<WalletTransferEvent> {
+        EntityEvents::init(
+            self.id,
+            [WalletTransferEvent::Initialized {
+                id: self.id,
+                wallet_id: self.wallet_id,
+                custodian_id: self.custodian_id,
+                destination_address: self.destination_address,
+                amount: self.amount,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transfer() -> WalletTransfer {
+        let id = WalletTransferId::new();
+        let new_transfer = NewWalletTransfer::builder()
+            .id(id)
+            .wallet_id(WalletId::new())
+            .custodian_id(CustodianId::new())
+            .destination_address("tb1qdestination".to_string())
+            .amount(Satoshis::from(10_000))
+            .build()
+            .unwrap();
+
+        let mut transfer = WalletTransfer::try_from_events(new_transfer.into_events()).unwrap();
+        let _ = transfer
+            .record_sent(id.to_string(), serde_json::Value::Null)
+            .unwrap();
+        transfer
+    }
+
+    #[test]
+    fn new_transfer_is_requested_until_sent() {
+        let new_transfer = NewWalletTransfer::builder()
+            .id(WalletTransferId::new())
+            .wallet_id(WalletId::new())
+            .custodian_id(CustodianId::new())
+            .destination_address("tb1qdestination".to_string())
+            .amount(Satoshis::from(10_000))
+            .build()
+            .unwrap();
+        let mut transfer = WalletTransfer::try_from_events(new_transfer.into_events()).unwrap();
+        assert_eq!(transfer.status(), WalletTransferStatus::Requested);
+        assert!(matches!(
+            transfer.confirm("txhash".to_string(), Utc::now()),
+            Err(WalletTransferError::NotSent)
+        ));
+
+        assert!(
+            transfer
+                .record_sent("external".to_string(), serde_json::Value::Null)
+                .unwrap()
+                .did_execute()
+        );
+        assert!(
+            transfer
+                .record_sent("external".to_string(), serde_json::Value::Null)
+                .unwrap()
+                .was_already_applied()
+        );
+        assert_eq!(transfer.status(), WalletTransferStatus::Pending);
+        assert_eq!(transfer.external_transfer_id.as_deref(), Some("external"));
+    }
+
+    #[test]
+    fn confirm_is_idempotent() {
+        let mut transfer = transfer();
+
+        assert!(
+            transfer
+                .confirm("txhash".to_string(), Utc::now())
+                .unwrap()
+                .did_execute()
+        );
+        assert!(
+            transfer
+                .confirm("txhash".to_string(), Utc::now())
+                .unwrap()
+                .was_already_applied()
+        );
+        assert_eq!(transfer.status(), WalletTransferStatus::Confirmed);
+        assert_eq!(transfer.tx_hash(), Some("txhash"));
+    }
+
+    #[test]
+    fn cannot_fail_confirmed_transfer() {
+        let mut transfer = transfer();
+        let _ = transfer.confirm("txhash".to_string(), Utc::now()).unwrap();
+
+        assert!(matches!(
+            transfer.fail("Rejected".to_string(), Utc::now()),
+            Err(WalletTransferError::AlreadyConfirmed)
+        ));
+    }
+
+    #[test]
+    fn cannot_confirm_failed_transfer() {
+        let mut transfer = transfer();
+        let _ = transfer.fail("Rejected".to_string(), Utc::now()).unwrap();
+
+        assert_eq!(transfer.status(), WalletTransferStatus::Failed);
+        assert!(matches!(
+            transfer.confirm("txhash".to_string(), Utc::now()),
+            Err(WalletTransferError::AlreadyFailed)
+        ));
+    }
+}
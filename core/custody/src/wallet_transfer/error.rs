@@ -0,0 +1,33 @@
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+#[derive(thiserror::Error, Debug)]
+pub enum WalletTransferError {
+    #[error("WalletTransferError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("WalletTransferError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("WalletTransferError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("WalletTransferError - AlreadyConfirmed")]
+    AlreadyConfirmed,
+    #[error("WalletTransferError - AlreadyFailed")]
+    AlreadyFailed,
+    #[error("WalletTransferError - NotSent")]
+    NotSent,
+}
+
+es_entity::from_es_entity_error!(WalletTransferError);
+
+impl ErrorSeverity for WalletTransferError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::Sqlx(_) => Level::ERROR,
+            Self::EsEntityError(e) => e.severity(),
+            Self::CursorDestructureError(_) => Level::ERROR,
+            Self::AlreadyConfirmed => Level::WARN,
+            Self::AlreadyFailed => Level::WARN,
+            Self::NotSent => Level::WARN,
+        }
+    }
+}
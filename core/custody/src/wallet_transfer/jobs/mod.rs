@@ -0,0 +1 @@
+pub(crate) mod send_wallet_transfer;
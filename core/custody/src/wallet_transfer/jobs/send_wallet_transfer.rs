@@ -0,0 +1,147 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use encryption::EncryptionConfig;
+use job::*;
+use obix::out::OutboxEventMarker;
+use tracing_macros::record_error_severity;
+
+use crate::{
+    CoreCustodyEvent, CustodianRepo, CustodyConfig, WalletRepo,
+    primitives::{WalletTransferId, WalletTransferStatus},
+    wallet_transfer::WalletTransferRepo,
+};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SendWalletTransferConfig {
+    pub wallet_transfer_id: WalletTransferId,
+}
+
+pub const SEND_WALLET_TRANSFER_COMMAND: JobType =
+    JobType::new("command.core-custody.send-wallet-transfer");
+
+pub struct SendWalletTransferJobInitializer<E>
+where
+    E: OutboxEventMarker<CoreCustodyEvent>,
+{
+    transfers: WalletTransferRepo<E>,
+    wallets: WalletRepo<E>,
+    custodians: CustodianRepo,
+    encryption_config: EncryptionConfig,
+    config: CustodyConfig,
+}
+
+impl<E> SendWalletTransferJobInitializer<E>
+where
+    E: OutboxEventMarker<CoreCustodyEvent>,
+{
+    pub fn new(
+        transfers: &WalletTransferRepo<E>,
+        wallets: &WalletRepo<E>,
+        custodians: &CustodianRepo,
+        encryption_config: &EncryptionConfig,
+        config: &CustodyConfig,
+    ) -> Self {
+        Self {
+            transfers: transfers.clone(),
+            wallets: wallets.clone(),
+            custodians: custodians.clone(),
+            encryption_config: encryption_config.clone(),
+            config: config.clone(),
+        }
+    }
+}
+
+impl<E> JobInitializer for SendWalletTransferJobInitializer<E>
+where
+    E: OutboxEventMarker<CoreCustodyEvent>,
+{
+    type Config = SendWalletTransferConfig;
+
+    fn job_type(&self) -> JobType {
+        SEND_WALLET_TRANSFER_COMMAND
+    }
+
+    fn init(
+        &self,
+        job: &Job,
+        _: JobSpawner<Self::Config>,
+    ) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(SendWalletTransferJobRunner::<E> {
+            config: job.config()?,
+            transfers: self.transfers.clone(),
+            wallets: self.wallets.clone(),
+            custodians: self.custodians.clone(),
+            encryption_config: self.encryption_config.clone(),
+            custody_config: self.config.clone(),
+        }))
+    }
+}
+
+pub struct SendWalletTransferJobRunner<E>
+where
+    E: OutboxEventMarker<CoreCustodyEvent>,
+{
+    config: SendWalletTransferConfig,
+    transfers: WalletTransferRepo<E>,
+    wallets: WalletRepo<E>,
+    custodians: CustodianRepo,
+    encryption_config: EncryptionConfig,
+    custody_config: CustodyConfig,
+}
+
+#[async_trait]
+impl<E> JobRunner for SendWalletTransferJobRunner<E>
+where
+    E: OutboxEventMarker<CoreCustodyEvent>,
+{
+    #[record_error_severity]
+    #[tracing::instrument(
+        name = "core_custody.send_wallet_transfer_job.process_command",
+        skip(self, current_job),
+        fields(wallet_transfer_id = %self.config.wallet_transfer_id),
+    )]
+    async fn run(
+        &self,
+        current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut transfer = self
+            .transfers
+            .find_by_id(self.config.wallet_transfer_id)
+            .await?;
+        if transfer.status() != WalletTransferStatus::Requested {
+            return Ok(JobCompletion::Complete);
+        }
+
+        let wallet = self.wallets.find_by_id(transfer.wallet_id).await?;
+        let custodian = self.custodians.find_by_id(wallet.custodian_id).await?;
+        let client = custodian.custodian_client(
+            &self.encryption_config.key,
+            &self.custody_config.custody_providers,
+        )?;
+
+        // The transfer id is the custodian's idempotency key, so a retry after
+        // a crash between sending and recording does not send the funds twice.
+        let external_transfer = client
+            .send_transfer(
+                &wallet.external_wallet_id,
+                &transfer.destination_address,
+                transfer.amount,
+                &transfer.id.to_string(),
+            )
+            .await?;
+
+        let mut op = current_job.begin_op().await?;
+        if transfer
+            .record_sent(
+                external_transfer.external_id,
+                external_transfer.full_response,
+            )?
+            .did_execute()
+        {
+            self.transfers.update_in_op(&mut op, &mut transfer).await?;
+        }
+
+        Ok(JobCompletion::CompleteWithOp(op))
+    }
+}
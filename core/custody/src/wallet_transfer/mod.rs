@@ -0,0 +1,7 @@
+mod entity;
+pub mod error;
+pub(crate) mod jobs;
+mod repo;
+
+pub use entity::{NewWalletTransfer, WalletTransfer, WalletTransferEvent};
+pub use repo::WalletTransferRepo;
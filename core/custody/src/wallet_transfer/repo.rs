@@ -0,0 +1,64 @@
+use es_entity::clock::ClockHandle;
+use sqlx::PgPool;
+
+use es_entity::*;
+use obix::out::OutboxEventMarker;
+
+use crate::primitives::WalletTransferId;
+use crate::{CoreCustodyEvent, publisher::CustodyPublisher};
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo)]
+#[es_repo(
+    entity = "WalletTransfer",
+    err = "WalletTransferError",
+    columns(external_transfer_id(ty = "Option<String>", find_by, create(persist = false))),
+    tbl_prefix = "core",
+    post_persist_hook = "publish_in_op"
+)]
+pub struct WalletTransferRepo<E>
+where
+    E: OutboxEventMarker<CoreCustodyEvent>,
+{
+    pool: PgPool,
+    publisher: CustodyPublisher<E>,
+    clock: ClockHandle,
+}
+
+impl<E> WalletTransferRepo<E>
+where
+    E: OutboxEventMarker<CoreCustodyEvent>,
+{
+    pub fn new(pool: &PgPool, publisher: &CustodyPublisher<E>, clock: ClockHandle) -> Self {
+        Self {
+            pool: pool.clone(),
+            publisher: publisher.clone(),
+            clock,
+        }
+    }
+
+    async fn publish_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        entity: &WalletTransfer,
+        new_events: es_entity::LastPersisted<'_, WalletTransferEvent>,
+    ) -> Result<(), WalletTransferError> {
+        self.publisher
+            .publish_wallet_transfer_in_op(op, entity, new_events)
+            .await
+    }
+}
+
+impl<E> Clone for WalletTransferRepo<E>
+where
+    E: OutboxEventMarker<CoreCustodyEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pool: self.pool.clone(),
+            publisher: self.publisher.clone(),
+            clock: self.clock.clone(),
+        }
+    }
+}
@@ -0,0 +1,140 @@
+mod helpers;
+
+use es_entity::clock::{ArtificialClockConfig, ClockHandle};
+use serde_json::json;
+use uuid::Uuid;
+
+use core_custody::{
+    CoreCustodyEvent, CustodianId, CustodyPublisher, NewWalletTransfer, WalletId, WalletTransfer,
+    WalletTransferId, WalletTransferRepo, WalletTransferStatus,
+};
+use helpers::event;
+use money::Satoshis;
+
+async fn setup() -> anyhow::Result<(
+    WalletTransferRepo<event::DummyEvent>,
+    obix::Outbox<event::DummyEvent>,
+    ClockHandle,
+)> {
+    let pool = helpers::init_pool().await?;
+    let (clock, _time) = ClockHandle::artificial(ArtificialClockConfig::manual());
+
+    let outbox = obix::Outbox::<event::DummyEvent>::init(
+        &pool,
+        obix::MailboxConfig::builder()
+            .clock(clock.clone())
+            .build()?,
+    )
+    .await?;
+
+    let publisher = CustodyPublisher::new(&outbox);
+    let transfers = WalletTransferRepo::new(&pool, &publisher, clock.clone());
+
+    Ok((transfers, outbox, clock))
+}
+
+async fn create_test_transfer(
+    transfers: &WalletTransferRepo<event::DummyEvent>,
+) -> anyhow::Result<WalletTransfer> {
+    let new_transfer = NewWalletTransfer::builder()
+        .id(WalletTransferId::new())
+        .wallet_id(WalletId::new())
+        .custodian_id(CustodianId::new())
+        .destination_address(format!("tb1q{}", Uuid::new_v4().simple()))
+        .amount(Satoshis::from(25_000))
+        .build()
+        .expect("all fields for new wallet transfer provided");
+
+    let mut db = transfers.begin_op().await?;
+    let mut transfer = transfers.create_in_op(&mut db, new_transfer).await?;
+    let _ = transfer.record_sent(format!("external-transfer-{}", Uuid::new_v4()), json!({}))?;
+    transfers.update_in_op(&mut db, &mut transfer).await?;
+    db.commit().await?;
+
+    Ok(transfer)
+}
+
+/// `WalletTransferConfirmed` is published when the custodian reports an outbound transfer as settled.
+///
+/// The snapshot carries the on-chain transaction hash so consumers can reconcile the movement of funds.
+#[tokio::test]
+async fn wallet_transfer_confirmed_publishes_event() -> anyhow::Result<()> {
+    let (transfers, outbox, clock) = setup().await?;
+
+    let transfer = create_test_transfer(&transfers).await?;
+    let external_transfer_id = transfer.external_transfer_id.clone();
+    assert!(external_transfer_id.is_some());
+    let confirmed_at = clock.now();
+
+    let (confirmed_transfer, recorded) = event::expect_event(
+        &outbox,
+        || async {
+            let mut db = transfers.begin_op().await?;
+            let mut transfer = transfers
+                .find_by_external_transfer_id_in_op(&mut db, external_transfer_id)
+                .await?;
+            if transfer
+                .confirm("txhash".to_string(), confirmed_at)?
+                .did_execute()
+            {
+                transfers.update_in_op(&mut db, &mut transfer).await?;
+            }
+            db.commit().await?;
+            Ok::<_, anyhow::Error>(transfer)
+        },
+        |result, e| match e {
+            CoreCustodyEvent::WalletTransferConfirmed { entity } if entity.id == result.id => {
+                Some(entity.clone())
+            }
+            _ => None,
+        },
+    )
+    .await?;
+
+    assert_eq!(recorded.id, confirmed_transfer.id);
+    assert_eq!(recorded.wallet_id, confirmed_transfer.wallet_id);
+    assert_eq!(recorded.amount, confirmed_transfer.amount);
+    assert_eq!(recorded.status, WalletTransferStatus::Confirmed);
+    assert_eq!(recorded.tx_hash.as_deref(), Some("txhash"));
+
+    Ok(())
+}
+
+/// `WalletTransferFailed` is published when the custodian rejects or drops an outbound transfer.
+#[tokio::test]
+async fn wallet_transfer_failed_publishes_event() -> anyhow::Result<()> {
+    let (transfers, outbox, clock) = setup().await?;
+
+    let transfer = create_test_transfer(&transfers).await?;
+    let transfer_id = transfer.id;
+    let failed_at = clock.now();
+
+    let (failed_transfer, recorded) = event::expect_event(
+        &outbox,
+        || async {
+            let mut db = transfers.begin_op().await?;
+            let mut transfer = transfers.find_by_id_in_op(&mut db, transfer_id).await?;
+            if transfer
+                .fail("Rejected".to_string(), failed_at)?
+                .did_execute()
+            {
+                transfers.update_in_op(&mut db, &mut transfer).await?;
+            }
+            db.commit().await?;
+            Ok::<_, anyhow::Error>(transfer)
+        },
+        |result, e| match e {
+            CoreCustodyEvent::WalletTransferFailed { entity } if entity.id == result.id => {
+                Some(entity.clone())
+            }
+            _ => None,
+        },
+    )
+    .await?;
+
+    assert_eq!(recorded.id, failed_transfer.id);
+    assert_eq!(recorded.status, WalletTransferStatus::Failed);
+    assert_eq!(recorded.tx_hash, None);
+
+    Ok(())
+}
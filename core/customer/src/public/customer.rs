@@ -5,7 +5,7 @@ use schemars::JsonSchema;
 
 use crate::{
     entity::Customer,
-    primitives::{CustomerId, KycVerification, PartyId},
+    primitives::{Activity, CustomerId, KycVerification, PartyId},
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,6 +14,8 @@ pub struct PublicCustomer {
     pub id: CustomerId,
     pub party_id: PartyId,
     pub kyc_verification: KycVerification,
+    #[serde(default)]
+    pub activity: Activity,
 }
 
 impl From<&Customer> for PublicCustomer {
@@ -22,6 +24,7 @@ impl From<&Customer> for PublicCustomer {
             id: entity.id,
             party_id: entity.party_id,
             kyc_verification: entity.kyc_verification,
+            activity: entity.activity,
         }
     }
 }
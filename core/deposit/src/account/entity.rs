@@ -1,3 +1,4 @@
+use chrono::Datelike;
 use derive_builder::Builder;
 #[cfg(feature = "json-schema")]
 use schemars::JsonSchema;
@@ -32,6 +33,58 @@ pub enum DepositAccountEvent {
     Closed {
         status: DepositAccountStatus,
     },
+    MarkedDormant {
+        status: DepositAccountStatus,
+    },
+    Reactivated {
+        status: DepositAccountStatus,
+    },
+    Escheated {
+        ledger_tx_id: Option<CalaTransactionId>,
+        amount: UsdCents,
+        status: DepositAccountStatus,
+    },
+    InterestTermsUpdated {
+        terms: DepositAccountInterestTerms,
+    },
+    InterestAccrued {
+        day: chrono::NaiveDate,
+        balance: UsdCents,
+        annual_rate: rust_decimal::Decimal,
+    },
+    InterestCapitalized {
+        ledger_tx_id: CalaTransactionId,
+        day: chrono::NaiveDate,
+        amount: UsdCents,
+    },
+    TermMatured {
+        day: chrono::NaiveDate,
+    },
+    TermBroken {
+        ledger_tx_id: Option<CalaTransactionId>,
+        day: chrono::NaiveDate,
+        penalty: UsdCents,
+        forfeited_interest: UsdCents,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InterestCapitalization {
+    pub ledger_tx_id: CalaTransactionId,
+    pub amount: UsdCents,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TermBreak {
+    pub ledger_tx_id: Option<CalaTransactionId>,
+    pub penalty: UsdCents,
+    pub forfeited_interest: UsdCents,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Escheatment {
+    pub ledger_tx_id: CalaTransactionId,
+    pub amount: UsdCents,
 }
 
 #[derive(EsEntity, Builder)]
@@ -42,6 +95,8 @@ pub struct DepositAccount {
     pub account_ids: DepositAccountLedgerAccountIds,
     pub status: DepositAccountStatus,
     pub public_id: PublicId,
+    #[builder(default)]
+    pub interest_terms: Option<DepositAccountInterestTerms>,
 
     events: EntityEvents<DepositAccountEvent>,
 }
@@ -61,6 +116,24 @@ impl DepositAccount {
         self.status == DepositAccountStatus::Frozen
     }
 
+    pub fn is_dormant(&self) -> bool {
+        self.status == DepositAccountStatus::Dormant
+    }
+
+    pub fn is_escheated(&self) -> bool {
+        self.status == DepositAccountStatus::Escheated
+    }
+
+    fn ensure_not_dormant_or_escheated(&self) -> Result<(), DepositAccountError> {
+        if self.is_dormant() {
+            return Err(DepositAccountError::CannotUpdateDormantAccount(self.id));
+        }
+        if self.is_escheated() {
+            return Err(DepositAccountError::CannotUpdateEscheatedAccount(self.id));
+        }
+        Ok(())
+    }
+
     pub fn update_status_via_holder(
         &mut self,
         status: DepositAccountHolderStatus,
@@ -75,6 +148,7 @@ impl DepositAccount {
         if self.is_frozen() {
             return Err(DepositAccountError::CannotUpdateFrozenAccount(self.id));
         }
+        self.ensure_not_dormant_or_escheated()?;
         self.events
             .push(DepositAccountEvent::AccountHolderStatusUpdated { status });
         self.status = status;
@@ -93,6 +167,7 @@ impl DepositAccount {
         if self.status == DepositAccountStatus::Inactive {
             return Err(DepositAccountError::CannotFreezeInactiveAccount(self.id));
         }
+        self.ensure_not_dormant_or_escheated()?;
         let status = DepositAccountStatus::Frozen;
         self.events.push(DepositAccountEvent::Frozen { status });
         self.status = status;
@@ -131,6 +206,233 @@ impl DepositAccount {
         self.status = status;
         Ok(Idempotent::Executed(()))
     }
+
+    /// Marks an active account dormant after its holder has been inactive for the
+    /// configured period. Dormant accounts still accept incoming funds but block
+    /// outgoing movements until reactivated.
+    pub fn mark_dormant(&mut self) -> Idempotent<()> {
+        if self.status != DepositAccountStatus::Active {
+            return Idempotent::AlreadyApplied;
+        }
+        let status = DepositAccountStatus::Dormant;
+        self.events
+            .push(DepositAccountEvent::MarkedDormant { status });
+        self.status = status;
+        Idempotent::Executed(())
+    }
+
+    pub fn reactivate(&mut self) -> Result<Idempotent<()>, DepositAccountError> {
+        if self.is_closed() {
+            return Err(DepositAccountError::CannotUpdateClosedAccount(self.id));
+        }
+        if self.is_escheated() {
+            return Err(DepositAccountError::CannotUpdateEscheatedAccount(self.id));
+        }
+        if !self.is_dormant() {
+            return Ok(Idempotent::AlreadyApplied);
+        }
+        let status = DepositAccountStatus::Active;
+        self.events
+            .push(DepositAccountEvent::Reactivated { status });
+        self.status = status;
+        Ok(Idempotent::Executed(()))
+    }
+
+    /// Hands the balance of an account that has stayed unclaimed past the statutory
+    /// period over to unclaimed funds. The account and its history are retained.
+    pub fn escheat(&mut self, balance: UsdCents) -> Idempotent<Option<Escheatment>> {
+        if !matches!(
+            self.status,
+            DepositAccountStatus::Active | DepositAccountStatus::Dormant
+        ) {
+            return Idempotent::AlreadyApplied;
+        }
+        let ledger_tx_id = (!balance.is_zero()).then(CalaTransactionId::new);
+        let status = DepositAccountStatus::Escheated;
+        self.events.push(DepositAccountEvent::Escheated {
+            ledger_tx_id,
+            amount: balance,
+            status,
+        });
+        self.status = status;
+        Idempotent::Executed(ledger_tx_id.map(|ledger_tx_id| Escheatment {
+            ledger_tx_id,
+            amount: balance,
+        }))
+    }
+
+    pub fn is_interest_bearing(&self) -> bool {
+        self.interest_terms.is_some()
+    }
+
+    pub fn product(&self) -> DepositProduct {
+        DepositProduct::from(self.interest_terms.as_ref())
+    }
+
+    pub fn is_locked(&self, today: chrono::NaiveDate) -> bool {
+        self.interest_terms
+            .and_then(|terms| terms.maturity_date)
+            .is_some_and(|maturity_date| today < maturity_date)
+    }
+
+    /// The last day interest was accrued, capitalized or settled by the term ending.
+    pub fn last_accrual_day(&self) -> Option<chrono::NaiveDate> {
+        self.events.iter_all().rev().find_map(|event| match event {
+            DepositAccountEvent::InterestAccrued { day, .. }
+            | DepositAccountEvent::InterestCapitalized { day, .. }
+            | DepositAccountEvent::TermMatured { day }
+            | DepositAccountEvent::TermBroken { day, .. } => Some(*day),
+            _ => None,
+        })
+    }
+
+    /// Interest accrued since the last capitalization (or broken term) that has
+    /// not yet been credited to the account.
+    pub fn accrued_interest(&self) -> UsdCents {
+        let accrued: rust_decimal::Decimal = self
+            .events
+            .iter_all()
+            .rev()
+            .take_while(|event| {
+                !matches!(
+                    event,
+                    DepositAccountEvent::InterestCapitalized { .. }
+                        | DepositAccountEvent::TermBroken { .. }
+                )
+            })
+            .filter_map(|event| match event {
+                DepositAccountEvent::InterestAccrued {
+                    balance,
+                    annual_rate,
+                    ..
+                } => Some(
+                    DepositAccountInterestTerms::savings(*annual_rate).daily_interest(*balance),
+                ),
+                _ => None,
+            })
+            .sum();
+        UsdCents::from(u64::try_from(accrued.floor()).expect("accrued interest should fit in u64"))
+    }
+
+    pub fn update_interest_terms(
+        &mut self,
+        terms: DepositAccountInterestTerms,
+        today: chrono::NaiveDate,
+    ) -> Result<Idempotent<()>, DepositAccountError> {
+        if self.interest_terms == Some(terms) {
+            return Ok(Idempotent::AlreadyApplied);
+        }
+        if self.is_closed() {
+            return Err(DepositAccountError::CannotUpdateClosedAccount(self.id));
+        }
+        if self.is_locked(today) {
+            return Err(DepositAccountError::TermDepositNotMatured(self.id));
+        }
+        if terms.annual_rate.is_sign_negative()
+            || terms.annual_rate > rust_decimal::Decimal::ONE_HUNDRED
+            || terms.maturity_date.is_some_and(|date| date <= today)
+            || terms.early_break_penalty_rate.is_some_and(|rate| {
+                !terms.is_term_deposit()
+                    || rate.is_sign_negative()
+                    || rate > rust_decimal::Decimal::ONE_HUNDRED
+            })
+        {
+            return Err(DepositAccountError::InvalidInterestTerms);
+        }
+
+        self.events
+            .push(DepositAccountEvent::InterestTermsUpdated { terms });
+        self.interest_terms = Some(terms);
+        Ok(Idempotent::Executed(()))
+    }
+
+    /// Records the end-of-day interest accrual for `day` and capitalizes the
+    /// accrued interest when `day` is the last day of the month or the maturity date.
+    pub fn accrue_interest(
+        &mut self,
+        day: chrono::NaiveDate,
+        balance: UsdCents,
+    ) -> Idempotent<Option<InterestCapitalization>> {
+        let Some(terms) = self.interest_terms else {
+            return Idempotent::AlreadyApplied;
+        };
+        if self.is_closed() || self.is_frozen() {
+            return Idempotent::AlreadyApplied;
+        }
+        if self.last_accrual_day().is_some_and(|last| day <= last) {
+            return Idempotent::AlreadyApplied;
+        }
+
+        let matured = terms.maturity_date.is_some_and(|date| day >= date);
+        if !matured {
+            self.events.push(DepositAccountEvent::InterestAccrued {
+                day,
+                balance,
+                annual_rate: terms.annual_rate,
+            });
+        }
+
+        let month_end = day
+            .succ_opt()
+            .is_none_or(|next| next.month() != day.month());
+        let mut capitalization = None;
+        if month_end || matured {
+            let amount = self.accrued_interest();
+            if !amount.is_zero() {
+                let ledger_tx_id = CalaTransactionId::new();
+                self.events.push(DepositAccountEvent::InterestCapitalized {
+                    ledger_tx_id,
+                    day,
+                    amount,
+                });
+                capitalization = Some(InterestCapitalization {
+                    ledger_tx_id,
+                    amount,
+                });
+            }
+        }
+
+        if matured {
+            self.events.push(DepositAccountEvent::TermMatured { day });
+            self.interest_terms = None;
+        }
+
+        Idempotent::Executed(capitalization)
+    }
+
+    pub fn break_term(
+        &mut self,
+        today: chrono::NaiveDate,
+        balance: UsdCents,
+    ) -> Result<TermBreak, DepositAccountError> {
+        let terms = match self.interest_terms {
+            Some(terms) if terms.is_term_deposit() => terms,
+            _ => return Err(DepositAccountError::NotATermDeposit(self.id)),
+        };
+        if !self.is_locked(today) {
+            return Err(DepositAccountError::NotATermDeposit(self.id));
+        }
+        if self.is_frozen() {
+            return Err(DepositAccountError::CannotUpdateFrozenAccount(self.id));
+        }
+
+        let penalty = terms.early_break_penalty(balance);
+        let forfeited_interest = self.accrued_interest();
+        let ledger_tx_id = (!penalty.is_zero()).then(CalaTransactionId::new);
+        self.events.push(DepositAccountEvent::TermBroken {
+            ledger_tx_id,
+            day: today,
+            penalty,
+            forfeited_interest,
+        });
+        self.interest_terms = None;
+
+        Ok(TermBreak {
+            ledger_tx_id,
+            penalty,
+            forfeited_interest,
+        })
+    }
 }
 
 impl TryFromEvents<DepositAccountEvent> for DepositAccount {
@@ -162,9 +464,21 @@ impl TryFromEvents<DepositAccountEvent> for DepositAccount {
                 DepositAccountEvent::Unfrozen { status, .. } => {
                     builder = builder.status(*status);
                 }
-                DepositAccountEvent::Closed { status, .. } => {
+                DepositAccountEvent::Closed { status, .. }
+                | DepositAccountEvent::MarkedDormant { status }
+                | DepositAccountEvent::Reactivated { status }
+                | DepositAccountEvent::Escheated { status, .. } => {
                     builder = builder.status(*status);
                 }
+                DepositAccountEvent::InterestTermsUpdated { terms } => {
+                    builder = builder.interest_terms(Some(*terms));
+                }
+                DepositAccountEvent::TermMatured { .. }
+                | DepositAccountEvent::TermBroken { .. } => {
+                    builder = builder.interest_terms(None);
+                }
+                DepositAccountEvent::InterestAccrued { .. }
+                | DepositAccountEvent::InterestCapitalized { .. } => {}
             }
         }
         builder.events(events).build()
@@ -209,8 +523,12 @@ mod tests {
     use es_entity::{EntityEvents, TryFromEvents as _};
     use public_id::PublicId;
 
+    use chrono::NaiveDate;
+    use rust_decimal_macros::dec;
+
     use crate::{
-        DepositAccountHolderId, DepositAccountHolderStatus, DepositAccountId, DepositAccountStatus,
+        DepositAccountHolderId, DepositAccountHolderStatus, DepositAccountId,
+        DepositAccountInterestTerms, DepositAccountStatus, UsdCents,
     };
 
     use super::{
@@ -387,4 +705,218 @@ mod tests {
 
         assert!(account.freeze().unwrap().did_execute());
     }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn interest_accrues_daily_and_capitalizes_at_month_end() {
+        let mut account = DepositAccount::try_from_events(EntityEvents::init(
+            DepositAccountId::new(),
+            initial_events(),
+        ))
+        .unwrap();
+        assert!(
+            account
+                .update_interest_terms(
+                    DepositAccountInterestTerms::savings(dec!(3.65)),
+                    date(2025, 1, 1),
+                )
+                .unwrap()
+                .did_execute()
+        );
+        assert!(account.is_interest_bearing());
+
+        let balance = UsdCents::from(1_000_000);
+        for day in 1..=30 {
+            let res = account.accrue_interest(date(2025, 1, day), balance);
+            assert_eq!(res.unwrap(), None);
+        }
+        assert_eq!(account.accrued_interest(), UsdCents::from(3_000));
+        assert!(
+            account
+                .accrue_interest(date(2025, 1, 30), balance)
+                .was_already_applied()
+        );
+
+        let capitalization = account
+            .accrue_interest(date(2025, 1, 31), balance)
+            .unwrap()
+            .expect("interest should be capitalized at month end");
+        assert_eq!(capitalization.amount, UsdCents::from(3_100));
+        assert_eq!(account.accrued_interest(), UsdCents::ZERO);
+    }
+
+    #[test]
+    fn term_deposit_locks_funds_until_maturity() {
+        let mut account = DepositAccount::try_from_events(EntityEvents::init(
+            DepositAccountId::new(),
+            initial_events(),
+        ))
+        .unwrap();
+        let terms = DepositAccountInterestTerms::term_deposit(dec!(3.65), date(2025, 1, 11), None);
+        assert!(
+            account
+                .update_interest_terms(terms, date(2025, 1, 1))
+                .unwrap()
+                .did_execute()
+        );
+        assert!(account.is_locked(date(2025, 1, 10)));
+        assert!(matches!(
+            account.update_interest_terms(
+                DepositAccountInterestTerms::savings(dec!(1)),
+                date(2025, 1, 5)
+            ),
+            Err(DepositAccountError::TermDepositNotMatured(_))
+        ));
+
+        let balance = UsdCents::from(1_000_000);
+        for day in 1..=10 {
+            let _ = account.accrue_interest(date(2025, 1, day), balance);
+        }
+        let capitalization = account
+            .accrue_interest(date(2025, 1, 11), balance)
+            .unwrap()
+            .expect("interest should be capitalized at maturity");
+        assert_eq!(capitalization.amount, UsdCents::from(1_000));
+        assert!(!account.is_locked(date(2025, 1, 11)));
+        assert!(!account.is_interest_bearing());
+    }
+
+    #[test]
+    fn breaking_term_forfeits_interest_and_charges_penalty() {
+        let mut account = DepositAccount::try_from_events(EntityEvents::init(
+            DepositAccountId::new(),
+            initial_events(),
+        ))
+        .unwrap();
+        let terms =
+            DepositAccountInterestTerms::term_deposit(dec!(3.65), date(2025, 6, 1), Some(dec!(1)));
+        assert!(
+            account
+                .update_interest_terms(terms, date(2025, 1, 1))
+                .unwrap()
+                .did_execute()
+        );
+
+        let balance = UsdCents::from(1_000_000);
+        for day in 1..=5 {
+            let _ = account.accrue_interest(date(2025, 1, day), balance);
+        }
+
+        let term_break = account.break_term(date(2025, 1, 6), balance).unwrap();
+        assert_eq!(term_break.penalty, UsdCents::from(10_000));
+        assert_eq!(term_break.forfeited_interest, UsdCents::from(500));
+        assert!(term_break.ledger_tx_id.is_some());
+        assert_eq!(account.accrued_interest(), UsdCents::ZERO);
+        assert!(!account.is_locked(date(2025, 1, 6)));
+        assert!(matches!(
+            account.break_term(date(2025, 1, 6), balance),
+            Err(DepositAccountError::NotATermDeposit(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_interest_terms() {
+        let mut account = DepositAccount::try_from_events(EntityEvents::init(
+            DepositAccountId::new(),
+            initial_events(),
+        ))
+        .unwrap();
+        assert!(matches!(
+            account.update_interest_terms(
+                DepositAccountInterestTerms::savings(dec!(-1)),
+                date(2025, 1, 1)
+            ),
+            Err(DepositAccountError::InvalidInterestTerms)
+        ));
+        assert!(matches!(
+            account.update_interest_terms(
+                DepositAccountInterestTerms::term_deposit(dec!(2), date(2025, 1, 1), None),
+                date(2025, 1, 1)
+            ),
+            Err(DepositAccountError::InvalidInterestTerms)
+        ));
+        assert!(matches!(
+            account.update_interest_terms(
+                DepositAccountInterestTerms {
+                    annual_rate: dec!(2),
+                    maturity_date: None,
+                    early_break_penalty_rate: Some(dec!(1)),
+                },
+                date(2025, 1, 1)
+            ),
+            Err(DepositAccountError::InvalidInterestTerms)
+        ));
+    }
+
+    #[test]
+    fn dormant_account_blocks_updates_until_reactivated() {
+        let mut account = DepositAccount::try_from_events(EntityEvents::init(
+            DepositAccountId::new(),
+            initial_events(),
+        ))
+        .unwrap();
+
+        assert!(account.mark_dormant().did_execute());
+        assert!(account.mark_dormant().was_already_applied());
+        assert_eq!(account.status, DepositAccountStatus::Dormant);
+        assert!(matches!(
+            account.freeze(),
+            Err(DepositAccountError::CannotUpdateDormantAccount(_))
+        ));
+
+        assert!(account.reactivate().unwrap().did_execute());
+        assert!(account.reactivate().unwrap().was_already_applied());
+        assert_eq!(account.status, DepositAccountStatus::Active);
+    }
+
+    #[test]
+    fn frozen_account_is_not_marked_dormant() {
+        let mut account = DepositAccount::try_from_events(EntityEvents::init(
+            DepositAccountId::new(),
+            initial_events(),
+        ))
+        .unwrap();
+
+        let _ = account.freeze().unwrap();
+        assert!(account.mark_dormant().was_already_applied());
+        assert_eq!(account.status, DepositAccountStatus::Frozen);
+    }
+
+    #[test]
+    fn escheat_dormant_account() {
+        let mut account = DepositAccount::try_from_events(EntityEvents::init(
+            DepositAccountId::new(),
+            initial_events(),
+        ))
+        .unwrap();
+        let _ = account.mark_dormant();
+
+        let escheatment = account
+            .escheat(UsdCents::from(1_000))
+            .unwrap()
+            .expect("non-zero balance is escheated");
+        assert_eq!(escheatment.amount, UsdCents::from(1_000));
+        assert_eq!(account.status, DepositAccountStatus::Escheated);
+
+        assert!(account.escheat(UsdCents::from(1_000)).was_already_applied());
+        assert!(matches!(
+            account.reactivate(),
+            Err(DepositAccountError::CannotUpdateEscheatedAccount(_))
+        ));
+    }
+
+    #[test]
+    fn escheat_empty_account_posts_nothing() {
+        let mut account = DepositAccount::try_from_events(EntityEvents::init(
+            DepositAccountId::new(),
+            initial_events(),
+        ))
+        .unwrap();
+
+        assert!(account.escheat(UsdCents::ZERO).unwrap().is_none());
+        assert_eq!(account.status, DepositAccountStatus::Escheated);
+    }
 }
@@ -16,8 +16,18 @@ pub enum DepositAccountError {
     CannotUpdateClosedAccount(crate::DepositAccountId),
     #[error("DepositAccountError - CannotUpdateFrozenAccount")]
     CannotUpdateFrozenAccount(crate::DepositAccountId),
+    #[error("DepositAccountError - CannotUpdateDormantAccount: {0}")]
+    CannotUpdateDormantAccount(crate::DepositAccountId),
+    #[error("DepositAccountError - CannotUpdateEscheatedAccount: {0}")]
+    CannotUpdateEscheatedAccount(crate::DepositAccountId),
     #[error("DepositAccountError - BalanceIsNotZero")]
     BalanceIsNotZero,
+    #[error("DepositAccountError - InvalidInterestTerms")]
+    InvalidInterestTerms,
+    #[error("DepositAccountError - TermDepositNotMatured: {0}")]
+    TermDepositNotMatured(crate::DepositAccountId),
+    #[error("DepositAccountError - NotATermDeposit: {0}")]
+    NotATermDeposit(crate::DepositAccountId),
 }
 
 es_entity::from_es_entity_error!(DepositAccountError);
@@ -31,7 +41,12 @@ impl ErrorSeverity for DepositAccountError {
             Self::CannotFreezeInactiveAccount(_) => Level::WARN,
             Self::CannotUpdateClosedAccount(_) => Level::WARN,
             Self::CannotUpdateFrozenAccount(_) => Level::WARN,
+            Self::CannotUpdateDormantAccount(_) => Level::WARN,
+            Self::CannotUpdateEscheatedAccount(_) => Level::WARN,
             Self::BalanceIsNotZero => Level::WARN,
+            Self::InvalidInterestTerms => Level::WARN,
+            Self::TermDepositNotMatured(_) => Level::WARN,
+            Self::NotATermDeposit(_) => Level::WARN,
         }
     }
 }
@@ -22,7 +22,13 @@ use super::{entity::*, error::*};
             list_for(by(created_at, id)),
             update(persist = false)
         ),
-        public_id(ty = "PublicId", list_by)
+        public_id(ty = "PublicId", list_by),
+        interest_bearing(
+            ty = "bool",
+            list_for(by(created_at)),
+            create(persist = false),
+            update(accessor = "is_interest_bearing()")
+        )
     ),
     tbl_prefix = "core",
     post_persist_hook = "publish_in_op"
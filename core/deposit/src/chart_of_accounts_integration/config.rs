@@ -24,6 +24,10 @@ pub struct ChartOfAccountsIntegrationConfig {
     pub chart_of_account_frozen_bank_deposit_accounts_parent_code: AccountCode,
     pub chart_of_account_frozen_financial_institution_deposit_accounts_parent_code: AccountCode,
     pub chart_of_account_frozen_non_domiciled_company_deposit_accounts_parent_code: AccountCode,
+    #[serde(default)]
+    pub chart_of_accounts_interest_expense_parent_code: Option<AccountCode>,
+    #[serde(default)]
+    pub chart_of_accounts_unclaimed_funds_parent_code: Option<AccountCode>,
 }
 
 define_internal_config! {
@@ -48,6 +52,11 @@ define_internal_config! {
             CalaAccountSetId,
         pub(crate) frozen_non_domiciled_company_deposit_accounts_parent_account_set_id:
             CalaAccountSetId,
+
+        #[serde(default)]
+        pub(crate) interest_expense_parent_account_set_id: Option<CalaAccountSetId>,
+        #[serde(default)]
+        pub(crate) unclaimed_funds_parent_account_set_id: Option<CalaAccountSetId>,
     }
 
     spec {
@@ -79,6 +88,8 @@ impl ResolvedChartOfAccountsIntegrationConfig {
         let deposit = catalog.deposit();
         let frozen = catalog.frozen();
         let omnibus = catalog.omnibus();
+        let interest_expense = catalog.interest_expense();
+        let unclaimed_funds = catalog.unclaimed_funds();
 
         let omnibus_parent_account_set_id = category_account_set_member_parent_id(
             &config.chart_of_accounts_omnibus_parent_code,
@@ -156,6 +167,22 @@ impl ResolvedChartOfAccountsIntegrationConfig {
                 frozen.non_domiciled_company.account_category,
             )?;
 
+        let interest_expense_parent_account_set_id = config
+            .chart_of_accounts_interest_expense_parent_code
+            .as_ref()
+            .map(|code| {
+                category_account_set_member_parent_id(code, interest_expense.account_category)
+            })
+            .transpose()?;
+
+        let unclaimed_funds_parent_account_set_id = config
+            .chart_of_accounts_unclaimed_funds_parent_code
+            .as_ref()
+            .map(|code| {
+                category_account_set_member_parent_id(code, unclaimed_funds.account_category)
+            })
+            .transpose()?;
+
         Ok(Self {
             config,
 
@@ -172,6 +199,8 @@ impl ResolvedChartOfAccountsIntegrationConfig {
             frozen_bank_deposit_accounts_parent_account_set_id,
             frozen_financial_institution_deposit_accounts_parent_account_set_id,
             frozen_non_domiciled_company_deposit_accounts_parent_account_set_id,
+            interest_expense_parent_account_set_id,
+            unclaimed_funds_parent_account_set_id,
         })
     }
 }
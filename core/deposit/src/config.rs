@@ -0,0 +1,177 @@
+use serde::{Deserialize, Serialize};
+
+use domain_config::{DomainConfigError, define_exposed_config, define_internal_config};
+
+use crate::primitives::{CustomerType, DepositProduct, UsdCents};
+
+define_exposed_config! {
+    /// Transfers at or above this amount (in USD cents) require approval
+    pub struct TransferApprovalThreshold(u64);
+    spec {
+        key: "transfer-approval-threshold";
+    }
+}
+
+/// Maximum total withdrawn from an account per calendar day, ISO week and month.
+/// Windows without a limit are not enforced.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WithdrawalLimits {
+    pub daily: Option<UsdCents>,
+    pub weekly: Option<UsdCents>,
+    pub monthly: Option<UsdCents>,
+}
+
+impl WithdrawalLimits {
+    pub fn is_empty(&self) -> bool {
+        self.daily.is_none() && self.weekly.is_none() && self.monthly.is_none()
+    }
+}
+
+/// Limits applying to accounts of a given product held by a given customer type.
+/// A `None` matcher applies to every product or customer type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WithdrawalLimitRule {
+    pub product: Option<DepositProduct>,
+    pub customer_type: Option<CustomerType>,
+    pub limits: WithdrawalLimits,
+}
+
+impl WithdrawalLimitRule {
+    fn specificity(&self, product: DepositProduct, customer_type: CustomerType) -> Option<u8> {
+        let customer_type_match = match self.customer_type {
+            Some(t) if t == customer_type => 2,
+            Some(_) => return None,
+            None => 0,
+        };
+        let product_match = match self.product {
+            Some(p) if p == product => 1,
+            Some(_) => return None,
+            None => 0,
+        };
+        Some(customer_type_match + product_match)
+    }
+}
+
+define_internal_config! {
+    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+    pub struct WithdrawalLimitsConfig {
+        pub rules: Vec<WithdrawalLimitRule>,
+    }
+
+    spec {
+        key: "withdrawal-limits";
+        validate: |value: &Self| {
+            for (i, rule) in value.rules.iter().enumerate() {
+                if value.rules[..i]
+                    .iter()
+                    .any(|r| r.product == rule.product && r.customer_type == rule.customer_type)
+                {
+                    return Err(DomainConfigError::InvalidState(
+                        "Duplicate withdrawal limit rule".to_string(),
+                    ));
+                }
+            }
+            Ok(())
+        };
+    }
+}
+
+impl WithdrawalLimitsConfig {
+    /// Returns the limits of the most specific matching rule. A customer type match
+    /// outranks a product match.
+    pub fn limits_for(
+        &self,
+        product: DepositProduct,
+        customer_type: CustomerType,
+    ) -> WithdrawalLimits {
+        self.rules
+            .iter()
+            .filter_map(|rule| {
+                rule.specificity(product, customer_type)
+                    .map(|specificity| (specificity, rule.limits))
+            })
+            .max_by_key(|(specificity, _)| *specificity)
+            .map(|(_, limits)| limits)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits(daily: u64) -> WithdrawalLimits {
+        WithdrawalLimits {
+            daily: Some(UsdCents::from(daily)),
+            ..Default::default()
+        }
+    }
+
+    fn rule(
+        product: Option<DepositProduct>,
+        customer_type: Option<CustomerType>,
+        daily: u64,
+    ) -> WithdrawalLimitRule {
+        WithdrawalLimitRule {
+            product,
+            customer_type,
+            limits: limits(daily),
+        }
+    }
+
+    #[test]
+    fn no_rules_means_no_limits() {
+        let config = WithdrawalLimitsConfig::default();
+        assert!(
+            config
+                .limits_for(DepositProduct::Current, CustomerType::Individual)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn most_specific_rule_wins() {
+        let config = WithdrawalLimitsConfig {
+            rules: vec![
+                rule(None, None, 1),
+                rule(Some(DepositProduct::Savings), None, 2),
+                rule(None, Some(CustomerType::PrivateCompany), 3),
+                rule(
+                    Some(DepositProduct::Savings),
+                    Some(CustomerType::PrivateCompany),
+                    4,
+                ),
+            ],
+        };
+
+        assert_eq!(
+            config.limits_for(DepositProduct::Current, CustomerType::Individual),
+            limits(1)
+        );
+        assert_eq!(
+            config.limits_for(DepositProduct::Savings, CustomerType::Individual),
+            limits(2)
+        );
+        assert_eq!(
+            config.limits_for(DepositProduct::Current, CustomerType::PrivateCompany),
+            limits(3)
+        );
+        assert_eq!(
+            config.limits_for(DepositProduct::Savings, CustomerType::PrivateCompany),
+            limits(4)
+        );
+    }
+
+    #[test]
+    fn rejects_duplicate_rules() {
+        use domain_config::ConfigSpec;
+
+        let config = WithdrawalLimitsConfig {
+            rules: vec![
+                rule(None, Some(CustomerType::Bank), 1),
+                rule(None, Some(CustomerType::Bank), 2),
+            ],
+        };
+        assert!(WithdrawalLimitsConfig::validate(&config).is_err());
+    }
+}
@@ -3,15 +3,26 @@ use money::UsdCents;
 pub struct DepositAccountBalance {
     pub settled: UsdCents,
     pub pending: UsdCents,
+    pub held: UsdCents,
 }
 
 impl DepositAccountBalance {
     pub const ZERO: Self = DepositAccountBalance {
         settled: UsdCents::ZERO,
         pending: UsdCents::ZERO,
+        held: UsdCents::ZERO,
     };
 
     pub fn is_zero(&self) -> bool {
-        self.settled.is_zero() && self.pending.is_zero()
+        self.settled.is_zero() && self.pending.is_zero() && self.held.is_zero()
+    }
+
+    /// Settled funds that are not earmarked by an active hold.
+    pub fn available(&self) -> UsdCents {
+        if self.held >= self.settled {
+            UsdCents::ZERO
+        } else {
+            self.settled - self.held
+        }
     }
 }
@@ -18,6 +18,10 @@ pub enum CoreDepositError {
     DepositError(#[from] crate::deposit::error::DepositError),
     #[error("CoreDepositError - WithdrawalError: {0}")]
     WithdrawalError(#[from] crate::withdrawal::error::WithdrawalError),
+    #[error("CoreDepositError - TransferError: {0}")]
+    TransferError(#[from] crate::transfer::error::TransferError),
+    #[error("CoreDepositError - HoldError: {0}")]
+    HoldError(#[from] crate::hold::error::HoldError),
     #[error("CoreDepositError - DepositLedgerError: {0}")]
     DepositLedgerError(#[from] crate::ledger::error::DepositLedgerError),
     #[error("CoreDepositError - GovernanceError: {0}")]
@@ -46,10 +50,20 @@ pub enum CoreDepositError {
     DepositAccountFrozen,
     #[error("CoreDepositError - DepositAccountClosed")]
     DepositAccountClosed,
+    #[error("CoreDepositError - DepositAccountDormant")]
+    DepositAccountDormant,
+    #[error("CoreDepositError - DepositAccountEscheated")]
+    DepositAccountEscheated,
+    #[error("CoreDepositError - TermDepositNotMatured")]
+    TermDepositNotMatured,
     #[error("CoreDepositError - WithdrawalBuilderError: {0}")]
     WithdrawalBuilderError(#[from] super::NewWithdrawalBuilderError),
     #[error("CoreDepositError - DepositBuilderError: {0}")]
     DepositBuilderError(#[from] super::NewDepositBuilderError),
+    #[error("CoreDepositError - TransferBuilderError: {0}")]
+    TransferBuilderError(#[from] super::NewTransferBuilderError),
+    #[error("CoreDepositError - HoldBuilderError: {0}")]
+    HoldBuilderError(#[from] super::NewHoldBuilderError),
     #[error("CoreDepositError - PublicIdError: {0}")]
     PublicIdError(#[from] public_id::PublicIdError),
     #[error("CoreDepositError - DomainConfigError: {0}")]
@@ -69,6 +83,15 @@ impl CoreDepositError {
             ))
         )
     }
+
+    pub fn is_insufficient_available_balance(&self) -> bool {
+        matches!(
+            self,
+            Self::DepositLedgerError(
+                crate::ledger::error::DepositLedgerError::InsufficientAvailableBalance
+            )
+        )
+    }
 }
 
 impl ErrorSeverity for CoreDepositError {
@@ -81,6 +104,8 @@ impl ErrorSeverity for CoreDepositError {
             Self::DepositAccountError(e) => e.severity(),
             Self::DepositError(e) => e.severity(),
             Self::WithdrawalError(e) => e.severity(),
+            Self::TransferError(e) => e.severity(),
+            Self::HoldError(e) => e.severity(),
             Self::DepositLedgerError(e) => e.severity(),
             Self::GovernanceError(e) => e.severity(),
             Self::CustomerError(e) => e.severity(),
@@ -95,8 +120,13 @@ impl ErrorSeverity for CoreDepositError {
             Self::DepositAccountInactive => Level::WARN,
             Self::DepositAccountFrozen => Level::WARN,
             Self::DepositAccountClosed => Level::WARN,
+            Self::DepositAccountDormant => Level::WARN,
+            Self::DepositAccountEscheated => Level::WARN,
+            Self::TermDepositNotMatured => Level::WARN,
             Self::WithdrawalBuilderError(_) => Level::ERROR,
             Self::DepositBuilderError(_) => Level::ERROR,
+            Self::TransferBuilderError(_) => Level::WARN,
+            Self::HoldBuilderError(_) => Level::WARN,
             Self::PublicIdError(e) => e.severity(),
             Self::DomainConfigError(e) => e.severity(),
             Self::CustomerNotVerified => Level::WARN,
@@ -1,10 +1,13 @@
 use audit::AuditSvc;
 use authz::PermissionCheck;
+use core_customer::{CoreCustomerAction, CoreCustomerEvent, CustomerObject};
+use governance::GovernanceEvent;
 use obix::out::OutboxEventMarker;
 use tracing::instrument;
 use tracing_macros::record_error_severity;
 
 use crate::{
+    CoreDeposit,
     account::*,
     deposit::*,
     deposit_account_balance::*,
@@ -20,10 +23,13 @@ use crate::{
 pub struct DepositsForSubject<'a, Perms, E>
 where
     Perms: PermissionCheck,
-    E: OutboxEventMarker<CoreDepositEvent>,
+    E: OutboxEventMarker<CoreDepositEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustomerEvent>,
 {
     account_holder_id: DepositAccountHolderId,
     sub: &'a <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+    deposit: &'a CoreDeposit<Perms, E>,
     accounts: &'a DepositAccountRepo<E>,
     deposits: &'a DepositRepo<E>,
     withdrawals: &'a WithdrawalRepo<E>,
@@ -35,14 +41,17 @@ impl<'a, Perms, E> DepositsForSubject<'a, Perms, E>
 where
     Perms: PermissionCheck,
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
-        From<CoreDepositAction> + From<GovernanceAction>,
+        From<CoreDepositAction> + From<GovernanceAction> + From<CoreCustomerAction>,
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
-        From<CoreDepositObject> + From<GovernanceObject>,
-    E: OutboxEventMarker<CoreDepositEvent>,
+        From<CoreDepositObject> + From<GovernanceObject> + From<CustomerObject>,
+    E: OutboxEventMarker<CoreDepositEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustomerEvent>,
 {
     pub(super) fn new(
         subject: &'a <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         account_holder_id: DepositAccountHolderId,
+        deposit: &'a CoreDeposit<Perms, E>,
         accounts: &'a DepositAccountRepo<E>,
         deposits: &'a DepositRepo<E>,
         withdrawals: &'a WithdrawalRepo<E>,
@@ -52,6 +61,7 @@ where
         Self {
             sub: subject,
             account_holder_id,
+            deposit,
             accounts,
             deposits,
             withdrawals,
@@ -236,6 +246,28 @@ where
         Ok(withdrawal)
     }
 
+    #[record_error_severity]
+    #[instrument(name = "deposit.for_subject.initiate_withdrawal", skip(self))]
+    pub async fn initiate_withdrawal(
+        &self,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        amount: UsdCents,
+        reference: Option<String>,
+    ) -> Result<Withdrawal, CoreDepositError> {
+        let account_id = account_id.into();
+
+        self.ensure_account_access(
+            account_id,
+            CoreDepositObject::all_withdrawals(),
+            CoreDepositAction::WITHDRAWAL_INITIATE,
+        )
+        .await?;
+
+        self.deposit
+            .initiate_withdrawal_without_audit(self.sub, account_id, amount, reference)
+            .await
+    }
+
     async fn ensure_account_access(
         &self,
         account_id: DepositAccountId,
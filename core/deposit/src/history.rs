@@ -7,6 +7,15 @@ pub enum DepositAccountHistoryEntry {
     Deposit(DepositEntry),
     Withdrawal(WithdrawalEntry),
     CancelledWithdrawal(WithdrawalEntry),
+    TransferOut(TransferEntry),
+    TransferIn(TransferEntry),
+    CancelledTransfer(TransferEntry),
+    InterestCapitalized(InterestEntry),
+    EarlyBreakPenalty(EarlyBreakPenaltyEntry),
+    Escheatment(EscheatmentEntry),
+    HoldPlaced(HoldEntry),
+    HoldReleased(HoldEntry),
+    HoldCaptured(HoldEntry),
     Disbursal(DisbursalEntry),
     Payment(PaymentEntry),
     Freeze(FreezeEntry),
@@ -18,24 +27,63 @@ pub struct DepositEntry {
     pub tx_id: CalaTxId,
     pub entry_id: CalaEntryId,
     pub recorded_at: DateTime<Utc>,
+    pub amount: UsdCents,
 }
 
 pub struct WithdrawalEntry {
     pub tx_id: CalaTxId,
     pub entry_id: CalaEntryId,
     pub recorded_at: DateTime<Utc>,
+    pub amount: UsdCents,
+}
+
+pub struct TransferEntry {
+    pub tx_id: CalaTxId,
+    pub entry_id: CalaEntryId,
+    pub recorded_at: DateTime<Utc>,
+    pub amount: UsdCents,
+}
+
+pub struct InterestEntry {
+    pub tx_id: CalaTxId,
+    pub entry_id: CalaEntryId,
+    pub recorded_at: DateTime<Utc>,
+    pub amount: UsdCents,
+}
+
+pub struct EarlyBreakPenaltyEntry {
+    pub tx_id: CalaTxId,
+    pub entry_id: CalaEntryId,
+    pub recorded_at: DateTime<Utc>,
+    pub amount: UsdCents,
+}
+
+pub struct EscheatmentEntry {
+    pub tx_id: CalaTxId,
+    pub entry_id: CalaEntryId,
+    pub recorded_at: DateTime<Utc>,
+    pub amount: UsdCents,
+}
+
+pub struct HoldEntry {
+    pub tx_id: CalaTxId,
+    pub entry_id: CalaEntryId,
+    pub recorded_at: DateTime<Utc>,
+    pub amount: UsdCents,
 }
 
 pub struct DisbursalEntry {
     pub tx_id: CalaTxId,
     pub entry_id: CalaEntryId,
     pub recorded_at: DateTime<Utc>,
+    pub amount: UsdCents,
 }
 
 pub struct PaymentEntry {
     pub tx_id: CalaTxId,
     pub entry_id: CalaEntryId,
     pub recorded_at: DateTime<Utc>,
+    pub amount: UsdCents,
 }
 
 pub struct FreezeEntry {
@@ -61,6 +109,15 @@ pub struct UnknownEntry {
 const RECORD_DEPOSIT: &str = "RECORD_DEPOSIT_CR";
 const INITIATE_WITHDRAW: &str = "INITIATE_WITHDRAW_SETTLED_DR";
 const CANCEL_WITHDRAW: &str = "CANCEL_WITHDRAW_SETTLED_CR";
+const INITIATE_TRANSFER: &str = "INITIATE_TRANSFER_SETTLED_DR";
+const COMPLETE_TRANSFER: &str = "COMPLETE_TRANSFER_SETTLED_CR";
+const DENY_TRANSFER: &str = "DENY_TRANSFER_SETTLED_CR";
+const CAPITALIZE_DEPOSIT_INTEREST: &str = "CAPITALIZE_DEPOSIT_INTEREST_CR";
+const CHARGE_EARLY_BREAK_PENALTY: &str = "CHARGE_EARLY_BREAK_PENALTY_DR";
+const ESCHEAT_DEPOSIT_ACCOUNT: &str = "ESCHEAT_DEPOSIT_ACCOUNT_DR";
+const PLACE_HOLD: &str = "PLACE_HOLD_ENCUMBRANCE_DR";
+const RELEASE_HOLD: &str = "RELEASE_HOLD_ENCUMBRANCE_CR";
+const CAPTURE_HOLD: &str = "CAPTURE_HOLD_SETTLED_DR";
 const CONFIRM_DISBURSAL: &str = "CONFIRM_DISBURSAL_SETTLED_CR";
 const RECORD_PAYMENT_ALLOCATION: &str = "RECORD_PAYMENT_ALLOCATION_DR";
 const CONFIRM_INITIAL_DISBURSAL: &str = "SINGLE_DISBURSAL_RECEIVABLE_CR";
@@ -72,6 +129,16 @@ const UNFREEZE_ACCOUNT_CR: &str = "UNFREEZE_ACCOUNT_CR";
 const IGNORE_INITIATE_WITHDRAW_PENDING: &str = "INITIATE_WITHDRAW_PENDING_CR";
 const IGNORE_CONFIRM_WITHDRAWAL_PENDING: &str = "CONFIRM_WITHDRAW_PENDING_DR";
 const IGNORE_CANCEL_WITHDRAW_PENDING: &str = "CANCEL_WITHDRAW_PENDING_DR";
+const IGNORE_INITIATE_TRANSFER_PENDING: &str = "INITIATE_TRANSFER_PENDING_CR";
+const IGNORE_COMPLETE_TRANSFER_PENDING: &str = "COMPLETE_TRANSFER_PENDING_DR";
+const IGNORE_DENY_TRANSFER_PENDING: &str = "DENY_TRANSFER_PENDING_DR";
+const IGNORE_CAPTURE_HOLD_ENCUMBRANCE: &str = "CAPTURE_HOLD_ENCUMBRANCE_CR";
+const IGNORE_INITIATE_WITHDRAW_ENCUMBRANCE: &str = "INITIATE_WITHDRAW_ENCUMBRANCE_DR";
+const IGNORE_CONFIRM_WITHDRAWAL_ENCUMBRANCE: &str = "CONFIRM_WITHDRAW_ENCUMBRANCE_CR";
+const IGNORE_CANCEL_WITHDRAW_ENCUMBRANCE: &str = "CANCEL_WITHDRAW_ENCUMBRANCE_CR";
+const IGNORE_INITIATE_TRANSFER_ENCUMBRANCE: &str = "INITIATE_TRANSFER_ENCUMBRANCE_DR";
+const IGNORE_COMPLETE_TRANSFER_ENCUMBRANCE: &str = "COMPLETE_TRANSFER_ENCUMBRANCE_CR";
+const IGNORE_DENY_TRANSFER_ENCUMBRANCE: &str = "DENY_TRANSFER_ENCUMBRANCE_CR";
 
 impl From<cala_ledger::entry::Entry> for DepositAccountHistoryEntry {
     fn from(entry: cala_ledger::entry::Entry) -> Self {
@@ -80,31 +147,96 @@ impl From<cala_ledger::entry::Entry> for DepositAccountHistoryEntry {
                 tx_id: entry.values().transaction_id,
                 entry_id: entry.id,
                 recorded_at: entry.created_at(),
+                amount: UsdCents::try_from_usd(entry.values().units)
+                    .expect("deposit amount should convert to cents"),
             }),
             INITIATE_WITHDRAW => DepositAccountHistoryEntry::Withdrawal(WithdrawalEntry {
                 tx_id: entry.values().transaction_id,
                 entry_id: entry.id,
                 recorded_at: entry.created_at(),
+                amount: UsdCents::try_from_usd(entry.values().units)
+                    .expect("withdrawal amount should convert to cents"),
             }),
             CANCEL_WITHDRAW => DepositAccountHistoryEntry::CancelledWithdrawal(WithdrawalEntry {
                 tx_id: entry.values().transaction_id,
                 entry_id: entry.id,
                 recorded_at: entry.created_at(),
+                amount: UsdCents::try_from_usd(entry.values().units)
+                    .expect("withdrawal amount should convert to cents"),
             }),
+            INITIATE_TRANSFER | COMPLETE_TRANSFER | DENY_TRANSFER => {
+                let transfer_entry = TransferEntry {
+                    tx_id: entry.values().transaction_id,
+                    entry_id: entry.id,
+                    recorded_at: entry.created_at(),
+                    amount: UsdCents::try_from_usd(entry.values().units)
+                        .expect("transfer amount should convert to cents"),
+                };
+                match entry.values().entry_type.as_str() {
+                    INITIATE_TRANSFER => DepositAccountHistoryEntry::TransferOut(transfer_entry),
+                    COMPLETE_TRANSFER => DepositAccountHistoryEntry::TransferIn(transfer_entry),
+                    _ => DepositAccountHistoryEntry::CancelledTransfer(transfer_entry),
+                }
+            }
+            CAPITALIZE_DEPOSIT_INTEREST => {
+                DepositAccountHistoryEntry::InterestCapitalized(InterestEntry {
+                    tx_id: entry.values().transaction_id,
+                    entry_id: entry.id,
+                    recorded_at: entry.created_at(),
+                    amount: UsdCents::try_from_usd(entry.values().units)
+                        .expect("interest amount should convert to cents"),
+                })
+            }
+            CHARGE_EARLY_BREAK_PENALTY => {
+                DepositAccountHistoryEntry::EarlyBreakPenalty(EarlyBreakPenaltyEntry {
+                    tx_id: entry.values().transaction_id,
+                    entry_id: entry.id,
+                    recorded_at: entry.created_at(),
+                    amount: UsdCents::try_from_usd(entry.values().units)
+                        .expect("penalty amount should convert to cents"),
+                })
+            }
+            ESCHEAT_DEPOSIT_ACCOUNT => DepositAccountHistoryEntry::Escheatment(EscheatmentEntry {
+                tx_id: entry.values().transaction_id,
+                entry_id: entry.id,
+                recorded_at: entry.created_at(),
+                amount: UsdCents::try_from_usd(entry.values().units)
+                    .expect("escheated amount should convert to cents"),
+            }),
+            PLACE_HOLD | RELEASE_HOLD | CAPTURE_HOLD => {
+                let hold_entry = HoldEntry {
+                    tx_id: entry.values().transaction_id,
+                    entry_id: entry.id,
+                    recorded_at: entry.created_at(),
+                    amount: UsdCents::try_from_usd(entry.values().units)
+                        .expect("hold amount should convert to cents"),
+                };
+                match entry.values().entry_type.as_str() {
+                    PLACE_HOLD => DepositAccountHistoryEntry::HoldPlaced(hold_entry),
+                    RELEASE_HOLD => DepositAccountHistoryEntry::HoldReleased(hold_entry),
+                    _ => DepositAccountHistoryEntry::HoldCaptured(hold_entry),
+                }
+            }
             CONFIRM_DISBURSAL => DepositAccountHistoryEntry::Disbursal(DisbursalEntry {
                 tx_id: entry.values().transaction_id,
                 entry_id: entry.id,
                 recorded_at: entry.created_at(),
+                amount: UsdCents::try_from_usd(entry.values().units)
+                    .expect("disbursal amount should convert to cents"),
             }),
             RECORD_PAYMENT_ALLOCATION => DepositAccountHistoryEntry::Payment(PaymentEntry {
                 tx_id: entry.values().transaction_id,
                 entry_id: entry.id,
                 recorded_at: entry.created_at(),
+                amount: UsdCents::try_from_usd(entry.values().units)
+                    .expect("payment amount should convert to cents"),
             }),
             CONFIRM_INITIAL_DISBURSAL => DepositAccountHistoryEntry::Disbursal(DisbursalEntry {
                 tx_id: entry.values().transaction_id,
                 entry_id: entry.id,
                 recorded_at: entry.created_at(),
+                amount: UsdCents::try_from_usd(entry.values().units)
+                    .expect("disbursal amount should convert to cents"),
             }),
             FREEZE_ACCOUNT_DR | FREEZE_ACCOUNT_CR => {
                 let amount = UsdCents::try_from_usd(entry.values().units)
@@ -130,6 +262,16 @@ impl From<cala_ledger::entry::Entry> for DepositAccountHistoryEntry {
             IGNORE_CONFIRM_WITHDRAWAL_PENDING => DepositAccountHistoryEntry::Ignored,
             IGNORE_INITIATE_WITHDRAW_PENDING => DepositAccountHistoryEntry::Ignored,
             IGNORE_CANCEL_WITHDRAW_PENDING => DepositAccountHistoryEntry::Ignored,
+            IGNORE_INITIATE_TRANSFER_PENDING => DepositAccountHistoryEntry::Ignored,
+            IGNORE_COMPLETE_TRANSFER_PENDING => DepositAccountHistoryEntry::Ignored,
+            IGNORE_DENY_TRANSFER_PENDING => DepositAccountHistoryEntry::Ignored,
+            IGNORE_CAPTURE_HOLD_ENCUMBRANCE => DepositAccountHistoryEntry::Ignored,
+            IGNORE_INITIATE_WITHDRAW_ENCUMBRANCE => DepositAccountHistoryEntry::Ignored,
+            IGNORE_CONFIRM_WITHDRAWAL_ENCUMBRANCE => DepositAccountHistoryEntry::Ignored,
+            IGNORE_CANCEL_WITHDRAW_ENCUMBRANCE => DepositAccountHistoryEntry::Ignored,
+            IGNORE_INITIATE_TRANSFER_ENCUMBRANCE => DepositAccountHistoryEntry::Ignored,
+            IGNORE_COMPLETE_TRANSFER_ENCUMBRANCE => DepositAccountHistoryEntry::Ignored,
+            IGNORE_DENY_TRANSFER_ENCUMBRANCE => DepositAccountHistoryEntry::Ignored,
 
             _ => DepositAccountHistoryEntry::Unknown(UnknownEntry {
                 tx_id: entry.values().transaction_id,
@@ -161,6 +303,30 @@ impl From<&DepositAccountHistoryEntry> for DepositAccountHistoryCursor {
                 entry_id: entry.entry_id,
                 created_at: entry.recorded_at,
             },
+            DepositAccountHistoryEntry::TransferOut(entry)
+            | DepositAccountHistoryEntry::TransferIn(entry)
+            | DepositAccountHistoryEntry::CancelledTransfer(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::InterestCapitalized(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::EarlyBreakPenalty(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::Escheatment(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::HoldPlaced(entry)
+            | DepositAccountHistoryEntry::HoldReleased(entry)
+            | DepositAccountHistoryEntry::HoldCaptured(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
             DepositAccountHistoryEntry::Disbursal(entry) => Self {
                 entry_id: entry.entry_id,
                 created_at: entry.recorded_at,
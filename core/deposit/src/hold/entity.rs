@@ -0,0 +1,284 @@
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::primitives::{CalaTransactionId, DepositAccountId, HoldId, UsdCents};
+
+use super::error::HoldError;
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum HoldStatus {
+    Active,
+    Released,
+    Captured,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "HoldId")]
+pub enum HoldEvent {
+    Initialized {
+        id: HoldId,
+        ledger_tx_id: CalaTransactionId,
+        deposit_account_id: DepositAccountId,
+        amount: UsdCents,
+        reason: String,
+        status: HoldStatus,
+    },
+    Released {
+        ledger_tx_id: CalaTransactionId,
+        status: HoldStatus,
+    },
+    Captured {
+        ledger_tx_id: CalaTransactionId,
+        status: HoldStatus,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct Hold {
+    pub id: HoldId,
+    pub deposit_account_id: DepositAccountId,
+    pub amount: UsdCents,
+    pub reason: String,
+    #[builder(setter(strip_option), default)]
+    pub released_tx_id: Option<CalaTransactionId>,
+    #[builder(setter(strip_option), default)]
+    pub captured_tx_id: Option<CalaTransactionId>,
+
+    events: EntityEvents<HoldEvent>,
+}
+
+impl Hold {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for hold")
+    }
+
+    pub fn status(&self) -> HoldStatus {
+        self.events
+            .iter_all()
+            .rev()
+            .map(|e| match e {
+                HoldEvent::Initialized { status, .. } => *status,
+                HoldEvent::Released { status, .. } => *status,
+                HoldEvent::Captured { status, .. } => *status,
+            })
+            .next()
+            .expect("status should always exist")
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status() == HoldStatus::Active
+    }
+
+    pub fn release(&mut self) -> Result<Idempotent<CalaTransactionId>, HoldError> {
+        idempotency_guard!(self.events.iter_all().rev(), HoldEvent::Released { .. });
+
+        if self.captured_tx_id.is_some() {
+            return Err(HoldError::AlreadyCaptured(self.id));
+        }
+
+        let ledger_tx_id = CalaTransactionId::new();
+        self.events.push(HoldEvent::Released {
+            ledger_tx_id,
+            status: HoldStatus::Released,
+        });
+        self.released_tx_id = Some(ledger_tx_id);
+
+        Ok(Idempotent::Executed(ledger_tx_id))
+    }
+
+    pub fn capture(&mut self) -> Result<Idempotent<CalaTransactionId>, HoldError> {
+        idempotency_guard!(self.events.iter_all().rev(), HoldEvent::Captured { .. });
+
+        if self.released_tx_id.is_some() {
+            return Err(HoldError::AlreadyReleased(self.id));
+        }
+
+        let ledger_tx_id = CalaTransactionId::new();
+        self.events.push(HoldEvent::Captured {
+            ledger_tx_id,
+            status: HoldStatus::Captured,
+        });
+        self.captured_tx_id = Some(ledger_tx_id);
+
+        Ok(Idempotent::Executed(ledger_tx_id))
+    }
+
+    pub fn ledger_tx_ids(&self) -> Vec<CalaTransactionId> {
+        self.events
+            .iter_all()
+            .map(|e| match e {
+                HoldEvent::Initialized { ledger_tx_id, .. } => *ledger_tx_id,
+                HoldEvent::Released { ledger_tx_id, .. } => *ledger_tx_id,
+                HoldEvent::Captured { ledger_tx_id, .. } => *ledger_tx_id,
+            })
+            .collect()
+    }
+}
+
+impl TryFromEvents<HoldEvent> for Hold {
+    fn try_from_events(events: EntityEvents<HoldEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = HoldBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                HoldEvent::Initialized {
+                    id,
+                    deposit_account_id,
+                    amount,
+                    reason,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .deposit_account_id(*deposit_account_id)
+                        .amount(*amount)
+                        .reason(reason.clone())
+                }
+                HoldEvent::Released { ledger_tx_id, .. } => {
+                    builder = builder.released_tx_id(*ledger_tx_id)
+                }
+                HoldEvent::Captured { ledger_tx_id, .. } => {
+                    builder = builder.captured_tx_id(*ledger_tx_id)
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct NewHold {
+    #[builder(setter(into))]
+    pub(super) id: HoldId,
+    #[builder(setter(into))]
+    pub(super) deposit_account_id: DepositAccountId,
+    #[builder(setter(into))]
+    pub(super) amount: UsdCents,
+    #[builder(setter(into))]
+    pub(super) reason: String,
+}
+
+impl NewHold {
+    pub fn builder() -> NewHoldBuilder {
+        NewHoldBuilder::default()
+    }
+}
+
+impl NewHoldBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(amount) = self.amount
+            && amount.is_zero()
+        {
+            return Err("Hold amount cannot be zero".to_string());
+        }
+        if let Some(reason) = self.reason.as_deref()
+            && reason.trim().is_empty()
+        {
+            return Err("Hold reason cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl IntoEvents<HoldEvent> for NewHold {
+    fn into_events(self) -> EntityEvents<HoldEvent> {
+        EntityEvents::init(
+            self.id,
+            [HoldEvent::Initialized {
+                id: self.id,
+                ledger_tx_id: self.id.into(),
+                deposit_account_id: self.deposit_account_id,
+                amount: self.amount,
+                reason: self.reason,
+                status: HoldStatus::Active,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn new_hold() -> Hold {
+        let new_hold = NewHold::builder()
+            .id(HoldId::new())
+            .deposit_account_id(DepositAccountId::new())
+            .amount(UsdCents::ONE)
+            .reason("garnishment order")
+            .build()
+            .unwrap();
+
+        Hold::try_from_events(new_hold.into_events()).unwrap()
+    }
+
+    #[test]
+    fn errors_when_zero_amount_is_passed() {
+        let hold = NewHold::builder()
+            .id(HoldId::new())
+            .deposit_account_id(DepositAccountId::new())
+            .amount(UsdCents::ZERO)
+            .reason("garnishment order")
+            .build();
+
+        assert!(matches!(hold, Err(NewHoldBuilderError::ValidationError(_))));
+    }
+
+    #[test]
+    fn errors_when_reason_is_empty() {
+        let hold = NewHold::builder()
+            .id(HoldId::new())
+            .deposit_account_id(DepositAccountId::new())
+            .amount(UsdCents::ONE)
+            .reason(" ")
+            .build();
+
+        assert!(matches!(hold, Err(NewHoldBuilderError::ValidationError(_))));
+    }
+
+    #[test]
+    fn release_is_idempotent() {
+        let mut hold = new_hold();
+        assert!(hold.is_active());
+
+        let tx_id = hold.release().unwrap().unwrap();
+
+        assert_eq!(hold.status(), HoldStatus::Released);
+        assert_eq!(hold.released_tx_id, Some(tx_id));
+        assert!(hold.release().unwrap().was_already_applied());
+        assert_eq!(hold.ledger_tx_ids().len(), 2);
+    }
+
+    #[test]
+    fn released_hold_cannot_be_captured() {
+        let mut hold = new_hold();
+        let _ = hold.release().unwrap();
+
+        let result = hold.capture();
+
+        assert!(matches!(result, Err(HoldError::AlreadyReleased(_))));
+    }
+
+    #[test]
+    fn captured_hold_cannot_be_released() {
+        let mut hold = new_hold();
+        let tx_id = hold.capture().unwrap().unwrap();
+        assert_eq!(hold.status(), HoldStatus::Captured);
+        assert_eq!(hold.captured_tx_id, Some(tx_id));
+
+        let result = hold.release();
+
+        assert!(matches!(result, Err(HoldError::AlreadyCaptured(_))));
+    }
+}
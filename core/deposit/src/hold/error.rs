@@ -0,0 +1,33 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+use crate::primitives::HoldId;
+
+#[derive(Error, Debug)]
+pub enum HoldError {
+    #[error("HoldError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("HoldError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("HoldError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("HoldError - AlreadyReleased: {0}")]
+    AlreadyReleased(HoldId),
+    #[error("HoldError - AlreadyCaptured: {0}")]
+    AlreadyCaptured(HoldId),
+}
+
+es_entity::from_es_entity_error!(HoldError);
+
+impl ErrorSeverity for HoldError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::Sqlx(_) => Level::ERROR,
+            Self::EsEntityError(e) => e.severity(),
+            Self::CursorDestructureError(_) => Level::ERROR,
+            Self::AlreadyReleased(_) => Level::WARN,
+            Self::AlreadyCaptured(_) => Level::WARN,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod entity;
+pub mod error;
+mod repo;
+
+#[cfg(feature = "json-schema")]
+pub use entity::HoldEvent;
+pub(super) use entity::*;
+pub use entity::{Hold, HoldStatus};
+pub(super) use repo::*;
@@ -0,0 +1,34 @@
+use es_entity::clock::ClockHandle;
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::{DepositAccountId, HoldId};
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "Hold",
+    err = "HoldError",
+    columns(deposit_account_id(
+        ty = "DepositAccountId",
+        list_for(by(created_at)),
+        update(persist = false)
+    )),
+    tbl_prefix = "core"
+)]
+pub struct HoldRepo {
+    #[allow(dead_code)]
+    pool: PgPool,
+    clock: ClockHandle,
+}
+
+impl HoldRepo {
+    pub fn new(pool: &PgPool, clock: ClockHandle) -> Self {
+        Self {
+            pool: pool.clone(),
+            clock,
+        }
+    }
+}
@@ -36,6 +36,10 @@ pub enum DepositLedgerError {
     NonAccountMemberFoundInAccountSet(String),
     #[error("DepositLedgerError - JournalIdMismatch: Account sets have wrong JournalId")]
     JournalIdMismatch,
+    #[error("DepositLedgerError - WithdrawalLimitExceeded")]
+    WithdrawalLimitExceeded,
+    #[error("DepositLedgerError - InsufficientAvailableBalance")]
+    InsufficientAvailableBalance,
 }
 
 impl ErrorSeverity for DepositLedgerError {
@@ -56,6 +60,8 @@ impl ErrorSeverity for DepositLedgerError {
             Self::MismatchedTxMetadata(_) => Level::WARN,
             Self::NonAccountMemberFoundInAccountSet(_) => Level::ERROR,
             Self::JournalIdMismatch => Level::ERROR,
+            Self::WithdrawalLimitExceeded => Level::WARN,
+            Self::InsufficientAvailableBalance => Level::WARN,
         }
     }
 }
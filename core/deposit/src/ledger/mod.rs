@@ -16,22 +16,28 @@ use cala_ledger::{
     CalaLedger, Currency, DebitOrCredit, JournalId, TransactionId,
     account::*,
     account_set::{AccountSetMemberId, NewAccountSet},
+    error::LedgerError,
     tx_template::Params,
-    velocity::{NewVelocityControl, VelocityControlId},
+    velocity::{
+        NewVelocityControl, VelocityControlId,
+        error::{LimitExceededError, VelocityError},
+    },
 };
 
 use crate::{
     DepositAccount, DepositAccountBalance, DepositReversalData, LedgerOmnibusAccountIds,
-    WithdrawalReversalData,
+    WithdrawalLimits, WithdrawalReversalData,
     chart_of_accounts_integration::ResolvedChartOfAccountsIntegrationConfig,
     primitives::{
-        CalaAccountId, CalaAccountSetId, DEPOSIT_ACCOUNT_ENTITY_TYPE, DEPOSIT_ACCOUNT_SET_CATALOG,
-        DepositAccountType, DepositId, UsdCents, WithdrawalId,
+        CalaAccountId, CalaAccountSetId, CalaTransactionId, DEPOSIT_ACCOUNT_ENTITY_TYPE,
+        DEPOSIT_ACCOUNT_SET_CATALOG, DepositAccountId, DepositAccountType, DepositId, HoldId,
+        TransferId, UsdCents, WithdrawalId,
     },
 };
 
 pub(super) use deposit_accounts::*;
 use error::*;
+pub(crate) use velocity::{WithdrawalLimitControls, WithdrawalLimitReversal};
 
 pub const DEPOSITS_VELOCITY_CONTROL_ID: uuid::Uuid =
     uuid::uuid!("00000000-0000-0000-0000-000000000001");
@@ -60,6 +66,8 @@ pub struct DepositLedger {
     deposit_account_sets: DepositAccountSets,
     frozen_deposit_account_sets: DepositAccountSets,
     deposit_omnibus_account_ids: LedgerOmnibusAccountIds,
+    interest_expense_omnibus_account_ids: LedgerOmnibusAccountIds,
+    unclaimed_funds_omnibus_account_ids: LedgerOmnibusAccountIds,
     usd: Currency,
     deposit_control_id: VelocityControlId,
 }
@@ -81,6 +89,15 @@ impl DepositLedger {
         templates::RevertDeposit::init(cala).await?;
         templates::FreezeAccount::init(cala).await?;
         templates::UnfreezeAccount::init(cala).await?;
+        templates::InitiateTransfer::init(cala).await?;
+        templates::CompleteTransfer::init(cala).await?;
+        templates::DenyTransfer::init(cala).await?;
+        templates::CapitalizeDepositInterest::init(cala).await?;
+        templates::ChargeEarlyBreakPenalty::init(cala).await?;
+        templates::PlaceHold::init(cala).await?;
+        templates::ReleaseHold::init(cala).await?;
+        templates::CaptureHold::init(cala).await?;
+        templates::EscheatDepositAccount::init(cala).await?;
 
         let catalog = DEPOSIT_ACCOUNT_SET_CATALOG;
         let deposit = catalog.deposit();
@@ -139,19 +156,27 @@ impl DepositLedger {
         }
 
         let deposit_omnibus_account_ids = omnibus_ids[catalog.omnibus().account_set_ref].clone();
+        let interest_expense_omnibus_account_ids =
+            omnibus_ids[catalog.interest_expense().account_set_ref].clone();
+        let unclaimed_funds_omnibus_account_ids =
+            omnibus_ids[catalog.unclaimed_funds().account_set_ref].clone();
 
         let overdraft_prevention_id = velocity::OverdraftPrevention::init(cala).await?;
 
+        let hold_enforcement_id = velocity::HoldEnforcement::init(cala).await?;
+
         let deposit_control_id = Self::create_deposit_control(cala).await?;
 
-        match cala
-            .velocities()
-            .add_limit_to_control(deposit_control_id, overdraft_prevention_id)
-            .await
-        {
-            Ok(_)
-            | Err(cala_ledger::velocity::error::VelocityError::LimitAlreadyAddedToControl) => {}
-            Err(e) => return Err(e.into()),
+        for limit_id in [overdraft_prevention_id, hold_enforcement_id] {
+            match cala
+                .velocities()
+                .add_limit_to_control(deposit_control_id, limit_id)
+                .await
+            {
+                Ok(_)
+                | Err(cala_ledger::velocity::error::VelocityError::LimitAlreadyAddedToControl) => {}
+                Err(e) => return Err(e.into()),
+            }
         }
         Ok(Self {
             clock,
@@ -174,6 +199,8 @@ impl DepositLedger {
                 non_domiciled_company: frozen_ids[frozen.non_domiciled_company.external_ref],
             },
             deposit_omnibus_account_ids,
+            interest_expense_omnibus_account_ids,
+            unclaimed_funds_omnibus_account_ids,
             deposit_control_id,
             usd: Currency::USD,
         })
@@ -384,6 +411,7 @@ impl DepositLedger {
         entity_id: WithdrawalId,
         amount: UsdCents,
         credit_account_id: impl Into<AccountId>,
+        withdrawal_limits: &WithdrawalLimitControls,
         initiated_by: &impl SystemSubject,
     ) -> Result<(), DepositLedgerError> {
         let tx_id = entity_id.into();
@@ -403,15 +431,87 @@ impl DepositLedger {
             currency: self.usd,
             initiated_by,
             effective_date: self.clock.today(),
+            withdrawal_limits,
         };
 
-        self.cala
+        match self
+            .cala
             .post_transaction_in_op(op, tx_id, templates::INITIATE_WITHDRAW_CODE, params)
-            .await?;
+            .await
+        {
+            Err(LedgerError::VelocityError(VelocityError::Enforcement(LimitExceededError {
+                limit_id,
+                ..
+            }))) if velocity::WithdrawalLimit::is_withdrawal_limit(limit_id) => {
+                return Err(DepositLedgerError::WithdrawalLimitExceeded);
+            }
+            Err(LedgerError::VelocityError(VelocityError::Enforcement(LimitExceededError {
+                limit_id,
+                ..
+            }))) if velocity::HoldEnforcement::is_hold_enforcement(limit_id) => {
+                return Err(DepositLedgerError::InsufficientAvailableBalance);
+            }
+            Err(e) => return Err(e.into()),
+            _ => (),
+        };
 
         Ok(())
     }
 
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit_ledger.attach_withdrawal_limits",
+        skip_all,
+        fields(account_id = tracing::field::Empty)
+    )]
+    pub async fn attach_withdrawal_limits(
+        &self,
+        account_id: impl Into<AccountId>,
+        limits: &WithdrawalLimits,
+    ) -> Result<WithdrawalLimitControls, DepositLedgerError> {
+        let account_id = account_id.into();
+        tracing::Span::current().record("account_id", tracing::field::debug(&account_id));
+
+        let mut controls = WithdrawalLimitControls::default();
+        for (window, amount) in [
+            (velocity::WithdrawalLimitWindow::Daily, limits.daily),
+            (velocity::WithdrawalLimitWindow::Weekly, limits.weekly),
+            (velocity::WithdrawalLimitWindow::Monthly, limits.monthly),
+        ] {
+            let Some(amount) = amount else {
+                continue;
+            };
+            let control_id = velocity::WithdrawalLimit::init(&self.cala, window, amount).await?;
+            match self
+                .cala
+                .velocities()
+                .attach_control_to_account(control_id, account_id, Params::default())
+                .await
+            {
+                Ok(_) => {}
+                Err(e) if velocity::WithdrawalLimit::is_already_attached(&e) => {}
+                Err(e) => return Err(e.into()),
+            }
+            controls.push(window, amount);
+        }
+
+        Ok(controls)
+    }
+
+    async fn withdrawal_limit_reversal(
+        &self,
+        entity_id: WithdrawalId,
+    ) -> Result<WithdrawalLimitReversal, DepositLedgerError> {
+        let initiation = self
+            .cala
+            .transactions()
+            .find_by_id(TransactionId::from(entity_id))
+            .await?;
+        Ok(WithdrawalLimitReversal::of_initiation(
+            initiation.values().metadata.as_ref(),
+        ))
+    }
+
     #[record_error_severity]
     #[instrument(
         name = "deposit_ledger.deny_withdrawal_in_op",
@@ -436,6 +536,7 @@ impl DepositLedger {
             tracing::field::debug(&credit_account_id),
         );
 
+        let withdrawal_limits = self.withdrawal_limit_reversal(entity_id).await?;
         let params = templates::DenyWithdrawParams {
             entity_id: entity_id.into(),
             journal_id: self.journal_id,
@@ -445,6 +546,7 @@ impl DepositLedger {
             currency: self.usd,
             initiated_by,
             effective_date: self.clock.today(),
+            withdrawal_limits: &withdrawal_limits,
         };
 
         self.cala
@@ -697,6 +799,7 @@ impl DepositLedger {
             tracing::field::debug(&credit_account_id),
         );
 
+        let withdrawal_limits = self.withdrawal_limit_reversal(entity_id).await?;
         let params = templates::CancelWithdrawParams {
             entity_id: entity_id.into(),
             journal_id: self.journal_id,
@@ -706,6 +809,7 @@ impl DepositLedger {
             deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
             initiated_by,
             effective_date: self.clock.today(),
+            withdrawal_limits: &withdrawal_limits,
         };
 
         self.cala
@@ -714,6 +818,346 @@ impl DepositLedger {
         Ok(())
     }
 
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit_ledger.initiate_transfer_in_op",
+        skip_all,
+        fields(entity_id = tracing::field::Empty, source_account_id = tracing::field::Empty)
+    )]
+    pub async fn initiate_transfer_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        entity_id: TransferId,
+        amount: UsdCents,
+        source_account_id: impl Into<AccountId>,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), DepositLedgerError> {
+        let tx_id = entity_id.into();
+        tracing::Span::current().record("entity_id", tracing::field::debug(&entity_id));
+        let source_account_id = source_account_id.into();
+        tracing::Span::current().record(
+            "source_account_id",
+            tracing::field::debug(&source_account_id),
+        );
+
+        let params = templates::InitiateTransferParams {
+            entity_id: entity_id.into(),
+            journal_id: self.journal_id,
+            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+            source_account_id,
+            amount: amount.to_usd(),
+            currency: self.usd,
+            initiated_by,
+            effective_date: self.clock.today(),
+        };
+
+        match self
+            .cala
+            .post_transaction_in_op(op, tx_id, templates::INITIATE_TRANSFER_CODE, params)
+            .await
+        {
+            Err(LedgerError::VelocityError(VelocityError::Enforcement(LimitExceededError {
+                limit_id,
+                ..
+            }))) if velocity::HoldEnforcement::is_hold_enforcement(limit_id) => {
+                return Err(DepositLedgerError::InsufficientAvailableBalance);
+            }
+            Err(e) => return Err(e.into()),
+            _ => (),
+        };
+
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit_ledger.complete_transfer_in_op",
+        skip_all,
+        fields(entity_id = tracing::field::Empty, tx_id = tracing::field::Empty, destination_account_id = tracing::field::Empty)
+    )]
+    pub async fn complete_transfer_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        entity_id: TransferId,
+        tx_id: impl Into<TransactionId>,
+        amount: UsdCents,
+        source_account_id: impl Into<AccountId>,
+        destination_account_id: impl Into<AccountId>,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), DepositLedgerError> {
+        let tx_id = tx_id.into();
+        tracing::Span::current().record("entity_id", tracing::field::debug(&entity_id));
+        tracing::Span::current().record("tx_id", tracing::field::debug(&tx_id));
+        let destination_account_id = destination_account_id.into();
+        tracing::Span::current().record(
+            "destination_account_id",
+            tracing::field::debug(&destination_account_id),
+        );
+
+        let params = templates::CompleteTransferParams {
+            entity_id: entity_id.into(),
+            journal_id: self.journal_id,
+            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+            source_account_id: source_account_id.into(),
+            destination_account_id,
+            amount: amount.to_usd(),
+            currency: self.usd,
+            initiated_by,
+            effective_date: self.clock.today(),
+        };
+
+        self.cala
+            .post_transaction_in_op(op, tx_id, templates::COMPLETE_TRANSFER_CODE, params)
+            .await?;
+
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit_ledger.deny_transfer_in_op",
+        skip_all,
+        fields(entity_id = tracing::field::Empty, tx_id = tracing::field::Empty, source_account_id = tracing::field::Empty)
+    )]
+    pub async fn deny_transfer_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        entity_id: TransferId,
+        tx_id: impl Into<TransactionId>,
+        amount: UsdCents,
+        source_account_id: impl Into<AccountId>,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), DepositLedgerError> {
+        let tx_id = tx_id.into();
+        tracing::Span::current().record("entity_id", tracing::field::debug(&entity_id));
+        tracing::Span::current().record("tx_id", tracing::field::debug(&tx_id));
+        let source_account_id = source_account_id.into();
+        tracing::Span::current().record(
+            "source_account_id",
+            tracing::field::debug(&source_account_id),
+        );
+
+        let params = templates::DenyTransferParams {
+            entity_id: entity_id.into(),
+            journal_id: self.journal_id,
+            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+            source_account_id,
+            amount: amount.to_usd(),
+            currency: self.usd,
+            initiated_by,
+            effective_date: self.clock.today(),
+        };
+
+        self.cala
+            .post_transaction_in_op(op, tx_id, templates::DENY_TRANSFER_CODE, params)
+            .await?;
+
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit_ledger.capitalize_interest_in_op",
+        skip_all,
+        fields(account_id = %account_id, amount = %amount)
+    )]
+    pub async fn capitalize_interest_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        tx_id: CalaTransactionId,
+        account_id: DepositAccountId,
+        amount: UsdCents,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), DepositLedgerError> {
+        let params = templates::CapitalizeDepositInterestParams {
+            entity_id: account_id.into(),
+            journal_id: self.journal_id,
+            currency: self.usd,
+            amount: amount.to_usd(),
+            deposit_account_id: account_id.into(),
+            interest_expense_account_id: self.interest_expense_omnibus_account_ids.account_id,
+            initiated_by,
+            effective_date: self.clock.today(),
+        };
+        self.cala
+            .post_transaction_in_op(
+                op,
+                tx_id,
+                templates::CAPITALIZE_DEPOSIT_INTEREST_CODE,
+                params,
+            )
+            .await?;
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit_ledger.escheat_account_in_op",
+        skip_all,
+        fields(account_id = %account_id, amount = %amount)
+    )]
+    pub async fn escheat_account_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        tx_id: CalaTransactionId,
+        account_id: DepositAccountId,
+        amount: UsdCents,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), DepositLedgerError> {
+        let params = templates::EscheatDepositAccountParams {
+            entity_id: account_id.into(),
+            journal_id: self.journal_id,
+            currency: self.usd,
+            amount: amount.to_usd(),
+            deposit_account_id: account_id.into(),
+            unclaimed_funds_account_id: self.unclaimed_funds_omnibus_account_ids.account_id,
+            initiated_by,
+            effective_date: self.clock.today(),
+        };
+        self.cala
+            .post_transaction_in_op(op, tx_id, templates::ESCHEAT_DEPOSIT_ACCOUNT_CODE, params)
+            .await?;
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit_ledger.charge_early_break_penalty_in_op",
+        skip_all,
+        fields(account_id = %account_id, amount = %amount)
+    )]
+    pub async fn charge_early_break_penalty_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        tx_id: CalaTransactionId,
+        account_id: DepositAccountId,
+        amount: UsdCents,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), DepositLedgerError> {
+        let params = templates::ChargeEarlyBreakPenaltyParams {
+            entity_id: account_id.into(),
+            journal_id: self.journal_id,
+            currency: self.usd,
+            amount: amount.to_usd(),
+            deposit_account_id: account_id.into(),
+            interest_expense_account_id: self.interest_expense_omnibus_account_ids.account_id,
+            initiated_by,
+            effective_date: self.clock.today(),
+        };
+        self.cala
+            .post_transaction_in_op(
+                op,
+                tx_id,
+                templates::CHARGE_EARLY_BREAK_PENALTY_CODE,
+                params,
+            )
+            .await?;
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit_ledger.place_hold_in_op",
+        skip_all,
+        fields(hold_id = %hold_id, account_id = %account_id, amount = %amount)
+    )]
+    pub async fn place_hold_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        hold_id: HoldId,
+        account_id: DepositAccountId,
+        amount: UsdCents,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), DepositLedgerError> {
+        let params = templates::PlaceHoldParams {
+            entity_id: hold_id.into(),
+            journal_id: self.journal_id,
+            currency: self.usd,
+            amount: amount.to_usd(),
+            deposit_account_id: account_id.into(),
+            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+            initiated_by,
+            effective_date: self.clock.today(),
+        };
+        match self
+            .cala
+            .post_transaction_in_op(op, hold_id.into(), templates::PLACE_HOLD_CODE, params)
+            .await
+        {
+            Err(LedgerError::VelocityError(VelocityError::Enforcement(LimitExceededError {
+                limit_id,
+                ..
+            }))) if velocity::HoldEnforcement::is_hold_enforcement(limit_id) => {
+                return Err(DepositLedgerError::InsufficientAvailableBalance);
+            }
+            Err(e) => return Err(e.into()),
+            _ => (),
+        };
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit_ledger.release_hold_in_op",
+        skip_all,
+        fields(hold_id = %hold_id, account_id = %account_id, amount = %amount)
+    )]
+    pub async fn release_hold_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        tx_id: CalaTransactionId,
+        hold_id: HoldId,
+        account_id: DepositAccountId,
+        amount: UsdCents,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), DepositLedgerError> {
+        let params = templates::ReleaseHoldParams {
+            entity_id: hold_id.into(),
+            journal_id: self.journal_id,
+            currency: self.usd,
+            amount: amount.to_usd(),
+            deposit_account_id: account_id.into(),
+            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+            initiated_by,
+            effective_date: self.clock.today(),
+        };
+        self.cala
+            .post_transaction_in_op(op, tx_id, templates::RELEASE_HOLD_CODE, params)
+            .await?;
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "deposit_ledger.capture_hold_in_op",
+        skip_all,
+        fields(hold_id = %hold_id, account_id = %account_id, amount = %amount)
+    )]
+    pub async fn capture_hold_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        tx_id: CalaTransactionId,
+        hold_id: HoldId,
+        account_id: DepositAccountId,
+        amount: UsdCents,
+        initiated_by: &impl SystemSubject,
+    ) -> Result<(), DepositLedgerError> {
+        let params = templates::CaptureHoldParams {
+            entity_id: hold_id.into(),
+            journal_id: self.journal_id,
+            currency: self.usd,
+            amount: amount.to_usd(),
+            deposit_account_id: account_id.into(),
+            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+            initiated_by,
+            effective_date: self.clock.today(),
+        };
+        self.cala
+            .post_transaction_in_op(op, tx_id, templates::CAPTURE_HOLD_CODE, params)
+            .await?;
+        Ok(())
+    }
+
     #[record_error_severity]
     #[instrument(name = "deposit_ledger.balance", skip_all, fields(account_id = tracing::field::Empty))]
     pub async fn balance(
@@ -731,6 +1175,8 @@ impl DepositLedger {
             Ok(balances) => Ok(DepositAccountBalance {
                 settled: UsdCents::try_from_usd(balances.settled())?,
                 pending: UsdCents::try_from_usd(balances.pending())?,
+                // pending outflows are mirrored on the encumbrance layer
+                held: UsdCents::try_from_usd(-(balances.encumbrance() + balances.pending()))?,
             }),
             Err(cala_ledger::balance::error::BalanceError::NotFound(..)) => {
                 Ok(DepositAccountBalance::ZERO)
@@ -739,6 +1185,29 @@ impl DepositLedger {
         }
     }
 
+    /// Settled balance as of the end of `date`, built from effective-date balances.
+    #[record_error_severity]
+    #[instrument(name = "deposit_ledger.settled_balance_as_of", skip_all, fields(account_id = tracing::field::Empty))]
+    pub async fn settled_balance_as_of(
+        &self,
+        account_id: impl Into<AccountId>,
+        date: chrono::NaiveDate,
+    ) -> Result<UsdCents, DepositLedgerError> {
+        let account_id = account_id.into();
+        tracing::Span::current().record("account_id", tracing::field::debug(&account_id));
+        match self
+            .cala
+            .balances()
+            .effective()
+            .find_cumulative(self.journal_id, account_id, self.usd, date)
+            .await
+        {
+            Ok(balance) => Ok(UsdCents::try_from_usd(balance.settled())?),
+            Err(cala_ledger::balance::error::BalanceError::NotFound(..)) => Ok(UsdCents::ZERO),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     #[record_error_severity]
     #[instrument(name = "deposit_ledger.create_deposit_accounts_in_op", skip_all)]
     pub async fn create_deposit_accounts_in_op(
@@ -960,6 +1429,8 @@ impl DepositLedger {
             frozen_bank_deposit_accounts_parent_account_set_id,
             frozen_financial_institution_deposit_accounts_parent_account_set_id,
             frozen_non_domiciled_company_deposit_accounts_parent_account_set_id,
+            interest_expense_parent_account_set_id,
+            unclaimed_funds_parent_account_set_id,
         } = &new_integration_config;
 
         self.attach_charts_account_set_in_op(
@@ -1080,6 +1551,28 @@ impl DepositLedger {
         )
         .await?;
 
+        if let Some(parent_account_set_id) = interest_expense_parent_account_set_id {
+            self.attach_charts_account_set_in_op(
+                op,
+                self.interest_expense_omnibus_account_ids.account_set_id,
+                *parent_account_set_id,
+                old_integration_config
+                    .and_then(|config| config.interest_expense_parent_account_set_id),
+            )
+            .await?;
+        }
+
+        if let Some(parent_account_set_id) = unclaimed_funds_parent_account_set_id {
+            self.attach_charts_account_set_in_op(
+                op,
+                self.unclaimed_funds_omnibus_account_ids.account_set_id,
+                *parent_account_set_id,
+                old_integration_config
+                    .and_then(|config| config.unclaimed_funds_parent_account_set_id),
+            )
+            .await?;
+        }
+
         Ok(())
     }
 }
@@ -7,12 +7,15 @@ use cala_ledger::{
     *,
 };
 
-use crate::{ledger::error::*, primitives::WITHDRAWAL_TRANSACTION_ENTITY_TYPE};
+use crate::{
+    ledger::{error::*, velocity::WithdrawalLimitReversal},
+    primitives::WITHDRAWAL_TRANSACTION_ENTITY_TYPE,
+};
 
 pub const CANCEL_WITHDRAW_CODE: &str = "CANCEL_WITHDRAW";
 
 #[derive(Debug)]
-pub struct CancelWithdrawParams<S: std::fmt::Display> {
+pub struct CancelWithdrawParams<'a, S: std::fmt::Display> {
     pub entity_id: uuid::Uuid,
     pub journal_id: JournalId,
     pub currency: Currency,
@@ -21,9 +24,10 @@ pub struct CancelWithdrawParams<S: std::fmt::Display> {
     pub credit_account_id: AccountId,
     pub initiated_by: S,
     pub effective_date: chrono::NaiveDate,
+    pub withdrawal_limits: &'a WithdrawalLimitReversal,
 }
 
-impl<S: std::fmt::Display> CancelWithdrawParams<S> {
+impl<S: std::fmt::Display> CancelWithdrawParams<'_, S> {
     pub fn defs() -> Vec<NewParamDefinition> {
         vec![
             NewParamDefinition::builder()
@@ -65,7 +69,7 @@ impl<S: std::fmt::Display> CancelWithdrawParams<S> {
     }
 }
 
-impl<S: std::fmt::Display> From<CancelWithdrawParams<S>> for Params {
+impl<S: std::fmt::Display> From<CancelWithdrawParams<'_, S>> for Params {
     fn from(
         CancelWithdrawParams {
             entity_id,
@@ -76,7 +80,8 @@ impl<S: std::fmt::Display> From<CancelWithdrawParams<S>> for Params {
             credit_account_id,
             initiated_by,
             effective_date,
-        }: CancelWithdrawParams<S>,
+            withdrawal_limits,
+        }: CancelWithdrawParams<'_, S>,
     ) -> Self {
         let mut params = Self::default();
         params.insert("journal_id", journal_id);
@@ -89,13 +94,16 @@ impl<S: std::fmt::Display> From<CancelWithdrawParams<S>> for Params {
             WITHDRAWAL_TRANSACTION_ENTITY_TYPE,
             entity_id,
         );
-        params.insert(
-            "meta",
-            serde_json::json!({
-                "entity_ref": entity_ref,
-                "initiated_by": initiated_by.to_string(),
-            }),
-        );
+        let mut meta = serde_json::json!({
+            "entity_ref": entity_ref,
+            "initiated_by": initiated_by.to_string(),
+        });
+        if let Some((key, value)) = withdrawal_limits.tx_metadata() {
+            meta.as_object_mut()
+                .expect("meta should be an object")
+                .insert(key.to_string(), value);
+        }
+        params.insert("meta", meta);
 
         params
     }
@@ -133,6 +141,24 @@ impl CancelWithdraw {
                 .units("params.amount")
                 .build()
                 .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'CANCEL_WITHDRAW_ENCUMBRANCE_DR'")
+                .currency("params.currency")
+                .account_id("params.deposit_omnibus_account_id")
+                .direction("DEBIT")
+                .layer("ENCUMBRANCE")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'CANCEL_WITHDRAW_ENCUMBRANCE_CR'")
+                .currency("params.currency")
+                .account_id("params.credit_account_id")
+                .direction("CREDIT")
+                .layer("ENCUMBRANCE")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
             NewTxTemplateEntry::builder()
                 .entry_type("'CANCEL_WITHDRAW_SETTLED_DR'")
                 .currency("params.currency")
@@ -154,6 +154,24 @@ impl ConfirmWithdraw {
                 .units("params.amount")
                 .build()
                 .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'CONFIRM_WITHDRAW_ENCUMBRANCE_DR'")
+                .currency("params.currency")
+                .account_id("params.deposit_omnibus_account_id")
+                .direction("DEBIT")
+                .layer("ENCUMBRANCE")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'CONFIRM_WITHDRAW_ENCUMBRANCE_CR'")
+                .currency("params.currency")
+                .account_id("params.credit_account_id")
+                .direction("CREDIT")
+                .layer("ENCUMBRANCE")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
         ];
 
         let params = ConfirmWithdrawParams::<String>::defs();
@@ -7,14 +7,14 @@ use cala_ledger::{
 };
 
 use crate::{
-    ledger::error::*,
+    ledger::{error::*, velocity::WithdrawalLimitReversal},
     primitives::{CalaAccountId, WITHDRAWAL_TRANSACTION_ENTITY_TYPE},
 };
 
 pub const DENY_WITHDRAW_CODE: &str = "DENY_WITHDRAW";
 
 #[derive(Debug)]
-pub struct DenyWithdrawParams<S: std::fmt::Display> {
+pub struct DenyWithdrawParams<'a, S: std::fmt::Display> {
     pub entity_id: uuid::Uuid,
     pub journal_id: JournalId,
     pub deposit_omnibus_account_id: CalaAccountId,
@@ -23,9 +23,10 @@ pub struct DenyWithdrawParams<S: std::fmt::Display> {
     pub currency: Currency,
     pub initiated_by: S,
     pub effective_date: chrono::NaiveDate,
+    pub withdrawal_limits: &'a WithdrawalLimitReversal,
 }
 
-impl<S: std::fmt::Display> DenyWithdrawParams<S> {
+impl<S: std::fmt::Display> DenyWithdrawParams<'_, S> {
     pub fn defs() -> Vec<NewParamDefinition> {
         vec![
             NewParamDefinition::builder()
@@ -67,7 +68,7 @@ impl<S: std::fmt::Display> DenyWithdrawParams<S> {
     }
 }
 
-impl<S: std::fmt::Display> From<DenyWithdrawParams<S>> for Params {
+impl<S: std::fmt::Display> From<DenyWithdrawParams<'_, S>> for Params {
     fn from(
         DenyWithdrawParams {
             entity_id,
@@ -78,7 +79,8 @@ impl<S: std::fmt::Display> From<DenyWithdrawParams<S>> for Params {
             currency,
             initiated_by,
             effective_date,
-        }: DenyWithdrawParams<S>,
+            withdrawal_limits,
+        }: DenyWithdrawParams<'_, S>,
     ) -> Self {
         let mut params = Self::default();
 
@@ -92,13 +94,16 @@ impl<S: std::fmt::Display> From<DenyWithdrawParams<S>> for Params {
             WITHDRAWAL_TRANSACTION_ENTITY_TYPE,
             entity_id,
         );
-        params.insert(
-            "meta",
-            serde_json::json!({
-                "entity_ref": entity_ref,
-                "initiated_by": initiated_by.to_string(),
-            }),
-        );
+        let mut meta = serde_json::json!({
+            "entity_ref": entity_ref,
+            "initiated_by": initiated_by.to_string(),
+        });
+        if let Some((key, value)) = withdrawal_limits.tx_metadata() {
+            meta.as_object_mut()
+                .expect("meta should be an object")
+                .insert(key.to_string(), value);
+        }
+        params.insert("meta", meta);
 
         params
     }
@@ -135,6 +140,24 @@ impl DenyWithdraw {
                 .units("params.amount")
                 .build()
                 .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'DENY_WITHDRAW_ENCUMBRANCE_DR'")
+                .currency("params.currency")
+                .account_id("params.deposit_omnibus_account_id")
+                .direction("DEBIT")
+                .layer("ENCUMBRANCE")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'DENY_WITHDRAW_ENCUMBRANCE_CR'")
+                .currency("params.currency")
+                .account_id("params.credit_account_id")
+                .direction("CREDIT")
+                .layer("ENCUMBRANCE")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
             NewTxTemplateEntry::builder()
                 .entry_type("'DENY_WITHDRAW_SETTLED_DR'")
                 .currency("params.currency")
@@ -10,7 +10,7 @@ use cala_ledger::{
     },
 };
 
-use crate::ledger::error::DepositLedgerError;
+use crate::ledger::{error::DepositLedgerError, velocity::HOLD_EXEMPT_METADATA_KEY};
 
 pub const FREEZE_ACCOUNT_CODE: &str = "FREEZE_ACCOUNT";
 
@@ -90,6 +90,7 @@ impl<S: std::fmt::Display> From<FreezeAccountParams<S>> for Params {
             "meta",
             serde_json::json!({
                 "initiated_by": initiated_by.to_string(),
+                HOLD_EXEMPT_METADATA_KEY: true,
             }),
         );
         params
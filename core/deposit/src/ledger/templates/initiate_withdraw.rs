@@ -8,14 +8,14 @@ use cala_ledger::{
 };
 
 use crate::{
-    ledger::error::*,
+    ledger::{error::*, velocity::WithdrawalLimitControls},
     primitives::{CalaAccountId, WITHDRAWAL_TRANSACTION_ENTITY_TYPE},
 };
 
 pub const INITIATE_WITHDRAW_CODE: &str = "INITIATE_WITHDRAW";
 
 #[derive(Debug)]
-pub struct InitiateWithdrawParams<S: std::fmt::Display> {
+pub struct InitiateWithdrawParams<'a, S: std::fmt::Display> {
     pub entity_id: uuid::Uuid,
     pub journal_id: JournalId,
     pub deposit_omnibus_account_id: CalaAccountId,
@@ -24,9 +24,10 @@ pub struct InitiateWithdrawParams<S: std::fmt::Display> {
     pub currency: Currency,
     pub initiated_by: S,
     pub effective_date: chrono::NaiveDate,
+    pub withdrawal_limits: &'a WithdrawalLimitControls,
 }
 
-impl<S: std::fmt::Display> InitiateWithdrawParams<S> {
+impl<S: std::fmt::Display> InitiateWithdrawParams<'_, S> {
     pub fn defs() -> Vec<NewParamDefinition> {
         vec![
             NewParamDefinition::builder()
@@ -68,7 +69,7 @@ impl<S: std::fmt::Display> InitiateWithdrawParams<S> {
     }
 }
 
-impl<S: std::fmt::Display> From<InitiateWithdrawParams<S>> for Params {
+impl<S: std::fmt::Display> From<InitiateWithdrawParams<'_, S>> for Params {
     fn from(
         InitiateWithdrawParams {
             entity_id,
@@ -79,7 +80,8 @@ impl<S: std::fmt::Display> From<InitiateWithdrawParams<S>> for Params {
             currency,
             initiated_by,
             effective_date,
-        }: InitiateWithdrawParams<S>,
+            withdrawal_limits,
+        }: InitiateWithdrawParams<'_, S>,
     ) -> Self {
         let mut params = Self::default();
 
@@ -93,13 +95,16 @@ impl<S: std::fmt::Display> From<InitiateWithdrawParams<S>> for Params {
             WITHDRAWAL_TRANSACTION_ENTITY_TYPE,
             entity_id,
         );
-        params.insert(
-            "meta",
-            serde_json::json!({
-                "entity_ref": entity_ref,
-                "initiated_by": initiated_by.to_string(),
-            }),
-        );
+        let mut meta = serde_json::json!({
+            "entity_ref": entity_ref,
+            "initiated_by": initiated_by.to_string(),
+        });
+        if let Some((key, value)) = withdrawal_limits.tx_metadata(effective_date) {
+            meta.as_object_mut()
+                .expect("meta should be an object")
+                .insert(key.to_string(), value);
+        }
+        params.insert("meta", meta);
 
         params
     }
@@ -155,6 +160,24 @@ impl InitiateWithdraw {
                 .units("params.amount")
                 .build()
                 .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'INITIATE_WITHDRAW_ENCUMBRANCE_CR'")
+                .currency("params.currency")
+                .account_id("params.deposit_omnibus_account_id")
+                .direction("CREDIT")
+                .layer("ENCUMBRANCE")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'INITIATE_WITHDRAW_ENCUMBRANCE_DR'")
+                .currency("params.currency")
+                .account_id("params.credit_account_id")
+                .direction("DEBIT")
+                .layer("ENCUMBRANCE")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
         ];
 
         let params = InitiateWithdrawParams::<String>::defs();
@@ -1,19 +1,37 @@
 mod cancel_withdraw;
+mod capitalize_interest;
+mod capture_hold;
+mod charge_early_break_penalty;
+mod complete_transfer;
 mod confirm_withdraw;
+mod deny_transfer;
 mod deny_withdraw;
+mod escheat_deposit_account;
 mod freeze_account;
+mod initiate_transfer;
 mod initiate_withdraw;
+mod place_hold;
 mod record_deposit;
+mod release_hold;
 mod revert_deposit;
 mod revert_withdraw;
 mod unfreeze_account;
 
 pub use cancel_withdraw::*;
+pub use capitalize_interest::*;
+pub use capture_hold::*;
+pub use charge_early_break_penalty::*;
+pub use complete_transfer::*;
 pub use confirm_withdraw::*;
+pub use deny_transfer::*;
 pub use deny_withdraw::*;
+pub use escheat_deposit_account::*;
 pub use freeze_account::*;
+pub use initiate_transfer::*;
 pub use initiate_withdraw::*;
+pub use place_hold::*;
 pub use record_deposit::*;
+pub use release_hold::*;
 pub use revert_deposit::*;
 pub use revert_withdraw::*;
 pub use unfreeze_account::*;
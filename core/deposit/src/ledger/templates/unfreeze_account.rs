@@ -10,7 +10,7 @@ use cala_ledger::{
     },
 };
 
-use crate::ledger::error::DepositLedgerError;
+use crate::ledger::{error::DepositLedgerError, velocity::HOLD_EXEMPT_METADATA_KEY};
 
 pub const UNFREEZE_ACCOUNT_CODE: &str = "UNFREEZE_ACCOUNT";
 
@@ -90,6 +90,7 @@ impl<S: std::fmt::Display> From<UnfreezeAccountParams<S>> for Params {
             "meta",
             serde_json::json!({
                 "initiated_by": initiated_by.to_string(),
+                HOLD_EXEMPT_METADATA_KEY: true,
             }),
         );
         params
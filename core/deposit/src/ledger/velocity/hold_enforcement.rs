@@ -0,0 +1,64 @@
+use tracing::instrument;
+use tracing_macros::record_error_severity;
+
+use cala_ledger::{velocity::*, *};
+
+use crate::ledger::error::*;
+
+pub const HOLD_EXEMPT_METADATA_KEY: &str = "hold_exempt";
+
+/// Rejects debits that would dip into funds earmarked by holds.
+///
+/// Holds are booked on the encumbrance layer and every pending outflow is
+/// mirrored there with the opposite direction, so the available balance on
+/// the encumbrance layer is the settled balance less active holds. Freezing
+/// and unfreezing move the whole balance out and back again, so they are
+/// exempted via their metadata to keep accounts with holds freezable.
+pub struct HoldEnforcement;
+
+const HOLD_ENFORCEMENT_ID: uuid::Uuid = uuid::uuid!("00000000-0000-0000-0000-000000000002");
+
+impl HoldEnforcement {
+    pub fn is_hold_enforcement(limit_id: VelocityLimitId) -> bool {
+        uuid::Uuid::from(limit_id) == HOLD_ENFORCEMENT_ID
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "ledger.hold_enforcement.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<VelocityLimitId, DepositLedgerError> {
+        let limit = NewVelocityLimit::builder()
+            .id(HOLD_ENFORCEMENT_ID)
+            .name("Hold Enforcement")
+            .description("Prevent withdrawals and transfers of held funds")
+            .window(vec![])
+            .condition(format!(
+                r#"
+                !(has(context.vars.transaction.metadata) &&
+                  has(context.vars.transaction.metadata.{HOLD_EXEMPT_METADATA_KEY}))
+            "#
+            ))
+            .limit(
+                NewLimit::builder()
+                    .balance(vec![
+                        NewBalanceLimit::builder()
+                            .layer("ENCUMBRANCE")
+                            .amount("decimal('0.0')")
+                            .enforcement_direction("DEBIT")
+                            .build()
+                            .expect("balance limit"),
+                    ])
+                    .build()
+                    .expect("limit"),
+            )
+            .build()
+            .expect("velocity limit");
+
+        match ledger.velocities().create_limit(limit).await {
+            Err(cala_ledger::velocity::error::VelocityError::LimitIdAlreadyExists) => {
+                Ok(HOLD_ENFORCEMENT_ID.into())
+            }
+            Err(e) => Err(e.into()),
+            Ok(limit) => Ok(limit.id()),
+        }
+    }
+}
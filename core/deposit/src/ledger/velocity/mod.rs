@@ -1,3 +1,7 @@
+mod hold_enforcement;
 mod overdraft_prevention;
+mod withdrawal_limit;
 
+pub use hold_enforcement::*;
 pub use overdraft_prevention::*;
+pub use withdrawal_limit::*;
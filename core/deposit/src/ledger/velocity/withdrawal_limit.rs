@@ -0,0 +1,277 @@
+use tracing::instrument;
+use tracing_macros::record_error_severity;
+
+use cala_ledger::{velocity::*, *};
+
+use crate::{ledger::error::*, primitives::UsdCents};
+
+const WITHDRAWAL_LIMIT_ID_PREFIX: u64 = 0x0000_0000_0002_0000;
+const WITHDRAWAL_LIMIT_ID_MASK: u64 = 0xffff_ffff_ffff_fff0;
+
+const METADATA_KEY: &str = "withdrawal_limits";
+const WINDOWS_KEY: &str = "windows";
+const RECORD_ONLY_KEY: &str = "record_only";
+const RECORD_ONLY_TIMESTAMP: &str = "1970-01-01T00:00:00Z";
+const ENFORCEMENT_START: &str = "1970-01-02T00:00:00Z";
+const ACCOUNT_CONTROLS_CONSTRAINT: &str = "cala_velocity_account_control";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WithdrawalLimitWindow {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl WithdrawalLimitWindow {
+    fn tag(&self) -> u64 {
+        match self {
+            Self::Daily => 1,
+            Self::Weekly => 2,
+            Self::Monthly => 3,
+        }
+    }
+
+    fn key(&self) -> &'static str {
+        match self {
+            Self::Daily => "daily",
+            Self::Weekly => "weekly",
+            Self::Monthly => "monthly",
+        }
+    }
+
+    fn partition_format(&self) -> &'static str {
+        match self {
+            Self::Daily => "%Y-%m-%d",
+            Self::Weekly => "%G-W%V",
+            Self::Monthly => "%Y-%m",
+        }
+    }
+}
+
+/// Velocity controls enforcing a withdrawal limit for one window and amount.
+///
+/// Cala fixes a limit's amount when its control is attached to an account, so
+/// every (window, amount) pair gets its own deterministic control that is
+/// attached on first use. A withdrawal only counts towards the controls flagged
+/// in its transaction metadata, which keeps controls for limits that have since
+/// been reconfigured inert.
+///
+/// The limit is enforced on the settled layer, which a withdrawal debits when it
+/// is initiated, so pending withdrawals count towards the window of their
+/// initiation date. Cancelling or denying a withdrawal credits the usage back
+/// (see [`WithdrawalLimitReversal`]). Transactions flagged as record-only count
+/// towards the limit without being checked against it: their enforcement
+/// timestamp is moved before the limit starts.
+pub struct WithdrawalLimit;
+
+impl WithdrawalLimit {
+    pub fn id(window: WithdrawalLimitWindow, amount: UsdCents) -> uuid::Uuid {
+        uuid::Uuid::from_u64_pair(
+            WITHDRAWAL_LIMIT_ID_PREFIX | window.tag(),
+            amount.into_inner(),
+        )
+    }
+
+    pub fn is_withdrawal_limit(limit_id: VelocityLimitId) -> bool {
+        let (high, _) = uuid::Uuid::from(limit_id).as_u64_pair();
+        high & WITHDRAWAL_LIMIT_ID_MASK == WITHDRAWAL_LIMIT_ID_PREFIX
+    }
+
+    pub fn is_already_attached(err: &cala_ledger::velocity::error::VelocityError) -> bool {
+        matches!(
+            err,
+            cala_ledger::velocity::error::VelocityError::Sqlx(e) if e
+                .as_database_error()
+                .and_then(|e| e.constraint())
+                .is_some_and(|c| c.contains(ACCOUNT_CONTROLS_CONSTRAINT))
+        )
+    }
+
+    fn metadata_flag(window: WithdrawalLimitWindow, amount: UsdCents) -> String {
+        format!("{}_{}", window.key(), amount.into_inner())
+    }
+
+    fn condition(window: WithdrawalLimitWindow, amount: UsdCents) -> String {
+        format!(
+            r#"
+            has(context.vars.transaction.metadata) &&
+            has(context.vars.transaction.metadata.{key}) &&
+            has(context.vars.transaction.metadata.{key}.{flag})
+        "#,
+            key = METADATA_KEY,
+            flag = Self::metadata_flag(window, amount),
+        )
+    }
+
+    fn timestamp_source() -> String {
+        format!(
+            r#"
+            has(context.vars.transaction.metadata.{METADATA_KEY}.{RECORD_ONLY_KEY}) ?
+            timestamp('{RECORD_ONLY_TIMESTAMP}') :
+            context.vars.transaction.createdAt
+        "#
+        )
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "ledger.withdrawal_limit.init", skip(ledger))]
+    pub async fn init(
+        ledger: &CalaLedger,
+        window: WithdrawalLimitWindow,
+        amount: UsdCents,
+    ) -> Result<VelocityControlId, DepositLedgerError> {
+        let id = Self::id(window, amount);
+        let limit = NewVelocityLimit::builder()
+            .id(id)
+            .name(format!("Withdrawal Limit ({})", window.key()))
+            .description(format!(
+                "Limit {} withdrawals to {} USD",
+                window.key(),
+                amount.to_usd()
+            ))
+            .window(vec![
+                NewPartitionKey::builder()
+                    .alias(window.key())
+                    .value(format!(
+                        "context.vars.transaction.metadata.{METADATA_KEY}.{WINDOWS_KEY}.{}",
+                        window.key()
+                    ))
+                    .build()
+                    .expect("partition key"),
+            ])
+            .condition(Self::condition(window, amount))
+            .limit(
+                NewLimit::builder()
+                    .timestamp_source(Self::timestamp_source())
+                    .balance(vec![
+                        NewBalanceLimit::builder()
+                            .layer("SETTLED")
+                            .amount(format!("decimal('{}')", amount.to_usd()))
+                            .enforcement_direction("DEBIT")
+                            .start(format!("timestamp('{ENFORCEMENT_START}')"))
+                            .build()
+                            .expect("balance limit"),
+                    ])
+                    .build()
+                    .expect("limit"),
+            )
+            .build()
+            .expect("velocity limit");
+
+        match ledger.velocities().create_limit(limit).await {
+            Ok(_) | Err(cala_ledger::velocity::error::VelocityError::LimitIdAlreadyExists) => {}
+            Err(e) => return Err(e.into()),
+        }
+
+        let control = NewVelocityControl::builder()
+            .id(id)
+            .name(format!("Withdrawal Limit ({})", window.key()))
+            .description(format!(
+                "Velocity Control for {} withdrawal limit",
+                window.key()
+            ))
+            .build()
+            .expect("build control");
+
+        match ledger.velocities().create_control(control).await {
+            Ok(_) | Err(cala_ledger::velocity::error::VelocityError::ControlIdAlreadyExists) => {}
+            Err(e) => return Err(e.into()),
+        }
+
+        match ledger
+            .velocities()
+            .add_limit_to_control(id.into(), id.into())
+            .await
+        {
+            Ok(_)
+            | Err(cala_ledger::velocity::error::VelocityError::LimitAlreadyAddedToControl) => {}
+            Err(e) => return Err(e.into()),
+        }
+
+        Ok(id.into())
+    }
+}
+
+/// The withdrawal limit controls a withdrawal is subject to.
+#[derive(Debug, Clone, Default)]
+pub struct WithdrawalLimitControls {
+    limits: Vec<(WithdrawalLimitWindow, UsdCents)>,
+    record_only: bool,
+}
+
+impl WithdrawalLimitControls {
+    pub(crate) fn push(&mut self, window: WithdrawalLimitWindow, amount: UsdCents) {
+        self.limits.push((window, amount));
+    }
+
+    /// Counts the transaction towards the limits without enforcing them.
+    pub(crate) fn record_only(mut self) -> Self {
+        self.record_only = true;
+        self
+    }
+
+    pub(crate) fn tx_metadata(
+        &self,
+        effective: chrono::NaiveDate,
+    ) -> Option<(&'static str, serde_json::Value)> {
+        if self.limits.is_empty() {
+            return None;
+        }
+        let mut meta = self
+            .limits
+            .iter()
+            .map(|(window, amount)| {
+                (
+                    WithdrawalLimit::metadata_flag(*window, *amount),
+                    serde_json::Value::Bool(true),
+                )
+            })
+            .collect::<serde_json::Map<_, _>>();
+        let windows = [
+            WithdrawalLimitWindow::Daily,
+            WithdrawalLimitWindow::Weekly,
+            WithdrawalLimitWindow::Monthly,
+        ]
+        .iter()
+        .map(|window| {
+            (
+                window.key().to_string(),
+                serde_json::Value::String(effective.format(window.partition_format()).to_string()),
+            )
+        })
+        .collect::<serde_json::Map<_, _>>();
+        meta.insert(WINDOWS_KEY.to_string(), windows.into());
+        if self.record_only {
+            meta.insert(RECORD_ONLY_KEY.to_string(), serde_json::Value::Bool(true));
+        }
+        Some((METADATA_KEY, meta.into()))
+    }
+}
+
+/// Reverses the usage a withdrawal's initiation counted towards its limits.
+///
+/// The reversal is flagged with the limits and windows of the initiating
+/// transaction, so it credits back the same balances even when the limits have
+/// been reconfigured or the window has passed since. It is record-only because
+/// the remaining usage may still exceed the limit after over-limit withdrawals.
+#[derive(Debug, Clone, Default)]
+pub struct WithdrawalLimitReversal(Option<serde_json::Value>);
+
+impl WithdrawalLimitReversal {
+    pub(crate) fn of_initiation(metadata: Option<&serde_json::Value>) -> Self {
+        Self(
+            metadata
+                .and_then(|meta| meta.get(METADATA_KEY))
+                .and_then(|limits| limits.as_object())
+                .map(|limits| {
+                    let mut limits = limits.clone();
+                    limits.insert(RECORD_ONLY_KEY.to_string(), serde_json::Value::Bool(true));
+                    limits.into()
+                }),
+        )
+    }
+
+    pub(crate) fn tx_metadata(&self) -> Option<(&'static str, serde_json::Value)> {
+        self.0.clone().map(|limits| (METADATA_KEY, limits))
+    }
+}
@@ -3,16 +3,19 @@
 
 mod account;
 mod chart_of_accounts_integration;
+mod config;
 mod deposit;
 mod deposit_account_balance;
 pub mod error;
 mod for_subject;
 mod history;
+mod hold;
 mod ledger;
 mod primitives;
 mod processes;
 pub mod public;
 mod publisher;
+mod transfer;
 mod withdrawal;
 
 use std::sync::Arc;
@@ -20,7 +23,7 @@ use std::sync::Arc;
 use tracing::instrument;
 use tracing_macros::record_error_severity;
 
-use audit::AuditSvc;
+use audit::{AuditSvc, SystemSubject};
 use authz::PermissionCheck;
 use cala_ledger::CalaLedger;
 use core_customer::{CoreCustomerAction, CoreCustomerEvent, CustomerId, CustomerObject, Customers};
@@ -36,6 +39,9 @@ use chart_of_accounts_integration::ChartOfAccountsIntegrations;
 pub use chart_of_accounts_integration::{
     ChartOfAccountsIntegrationConfig, error::ChartOfAccountsIntegrationError,
 };
+pub use config::{
+    TransferApprovalThreshold, WithdrawalLimitRule, WithdrawalLimits, WithdrawalLimitsConfig,
+};
 use deposit::*;
 pub use deposit::{Deposit, DepositsByCreatedAtCursor};
 pub use deposit_account_balance::DepositAccountBalance;
@@ -43,12 +49,20 @@ pub use domain_config::RequireVerifiedCustomerForAccount;
 use error::*;
 pub use for_subject::DepositsForSubject;
 pub use history::{DepositAccountHistoryCursor, DepositAccountHistoryEntry};
+use hold::*;
+pub use hold::{Hold, HoldStatus};
 use ledger::*;
 pub use primitives::*;
-pub use processes::approval::APPROVE_WITHDRAWAL_PROCESS;
+pub use processes::approval::{APPROVE_WITHDRAWAL_OVER_LIMIT_PROCESS, APPROVE_WITHDRAWAL_PROCESS};
 use processes::approval::{ApproveWithdrawal, WITHDRAW_APPROVE_JOB, WithdrawApprovalHandler};
+pub use processes::transfer_approval::APPROVE_TRANSFER_PROCESS;
+use processes::transfer_approval::{
+    ApproveTransfer, TRANSFER_APPROVE_JOB, TransferApprovalHandler,
+};
 pub use public::*;
 use publisher::DepositPublisher;
+use transfer::*;
+pub use transfer::{Transfer, TransferStatus, TransfersByCreatedAtCursor};
 use withdrawal::*;
 pub use withdrawal::{Withdrawal, WithdrawalStatus, WithdrawalsByCreatedAtCursor};
 
@@ -56,6 +70,8 @@ pub use withdrawal::{Withdrawal, WithdrawalStatus, WithdrawalsByCreatedAtCursor}
 pub mod event_schema {
     pub use crate::account::DepositAccountEvent;
     pub use crate::deposit::DepositEvent;
+    pub use crate::hold::HoldEvent;
+    pub use crate::transfer::TransferEvent;
     pub use crate::withdrawal::WithdrawalEvent;
 }
 
@@ -69,7 +85,10 @@ where
     deposit_accounts: DepositAccountRepo<E>,
     deposits: DepositRepo<E>,
     withdrawals: WithdrawalRepo<E>,
+    transfers: TransferRepo<E>,
+    holds: HoldRepo,
     approve_withdrawal: ApproveWithdrawal<Perms, E>,
+    approve_transfer: ApproveTransfer<Perms, E>,
     ledger: Arc<DepositLedger>,
     cala: CalaLedger,
     authz: Arc<Perms>,
@@ -79,6 +98,8 @@ where
     customers: Customers<Perms, E>,
     chart_of_accounts_integrations: Arc<ChartOfAccountsIntegrations<Perms>>,
     domain_configs: ExposedDomainConfigsReadOnly,
+    internal_domain_configs: Arc<InternalDomainConfigs>,
+    clock: es_entity::clock::ClockHandle,
 }
 
 impl<Perms, E> Clone for CoreDeposit<Perms, E>
@@ -93,16 +114,21 @@ where
             deposit_accounts: self.deposit_accounts.clone(),
             deposits: self.deposits.clone(),
             withdrawals: self.withdrawals.clone(),
+            transfers: self.transfers.clone(),
+            holds: self.holds.clone(),
             ledger: self.ledger.clone(),
             cala: self.cala.clone(),
             authz: self.authz.clone(),
             governance: self.governance.clone(),
             approve_withdrawal: self.approve_withdrawal.clone(),
+            approve_transfer: self.approve_transfer.clone(),
             outbox: self.outbox.clone(),
             public_ids: self.public_ids.clone(),
             customers: self.customers.clone(),
             chart_of_accounts_integrations: self.chart_of_accounts_integrations.clone(),
             domain_configs: self.domain_configs.clone(),
+            internal_domain_configs: self.internal_domain_configs.clone(),
+            clock: self.clock.clone(),
         }
     }
 }
@@ -141,6 +167,8 @@ where
         let accounts = DepositAccountRepo::new(pool, &publisher, clock.clone());
         let deposits = DepositRepo::new(pool, &publisher, clock.clone());
         let withdrawals = WithdrawalRepo::new(pool, &publisher, clock.clone());
+        let transfers = TransferRepo::new(pool, &publisher, clock.clone());
+        let holds = HoldRepo::new(pool, clock.clone());
         let ledger = DepositLedger::init(cala, journal_id, clock.clone()).await?;
         let ledger_arc = Arc::new(ledger);
         let internal_domain_configs_arc = Arc::new(internal_domain_configs.clone());
@@ -157,6 +185,27 @@ where
             .await?;
 
         governance.init_policy(APPROVE_WITHDRAWAL_PROCESS).await?;
+        governance
+            .init_policy(APPROVE_WITHDRAWAL_OVER_LIMIT_PROCESS)
+            .await?;
+
+        let approve_transfer = ApproveTransfer::new(
+            &transfers,
+            &accounts,
+            authz.audit(),
+            governance,
+            ledger_arc.as_ref(),
+        );
+
+        outbox
+            .register_event_handler(
+                jobs,
+                OutboxEventJobConfig::new(TRANSFER_APPROVE_JOB),
+                TransferApprovalHandler::new(&approve_transfer),
+            )
+            .await?;
+
+        governance.init_policy(APPROVE_TRANSFER_PROCESS).await?;
 
         let chart_of_accounts_integrations = ChartOfAccountsIntegrations::new(
             authz_arc.clone(),
@@ -169,16 +218,21 @@ where
             deposit_accounts: accounts,
             deposits,
             withdrawals,
+            transfers,
+            holds,
             authz: authz_arc,
             outbox: outbox.clone(),
             governance: governance.clone(),
             cala: cala.clone(),
             approve_withdrawal,
+            approve_transfer,
             ledger: ledger_arc,
             public_ids: public_ids.clone(),
             customers: customers.clone(),
             chart_of_accounts_integrations: chart_of_accounts_integrations_arc.clone(),
             domain_configs: domain_configs.clone(),
+            internal_domain_configs: internal_domain_configs_arc,
+            clock,
         };
         Ok(res)
     }
@@ -200,6 +254,7 @@ where
         Ok(DepositsForSubject::new(
             sub,
             holder_id,
+            self,
             &self.deposit_accounts,
             &self.deposits,
             &self.withdrawals,
@@ -375,6 +430,13 @@ where
                     tracing::warn!("Skipping update error if account already frozen");
                     continue;
                 }
+                Err(
+                    DepositAccountError::CannotUpdateDormantAccount(_)
+                    | DepositAccountError::CannotUpdateEscheatedAccount(_),
+                ) => {
+                    tracing::warn!("Skipping update error if account dormant or escheated");
+                    continue;
+                }
                 Err(e) => {
                     return Err(e.into());
                 }
@@ -430,7 +492,7 @@ where
                 CoreDepositAction::DEPOSIT_CREATE,
             )
             .await?;
-        self.check_account_active(deposit_account_id).await?;
+        self.check_account_can_receive(deposit_account_id).await?;
         let deposit_id = DepositId::new();
         let mut op = self.deposits.begin_op().await?;
         let public_id = self
@@ -463,7 +525,6 @@ where
         amount: UsdCents,
         reference: Option<String>,
     ) -> Result<Withdrawal, CoreDepositError> {
-        let deposit_account_id = deposit_account_id.into();
         self.authz
             .enforce_permission(
                 sub,
@@ -471,7 +532,28 @@ where
                 CoreDepositAction::WITHDRAWAL_INITIATE,
             )
             .await?;
-        self.check_account_active(deposit_account_id).await?;
+        self.initiate_withdrawal_without_audit(sub, deposit_account_id, amount, reference)
+            .await
+    }
+
+    pub(crate) async fn initiate_withdrawal_without_audit(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        amount: UsdCents,
+        reference: Option<String>,
+    ) -> Result<Withdrawal, CoreDepositError> {
+        let deposit_account_id = deposit_account_id.into();
+        let account = self.check_account_active(deposit_account_id).await?;
+        if account.is_locked(self.clock.today()) {
+            return Err(CoreDepositError::TermDepositNotMatured);
+        }
+        let limits = self.withdrawal_limits_for_account(&account).await?;
+        let withdrawal_limits = self
+            .ledger
+            .attach_withdrawal_limits(deposit_account_id, &limits)
+            .await?;
+
         let withdrawal_id = WithdrawalId::new();
         let mut op = self.withdrawals.begin_op().await?;
         let public_id = self
@@ -488,12 +570,47 @@ where
             .reference(reference)
             .build()?;
 
+        let mut ledger_op = op.begin().await?;
+        let process_type = match self
+            .ledger
+            .initiate_withdrawal_in_op(
+                &mut ledger_op,
+                withdrawal_id,
+                amount,
+                deposit_account_id,
+                &withdrawal_limits,
+                sub,
+            )
+            .await
+        {
+            Ok(()) => {
+                ledger_op.commit().await?;
+                APPROVE_WITHDRAWAL_PROCESS
+            }
+            Err(ledger::error::DepositLedgerError::WithdrawalLimitExceeded) => {
+                drop(ledger_op);
+                self.ledger
+                    .initiate_withdrawal_in_op(
+                        &mut op,
+                        withdrawal_id,
+                        amount,
+                        deposit_account_id,
+                        &withdrawal_limits.record_only(),
+                        sub,
+                    )
+                    .await?;
+                APPROVE_WITHDRAWAL_OVER_LIMIT_PROCESS
+            }
+            Err(e) => return Err(e.into()),
+        };
+
         self.governance
-            .start_process_in_op(
+            .start_process_with_amount_in_op(
                 &mut op,
                 withdrawal_id,
                 withdrawal_id.to_string(),
-                APPROVE_WITHDRAWAL_PROCESS,
+                process_type,
+                Some(amount.into_inner()),
             )
             .await?;
         let withdrawal = self
@@ -501,13 +618,146 @@ where
             .create_in_op(&mut op, new_withdrawal)
             .await?;
 
+        op.commit().await?;
+
+        Ok(withdrawal)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.withdrawal_limits_config", skip(self))]
+    pub async fn withdrawal_limits_config(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+    ) -> Result<WithdrawalLimitsConfig, CoreDepositError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_withdrawals(),
+                CoreDepositAction::WITHDRAWAL_READ_LIMITS,
+            )
+            .await?;
+
+        Ok(self
+            .internal_domain_configs
+            .get::<WithdrawalLimitsConfig>()
+            .await?
+            .maybe_value()
+            .unwrap_or_default())
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.update_withdrawal_limits_config", skip(self))]
+    pub async fn update_withdrawal_limits_config(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        config: WithdrawalLimitsConfig,
+    ) -> Result<WithdrawalLimitsConfig, CoreDepositError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_withdrawals(),
+                CoreDepositAction::WITHDRAWAL_UPDATE_LIMITS,
+            )
+            .await?;
+
+        self.internal_domain_configs
+            .update::<WithdrawalLimitsConfig>(config.clone())
+            .await?;
+
+        Ok(config)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.initiate_transfer", skip(self))]
+    pub async fn initiate_transfer(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        source_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        destination_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        amount: UsdCents,
+        reference: Option<String>,
+    ) -> Result<Transfer, CoreDepositError> {
+        let source_account_id = source_account_id.into();
+        let destination_account_id = destination_account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_transfers(),
+                CoreDepositAction::TRANSFER_INITIATE,
+            )
+            .await?;
+        let source_account = self.check_account_active(source_account_id).await?;
+        if source_account.is_locked(self.clock.today()) {
+            return Err(CoreDepositError::TermDepositNotMatured);
+        }
+        self.check_account_can_receive(destination_account_id)
+            .await?;
+
+        let requires_approval = self
+            .domain_configs
+            .get_without_audit::<TransferApprovalThreshold>()
+            .await?
+            .maybe_value()
+            .is_some_and(|threshold| amount >= UsdCents::from(threshold));
+
+        let transfer_id = TransferId::new();
+        let mut op = self.transfers.begin_op().await?;
+        let public_id = self
+            .public_ids
+            .create_in_op(&mut op, TRANSFER_REF_TARGET, transfer_id)
+            .await?;
+
+        let approval_process_id = if requires_approval {
+            self.governance
+                .start_process_with_amount_in_op(
+                    &mut op,
+                    transfer_id,
+                    transfer_id.to_string(),
+                    APPROVE_TRANSFER_PROCESS,
+                    Some(amount.into_inner()),
+                )
+                .await?;
+            Some(ApprovalProcessId::from(transfer_id))
+        } else {
+            None
+        };
+
+        let new_transfer = NewTransfer::builder()
+            .id(transfer_id)
+            .source_account_id(source_account_id)
+            .destination_account_id(destination_account_id)
+            .amount(amount)
+            .approval_process_id(approval_process_id)
+            .public_id(public_id.id)
+            .reference(reference)
+            .build()?;
+
+        let mut transfer = self.transfers.create_in_op(&mut op, new_transfer).await?;
+
         self.ledger
-            .initiate_withdrawal_in_op(&mut op, withdrawal_id, amount, deposit_account_id, sub)
+            .initiate_transfer_in_op(&mut op, transfer_id, amount, source_account_id, sub)
             .await?;
 
+        if !requires_approval
+            && let es_entity::Idempotent::Executed(completed_tx_id) = transfer.complete()?
+        {
+            self.transfers.update_in_op(&mut op, &mut transfer).await?;
+            self.ledger
+                .complete_transfer_in_op(
+                    &mut op,
+                    transfer_id,
+                    completed_tx_id,
+                    amount,
+                    source_account_id,
+                    destination_account_id,
+                    sub,
+                )
+                .await?;
+        }
+
         op.commit().await?;
 
-        Ok(withdrawal)
+        Ok(transfer)
     }
 
     #[record_error_severity]
@@ -638,7 +888,7 @@ where
             )
             .await?;
         let mut withdrawal = self.withdrawals.find_by_id(id).await?;
-        self.check_account_active(withdrawal.deposit_account_id)
+        self.check_account_can_receive(withdrawal.deposit_account_id)
             .await?;
         let mut op = self.withdrawals.begin_op().await?;
         let es_entity::Idempotent::Executed(tx_id) = withdrawal.cancel()? else {
@@ -729,6 +979,30 @@ where
         Ok(account)
     }
 
+    #[record_error_severity]
+    #[instrument(name = "deposit.reactivate_account", skip(self))]
+    pub async fn reactivate_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_REACTIVATE,
+            )
+            .await?;
+
+        let mut account = self.deposit_accounts.find_by_id(account_id).await?;
+        if account.reactivate()?.did_execute() {
+            self.deposit_accounts.update(&mut account).await?;
+        }
+
+        Ok(account)
+    }
+
     #[record_error_severity]
     #[instrument(name = "deposit.close_account", skip(self))]
     pub async fn close_account(
@@ -767,6 +1041,358 @@ where
         Ok(account)
     }
 
+    #[record_error_severity]
+    #[instrument(name = "deposit.update_account_interest_terms", skip(self))]
+    pub async fn update_account_interest_terms(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        terms: DepositAccountInterestTerms,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_UPDATE_INTEREST_TERMS,
+            )
+            .await?;
+
+        let mut account = self.deposit_accounts.find_by_id(account_id).await?;
+
+        if account
+            .update_interest_terms(terms, self.clock.today())?
+            .did_execute()
+        {
+            self.deposit_accounts.update(&mut account).await?;
+        }
+
+        Ok(account)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.break_term_deposit", skip(self))]
+    pub async fn break_term_deposit(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_BREAK_TERM,
+            )
+            .await?;
+
+        let mut account = self.deposit_accounts.find_by_id(account_id).await?;
+        let balance = self.ledger.balance(account_id).await?;
+        let term_break = account.break_term(self.clock.today(), balance.settled)?;
+
+        let mut op = self.deposit_accounts.begin_op().await?;
+        self.deposit_accounts
+            .update_in_op(&mut op, &mut account)
+            .await?;
+        if let Some(ledger_tx_id) = term_break.ledger_tx_id {
+            self.ledger
+                .charge_early_break_penalty_in_op(
+                    &mut op,
+                    ledger_tx_id,
+                    account_id,
+                    term_break.penalty,
+                    sub,
+                )
+                .await?;
+        }
+        op.commit().await?;
+
+        Ok(account)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.perform_interest_accrual", skip(self))]
+    pub async fn perform_interest_accrual(
+        &self,
+        day: chrono::NaiveDate,
+    ) -> Result<(), CoreDepositError> {
+        let mut after = None;
+        loop {
+            let res = self
+                .deposit_accounts
+                .list_for_interest_bearing_by_created_at(
+                    true,
+                    es_entity::PaginatedQueryArgs { first: 100, after },
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+
+            for mut account in res.entities {
+                // Days missed by earlier runs are caught up one by one so each
+                // accrues on the balance the account actually held that day.
+                let mut accrual_day = account
+                    .last_accrual_day()
+                    .and_then(|last| last.succ_opt())
+                    .unwrap_or(day);
+                while accrual_day <= day {
+                    let balance = self
+                        .ledger
+                        .settled_balance_as_of(account.id, accrual_day)
+                        .await?;
+                    let es_entity::Idempotent::Executed(capitalization) =
+                        account.accrue_interest(accrual_day, balance)
+                    else {
+                        break;
+                    };
+
+                    let mut op = self.deposit_accounts.begin_op().await?;
+                    self.authz
+                        .audit()
+                        .record_system_entry_in_op(
+                            &mut op,
+                            DEPOSIT_INTEREST_ACCRUAL,
+                            CoreDepositObject::deposit_account(account.id),
+                            CoreDepositAction::DEPOSIT_ACCOUNT_ACCRUE_INTEREST,
+                        )
+                        .await?;
+                    self.deposit_accounts
+                        .update_in_op(&mut op, &mut account)
+                        .await?;
+                    if let Some(capitalization) = capitalization {
+                        self.ledger
+                            .capitalize_interest_in_op(
+                                &mut op,
+                                capitalization.ledger_tx_id,
+                                account.id,
+                                capitalization.amount,
+                                &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(
+                                    DEPOSIT_INTEREST_ACCRUAL,
+                                ),
+                            )
+                            .await?;
+                    }
+                    op.commit().await?;
+
+                    let Some(next_day) = accrual_day.succ_opt() else {
+                        break;
+                    };
+                    accrual_day = next_day;
+                }
+            }
+
+            match res.end_cursor {
+                Some(end_cursor) if res.has_next_page => {
+                    after = Some(end_cursor);
+                }
+                _ => break,
+            }
+        }
+
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.mark_accounts_dormant_for_holder", skip(self))]
+    pub async fn mark_accounts_dormant_for_holder(
+        &self,
+        holder_id: impl Into<DepositAccountHolderId> + std::fmt::Debug,
+    ) -> Result<(), CoreDepositError> {
+        let accounts = self
+            .deposit_accounts
+            .list_for_account_holder_id_by_id(
+                holder_id.into(),
+                Default::default(),
+                Default::default(),
+            )
+            .await?;
+
+        for mut account in accounts.entities {
+            if account.mark_dormant().did_execute() {
+                let mut op = self.deposit_accounts.begin_op().await?;
+                self.authz
+                    .audit()
+                    .record_system_entry_in_op(
+                        &mut op,
+                        DEPOSIT_DORMANCY,
+                        CoreDepositObject::deposit_account(account.id),
+                        CoreDepositAction::DEPOSIT_ACCOUNT_MARK_DORMANT,
+                    )
+                    .await?;
+                self.deposit_accounts
+                    .update_in_op(&mut op, &mut account)
+                    .await?;
+                op.commit().await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.escheat_accounts_for_holder", skip(self))]
+    pub async fn escheat_accounts_for_holder(
+        &self,
+        holder_id: impl Into<DepositAccountHolderId> + std::fmt::Debug,
+    ) -> Result<(), CoreDepositError> {
+        let accounts = self
+            .deposit_accounts
+            .list_for_account_holder_id_by_id(
+                holder_id.into(),
+                Default::default(),
+                Default::default(),
+            )
+            .await?;
+
+        for mut account in accounts.entities {
+            let balance = self.ledger.balance(account.id).await?;
+            let es_entity::Idempotent::Executed(escheatment) = account.escheat(balance.available())
+            else {
+                continue;
+            };
+
+            let mut op = self.deposit_accounts.begin_op().await?;
+            self.authz
+                .audit()
+                .record_system_entry_in_op(
+                    &mut op,
+                    DEPOSIT_DORMANCY,
+                    CoreDepositObject::deposit_account(account.id),
+                    CoreDepositAction::DEPOSIT_ACCOUNT_ESCHEAT,
+                )
+                .await?;
+            self.deposit_accounts
+                .update_in_op(&mut op, &mut account)
+                .await?;
+            if let Some(escheatment) = escheatment {
+                self.ledger
+                    .escheat_account_in_op(
+                        &mut op,
+                        escheatment.ledger_tx_id,
+                        account.id,
+                        escheatment.amount,
+                        &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(
+                            DEPOSIT_DORMANCY,
+                        ),
+                    )
+                    .await?;
+            }
+            op.commit().await?;
+        }
+
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.place_hold", skip(self))]
+    pub async fn place_hold(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        amount: UsdCents,
+        reason: impl Into<String> + std::fmt::Debug,
+    ) -> Result<Hold, CoreDepositError> {
+        let deposit_account_id = deposit_account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_holds(),
+                CoreDepositAction::HOLD_PLACE,
+            )
+            .await?;
+        self.check_account_active(deposit_account_id).await?;
+
+        let hold_id = HoldId::new();
+        let new_hold = NewHold::builder()
+            .id(hold_id)
+            .deposit_account_id(deposit_account_id)
+            .amount(amount)
+            .reason(reason)
+            .build()?;
+
+        let mut op = self.holds.begin_op().await?;
+        let hold = self.holds.create_in_op(&mut op, new_hold).await?;
+        self.ledger
+            .place_hold_in_op(&mut op, hold_id, deposit_account_id, amount, sub)
+            .await?;
+        op.commit().await?;
+
+        Ok(hold)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.release_hold", skip(self))]
+    pub async fn release_hold(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        hold_id: impl Into<HoldId> + std::fmt::Debug,
+    ) -> Result<Hold, CoreDepositError> {
+        let hold_id = hold_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::hold(hold_id),
+                CoreDepositAction::HOLD_RELEASE,
+            )
+            .await?;
+
+        let mut hold = self.holds.find_by_id(hold_id).await?;
+        if let es_entity::Idempotent::Executed(tx_id) = hold.release()? {
+            let mut op = self.holds.begin_op().await?;
+            self.holds.update_in_op(&mut op, &mut hold).await?;
+            self.ledger
+                .release_hold_in_op(
+                    &mut op,
+                    tx_id,
+                    hold.id,
+                    hold.deposit_account_id,
+                    hold.amount,
+                    sub,
+                )
+                .await?;
+            op.commit().await?;
+        }
+
+        Ok(hold)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.capture_hold", skip(self))]
+    pub async fn capture_hold(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        hold_id: impl Into<HoldId> + std::fmt::Debug,
+    ) -> Result<Hold, CoreDepositError> {
+        let hold_id = hold_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::hold(hold_id),
+                CoreDepositAction::HOLD_CAPTURE,
+            )
+            .await?;
+
+        let mut hold = self.holds.find_by_id(hold_id).await?;
+        self.check_account_active(hold.deposit_account_id).await?;
+
+        if let es_entity::Idempotent::Executed(tx_id) = hold.capture()? {
+            let mut op = self.holds.begin_op().await?;
+            self.holds.update_in_op(&mut op, &mut hold).await?;
+            self.ledger
+                .capture_hold_in_op(
+                    &mut op,
+                    tx_id,
+                    hold.id,
+                    hold.deposit_account_id,
+                    hold.amount,
+                    sub,
+                )
+                .await?;
+            op.commit().await?;
+        }
+
+        Ok(hold)
+    }
+
     #[record_error_severity]
     #[instrument(name = "deposit.account_balance", skip(self))]
     pub async fn account_balance(
@@ -788,6 +1414,26 @@ where
         Ok(balance)
     }
 
+    #[record_error_severity]
+    #[instrument(name = "deposit.account_settled_balance_as_of", skip(self))]
+    pub async fn account_settled_balance_as_of(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        date: chrono::NaiveDate,
+    ) -> Result<UsdCents, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_READ_BALANCE,
+            )
+            .await?;
+
+        Ok(self.ledger.settled_balance_as_of(account_id, date).await?)
+    }
+
     #[record_error_severity]
     #[instrument(name = "deposit.find_deposit_by_id", skip(self))]
     pub async fn find_deposit_by_id(
@@ -900,6 +1546,129 @@ where
         Ok(self.withdrawals.find_all(ids).await?)
     }
 
+    #[record_error_severity]
+    #[instrument(name = "deposit.find_transfer_by_id", skip(self))]
+    pub async fn find_transfer_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<TransferId> + std::fmt::Debug,
+    ) -> Result<Option<Transfer>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::transfer(id),
+                CoreDepositAction::TRANSFER_READ,
+            )
+            .await?;
+
+        Ok(self.transfers.maybe_find_by_id(id).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.find_transfer_by_public_id", skip(self))]
+    pub async fn find_transfer_by_public_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        public_id: impl Into<public_id::PublicId> + std::fmt::Debug,
+    ) -> Result<Option<Transfer>, CoreDepositError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_transfers(),
+                CoreDepositAction::TRANSFER_READ,
+            )
+            .await?;
+
+        Ok(self
+            .transfers
+            .maybe_find_by_public_id(public_id.into())
+            .await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.find_transfer_by_completed_tx_id", skip(self))]
+    pub async fn find_transfer_by_completed_tx_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        completed_tx_id: impl Into<CalaTransactionId> + std::fmt::Debug,
+    ) -> Result<Transfer, CoreDepositError> {
+        let completed_tx_id = completed_tx_id.into();
+        let transfer = self
+            .transfers
+            .find_by_completed_tx_id(Some(completed_tx_id))
+            .await?;
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::transfer(transfer.id),
+                CoreDepositAction::TRANSFER_READ,
+            )
+            .await?;
+
+        Ok(transfer)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.find_transfer_by_denied_tx_id", skip(self))]
+    pub async fn find_transfer_by_denied_tx_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        denied_tx_id: impl Into<CalaTransactionId> + std::fmt::Debug,
+    ) -> Result<Transfer, CoreDepositError> {
+        let denied_tx_id = denied_tx_id.into();
+        let transfer = self
+            .transfers
+            .find_by_denied_tx_id(Some(denied_tx_id))
+            .await?;
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::transfer(transfer.id),
+                CoreDepositAction::TRANSFER_READ,
+            )
+            .await?;
+
+        Ok(transfer)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.find_all_transfers", skip(self))]
+    pub async fn find_all_transfers<T: From<Transfer>>(
+        &self,
+        ids: &[TransferId],
+    ) -> Result<std::collections::HashMap<TransferId, T>, CoreDepositError> {
+        Ok(self.transfers.find_all(ids).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.find_hold_by_id", skip(self))]
+    pub async fn find_hold_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<HoldId> + std::fmt::Debug,
+    ) -> Result<Option<Hold>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::hold(id),
+                CoreDepositAction::HOLD_READ,
+            )
+            .await?;
+
+        Ok(self.holds.maybe_find_by_id(id).await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.find_all_holds", skip(self))]
+    pub async fn find_all_holds<T: From<Hold>>(
+        &self,
+        ids: &[HoldId],
+    ) -> Result<std::collections::HashMap<HoldId, T>, CoreDepositError> {
+        Ok(self.holds.find_all(ids).await?)
+    }
+
     #[record_error_severity]
     #[instrument(name = "deposit.find_all_deposits", skip(self))]
     pub async fn find_all_deposits<T: From<Deposit>>(
@@ -1037,6 +1806,76 @@ where
             .entities)
     }
 
+    #[record_error_severity]
+    #[instrument(name = "deposit.list_transfers", skip(self))]
+    pub async fn list_transfers(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<TransfersByCreatedAtCursor>,
+    ) -> Result<es_entity::PaginatedQueryRet<Transfer, TransfersByCreatedAtCursor>, CoreDepositError>
+    {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_transfers(),
+                CoreDepositAction::TRANSFER_LIST,
+            )
+            .await?;
+        Ok(self
+            .transfers
+            .list_by_created_at(query, es_entity::ListDirection::Descending)
+            .await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.list_transfers_for_account", skip(self))]
+    pub async fn list_transfers_for_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        query: es_entity::PaginatedQueryArgs<TransfersByCreatedAtCursor>,
+    ) -> Result<es_entity::PaginatedQueryRet<Transfer, TransfersByCreatedAtCursor>, CoreDepositError>
+    {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_transfers(),
+                CoreDepositAction::TRANSFER_LIST,
+            )
+            .await?;
+        Ok(self
+            .transfers
+            .list_for_account_id_by_created_at(account_id, query)
+            .await?)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "deposit.list_holds_for_account", skip(self))]
+    pub async fn list_holds_for_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<Hold>, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_holds(),
+                CoreDepositAction::HOLD_LIST,
+            )
+            .await?;
+        Ok(self
+            .holds
+            .list_for_deposit_account_id_by_created_at(
+                account_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities)
+    }
+
     #[record_error_severity]
     #[instrument(
         name = "deposit.list_accounts_by_created_at_for_account_holder",
@@ -1083,16 +1922,56 @@ where
             .await?)
     }
 
+    async fn withdrawal_limits_for_account(
+        &self,
+        account: &DepositAccount,
+    ) -> Result<WithdrawalLimits, CoreDepositError> {
+        let Some(config) = self
+            .internal_domain_configs
+            .get::<WithdrawalLimitsConfig>()
+            .await?
+            .maybe_value()
+        else {
+            return Ok(WithdrawalLimits::default());
+        };
+        let customer = self
+            .customers
+            .find_by_id_without_audit(CustomerId::from(account.account_holder_id))
+            .await?;
+        let party = self
+            .customers
+            .find_party_by_id_without_audit(customer.party_id)
+            .await?;
+
+        Ok(config.limits_for(account.product(), party.customer_type))
+    }
+
     async fn check_account_active(
         &self,
         deposit_account_id: DepositAccountId,
-    ) -> Result<(), CoreDepositError> {
+    ) -> Result<DepositAccount, CoreDepositError> {
         let account = self.deposit_accounts.find_by_id(deposit_account_id).await?;
         match account.status {
             DepositAccountStatus::Inactive => Err(CoreDepositError::DepositAccountInactive),
             DepositAccountStatus::Frozen => Err(CoreDepositError::DepositAccountFrozen),
             DepositAccountStatus::Closed => Err(CoreDepositError::DepositAccountClosed),
-            DepositAccountStatus::Active => Ok(()),
+            DepositAccountStatus::Dormant => Err(CoreDepositError::DepositAccountDormant),
+            DepositAccountStatus::Escheated => Err(CoreDepositError::DepositAccountEscheated),
+            DepositAccountStatus::Active => Ok(account),
+        }
+    }
+
+    /// Like `check_account_active` but also lets dormant accounts through, as they
+    /// keep accepting incoming funds.
+    async fn check_account_can_receive(
+        &self,
+        deposit_account_id: DepositAccountId,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        match self.check_account_active(deposit_account_id).await {
+            Err(CoreDepositError::DepositAccountDormant) => {
+                Ok(self.deposit_accounts.find_by_id(deposit_account_id).await?)
+            }
+            res => res,
         }
     }
 }
@@ -4,6 +4,7 @@ use cala_ledger::DebitOrCredit;
 pub enum DepositAccountCategory {
     Asset,
     Liability,
+    Expenses,
 }
 
 impl From<DepositAccountCategory> for core_accounting_primitives::AccountCategory {
@@ -11,6 +12,7 @@ impl From<DepositAccountCategory> for core_accounting_primitives::AccountCategor
         match value {
             DepositAccountCategory::Asset => Self::Asset,
             DepositAccountCategory::Liability => Self::Liability,
+            DepositAccountCategory::Expenses => Self::Expenses,
         }
     }
 }
@@ -71,6 +73,8 @@ pub struct DepositAccountSetCatalog {
     deposit: DepositAccountSetCatalogGroup,
     frozen: DepositAccountSetCatalogGroup,
     omnibus: DepositOmnibusAccountSetSpec,
+    interest_expense: DepositOmnibusAccountSetSpec,
+    unclaimed_funds: DepositOmnibusAccountSetSpec,
 }
 
 #[derive(Debug, Clone)]
@@ -96,6 +100,14 @@ impl DepositAccountSetCatalog {
         &self.omnibus
     }
 
+    pub fn interest_expense(&self) -> &DepositOmnibusAccountSetSpec {
+        &self.interest_expense
+    }
+
+    pub fn unclaimed_funds(&self) -> &DepositOmnibusAccountSetSpec {
+        &self.unclaimed_funds
+    }
+
     pub fn deposit_specs(&self) -> [DepositSummaryAccountSetSpec; 6] {
         [
             self.deposit.individual,
@@ -118,8 +130,8 @@ impl DepositAccountSetCatalog {
         ]
     }
 
-    pub fn omnibus_specs(&self) -> [DepositOmnibusAccountSetSpec; 1] {
-        [self.omnibus]
+    pub fn omnibus_specs(&self) -> [DepositOmnibusAccountSetSpec; 3] {
+        [self.omnibus, self.interest_expense, self.unclaimed_funds]
     }
 }
 
@@ -265,6 +277,30 @@ const DEPOSIT_OMNIBUS_ACCOUNT_SET: DepositOmnibusAccountSetSpec = DepositOmnibus
     DebitOrCredit::Debit,
 );
 
+const DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET_NAME: &str = "Deposit Interest Expense Account Set";
+const DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET_REF: &str = "deposit-interest-expense-account-set";
+const DEPOSIT_INTEREST_EXPENSE_ACCOUNT_REF: &str = "deposit-interest-expense-account";
+const DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET: DepositOmnibusAccountSetSpec =
+    DepositOmnibusAccountSetSpec::new(
+        DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET_NAME,
+        DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET_REF,
+        DEPOSIT_INTEREST_EXPENSE_ACCOUNT_REF,
+        DepositAccountCategory::Expenses,
+        DebitOrCredit::Debit,
+    );
+
+const DEPOSIT_UNCLAIMED_FUNDS_ACCOUNT_SET_NAME: &str = "Deposit Unclaimed Funds Account Set";
+const DEPOSIT_UNCLAIMED_FUNDS_ACCOUNT_SET_REF: &str = "deposit-unclaimed-funds-account-set";
+const DEPOSIT_UNCLAIMED_FUNDS_ACCOUNT_REF: &str = "deposit-unclaimed-funds-account";
+const DEPOSIT_UNCLAIMED_FUNDS_ACCOUNT_SET: DepositOmnibusAccountSetSpec =
+    DepositOmnibusAccountSetSpec::new(
+        DEPOSIT_UNCLAIMED_FUNDS_ACCOUNT_SET_NAME,
+        DEPOSIT_UNCLAIMED_FUNDS_ACCOUNT_SET_REF,
+        DEPOSIT_UNCLAIMED_FUNDS_ACCOUNT_REF,
+        DepositAccountCategory::Liability,
+        DebitOrCredit::Credit,
+    );
+
 pub const DEPOSIT_ACCOUNT_SET_CATALOG: DepositAccountSetCatalog = DepositAccountSetCatalog {
     deposit: DepositAccountSetCatalogGroup {
         individual: DEPOSIT_INDIVIDUAL_ACCOUNT_SET,
@@ -283,4 +319,6 @@ pub const DEPOSIT_ACCOUNT_SET_CATALOG: DepositAccountSetCatalog = DepositAccount
         non_domiciled_company: FROZEN_DEPOSIT_NON_DOMICILED_COMPANY_ACCOUNT_SET,
     },
     omnibus: DEPOSIT_OMNIBUS_ACCOUNT_SET,
+    interest_expense: DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET,
+    unclaimed_funds: DEPOSIT_UNCLAIMED_FUNDS_ACCOUNT_SET,
 };
@@ -13,6 +13,9 @@ use std::{fmt::Display, str::FromStr};
 use authz::{ActionPermission, AllOrOne, action_description::*, map_action};
 
 pub const DEPOSIT_APPROVAL: audit::SystemActor = audit::SystemActor::new("deposit-approval");
+pub const DEPOSIT_INTEREST_ACCRUAL: audit::SystemActor =
+    audit::SystemActor::new("deposit-interest-accrual");
+pub const DEPOSIT_DORMANCY: audit::SystemActor = audit::SystemActor::new("deposit-dormancy");
 
 pub use core_accounting_primitives::ChartId;
 pub use core_customer::CustomerType;
@@ -28,6 +31,8 @@ es_entity::entity_id! {
     DepositAccountHolderId,
     DepositAccountId,
     WithdrawalId,
+    TransferId,
+    HoldId,
     ChartOfAccountsIntegrationConfigId,
     DepositId;
 
@@ -38,7 +43,11 @@ es_entity::entity_id! {
     DepositId => CalaTransactionId,
     WithdrawalId => CalaTransactionId,
     WithdrawalId => public_id::PublicIdTargetId,
-    WithdrawalId => ApprovalProcessId
+    WithdrawalId => ApprovalProcessId,
+    TransferId => CalaTransactionId,
+    TransferId => public_id::PublicIdTargetId,
+    TransferId => ApprovalProcessId,
+    HoldId => CalaTransactionId
 }
 
 pub use money::UsdCents;
@@ -49,12 +58,18 @@ pub const DEPOSIT_TRANSACTION_ENTITY_TYPE: core_accounting_primitives::EntityTyp
     core_accounting_primitives::EntityType::new("Deposit");
 pub const WITHDRAWAL_TRANSACTION_ENTITY_TYPE: core_accounting_primitives::EntityType =
     core_accounting_primitives::EntityType::new("Withdrawal");
+pub const TRANSFER_TRANSACTION_ENTITY_TYPE: core_accounting_primitives::EntityType =
+    core_accounting_primitives::EntityType::new("Transfer");
+pub const HOLD_ENTITY_TYPE: core_accounting_primitives::EntityType =
+    core_accounting_primitives::EntityType::new("Hold");
 
 pub type DepositAccountAllOrOne = AllOrOne<DepositAccountId>;
 pub type DepositAccountByHolderAllOrOne = AllOrOne<DepositAccountHolderId>;
 pub type DepositAllOrOne = AllOrOne<DepositId>;
 pub type ChartOfAccountsIntegrationConfigAllOrOne = AllOrOne<ChartOfAccountsIntegrationConfigId>;
 pub type WithdrawalAllOrOne = AllOrOne<WithdrawalId>;
+pub type TransferAllOrOne = AllOrOne<TransferId>;
+pub type HoldAllOrOne = AllOrOne<HoldId>;
 
 permission_sets_macro::permission_sets! {
     DepositViewer("Can view deposit accounts, balances, transaction history, and withdrawal details"),
@@ -69,6 +84,8 @@ pub const DEPOSIT_REF_TARGET: public_id::PublicIdTargetType =
     public_id::PublicIdTargetType::new("deposit");
 pub const WITHDRAWAL_REF_TARGET: public_id::PublicIdTargetType =
     public_id::PublicIdTargetType::new("withdrawal");
+pub const TRANSFER_REF_TARGET: public_id::PublicIdTargetType =
+    public_id::PublicIdTargetType::new("transfer");
 
 #[derive(Debug, Clone)]
 pub struct LedgerOmnibusAccountIds {
@@ -84,6 +101,8 @@ pub enum CoreDepositObject {
     Deposit(DepositAllOrOne),
     ChartOfAccountsIntegrationConfig(ChartOfAccountsIntegrationConfigAllOrOne),
     Withdrawal(WithdrawalAllOrOne),
+    Transfer(TransferAllOrOne),
+    Hold(HoldAllOrOne),
 }
 
 impl CoreDepositObject {
@@ -111,6 +130,22 @@ impl CoreDepositObject {
         CoreDepositObject::Withdrawal(AllOrOne::ById(id))
     }
 
+    pub fn all_transfers() -> Self {
+        CoreDepositObject::Transfer(AllOrOne::All)
+    }
+
+    pub fn transfer(id: TransferId) -> Self {
+        CoreDepositObject::Transfer(AllOrOne::ById(id))
+    }
+
+    pub fn all_holds() -> Self {
+        CoreDepositObject::Hold(AllOrOne::All)
+    }
+
+    pub fn hold(id: HoldId) -> Self {
+        CoreDepositObject::Hold(AllOrOne::ById(id))
+    }
+
     pub fn chart_of_accounts_integration() -> Self {
         CoreDepositObject::ChartOfAccountsIntegrationConfig(AllOrOne::All)
     }
@@ -124,6 +159,8 @@ impl Display for CoreDepositObject {
             DepositAccount(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             Deposit(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             Withdrawal(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            Transfer(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            Hold(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             ChartOfAccountsIntegrationConfig(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
         }
     }
@@ -154,6 +191,18 @@ impl FromStr for CoreDepositObject {
                     .map_err(|_| "could not parse CoreDepositObject")?;
                 CoreDepositObject::Withdrawal(obj_ref)
             }
+            Transfer => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::Transfer(obj_ref)
+            }
+            Hold => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::Hold(obj_ref)
+            }
             ChartOfAccountsIntegrationConfig => {
                 let obj_ref = id
                     .parse()
@@ -173,6 +222,8 @@ pub enum CoreDepositAction {
     Deposit(DepositAction),
     ChartOfAccountsIntegrationConfig(ChartOfAccountsIntegrationConfigAction),
     Withdrawal(WithdrawalAction),
+    Transfer(TransferAction),
+    Hold(HoldAction),
 }
 
 impl CoreDepositAction {
@@ -192,6 +243,18 @@ impl CoreDepositAction {
         CoreDepositAction::DepositAccount(DepositAccountAction::Unfreeze);
     pub const DEPOSIT_ACCOUNT_CLOSE: Self =
         CoreDepositAction::DepositAccount(DepositAccountAction::Close);
+    pub const DEPOSIT_ACCOUNT_UPDATE_INTEREST_TERMS: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::UpdateInterestTerms);
+    pub const DEPOSIT_ACCOUNT_BREAK_TERM: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::BreakTerm);
+    pub const DEPOSIT_ACCOUNT_ACCRUE_INTEREST: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::AccrueInterest);
+    pub const DEPOSIT_ACCOUNT_MARK_DORMANT: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::MarkDormant);
+    pub const DEPOSIT_ACCOUNT_REACTIVATE: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::Reactivate);
+    pub const DEPOSIT_ACCOUNT_ESCHEAT: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::Escheat);
 
     pub const DEPOSIT_CREATE: Self = CoreDepositAction::Deposit(DepositAction::Create);
     pub const DEPOSIT_READ: Self = CoreDepositAction::Deposit(DepositAction::Read);
@@ -215,6 +278,22 @@ impl CoreDepositAction {
     pub const WITHDRAWAL_READ: Self = CoreDepositAction::Withdrawal(WithdrawalAction::Read);
     pub const WITHDRAWAL_LIST: Self = CoreDepositAction::Withdrawal(WithdrawalAction::List);
     pub const WITHDRAWAL_REVERT: Self = CoreDepositAction::Withdrawal(WithdrawalAction::Revert);
+    pub const WITHDRAWAL_READ_LIMITS: Self =
+        CoreDepositAction::Withdrawal(WithdrawalAction::ReadLimits);
+    pub const WITHDRAWAL_UPDATE_LIMITS: Self =
+        CoreDepositAction::Withdrawal(WithdrawalAction::UpdateLimits);
+
+    pub const TRANSFER_INITIATE: Self = CoreDepositAction::Transfer(TransferAction::Initiate);
+    pub const TRANSFER_CONCLUDE_APPROVAL_PROCESS: Self =
+        CoreDepositAction::Transfer(TransferAction::ConcludeApprovalProcess);
+    pub const TRANSFER_READ: Self = CoreDepositAction::Transfer(TransferAction::Read);
+    pub const TRANSFER_LIST: Self = CoreDepositAction::Transfer(TransferAction::List);
+
+    pub const HOLD_PLACE: Self = CoreDepositAction::Hold(HoldAction::Place);
+    pub const HOLD_RELEASE: Self = CoreDepositAction::Hold(HoldAction::Release);
+    pub const HOLD_CAPTURE: Self = CoreDepositAction::Hold(HoldAction::Capture);
+    pub const HOLD_READ: Self = CoreDepositAction::Hold(HoldAction::Read);
+    pub const HOLD_LIST: Self = CoreDepositAction::Hold(HoldAction::List);
 
     pub fn actions() -> Vec<ActionMapping> {
         use CoreDepositActionDiscriminants::*;
@@ -233,6 +312,8 @@ impl CoreDepositAction {
                     ChartOfAccountsIntegrationConfigAction
                 ),
                 Withdrawal => map_action!(deposit, Withdrawal, WithdrawalAction),
+                Transfer => map_action!(deposit, Transfer, TransferAction),
+                Hold => map_action!(deposit, Hold, HoldAction),
             })
             .collect()
     }
@@ -247,6 +328,8 @@ impl Display for CoreDepositAction {
             Deposit(action) => action.fmt(f),
             ChartOfAccountsIntegrationConfig(action) => action.fmt(f),
             Withdrawal(action) => action.fmt(f),
+            Transfer(action) => action.fmt(f),
+            Hold(action) => action.fmt(f),
         }
     }
 }
@@ -264,6 +347,8 @@ impl FromStr for CoreDepositAction {
                 CoreDepositAction::from(action.parse::<ChartOfAccountsIntegrationConfigAction>()?)
             }
             Withdrawal => CoreDepositAction::from(action.parse::<WithdrawalAction>()?),
+            Transfer => CoreDepositAction::from(action.parse::<TransferAction>()?),
+            Hold => CoreDepositAction::from(action.parse::<HoldAction>()?),
         };
 
         Ok(res)
@@ -282,6 +367,12 @@ pub enum DepositAccountAction {
     Freeze,
     Unfreeze,
     Close,
+    UpdateInterestTerms,
+    BreakTerm,
+    AccrueInterest,
+    MarkDormant,
+    Reactivate,
+    Escheat,
 }
 
 impl ActionPermission for DepositAccountAction {
@@ -290,7 +381,15 @@ impl ActionPermission for DepositAccountAction {
             Self::Read | Self::List | Self::ReadBalance | Self::ReadTxHistory => {
                 PERMISSION_SET_DEPOSIT_VIEWER
             }
-            Self::Create | Self::UpdateStatus | Self::Close => PERMISSION_SET_DEPOSIT_WRITER,
+            Self::Create
+            | Self::UpdateStatus
+            | Self::Close
+            | Self::UpdateInterestTerms
+            | Self::BreakTerm
+            | Self::AccrueInterest
+            | Self::MarkDormant
+            | Self::Reactivate
+            | Self::Escheat => PERMISSION_SET_DEPOSIT_WRITER,
             Self::Freeze => PERMISSION_SET_DEPOSIT_FREEZE,
             Self::Unfreeze => PERMISSION_SET_DEPOSIT_UNFREEZE,
         }
@@ -337,17 +436,20 @@ pub enum WithdrawalAction {
     Read,
     List,
     Revert,
+    ReadLimits,
+    UpdateLimits,
 }
 
 impl ActionPermission for WithdrawalAction {
     fn permission_set(&self) -> &'static str {
         match self {
-            Self::Read | Self::List => PERMISSION_SET_DEPOSIT_VIEWER,
+            Self::Read | Self::List | Self::ReadLimits => PERMISSION_SET_DEPOSIT_VIEWER,
             Self::Cancel
             | Self::Initiate
             | Self::ConcludeApprovalProcess
             | Self::Confirm
-            | Self::Revert => PERMISSION_SET_DEPOSIT_WRITER,
+            | Self::Revert
+            | Self::UpdateLimits => PERMISSION_SET_DEPOSIT_WRITER,
         }
     }
 }
@@ -358,6 +460,55 @@ impl From<WithdrawalAction> for CoreDepositAction {
     }
 }
 
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum TransferAction {
+    Initiate,
+    ConcludeApprovalProcess,
+    Read,
+    List,
+}
+
+impl ActionPermission for TransferAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::Read | Self::List => PERMISSION_SET_DEPOSIT_VIEWER,
+            Self::Initiate | Self::ConcludeApprovalProcess => PERMISSION_SET_DEPOSIT_WRITER,
+        }
+    }
+}
+
+impl From<TransferAction> for CoreDepositAction {
+    fn from(action: TransferAction) -> Self {
+        CoreDepositAction::Transfer(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum HoldAction {
+    Place,
+    Release,
+    Capture,
+    Read,
+    List,
+}
+
+impl ActionPermission for HoldAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::Read | Self::List => PERMISSION_SET_DEPOSIT_VIEWER,
+            Self::Place | Self::Release | Self::Capture => PERMISSION_SET_DEPOSIT_WRITER,
+        }
+    }
+}
+
+impl From<HoldAction> for CoreDepositAction {
+    fn from(action: HoldAction) -> Self {
+        CoreDepositAction::Hold(action)
+    }
+}
+
 #[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
 #[strum(serialize_all = "kebab-case")]
 pub enum ChartOfAccountsIntegrationConfigAction {
@@ -389,6 +540,65 @@ pub enum DepositAccountStatus {
     Active,
     Frozen,
     Closed,
+    Dormant,
+    Escheated,
+}
+
+/// Interest configuration of a deposit account. Interest accrues daily on the
+/// settled balance (actual/365) and is capitalized into the account at month end.
+/// When a `maturity_date` is set the account is a term deposit: funds are locked
+/// until maturity and breaking the term early forfeits uncapitalized interest and
+/// charges `early_break_penalty_rate` percent of the balance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct DepositAccountInterestTerms {
+    pub annual_rate: rust_decimal::Decimal,
+    pub maturity_date: Option<chrono::NaiveDate>,
+    pub early_break_penalty_rate: Option<rust_decimal::Decimal>,
+}
+
+impl DepositAccountInterestTerms {
+    pub fn savings(annual_rate: rust_decimal::Decimal) -> Self {
+        Self {
+            annual_rate,
+            maturity_date: None,
+            early_break_penalty_rate: None,
+        }
+    }
+
+    pub fn term_deposit(
+        annual_rate: rust_decimal::Decimal,
+        maturity_date: chrono::NaiveDate,
+        early_break_penalty_rate: Option<rust_decimal::Decimal>,
+    ) -> Self {
+        Self {
+            annual_rate,
+            maturity_date: Some(maturity_date),
+            early_break_penalty_rate,
+        }
+    }
+
+    pub fn is_term_deposit(&self) -> bool {
+        self.maturity_date.is_some()
+    }
+
+    pub(crate) fn daily_interest(&self, balance: UsdCents) -> rust_decimal::Decimal {
+        rust_decimal::Decimal::from(balance.into_inner()) * self.annual_rate
+            / rust_decimal::Decimal::ONE_HUNDRED
+            / rust_decimal::Decimal::from(365)
+    }
+
+    pub(crate) fn early_break_penalty(&self, balance: UsdCents) -> UsdCents {
+        let rate = self.early_break_penalty_rate.unwrap_or_default();
+        let penalty = (rust_decimal::Decimal::from(balance.into_inner()) * rate
+            / rust_decimal::Decimal::ONE_HUNDRED)
+            .floor();
+        UsdCents::from(
+            u64::try_from(penalty)
+                .expect("penalty should fit in u64")
+                .min(balance.into_inner()),
+        )
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -430,6 +640,26 @@ impl From<CustomerType> for DepositAccountType {
     }
 }
 
+/// The product an account is held under, derived from its interest terms.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum DepositProduct {
+    Current,
+    Savings,
+    TermDeposit,
+}
+
+impl From<Option<&DepositAccountInterestTerms>> for DepositProduct {
+    fn from(terms: Option<&DepositAccountInterestTerms>) -> Self {
+        match terms {
+            None => DepositProduct::Current,
+            Some(terms) if terms.is_term_deposit() => DepositProduct::TermDeposit,
+            Some(_) => DepositProduct::Savings,
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
 #[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
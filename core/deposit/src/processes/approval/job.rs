@@ -57,7 +57,8 @@ where
         event: &PersistentOutboxEvent<E>,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         if let Some(e @ GovernanceEvent::ApprovalProcessConcluded { entity }) = event.as_event()
-            && entity.process_type == super::APPROVE_WITHDRAWAL_PROCESS
+            && (entity.process_type == super::APPROVE_WITHDRAWAL_PROCESS
+                || entity.process_type == super::APPROVE_WITHDRAWAL_OVER_LIMIT_PROCESS)
         {
             event.inject_trace_parent();
             Span::current().record("handled", true);
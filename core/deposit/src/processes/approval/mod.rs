@@ -20,6 +20,10 @@ use crate::{
 pub use job::*;
 
 pub const APPROVE_WITHDRAWAL_PROCESS: ApprovalProcessType = ApprovalProcessType::new("withdraw");
+/// Withdrawals that would breach a configured withdrawal limit go through this
+/// process instead, so that they can be routed to a different committee.
+pub const APPROVE_WITHDRAWAL_OVER_LIMIT_PROCESS: ApprovalProcessType =
+    ApprovalProcessType::new("withdraw-over-limit");
 
 pub struct ApproveWithdrawal<Perms, E>
 where
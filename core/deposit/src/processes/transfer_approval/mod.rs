@@ -0,0 +1,152 @@
+mod job;
+
+use authz::PermissionCheck;
+use governance::{ApprovalProcessType, GovernanceAction, GovernanceEvent, GovernanceObject};
+use tracing::instrument;
+use tracing_macros::record_error_severity;
+
+use audit::{AuditSvc, SystemSubject};
+use governance::Governance;
+use obix::out::OutboxEventMarker;
+
+use crate::{
+    CoreDepositAction, CoreDepositObject, TransferAction,
+    account::DepositAccountRepo,
+    ledger::DepositLedger,
+    primitives::{DepositAccountStatus, TransferId},
+    public::CoreDepositEvent,
+    transfer::{Transfer, error::TransferError, repo::TransferRepo},
+};
+
+pub use job::*;
+
+pub const APPROVE_TRANSFER_PROCESS: ApprovalProcessType =
+    ApprovalProcessType::new("deposit-transfer");
+
+pub struct ApproveTransfer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    repo: TransferRepo<E>,
+    deposit_accounts: DepositAccountRepo<E>,
+    audit: Perms::Audit,
+    governance: Governance<Perms, E>,
+    ledger: DepositLedger,
+}
+impl<Perms, E> Clone for ApproveTransfer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            repo: self.repo.clone(),
+            deposit_accounts: self.deposit_accounts.clone(),
+            audit: self.audit.clone(),
+            governance: self.governance.clone(),
+            ledger: self.ledger.clone(),
+        }
+    }
+}
+
+impl<Perms, E> ApproveTransfer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(
+        repo: &TransferRepo<E>,
+        deposit_accounts: &DepositAccountRepo<E>,
+        audit: &Perms::Audit,
+        governance: &Governance<Perms, E>,
+        ledger: &DepositLedger,
+    ) -> Self {
+        Self {
+            repo: repo.clone(),
+            deposit_accounts: deposit_accounts.clone(),
+            audit: audit.clone(),
+            governance: governance.clone(),
+            ledger: ledger.clone(),
+        }
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "core_deposit.transfer_approval.execute", skip(self))]
+    #[es_entity::retry_on_concurrent_modification]
+    pub async fn execute_transfer_approval(
+        &self,
+        id: impl es_entity::RetryableInto<TransferId>,
+        approved: bool,
+    ) -> Result<Transfer, TransferError> {
+        let id = id.into();
+        let mut transfer = self.repo.find_by_id(id).await?;
+        if transfer.is_approved_or_denied().is_some() {
+            return Ok(transfer);
+        }
+
+        // A transfer approved after the destination stopped accepting funds is
+        // returned to the source instead of being credited.
+        let destination = self
+            .deposit_accounts
+            .find_by_id(transfer.destination_account_id)
+            .await?;
+        let approved = approved
+            && matches!(
+                destination.status,
+                DepositAccountStatus::Active | DepositAccountStatus::Dormant
+            );
+
+        let mut op = self.repo.begin_op().await?;
+        self.audit
+            .record_system_entry_in_op(
+                &mut op,
+                crate::primitives::DEPOSIT_APPROVAL,
+                CoreDepositObject::transfer(id),
+                CoreDepositAction::Transfer(TransferAction::ConcludeApprovalProcess),
+            )
+            .await?;
+        let subject = <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(
+            crate::primitives::DEPOSIT_APPROVAL,
+        );
+        match transfer.approval_process_concluded(approved)? {
+            es_entity::Idempotent::Executed(Some(denied_tx_id)) => {
+                self.repo.update_in_op(&mut op, &mut transfer).await?;
+                self.ledger
+                    .deny_transfer_in_op(
+                        &mut op,
+                        transfer.id,
+                        denied_tx_id,
+                        transfer.amount,
+                        transfer.source_account_id,
+                        &subject,
+                    )
+                    .await?;
+                op.commit().await?;
+            }
+            es_entity::Idempotent::Executed(None) => {
+                if let es_entity::Idempotent::Executed(completed_tx_id) = transfer.complete()? {
+                    self.repo.update_in_op(&mut op, &mut transfer).await?;
+                    self.ledger
+                        .complete_transfer_in_op(
+                            &mut op,
+                            transfer.id,
+                            completed_tx_id,
+                            transfer.amount,
+                            transfer.source_account_id,
+                            transfer.destination_account_id,
+                            &subject,
+                        )
+                        .await?;
+                }
+                op.commit().await?;
+            }
+            _ => (),
+        };
+        Ok(transfer)
+    }
+}
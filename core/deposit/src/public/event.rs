@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "json-schema")]
 use schemars::JsonSchema;
 
-use super::{PublicDeposit, PublicDepositAccount, PublicWithdrawal};
+use super::{PublicDeposit, PublicDepositAccount, PublicTransfer, PublicWithdrawal};
 
 #[derive(Debug, Serialize, Deserialize, strum::AsRefStr)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
@@ -13,4 +13,5 @@ pub enum CoreDepositEvent {
     DepositInitialized { entity: PublicDeposit },
     WithdrawalConfirmed { entity: PublicWithdrawal },
     DepositReverted { entity: PublicDeposit },
+    TransferCompleted { entity: PublicTransfer },
 }
@@ -1,9 +1,11 @@
 mod deposit;
 mod deposit_account;
 mod event;
+mod transfer;
 mod withdrawal;
 
 pub use deposit::*;
 pub use deposit_account::*;
 pub use event::*;
+pub use transfer::*;
 pub use withdrawal::*;
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+
+use crate::{
+    primitives::{DepositAccountId, TransferId, UsdCents},
+    transfer::Transfer,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct PublicTransfer {
+    pub id: TransferId,
+    pub source_account_id: DepositAccountId,
+    pub destination_account_id: DepositAccountId,
+    pub amount: UsdCents,
+}
+
+impl From<&Transfer> for PublicTransfer {
+    fn from(entity: &Transfer) -> Self {
+        PublicTransfer {
+            id: entity.id,
+            source_account_id: entity.source_account_id,
+            destination_account_id: entity.destination_account_id,
+            amount: entity.amount,
+        }
+    }
+}
@@ -1,9 +1,10 @@
 use obix::out::{Outbox, OutboxEventMarker};
 
 use crate::{
-    CoreDepositEvent, PublicDeposit, PublicDepositAccount, PublicWithdrawal,
+    CoreDepositEvent, PublicDeposit, PublicDepositAccount, PublicTransfer, PublicWithdrawal,
     account::{DepositAccount, DepositAccountEvent, error::DepositAccountError},
     deposit::{Deposit, DepositEvent, error::DepositError},
+    transfer::{Transfer, TransferEvent, error::TransferError},
     withdrawal::{Withdrawal, WithdrawalEvent, error::WithdrawalError},
 };
 
@@ -77,6 +78,27 @@ where
         Ok(())
     }
 
+    pub async fn publish_transfer_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        entity: &Transfer,
+        new_events: es_entity::LastPersisted<'_, TransferEvent>,
+    ) -> Result<(), TransferError> {
+        use TransferEvent::*;
+        let publish_events = new_events
+            .filter_map(|event| match &event.event {
+                Completed { .. } => Some(CoreDepositEvent::TransferCompleted {
+                    entity: PublicTransfer::from(entity),
+                }),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        self.outbox
+            .publish_all_persisted(op, publish_events)
+            .await?;
+        Ok(())
+    }
+
     pub async fn publish_deposit_in_op(
         &self,
         op: &mut impl es_entity::AtomicOperation,
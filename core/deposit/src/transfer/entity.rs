@@ -0,0 +1,397 @@
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::primitives::{
+    ApprovalProcessId, CalaTransactionId, DepositAccountId, PublicId, TransferId, UsdCents,
+};
+
+use super::error::TransferError;
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum TransferStatus {
+    Initiated,
+    PendingApproval,
+    Approved,
+    Completed,
+    Denied,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "TransferId")]
+pub enum TransferEvent {
+    Initialized {
+        id: TransferId,
+        ledger_tx_id: CalaTransactionId,
+        source_account_id: DepositAccountId,
+        destination_account_id: DepositAccountId,
+        amount: UsdCents,
+        reference: String,
+        approval_process_id: Option<ApprovalProcessId>,
+        status: TransferStatus,
+        public_id: PublicId,
+    },
+    ApprovalProcessConcluded {
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        status: TransferStatus,
+    },
+    Denied {
+        ledger_tx_id: CalaTransactionId,
+        status: TransferStatus,
+    },
+    Completed {
+        ledger_tx_id: CalaTransactionId,
+        status: TransferStatus,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct Transfer {
+    pub id: TransferId,
+    pub source_account_id: DepositAccountId,
+    pub destination_account_id: DepositAccountId,
+    pub reference: String,
+    pub amount: UsdCents,
+    #[builder(default)]
+    pub approval_process_id: Option<ApprovalProcessId>,
+    pub public_id: PublicId,
+    #[builder(setter(strip_option), default)]
+    pub completed_tx_id: Option<CalaTransactionId>,
+    #[builder(setter(strip_option), default)]
+    pub denied_tx_id: Option<CalaTransactionId>,
+
+    events: EntityEvents<TransferEvent>,
+}
+
+impl Transfer {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for transfer")
+    }
+
+    pub fn requires_approval(&self) -> bool {
+        self.approval_process_id.is_some()
+    }
+
+    pub fn complete(&mut self) -> Result<Idempotent<CalaTransactionId>, TransferError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            TransferEvent::Completed { .. }
+        );
+
+        if self.denied_tx_id.is_some() {
+            return Err(TransferError::AlreadyDenied(self.id));
+        }
+
+        if self.requires_approval() && self.is_approved_or_denied() != Some(true) {
+            return Err(TransferError::NotApproved(self.id));
+        }
+
+        let ledger_tx_id = CalaTransactionId::new();
+        self.events.push(TransferEvent::Completed {
+            ledger_tx_id,
+            status: TransferStatus::Completed,
+        });
+        self.completed_tx_id = Some(ledger_tx_id);
+
+        Ok(Idempotent::Executed(ledger_tx_id))
+    }
+
+    pub fn approval_process_concluded(
+        &mut self,
+        approved: bool,
+    ) -> Result<Idempotent<Option<CalaTransactionId>>, TransferError> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            TransferEvent::ApprovalProcessConcluded { .. }
+        );
+
+        let Some(approval_process_id) = self.approval_process_id else {
+            return Err(TransferError::NotApproved(self.id));
+        };
+        if self.completed_tx_id.is_some() {
+            return Err(TransferError::AlreadyCompleted(self.id));
+        }
+
+        let status = if approved {
+            TransferStatus::Approved
+        } else {
+            TransferStatus::Denied
+        };
+        self.events.push(TransferEvent::ApprovalProcessConcluded {
+            approval_process_id,
+            approved,
+            status,
+        });
+
+        if approved {
+            return Ok(Idempotent::Executed(None));
+        }
+
+        let ledger_tx_id = CalaTransactionId::new();
+        self.events.push(TransferEvent::Denied {
+            ledger_tx_id,
+            status,
+        });
+        self.denied_tx_id = Some(ledger_tx_id);
+
+        Ok(Idempotent::Executed(Some(ledger_tx_id)))
+    }
+
+    pub fn is_approved_or_denied(&self) -> Option<bool> {
+        self.events.iter_all().find_map(|e| {
+            if let TransferEvent::ApprovalProcessConcluded { approved, .. } = e {
+                Some(*approved)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn status(&self) -> TransferStatus {
+        self.events
+            .iter_all()
+            .rev()
+            .map(|e| match e {
+                TransferEvent::Completed { status, .. } => *status,
+                TransferEvent::Denied { status, .. } => *status,
+                TransferEvent::ApprovalProcessConcluded { status, .. } => *status,
+                TransferEvent::Initialized { status, .. } => *status,
+            })
+            .next()
+            .expect("status should always exist")
+    }
+
+    pub fn ledger_tx_ids(&self) -> Vec<CalaTransactionId> {
+        self.events
+            .iter_all()
+            .filter_map(|e| match e {
+                TransferEvent::Initialized { ledger_tx_id, .. } => Some(*ledger_tx_id),
+                TransferEvent::Denied { ledger_tx_id, .. } => Some(*ledger_tx_id),
+                TransferEvent::Completed { ledger_tx_id, .. } => Some(*ledger_tx_id),
+                TransferEvent::ApprovalProcessConcluded { .. } => None,
+            })
+            .collect()
+    }
+}
+
+impl TryFromEvents<TransferEvent> for Transfer {
+    fn try_from_events(events: EntityEvents<TransferEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = TransferBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                TransferEvent::Initialized {
+                    id,
+                    reference,
+                    source_account_id,
+                    destination_account_id,
+                    amount,
+                    approval_process_id,
+                    public_id,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .source_account_id(*source_account_id)
+                        .destination_account_id(*destination_account_id)
+                        .amount(*amount)
+                        .reference(reference.clone())
+                        .approval_process_id(*approval_process_id)
+                        .public_id(public_id.clone())
+                }
+                TransferEvent::Completed { ledger_tx_id, .. } => {
+                    builder = builder.completed_tx_id(*ledger_tx_id)
+                }
+                TransferEvent::Denied { ledger_tx_id, .. } => {
+                    builder = builder.denied_tx_id(*ledger_tx_id)
+                }
+                _ => (),
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct NewTransfer {
+    #[builder(setter(into))]
+    pub(super) id: TransferId,
+    #[builder(setter(into))]
+    pub(super) source_account_id: DepositAccountId,
+    #[builder(setter(into))]
+    pub(super) destination_account_id: DepositAccountId,
+    #[builder(setter(into))]
+    pub(super) amount: UsdCents,
+    #[builder(default)]
+    pub(super) approval_process_id: Option<ApprovalProcessId>,
+    #[builder(setter(into))]
+    pub(super) public_id: PublicId,
+    reference: Option<String>,
+}
+
+impl NewTransfer {
+    pub fn builder() -> NewTransferBuilder {
+        NewTransferBuilder::default()
+    }
+
+    pub(super) fn reference(&self) -> String {
+        match self.reference.as_deref() {
+            None => self.id.to_string(),
+            Some("") => self.id.to_string(),
+            Some(reference) => reference.to_string(),
+        }
+    }
+}
+
+impl NewTransferBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(amount) = self.amount
+            && amount.is_zero()
+        {
+            return Err("Transfer amount cannot be zero".to_string());
+        }
+        if let (Some(source), Some(destination)) =
+            (self.source_account_id, self.destination_account_id)
+            && source == destination
+        {
+            return Err("Transfer source and destination must differ".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl IntoEvents<TransferEvent> for NewTransfer {
+    fn into_events(self) -> EntityEvents<TransferEvent> {
+        let status = if self.approval_process_id.is_some() {
+            TransferStatus::PendingApproval
+        } else {
+            TransferStatus::Initiated
+        };
+        EntityEvents::init(
+            self.id,
+            [TransferEvent::Initialized {
+                reference: self.reference(),
+                id: self.id,
+                ledger_tx_id: self.id.into(),
+                source_account_id: self.source_account_id,
+                destination_account_id: self.destination_account_id,
+                amount: self.amount,
+                approval_process_id: self.approval_process_id,
+                status,
+                public_id: self.public_id,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn new_transfer(approval_process_id: Option<ApprovalProcessId>) -> Transfer {
+        let new_transfer = NewTransfer::builder()
+            .id(TransferId::new())
+            .source_account_id(DepositAccountId::new())
+            .destination_account_id(DepositAccountId::new())
+            .amount(UsdCents::ONE)
+            .reference(None)
+            .approval_process_id(approval_process_id)
+            .public_id(PublicId::new("test-public-id"))
+            .build()
+            .unwrap();
+
+        Transfer::try_from_events(new_transfer.into_events()).unwrap()
+    }
+
+    #[test]
+    fn errors_when_zero_amount_is_passed() {
+        let transfer = NewTransfer::builder()
+            .id(TransferId::new())
+            .source_account_id(DepositAccountId::new())
+            .destination_account_id(DepositAccountId::new())
+            .amount(UsdCents::ZERO)
+            .reference(None)
+            .public_id(PublicId::new("test-public-id"))
+            .build();
+
+        assert!(matches!(
+            transfer,
+            Err(NewTransferBuilderError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn errors_when_source_and_destination_match() {
+        let account_id = DepositAccountId::new();
+        let transfer = NewTransfer::builder()
+            .id(TransferId::new())
+            .source_account_id(account_id)
+            .destination_account_id(account_id)
+            .amount(UsdCents::ONE)
+            .reference(None)
+            .public_id(PublicId::new("test-public-id"))
+            .build();
+
+        assert!(matches!(
+            transfer,
+            Err(NewTransferBuilderError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn completes_immediately_without_approval() {
+        let mut transfer = new_transfer(None);
+        assert_eq!(transfer.status(), TransferStatus::Initiated);
+
+        let tx_id = transfer.complete().unwrap().unwrap();
+
+        assert_eq!(transfer.status(), TransferStatus::Completed);
+        assert_eq!(transfer.completed_tx_id, Some(tx_id));
+        assert!(transfer.complete().unwrap().was_already_applied());
+    }
+
+    #[test]
+    fn cannot_complete_before_approval() {
+        let mut transfer = new_transfer(Some(ApprovalProcessId::new()));
+        assert_eq!(transfer.status(), TransferStatus::PendingApproval);
+
+        let result = transfer.complete();
+
+        assert!(matches!(result, Err(TransferError::NotApproved(_))));
+    }
+
+    #[test]
+    fn completes_after_approval() {
+        let mut transfer = new_transfer(Some(ApprovalProcessId::new()));
+        let denied_tx_id = transfer.approval_process_concluded(true).unwrap().unwrap();
+        assert!(denied_tx_id.is_none());
+        assert_eq!(transfer.status(), TransferStatus::Approved);
+
+        assert!(transfer.complete().is_ok());
+        assert_eq!(transfer.status(), TransferStatus::Completed);
+    }
+
+    #[test]
+    fn denied_transfer_cannot_complete() {
+        let mut transfer = new_transfer(Some(ApprovalProcessId::new()));
+        let denied_tx_id = transfer.approval_process_concluded(false).unwrap().unwrap();
+        assert!(denied_tx_id.is_some());
+        assert_eq!(transfer.denied_tx_id, denied_tx_id);
+        assert_eq!(transfer.status(), TransferStatus::Denied);
+
+        let result = transfer.complete();
+
+        assert!(matches!(result, Err(TransferError::AlreadyDenied(_))));
+    }
+}
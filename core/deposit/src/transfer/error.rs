@@ -0,0 +1,45 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+use crate::primitives::TransferId;
+
+#[derive(Error, Debug)]
+pub enum TransferError {
+    #[error("TransferError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("TransferError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("TransferError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("TransferError - DepositLedgerError: {0}")]
+    DepositLedgerError(#[from] crate::ledger::error::DepositLedgerError),
+    #[error("TransferError - DepositAccountError: {0}")]
+    DepositAccountError(#[from] crate::account::error::DepositAccountError),
+    #[error("TransferError - AuditError: {0}")]
+    AuditError(#[from] audit::error::AuditError),
+    #[error("TransferError - NotApproved: {0}")]
+    NotApproved(TransferId),
+    #[error("TransferError - AlreadyDenied: {0}")]
+    AlreadyDenied(TransferId),
+    #[error("TransferError - AlreadyCompleted: {0}")]
+    AlreadyCompleted(TransferId),
+}
+
+es_entity::from_es_entity_error!(TransferError);
+
+impl ErrorSeverity for TransferError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::Sqlx(_) => Level::ERROR,
+            Self::EsEntityError(e) => e.severity(),
+            Self::CursorDestructureError(_) => Level::ERROR,
+            Self::DepositLedgerError(_) => Level::ERROR,
+            Self::DepositAccountError(e) => e.severity(),
+            Self::AuditError(e) => e.severity(),
+            Self::NotApproved(_) => Level::WARN,
+            Self::AlreadyDenied(_) => Level::WARN,
+            Self::AlreadyCompleted(_) => Level::WARN,
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use es_entity::clock::ClockHandle;
+use sqlx::PgPool;
+
+use es_entity::*;
+use obix::out::OutboxEventMarker;
+use tracing_macros::record_error_severity;
+
+use crate::{
+    primitives::{ApprovalProcessId, CalaTransactionId, DepositAccountId, PublicId, TransferId},
+    public::CoreDepositEvent,
+    publisher::DepositPublisher,
+};
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo)]
+#[es_repo(
+    entity = "Transfer",
+    err = "TransferError",
+    columns(
+        source_account_id(
+            ty = "DepositAccountId",
+            list_for(by(created_at)),
+            update(persist = false)
+        ),
+        destination_account_id(
+            ty = "DepositAccountId",
+            list_for(by(created_at)),
+            update(persist = false)
+        ),
+        approval_process_id(ty = "Option<ApprovalProcessId>", update(persist = false)),
+        completed_tx_id(ty = "Option<CalaTransactionId>", create(persist = false)),
+        denied_tx_id(ty = "Option<CalaTransactionId>", create(persist = false)),
+        reference(ty = "String", create(accessor = "reference()")),
+        public_id(ty = "PublicId", list_by)
+    ),
+    tbl_prefix = "core",
+    post_persist_hook = "publish_in_op"
+)]
+pub struct TransferRepo<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    publisher: DepositPublisher<E>,
+    pool: PgPool,
+    clock: ClockHandle,
+}
+
+impl<E> Clone for TransferRepo<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            publisher: self.publisher.clone(),
+            pool: self.pool.clone(),
+            clock: self.clock.clone(),
+        }
+    }
+}
+
+impl<E> TransferRepo<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(pool: &PgPool, publisher: &DepositPublisher<E>, clock: ClockHandle) -> Self {
+        Self {
+            pool: pool.clone(),
+            publisher: publisher.clone(),
+            clock,
+        }
+    }
+
+    /// Transfers into or out of `account_id`, newest first.
+    #[record_error_severity]
+    #[tracing::instrument(name = "transfer.list_for_account_id_by_created_at", skip(self))]
+    pub async fn list_for_account_id_by_created_at(
+        &self,
+        account_id: DepositAccountId,
+        query: es_entity::PaginatedQueryArgs<transfer_cursor::TransfersByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<Transfer, transfer_cursor::TransfersByCreatedAtCursor>,
+        TransferError,
+    > {
+        let es_entity::PaginatedQueryArgs { first, after } = query;
+        let (after_id, after_created_at) = match after {
+            Some(cursor) => (Some(cursor.id), Some(cursor.created_at)),
+            None => (None, None),
+        };
+        let (entities, has_next_page) = es_query!(
+            tbl_prefix = "core",
+            r#"
+                SELECT created_at, id FROM core_transfers
+                WHERE (source_account_id = $1 OR destination_account_id = $1)
+                AND COALESCE((created_at, id) < ($3, $2), $2 IS NULL)
+                ORDER BY created_at DESC, id DESC
+                LIMIT $4"#,
+            account_id as DepositAccountId,
+            after_id as Option<TransferId>,
+            after_created_at as Option<chrono::DateTime<chrono::Utc>>,
+            (first + 1) as i64,
+        )
+        .fetch_n(self.pool(), first)
+        .await?;
+
+        let end_cursor = entities
+            .last()
+            .map(transfer_cursor::TransfersByCreatedAtCursor::from);
+        Ok(es_entity::PaginatedQueryRet {
+            entities,
+            has_next_page,
+            end_cursor,
+        })
+    }
+
+    async fn publish_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        entity: &Transfer,
+        new_events: es_entity::LastPersisted<'_, TransferEvent>,
+    ) -> Result<(), TransferError> {
+        self.publisher
+            .publish_transfer_in_op(op, entity, new_events)
+            .await
+    }
+}
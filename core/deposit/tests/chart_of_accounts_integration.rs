@@ -121,7 +121,7 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
     )
     .await?;
 
-    let accounting = CoreAccounting::new(
+    let accounting = CoreAccounting::init(
         &pool,
         &authz,
         &cala,
@@ -129,7 +129,9 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         document_storage,
         &mut jobs,
         &outbox,
-    );
+        &governance,
+    )
+    .await?;
     let chart_ref = format!("ref-{:010}", rand::rng().random_range(0..10_000_000_000u64));
     let chart_id = accounting
         .chart_of_accounts()
@@ -182,6 +184,8 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         chart_of_account_frozen_non_domiciled_company_deposit_accounts_parent_code: "27"
             .parse()
             .unwrap(),
+        chart_of_accounts_interest_expense_parent_code: None,
+        chart_of_accounts_unclaimed_funds_parent_code: None,
     };
 
     deposit
@@ -340,6 +344,8 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         chart_of_account_frozen_non_domiciled_company_deposit_accounts_parent_code: "27"
             .parse()
             .unwrap(),
+        chart_of_accounts_interest_expense_parent_code: None,
+        chart_of_accounts_unclaimed_funds_parent_code: None,
     };
     let res = deposit
         .chart_of_accounts_integrations()
@@ -0,0 +1,217 @@
+mod helpers;
+
+use rust_decimal_macros::dec;
+use uuid::Uuid;
+
+use authz::dummy::DummySubject;
+use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use cloud_storage::{Storage, config::StorageConfig};
+use core_customer::{CustomerType, Customers};
+use core_deposit::*;
+use document_storage::DocumentStorage;
+use es_entity::clock::{ArtificialClockConfig, ClockHandle};
+
+use helpers::{action, event, object};
+
+#[tokio::test]
+async fn dormant_account_lifecycle() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let (clock, _) = ClockHandle::artificial(ArtificialClockConfig::manual());
+
+    let outbox =
+        obix::Outbox::<event::DummyEvent>::init(&pool, obix::MailboxConfig::builder().build()?)
+            .await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox, clock.clone());
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let mut jobs = job::Jobs::init(
+        job::JobSvcConfig::builder()
+            .pool(pool.clone())
+            .build()
+            .unwrap(),
+    )
+    .await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let document_storage = DocumentStorage::new(&pool, &storage, clock.clone());
+    let journal_id = helpers::init_journal(&cala).await?;
+    let public_ids = public_id::PublicIds::new(&pool);
+
+    let customers = Customers::new(
+        &pool,
+        &authz,
+        &outbox,
+        document_storage.clone(),
+        public_ids.clone(),
+        clock.clone(),
+    );
+
+    let exposed_domain_configs =
+        helpers::init_read_only_exposed_domain_configs(&pool, &authz).await?;
+    let internal_domain_configs = helpers::init_internal_domain_configs(&pool).await?;
+
+    let deposit = CoreDeposit::init(
+        &pool,
+        &authz,
+        &outbox,
+        &governance,
+        &mut jobs,
+        &cala,
+        journal_id,
+        &public_ids,
+        &customers,
+        &exposed_domain_configs,
+        &internal_domain_configs,
+    )
+    .await?;
+
+    let customer = customers
+        .create_customer_bypassing_kyc(
+            &DummySubject,
+            format!("user{}@example.com", Uuid::new_v4()),
+            format!("telegram{}", Uuid::new_v4()),
+            CustomerType::Individual,
+        )
+        .await?;
+    let account = deposit.create_account(&DummySubject, customer.id).await?;
+
+    let deposit_amount = UsdCents::try_from_usd(dec!(1000)).unwrap();
+    deposit
+        .record_deposit(&DummySubject, account.id, deposit_amount, None)
+        .await?;
+
+    deposit
+        .mark_accounts_dormant_for_holder(customer.id)
+        .await?;
+    let account = deposit
+        .find_account_by_id(&DummySubject, account.id)
+        .await?
+        .expect("account exists");
+    assert_eq!(account.status, DepositAccountStatus::Dormant);
+
+    // outgoing funds are blocked while dormant
+    let withdrawal_amount = UsdCents::try_from_usd(dec!(100)).unwrap();
+    let res = deposit
+        .initiate_withdrawal(&DummySubject, account.id, withdrawal_amount, None)
+        .await;
+    assert!(matches!(
+        res,
+        Err(core_deposit::error::CoreDepositError::DepositAccountDormant)
+    ));
+
+    // incoming funds are still accepted
+    deposit
+        .record_deposit(&DummySubject, account.id, deposit_amount, None)
+        .await?;
+
+    let account = deposit
+        .reactivate_account(&DummySubject, account.id)
+        .await?;
+    assert_eq!(account.status, DepositAccountStatus::Active);
+    deposit
+        .initiate_withdrawal(&DummySubject, account.id, withdrawal_amount, None)
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn escheat_unclaimed_account() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let (clock, _) = ClockHandle::artificial(ArtificialClockConfig::manual());
+
+    let outbox =
+        obix::Outbox::<event::DummyEvent>::init(&pool, obix::MailboxConfig::builder().build()?)
+            .await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox, clock.clone());
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let mut jobs = job::Jobs::init(
+        job::JobSvcConfig::builder()
+            .pool(pool.clone())
+            .build()
+            .unwrap(),
+    )
+    .await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let document_storage = DocumentStorage::new(&pool, &storage, clock.clone());
+    let journal_id = helpers::init_journal(&cala).await?;
+    let public_ids = public_id::PublicIds::new(&pool);
+
+    let customers = Customers::new(
+        &pool,
+        &authz,
+        &outbox,
+        document_storage.clone(),
+        public_ids.clone(),
+        clock.clone(),
+    );
+
+    let exposed_domain_configs =
+        helpers::init_read_only_exposed_domain_configs(&pool, &authz).await?;
+    let internal_domain_configs = helpers::init_internal_domain_configs(&pool).await?;
+
+    let deposit = CoreDeposit::init(
+        &pool,
+        &authz,
+        &outbox,
+        &governance,
+        &mut jobs,
+        &cala,
+        journal_id,
+        &public_ids,
+        &customers,
+        &exposed_domain_configs,
+        &internal_domain_configs,
+    )
+    .await?;
+
+    let customer = customers
+        .create_customer_bypassing_kyc(
+            &DummySubject,
+            format!("user{}@example.com", Uuid::new_v4()),
+            format!("telegram{}", Uuid::new_v4()),
+            CustomerType::Individual,
+        )
+        .await?;
+    let account = deposit.create_account(&DummySubject, customer.id).await?;
+
+    let deposit_amount = UsdCents::try_from_usd(dec!(1000)).unwrap();
+    deposit
+        .record_deposit(&DummySubject, account.id, deposit_amount, None)
+        .await?;
+
+    deposit
+        .mark_accounts_dormant_for_holder(customer.id)
+        .await?;
+    deposit.escheat_accounts_for_holder(customer.id).await?;
+
+    let account = deposit
+        .find_account_by_id(&DummySubject, account.id)
+        .await?
+        .expect("account exists");
+    assert_eq!(account.status, DepositAccountStatus::Escheated);
+
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert_eq!(balance.settled, UsdCents::ZERO);
+
+    // escheated accounts cannot be reactivated
+    let res = deposit.reactivate_account(&DummySubject, account.id).await;
+    assert!(res.is_err());
+
+    // repeated runs are idempotent
+    deposit.escheat_accounts_for_holder(customer.id).await?;
+
+    Ok(())
+}
@@ -0,0 +1,217 @@
+mod helpers;
+
+use rust_decimal_macros::dec;
+use uuid::Uuid;
+
+use authz::dummy::DummySubject;
+use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use cloud_storage::{Storage, config::StorageConfig};
+use core_customer::{CustomerType, Customers};
+use core_deposit::*;
+use document_storage::DocumentStorage;
+use es_entity::clock::{ArtificialClockConfig, ClockHandle};
+
+use helpers::{action, event, object};
+
+#[tokio::test]
+async fn holds_reduce_available_balance() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let (clock, _) = ClockHandle::artificial(ArtificialClockConfig::manual());
+
+    let outbox = obix::Outbox::<event::DummyEvent>::init(
+        &pool,
+        obix::MailboxConfig::builder()
+            .clock(clock.clone())
+            .build()?,
+    )
+    .await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox, clock.clone());
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let mut jobs = job::Jobs::init(
+        job::JobSvcConfig::builder()
+            .pool(pool.clone())
+            .clock(clock.clone())
+            .build()
+            .unwrap(),
+    )
+    .await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let document_storage = DocumentStorage::new(&pool, &storage, clock.clone());
+    let journal_id = helpers::init_journal(&cala).await?;
+    let public_ids = public_id::PublicIds::new(&pool);
+
+    let customers = Customers::new(
+        &pool,
+        &authz,
+        &outbox,
+        document_storage.clone(),
+        public_ids.clone(),
+        clock.clone(),
+    );
+
+    let exposed_domain_configs =
+        helpers::init_read_only_exposed_domain_configs(&pool, &authz).await?;
+    let internal_domain_configs = helpers::init_internal_domain_configs(&pool).await?;
+
+    let deposit = CoreDeposit::init(
+        &pool,
+        &authz,
+        &outbox,
+        &governance,
+        &mut jobs,
+        &cala,
+        journal_id,
+        &public_ids,
+        &customers,
+        &exposed_domain_configs,
+        &internal_domain_configs,
+    )
+    .await?;
+
+    let mut accounts = Vec::new();
+    for _ in 0..2 {
+        let customer = customers
+            .create_customer_bypassing_kyc(
+                &DummySubject,
+                format!("user{}@example.com", Uuid::new_v4()),
+                format!("telegram{}", Uuid::new_v4()),
+                CustomerType::Individual,
+            )
+            .await?;
+        let account = deposit.create_account(&DummySubject, customer.id).await?;
+        deposit
+            .record_deposit(
+                &DummySubject,
+                account.id,
+                UsdCents::try_from_usd(dec!(1000)).unwrap(),
+                None,
+            )
+            .await?;
+        accounts.push(account);
+    }
+    let (account, other_account) = (&accounts[0], &accounts[1]);
+
+    let hold = deposit
+        .place_hold(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(400)).unwrap(),
+            "garnishment order",
+        )
+        .await?;
+    assert_eq!(hold.status(), HoldStatus::Active);
+
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert_eq!(balance.held, UsdCents::try_from_usd(dec!(400)).unwrap());
+    assert_eq!(
+        balance.available(),
+        UsdCents::try_from_usd(dec!(600)).unwrap()
+    );
+
+    let res = deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(700)).unwrap(),
+            None,
+        )
+        .await;
+    assert!(res.is_err_and(|e| e.is_insufficient_available_balance()));
+
+    deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(500)).unwrap(),
+            None,
+        )
+        .await?;
+
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert_eq!(balance.held, UsdCents::try_from_usd(dec!(400)).unwrap());
+    assert_eq!(
+        balance.available(),
+        UsdCents::try_from_usd(dec!(100)).unwrap()
+    );
+
+    let res = deposit
+        .place_hold(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(200)).unwrap(),
+            "card authorization",
+        )
+        .await;
+    assert!(res.is_err_and(|e| e.is_insufficient_available_balance()));
+
+    let res = deposit
+        .initiate_transfer(
+            &DummySubject,
+            account.id,
+            other_account.id,
+            UsdCents::try_from_usd(dec!(200)).unwrap(),
+            None,
+        )
+        .await;
+    assert!(res.is_err_and(|e| e.is_insufficient_available_balance()));
+
+    let hold = deposit.release_hold(&DummySubject, hold.id).await?;
+    assert_eq!(hold.status(), HoldStatus::Released);
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert!(balance.held.is_zero());
+
+    let hold = deposit
+        .place_hold(
+            &DummySubject,
+            other_account.id,
+            UsdCents::try_from_usd(dec!(300)).unwrap(),
+            "chargeback",
+        )
+        .await?;
+    let hold = deposit.capture_hold(&DummySubject, hold.id).await?;
+    assert_eq!(hold.status(), HoldStatus::Captured);
+    assert!(deposit.release_hold(&DummySubject, hold.id).await.is_err());
+
+    let balance = deposit
+        .account_balance(&DummySubject, other_account.id)
+        .await?;
+    assert!(balance.held.is_zero());
+    assert_eq!(balance.settled, UsdCents::try_from_usd(dec!(700)).unwrap());
+
+    let holds = deposit
+        .list_holds_for_account(&DummySubject, other_account.id)
+        .await?;
+    assert_eq!(holds.len(), 1);
+
+    let history = deposit
+        .account_history(
+            &DummySubject,
+            other_account.id,
+            es_entity::PaginatedQueryArgs {
+                first: 10,
+                after: None,
+            },
+        )
+        .await?;
+    assert!(
+        history
+            .entities
+            .iter()
+            .any(|entry| matches!(entry, DepositAccountHistoryEntry::HoldPlaced(_)))
+    );
+    assert!(
+        history
+            .entities
+            .iter()
+            .any(|entry| matches!(entry, DepositAccountHistoryEntry::HoldCaptured(_)))
+    );
+
+    Ok(())
+}
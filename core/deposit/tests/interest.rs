@@ -0,0 +1,204 @@
+mod helpers;
+
+use std::time::Duration;
+
+use rust_decimal_macros::dec;
+use uuid::Uuid;
+
+use authz::dummy::DummySubject;
+use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use cloud_storage::{Storage, config::StorageConfig};
+use core_customer::{CustomerType, Customers};
+use core_deposit::*;
+use document_storage::DocumentStorage;
+use es_entity::clock::{ArtificialClockConfig, ClockHandle};
+
+use helpers::{action, event, object};
+
+#[tokio::test]
+async fn term_deposit_accrues_interest_and_unlocks_at_maturity() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let (clock, clock_ctrl) = ClockHandle::artificial(ArtificialClockConfig::manual());
+
+    let outbox = obix::Outbox::<event::DummyEvent>::init(
+        &pool,
+        obix::MailboxConfig::builder()
+            .clock(clock.clone())
+            .build()?,
+    )
+    .await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox, clock.clone());
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let mut jobs = job::Jobs::init(
+        job::JobSvcConfig::builder()
+            .pool(pool.clone())
+            .clock(clock.clone())
+            .build()
+            .unwrap(),
+    )
+    .await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let document_storage = DocumentStorage::new(&pool, &storage, clock.clone());
+    let journal_id = helpers::init_journal(&cala).await?;
+    let public_ids = public_id::PublicIds::new(&pool);
+
+    let customers = Customers::new(
+        &pool,
+        &authz,
+        &outbox,
+        document_storage.clone(),
+        public_ids.clone(),
+        clock.clone(),
+    );
+
+    let exposed_domain_configs =
+        helpers::init_read_only_exposed_domain_configs(&pool, &authz).await?;
+    let internal_domain_configs = helpers::init_internal_domain_configs(&pool).await?;
+
+    let deposit = CoreDeposit::init(
+        &pool,
+        &authz,
+        &outbox,
+        &governance,
+        &mut jobs,
+        &cala,
+        journal_id,
+        &public_ids,
+        &customers,
+        &exposed_domain_configs,
+        &internal_domain_configs,
+    )
+    .await?;
+
+    let customer = customers
+        .create_customer_bypassing_kyc(
+            &DummySubject,
+            format!("user{}@example.com", Uuid::new_v4()),
+            format!("telegram{}", Uuid::new_v4()),
+            CustomerType::Individual,
+        )
+        .await?;
+    let account = deposit.create_account(&DummySubject, customer.id).await?;
+    let deposit_amount = UsdCents::try_from_usd(dec!(100000)).unwrap();
+    deposit
+        .record_deposit(&DummySubject, account.id, deposit_amount, None)
+        .await?;
+
+    let today = clock.today();
+    let maturity_date = today + chrono::Days::new(5);
+    let account = deposit
+        .update_account_interest_terms(
+            &DummySubject,
+            account.id,
+            DepositAccountInterestTerms::term_deposit(dec!(3.65), maturity_date, None),
+        )
+        .await?;
+    assert!(account.is_interest_bearing());
+
+    let res = deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(100)).unwrap(),
+            None,
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(core_deposit::error::CoreDepositError::TermDepositNotMatured)
+    ));
+
+    for days in 0..=2 {
+        deposit
+            .perform_interest_accrual(today + chrono::Days::new(days))
+            .await?;
+    }
+    // days without a run are caught up by the next one
+    deposit.perform_interest_accrual(maturity_date).await?;
+    // running the same day twice must not accrue again
+    deposit.perform_interest_accrual(maturity_date).await?;
+
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert_eq!(
+        balance.settled,
+        deposit_amount + UsdCents::try_from_usd(dec!(50)).unwrap()
+    );
+
+    let account = deposit
+        .find_account_by_id(&DummySubject, account.id)
+        .await?
+        .expect("account should exist");
+    assert!(!account.is_interest_bearing());
+
+    let history = deposit
+        .account_history(
+            &DummySubject,
+            account.id,
+            es_entity::PaginatedQueryArgs {
+                first: 10,
+                after: None,
+            },
+        )
+        .await?;
+    assert!(
+        history
+            .entities
+            .iter()
+            .any(|entry| matches!(entry, DepositAccountHistoryEntry::InterestCapitalized(_)))
+    );
+
+    clock_ctrl
+        .advance(Duration::from_secs(5 * 24 * 60 * 60))
+        .await;
+    deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(100)).unwrap(),
+            None,
+        )
+        .await?;
+
+    let other_customer = customers
+        .create_customer_bypassing_kyc(
+            &DummySubject,
+            format!("user{}@example.com", Uuid::new_v4()),
+            format!("telegram{}", Uuid::new_v4()),
+            CustomerType::Individual,
+        )
+        .await?;
+    let broken = deposit
+        .create_account(&DummySubject, other_customer.id)
+        .await?;
+    deposit
+        .record_deposit(&DummySubject, broken.id, deposit_amount, None)
+        .await?;
+    deposit
+        .update_account_interest_terms(
+            &DummySubject,
+            broken.id,
+            DepositAccountInterestTerms::term_deposit(
+                dec!(3.65),
+                clock.today() + chrono::Days::new(30),
+                Some(dec!(1)),
+            ),
+        )
+        .await?;
+    let broken = deposit.break_term_deposit(&DummySubject, broken.id).await?;
+    assert!(!broken.is_locked(clock.today()));
+
+    let balance = deposit.account_balance(&DummySubject, broken.id).await?;
+    assert_eq!(
+        balance.settled,
+        deposit_amount - UsdCents::try_from_usd(dec!(1000)).unwrap()
+    );
+
+    Ok(())
+}
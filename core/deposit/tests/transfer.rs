@@ -0,0 +1,216 @@
+mod helpers;
+
+use rust_decimal_macros::dec;
+use uuid::Uuid;
+
+use authz::dummy::DummySubject;
+use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use cloud_storage::{Storage, config::StorageConfig};
+use core_customer::{CustomerType, Customers};
+use core_deposit::*;
+use document_storage::DocumentStorage;
+use es_entity::clock::{ArtificialClockConfig, ClockHandle};
+
+use helpers::{action, event, object};
+
+#[tokio::test]
+async fn transfer_between_accounts() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let (clock, _) = ClockHandle::artificial(ArtificialClockConfig::manual());
+
+    let outbox =
+        obix::Outbox::<event::DummyEvent>::init(&pool, obix::MailboxConfig::builder().build()?)
+            .await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox, clock.clone());
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let mut jobs = job::Jobs::init(
+        job::JobSvcConfig::builder()
+            .pool(pool.clone())
+            .build()
+            .unwrap(),
+    )
+    .await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let document_storage = DocumentStorage::new(&pool, &storage, clock.clone());
+    let journal_id = helpers::init_journal(&cala).await?;
+    let public_ids = public_id::PublicIds::new(&pool);
+
+    let customers = Customers::new(
+        &pool,
+        &authz,
+        &outbox,
+        document_storage.clone(),
+        public_ids.clone(),
+        clock.clone(),
+    );
+
+    let exposed_domain_configs =
+        helpers::init_read_only_exposed_domain_configs(&pool, &authz).await?;
+    let internal_domain_configs = helpers::init_internal_domain_configs(&pool).await?;
+
+    let deposit = CoreDeposit::init(
+        &pool,
+        &authz,
+        &outbox,
+        &governance,
+        &mut jobs,
+        &cala,
+        journal_id,
+        &public_ids,
+        &customers,
+        &exposed_domain_configs,
+        &internal_domain_configs,
+    )
+    .await?;
+
+    let source_customer = customers
+        .create_customer_bypassing_kyc(
+            &DummySubject,
+            format!("user{}@example.com", Uuid::new_v4()),
+            format!("telegram{}", Uuid::new_v4()),
+            CustomerType::Individual,
+        )
+        .await?;
+    let destination_customer = customers
+        .create_customer_bypassing_kyc(
+            &DummySubject,
+            format!("user{}@example.com", Uuid::new_v4()),
+            format!("telegram{}", Uuid::new_v4()),
+            CustomerType::Individual,
+        )
+        .await?;
+
+    let source = deposit
+        .create_account(&DummySubject, source_customer.id)
+        .await?;
+    let destination = deposit
+        .create_account(&DummySubject, destination_customer.id)
+        .await?;
+
+    let deposit_amount = UsdCents::try_from_usd(dec!(1000)).unwrap();
+    deposit
+        .record_deposit(&DummySubject, source.id, deposit_amount, None)
+        .await?;
+
+    // overdraw
+    let res = deposit
+        .initiate_transfer(
+            &DummySubject,
+            source.id,
+            destination.id,
+            UsdCents::try_from_usd(dec!(5000)).unwrap(),
+            None,
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(core_deposit::error::CoreDepositError::DepositLedgerError(_))
+    ));
+
+    let transfer_amount = UsdCents::try_from_usd(dec!(400)).unwrap();
+    let transfer = deposit
+        .initiate_transfer(
+            &DummySubject,
+            source.id,
+            destination.id,
+            transfer_amount,
+            None,
+        )
+        .await?;
+    assert_eq!(transfer.status(), TransferStatus::Completed);
+
+    let source_balance = deposit.account_balance(&DummySubject, source.id).await?;
+    assert_eq!(source_balance.settled, deposit_amount - transfer_amount);
+    assert_eq!(source_balance.pending, UsdCents::ZERO);
+    let destination_balance = deposit
+        .account_balance(&DummySubject, destination.id)
+        .await?;
+    assert_eq!(destination_balance.settled, transfer_amount);
+
+    let history = deposit
+        .account_history(
+            &DummySubject,
+            source.id,
+            es_entity::PaginatedQueryArgs {
+                first: 10,
+                after: None,
+            },
+        )
+        .await?;
+    assert!(
+        history
+            .entities
+            .iter()
+            .any(|entry| matches!(entry, DepositAccountHistoryEntry::TransferOut(_)))
+    );
+    let history = deposit
+        .account_history(
+            &DummySubject,
+            destination.id,
+            es_entity::PaginatedQueryArgs {
+                first: 10,
+                after: None,
+            },
+        )
+        .await?;
+    assert!(
+        history
+            .entities
+            .iter()
+            .any(|entry| matches!(entry, DepositAccountHistoryEntry::TransferIn(_)))
+    );
+
+    let transfers = deposit
+        .list_transfers_for_account(
+            &DummySubject,
+            destination.id,
+            es_entity::PaginatedQueryArgs {
+                first: 10,
+                after: None,
+            },
+        )
+        .await?
+        .entities;
+    assert_eq!(transfers.len(), 1);
+    assert_eq!(transfers[0].id, transfer.id);
+
+    let transfers = deposit
+        .list_transfers_for_account(
+            &DummySubject,
+            source.id,
+            es_entity::PaginatedQueryArgs {
+                first: 10,
+                after: None,
+            },
+        )
+        .await?
+        .entities;
+    assert_eq!(transfers.len(), 1);
+    assert_eq!(transfers[0].id, transfer.id);
+
+    deposit
+        .freeze_account(&DummySubject, destination.id)
+        .await?;
+    let res = deposit
+        .initiate_transfer(
+            &DummySubject,
+            source.id,
+            destination.id,
+            transfer_amount,
+            None,
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(core_deposit::error::CoreDepositError::DepositAccountFrozen)
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,222 @@
+mod helpers;
+
+use rust_decimal_macros::dec;
+use uuid::Uuid;
+
+use authz::dummy::DummySubject;
+use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use cloud_storage::{Storage, config::StorageConfig};
+use core_customer::{CustomerType, Customers};
+use core_deposit::*;
+use document_storage::DocumentStorage;
+use es_entity::clock::{ArtificialClockConfig, ClockHandle};
+
+use helpers::{action, event, object};
+
+#[tokio::test]
+async fn withdrawals_over_limit_require_separate_approval() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let (clock, _) = ClockHandle::artificial(ArtificialClockConfig::manual());
+
+    let outbox = obix::Outbox::<event::DummyEvent>::init(
+        &pool,
+        obix::MailboxConfig::builder()
+            .clock(clock.clone())
+            .build()?,
+    )
+    .await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox, clock.clone());
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let mut jobs = job::Jobs::init(
+        job::JobSvcConfig::builder()
+            .pool(pool.clone())
+            .clock(clock.clone())
+            .build()
+            .unwrap(),
+    )
+    .await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let document_storage = DocumentStorage::new(&pool, &storage, clock.clone());
+    let journal_id = helpers::init_journal(&cala).await?;
+    let public_ids = public_id::PublicIds::new(&pool);
+
+    let customers = Customers::new(
+        &pool,
+        &authz,
+        &outbox,
+        document_storage.clone(),
+        public_ids.clone(),
+        clock.clone(),
+    );
+
+    let exposed_domain_configs =
+        helpers::init_read_only_exposed_domain_configs(&pool, &authz).await?;
+    let internal_domain_configs = helpers::init_internal_domain_configs(&pool).await?;
+
+    let deposit = CoreDeposit::init(
+        &pool,
+        &authz,
+        &outbox,
+        &governance,
+        &mut jobs,
+        &cala,
+        journal_id,
+        &public_ids,
+        &customers,
+        &exposed_domain_configs,
+        &internal_domain_configs,
+    )
+    .await?;
+    jobs.start_poll().await?;
+
+    let customer = customers
+        .create_customer_bypassing_kyc(
+            &DummySubject,
+            format!("user{}@example.com", Uuid::new_v4()),
+            format!("telegram{}", Uuid::new_v4()),
+            CustomerType::NonDomiciledCompany,
+        )
+        .await?;
+    let account = deposit.create_account(&DummySubject, customer.id).await?;
+    deposit
+        .record_deposit(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(1000)).unwrap(),
+            None,
+        )
+        .await?;
+
+    deposit
+        .update_withdrawal_limits_config(
+            &DummySubject,
+            WithdrawalLimitsConfig {
+                rules: vec![WithdrawalLimitRule {
+                    product: Some(DepositProduct::Current),
+                    customer_type: Some(CustomerType::NonDomiciledCompany),
+                    limits: WithdrawalLimits {
+                        daily: Some(UsdCents::try_from_usd(dec!(300)).unwrap()),
+                        weekly: None,
+                        monthly: None,
+                    },
+                }],
+            },
+        )
+        .await?;
+
+    let process_type_of = |withdrawal: &Withdrawal| {
+        let approval_process_id = withdrawal.approval_process_id;
+        let governance = governance.clone();
+        async move {
+            governance
+                .find_all_approval_processes::<governance::ApprovalProcess>(&[approval_process_id])
+                .await
+                .map(|mut processes| {
+                    processes
+                        .remove(&approval_process_id)
+                        .expect("approval process not found")
+                        .process_type
+                })
+        }
+    };
+
+    let first = deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(200)).unwrap(),
+            None,
+        )
+        .await?;
+    assert_eq!(process_type_of(&first).await?, APPROVE_WITHDRAWAL_PROCESS);
+
+    // Pending withdrawals count towards the window from initiation
+    let second = deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(200)).unwrap(),
+            None,
+        )
+        .await?;
+    assert_eq!(
+        process_type_of(&second).await?,
+        APPROVE_WITHDRAWAL_OVER_LIMIT_PROCESS
+    );
+
+    // Cancelling a withdrawal releases its usage, including over-limit ones
+    deposit.cancel_withdrawal(&DummySubject, second.id).await?;
+    let within_limit = deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(100)).unwrap(),
+            None,
+        )
+        .await?;
+    assert_eq!(
+        process_type_of(&within_limit).await?,
+        APPROVE_WITHDRAWAL_PROCESS
+    );
+
+    // Settling does not count the withdrawal again
+    confirm_when_approved(&deposit, first.id).await?;
+    confirm_when_approved(&deposit, within_limit.id).await?;
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert_eq!(balance.settled, UsdCents::try_from_usd(dec!(700)).unwrap());
+
+    let over_limit = deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(1)).unwrap(),
+            None,
+        )
+        .await?;
+    assert_eq!(
+        process_type_of(&over_limit).await?,
+        APPROVE_WITHDRAWAL_OVER_LIMIT_PROCESS
+    );
+
+    jobs.shutdown().await?;
+    Ok(())
+}
+
+/// Withdrawal approval concludes asynchronously via the governance → outbox → jobs pipeline.
+async fn confirm_when_approved(
+    deposit: &CoreDeposit<
+        authz::dummy::DummyPerms<action::DummyAction, object::DummyObject>,
+        event::DummyEvent,
+    >,
+    withdrawal_id: WithdrawalId,
+) -> anyhow::Result<()> {
+    let max_retries = 100;
+    for attempt in 1..=max_retries {
+        let Some(current) = deposit
+            .find_withdrawal_by_id(&DummySubject, withdrawal_id)
+            .await?
+        else {
+            anyhow::bail!("withdrawal not found");
+        };
+        match current.is_approved_or_denied() {
+            Some(true) => break,
+            Some(false) => anyhow::bail!("withdrawal approval was denied"),
+            None => {}
+        }
+        if attempt == max_retries {
+            anyhow::bail!("withdrawal approval not processed in time");
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+    deposit
+        .confirm_withdrawal(&DummySubject, withdrawal_id)
+        .await?;
+    Ok(())
+}
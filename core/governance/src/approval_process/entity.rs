@@ -1,3 +1,4 @@
+use chrono::{DateTime, NaiveDate, Utc};
 use derive_builder::Builder;
 #[cfg(feature = "json-schema")]
 use schemars::JsonSchema;
@@ -7,7 +8,10 @@ use std::collections::HashSet;
 
 use es_entity::*;
 
-use crate::{policy::ApprovalRules, primitives::*};
+use crate::{
+    policy::{ApprovalRules, ApprovalStage, ExpiryAction, VotingDeadline},
+    primitives::*,
+};
 
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
@@ -20,16 +24,47 @@ pub enum ApprovalProcessEvent {
         process_type: ApprovalProcessType,
         rules: ApprovalRules,
         target_ref: String,
+        #[serde(default)]
+        amount: Option<u64>,
+        #[serde(default)]
+        voting_deadline: Option<VotingDeadline>,
+        #[serde(default)]
+        expires_at: Option<DateTime<Utc>>,
+        #[serde(default)]
+        initiated_by: Option<String>,
     },
     Approved {
         approver_id: CommitteeMemberId,
+        #[serde(default)]
+        stage: usize,
+        #[serde(default)]
+        delegate_id: Option<CommitteeMemberId>,
     },
     Denied {
         denier_id: CommitteeMemberId,
         reason: String,
+        #[serde(default)]
+        stage: usize,
+        #[serde(default)]
+        delegate_id: Option<CommitteeMemberId>,
+    },
+    StageApproved {
+        stage: usize,
+    },
+    Escalated {
+        stage: usize,
+        committee_id: CommitteeId,
+        threshold: usize,
+        expires_at: DateTime<Utc>,
+    },
+    ReminderSent {
+        day: NaiveDate,
+        recipients: Vec<CommitteeMemberId>,
     },
     Concluded {
         approved: bool,
+        #[serde(default)]
+        expired: bool,
     },
 }
 
@@ -40,9 +75,21 @@ pub struct ApprovalProcess {
     pub process_type: ApprovalProcessType,
     pub policy_id: PolicyId,
     pub rules: ApprovalRules,
+    pub amount: Option<u64>,
+    pub stages: Vec<ApprovalStage>,
+    pub voting_deadline: Option<VotingDeadline>,
+    pub initiated_by: Option<String>,
+    expires_at: Option<DateTime<Utc>>,
+    on_expiry: Option<ExpiryAction>,
     events: EntityEvents<ApprovalProcessEvent>,
 }
 
+pub(crate) enum ApprovalProcessProgress {
+    StageApproved,
+    Escalated,
+    Concluded,
+}
+
 impl ApprovalProcess {
     pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
         self.events
@@ -77,6 +124,28 @@ impl ApprovalProcess {
             })
             .next()
     }
+
+    /// The user who cast the vote of `member_id` through a delegation, if any.
+    pub fn member_vote_delegate(&self, member_id: CommitteeMemberId) -> Option<CommitteeMemberId> {
+        self.events
+            .iter_all()
+            .filter_map(|event| match event {
+                ApprovalProcessEvent::Approved {
+                    approver_id: id,
+                    delegate_id,
+                    ..
+                }
+                | ApprovalProcessEvent::Denied {
+                    denier_id: id,
+                    delegate_id,
+                    ..
+                } if *id == member_id => Some(*delegate_id),
+                _ => None,
+            })
+            .next()
+            .flatten()
+    }
+
     pub fn target_ref(&self) -> &str {
         match self.events.iter_all().next().expect("No events") {
             ApprovalProcessEvent::Initialized { target_ref, .. } => target_ref,
@@ -86,8 +155,42 @@ impl ApprovalProcess {
         }
     }
 
+    /// The committee voting in the current stage.
     pub fn committee_id(&self) -> Option<CommitteeId> {
-        self.rules.committee_id()
+        self.current_stage().map(|stage| stage.committee_id)
+    }
+
+    /// Index of the stage currently being voted on.
+    pub fn current_stage_index(&self) -> usize {
+        self.events
+            .iter_all()
+            .filter(|event| {
+                matches!(
+                    event,
+                    ApprovalProcessEvent::StageApproved { .. }
+                        | ApprovalProcessEvent::Escalated { .. }
+                )
+            })
+            .count()
+    }
+
+    /// When the current stage runs out of time, as long as the process is still open.
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        if self.status().is_concluded() {
+            None
+        } else {
+            self.expires_at
+        }
+    }
+
+    pub fn expired(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|event| matches!(event, ApprovalProcessEvent::Concluded { expired: true, .. }))
+    }
+
+    pub fn current_stage(&self) -> Option<&ApprovalStage> {
+        self.stages.get(self.current_stage_index())
     }
 
     pub fn can_member_vote(
@@ -96,36 +199,128 @@ impl ApprovalProcess {
         eligible: HashSet<CommitteeMemberId>,
     ) -> bool {
         eligible.contains(&member_id)
-            && !self.approvers().contains(&member_id)
-            && !self.deniers().contains(&member_id)
+            && !self.stage_approvers().contains(&member_id)
+            && !self.stage_deniers().contains(&member_id)
     }
 
     pub(crate) fn check_concluded(
         &mut self,
         eligible: HashSet<CommitteeMemberId>,
-    ) -> Idempotent<(bool, Option<String>)> {
+    ) -> Idempotent<ApprovalProcessProgress> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            ApprovalProcessEvent::Concluded { .. },
+        );
+        let stage_index = self.current_stage_index();
+        let outcome = match self.current_stage() {
+            Some(stage) => stage.is_approved_or_denied(
+                &eligible,
+                &self.stage_approvers(),
+                &self.stage_deniers(),
+            ),
+            None => Some(true),
+        };
+
+        match outcome {
+            Some(true) if stage_index + 1 < self.stages.len() => {
+                self.events
+                    .push(ApprovalProcessEvent::StageApproved { stage: stage_index });
+                Idempotent::Executed(ApprovalProcessProgress::StageApproved)
+            }
+            Some(approved) => {
+                self.events.push(ApprovalProcessEvent::Concluded {
+                    approved,
+                    expired: false,
+                });
+                Idempotent::Executed(ApprovalProcessProgress::Concluded)
+            }
+            None => Idempotent::AlreadyApplied,
+        }
+    }
+
+    /// Applies the expiry action once the voting deadline has passed.
+    ///
+    /// Escalation replaces the remaining stages with the fallback committee and starts
+    /// a new voting period. A process that runs out of time after escalating is denied.
+    pub(crate) fn expire(&mut self, now: DateTime<Utc>) -> Idempotent<ApprovalProcessProgress> {
         idempotency_guard!(
             self.events.iter_all(),
             ApprovalProcessEvent::Concluded { .. },
         );
-        if let Some(approved) =
-            self.rules
-                .is_approved_or_denied(&eligible, &self.approvers(), &self.deniers())
-        {
-            let reason = self
-                .events
-                .iter_all()
-                .filter_map(|event| match event {
-                    ApprovalProcessEvent::Denied { reason, .. } => Some(reason.clone()),
-                    _ => None,
-                })
-                .next();
-
-            self.events
-                .push(ApprovalProcessEvent::Concluded { approved });
-            return Idempotent::Executed((approved, reason));
+        let (Some(expires_at), Some(on_expiry), Some(voting_deadline)) =
+            (self.expires_at, self.on_expiry, self.voting_deadline)
+        else {
+            return Idempotent::AlreadyApplied;
+        };
+        if expires_at > now {
+            return Idempotent::AlreadyApplied;
+        }
+
+        match on_expiry {
+            ExpiryAction::AutoApprove | ExpiryAction::AutoDeny => {
+                self.events.push(ApprovalProcessEvent::Concluded {
+                    approved: on_expiry == ExpiryAction::AutoApprove,
+                    expired: true,
+                });
+                Idempotent::Executed(ApprovalProcessProgress::Concluded)
+            }
+            ExpiryAction::Escalate {
+                committee_id,
+                threshold,
+            } => {
+                let stage = self.current_stage_index() + 1;
+                let expires_at = voting_deadline.expires_at(now);
+                self.stages.truncate(stage);
+                self.stages.push(ApprovalStage {
+                    committee_id,
+                    threshold,
+                    deny_threshold: None,
+                });
+                self.expires_at = Some(expires_at);
+                self.on_expiry = Some(ExpiryAction::AutoDeny);
+                self.events.push(ApprovalProcessEvent::Escalated {
+                    stage,
+                    committee_id,
+                    threshold,
+                    expires_at,
+                });
+                Idempotent::Executed(ApprovalProcessProgress::Escalated)
+            }
         }
-        Idempotent::AlreadyApplied
+    }
+
+    /// Records a reminder for the eligible members who have not voted in the current stage.
+    /// Returns the members to remind, at most once per day.
+    pub(crate) fn remind_pending_voters(
+        &mut self,
+        day: NaiveDate,
+        eligible: &HashSet<CommitteeMemberId>,
+    ) -> Idempotent<Vec<CommitteeMemberId>> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            ApprovalProcessEvent::Concluded { .. },
+            ApprovalProcessEvent::ReminderSent { day: d, .. } if d == &day,
+        );
+        if self.expires_at.is_none() {
+            return Idempotent::AlreadyApplied;
+        }
+
+        let approvers = self.stage_approvers();
+        let deniers = self.stage_deniers();
+        let recipients: Vec<_> = eligible
+            .iter()
+            .filter(|member| !approvers.contains(member) && !deniers.contains(member))
+            .copied()
+            .collect();
+        if recipients.is_empty() {
+            return Idempotent::AlreadyApplied;
+        }
+
+        self.events.push(ApprovalProcessEvent::ReminderSent {
+            day,
+            recipients: recipients.clone(),
+        });
+        Idempotent::Executed(recipients)
     }
 
     pub fn status(&self) -> ApprovalProcessStatus {
@@ -143,24 +338,31 @@ impl ApprovalProcess {
         ApprovalProcessStatus::InProgress
     }
 
+    /// Records the approval of `approver_id`. When the vote is cast through a delegation,
+    /// `delegate_id` is the user who cast it.
     pub(crate) fn approve(
         &mut self,
         eligible_members: &HashSet<CommitteeMemberId>,
         approver_id: CommitteeMemberId,
+        delegate_id: Option<CommitteeMemberId>,
     ) -> Idempotent<()> {
         use ApprovalProcessEvent::*;
+        let stage = self.current_stage_index();
         idempotency_guard!(
             self.events.iter_all(),
             Concluded {..},
-            Approved {approver_id: id, ..} | Denied {denier_id: id,..} if id == &approver_id,
+            Approved {approver_id: id, stage: s, ..} | Denied {denier_id: id, stage: s, ..} if id == &approver_id && *s == stage,
         );
 
         if !eligible_members.contains(&approver_id) {
             return Idempotent::AlreadyApplied;
         }
 
-        self.events
-            .push(ApprovalProcessEvent::Approved { approver_id });
+        self.events.push(ApprovalProcessEvent::Approved {
+            approver_id,
+            stage,
+            delegate_id,
+        });
 
         Idempotent::Executed(())
     }
@@ -169,21 +371,27 @@ impl ApprovalProcess {
         &mut self,
         eligible_members: &HashSet<CommitteeMemberId>,
         denier_id: CommitteeMemberId,
+        delegate_id: Option<CommitteeMemberId>,
         reason: String,
     ) -> Idempotent<()> {
         use ApprovalProcessEvent::*;
+        let stage = self.current_stage_index();
         idempotency_guard!(
             self.events.iter_all(),
             Concluded {..},
-            Approved {approver_id: id, ..} | Denied {denier_id: id,..} if id == &denier_id,
+            Approved {approver_id: id, stage: s, ..} | Denied {denier_id: id, stage: s, ..} if id == &denier_id && *s == stage,
         );
 
         if !eligible_members.contains(&denier_id) {
             return Idempotent::AlreadyApplied;
         }
 
-        self.events
-            .push(ApprovalProcessEvent::Denied { denier_id, reason });
+        self.events.push(ApprovalProcessEvent::Denied {
+            denier_id,
+            reason,
+            stage,
+            delegate_id,
+        });
 
         Idempotent::Executed(())
     }
@@ -207,11 +415,42 @@ impl ApprovalProcess {
             })
             .collect()
     }
+
+    /// Members who approved in the current stage.
+    pub fn stage_approvers(&self) -> HashSet<CommitteeMemberId> {
+        let current = self.current_stage_index();
+        self.events
+            .iter_all()
+            .filter_map(|event| match event {
+                ApprovalProcessEvent::Approved {
+                    approver_id, stage, ..
+                } if *stage == current => Some(*approver_id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Members who denied in the current stage.
+    pub fn stage_deniers(&self) -> HashSet<CommitteeMemberId> {
+        let current = self.current_stage_index();
+        self.events
+            .iter_all()
+            .filter_map(|event| match event {
+                ApprovalProcessEvent::Denied {
+                    denier_id, stage, ..
+                } if *stage == current => Some(*denier_id),
+                _ => None,
+            })
+            .collect()
+    }
 }
 
 impl TryFromEvents<ApprovalProcessEvent> for ApprovalProcess {
     fn try_from_events(events: EntityEvents<ApprovalProcessEvent>) -> Result<Self, EsEntityError> {
         let mut builder = ApprovalProcessBuilder::default();
+        let mut stages = Vec::new();
+        let mut expires_at = None;
+        let mut on_expiry = None;
         for event in events.iter_all() {
             match event {
                 ApprovalProcessEvent::Initialized {
@@ -219,20 +458,52 @@ impl TryFromEvents<ApprovalProcessEvent> for ApprovalProcess {
                     process_type,
                     policy_id,
                     rules,
+                    amount,
+                    voting_deadline,
+                    expires_at: initial_expires_at,
+                    initiated_by,
                     ..
                 } => {
+                    stages = rules.stages_for_amount(*amount);
+                    expires_at = *initial_expires_at;
+                    on_expiry = voting_deadline.map(|deadline| deadline.on_expiry);
                     builder = builder
                         .id(*id)
                         .process_type(process_type.clone())
                         .policy_id(*policy_id)
-                        .rules(*rules);
+                        .rules(rules.clone())
+                        .amount(*amount)
+                        .voting_deadline(*voting_deadline)
+                        .initiated_by(initiated_by.clone());
+                }
+                ApprovalProcessEvent::Escalated {
+                    stage,
+                    committee_id,
+                    threshold,
+                    expires_at: escalated_expires_at,
+                } => {
+                    stages.truncate(*stage);
+                    stages.push(ApprovalStage {
+                        committee_id: *committee_id,
+                        threshold: *threshold,
+                        deny_threshold: None,
+                    });
+                    expires_at = Some(*escalated_expires_at);
+                    on_expiry = Some(ExpiryAction::AutoDeny);
                 }
                 ApprovalProcessEvent::Approved { .. } => {}
                 ApprovalProcessEvent::Denied { .. } => {}
+                ApprovalProcessEvent::StageApproved { .. } => {}
+                ApprovalProcessEvent::ReminderSent { .. } => {}
                 ApprovalProcessEvent::Concluded { .. } => {}
             }
         }
-        builder.events(events).build()
+        builder
+            .stages(stages)
+            .expires_at(expires_at)
+            .on_expiry(on_expiry)
+            .events(events)
+            .build()
     }
 }
 
@@ -245,6 +516,14 @@ pub struct NewApprovalProcess {
     pub(super) rules: ApprovalRules,
     #[builder(setter(into))]
     pub(super) target_ref: String,
+    #[builder(default)]
+    pub(super) amount: Option<u64>,
+    #[builder(default)]
+    pub(super) voting_deadline: Option<VotingDeadline>,
+    #[builder(default)]
+    pub(super) expires_at: Option<DateTime<Utc>>,
+    #[builder(default)]
+    pub(super) initiated_by: Option<String>,
 }
 
 impl NewApprovalProcess {
@@ -253,7 +532,14 @@ impl NewApprovalProcess {
     }
 
     pub fn committee_id(&self) -> Option<CommitteeId> {
-        self.rules.committee_id()
+        self.rules
+            .stages_for_amount(self.amount)
+            .first()
+            .map(|stage| stage.committee_id)
+    }
+
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.expires_at
     }
 }
 
@@ -267,6 +553,10 @@ impl IntoEvents<ApprovalProcessEvent> for NewApprovalProcess {
                 process_type: self.process_type,
                 rules: self.rules,
                 target_ref: self.target_ref,
+                amount: self.amount,
+                voting_deadline: self.voting_deadline,
+                expires_at: self.expires_at,
+                initiated_by: self.initiated_by,
             }],
         )
     }
@@ -275,8 +565,16 @@ impl IntoEvents<ApprovalProcessEvent> for NewApprovalProcess {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::policy::AmountBand;
 
     fn init_events(rules: ApprovalRules) -> EntityEvents<ApprovalProcessEvent> {
+        init_events_with_amount(rules, None)
+    }
+
+    fn init_events_with_amount(
+        rules: ApprovalRules,
+        amount: Option<u64>,
+    ) -> EntityEvents<ApprovalProcessEvent> {
         EntityEvents::init(
             ApprovalProcessId::new(),
             [ApprovalProcessEvent::Initialized {
@@ -285,6 +583,31 @@ mod tests {
                 process_type: ApprovalProcessType::from_owned("type".to_string()),
                 rules,
                 target_ref: "target_ref".to_string(),
+                amount,
+                voting_deadline: None,
+                expires_at: None,
+                initiated_by: None,
+            }],
+        )
+    }
+
+    fn init_events_with_deadline(
+        rules: ApprovalRules,
+        voting_deadline: VotingDeadline,
+        started_at: DateTime<Utc>,
+    ) -> EntityEvents<ApprovalProcessEvent> {
+        EntityEvents::init(
+            ApprovalProcessId::new(),
+            [ApprovalProcessEvent::Initialized {
+                id: ApprovalProcessId::new(),
+                policy_id: PolicyId::new(),
+                process_type: ApprovalProcessType::from_owned("type".to_string()),
+                rules,
+                target_ref: "target_ref".to_string(),
+                amount: None,
+                voting_deadline: Some(voting_deadline),
+                expires_at: Some(voting_deadline.expires_at(started_at)),
+                initiated_by: None,
             }],
         )
     }
@@ -299,10 +622,25 @@ mod tests {
             .expect("Could not build approval process");
         let approver = CommitteeMemberId::new();
         let eligible = [approver].iter().copied().collect();
-        assert!(process.approve(&eligible, approver).did_execute());
+        assert!(process.approve(&eligible, approver, None).did_execute());
         assert!(process.approvers().contains(&approver));
     }
 
+    #[test]
+    fn initiated_by_is_kept() {
+        let new_process = NewApprovalProcess::builder()
+            .id(ApprovalProcessId::new())
+            .policy_id(PolicyId::new())
+            .process_type(ApprovalProcessType::from_owned("type".to_string()))
+            .rules(ApprovalRules::SystemAutoApprove)
+            .target_ref("target_ref")
+            .initiated_by(Some("user:maker".to_string()))
+            .build()
+            .unwrap();
+        let process = ApprovalProcess::try_from_events(new_process.into_events()).unwrap();
+        assert_eq!(process.initiated_by.as_deref(), Some("user:maker"));
+    }
+
     #[test]
     fn approve_not_eligible() {
         let mut process =
@@ -314,7 +652,7 @@ mod tests {
         let approver = CommitteeMemberId::new();
         assert!(
             process
-                .approve(&HashSet::new(), approver)
+                .approve(&HashSet::new(), approver, None)
                 .was_already_applied()
         );
         assert!(process.approvers().is_empty());
@@ -330,8 +668,12 @@ mod tests {
             .expect("Could not build approval process");
         let approver = CommitteeMemberId::new();
         let eligible: HashSet<_> = [approver].iter().copied().collect();
-        assert!(process.approve(&eligible, approver).did_execute());
-        assert!(process.approve(&eligible, approver).was_already_applied());
+        assert!(process.approve(&eligible, approver, None).did_execute());
+        assert!(
+            process
+                .approve(&eligible, approver, None)
+                .was_already_applied()
+        );
     }
 
     #[test]
@@ -342,7 +684,47 @@ mod tests {
         let _ = process.check_concluded(HashSet::new());
         let approver = CommitteeMemberId::new();
         let eligible: HashSet<_> = [approver].iter().copied().collect();
-        assert!(process.approve(&eligible, approver).was_already_applied());
+        assert!(
+            process
+                .approve(&eligible, approver, None)
+                .was_already_applied()
+        );
+    }
+
+    #[test]
+    fn approve_as_delegate() {
+        let mut process =
+            ApprovalProcess::try_from_events(init_events(ApprovalRules::CommitteeThreshold {
+                threshold: 2,
+                committee_id: CommitteeId::new(),
+            }))
+            .expect("Could not build approval process");
+        let (on_leave, present, delegate) = (
+            CommitteeMemberId::new(),
+            CommitteeMemberId::new(),
+            CommitteeMemberId::new(),
+        );
+        let eligible: HashSet<_> = [on_leave, present].into_iter().collect();
+
+        assert!(
+            process
+                .approve(&eligible, on_leave, Some(delegate))
+                .did_execute()
+        );
+        assert!(
+            process
+                .approve(&eligible, on_leave, None)
+                .was_already_applied()
+        );
+        assert_eq!(process.member_vote_delegate(on_leave), Some(delegate));
+
+        assert!(process.approve(&eligible, present, None).did_execute());
+        assert_eq!(process.member_vote_delegate(present), None);
+        assert!(matches!(
+            process.check_concluded(eligible),
+            Idempotent::Executed(ApprovalProcessProgress::Concluded)
+        ));
+        assert_eq!(process.status(), ApprovalProcessStatus::Approved);
     }
 
     #[test]
@@ -356,7 +738,7 @@ mod tests {
         let denier = CommitteeMemberId::new();
         let reason = String::new();
         let eligible = [denier].iter().copied().collect();
-        assert!(process.deny(&eligible, denier, reason).did_execute());
+        assert!(process.deny(&eligible, denier, None, reason).did_execute());
         assert!(process.deniers().contains(&denier));
     }
 
@@ -372,7 +754,7 @@ mod tests {
         let reason = String::new();
         assert!(
             process
-                .deny(&HashSet::new(), denier, reason)
+                .deny(&HashSet::new(), denier, None, reason)
                 .was_already_applied()
         );
         assert!(process.deniers().is_empty());
@@ -388,10 +770,10 @@ mod tests {
             .expect("Could not build approval process");
         let denier = CommitteeMemberId::new();
         let eligible: HashSet<_> = [denier].iter().copied().collect();
-        assert!(process.approve(&eligible, denier).did_execute());
+        assert!(process.approve(&eligible, denier, None).did_execute());
         assert!(
             process
-                .deny(&eligible, denier, String::new())
+                .deny(&eligible, denier, None, String::new())
                 .was_already_applied()
         );
     }
@@ -406,8 +788,247 @@ mod tests {
         let eligible: HashSet<_> = [denier].iter().copied().collect();
         assert!(
             process
-                .deny(&eligible, denier, String::new())
+                .deny(&eligible, denier, None, String::new())
+                .was_already_applied()
+        );
+    }
+
+    fn two_stage_rules(deny_threshold: Option<usize>) -> ApprovalRules {
+        ApprovalRules::MultiStage {
+            stages: vec![
+                ApprovalStage {
+                    committee_id: CommitteeId::new(),
+                    threshold: 2,
+                    deny_threshold,
+                },
+                ApprovalStage {
+                    committee_id: CommitteeId::new(),
+                    threshold: 1,
+                    deny_threshold: None,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn multi_stage_advances_then_concludes() {
+        let rules = two_stage_rules(None);
+        let first_committee = rules.stages()[0].committee_id;
+        let second_committee = rules.stages()[1].committee_id;
+        let mut process = ApprovalProcess::try_from_events(init_events(rules))
+            .expect("Could not build approval process");
+        assert_eq!(process.committee_id(), Some(first_committee));
+
+        let (a, b) = (CommitteeMemberId::new(), CommitteeMemberId::new());
+        let first: HashSet<_> = [a, b].iter().copied().collect();
+        assert!(process.approve(&first, a, None).did_execute());
+        assert!(process.check_concluded(first.clone()).was_already_applied());
+        assert!(process.approve(&first, b, None).did_execute());
+        assert!(matches!(
+            process.check_concluded(first),
+            Idempotent::Executed(ApprovalProcessProgress::StageApproved)
+        ));
+        assert_eq!(process.current_stage_index(), 1);
+        assert_eq!(process.committee_id(), Some(second_committee));
+        assert_eq!(process.status(), ApprovalProcessStatus::InProgress);
+
+        let second: HashSet<_> = [a].iter().copied().collect();
+        assert!(process.can_member_vote(a, second.clone()));
+        assert!(process.approve(&second, a, None).did_execute());
+        assert!(matches!(
+            process.check_concluded(second),
+            Idempotent::Executed(ApprovalProcessProgress::Concluded)
+        ));
+        assert_eq!(process.status(), ApprovalProcessStatus::Approved);
+    }
+
+    #[test]
+    fn single_denial_below_deny_threshold_keeps_process_open() {
+        let mut process = ApprovalProcess::try_from_events(init_events(two_stage_rules(Some(2))))
+            .expect("Could not build approval process");
+        let (a, b, c) = (
+            CommitteeMemberId::new(),
+            CommitteeMemberId::new(),
+            CommitteeMemberId::new(),
+        );
+        let eligible: HashSet<_> = [a, b, c].iter().copied().collect();
+
+        assert!(
+            process
+                .deny(&eligible, a, None, String::new())
+                .did_execute()
+        );
+        assert!(
+            process
+                .check_concluded(eligible.clone())
+                .was_already_applied()
+        );
+        assert!(
+            process
+                .deny(&eligible, b, None, "too large".to_string())
+                .did_execute()
+        );
+        assert!(matches!(
+            process.check_concluded(eligible),
+            Idempotent::Executed(ApprovalProcessProgress::Concluded)
+        ));
+        assert_eq!(process.status(), ApprovalProcessStatus::Denied);
+    }
+
+    #[test]
+    fn amount_banded_resolves_stages_from_amount() {
+        let stage = ApprovalStage {
+            committee_id: CommitteeId::new(),
+            threshold: 1,
+            deny_threshold: None,
+        };
+        let rules = ApprovalRules::AmountBanded {
+            bands: vec![
+                AmountBand {
+                    min_amount: 0,
+                    stages: vec![],
+                },
+                AmountBand {
+                    min_amount: 1_000,
+                    stages: vec![stage],
+                },
+            ],
+        };
+
+        let small =
+            ApprovalProcess::try_from_events(init_events_with_amount(rules.clone(), Some(999)))
+                .expect("Could not build approval process");
+        assert!(small.stages.is_empty());
+        assert_eq!(small.committee_id(), None);
+
+        let large = ApprovalProcess::try_from_events(init_events_with_amount(rules, Some(5_000)))
+            .expect("Could not build approval process");
+        assert_eq!(large.stages, vec![stage]);
+        assert_eq!(large.committee_id(), Some(stage.committee_id));
+    }
+
+    #[test]
+    fn expire_auto_denies_after_deadline() {
+        let started_at = Utc::now();
+        let deadline = VotingDeadline {
+            voting_period_days: 2,
+            on_expiry: ExpiryAction::AutoDeny,
+        };
+        let mut process = ApprovalProcess::try_from_events(init_events_with_deadline(
+            ApprovalRules::CommitteeThreshold {
+                threshold: 1,
+                committee_id: CommitteeId::new(),
+            },
+            deadline,
+            started_at,
+        ))
+        .expect("Could not build approval process");
+
+        assert!(
+            process
+                .expire(started_at + chrono::Duration::days(1))
                 .was_already_applied()
         );
+        assert!(matches!(
+            process.expire(started_at + chrono::Duration::days(2)),
+            Idempotent::Executed(ApprovalProcessProgress::Concluded)
+        ));
+        assert_eq!(process.status(), ApprovalProcessStatus::Denied);
+        assert!(process.expired());
+        assert_eq!(process.expires_at(), None);
+    }
+
+    #[test]
+    fn expire_escalates_to_fallback_committee_then_denies() {
+        let started_at = Utc::now();
+        let (primary, fallback) = (CommitteeId::new(), CommitteeId::new());
+        let deadline = VotingDeadline {
+            voting_period_days: 1,
+            on_expiry: ExpiryAction::Escalate {
+                committee_id: fallback,
+                threshold: 1,
+            },
+        };
+        let mut process = ApprovalProcess::try_from_events(init_events_with_deadline(
+            ApprovalRules::CommitteeThreshold {
+                threshold: 2,
+                committee_id: primary,
+            },
+            deadline,
+            started_at,
+        ))
+        .expect("Could not build approval process");
+        let primary_member = CommitteeMemberId::new();
+        let eligible = [primary_member].iter().copied().collect();
+        assert!(
+            process
+                .approve(&eligible, primary_member, None)
+                .did_execute()
+        );
+
+        let escalated_at = started_at + chrono::Duration::days(1);
+        assert!(matches!(
+            process.expire(escalated_at),
+            Idempotent::Executed(ApprovalProcessProgress::Escalated)
+        ));
+        assert_eq!(process.committee_id(), Some(fallback));
+        assert_eq!(process.current_stage_index(), 1);
+        assert!(process.stage_approvers().is_empty());
+        assert_eq!(
+            process.expires_at(),
+            Some(escalated_at + chrono::Duration::days(1))
+        );
+
+        let rebuilt = ApprovalProcess::try_from_events(EntityEvents::init(
+            process.id,
+            process.events.iter_all().cloned(),
+        ))
+        .expect("Could not rebuild approval process");
+        assert_eq!(rebuilt.stages, process.stages);
+        assert_eq!(rebuilt.expires_at(), process.expires_at());
+
+        assert!(matches!(
+            process.expire(escalated_at + chrono::Duration::days(1)),
+            Idempotent::Executed(ApprovalProcessProgress::Concluded)
+        ));
+        assert_eq!(process.status(), ApprovalProcessStatus::Denied);
+    }
+
+    #[test]
+    fn remind_pending_voters_once_per_day() {
+        let started_at = Utc::now();
+        let deadline = VotingDeadline {
+            voting_period_days: 3,
+            on_expiry: ExpiryAction::AutoApprove,
+        };
+        let mut process = ApprovalProcess::try_from_events(init_events_with_deadline(
+            ApprovalRules::CommitteeThreshold {
+                threshold: 2,
+                committee_id: CommitteeId::new(),
+            },
+            deadline,
+            started_at,
+        ))
+        .expect("Could not build approval process");
+        let (voted, pending) = (CommitteeMemberId::new(), CommitteeMemberId::new());
+        let eligible: HashSet<_> = [voted, pending].iter().copied().collect();
+        assert!(process.approve(&eligible, voted, None).did_execute());
+
+        let today = started_at.date_naive();
+        let Idempotent::Executed(recipients) = process.remind_pending_voters(today, &eligible)
+        else {
+            panic!("expected reminder to be sent");
+        };
+        assert_eq!(recipients, vec![pending]);
+        assert!(
+            process
+                .remind_pending_voters(today, &eligible)
+                .was_already_applied()
+        );
+        assert!(
+            process
+                .remind_pending_voters(today.succ_opt().unwrap(), &eligible)
+                .did_execute()
+        );
     }
 }
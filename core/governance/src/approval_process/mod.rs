@@ -7,4 +7,5 @@ pub use entity::ApprovalProcessEvent;
 pub use entity::{ApprovalProcess, NewApprovalProcess};
 pub use repo::approval_process_cursor;
 
+pub(crate) use entity::ApprovalProcessProgress;
 pub(crate) use repo::ApprovalProcessRepo;
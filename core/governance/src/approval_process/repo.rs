@@ -2,6 +2,7 @@ use es_entity::clock::ClockHandle;
 use sqlx::PgPool;
 
 use es_entity::*;
+use tracing_macros::record_error_severity;
 
 use crate::primitives::*;
 
@@ -19,7 +20,12 @@ use super::{entity::*, error::*};
             create(accessor = "committee_id()"),
             update(accessor = "committee_id()")
         ),
-        policy_id(ty = "PolicyId")
+        policy_id(ty = "PolicyId"),
+        expires_at(
+            ty = "Option<chrono::DateTime<chrono::Utc>>",
+            create(accessor = "expires_at()"),
+            update(accessor = "expires_at()")
+        )
     ),
     tbl_prefix = "core"
 )]
@@ -37,4 +43,26 @@ impl ApprovalProcessRepo {
             clock,
         }
     }
+
+    /// Open processes that have a voting deadline, ordered by id.
+    #[record_error_severity]
+    #[tracing::instrument(name = "approval_process.list_with_voting_deadline", skip_all)]
+    pub async fn list_with_voting_deadline(
+        &self,
+        after: Option<ApprovalProcessId>,
+        first: usize,
+    ) -> Result<(Vec<ApprovalProcess>, bool), ApprovalProcessError> {
+        es_query!(
+            tbl_prefix = "core",
+            r#"
+                SELECT id FROM core_approval_processes
+                WHERE expires_at IS NOT NULL AND COALESCE(id > $1, true)
+                ORDER BY id
+                LIMIT $2"#,
+            after as Option<ApprovalProcessId>,
+            (first + 1) as i64,
+        )
+        .fetch_n(self.pool(), first)
+        .await
+    }
 }
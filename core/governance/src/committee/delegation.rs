@@ -0,0 +1,24 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::primitives::{CommitteeDelegationId, CommitteeMemberId};
+
+/// A member handing their vote to another user for an inclusive range of days.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CommitteeDelegation {
+    pub id: CommitteeDelegationId,
+    pub delegator_id: CommitteeMemberId,
+    pub delegate_id: CommitteeMemberId,
+    pub starts_on: NaiveDate,
+    pub ends_on: NaiveDate,
+}
+
+impl CommitteeDelegation {
+    pub fn is_active_on(&self, day: NaiveDate) -> bool {
+        self.starts_on <= day && day <= self.ends_on
+    }
+
+    pub(super) fn overlaps(&self, starts_on: NaiveDate, ends_on: NaiveDate) -> bool {
+        self.starts_on <= ends_on && starts_on <= self.ends_on
+    }
+}
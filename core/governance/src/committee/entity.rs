@@ -1,5 +1,6 @@
 use std::collections::HashSet;
 
+use chrono::NaiveDate;
 use derive_builder::Builder;
 #[cfg(feature = "json-schema")]
 use schemars::JsonSchema;
@@ -7,16 +8,35 @@ use serde::{Deserialize, Serialize};
 
 use es_entity::*;
 
-use crate::primitives::{CommitteeId, CommitteeMemberId};
+use crate::primitives::{CommitteeDelegationId, CommitteeId, CommitteeMemberId};
+
+use super::{delegation::CommitteeDelegation, error::CommitteeError};
 
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 #[es_event(id = "CommitteeId")]
 pub enum CommitteeEvent {
-    Initialized { id: CommitteeId, name: String },
-    MemberAdded { member_id: CommitteeMemberId },
-    MemberRemoved { member_id: CommitteeMemberId },
+    Initialized {
+        id: CommitteeId,
+        name: String,
+    },
+    MemberAdded {
+        member_id: CommitteeMemberId,
+    },
+    MemberRemoved {
+        member_id: CommitteeMemberId,
+    },
+    VoteDelegated {
+        delegation_id: CommitteeDelegationId,
+        delegator_id: CommitteeMemberId,
+        delegate_id: CommitteeMemberId,
+        starts_on: NaiveDate,
+        ends_on: NaiveDate,
+    },
+    DelegationRevoked {
+        delegation_id: CommitteeDelegationId,
+    },
 }
 
 #[derive(EsEntity, Builder)]
@@ -53,6 +73,129 @@ impl Committee {
         Idempotent::Executed(())
     }
 
+    /// Hands the vote of `delegator_id` to `delegate_id` between `starts_on` and `ends_on`.
+    ///
+    /// A member can only have one delegation covering any given day, and a delegate cannot
+    /// pass on votes while delegating their own. Votes cannot be delegated to another member
+    /// of the committee, as that would let one person cast two of the required approvals.
+    pub(crate) fn delegate_vote(
+        &mut self,
+        delegator_id: CommitteeMemberId,
+        delegate_id: CommitteeMemberId,
+        starts_on: NaiveDate,
+        ends_on: NaiveDate,
+    ) -> Result<Idempotent<CommitteeDelegationId>, CommitteeError> {
+        let delegations = self.delegations();
+        if delegations.iter().any(|d| {
+            d.delegator_id == delegator_id
+                && d.delegate_id == delegate_id
+                && d.starts_on == starts_on
+                && d.ends_on == ends_on
+        }) {
+            return Ok(Idempotent::AlreadyApplied);
+        }
+
+        if !self.members().contains(&delegator_id) {
+            return Err(CommitteeError::DelegatorNotMember(delegator_id));
+        }
+        if delegator_id == delegate_id {
+            return Err(CommitteeError::SelfDelegation);
+        }
+        if self.members().contains(&delegate_id) {
+            return Err(CommitteeError::DelegateIsMember(delegate_id));
+        }
+        if starts_on > ends_on {
+            return Err(CommitteeError::InvalidDelegationPeriod(starts_on, ends_on));
+        }
+        for delegation in delegations
+            .iter()
+            .filter(|d| d.overlaps(starts_on, ends_on))
+        {
+            if delegation.delegator_id == delegator_id {
+                return Err(CommitteeError::OverlappingDelegation(delegation.id));
+            }
+            if delegation.delegator_id == delegate_id || delegation.delegate_id == delegator_id {
+                return Err(CommitteeError::ChainedDelegation(delegation.id));
+            }
+        }
+
+        let delegation_id = CommitteeDelegationId::new();
+        self.events.push(CommitteeEvent::VoteDelegated {
+            delegation_id,
+            delegator_id,
+            delegate_id,
+            starts_on,
+            ends_on,
+        });
+        Ok(Idempotent::Executed(delegation_id))
+    }
+
+    pub(crate) fn revoke_delegation(
+        &mut self,
+        delegation_id: CommitteeDelegationId,
+    ) -> Result<Idempotent<()>, CommitteeError> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            CommitteeEvent::DelegationRevoked { delegation_id: id } if id == &delegation_id,
+        );
+        if !self.delegations().iter().any(|d| d.id == delegation_id) {
+            return Err(CommitteeError::DelegationNotFound(delegation_id));
+        }
+
+        self.events
+            .push(CommitteeEvent::DelegationRevoked { delegation_id });
+        Ok(Idempotent::Executed(()))
+    }
+
+    /// Delegations that have not been revoked.
+    pub fn delegations(&self) -> Vec<CommitteeDelegation> {
+        let mut delegations = Vec::new();
+        for event in self.events.iter_all() {
+            match event {
+                CommitteeEvent::VoteDelegated {
+                    delegation_id,
+                    delegator_id,
+                    delegate_id,
+                    starts_on,
+                    ends_on,
+                } => delegations.push(CommitteeDelegation {
+                    id: *delegation_id,
+                    delegator_id: *delegator_id,
+                    delegate_id: *delegate_id,
+                    starts_on: *starts_on,
+                    ends_on: *ends_on,
+                }),
+                CommitteeEvent::DelegationRevoked { delegation_id } => {
+                    delegations.retain(|d| d.id != *delegation_id);
+                }
+                _ => {}
+            }
+        }
+        delegations
+    }
+
+    /// Members whose vote `delegate_id` may cast on `day`. Delegates who have since joined
+    /// the committee only cast their own vote.
+    pub fn delegators_for(
+        &self,
+        delegate_id: CommitteeMemberId,
+        day: NaiveDate,
+    ) -> HashSet<CommitteeMemberId> {
+        let members = self.members();
+        if members.contains(&delegate_id) {
+            return HashSet::new();
+        }
+        self.delegations()
+            .into_iter()
+            .filter(|d| {
+                d.delegate_id == delegate_id
+                    && d.is_active_on(day)
+                    && members.contains(&d.delegator_id)
+            })
+            .map(|d| d.delegator_id)
+            .collect()
+    }
+
     pub fn n_members(&self) -> usize {
         self.events.iter_all().fold(0, |count, event| match event {
             CommitteeEvent::MemberAdded { .. } => count + 1,
@@ -89,6 +232,8 @@ impl TryFromEvents<CommitteeEvent> for Committee {
                 }
                 CommitteeEvent::MemberAdded { .. } => {}
                 CommitteeEvent::MemberRemoved { .. } => {}
+                CommitteeEvent::VoteDelegated { .. } => {}
+                CommitteeEvent::DelegationRevoked { .. } => {}
             }
         }
         builder.events(events).build()
@@ -119,3 +264,153 @@ impl IntoEvents<CommitteeEvent> for NewCommittee {
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn committee_with_members(members: &[CommitteeMemberId]) -> Committee {
+        let id = CommitteeId::new();
+        let events = std::iter::once(CommitteeEvent::Initialized {
+            id,
+            name: "committee".to_string(),
+        })
+        .chain(members.iter().map(|member_id| CommitteeEvent::MemberAdded {
+            member_id: *member_id,
+        }));
+        Committee::try_from_events(EntityEvents::init(id, events))
+            .expect("Could not build committee")
+    }
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, d).expect("valid date")
+    }
+
+    #[test]
+    fn delegate_vote() {
+        let (member, delegate) = (CommitteeMemberId::new(), CommitteeMemberId::new());
+        let mut committee = committee_with_members(&[member]);
+
+        assert!(
+            committee
+                .delegate_vote(member, delegate, day(5), day(10))
+                .unwrap()
+                .did_execute()
+        );
+        assert!(
+            committee
+                .delegate_vote(member, delegate, day(5), day(10))
+                .unwrap()
+                .was_already_applied()
+        );
+
+        assert!(committee.delegators_for(delegate, day(4)).is_empty());
+        assert!(committee.delegators_for(delegate, day(5)).contains(&member));
+        assert!(
+            committee
+                .delegators_for(delegate, day(10))
+                .contains(&member)
+        );
+        assert!(committee.delegators_for(delegate, day(11)).is_empty());
+    }
+
+    #[test]
+    fn delegate_vote_rejects_invalid_delegations() {
+        let (member, other, delegate, other_delegate) = (
+            CommitteeMemberId::new(),
+            CommitteeMemberId::new(),
+            CommitteeMemberId::new(),
+            CommitteeMemberId::new(),
+        );
+        let mut committee = committee_with_members(&[member, other]);
+
+        assert!(matches!(
+            committee.delegate_vote(delegate, member, day(1), day(2)),
+            Err(CommitteeError::DelegatorNotMember(_))
+        ));
+        assert!(matches!(
+            committee.delegate_vote(member, member, day(1), day(2)),
+            Err(CommitteeError::SelfDelegation)
+        ));
+        assert!(matches!(
+            committee.delegate_vote(member, other, day(1), day(2)),
+            Err(CommitteeError::DelegateIsMember(_))
+        ));
+        assert!(matches!(
+            committee.delegate_vote(member, delegate, day(3), day(2)),
+            Err(CommitteeError::InvalidDelegationPeriod(_, _))
+        ));
+
+        let _ = committee
+            .delegate_vote(member, delegate, day(5), day(10))
+            .unwrap();
+        assert!(matches!(
+            committee.delegate_vote(member, other_delegate, day(10), day(12)),
+            Err(CommitteeError::OverlappingDelegation(_))
+        ));
+        assert!(
+            committee
+                .delegate_vote(member, other_delegate, day(11), day(12))
+                .unwrap()
+                .did_execute()
+        );
+
+        let _ = committee.add_member(delegate);
+        assert!(matches!(
+            committee.delegate_vote(delegate, other_delegate, day(8), day(9)),
+            Err(CommitteeError::ChainedDelegation(_))
+        ));
+    }
+
+    #[test]
+    fn delegate_joining_the_committee_only_casts_own_vote() {
+        let (member, delegate) = (CommitteeMemberId::new(), CommitteeMemberId::new());
+        let mut committee = committee_with_members(&[member]);
+        let _ = committee
+            .delegate_vote(member, delegate, day(1), day(10))
+            .unwrap();
+        assert!(committee.delegators_for(delegate, day(5)).contains(&member));
+
+        let _ = committee.add_member(delegate);
+        assert!(committee.delegators_for(delegate, day(5)).is_empty());
+    }
+
+    #[test]
+    fn revoked_or_removed_delegators_do_not_count() {
+        let (member, other, delegate) = (
+            CommitteeMemberId::new(),
+            CommitteeMemberId::new(),
+            CommitteeMemberId::new(),
+        );
+        let mut committee = committee_with_members(&[member, other]);
+        let Idempotent::Executed(delegation_id) = committee
+            .delegate_vote(member, delegate, day(1), day(10))
+            .unwrap()
+        else {
+            panic!("delegation should be recorded");
+        };
+        let _ = committee
+            .delegate_vote(other, delegate, day(1), day(10))
+            .unwrap();
+
+        assert!(
+            committee
+                .revoke_delegation(delegation_id)
+                .unwrap()
+                .did_execute()
+        );
+        assert!(
+            committee
+                .revoke_delegation(delegation_id)
+                .unwrap()
+                .was_already_applied()
+        );
+        let _ = committee.remove_member(other);
+
+        assert!(committee.delegators_for(delegate, day(5)).is_empty());
+        assert!(matches!(
+            committee.revoke_delegation(CommitteeDelegationId::new()),
+            Err(CommitteeError::DelegationNotFound(_))
+        ));
+    }
+}
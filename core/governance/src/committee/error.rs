@@ -10,6 +10,20 @@ pub enum CommitteeError {
     EsEntityError(es_entity::EsEntityError),
     #[error("CommitteeError - CursorDestructureError: {0}")]
     CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("CommitteeError - DelegatorNotMember: {0}")]
+    DelegatorNotMember(crate::primitives::CommitteeMemberId),
+    #[error("CommitteeError - SelfDelegation")]
+    SelfDelegation,
+    #[error("CommitteeError - DelegateIsMember: {0}")]
+    DelegateIsMember(crate::primitives::CommitteeMemberId),
+    #[error("CommitteeError - InvalidDelegationPeriod: {0} is after {1}")]
+    InvalidDelegationPeriod(chrono::NaiveDate, chrono::NaiveDate),
+    #[error("CommitteeError - OverlappingDelegation: {0}")]
+    OverlappingDelegation(crate::primitives::CommitteeDelegationId),
+    #[error("CommitteeError - ChainedDelegation: {0}")]
+    ChainedDelegation(crate::primitives::CommitteeDelegationId),
+    #[error("CommitteeError - DelegationNotFound: {0}")]
+    DelegationNotFound(crate::primitives::CommitteeDelegationId),
 }
 
 es_entity::from_es_entity_error!(CommitteeError);
@@ -20,6 +34,13 @@ impl ErrorSeverity for CommitteeError {
             Self::Sqlx(_) => Level::ERROR,
             Self::EsEntityError(e) => e.severity(),
             Self::CursorDestructureError(_) => Level::ERROR,
+            Self::DelegatorNotMember(_) => Level::WARN,
+            Self::SelfDelegation => Level::WARN,
+            Self::DelegateIsMember(_) => Level::WARN,
+            Self::InvalidDelegationPeriod(_, _) => Level::WARN,
+            Self::OverlappingDelegation(_) => Level::WARN,
+            Self::ChainedDelegation(_) => Level::WARN,
+            Self::DelegationNotFound(_) => Level::WARN,
         }
     }
 }
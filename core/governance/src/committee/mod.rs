@@ -1,7 +1,9 @@
+mod delegation;
 mod entity;
 pub mod error;
 mod repo;
 
+pub use delegation::CommitteeDelegation;
 #[cfg(feature = "json-schema")]
 pub use entity::CommitteeEvent;
 pub use entity::{Committee, NewCommittee};
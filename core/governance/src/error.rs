@@ -18,6 +18,8 @@ pub enum GovernanceError {
     AuditError(#[from] audit::error::AuditError),
     #[error("GovernanceError - SubjectIsNotCommitteeMember")]
     SubjectIsNotCommitteeMember,
+    #[error("GovernanceError - InitiatorCannotApprove")]
+    InitiatorCannotApprove,
 }
 
 impl ErrorSeverity for GovernanceError {
@@ -30,6 +32,7 @@ impl ErrorSeverity for GovernanceError {
             Self::ApprovalProcessError(e) => e.severity(),
             Self::AuditError(e) => e.severity(),
             Self::SubjectIsNotCommitteeMember => Level::WARN,
+            Self::InitiatorCannotApprove => Level::WARN,
         }
     }
 }
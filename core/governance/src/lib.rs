@@ -33,6 +33,8 @@ pub mod event_schema {
     pub use crate::policy::PolicyEvent;
 }
 
+const VOTING_DEADLINE_BATCH_SIZE: usize = 100;
+
 pub struct Governance<Perms, E>
 where
     Perms: PermissionCheck,
@@ -43,6 +45,7 @@ where
     process_repo: ApprovalProcessRepo,
     authz: Perms,
     outbox: Outbox<E>,
+    clock: ClockHandle,
 }
 
 impl<Perms, E> Clone for Governance<Perms, E>
@@ -57,6 +60,7 @@ where
             process_repo: self.process_repo.clone(),
             authz: self.authz.clone(),
             outbox: self.outbox.clone(),
+            clock: self.clock.clone(),
         }
     }
 }
@@ -71,7 +75,7 @@ where
     pub fn new(pool: &sqlx::PgPool, authz: &Perms, outbox: &Outbox<E>, clock: ClockHandle) -> Self {
         let committee_repo = CommitteeRepo::new(pool, clock.clone());
         let policy_repo = PolicyRepo::new(pool, clock.clone());
-        let process_repo = ApprovalProcessRepo::new(pool, clock);
+        let process_repo = ApprovalProcessRepo::new(pool, clock.clone());
 
         Self {
             committee_repo,
@@ -79,6 +83,7 @@ where
             process_repo,
             authz: authz.clone(),
             outbox: outbox.clone(),
+            clock,
         }
     }
 
@@ -202,6 +207,87 @@ where
         Ok(policy)
     }
 
+    #[record_error_severity]
+    #[instrument(name = "governance.update_policy_rules", skip(self))]
+    pub async fn update_policy_rules(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        policy_id: impl Into<PolicyId> + std::fmt::Debug,
+        rules: ApprovalRules,
+    ) -> Result<Policy, GovernanceError> {
+        let policy_id = policy_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                GovernanceObject::policy(policy_id),
+                GovernanceAction::POLICY_UPDATE_RULES,
+            )
+            .await?;
+
+        let mut committee_sizes = HashMap::new();
+        for stage in rules.stages() {
+            if let std::collections::hash_map::Entry::Vacant(entry) =
+                committee_sizes.entry(stage.committee_id)
+            {
+                let committee = self.committee_repo.find_by_id(stage.committee_id).await?;
+                entry.insert(committee.n_members());
+            }
+        }
+
+        let mut policy = self.policy_repo.find_by_id(policy_id).await?;
+        if policy.update_rules(rules, &committee_sizes)?.did_execute() {
+            let mut db_tx = self.policy_repo.begin_op().await?;
+            self.policy_repo
+                .update_in_op(&mut db_tx, &mut policy)
+                .await?;
+            db_tx.commit().await?;
+        }
+
+        Ok(policy)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "governance.update_policy_voting_deadline", skip(self))]
+    pub async fn update_policy_voting_deadline(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        policy_id: impl Into<PolicyId> + std::fmt::Debug,
+        voting_deadline: Option<VotingDeadline>,
+    ) -> Result<Policy, GovernanceError> {
+        let policy_id = policy_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                GovernanceObject::policy(policy_id),
+                GovernanceAction::POLICY_UPDATE_RULES,
+            )
+            .await?;
+
+        let mut committee_sizes = HashMap::new();
+        if let Some(VotingDeadline {
+            on_expiry: ExpiryAction::Escalate { committee_id, .. },
+            ..
+        }) = voting_deadline
+        {
+            let committee = self.committee_repo.find_by_id(committee_id).await?;
+            committee_sizes.insert(committee.id, committee.n_members());
+        }
+
+        let mut policy = self.policy_repo.find_by_id(policy_id).await?;
+        if policy
+            .update_voting_deadline(voting_deadline, &committee_sizes)?
+            .did_execute()
+        {
+            let mut db_tx = self.policy_repo.begin_op().await?;
+            self.policy_repo
+                .update_in_op(&mut db_tx, &mut policy)
+                .await?;
+            db_tx.commit().await?;
+        }
+
+        Ok(policy)
+    }
+
     #[record_error_severity]
     #[instrument(name = "governance.find_all_policies", skip(self))]
     pub async fn find_all_policies<T: From<Policy>>(
@@ -219,6 +305,56 @@ where
         id: impl Into<ApprovalProcessId> + std::fmt::Debug,
         target_ref: String,
         process_type: ApprovalProcessType,
+    ) -> Result<ApprovalProcess, GovernanceError> {
+        self.start_process_with_amount_in_op(db, id, target_ref, process_type, None)
+            .await
+    }
+
+    /// Starts a process whose stages may depend on the amount (in minor units) being approved.
+    #[record_error_severity]
+    #[instrument(name = "governance.start_process_with_amount_in_op", skip(self, db))]
+    pub async fn start_process_with_amount_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: impl Into<ApprovalProcessId> + std::fmt::Debug,
+        target_ref: String,
+        process_type: ApprovalProcessType,
+        amount: Option<u64>,
+    ) -> Result<ApprovalProcess, GovernanceError> {
+        self.start_process_inner_in_op(db, id.into(), target_ref, process_type, amount, None)
+            .await
+    }
+
+    /// Starts a process on behalf of `initiated_by`, who is then not allowed to approve it.
+    #[record_error_severity]
+    #[instrument(name = "governance.start_process_initiated_by_in_op", skip(self, db))]
+    pub async fn start_process_initiated_by_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: impl Into<ApprovalProcessId> + std::fmt::Debug,
+        target_ref: String,
+        process_type: ApprovalProcessType,
+        initiated_by: String,
+    ) -> Result<ApprovalProcess, GovernanceError> {
+        self.start_process_inner_in_op(
+            db,
+            id.into(),
+            target_ref,
+            process_type,
+            None,
+            Some(initiated_by),
+        )
+        .await
+    }
+
+    async fn start_process_inner_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: ApprovalProcessId,
+        target_ref: String,
+        process_type: ApprovalProcessType,
+        amount: Option<u64>,
+        initiated_by: Option<String>,
     ) -> Result<ApprovalProcess, GovernanceError> {
         let policy = self.policy_repo.find_by_process_type(process_type).await?;
         self.authz
@@ -229,11 +365,11 @@ where
                 GovernanceAction::APPROVAL_PROCESS_CREATE,
             )
             .await?;
-        let new_process = policy.spawn_process(id.into(), target_ref);
+        let new_process =
+            policy.spawn_process(id, target_ref, amount, initiated_by, self.clock.now());
         let mut process = self.process_repo.create_in_op(db, new_process).await?;
-        let eligible = self.eligible_voters_for_process(&process).await?;
         if self
-            .maybe_fire_concluded_event_in_op(db, eligible, &mut process)
+            .maybe_fire_concluded_event_in_op(db, &mut process)
             .await?
         {
             self.process_repo.update_in_op(db, &mut process).await?;
@@ -263,11 +399,37 @@ where
         let member_id = CommitteeMemberId::try_from(sub)
             .map_err(|_| GovernanceError::SubjectIsNotCommitteeMember)?;
         let mut process = self.process_repo.find_by_id(process_id).await?;
-        let eligible = self.eligible_voters_for_process(&process).await?;
+        let initiator = Self::initiator_member_id(&process);
+        if initiator == Some(member_id) {
+            return Err(GovernanceError::InitiatorCannotApprove);
+        }
+        let (eligible, mut delegators) = self.voters_for_member(&process, member_id).await?;
+        if let Some(initiator) = initiator {
+            delegators.remove(&initiator);
+        }
+
+        let mut executed = process.approve(&eligible, member_id, None).did_execute();
+        let mut delegated = Vec::new();
+        for delegator_id in delegators {
+            if process
+                .approve(&eligible, delegator_id, Some(member_id))
+                .did_execute()
+            {
+                delegated.push(delegator_id);
+            }
+        }
+        executed |= !delegated.is_empty();
 
-        if process.approve(&eligible, member_id).did_execute() {
+        if executed {
             let mut db = self.policy_repo.begin_op().await?;
-            self.maybe_fire_concluded_event_in_op(&mut db, eligible, &mut process)
+            self.record_delegated_votes_in_op(
+                &mut db,
+                sub,
+                &delegated,
+                GovernanceAction::APPROVAL_PROCESS_APPROVE_AS_DELEGATE,
+            )
+            .await?;
+            self.maybe_fire_concluded_event_in_op(&mut db, &mut process)
                 .await?;
             self.process_repo
                 .update_in_op(&mut db, &mut process)
@@ -301,17 +463,32 @@ where
         let member_id = CommitteeMemberId::try_from(sub)
             .map_err(|_| GovernanceError::SubjectIsNotCommitteeMember)?;
         let mut process = self.process_repo.find_by_id(process_id).await?;
-        let eligible = if let Some(committee_id) = process.committee_id() {
-            self.committee_repo
-                .find_by_id(committee_id)
-                .await?
-                .members()
-        } else {
-            HashSet::new()
-        };
-        if process.deny(&eligible, member_id, reason).did_execute() {
+        let (eligible, delegators) = self.voters_for_member(&process, member_id).await?;
+
+        let mut executed = process
+            .deny(&eligible, member_id, None, reason.clone())
+            .did_execute();
+        let mut delegated = Vec::new();
+        for delegator_id in delegators {
+            if process
+                .deny(&eligible, delegator_id, Some(member_id), reason.clone())
+                .did_execute()
+            {
+                delegated.push(delegator_id);
+            }
+        }
+        executed |= !delegated.is_empty();
+
+        if executed {
             let mut db = self.policy_repo.begin_op().await?;
-            self.maybe_fire_concluded_event_in_op(&mut db, eligible, &mut process)
+            self.record_delegated_votes_in_op(
+                &mut db,
+                sub,
+                &delegated,
+                GovernanceAction::APPROVAL_PROCESS_DENY_AS_DELEGATE,
+            )
+            .await?;
+            self.maybe_fire_concluded_event_in_op(&mut db, &mut process)
                 .await?;
             self.process_repo
                 .update_in_op(&mut db, &mut process)
@@ -352,32 +529,157 @@ where
         Ok(committee)
     }
 
-    async fn maybe_fire_concluded_event_in_op(
+    /// Applies the expiry action of open processes whose voting deadline has passed and
+    /// reminds the members who have not voted yet on the ones that remain open.
+    #[record_error_severity]
+    #[instrument(name = "governance.process_voting_deadlines", skip(self))]
+    pub async fn process_voting_deadlines(
+        &self,
+        day: chrono::NaiveDate,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), GovernanceError> {
+        let mut after = None;
+        loop {
+            let (processes, has_next_page) = self
+                .process_repo
+                .list_with_voting_deadline(after, VOTING_DEADLINE_BATCH_SIZE)
+                .await?;
+            after = processes.last().map(|process| process.id);
+
+            for mut process in processes {
+                let mut op = self.process_repo.begin_op().await?;
+                let expired = self
+                    .expire_process_in_op(&mut op, &mut process, now)
+                    .await?;
+                let reminded = self
+                    .remind_pending_voters_in_op(&mut op, &mut process, day)
+                    .await?;
+                if expired || reminded {
+                    self.process_repo
+                        .update_in_op(&mut op, &mut process)
+                        .await?;
+                }
+                op.commit().await?;
+            }
+
+            if !has_next_page {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    async fn expire_process_in_op(
         &self,
         op: &mut es_entity::DbOp<'_>,
-        eligible: HashSet<CommitteeMemberId>,
         process: &mut ApprovalProcess,
+        now: chrono::DateTime<chrono::Utc>,
     ) -> Result<bool, GovernanceError> {
+        let es_entity::Idempotent::Executed(progress) = process.expire(now) else {
+            return Ok(false);
+        };
+
         self.authz
             .audit()
             .record_system_entry_in_op(
                 op,
                 crate::primitives::GOVERNANCE,
                 GovernanceObject::approval_process(process.id),
-                GovernanceAction::APPROVAL_PROCESS_CONCLUDE,
+                GovernanceAction::APPROVAL_PROCESS_EXPIRE,
             )
             .await?;
 
-        if let es_entity::Idempotent::Executed(_) = process.check_concluded(eligible) {
+        if let ApprovalProcessProgress::Concluded = progress {
             let entity = PublicApprovalProcess::from(&*process);
             self.outbox
                 .publish_all_persisted(op, [GovernanceEvent::ApprovalProcessConcluded { entity }])
                 .await?;
-
-            return Ok(true);
+        } else {
+            self.maybe_fire_concluded_event_in_op(op, process).await?;
         }
 
-        Ok(false)
+        Ok(true)
+    }
+
+    async fn remind_pending_voters_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        process: &mut ApprovalProcess,
+        day: chrono::NaiveDate,
+    ) -> Result<bool, GovernanceError> {
+        let Some(expires_at) = process.expires_at() else {
+            return Ok(false);
+        };
+        let eligible = self.eligible_voters_for_process(process).await?;
+        let es_entity::Idempotent::Executed(pending_voters) =
+            process.remind_pending_voters(day, &eligible)
+        else {
+            return Ok(false);
+        };
+
+        self.authz
+            .audit()
+            .record_system_entry_in_op(
+                op,
+                crate::primitives::GOVERNANCE,
+                GovernanceObject::approval_process(process.id),
+                GovernanceAction::APPROVAL_PROCESS_REMIND,
+            )
+            .await?;
+
+        let entity = PublicApprovalProcess::from(&*process);
+        self.outbox
+            .publish_all_persisted(
+                op,
+                [GovernanceEvent::ApprovalProcessVoteReminder {
+                    entity,
+                    pending_voters,
+                    expires_at,
+                }],
+            )
+            .await?;
+
+        Ok(true)
+    }
+
+    async fn maybe_fire_concluded_event_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        process: &mut ApprovalProcess,
+    ) -> Result<bool, GovernanceError> {
+        self.authz
+            .audit()
+            .record_system_entry_in_op(
+                op,
+                crate::primitives::GOVERNANCE,
+                GovernanceObject::approval_process(process.id),
+                GovernanceAction::APPROVAL_PROCESS_CONCLUDE,
+            )
+            .await?;
+
+        let mut changed = false;
+        loop {
+            let eligible = self.eligible_voters_for_process(process).await?;
+            match process.check_concluded(eligible) {
+                es_entity::Idempotent::Executed(
+                    ApprovalProcessProgress::StageApproved | ApprovalProcessProgress::Escalated,
+                ) => {
+                    changed = true;
+                }
+                es_entity::Idempotent::Executed(ApprovalProcessProgress::Concluded) => {
+                    let entity = PublicApprovalProcess::from(&*process);
+                    self.outbox
+                        .publish_all_persisted(
+                            op,
+                            [GovernanceEvent::ApprovalProcessConcluded { entity }],
+                        )
+                        .await?;
+
+                    return Ok(true);
+                }
+                es_entity::Idempotent::AlreadyApplied => return Ok(changed),
+            }
+        }
     }
 
     #[record_error_severity]
@@ -430,6 +732,65 @@ where
         Ok(committee)
     }
 
+    #[record_error_severity]
+    #[instrument(name = "governance.delegate_committee_vote", skip(self))]
+    pub async fn delegate_committee_vote(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        committee_id: impl Into<CommitteeId> + std::fmt::Debug,
+        delegator_id: impl Into<CommitteeMemberId> + std::fmt::Debug,
+        delegate_id: impl Into<CommitteeMemberId> + std::fmt::Debug,
+        starts_on: chrono::NaiveDate,
+        ends_on: chrono::NaiveDate,
+    ) -> Result<Committee, GovernanceError> {
+        let committee_id = committee_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                GovernanceObject::committee(committee_id),
+                GovernanceAction::COMMITTEE_DELEGATE_VOTE,
+            )
+            .await?;
+
+        let mut committee = self.committee_repo.find_by_id(committee_id).await?;
+        if committee
+            .delegate_vote(delegator_id.into(), delegate_id.into(), starts_on, ends_on)?
+            .did_execute()
+        {
+            self.committee_repo.update(&mut committee).await?;
+        }
+
+        Ok(committee)
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "governance.revoke_committee_delegation", skip(self))]
+    pub async fn revoke_committee_delegation(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        committee_id: impl Into<CommitteeId> + std::fmt::Debug,
+        delegation_id: impl Into<CommitteeDelegationId> + std::fmt::Debug,
+    ) -> Result<Committee, GovernanceError> {
+        let committee_id = committee_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                GovernanceObject::committee(committee_id),
+                GovernanceAction::COMMITTEE_REVOKE_DELEGATION,
+            )
+            .await?;
+
+        let mut committee = self.committee_repo.find_by_id(committee_id).await?;
+        if committee
+            .revoke_delegation(delegation_id.into())?
+            .did_execute()
+        {
+            self.committee_repo.update(&mut committee).await?;
+        }
+
+        Ok(committee)
+    }
+
     #[record_error_severity]
     #[instrument(name = "governance.find_committee_by_id", skip(self))]
     pub async fn find_committee_by_id(
@@ -565,12 +926,73 @@ where
         if let Some(committee) = committee {
             let member_id = CommitteeMemberId::try_from(sub)
                 .map_err(|_| GovernanceError::SubjectIsNotCommitteeMember)?;
-            Ok(process.can_member_vote(member_id, committee.members()))
+            let members = committee.members();
+            let today = self.clock.now().date_naive();
+            Ok(process.can_member_vote(member_id, members.clone())
+                || committee
+                    .delegators_for(member_id, today)
+                    .into_iter()
+                    .any(|delegator_id| process.can_member_vote(delegator_id, members.clone())))
         } else {
             Ok(false)
         }
     }
 
+    /// The committee member who initiated the process, if it was started by one.
+    fn initiator_member_id(process: &ApprovalProcess) -> Option<CommitteeMemberId>
+    where
+        CommitteeMemberId:
+            for<'a> TryFrom<&'a <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject>,
+    {
+        let initiated_by = process.initiated_by.as_deref()?;
+        let sub = initiated_by
+            .parse::<<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject>()
+            .ok()?;
+        CommitteeMemberId::try_from(&sub).ok()
+    }
+
+    /// Eligible voters of the current stage, along with the members whose vote
+    /// `member_id` holds through a delegation today.
+    async fn voters_for_member(
+        &self,
+        process: &ApprovalProcess,
+        member_id: CommitteeMemberId,
+    ) -> Result<(HashSet<CommitteeMemberId>, HashSet<CommitteeMemberId>), GovernanceError> {
+        let res = if let Some(committee_id) = process.committee_id() {
+            let committee = self.committee_repo.find_by_id(committee_id).await?;
+            let today = self.clock.now().date_naive();
+            (
+                committee.members(),
+                committee.delegators_for(member_id, today),
+            )
+        } else {
+            (HashSet::new(), HashSet::new())
+        };
+        Ok(res)
+    }
+
+    async fn record_delegated_votes_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        delegators: &[CommitteeMemberId],
+        action: GovernanceAction,
+    ) -> Result<(), GovernanceError> {
+        for delegator_id in delegators {
+            self.authz
+                .audit()
+                .record_entry_in_op(
+                    op,
+                    sub,
+                    GovernanceObject::committee_member(*delegator_id),
+                    action,
+                    true,
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
     async fn eligible_voters_for_process(
         &self,
         process: &ApprovalProcess,
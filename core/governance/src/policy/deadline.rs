@@ -0,0 +1,32 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::primitives::CommitteeId;
+
+/// How long committee members have to vote on a process and what happens once the time runs out.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct VotingDeadline {
+    pub voting_period_days: u32,
+    pub on_expiry: ExpiryAction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ExpiryAction {
+    AutoDeny,
+    AutoApprove,
+    /// Hands the vote to a fallback committee, which gets a fresh voting period.
+    /// The process is denied if the fallback committee does not decide in time either.
+    Escalate {
+        committee_id: CommitteeId,
+        threshold: usize,
+    },
+}
+
+impl VotingDeadline {
+    pub fn expires_at(&self, from: DateTime<Utc>) -> DateTime<Utc> {
+        from + Duration::days(i64::from(self.voting_period_days))
+    }
+}
@@ -5,7 +5,13 @@ use serde::{Deserialize, Serialize};
 
 use es_entity::*;
 
-use super::{error::PolicyError, rules::ApprovalRules};
+use std::collections::HashMap;
+
+use super::{
+    deadline::{ExpiryAction, VotingDeadline},
+    error::PolicyError,
+    rules::{ApprovalRules, ApprovalStage},
+};
 use crate::{approval_process::NewApprovalProcess, primitives::*};
 
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
@@ -21,6 +27,9 @@ pub enum PolicyEvent {
     ApprovalRulesUpdated {
         rules: ApprovalRules,
     },
+    VotingDeadlineUpdated {
+        voting_deadline: Option<VotingDeadline>,
+    },
 }
 
 #[derive(EsEntity, Builder)]
@@ -29,6 +38,8 @@ pub struct Policy {
     pub id: PolicyId,
     pub process_type: ApprovalProcessType,
     pub rules: ApprovalRules,
+    #[builder(default)]
+    pub voting_deadline: Option<VotingDeadline>,
     events: EntityEvents<PolicyEvent>,
 }
 
@@ -47,13 +58,23 @@ impl Policy {
         &self,
         id: ApprovalProcessId,
         target_ref: String,
+        amount: Option<u64>,
+        initiated_by: Option<String>,
+        started_at: chrono::DateTime<chrono::Utc>,
     ) -> NewApprovalProcess {
         NewApprovalProcess::builder()
             .id(id)
             .target_ref(target_ref)
             .policy_id(self.id)
             .process_type(self.process_type.clone())
-            .rules(self.rules)
+            .rules(self.rules.clone())
+            .amount(amount)
+            .initiated_by(initiated_by)
+            .voting_deadline(self.voting_deadline)
+            .expires_at(
+                self.voting_deadline
+                    .map(|deadline| deadline.expires_at(started_at)),
+            )
             .build()
             .expect("failed to build new approval process")
     }
@@ -85,10 +106,120 @@ impl Policy {
             committee_id,
         };
 
+        self.events.push(PolicyEvent::ApprovalRulesUpdated {
+            rules: self.rules.clone(),
+        });
+        Ok(Idempotent::Executed(()))
+    }
+
+    /// Replaces the rules, validating every stage against its committee.
+    ///
+    /// `committee_sizes` must contain the member count of every committee the rules refer to.
+    pub fn update_rules(
+        &mut self,
+        rules: ApprovalRules,
+        committee_sizes: &HashMap<CommitteeId, usize>,
+    ) -> Result<Idempotent<()>, PolicyError> {
+        if self.rules == rules {
+            return Ok(Idempotent::AlreadyApplied);
+        }
+
+        match &rules {
+            ApprovalRules::MultiStage { stages } if stages.is_empty() => {
+                return Err(PolicyError::NoApprovalStages);
+            }
+            ApprovalRules::AmountBanded { bands } => {
+                if bands.is_empty() {
+                    return Err(PolicyError::NoApprovalStages);
+                }
+                let mut min_amounts: Vec<_> = bands.iter().map(|b| b.min_amount).collect();
+                min_amounts.sort_unstable();
+                min_amounts.dedup();
+                if min_amounts.len() != bands.len() {
+                    return Err(PolicyError::DuplicateAmountBand);
+                }
+            }
+            _ => {}
+        }
+
+        for stage in rules.stages() {
+            Self::validate_stage(&stage, committee_sizes)?;
+        }
+
+        self.rules = rules;
+        self.events.push(PolicyEvent::ApprovalRulesUpdated {
+            rules: self.rules.clone(),
+        });
+        Ok(Idempotent::Executed(()))
+    }
+
+    /// Sets how long members have to vote on new processes, or removes the deadline.
+    ///
+    /// `committee_sizes` must contain the member count of the escalation committee, if any.
+    pub fn update_voting_deadline(
+        &mut self,
+        voting_deadline: Option<VotingDeadline>,
+        committee_sizes: &HashMap<CommitteeId, usize>,
+    ) -> Result<Idempotent<()>, PolicyError> {
+        if self.voting_deadline == voting_deadline {
+            return Ok(Idempotent::AlreadyApplied);
+        }
+
+        if let Some(deadline) = voting_deadline {
+            if deadline.voting_period_days < 1 {
+                return Err(PolicyError::VotingPeriodTooShort);
+            }
+            if let ExpiryAction::Escalate {
+                committee_id,
+                threshold,
+            } = deadline.on_expiry
+            {
+                let fallback = ApprovalStage {
+                    committee_id,
+                    threshold,
+                    deny_threshold: None,
+                };
+                Self::validate_stage(&fallback, committee_sizes)?;
+            }
+        }
+
+        self.voting_deadline = voting_deadline;
         self.events
-            .push(PolicyEvent::ApprovalRulesUpdated { rules: self.rules });
+            .push(PolicyEvent::VotingDeadlineUpdated { voting_deadline });
         Ok(Idempotent::Executed(()))
     }
+
+    fn validate_stage(
+        stage: &ApprovalStage,
+        committee_sizes: &HashMap<CommitteeId, usize>,
+    ) -> Result<(), PolicyError> {
+        let n_committee_members = committee_sizes
+            .get(&stage.committee_id)
+            .copied()
+            .unwrap_or_default();
+        if stage.threshold < 1 {
+            return Err(PolicyError::PolicyThresholdTooLow(
+                stage.committee_id,
+                stage.threshold,
+            ));
+        }
+        if stage.threshold > n_committee_members {
+            return Err(PolicyError::PolicyThresholdTooHigh(
+                stage.committee_id,
+                stage.threshold,
+            ));
+        }
+        match stage.deny_threshold {
+            Some(deny_threshold) if deny_threshold < 1 => Err(PolicyError::PolicyThresholdTooLow(
+                stage.committee_id,
+                deny_threshold,
+            )),
+            Some(deny_threshold) if deny_threshold > n_committee_members => Err(
+                PolicyError::PolicyThresholdTooHigh(stage.committee_id, deny_threshold),
+            ),
+            _ => Ok(()),
+        }
+    }
 }
 
 impl TryFromEvents<PolicyEvent> for Policy {
@@ -105,9 +236,14 @@ impl TryFromEvents<PolicyEvent> for Policy {
                     builder = builder
                         .id(*id)
                         .process_type(process_type.clone())
-                        .rules(*rules)
+                        .rules(rules.clone())
+                }
+                PolicyEvent::ApprovalRulesUpdated { rules, .. } => {
+                    builder = builder.rules(rules.clone())
                 }
-                PolicyEvent::ApprovalRulesUpdated { rules, .. } => builder = builder.rules(*rules),
+                PolicyEvent::VotingDeadlineUpdated {
+                    voting_deadline, ..
+                } => builder = builder.voting_deadline(*voting_deadline),
             }
         }
         builder.events(events).build()
@@ -149,6 +285,7 @@ impl IntoEvents<PolicyEvent> for NewPolicy {
 mod test {
 
     use super::*;
+    use crate::policy::AmountBand;
 
     fn init_events() -> EntityEvents<PolicyEvent> {
         EntityEvents::init(
@@ -204,4 +341,141 @@ mod test {
 
         assert!(matches!(res, Err(PolicyError::PolicyThresholdTooLow(_, _))));
     }
+
+    fn stage(committee_id: CommitteeId, threshold: usize) -> ApprovalStage {
+        ApprovalStage {
+            committee_id,
+            threshold,
+            deny_threshold: None,
+        }
+    }
+
+    #[test]
+    fn update_rules_with_stages() {
+        let mut policy = Policy::try_from_events(init_events()).unwrap();
+        let (credit, risk) = (CommitteeId::new(), CommitteeId::new());
+        let sizes = HashMap::from([(credit, 3), (risk, 1)]);
+        let rules = ApprovalRules::MultiStage {
+            stages: vec![stage(credit, 2), stage(risk, 1)],
+        };
+
+        assert!(
+            policy
+                .update_rules(rules.clone(), &sizes)
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(policy.rules, rules);
+        assert_eq!(policy.committee_id(), Some(credit));
+        assert!(
+            policy
+                .update_rules(rules, &sizes)
+                .unwrap()
+                .was_already_applied()
+        );
+    }
+
+    #[test]
+    fn update_rules_rejects_invalid_stages() {
+        let mut policy = Policy::try_from_events(init_events()).unwrap();
+        let committee_id = CommitteeId::new();
+        let sizes = HashMap::from([(committee_id, 1)]);
+
+        let res = policy.update_rules(ApprovalRules::MultiStage { stages: vec![] }, &sizes);
+        assert!(matches!(res, Err(PolicyError::NoApprovalStages)));
+
+        let res = policy.update_rules(
+            ApprovalRules::MultiStage {
+                stages: vec![stage(committee_id, 2)],
+            },
+            &sizes,
+        );
+        assert!(matches!(
+            res,
+            Err(PolicyError::PolicyThresholdTooHigh(_, _))
+        ));
+
+        let res = policy.update_rules(
+            ApprovalRules::AmountBanded {
+                bands: vec![
+                    AmountBand {
+                        min_amount: 0,
+                        stages: vec![],
+                    },
+                    AmountBand {
+                        min_amount: 0,
+                        stages: vec![stage(committee_id, 1)],
+                    },
+                ],
+            },
+            &sizes,
+        );
+        assert!(matches!(res, Err(PolicyError::DuplicateAmountBand)));
+    }
+
+    #[test]
+    fn update_voting_deadline() {
+        let mut policy = Policy::try_from_events(init_events()).unwrap();
+        let fallback = CommitteeId::new();
+        let sizes = HashMap::from([(fallback, 2)]);
+        let deadline = VotingDeadline {
+            voting_period_days: 3,
+            on_expiry: ExpiryAction::Escalate {
+                committee_id: fallback,
+                threshold: 2,
+            },
+        };
+
+        assert!(
+            policy
+                .update_voting_deadline(Some(deadline), &sizes)
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(policy.voting_deadline, Some(deadline));
+        assert!(
+            policy
+                .update_voting_deadline(Some(deadline), &sizes)
+                .unwrap()
+                .was_already_applied()
+        );
+        assert!(
+            policy
+                .update_voting_deadline(None, &sizes)
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(policy.voting_deadline, None);
+    }
+
+    #[test]
+    fn update_voting_deadline_rejects_invalid_deadline() {
+        let mut policy = Policy::try_from_events(init_events()).unwrap();
+        let fallback = CommitteeId::new();
+        let sizes = HashMap::from([(fallback, 1)]);
+
+        let res = policy.update_voting_deadline(
+            Some(VotingDeadline {
+                voting_period_days: 0,
+                on_expiry: ExpiryAction::AutoDeny,
+            }),
+            &sizes,
+        );
+        assert!(matches!(res, Err(PolicyError::VotingPeriodTooShort)));
+
+        let res = policy.update_voting_deadline(
+            Some(VotingDeadline {
+                voting_period_days: 1,
+                on_expiry: ExpiryAction::Escalate {
+                    committee_id: fallback,
+                    threshold: 2,
+                },
+            }),
+            &sizes,
+        );
+        assert!(matches!(
+            res,
+            Err(PolicyError::PolicyThresholdTooHigh(_, _))
+        ));
+    }
 }
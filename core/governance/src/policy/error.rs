@@ -16,6 +16,12 @@ pub enum PolicyError {
     PolicyThresholdTooHigh(crate::primitives::CommitteeId, usize),
     #[error("PolicyError - Threshold {1} too low for committee {0}")]
     PolicyThresholdTooLow(crate::primitives::CommitteeId, usize),
+    #[error("PolicyError - NoApprovalStages")]
+    NoApprovalStages,
+    #[error("PolicyError - DuplicateAmountBand")]
+    DuplicateAmountBand,
+    #[error("PolicyError - VotingPeriodTooShort")]
+    VotingPeriodTooShort,
 }
 
 es_entity::from_es_entity_error!(PolicyError);
@@ -41,6 +47,9 @@ impl ErrorSeverity for PolicyError {
             Self::DuplicateApprovalProcessType => Level::WARN,
             Self::PolicyThresholdTooHigh(_, _) => Level::WARN,
             Self::PolicyThresholdTooLow(_, _) => Level::WARN,
+            Self::NoApprovalStages => Level::WARN,
+            Self::DuplicateAmountBand => Level::WARN,
+            Self::VotingPeriodTooShort => Level::WARN,
         }
     }
 }
@@ -1,8 +1,10 @@
+mod deadline;
 mod entity;
 pub mod error;
 mod repo;
 mod rules;
 
+pub use deadline::*;
 #[cfg(feature = "json-schema")]
 pub use entity::PolicyEvent;
 pub use entity::{NewPolicy, Policy};
@@ -4,7 +4,7 @@ use std::collections::HashSet;
 
 use crate::primitives::CommitteeId;
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ApprovalRules {
@@ -13,39 +13,102 @@ pub enum ApprovalRules {
         threshold: usize,
     },
     SystemAutoApprove,
+    MultiStage {
+        stages: Vec<ApprovalStage>,
+    },
+    AmountBanded {
+        bands: Vec<AmountBand>,
+    },
+}
+
+/// A single committee vote that has to pass before the process can move on.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ApprovalStage {
+    pub committee_id: CommitteeId,
+    pub threshold: usize,
+    /// Denials needed to deny the stage. A single denial is enough when unset.
+    #[serde(default)]
+    pub deny_threshold: Option<usize>,
+}
+
+/// Stages that apply to processes whose amount (in minor units) is at least `min_amount`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct AmountBand {
+    pub min_amount: u64,
+    pub stages: Vec<ApprovalStage>,
 }
 
 impl ApprovalRules {
+    /// The committee that votes first, if it does not depend on the process amount.
     pub fn committee_id(&self) -> Option<CommitteeId> {
         match self {
             ApprovalRules::CommitteeThreshold { committee_id, .. } => Some(*committee_id),
-            ApprovalRules::SystemAutoApprove => None,
+            ApprovalRules::MultiStage { stages } => stages.first().map(|s| s.committee_id),
+            ApprovalRules::SystemAutoApprove | ApprovalRules::AmountBanded { .. } => None,
+        }
+    }
+
+    /// Every stage referenced by the rules, across all amount bands.
+    pub fn stages(&self) -> Vec<ApprovalStage> {
+        match self {
+            ApprovalRules::AmountBanded { bands } => bands
+                .iter()
+                .flat_map(|band| band.stages.iter().copied())
+                .collect(),
+            _ => self.stages_for_amount(None),
+        }
+    }
+
+    /// Resolves the ordered stages a process has to pass.
+    ///
+    /// Amount-banded rules pick the band with the highest `min_amount` not above
+    /// `amount`. Without an amount the strictest (highest) band applies.
+    pub fn stages_for_amount(&self, amount: Option<u64>) -> Vec<ApprovalStage> {
+        match self {
+            ApprovalRules::SystemAutoApprove => vec![],
+            ApprovalRules::CommitteeThreshold {
+                committee_id,
+                threshold,
+            } => vec![ApprovalStage {
+                committee_id: *committee_id,
+                threshold: *threshold,
+                deny_threshold: None,
+            }],
+            ApprovalRules::MultiStage { stages } => stages.clone(),
+            ApprovalRules::AmountBanded { bands } => {
+                let band = match amount {
+                    Some(amount) => bands
+                        .iter()
+                        .filter(|band| band.min_amount <= amount)
+                        .max_by_key(|band| band.min_amount)
+                        .or_else(|| bands.iter().min_by_key(|band| band.min_amount)),
+                    None => bands.iter().max_by_key(|band| band.min_amount),
+                };
+                band.map(|band| band.stages.clone()).unwrap_or_default()
+            }
         }
     }
+}
 
+impl ApprovalStage {
     pub fn is_approved_or_denied<Id: Eq + std::hash::Hash>(
         &self,
         eligible_members: &HashSet<Id>,
         approving_members: &HashSet<Id>,
         denying_members: &HashSet<Id>,
     ) -> Option<bool> {
-        if !denying_members.is_empty() {
+        if denying_members.len() >= self.deny_threshold.unwrap_or(1) {
             return Some(false);
         }
-        match self {
-            ApprovalRules::SystemAutoApprove => Some(true),
-            ApprovalRules::CommitteeThreshold { threshold, .. }
-                if eligible_members.intersection(approving_members).count() >= *threshold =>
-            {
-                Some(true)
-            }
-            ApprovalRules::CommitteeThreshold { threshold, .. }
-                if eligible_members.len() < *threshold =>
-            {
-                Some(false)
-            }
-            _ => None,
+        if eligible_members.intersection(approving_members).count() >= self.threshold {
+            return Some(true);
         }
+        if eligible_members.difference(denying_members).count() < self.threshold {
+            return Some(false);
+        }
+        None
     }
 }
 
@@ -57,12 +120,17 @@ mod tests {
         ids.iter().copied().collect()
     }
 
+    fn stage(threshold: usize, deny_threshold: Option<usize>) -> ApprovalStage {
+        ApprovalStage {
+            committee_id: CommitteeId::new(),
+            threshold,
+            deny_threshold,
+        }
+    }
+
     #[test]
     fn test_committee_threshold_approval() {
-        let rules = ApprovalRules::CommitteeThreshold {
-            threshold: 3,
-            committee_id: CommitteeId::new(),
-        };
+        let rules = stage(3, None);
 
         let eligible = make_set(&[1, 2, 3, 4, 5]);
         let approving = make_set(&[1, 2, 3]);
@@ -79,10 +147,7 @@ mod tests {
 
     #[test]
     fn test_committee_threshold_denial() {
-        let rules = ApprovalRules::CommitteeThreshold {
-            threshold: 3,
-            committee_id: CommitteeId::new(),
-        };
+        let rules = stage(3, None);
 
         let eligible = make_set(&[1, 2, 3, 4, 5]);
         let approving = make_set(&[2, 3, 4]);
@@ -99,10 +164,7 @@ mod tests {
 
     #[test]
     fn test_committee_threshold_pending() {
-        let rules = ApprovalRules::CommitteeThreshold {
-            threshold: 3,
-            committee_id: CommitteeId::new(),
-        };
+        let rules = stage(3, None);
 
         let eligible = make_set(&[1, 2, 3, 4, 5]);
         let approving = make_set(&[1, 2]);
@@ -128,19 +190,15 @@ mod tests {
     fn test_automatic() {
         let rules = ApprovalRules::SystemAutoApprove;
 
-        assert_eq!(
-            rules.is_approved_or_denied(&make_set(&[1, 2, 3]), &HashSet::new(), &HashSet::new()),
-            Some(true),
-            "Automatic rules should always approve regardless of inputs"
+        assert!(
+            rules.stages_for_amount(None).is_empty(),
+            "Automatic rules should not require any stage"
         );
     }
 
     #[test]
     fn test_edge_cases() {
-        let rules = ApprovalRules::CommitteeThreshold {
-            threshold: 3,
-            committee_id: CommitteeId::new(),
-        };
+        let rules = stage(3, None);
 
         // Empty sets
         let empty = HashSet::new();
@@ -158,4 +216,82 @@ mod tests {
             "Should be denied when threshold exceeds eligible set size"
         );
     }
+
+    #[test]
+    fn test_deny_threshold() {
+        let rules = stage(2, Some(2));
+
+        let eligible = make_set(&[1, 2, 3, 4]);
+        assert_eq!(
+            rules.is_approved_or_denied(&eligible, &make_set(&[2]), &make_set(&[1])),
+            None,
+            "A single denial should not deny when 2 are required"
+        );
+        assert_eq!(
+            rules.is_approved_or_denied(&eligible, &make_set(&[2]), &make_set(&[1, 3])),
+            Some(false),
+            "Should be denied once the deny threshold is reached"
+        );
+        assert_eq!(
+            rules.is_approved_or_denied(&eligible, &make_set(&[2, 4]), &make_set(&[1])),
+            Some(true),
+            "Should be approved despite a denial below the deny threshold"
+        );
+    }
+
+    #[test]
+    fn test_deny_threshold_when_approval_unreachable() {
+        let rules = stage(3, Some(3));
+
+        let eligible = make_set(&[1, 2, 3, 4]);
+        assert_eq!(
+            rules.is_approved_or_denied(&eligible, &HashSet::new(), &make_set(&[1, 2])),
+            Some(false),
+            "Should be denied when too few members remain to reach the threshold"
+        );
+    }
+
+    #[test]
+    fn test_amount_banded_stages() {
+        let small = stage(1, None);
+        let large = stage(2, None);
+        let rules = ApprovalRules::AmountBanded {
+            bands: vec![
+                AmountBand {
+                    min_amount: 100_000,
+                    stages: vec![small, large],
+                },
+                AmountBand {
+                    min_amount: 0,
+                    stages: vec![],
+                },
+                AmountBand {
+                    min_amount: 1_000,
+                    stages: vec![small],
+                },
+            ],
+        };
+
+        assert!(rules.stages_for_amount(Some(999)).is_empty());
+        assert_eq!(rules.stages_for_amount(Some(1_000)), vec![small]);
+        assert_eq!(rules.stages_for_amount(Some(99_999)), vec![small]);
+        assert_eq!(rules.stages_for_amount(Some(100_000)), vec![small, large]);
+        assert_eq!(
+            rules.stages_for_amount(None),
+            vec![small, large],
+            "Unknown amounts should use the strictest band"
+        );
+    }
+
+    #[test]
+    fn test_multi_stage_stages() {
+        let first = stage(2, None);
+        let second = stage(1, None);
+        let rules = ApprovalRules::MultiStage {
+            stages: vec![first, second],
+        };
+
+        assert_eq!(rules.stages_for_amount(Some(0)), vec![first, second]);
+        assert_eq!(rules.committee_id(), Some(first.committee_id));
+    }
 }
@@ -4,7 +4,13 @@ use std::{borrow::Cow, fmt::Display, str::FromStr};
 use authz::{ActionPermission, AllOrOne, action_description::*, map_action};
 
 pub const GOVERNANCE: audit::SystemActor = audit::SystemActor::new("governance");
-es_entity::entity_id! { ApprovalProcessId, CommitteeId, PolicyId, CommitteeMemberId }
+es_entity::entity_id! {
+    ApprovalProcessId,
+    CommitteeId,
+    PolicyId,
+    CommitteeMemberId,
+    CommitteeDelegationId,
+}
 
 #[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -66,6 +72,10 @@ impl GovernanceAction {
     pub const COMMITTEE_ADD_MEMBER: Self = GovernanceAction::Committee(CommitteeAction::AddMember);
     pub const COMMITTEE_REMOVE_MEMBER: Self =
         GovernanceAction::Committee(CommitteeAction::RemoveMember);
+    pub const COMMITTEE_DELEGATE_VOTE: Self =
+        GovernanceAction::Committee(CommitteeAction::DelegateVote);
+    pub const COMMITTEE_REVOKE_DELEGATION: Self =
+        GovernanceAction::Committee(CommitteeAction::RevokeDelegation);
 
     pub const POLICY_CREATE: Self = GovernanceAction::Policy(PolicyAction::Create);
     pub const POLICY_READ: Self = GovernanceAction::Policy(PolicyAction::Read);
@@ -82,8 +92,16 @@ impl GovernanceAction {
         GovernanceAction::ApprovalProcess(ApprovalProcessAction::Approve);
     pub const APPROVAL_PROCESS_DENY: Self =
         GovernanceAction::ApprovalProcess(ApprovalProcessAction::Deny);
+    pub const APPROVAL_PROCESS_APPROVE_AS_DELEGATE: Self =
+        GovernanceAction::ApprovalProcess(ApprovalProcessAction::ApproveAsDelegate);
+    pub const APPROVAL_PROCESS_DENY_AS_DELEGATE: Self =
+        GovernanceAction::ApprovalProcess(ApprovalProcessAction::DenyAsDelegate);
     pub const APPROVAL_PROCESS_CONCLUDE: Self =
         GovernanceAction::ApprovalProcess(ApprovalProcessAction::Conclude);
+    pub const APPROVAL_PROCESS_EXPIRE: Self =
+        GovernanceAction::ApprovalProcess(ApprovalProcessAction::Expire);
+    pub const APPROVAL_PROCESS_REMIND: Self =
+        GovernanceAction::ApprovalProcess(ApprovalProcessAction::Remind);
 
     pub fn actions() -> Vec<ActionMapping> {
         use GovernanceActionDiscriminants::*;
@@ -133,6 +151,8 @@ pub enum CommitteeAction {
     Create,
     AddMember,
     RemoveMember,
+    DelegateVote,
+    RevokeDelegation,
     Read,
     List,
 }
@@ -142,7 +162,11 @@ impl ActionPermission for CommitteeAction {
         match self {
             Self::Read | Self::List => PERMISSION_SET_GOVERNANCE_VIEWER,
 
-            Self::Create | Self::AddMember | Self::RemoveMember => PERMISSION_SET_GOVERNANCE_WRITER,
+            Self::Create
+            | Self::AddMember
+            | Self::RemoveMember
+            | Self::DelegateVote
+            | Self::RevokeDelegation => PERMISSION_SET_GOVERNANCE_WRITER,
         }
     }
 }
@@ -174,7 +198,11 @@ pub enum ApprovalProcessAction {
     List,
     Approve,
     Deny,
+    ApproveAsDelegate,
+    DenyAsDelegate,
     Conclude,
+    Expire,
+    Remind,
 }
 
 impl ActionPermission for ApprovalProcessAction {
@@ -182,14 +210,20 @@ impl ActionPermission for ApprovalProcessAction {
         match self {
             Self::Read | Self::List => PERMISSION_SET_GOVERNANCE_VIEWER,
 
-            Self::Create | Self::Approve | Self::Deny | Self::Conclude => {
-                PERMISSION_SET_GOVERNANCE_WRITER
-            }
+            Self::Create
+            | Self::Approve
+            | Self::Deny
+            | Self::ApproveAsDelegate
+            | Self::DenyAsDelegate
+            | Self::Conclude
+            | Self::Expire
+            | Self::Remind => PERMISSION_SET_GOVERNANCE_WRITER,
         }
     }
 }
 
 pub type CommitteeAllOrOne = AllOrOne<CommitteeId>;
+pub type CommitteeMemberAllOrOne = AllOrOne<CommitteeMemberId>;
 pub type PolicyAllOrOne = AllOrOne<PolicyId>;
 pub type ApprovalProcessAllOrOne = AllOrOne<ApprovalProcessId>;
 
@@ -203,6 +237,7 @@ permission_sets_macro::permission_sets! {
 #[strum_discriminants(strum(serialize_all = "kebab-case"))]
 pub enum GovernanceObject {
     Committee(CommitteeAllOrOne),
+    CommitteeMember(CommitteeMemberAllOrOne),
     Policy(PolicyAllOrOne),
     ApprovalProcess(ApprovalProcessAllOrOne),
 }
@@ -216,6 +251,10 @@ impl GovernanceObject {
         GovernanceObject::Committee(AllOrOne::ById(id))
     }
 
+    pub fn committee_member(id: CommitteeMemberId) -> Self {
+        GovernanceObject::CommitteeMember(AllOrOne::ById(id))
+    }
+
     pub fn all_policies() -> Self {
         GovernanceObject::Policy(AllOrOne::All)
     }
@@ -239,6 +278,7 @@ impl Display for GovernanceObject {
         use GovernanceObject::*;
         match self {
             Committee(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            CommitteeMember(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             Policy(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             ApprovalProcess(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
         }
@@ -256,6 +296,10 @@ impl FromStr for GovernanceObject {
                 let obj_ref = id.parse().map_err(|_| "could not parse GovernanceObject")?;
                 GovernanceObject::Committee(obj_ref)
             }
+            CommitteeMember => {
+                let obj_ref = id.parse().map_err(|_| "could not parse GovernanceObject")?;
+                GovernanceObject::CommitteeMember(obj_ref)
+            }
             Policy => {
                 let obj_ref = id.parse().map_err(|_| "could not parse GovernanceObject")?;
                 GovernanceObject::Policy(obj_ref)
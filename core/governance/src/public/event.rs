@@ -1,13 +1,22 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "json-schema")]
 use schemars::JsonSchema;
 
 use super::PublicApprovalProcess;
+use crate::primitives::CommitteeMemberId;
 
 #[derive(Debug, Serialize, Deserialize, strum::AsRefStr)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 #[serde(tag = "type")]
 pub enum GovernanceEvent {
-    ApprovalProcessConcluded { entity: PublicApprovalProcess },
+    ApprovalProcessConcluded {
+        entity: PublicApprovalProcess,
+    },
+    ApprovalProcessVoteReminder {
+        entity: PublicApprovalProcess,
+        pending_voters: Vec<CommitteeMemberId>,
+        expires_at: DateTime<Utc>,
+    },
 }
@@ -0,0 +1,86 @@
+use rust_decimal::Decimal;
+
+use money::UsdCents;
+
+use crate::primitives::PriceOfOneBTC;
+
+/// Aggregates quotes from several providers into a single price.
+///
+/// Quotes deviating from the median of all quotes by more than
+/// `max_deviation_from_median_pct` percent are discarded, and the median of
+/// the remaining quotes is returned.
+pub fn aggregate_quotes(
+    quotes: &[PriceOfOneBTC],
+    max_deviation_from_median_pct: Decimal,
+) -> Option<PriceOfOneBTC> {
+    let reference = median(quotes.to_vec())?;
+    let reference_usd = reference.into_inner().to_usd();
+
+    let accepted = quotes
+        .iter()
+        .copied()
+        .filter(|quote| {
+            let deviation = (quote.into_inner().to_usd() - reference_usd).abs();
+            deviation * Decimal::ONE_HUNDRED <= reference_usd * max_deviation_from_median_pct
+        })
+        .collect::<Vec<_>>();
+
+    median(accepted)
+}
+
+fn median(mut quotes: Vec<PriceOfOneBTC>) -> Option<PriceOfOneBTC> {
+    if quotes.is_empty() {
+        return None;
+    }
+    quotes.sort();
+
+    let mid = quotes.len() / 2;
+    if quotes.len() % 2 == 1 {
+        return Some(quotes[mid]);
+    }
+
+    let lower = quotes[mid - 1].into_inner().into_inner();
+    let upper = quotes[mid].into_inner().into_inner();
+    Some(PriceOfOneBTC::new(UsdCents::from(
+        lower + (upper - lower) / 2,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn price(cents: u64) -> PriceOfOneBTC {
+        PriceOfOneBTC::new(UsdCents::from(cents))
+    }
+
+    #[test]
+    fn no_quotes() {
+        assert_eq!(aggregate_quotes(&[], dec!(5)), None);
+    }
+
+    #[test]
+    fn median_of_odd_number_of_quotes() {
+        let quotes = [price(6_010_000), price(6_000_000), price(6_020_000)];
+        assert_eq!(aggregate_quotes(&quotes, dec!(5)), Some(price(6_010_000)));
+    }
+
+    #[test]
+    fn median_of_even_number_of_quotes() {
+        let quotes = [price(6_000_000), price(6_010_000)];
+        assert_eq!(aggregate_quotes(&quotes, dec!(5)), Some(price(6_005_000)));
+    }
+
+    #[test]
+    fn outliers_are_rejected() {
+        let quotes = [
+            price(6_000_000),
+            price(6_010_000),
+            price(6_020_000),
+            price(9_000_000),
+        ];
+        assert_eq!(aggregate_quotes(&quotes, dec!(5)), Some(price(6_010_000)));
+    }
+}
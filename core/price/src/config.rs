@@ -1,16 +1,34 @@
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 
+use std::path::PathBuf;
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct PriceConfig {
     #[serde(default = "default_providers")]
     pub providers: Vec<PriceProvider>,
+    #[serde(default = "default_max_price_age_secs")]
+    pub max_price_age_secs: u64,
+    #[serde(default = "default_max_deviation_from_median_pct")]
+    pub max_deviation_from_median_pct: Decimal,
+}
+
+impl PriceConfig {
+    pub fn max_price_age(&self) -> chrono::Duration {
+        chrono::Duration::seconds(
+            i64::try_from(self.max_price_age_secs).expect("max_price_age_secs out of range"),
+        )
+    }
 }
 
 impl Default for PriceConfig {
     fn default() -> Self {
         Self {
             providers: default_providers(),
+            max_price_age_secs: default_max_price_age_secs(),
+            max_deviation_from_median_pct: default_max_deviation_from_median_pct(),
         }
     }
 }
@@ -19,8 +37,26 @@ impl Default for PriceConfig {
 #[serde(rename_all = "snake_case")]
 pub enum PriceProvider {
     Bitfinex,
+    Kraken,
+    Coinbase,
+    /// Always quotes the configured USD price. Intended for tests and local setups.
+    Static {
+        usd_per_btc: Decimal,
+    },
+    /// Reads the USD price from a file on every fetch. Intended for tests and local setups.
+    File {
+        path: PathBuf,
+    },
 }
 
 fn default_providers() -> Vec<PriceProvider> {
     vec![PriceProvider::Bitfinex]
 }
+
+fn default_max_price_age_secs() -> u64 {
+    300
+}
+
+fn default_max_deviation_from_median_pct() -> Decimal {
+    dec!(5)
+}
@@ -6,21 +6,42 @@ use tracing_utils::ErrorSeverity;
 pub enum PriceError {
     #[error("PriceError - BfxClientError: {0}")]
     BfxClientError(#[from] bfx_client::BfxClientError),
+    #[error("PriceError - KrakenClientError: {0}")]
+    KrakenClientError(#[from] kraken_client::KrakenClientError),
+    #[error("PriceError - CoinbaseClientError: {0}")]
+    CoinbaseClientError(#[from] coinbase_client::CoinbaseClientError),
     #[error("PriceError - ConversionError: {0}")]
     ConversionError(#[from] money::ConversionError),
     #[error("PriceError - JobError: {0}")]
     JobError(#[from] job::error::JobError),
+    #[error("PriceError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("PriceError - Io: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("PriceError - DecimalError: {0}")]
+    DecimalError(#[from] rust_decimal::Error),
     #[error("PriceError - Price not yet available")]
     PriceUnavailable,
+    #[error("PriceError - No provider returned a usable quote")]
+    NoQuotesAvailable,
+    #[error("PriceError - Price is stale, last updated {age_secs}s ago")]
+    StalePrice { age_secs: i64 },
 }
 
 impl ErrorSeverity for PriceError {
     fn severity(&self) -> Level {
         match self {
             Self::BfxClientError(e) => e.severity(),
+            Self::KrakenClientError(e) => e.severity(),
+            Self::CoinbaseClientError(e) => e.severity(),
             Self::ConversionError(e) => e.severity(),
             Self::JobError(_) => Level::ERROR,
+            Self::Sqlx(_) => Level::ERROR,
+            Self::Io(_) => Level::ERROR,
+            Self::DecimalError(_) => Level::ERROR,
             Self::PriceUnavailable => Level::WARN,
+            Self::NoQuotesAvailable => Level::WARN,
+            Self::StalePrice { .. } => Level::WARN,
         }
     }
 }
@@ -0,0 +1,180 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::PgPool;
+
+use tracing_macros::record_error_severity;
+
+use money::UsdCents;
+
+use crate::{error::PriceError, primitives::PriceOfOneBTC};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DailyPriceOhlc {
+    pub day: NaiveDate,
+    pub open: PriceOfOneBTC,
+    pub high: PriceOfOneBTC,
+    pub low: PriceOfOneBTC,
+    pub close: PriceOfOneBTC,
+}
+
+/// Persists price ticks and maintains a daily open/high/low/close downsample of them.
+#[derive(Clone)]
+pub struct PriceHistory {
+    pool: PgPool,
+}
+
+impl PriceHistory {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+
+    #[record_error_severity]
+    #[tracing::instrument(name = "core.price.history.record_tick", skip(self))]
+    pub async fn record_tick(
+        &self,
+        price: PriceOfOneBTC,
+        recorded_at: DateTime<Utc>,
+    ) -> Result<(), PriceError> {
+        let cents = to_db_cents(price);
+        let mut tx = self.pool.begin().await?;
+
+        let inserted = sqlx::query!(
+            r#"
+            INSERT INTO core_price_ticks (recorded_at, usd_cents_per_btc)
+            VALUES ($1, $2)
+            ON CONFLICT (recorded_at) DO NOTHING
+            "#,
+            recorded_at,
+            cents,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if inserted.rows_affected() > 0 {
+            sqlx::query!(
+                r#"
+                INSERT INTO core_price_daily_ohlc (
+                    day,
+                    open_usd_cents_per_btc,
+                    high_usd_cents_per_btc,
+                    low_usd_cents_per_btc,
+                    close_usd_cents_per_btc,
+                    opened_at,
+                    closed_at
+                )
+                VALUES ($1, $2, $2, $2, $2, $3, $3)
+                ON CONFLICT (day) DO UPDATE SET
+                    open_usd_cents_per_btc = CASE
+                        WHEN EXCLUDED.opened_at < core_price_daily_ohlc.opened_at
+                        THEN EXCLUDED.open_usd_cents_per_btc
+                        ELSE core_price_daily_ohlc.open_usd_cents_per_btc
+                    END,
+                    close_usd_cents_per_btc = CASE
+                        WHEN EXCLUDED.closed_at > core_price_daily_ohlc.closed_at
+                        THEN EXCLUDED.close_usd_cents_per_btc
+                        ELSE core_price_daily_ohlc.close_usd_cents_per_btc
+                    END,
+                    high_usd_cents_per_btc = GREATEST(core_price_daily_ohlc.high_usd_cents_per_btc, EXCLUDED.high_usd_cents_per_btc),
+                    low_usd_cents_per_btc = LEAST(core_price_daily_ohlc.low_usd_cents_per_btc, EXCLUDED.low_usd_cents_per_btc),
+                    opened_at = LEAST(core_price_daily_ohlc.opened_at, EXCLUDED.opened_at),
+                    closed_at = GREATEST(core_price_daily_ohlc.closed_at, EXCLUDED.closed_at)
+                "#,
+                recorded_at.date_naive(),
+                cents,
+                recorded_at,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    #[record_error_severity]
+    #[tracing::instrument(name = "core.price.history.price_at", skip(self))]
+    pub async fn price_at(&self, at: DateTime<Utc>) -> Result<PriceOfOneBTC, PriceError> {
+        let row = sqlx::query!(
+            r#"
+            SELECT usd_cents_per_btc
+            FROM core_price_ticks
+            WHERE recorded_at <= $1
+            ORDER BY recorded_at DESC
+            LIMIT 1
+            "#,
+            at,
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(|row| from_db_cents(row.usd_cents_per_btc))
+            .ok_or(PriceError::PriceUnavailable)
+    }
+
+    #[record_error_severity]
+    #[tracing::instrument(name = "core.price.history.price_before", skip(self))]
+    pub async fn price_before(&self, before: DateTime<Utc>) -> Result<PriceOfOneBTC, PriceError> {
+        let row = sqlx::query!(
+            r#"
+            SELECT usd_cents_per_btc
+            FROM core_price_ticks
+            WHERE recorded_at < $1
+            ORDER BY recorded_at DESC
+            LIMIT 1
+            "#,
+            before,
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(|row| from_db_cents(row.usd_cents_per_btc))
+            .ok_or(PriceError::PriceUnavailable)
+    }
+
+    #[record_error_severity]
+    #[tracing::instrument(name = "core.price.history.daily_ohlc", skip(self))]
+    pub async fn daily_ohlc(
+        &self,
+        from: NaiveDate,
+        until: NaiveDate,
+    ) -> Result<Vec<DailyPriceOhlc>, PriceError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                day,
+                open_usd_cents_per_btc,
+                high_usd_cents_per_btc,
+                low_usd_cents_per_btc,
+                close_usd_cents_per_btc
+            FROM core_price_daily_ohlc
+            WHERE day >= $1 AND day <= $2
+            ORDER BY day
+            "#,
+            from,
+            until,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| DailyPriceOhlc {
+                day: row.day,
+                open: from_db_cents(row.open_usd_cents_per_btc),
+                high: from_db_cents(row.high_usd_cents_per_btc),
+                low: from_db_cents(row.low_usd_cents_per_btc),
+                close: from_db_cents(row.close_usd_cents_per_btc),
+            })
+            .collect())
+    }
+}
+
+fn to_db_cents(price: PriceOfOneBTC) -> i64 {
+    i64::try_from(price.into_inner().into_inner()).expect("price should fit in i64")
+}
+
+fn from_db_cents(cents: i64) -> PriceOfOneBTC {
+    PriceOfOneBTC::new(UsdCents::from(
+        u64::try_from(cents).expect("stored price should not be negative"),
+    ))
+}
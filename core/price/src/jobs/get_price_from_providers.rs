@@ -0,0 +1,163 @@
+use async_trait::async_trait;
+use futures::future::join_all;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tokio::{select, time::Duration};
+
+use job::*;
+use obix::out::{Outbox, OutboxEventMarker};
+
+use crate::{
+    CorePriceEvent, PRICE_UPDATED_EVENT_TYPE, PriceOfOneBTC, aggregation::aggregate_quotes,
+    error::PriceError, history::PriceHistory, source::PriceSource,
+};
+
+const PRICE_UPDATE_INTERVAL: Duration = Duration::from_secs(60);
+
+#[tracing::instrument(
+    name = "core.price.fetch_aggregated_price",
+    skip(sources),
+    fields(quotes = tracing::field::Empty, price = tracing::field::Empty)
+)]
+pub async fn fetch_aggregated_price(
+    sources: &[PriceSource],
+    max_deviation_from_median_pct: Decimal,
+) -> Result<PriceOfOneBTC, PriceError> {
+    let quotes = join_all(sources.iter().map(|source| source.fetch_price()))
+        .await
+        .into_iter()
+        .filter_map(Result::ok)
+        .collect::<Vec<_>>();
+    tracing::Span::current().record("quotes", quotes.len());
+
+    let price = aggregate_quotes(&quotes, max_deviation_from_median_pct)
+        .ok_or(PriceError::NoQuotesAvailable)?;
+    tracing::Span::current().record("price", tracing::field::display(price));
+
+    Ok(price)
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct GetPriceFromProvidersJobConfig<E>
+where
+    E: OutboxEventMarker<CorePriceEvent> + Send + Sync + 'static,
+{
+    pub _phantom: std::marker::PhantomData<E>,
+}
+
+pub struct GetPriceFromProvidersJobInit<E>
+where
+    E: OutboxEventMarker<CorePriceEvent> + Send + Sync + 'static,
+{
+    sources: Vec<PriceSource>,
+    max_deviation_from_median_pct: Decimal,
+    history: PriceHistory,
+    outbox: Outbox<E>,
+}
+
+impl<E> GetPriceFromProvidersJobInit<E>
+where
+    E: OutboxEventMarker<CorePriceEvent> + Send + Sync + 'static,
+{
+    pub fn new(
+        sources: Vec<PriceSource>,
+        max_deviation_from_median_pct: Decimal,
+        history: &PriceHistory,
+        outbox: &Outbox<E>,
+    ) -> Self {
+        Self {
+            sources,
+            max_deviation_from_median_pct,
+            history: history.clone(),
+            outbox: outbox.clone(),
+        }
+    }
+}
+
+const GET_PRICE_FROM_PROVIDERS_JOB_TYPE: JobType =
+    JobType::new("cron.core-price.get-price-from-providers");
+
+impl<E> JobInitializer for GetPriceFromProvidersJobInit<E>
+where
+    E: OutboxEventMarker<CorePriceEvent> + Send + Sync + 'static,
+{
+    type Config = GetPriceFromProvidersJobConfig<E>;
+    fn job_type(&self) -> JobType {
+        GET_PRICE_FROM_PROVIDERS_JOB_TYPE
+    }
+
+    fn init(
+        &self,
+        _job: &Job,
+        _: JobSpawner<Self::Config>,
+    ) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(GetPriceFromProvidersJobRunner::<E> {
+            sources: self.sources.clone(),
+            max_deviation_from_median_pct: self.max_deviation_from_median_pct,
+            history: self.history.clone(),
+            outbox: self.outbox.clone(),
+        }))
+    }
+
+    fn retry_on_error_settings(&self) -> RetrySettings {
+        RetrySettings::repeat_indefinitely()
+    }
+}
+
+pub struct GetPriceFromProvidersJobRunner<E>
+where
+    E: OutboxEventMarker<CorePriceEvent> + Send + Sync + 'static,
+{
+    sources: Vec<PriceSource>,
+    max_deviation_from_median_pct: Decimal,
+    history: PriceHistory,
+    outbox: Outbox<E>,
+}
+
+#[async_trait]
+impl<E> JobRunner for GetPriceFromProvidersJobRunner<E>
+where
+    E: OutboxEventMarker<CorePriceEvent> + Send + Sync + 'static,
+{
+    async fn run(
+        &self,
+        mut current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        loop {
+            // A round without any usable quote is skipped rather than failed so
+            // that the last published price simply ages out.
+            if let Ok(price) =
+                fetch_aggregated_price(&self.sources, self.max_deviation_from_median_pct).await
+            {
+                let timestamp = current_job.clock().now();
+                self.outbox
+                    .publish_ephemeral(
+                        PRICE_UPDATED_EVENT_TYPE,
+                        CorePriceEvent::PriceUpdated { price, timestamp },
+                    )
+                    .await?;
+                // History only backs point-in-time lookups, so failing to persist
+                // a tick must not hold back the live price.
+                if let Err(e) = self.history.record_tick(price, timestamp).await {
+                    tracing::warn!(error = %e, "Failed to record price tick");
+                }
+            }
+
+            select! {
+                biased;
+
+                _ = current_job.shutdown_requested() => {
+                    tracing::info!(
+                        job_id = %current_job.id(),
+                        job_type = %GET_PRICE_FROM_PROVIDERS_JOB_TYPE,
+                        "Shutdown signal received"
+                    );
+                    return Ok(JobCompletion::RescheduleNow);
+                }
+                _ = tokio::time::sleep(PRICE_UPDATE_INTERVAL) => {
+                    tracing::debug!(job_id = %current_job.id(), "Sleep completed, continuing");
+                }
+            }
+        }
+    }
+}
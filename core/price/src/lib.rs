@@ -1,11 +1,16 @@
 #![cfg_attr(feature = "fail-on-warnings", deny(warnings))]
 #![cfg_attr(feature = "fail-on-warnings", deny(clippy::all))]
+mod aggregation;
 mod config;
 pub mod error;
 mod event;
+mod history;
 pub mod jobs;
 mod primitives;
+pub mod source;
 
+use chrono::{DateTime, NaiveDate, Utc};
+use es_entity::clock::ClockHandle;
 use futures::StreamExt;
 use job::Jobs;
 use obix::out::{EphemeralOutboxEvent, Outbox, OutboxEventMarker};
@@ -17,41 +22,62 @@ pub use config::*;
 use error::PriceError;
 
 pub use event::*;
-use jobs::get_price_from_bfx;
+pub use history::{DailyPriceOhlc, PriceHistory};
+use jobs::get_price_from_providers;
 pub use primitives::*;
+use source::PriceSource;
+
+#[derive(Clone, Copy)]
+struct LatestPrice {
+    price: PriceOfOneBTC,
+    timestamp: DateTime<Utc>,
+}
 
 #[derive(Clone)]
 pub struct Price {
-    receiver: watch::Receiver<Option<PriceOfOneBTC>>,
+    receiver: watch::Receiver<Option<LatestPrice>>,
+    history: PriceHistory,
+    clock: ClockHandle,
+    max_price_age: chrono::Duration,
     _handle: Arc<JoinHandle<Result<(), Box<dyn std::error::Error + Send + Sync>>>>,
 }
 
 impl Price {
-    #[tracing::instrument(name = "core.price.init", skip(jobs, outbox), err)]
+    #[tracing::instrument(name = "core.price.init", skip(pool, jobs, outbox, clock), err)]
     pub async fn init<E>(
+        pool: &sqlx::PgPool,
         config: PriceConfig,
         jobs: &mut Jobs,
         outbox: &Outbox<E>,
+        clock: ClockHandle,
     ) -> Result<Self, PriceError>
     where
         E: OutboxEventMarker<CorePriceEvent> + Send + Sync + 'static,
     {
-        for provider in &config.providers {
-            match provider {
-                PriceProvider::Bitfinex => {
-                    let spawner = jobs.add_initializer(
-                        get_price_from_bfx::GetPriceFromClientJobInit::new(outbox),
-                    );
-                    spawner
-                        .spawn_unique(
-                            job::JobId::new(),
-                            get_price_from_bfx::GetPriceFromClientJobConfig::<E> {
-                                _phantom: std::marker::PhantomData,
-                            },
-                        )
-                        .await?;
-                }
-            }
+        let history = PriceHistory::new(pool);
+
+        let sources = config
+            .providers
+            .iter()
+            .map(PriceSource::try_from_provider)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if !sources.is_empty() {
+            let spawner =
+                jobs.add_initializer(get_price_from_providers::GetPriceFromProvidersJobInit::new(
+                    sources,
+                    config.max_deviation_from_median_pct,
+                    &history,
+                    outbox,
+                ));
+            spawner
+                .spawn_unique(
+                    job::JobId::new(),
+                    get_price_from_providers::GetPriceFromProvidersJobConfig::<E> {
+                        _phantom: std::marker::PhantomData,
+                    },
+                )
+                .await?;
         }
 
         let (tx, rx) = watch::channel(None);
@@ -60,11 +86,62 @@ impl Price {
 
         Ok(Self {
             receiver: rx,
+            history,
+            clock,
+            max_price_age: config.max_price_age(),
             _handle: Arc::new(handle),
         })
     }
 
+    /// Latest known price regardless of its age. Waits until a first price is available.
     pub async fn usd_cents_per_btc(&self) -> PriceOfOneBTC {
+        self.latest().await.price
+    }
+
+    /// Latest known price, rejected as stale when it is older than the configured
+    /// maximum price age. Use this for any action that relies on the current CVL.
+    pub async fn fresh_usd_cents_per_btc(&self) -> Result<PriceOfOneBTC, PriceError> {
+        let LatestPrice { price, timestamp } = self.latest().await;
+        let age = self.clock.now() - timestamp;
+        if age > self.max_price_age {
+            return Err(PriceError::StalePrice {
+                age_secs: age.num_seconds(),
+            });
+        }
+        Ok(price)
+    }
+
+    /// Price as it was known at `at`, i.e. the last persisted tick recorded at or before it.
+    pub async fn usd_cents_per_btc_at(
+        &self,
+        at: DateTime<Utc>,
+    ) -> Result<PriceOfOneBTC, PriceError> {
+        self.history.price_at(at).await
+    }
+
+    /// Closing price of `day`, i.e. the last persisted tick recorded before the next day began.
+    pub async fn usd_cents_per_btc_at_close_of(
+        &self,
+        day: NaiveDate,
+    ) -> Result<PriceOfOneBTC, PriceError> {
+        let next_day = day
+            .succ_opt()
+            .expect("date within range")
+            .and_hms_opt(0, 0, 0)
+            .expect("valid midnight")
+            .and_utc();
+        self.history.price_before(next_day).await
+    }
+
+    pub async fn daily_ohlc(
+        &self,
+        from: NaiveDate,
+        until: NaiveDate,
+    ) -> Result<Vec<DailyPriceOhlc>, PriceError> {
+        self.history.daily_ohlc(from, until).await
+    }
+
+    async fn latest(&self) -> LatestPrice {
         let mut rec = self.receiver.clone();
         loop {
             if let Some(res) = *rec.borrow() {
@@ -75,7 +152,7 @@ impl Price {
     }
 
     fn spawn_price_listener<E>(
-        tx: watch::Sender<Option<PriceOfOneBTC>>,
+        tx: watch::Sender<Option<LatestPrice>>,
         outbox: Outbox<E>,
     ) -> JoinHandle<Result<(), Box<dyn std::error::Error + Send + Sync>>>
     where
@@ -86,7 +163,7 @@ impl Price {
 
     #[tracing::instrument(name = "core.price.listen_for_updates", skip_all, err)]
     async fn listen_for_price_updates<E>(
-        tx: watch::Sender<Option<PriceOfOneBTC>>,
+        tx: watch::Sender<Option<LatestPrice>>,
         outbox: Outbox<E>,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
     where
@@ -110,7 +187,7 @@ impl Price {
         err
     )]
     async fn process_message<E>(
-        tx: &watch::Sender<Option<PriceOfOneBTC>>,
+        tx: &watch::Sender<Option<LatestPrice>>,
         message: &EphemeralOutboxEvent<E>,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
     where
@@ -125,7 +202,10 @@ impl Price {
             Span::current().record("event_type", "PriceUpdated");
             Span::current().record("price", tracing::field::display(new_price));
             Span::current().record("timestamp", tracing::field::debug(timestamp));
-            tx.send(Some(*new_price))?;
+            tx.send(Some(LatestPrice {
+                price: *new_price,
+                timestamp: *timestamp,
+            }))?;
         }
 
         Ok(())
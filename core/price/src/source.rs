@@ -0,0 +1,70 @@
+use std::{path::PathBuf, str::FromStr, sync::Arc};
+
+use rust_decimal::{Decimal, RoundingStrategy};
+use tracing_macros::record_error_severity;
+
+use bfx_client::BfxClient;
+use coinbase_client::CoinbaseClient;
+use kraken_client::KrakenClient;
+use money::UsdCents;
+
+use crate::{config::PriceProvider, error::PriceError, primitives::PriceOfOneBTC};
+
+#[derive(Clone)]
+pub enum PriceSource {
+    Bitfinex(Arc<BfxClient>),
+    Kraken(Arc<KrakenClient>),
+    Coinbase(Arc<CoinbaseClient>),
+    Static(PriceOfOneBTC),
+    File(PathBuf),
+}
+
+impl PriceSource {
+    pub fn try_from_provider(provider: &PriceProvider) -> Result<Self, PriceError> {
+        let source = match provider {
+            PriceProvider::Bitfinex => Self::Bitfinex(Arc::new(BfxClient::new())),
+            PriceProvider::Kraken => Self::Kraken(Arc::new(KrakenClient::new())),
+            PriceProvider::Coinbase => Self::Coinbase(Arc::new(CoinbaseClient::new())),
+            PriceProvider::Static { usd_per_btc } => {
+                Self::Static(PriceOfOneBTC::new(UsdCents::try_from_usd(
+                    usd_per_btc.round_dp_with_strategy(2, RoundingStrategy::ToZero),
+                )?))
+            }
+            PriceProvider::File { path } => Self::File(path.clone()),
+        };
+        Ok(source)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Bitfinex(_) => "bitfinex",
+            Self::Kraken(_) => "kraken",
+            Self::Coinbase(_) => "coinbase",
+            Self::Static(_) => "static",
+            Self::File(_) => "file",
+        }
+    }
+
+    #[record_error_severity]
+    #[tracing::instrument(name = "core.price.source.fetch_price", skip(self), fields(source = self.name()))]
+    pub async fn fetch_price(&self) -> Result<PriceOfOneBTC, PriceError> {
+        let usd = match self {
+            Self::Bitfinex(client) => client.btc_usd_tick().await?.last_price,
+            Self::Kraken(client) => client
+                .btc_usd_ticker()
+                .await?
+                .last_price()
+                .ok_or(PriceError::PriceUnavailable)?,
+            Self::Coinbase(client) => client.btc_usd_spot_price().await?.amount,
+            Self::Static(price) => return Ok(*price),
+            Self::File(path) => {
+                let contents = std::fs::read_to_string(path)?;
+                Decimal::from_str(contents.trim())?
+            }
+        };
+
+        Ok(PriceOfOneBTC::new(UsdCents::try_from_usd(
+            usd.round_dp_with_strategy(2, RoundingStrategy::ToZero),
+        )?))
+    }
+}
@@ -1,20 +1,24 @@
 mod helpers;
 
-use std::sync::Arc;
-
 use rand::RngExt;
 use rust_decimal_macros::dec;
 
-use bfx_client::BfxClient;
-use core_price::{Price, PriceConfig, PriceOfOneBTC, jobs::get_price_from_bfx::fetch_price};
+use chrono::NaiveDate;
+use es_entity::clock::{ArtificialClockConfig, ClockHandle};
+use std::time::Duration;
+
+use core_price::{
+    DailyPriceOhlc, Price, PriceConfig, PriceHistory, PriceOfOneBTC, PriceProvider,
+    error::PriceError, source::PriceSource,
+};
 use helpers::{DummyEvent, init_pool, publish_dummy_price_event, wait_for_price_to_be_updated};
 use money::{Satoshis, UsdCents};
 use obix::out::Outbox;
 
 #[tokio::test]
 async fn get_price_from_client() {
-    let client = BfxClient::new();
-    let price = fetch_price(Arc::new(client)).await;
+    let source = PriceSource::try_from_provider(&PriceProvider::Bitfinex).unwrap();
+    let price = source.fetch_price().await;
     assert!(price.is_ok());
 }
 
@@ -31,7 +35,17 @@ async fn update_price() -> anyhow::Result<()> {
     )
     .await?;
 
-    let price = Price::init(PriceConfig { providers: vec![] }, &mut jobs, &outbox).await?;
+    let price = Price::init(
+        &pool,
+        PriceConfig {
+            providers: vec![],
+            ..Default::default()
+        },
+        &mut jobs,
+        &outbox,
+        ClockHandle::realtime(),
+    )
+    .await?;
 
     let initial_price_cents = rand::rng().random_range(1_000_000..10_000_000);
     let initial_price = PriceOfOneBTC::new(UsdCents::from(initial_price_cents));
@@ -51,6 +65,89 @@ async fn update_price() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn stale_price_is_rejected() -> anyhow::Result<()> {
+    let pool = init_pool().await?;
+    let (clock, clock_ctrl) = ClockHandle::artificial(ArtificialClockConfig::manual());
+
+    let outbox = Outbox::<DummyEvent>::init(&pool, obix::MailboxConfig::builder().build()?).await?;
+    let mut jobs = job::Jobs::init(
+        job::JobSvcConfig::builder()
+            .pool(pool.clone())
+            .build()
+            .unwrap(),
+    )
+    .await?;
+
+    let config = PriceConfig {
+        providers: vec![],
+        max_price_age_secs: 60,
+        ..Default::default()
+    };
+    let price = Price::init(&pool, config, &mut jobs, &outbox, clock).await?;
+
+    let expected_price_cents = rand::rng().random_range(1_000_000..10_000_000);
+    let expected_price = PriceOfOneBTC::new(UsdCents::from(expected_price_cents));
+    publish_dummy_price_event(&outbox, expected_price).await?;
+    wait_for_price_to_be_updated(&price, expected_price).await?;
+
+    assert_eq!(price.fresh_usd_cents_per_btc().await?, expected_price);
+
+    clock_ctrl.advance(Duration::from_secs(120)).await;
+
+    assert!(matches!(
+        price.fresh_usd_cents_per_btc().await,
+        Err(PriceError::StalePrice { .. })
+    ));
+    assert_eq!(price.usd_cents_per_btc().await, expected_price);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn price_history_point_in_time_and_daily_ohlc() -> anyhow::Result<()> {
+    let pool = init_pool().await?;
+    let history = PriceHistory::new(&pool);
+
+    let day = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap()
+        + chrono::Duration::days(rand::rng().random_range(0..100_000));
+    let at = |hour| day.and_hms_opt(hour, 0, 0).unwrap().and_utc();
+    let price = |cents| PriceOfOneBTC::new(UsdCents::from(cents));
+
+    history.record_tick(price(6_000_000), at(12)).await?;
+    history.record_tick(price(5_000_000), at(6)).await?;
+    history.record_tick(price(7_000_000), at(18)).await?;
+    history.record_tick(price(6_500_000), at(9)).await?;
+
+    assert_eq!(history.price_at(at(10)).await?, price(6_500_000));
+    assert_eq!(history.price_at(at(23)).await?, price(7_000_000));
+    assert_eq!(history.price_before(at(18)).await?, price(6_000_000));
+
+    let next_midnight = (day + chrono::Duration::days(1))
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+        .and_utc();
+    let last_tick_of_day = next_midnight - chrono::Duration::milliseconds(500);
+    history
+        .record_tick(price(7_100_000), last_tick_of_day)
+        .await?;
+    assert_eq!(history.price_before(next_midnight).await?, price(7_100_000));
+
+    let ohlc = history.daily_ohlc(day, day).await?;
+    assert_eq!(
+        ohlc,
+        vec![DailyPriceOhlc {
+            day,
+            open: price(5_000_000),
+            high: price(7_100_000),
+            low: price(5_000_000),
+            close: price(7_100_000),
+        }]
+    );
+
+    Ok(())
+}
+
 #[test]
 fn cents_to_sats_trivial() {
     let price = PriceOfOneBTC::new(UsdCents::try_from_usd(dec!(1000)).unwrap());
@@ -431,7 +431,7 @@ pub fn generate_rollup_migrations(
 
         // Check if we have a previous schema to compare with
         if let Some(ref previous_schema) = schema_change.previous_schema {
-            let (previous_fields, _) = extract_fields_and_events_from_schema(
+            let (previous_fields, previous_event_types) = extract_fields_and_events_from_schema(
                 previous_schema,
                 &schema_info.collections,
                 &schema_info.delete_events,
@@ -441,7 +441,14 @@ pub fn generate_rollup_migrations(
             // Compare fields
             let (new_fields, removed_fields) = compare_fields(&previous_fields, &current_fields);
 
-            if new_fields.is_empty() && removed_fields.is_empty() {
+            // New event types only touching existing fields still need the trigger
+            // function regenerated, otherwise inserts fail with an unknown event type
+            let event_types_changed = previous_event_types
+                .iter()
+                .map(|e| &e.name)
+                .ne(event_types.iter().map(|e| &e.name));
+
+            if new_fields.is_empty() && removed_fields.is_empty() && !event_types_changed {
                 println!(
                     "{} No changes in {}, skipping migration",
                     "ℹ️".blue(),
@@ -5,7 +5,7 @@ use colored::*;
 
 use core_access::event_schema::{PermissionSetEvent, RoleEvent, UserEvent};
 use core_accounting::event_schema::{
-    ChartEvent, ChartNodeEvent, FiscalYearEvent, ManualTransactionEvent,
+    ChartEvent, ChartNodeEvent, FiscalYearEvent, ManualTransactionEvent, RecurringJournalEntryEvent,
 };
 use core_credit::event_schema::{
     CollateralEvent, CreditFacilityEvent, CreditFacilityProposalEvent, DisbursalEvent,
@@ -13,9 +13,11 @@ use core_credit::event_schema::{
     PaymentEvent, PendingCreditFacilityEvent,
 };
 use core_credit_terms::TermsTemplateEvent;
-use core_custody::event_schema::{CustodianEvent, WalletEvent};
+use core_custody::event_schema::{CustodianEvent, WalletEvent, WalletTransferEvent};
 use core_customer::event_schema::{CustomerEvent, PartyEvent, ProspectEvent};
-use core_deposit::event_schema::{DepositAccountEvent, DepositEvent, WithdrawalEvent};
+use core_deposit::event_schema::{
+    DepositAccountEvent, DepositEvent, HoldEvent, TransferEvent, WithdrawalEvent,
+};
 use core_report::event_schema::{ReportEvent, ReportRunEvent};
 use document_storage::event_schema::DocumentEvent;
 use domain_config::event_schema::DomainConfigEvent;
@@ -243,6 +245,40 @@ pub fn update_schemas(
             generate_schema: || serde_json::to_value(schema_for!(WithdrawalEvent)).unwrap(),
             ..Default::default()
         },
+        SchemaInfo {
+            name: "TransferEvent",
+            filename: "transfer_event_schema.json",
+            collections: vec![CollectionRollup {
+                column_name: "ledger_tx_ids",
+                values: "ledger_tx_id",
+                add_events: vec![
+                    "Initialized".to_string(),
+                    "Completed".to_string(),
+                    "Denied".to_string(),
+                ],
+                remove_events: vec![],
+            }],
+            toggle_events: vec!["ApprovalProcessConcluded", "Completed", "Denied"],
+            generate_schema: || serde_json::to_value(schema_for!(TransferEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "HoldEvent",
+            filename: "hold_event_schema.json",
+            collections: vec![CollectionRollup {
+                column_name: "ledger_tx_ids",
+                values: "ledger_tx_id",
+                add_events: vec![
+                    "Initialized".to_string(),
+                    "Released".to_string(),
+                    "Captured".to_string(),
+                ],
+                remove_events: vec![],
+            }],
+            toggle_events: vec!["Released", "Captured"],
+            generate_schema: || serde_json::to_value(schema_for!(HoldEvent)).unwrap(),
+            ..Default::default()
+        },
         SchemaInfo {
             name: "CustodianEvent",
             filename: "custodian_event_schema.json",
@@ -272,6 +308,7 @@ pub fn update_schemas(
                         "Initialized".to_string(),
                         "Activated".to_string(),
                         "InterestAccrualCycleConcluded".to_string(),
+                        "PenaltyRecorded".to_string(),
                     ],
                     remove_events: vec![],
                 },
@@ -368,6 +405,8 @@ pub fn update_schemas(
                         "OverdueRecorded".to_string(),
                         "DefaultedRecorded".to_string(),
                         "PaymentAllocated".to_string(),
+                        "Rescheduled".to_string(),
+                        "WrittenOff".to_string(),
                     ],
                     remove_events: vec![],
                 },
@@ -467,6 +506,20 @@ pub fn update_schemas(
             generate_schema: || serde_json::to_value(schema_for!(ManualTransactionEvent)).unwrap(),
             ..Default::default()
         },
+        SchemaInfo {
+            name: "RecurringJournalEntryEvent",
+            filename: "recurring_journal_entry_event_schema.json",
+            collections: vec![CollectionRollup {
+                column_name: "manual_transaction_ids",
+                values: "manual_transaction_id",
+                add_events: vec!["OccurrenceSubmitted".to_string()],
+                remove_events: vec![],
+            }],
+            generate_schema: || {
+                serde_json::to_value(schema_for!(RecurringJournalEntryEvent)).unwrap()
+            },
+            ..Default::default()
+        },
         SchemaInfo {
             name: "DomainConfigEvent",
             filename: "domain_config_event_schema.json",
@@ -479,6 +532,12 @@ pub fn update_schemas(
             generate_schema: || serde_json::to_value(schema_for!(WalletEvent)).unwrap(),
             ..Default::default()
         },
+        SchemaInfo {
+            name: "WalletTransferEvent",
+            filename: "wallet_transfer_event_schema.json",
+            generate_schema: || serde_json::to_value(schema_for!(WalletTransferEvent)).unwrap(),
+            ..Default::default()
+        },
         SchemaInfo {
             name: "FiscalYearEvent",
             filename: "fiscal_year_event_schema.json",
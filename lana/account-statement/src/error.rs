@@ -0,0 +1,38 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+#[derive(Error, Debug)]
+pub enum AccountStatementError {
+    #[error("AccountStatementError - Rendering: {0}")]
+    Rendering(#[from] rendering::RenderingError),
+    #[error("AccountStatementError - DocumentStorage: {0}")]
+    DocumentStorage(#[from] document_storage::error::DocumentStorageError),
+    #[error("AccountStatementError - Auth: {0}")]
+    Auth(#[from] authz::error::AuthorizationError),
+    #[error("AccountStatementError - Audit: {0}")]
+    Audit(#[from] audit::error::AuditError),
+    #[error("AccountStatementError - CoreDeposit: {0}")]
+    CoreDeposit(#[from] core_deposit::error::CoreDepositError),
+    #[error("AccountStatementError - RegisterEventHandler: {0}")]
+    RegisterEventHandler(#[from] Box<dyn std::error::Error + Send + Sync>),
+    #[error("AccountStatementError - SubjectIsNotAccountHolder")]
+    SubjectIsNotAccountHolder,
+    #[error("AccountStatementError - NotFound")]
+    NotFound,
+}
+
+impl ErrorSeverity for AccountStatementError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::Rendering(e) => e.severity(),
+            Self::DocumentStorage(e) => e.severity(),
+            Self::Auth(e) => e.severity(),
+            Self::Audit(e) => e.severity(),
+            Self::CoreDeposit(e) => e.severity(),
+            Self::RegisterEventHandler(_) => Level::ERROR,
+            Self::SubjectIsNotAccountHolder => Level::WARN,
+            Self::NotFound => Level::WARN,
+        }
+    }
+}
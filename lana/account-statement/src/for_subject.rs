@@ -0,0 +1,119 @@
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use core_deposit::{CoreDeposit, DepositAccountHolderId, DepositAccountId};
+use document_storage::{DocumentStorage, GeneratedDocumentDownloadLink};
+use lana_events::LanaEvent;
+use tracing::instrument;
+use tracing_macros::record_error_severity;
+
+use crate::{AccountStatement, error::*, list_statements, primitives::*};
+
+pub struct AccountStatementsForSubject<'a, Perms>
+where
+    Perms: PermissionCheck,
+{
+    account_holder_id: DepositAccountHolderId,
+    sub: &'a <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+    document_storage: &'a DocumentStorage,
+    deposits: &'a CoreDeposit<Perms, LanaEvent>,
+    authz: &'a Perms,
+}
+
+impl<'a, Perms> AccountStatementsForSubject<'a, Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<AccountStatementModuleAction>
+        + From<core_deposit::CoreDepositAction>
+        + From<core_customer::CoreCustomerAction>
+        + From<governance::GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<AccountStatementModuleObject>
+        + From<core_deposit::CoreDepositObject>
+        + From<core_customer::CustomerObject>
+        + From<governance::GovernanceObject>,
+{
+    pub(super) fn new(
+        sub: &'a <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_holder_id: DepositAccountHolderId,
+        document_storage: &'a DocumentStorage,
+        deposits: &'a CoreDeposit<Perms, LanaEvent>,
+        authz: &'a Perms,
+    ) -> Self {
+        Self {
+            account_holder_id,
+            sub,
+            document_storage,
+            deposits,
+            authz,
+        }
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "account_statement.for_subject.list_for_deposit_account",
+        skip(self)
+    )]
+    pub async fn list_for_deposit_account(
+        &self,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<AccountStatement>, AccountStatementError> {
+        let deposit_account_id = deposit_account_id.into();
+        self.ensure_account_access(
+            deposit_account_id,
+            AccountStatementModuleObject::all_account_statements(),
+            AccountStatementModuleAction::ACCOUNT_STATEMENT_LIST,
+        )
+        .await?;
+
+        list_statements(self.document_storage, deposit_account_id).await
+    }
+
+    #[record_error_severity]
+    #[instrument(
+        name = "account_statement.for_subject.generate_download_link",
+        skip(self)
+    )]
+    pub async fn generate_download_link(
+        &self,
+        statement_id: impl Into<AccountStatementId> + std::fmt::Debug,
+    ) -> Result<GeneratedDocumentDownloadLink, AccountStatementError> {
+        let statement_id = statement_id.into();
+        let document = self.document_storage.find_by_id(statement_id).await?;
+        self.ensure_account_access(
+            DepositAccountId::from(uuid::Uuid::from(document.reference_id)),
+            AccountStatementModuleObject::account_statement(statement_id),
+            AccountStatementModuleAction::ACCOUNT_STATEMENT_GENERATE_DOWNLOAD_LINK,
+        )
+        .await?;
+
+        Ok(self
+            .document_storage
+            .generate_download_link(statement_id)
+            .await?)
+    }
+
+    async fn ensure_account_access(
+        &self,
+        deposit_account_id: DepositAccountId,
+        object: AccountStatementModuleObject,
+        action: AccountStatementModuleAction,
+    ) -> Result<(), AccountStatementError> {
+        let account = self
+            .deposits
+            .find_account_by_id_without_audit(deposit_account_id)
+            .await?;
+
+        if account.account_holder_id != self.account_holder_id {
+            self.authz
+                .audit()
+                .record_entry(self.sub, object, action, false)
+                .await?;
+            return Err(AccountStatementError::NotFound);
+        }
+        self.authz
+            .audit()
+            .record_entry(self.sub, object, action, true)
+            .await?;
+
+        Ok(())
+    }
+}
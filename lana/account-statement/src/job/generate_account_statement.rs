@@ -0,0 +1,489 @@
+use std::marker::PhantomData;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use audit::{AuditSvc, SystemSubject};
+use authz::PermissionCheck;
+use core_credit::{
+    CoreCredit, CreditFacilitiesFilters, CreditFacilitiesSortBy, CreditFacilityHistoryEntry,
+    ListDirection, Sort,
+};
+use core_customer::{CustomerId, Customers};
+use core_deposit::{
+    CoreDeposit, DepositAccountHistoryCursor, DepositAccountHistoryEntry, DepositAccountId,
+};
+use document_storage::{DocumentId, DocumentStorage};
+use job::*;
+use lana_events::LanaEvent;
+use money::UsdCents;
+use tracing_macros::record_error_severity;
+
+use crate::{
+    primitives::{ACCOUNT_STATEMENT, StatementPeriod},
+    templates::StatementTemplates,
+};
+
+const HISTORY_PAGE_SIZE: usize = 100;
+
+#[derive(Serialize, Deserialize)]
+pub struct GenerateAccountStatementConfig<Perms>
+where
+    Perms: PermissionCheck,
+{
+    pub deposit_account_id: DepositAccountId,
+    pub period: StatementPeriod,
+    #[serde(skip)]
+    pub phantom: PhantomData<Perms>,
+}
+
+impl<Perms> Clone for GenerateAccountStatementConfig<Perms>
+where
+    Perms: PermissionCheck,
+{
+    fn clone(&self) -> Self {
+        Self {
+            deposit_account_id: self.deposit_account_id,
+            period: self.period,
+            phantom: PhantomData,
+        }
+    }
+}
+
+pub struct GenerateAccountStatementJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+{
+    deposits: CoreDeposit<Perms, LanaEvent>,
+    credit: CoreCredit<Perms, LanaEvent>,
+    customers: Customers<Perms, LanaEvent>,
+    document_storage: DocumentStorage,
+    statement_templates: StatementTemplates,
+    renderer: rendering::Renderer,
+}
+
+impl<Perms> GenerateAccountStatementJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+{
+    pub fn new(
+        deposits: &CoreDeposit<Perms, LanaEvent>,
+        credit: &CoreCredit<Perms, LanaEvent>,
+        customers: &Customers<Perms, LanaEvent>,
+        document_storage: &DocumentStorage,
+        statement_templates: StatementTemplates,
+        renderer: rendering::Renderer,
+    ) -> Self {
+        Self {
+            deposits: deposits.clone(),
+            credit: credit.clone(),
+            customers: customers.clone(),
+            document_storage: document_storage.clone(),
+            statement_templates,
+            renderer,
+        }
+    }
+}
+
+pub const GENERATE_ACCOUNT_STATEMENT_JOB: JobType = JobType::new("task.generate-account-statement");
+
+impl<Perms> JobInitializer for GenerateAccountStatementJobInitializer<Perms>
+where
+    Perms: PermissionCheck + Send + Sync,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<core_credit::CoreCreditAction>
+        + From<core_credit_collection::CoreCreditCollectionAction>
+        + From<core_credit::CoreCreditCollateralAction>
+        + From<core_customer::CoreCustomerAction>
+        + From<core_deposit::CoreDepositAction>
+        + From<governance::GovernanceAction>
+        + From<core_custody::CoreCustodyAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<core_credit::CoreCreditObject>
+        + From<core_credit_collection::CoreCreditCollectionObject>
+        + From<core_credit::CoreCreditCollateralObject>
+        + From<core_customer::CustomerObject>
+        + From<core_deposit::CoreDepositObject>
+        + From<governance::GovernanceObject>
+        + From<core_custody::CoreCustodyObject>,
+{
+    type Config = GenerateAccountStatementConfig<Perms>;
+    fn job_type(&self) -> JobType {
+        GENERATE_ACCOUNT_STATEMENT_JOB
+    }
+
+    fn init(
+        &self,
+        job: &Job,
+        _: JobSpawner<Self::Config>,
+    ) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(GenerateAccountStatementJobRunner {
+            config: job.config()?,
+            deposits: self.deposits.clone(),
+            credit: self.credit.clone(),
+            customers: self.customers.clone(),
+            document_storage: self.document_storage.clone(),
+            statement_templates: self.statement_templates.clone(),
+            renderer: self.renderer.clone(),
+        }))
+    }
+}
+
+pub struct GenerateAccountStatementJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+{
+    config: GenerateAccountStatementConfig<Perms>,
+    deposits: CoreDeposit<Perms, LanaEvent>,
+    credit: CoreCredit<Perms, LanaEvent>,
+    customers: Customers<Perms, LanaEvent>,
+    document_storage: DocumentStorage,
+    statement_templates: StatementTemplates,
+    renderer: rendering::Renderer,
+}
+
+impl<Perms> GenerateAccountStatementJobRunner<Perms>
+where
+    Perms: PermissionCheck + Send + Sync,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<core_credit::CoreCreditAction>
+        + From<core_credit_collection::CoreCreditCollectionAction>
+        + From<core_credit::CoreCreditCollateralAction>
+        + From<core_customer::CoreCustomerAction>
+        + From<core_deposit::CoreDepositAction>
+        + From<governance::GovernanceAction>
+        + From<core_custody::CoreCustodyAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<core_credit::CoreCreditObject>
+        + From<core_credit_collection::CoreCreditCollectionObject>
+        + From<core_credit::CoreCreditCollateralObject>
+        + From<core_customer::CustomerObject>
+        + From<core_deposit::CoreDepositObject>
+        + From<governance::GovernanceObject>
+        + From<core_custody::CoreCustodyObject>,
+{
+    async fn account_lines(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+    ) -> Result<Vec<AccountStatementLine>, Box<dyn std::error::Error>> {
+        let period = self.config.period;
+        let mut lines = Vec::new();
+        let mut query = es_entity::PaginatedQueryArgs::<DepositAccountHistoryCursor> {
+            first: HISTORY_PAGE_SIZE,
+            after: None,
+        };
+        // History is returned newest first, so paging stops once an entry
+        // predates the statement period.
+        'pages: loop {
+            let res = self
+                .deposits
+                .account_history(sub, self.config.deposit_account_id, query)
+                .await?;
+            for entry in res.entities.iter() {
+                let recorded_on = DepositAccountHistoryCursor::from(entry)
+                    .created_at
+                    .date_naive();
+                if recorded_on < period.start {
+                    break 'pages;
+                }
+                if recorded_on <= period.end
+                    && let Some(line) = AccountStatementLine::from_history(recorded_on, entry)
+                {
+                    lines.push(line);
+                }
+            }
+            match res.into_next_query() {
+                Some(next) => query = next,
+                None => break,
+            }
+        }
+        lines.reverse();
+        Ok(lines)
+    }
+
+    async fn credit_lines(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        customer_id: CustomerId,
+    ) -> Result<Vec<CreditStatementLine>, Box<dyn std::error::Error>> {
+        let period = self.config.period;
+        let mut lines = Vec::new();
+        let mut query = es_entity::PaginatedQueryArgs::default();
+        loop {
+            let res = self
+                .credit
+                .facilities()
+                .list(
+                    sub,
+                    query,
+                    CreditFacilitiesFilters {
+                        customer_id: Some(customer_id),
+                        ..Default::default()
+                    },
+                    Sort {
+                        by: CreditFacilitiesSortBy::CreatedAt,
+                        direction: ListDirection::Ascending,
+                    },
+                )
+                .await?;
+            for facility in res.entities.iter() {
+                let history = self
+                    .credit
+                    .histories()
+                    .find_for_credit_facility_id::<CreditFacilityHistoryEntry>(sub, facility.id)
+                    .await?;
+                lines.extend(
+                    history
+                        .iter()
+                        .filter_map(|entry| {
+                            CreditStatementLine::from_history(facility.public_id.to_string(), entry)
+                        })
+                        .filter(|line| period.contains(line.effective)),
+                );
+            }
+            match res.into_next_query() {
+                Some(next) => query = next,
+                None => break,
+            }
+        }
+        lines.sort_by_key(|line| line.effective);
+        Ok(lines)
+    }
+}
+
+#[async_trait]
+impl<Perms> JobRunner for GenerateAccountStatementJobRunner<Perms>
+where
+    Perms: PermissionCheck + Send + Sync,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<core_credit::CoreCreditAction>
+        + From<core_credit_collection::CoreCreditCollectionAction>
+        + From<core_credit::CoreCreditCollateralAction>
+        + From<core_customer::CoreCustomerAction>
+        + From<core_deposit::CoreDepositAction>
+        + From<governance::GovernanceAction>
+        + From<core_custody::CoreCustodyAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<core_credit::CoreCreditObject>
+        + From<core_credit_collection::CoreCreditCollectionObject>
+        + From<core_credit::CoreCreditCollateralObject>
+        + From<core_customer::CustomerObject>
+        + From<core_deposit::CoreDepositObject>
+        + From<governance::GovernanceObject>
+        + From<core_custody::CoreCustodyObject>,
+{
+    #[record_error_severity]
+    #[tracing::instrument(
+        name = "account_statement.generate_account_statement_job.run",
+        skip_all,
+        fields(
+            job_id = %current_job.id(),
+            job_attempt = current_job.attempt(),
+            deposit_account_id = %self.config.deposit_account_id
+        ),
+    )]
+    async fn run(
+        &self,
+        current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let sub =
+            <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(ACCOUNT_STATEMENT);
+        let period = self.config.period;
+
+        let account = self
+            .deposits
+            .find_account_by_id_without_audit(self.config.deposit_account_id)
+            .await?;
+        let customer_id = CustomerId::from(account.account_holder_id);
+        let customer = self.customers.find_by_id_without_audit(customer_id).await?;
+        let party = self
+            .customers
+            .find_party_by_id_without_audit(customer.party_id)
+            .await?;
+
+        let opening_balance = self
+            .deposits
+            .account_settled_balance_as_of(
+                &sub,
+                account.id,
+                period
+                    .start
+                    .pred_opt()
+                    .expect("period start has a previous day"),
+            )
+            .await?;
+        let closing_balance = self
+            .deposits
+            .account_settled_balance_as_of(&sub, account.id, period.end)
+            .await?;
+
+        let account_lines = self.account_lines(&sub).await?;
+        let credit_lines = self.credit_lines(&sub, customer_id).await?;
+
+        let data = AccountStatementData::new(
+            party.email,
+            account.public_id.to_string(),
+            period,
+            current_job.clock().today(),
+            opening_balance,
+            closing_balance,
+            account_lines,
+            credit_lines,
+        );
+
+        let content = self
+            .statement_templates
+            .render_template("account_statement", &data)?;
+        let pdf_bytes = self.renderer.render_template_to_pdf(&content).await?;
+
+        let document_id = DocumentId::from(uuid::Uuid::from(*current_job.id()));
+        let mut document = self.document_storage.find_by_id(document_id).await?;
+        self.document_storage
+            .upload(pdf_bytes, &mut document)
+            .await?;
+
+        Ok(JobCompletion::Complete)
+    }
+}
+
+pub type GenerateAccountStatementJobSpawner<Perms> =
+    JobSpawner<GenerateAccountStatementConfig<Perms>>;
+
+/// Data structure for the account statement template
+#[derive(Serialize)]
+pub struct AccountStatementData {
+    pub email: String,
+    pub deposit_account_id: String,
+    pub period_start: String,
+    pub period_end: String,
+    pub generated_on: String,
+    pub opening_balance: String,
+    pub closing_balance: String,
+    pub total_credits: String,
+    pub total_debits: String,
+    pub account_lines: Vec<AccountStatementLine>,
+    pub credit_lines: Vec<CreditStatementLine>,
+}
+
+impl AccountStatementData {
+    pub fn new(
+        email: String,
+        deposit_account_id: String,
+        period: StatementPeriod,
+        generated_on: chrono::NaiveDate,
+        opening_balance: UsdCents,
+        closing_balance: UsdCents,
+        account_lines: Vec<AccountStatementLine>,
+        credit_lines: Vec<CreditStatementLine>,
+    ) -> Self {
+        let total_credits = account_lines
+            .iter()
+            .filter_map(|line| line.credit_cents)
+            .fold(UsdCents::ZERO, |total, amount| total + amount);
+        let total_debits = account_lines
+            .iter()
+            .filter_map(|line| line.debit_cents)
+            .fold(UsdCents::ZERO, |total, amount| total + amount);
+
+        Self {
+            email,
+            deposit_account_id,
+            period_start: period.start.to_string(),
+            period_end: period.end.to_string(),
+            generated_on: generated_on.to_string(),
+            opening_balance: opening_balance.formatted_usd(),
+            closing_balance: closing_balance.formatted_usd(),
+            total_credits: total_credits.formatted_usd(),
+            total_debits: total_debits.formatted_usd(),
+            account_lines,
+            credit_lines,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct AccountStatementLine {
+    pub date: String,
+    pub description: &'static str,
+    pub credit: String,
+    pub debit: String,
+    #[serde(skip)]
+    credit_cents: Option<UsdCents>,
+    #[serde(skip)]
+    debit_cents: Option<UsdCents>,
+}
+
+impl AccountStatementLine {
+    fn credit(date: NaiveDate, description: &'static str, amount: UsdCents) -> Self {
+        Self {
+            date: date.to_string(),
+            description,
+            credit: amount.formatted_usd(),
+            debit: String::new(),
+            credit_cents: Some(amount),
+            debit_cents: None,
+        }
+    }
+
+    fn debit(date: NaiveDate, description: &'static str, amount: UsdCents) -> Self {
+        Self {
+            date: date.to_string(),
+            description,
+            credit: String::new(),
+            debit: amount.formatted_usd(),
+            credit_cents: None,
+            debit_cents: Some(amount),
+        }
+    }
+
+    /// Maps a settled-balance movement to a statement line. Holds that are
+    /// placed or released and freeze/unfreeze entries do not change what the
+    /// customer owns and are left off the statement.
+    fn from_history(date: NaiveDate, entry: &DepositAccountHistoryEntry) -> Option<Self> {
+        use DepositAccountHistoryEntry::*;
+        let line = match entry {
+            Deposit(e) => Self::credit(date, "Deposit", e.amount),
+            Withdrawal(e) => Self::debit(date, "Withdrawal", e.amount),
+            CancelledWithdrawal(e) => Self::credit(date, "Cancelled withdrawal", e.amount),
+            TransferIn(e) => Self::credit(date, "Transfer in", e.amount),
+            TransferOut(e) => Self::debit(date, "Transfer out", e.amount),
+            CancelledTransfer(e) => Self::credit(date, "Cancelled transfer", e.amount),
+            InterestCapitalized(e) => Self::credit(date, "Interest", e.amount),
+            EarlyBreakPenalty(e) => Self::debit(date, "Early withdrawal penalty", e.amount),
+            Escheatment(e) => Self::debit(date, "Unclaimed funds transfer", e.amount),
+            HoldCaptured(e) => Self::debit(date, "Hold captured", e.amount),
+            Disbursal(e) => Self::credit(date, "Credit facility disbursal", e.amount),
+            Payment(e) => Self::debit(date, "Credit facility payment", e.amount),
+            HoldPlaced(_) | HoldReleased(_) | Freeze(_) | Unfreeze(_) | Unknown(_) | Ignored => {
+                return None;
+            }
+        };
+        Some(line)
+    }
+}
+
+#[derive(Serialize)]
+pub struct CreditStatementLine {
+    pub date: String,
+    pub credit_facility_id: String,
+    pub description: &'static str,
+    pub amount: String,
+    #[serde(skip)]
+    effective: NaiveDate,
+}
+
+impl CreditStatementLine {
+    fn from_history(
+        credit_facility_id: String,
+        entry: &CreditFacilityHistoryEntry,
+    ) -> Option<Self> {
+        let (description, cents, effective) = match entry {
+            CreditFacilityHistoryEntry::Disbursal(e) => ("Disbursal", e.cents, e.effective),
+            CreditFacilityHistoryEntry::Payment(e) => ("Payment", e.cents, e.effective),
+            CreditFacilityHistoryEntry::Interest(e) => ("Accrued interest", e.cents, e.effective),
+            _ => return None,
+        };
+        Some(Self {
+            date: effective.to_string(),
+            credit_facility_id,
+            description,
+            amount: cents.formatted_usd(),
+            effective,
+        })
+    }
+}
@@ -0,0 +1,5 @@
+mod generate_account_statement;
+mod month_end_account_statements;
+
+pub use generate_account_statement::*;
+pub use month_end_account_statements::*;
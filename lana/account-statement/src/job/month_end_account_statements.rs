@@ -0,0 +1,153 @@
+use tracing::{Span, instrument};
+
+use audit::{AuditSvc, SystemSubject};
+use authz::PermissionCheck;
+use core_deposit::{CoreDeposit, DepositAccount, DepositAccountStatus};
+use core_time_events::CoreTimeEvent;
+use document_storage::{DocumentStorage, DocumentType, ReferenceId};
+use job::{JobId, JobType};
+use lana_events::LanaEvent;
+use obix::out::{OutboxEventHandler, PersistentOutboxEvent};
+
+use super::generate_account_statement::{
+    GenerateAccountStatementConfig, GenerateAccountStatementJobSpawner,
+};
+use crate::primitives::{ACCOUNT_STATEMENT, StatementPeriod};
+
+pub const MONTH_END_ACCOUNT_STATEMENTS_JOB: JobType =
+    JobType::new("outbox.month-end-account-statements");
+
+pub const ACCOUNT_STATEMENT_DOCUMENT_TYPE: DocumentType = DocumentType::new("account_statement");
+
+pub struct MonthEndAccountStatementsHandler<Perms>
+where
+    Perms: PermissionCheck,
+{
+    deposits: CoreDeposit<Perms, LanaEvent>,
+    document_storage: DocumentStorage,
+    generate_account_statement: GenerateAccountStatementJobSpawner<Perms>,
+}
+
+impl<Perms> MonthEndAccountStatementsHandler<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<core_deposit::CoreDepositAction>
+        + From<core_customer::CoreCustomerAction>
+        + From<governance::GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<core_deposit::CoreDepositObject>
+        + From<core_customer::CustomerObject>
+        + From<governance::GovernanceObject>,
+{
+    pub fn new(
+        deposits: &CoreDeposit<Perms, LanaEvent>,
+        document_storage: &DocumentStorage,
+        generate_account_statement: GenerateAccountStatementJobSpawner<Perms>,
+    ) -> Self {
+        Self {
+            deposits: deposits.clone(),
+            document_storage: document_storage.clone(),
+            generate_account_statement,
+        }
+    }
+
+    async fn schedule_statements_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        period: StatementPeriod,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let sub =
+            <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(ACCOUNT_STATEMENT);
+        let mut query = es_entity::PaginatedQueryArgs::default();
+        loop {
+            let res = self.deposits.list_accounts(&sub, query).await?;
+            for account in res.entities.iter().filter(|a| receives_statement(a)) {
+                self.schedule_statement_in_op(op, account, period).await?;
+            }
+            match res.into_next_query() {
+                Some(next) => query = next,
+                None => break,
+            }
+        }
+        Ok(())
+    }
+
+    async fn schedule_statement_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        account: &DepositAccount,
+        period: StatementPeriod,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let reference_id = ReferenceId::from(uuid::Uuid::from(account.id));
+        let filename = period.filename();
+
+        let already_scheduled = self
+            .document_storage
+            .list_for_reference_id(reference_id)
+            .await?
+            .iter()
+            .any(|document| document.filename == filename);
+        if already_scheduled {
+            return Ok(());
+        }
+
+        let document = self
+            .document_storage
+            .create_in_op(
+                op,
+                filename,
+                "application/pdf",
+                reference_id,
+                ACCOUNT_STATEMENT_DOCUMENT_TYPE,
+            )
+            .await?;
+
+        self.generate_account_statement
+            .spawn_in_op(
+                op,
+                JobId::from(uuid::Uuid::from(document.id)),
+                GenerateAccountStatementConfig::<Perms> {
+                    deposit_account_id: account.id,
+                    period,
+                    phantom: std::marker::PhantomData,
+                },
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+fn receives_statement(account: &DepositAccount) -> bool {
+    !matches!(
+        account.status,
+        DepositAccountStatus::Closed | DepositAccountStatus::Escheated
+    )
+}
+
+impl<Perms> OutboxEventHandler<LanaEvent> for MonthEndAccountStatementsHandler<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<core_deposit::CoreDepositAction>
+        + From<core_customer::CoreCustomerAction>
+        + From<governance::GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<core_deposit::CoreDepositObject>
+        + From<core_customer::CustomerObject>
+        + From<governance::GovernanceObject>,
+{
+    #[instrument(name = "account_statement.month_end.process_message", parent = None, skip(self, op, event), fields(seq = %event.sequence, handled = false, event_type = tracing::field::Empty))]
+    async fn handle_persistent(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        event: &PersistentOutboxEvent<LanaEvent>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(e @ CoreTimeEvent::EndOfDay { day, .. }) = event.as_event()
+            && let Some(period) = StatementPeriod::ending_on(*day)
+        {
+            event.inject_trace_parent();
+            Span::current().record("handled", true);
+            Span::current().record("event_type", e.as_ref());
+
+            self.schedule_statements_in_op(op, period).await?;
+        }
+        Ok(())
+    }
+}
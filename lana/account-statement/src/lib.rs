@@ -0,0 +1,287 @@
+#![cfg_attr(feature = "fail-on-warnings", deny(warnings))]
+#![cfg_attr(feature = "fail-on-warnings", deny(clippy::all))]
+
+mod error;
+mod for_subject;
+pub mod job;
+pub mod primitives;
+mod templates;
+
+use ::job::Jobs;
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use core_credit::CoreCredit;
+use core_customer::Customers;
+use core_deposit::{CoreDeposit, DepositAccountHolderId, DepositAccountId};
+use document_storage::{
+    Document, DocumentStatus, DocumentStorage, GeneratedDocumentDownloadLink, ReferenceId,
+};
+use lana_events::LanaEvent;
+use obix::out::{Outbox, OutboxEventJobConfig};
+use tracing::instrument;
+use tracing_macros::record_error_severity;
+
+pub use error::*;
+pub use for_subject::AccountStatementsForSubject;
+use job::*;
+pub use primitives::{
+    AccountStatementId, AccountStatementModuleAction, AccountStatementModuleObject,
+    PERMISSION_SET_ACCOUNT_STATEMENT_VIEWER, StatementPeriod,
+};
+
+pub struct AccountStatements<Perms>
+where
+    Perms: PermissionCheck,
+{
+    document_storage: DocumentStorage,
+    deposits: CoreDeposit<Perms, LanaEvent>,
+    authz: Perms,
+}
+
+impl<Perms> Clone for AccountStatements<Perms>
+where
+    Perms: PermissionCheck,
+{
+    fn clone(&self) -> Self {
+        Self {
+            document_storage: self.document_storage.clone(),
+            deposits: self.deposits.clone(),
+            authz: self.authz.clone(),
+        }
+    }
+}
+
+impl<Perms> AccountStatements<Perms>
+where
+    Perms: PermissionCheck + Clone + Send + Sync + 'static,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<AccountStatementModuleAction>
+        + From<core_credit::CoreCreditAction>
+        + From<core_credit_collection::CoreCreditCollectionAction>
+        + From<core_credit::CoreCreditCollateralAction>
+        + From<core_customer::CoreCustomerAction>
+        + From<core_deposit::CoreDepositAction>
+        + From<governance::GovernanceAction>
+        + From<core_custody::CoreCustodyAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<AccountStatementModuleObject>
+        + From<core_credit::CoreCreditObject>
+        + From<core_credit_collection::CoreCreditCollectionObject>
+        + From<core_credit::CoreCreditCollateralObject>
+        + From<core_customer::CustomerObject>
+        + From<core_deposit::CoreDepositObject>
+        + From<governance::GovernanceObject>
+        + From<core_custody::CoreCustodyObject>,
+{
+    #[allow(clippy::too_many_arguments)]
+    #[record_error_severity]
+    #[tracing::instrument(name = "account_statement.init", skip_all)]
+    pub async fn init(
+        gotenberg_config: gotenberg::GotenbergConfig,
+        jobs: &mut Jobs,
+        outbox: &Outbox<LanaEvent>,
+        deposits: &CoreDeposit<Perms, LanaEvent>,
+        credit: &CoreCredit<Perms, LanaEvent>,
+        customers: &Customers<Perms, LanaEvent>,
+        document_storage: &DocumentStorage,
+        authz: &Perms,
+    ) -> Result<Self, AccountStatementError> {
+        let renderer = rendering::Renderer::new(gotenberg_config);
+        let statement_templates = templates::StatementTemplates::new();
+
+        let generate_account_statement_job_spawner =
+            jobs.add_initializer(GenerateAccountStatementJobInitializer::new(
+                deposits,
+                credit,
+                customers,
+                document_storage,
+                statement_templates,
+                renderer,
+            ));
+
+        outbox
+            .register_event_handler(
+                jobs,
+                OutboxEventJobConfig::new(MONTH_END_ACCOUNT_STATEMENTS_JOB),
+                MonthEndAccountStatementsHandler::new(
+                    deposits,
+                    document_storage,
+                    generate_account_statement_job_spawner,
+                ),
+            )
+            .await?;
+
+        Ok(Self {
+            document_storage: document_storage.clone(),
+            deposits: deposits.clone(),
+            authz: authz.clone(),
+        })
+    }
+
+    pub fn for_subject<'s>(
+        &'s self,
+        sub: &'s <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+    ) -> Result<AccountStatementsForSubject<'s, Perms>, AccountStatementError>
+    where
+        DepositAccountHolderId:
+            for<'a> TryFrom<&'a <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject>,
+    {
+        let holder_id = DepositAccountHolderId::try_from(sub)
+            .map_err(|_| AccountStatementError::SubjectIsNotAccountHolder)?;
+        Ok(AccountStatementsForSubject::new(
+            sub,
+            holder_id,
+            &self.document_storage,
+            &self.deposits,
+            &self.authz,
+        ))
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "account_statement.list_for_deposit_account", skip(self))]
+    pub async fn list_for_deposit_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<AccountStatement>, AccountStatementError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                AccountStatementModuleObject::all_account_statements(),
+                AccountStatementModuleAction::ACCOUNT_STATEMENT_LIST,
+            )
+            .await?;
+
+        list_statements(&self.document_storage, deposit_account_id.into()).await
+    }
+
+    #[record_error_severity]
+    #[instrument(name = "account_statement.generate_download_link", skip(self))]
+    pub async fn generate_download_link(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        statement_id: impl Into<AccountStatementId> + std::fmt::Debug,
+    ) -> Result<GeneratedDocumentDownloadLink, AccountStatementError> {
+        let statement_id = statement_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                AccountStatementModuleObject::account_statement(statement_id),
+                AccountStatementModuleAction::ACCOUNT_STATEMENT_GENERATE_DOWNLOAD_LINK,
+            )
+            .await?;
+
+        Ok(self
+            .document_storage
+            .generate_download_link(statement_id)
+            .await?)
+    }
+}
+
+pub(crate) async fn list_statements(
+    document_storage: &DocumentStorage,
+    deposit_account_id: DepositAccountId,
+) -> Result<Vec<AccountStatement>, AccountStatementError> {
+    Ok(document_storage
+        .list_for_reference_id(ReferenceId::from(uuid::Uuid::from(deposit_account_id)))
+        .await?
+        .into_iter()
+        .filter_map(|document| AccountStatement::try_from_document(document, deposit_account_id))
+        .collect())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccountStatementStatus {
+    Pending,
+    Completed,
+    Failed,
+    Removed,
+}
+
+impl From<DocumentStatus> for AccountStatementStatus {
+    fn from(document_status: DocumentStatus) -> Self {
+        match document_status {
+            DocumentStatus::New => AccountStatementStatus::Pending,
+            DocumentStatus::Active => AccountStatementStatus::Completed,
+            DocumentStatus::Failed => AccountStatementStatus::Failed,
+            DocumentStatus::Archived | DocumentStatus::Deleted => AccountStatementStatus::Removed,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AccountStatement {
+    pub id: AccountStatementId,
+    pub deposit_account_id: DepositAccountId,
+    pub period: StatementPeriod,
+    pub status: AccountStatementStatus,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl AccountStatement {
+    fn try_from_document(document: Document, deposit_account_id: DepositAccountId) -> Option<Self> {
+        let period = StatementPeriod::from_filename(&document.filename)?;
+        Some(Self {
+            id: document.id.into(),
+            deposit_account_id,
+            period,
+            status: document.status.into(),
+            created_at: document.created_at(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    #[test]
+    fn statement_period_closes_on_last_day_of_month() {
+        let day = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let period = StatementPeriod::ending_on(day).unwrap();
+        assert_eq!(period.start, NaiveDate::from_ymd_opt(2024, 2, 1).unwrap());
+        assert_eq!(period.end, day);
+
+        let mid_month = NaiveDate::from_ymd_opt(2024, 2, 28).unwrap();
+        assert!(StatementPeriod::ending_on(mid_month).is_none());
+    }
+
+    #[test]
+    fn statement_period_round_trips_through_filename() {
+        let period = StatementPeriod::for_month_of(NaiveDate::from_ymd_opt(2025, 12, 17).unwrap());
+        assert_eq!(period.filename(), "account_statement_2025-12.pdf");
+        assert_eq!(
+            StatementPeriod::from_filename(&period.filename()),
+            Some(period)
+        );
+        assert_eq!(StatementPeriod::from_filename("loan_agreement.pdf"), None);
+    }
+
+    #[test]
+    fn account_statement_template_renders() -> Result<(), AccountStatementError> {
+        let statement_templates = templates::StatementTemplates::new();
+        let data = serde_json::json!({
+            "email": "test@example.com",
+            "deposit_account_id": "test-123",
+            "period_start": "2025-01-01",
+            "period_end": "2025-01-31",
+            "generated_on": "2025-02-01",
+            "opening_balance": "$1,000.00",
+            "closing_balance": "$1,250.00",
+            "total_credits": "$300.00",
+            "total_debits": "$50.00",
+            "account_lines": [
+                { "date": "2025-01-05", "description": "Deposit", "credit": "$300.00", "debit": "" },
+                { "date": "2025-01-20", "description": "Withdrawal", "credit": "", "debit": "$50.00" }
+            ],
+            "credit_lines": []
+        });
+
+        let result = statement_templates.render_template("account_statement", &data)?;
+        assert!(result.contains("test@example.com"));
+        assert!(result.contains("| 2025-01-05 | Deposit | $300.00 |  |"));
+        assert!(result.contains("No credit facility activity during this period."));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,173 @@
+use std::{fmt::Display, str::FromStr};
+
+use authz::{ActionPermission, AllOrOne, action_description::*, map_action};
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+es_entity::entity_id! {
+    AccountStatementId;
+
+    AccountStatementId => document_storage::DocumentId,
+}
+
+pub const ACCOUNT_STATEMENT: audit::SystemActor = audit::SystemActor::new("account-statement");
+
+permission_sets_macro::permission_sets! {
+    AccountStatementViewer("Can list customer account statements and generate download links"),
+}
+
+/// A calendar month covered by a single statement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatementPeriod {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+
+impl StatementPeriod {
+    pub fn for_month_of(date: NaiveDate) -> Self {
+        let start = date.with_day(1).expect("first day of month always exists");
+        let end = start
+            .checked_add_months(chrono::Months::new(1))
+            .and_then(|d| d.pred_opt())
+            .expect("last day of month always exists");
+        Self { start, end }
+    }
+
+    /// The period that closes with `day`, if `day` is the last day of a month.
+    pub fn ending_on(day: NaiveDate) -> Option<Self> {
+        let period = Self::for_month_of(day);
+        (period.end == day).then_some(period)
+    }
+
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.start <= date && date <= self.end
+    }
+
+    pub fn filename(&self) -> String {
+        format!("account_statement_{}.pdf", self.start.format("%Y-%m"))
+    }
+
+    pub fn from_filename(filename: &str) -> Option<Self> {
+        let month = filename
+            .strip_prefix("account_statement_")?
+            .strip_suffix(".pdf")?;
+        NaiveDate::parse_from_str(&format!("{month}-01"), "%Y-%m-%d")
+            .ok()
+            .map(Self::for_month_of)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, strum::EnumDiscriminants)]
+#[strum_discriminants(derive(strum::Display, strum::EnumString, strum::VariantArray))]
+#[strum_discriminants(strum(serialize_all = "kebab-case"))]
+pub enum AccountStatementModuleAction {
+    AccountStatement(AccountStatementAction),
+}
+
+impl AccountStatementModuleAction {
+    pub const ACCOUNT_STATEMENT_LIST: Self =
+        AccountStatementModuleAction::AccountStatement(AccountStatementAction::List);
+    pub const ACCOUNT_STATEMENT_GENERATE_DOWNLOAD_LINK: Self =
+        AccountStatementModuleAction::AccountStatement(
+            AccountStatementAction::GenerateDownloadLink,
+        );
+
+    pub fn actions() -> Vec<ActionMapping> {
+        use AccountStatementModuleActionDiscriminants::*;
+        map_action!(account_statement, AccountStatement, AccountStatementAction)
+    }
+}
+
+impl Display for AccountStatementModuleAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:",
+            AccountStatementModuleActionDiscriminants::from(self)
+        )?;
+        use AccountStatementModuleAction::*;
+        match self {
+            AccountStatement(action) => action.fmt(f),
+        }
+    }
+}
+
+impl FromStr for AccountStatementModuleAction {
+    type Err = strum::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (entity, action) = s.split_once(':').expect("missing colon");
+        use AccountStatementModuleActionDiscriminants::*;
+        let res = match entity.parse()? {
+            AccountStatement => action.parse::<AccountStatementAction>()?,
+        };
+        Ok(res.into())
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum AccountStatementAction {
+    List,
+    GenerateDownloadLink,
+}
+
+impl ActionPermission for AccountStatementAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::List | Self::GenerateDownloadLink => PERMISSION_SET_ACCOUNT_STATEMENT_VIEWER,
+        }
+    }
+}
+
+pub type AccountStatementAllOrOne = AllOrOne<AccountStatementId>;
+
+#[derive(Clone, Copy, Debug, PartialEq, strum::EnumDiscriminants)]
+#[strum_discriminants(derive(strum::Display, strum::EnumString))]
+#[strum_discriminants(strum(serialize_all = "kebab-case"))]
+pub enum AccountStatementModuleObject {
+    AccountStatement(AccountStatementAllOrOne),
+}
+
+impl AccountStatementModuleObject {
+    pub const fn all_account_statements() -> Self {
+        Self::AccountStatement(AllOrOne::All)
+    }
+
+    pub const fn account_statement(id: AccountStatementId) -> Self {
+        Self::AccountStatement(AllOrOne::ById(id))
+    }
+}
+
+impl From<AccountStatementAction> for AccountStatementModuleAction {
+    fn from(action: AccountStatementAction) -> Self {
+        AccountStatementModuleAction::AccountStatement(action)
+    }
+}
+
+impl std::fmt::Display for AccountStatementModuleObject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let discriminant = AccountStatementModuleObjectDiscriminants::from(self);
+        match self {
+            Self::AccountStatement(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+        }
+    }
+}
+
+impl FromStr for AccountStatementModuleObject {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (entity, id) = s.split_once('/').expect("missing slash");
+        use AccountStatementModuleObjectDiscriminants::*;
+        let res = match entity.parse().expect("invalid entity") {
+            AccountStatement => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse AccountStatementObject")?;
+                AccountStatementModuleObject::AccountStatement(obj_ref)
+            }
+        };
+        Ok(res)
+    }
+}
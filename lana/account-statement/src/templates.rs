@@ -0,0 +1,50 @@
+use handlebars::Handlebars;
+use serde::Serialize;
+
+use tracing_macros::record_error_severity;
+
+use super::error::AccountStatementError;
+
+/// Statement template manager that handles embedded templates
+#[derive(Clone)]
+pub struct StatementTemplates {
+    handlebars: Handlebars<'static>,
+}
+
+impl Default for StatementTemplates {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StatementTemplates {
+    pub fn new() -> Self {
+        let mut handlebars = Handlebars::new();
+        handlebars
+            .register_template_string(
+                "account_statement",
+                include_str!("templates/account_statement.md.hbs"),
+            )
+            .expect("Could not register 'account_statement' template");
+
+        Self { handlebars }
+    }
+
+    #[record_error_severity]
+    #[tracing::instrument(
+        name = "lana.account_statement.render_template",
+        skip_all,
+        fields(template_name = %template_name),
+    )]
+    pub fn render_template<T: Serialize>(
+        &self,
+        template_name: &str,
+        data: &T,
+    ) -> Result<String, AccountStatementError> {
+        let rendered = self
+            .handlebars
+            .render(template_name, data)
+            .map_err(|e| AccountStatementError::Rendering(rendering::RenderingError::Render(e)))?;
+        Ok(rendered)
+    }
+}
@@ -0,0 +1,3 @@
+mod recurring_journal_entries;
+
+pub use recurring_journal_entries::*;
@@ -0,0 +1,70 @@
+use tracing::{Span, instrument};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use core_accounting::{
+    CoreAccounting, CoreAccountingAction, CoreAccountingEvent, CoreAccountingObject,
+    GovernanceAction, GovernanceObject,
+};
+use core_time_events::CoreTimeEvent;
+use governance::GovernanceEvent;
+use obix::out::{OutboxEventHandler, OutboxEventMarker, PersistentOutboxEvent};
+
+use job::JobType;
+
+pub const RECURRING_JOURNAL_ENTRIES_JOB: JobType = JobType::new("outbox.recurring-journal-entries");
+
+pub struct RecurringJournalEntriesHandler<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreAccountingEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreTimeEvent>,
+{
+    accounting: CoreAccounting<Perms, E>,
+}
+
+impl<Perms, E> RecurringJournalEntriesHandler<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreAccountingEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreTimeEvent>,
+{
+    pub fn new(accounting: &CoreAccounting<Perms, E>) -> Self {
+        Self {
+            accounting: accounting.clone(),
+        }
+    }
+}
+
+impl<Perms, E> OutboxEventHandler<E> for RecurringJournalEntriesHandler<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreAccountingEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreTimeEvent>,
+{
+    #[instrument(name = "accounting_sync.recurring_journal_entries.process_message", parent = None, skip(self, _op, event), fields(seq = %event.sequence, handled = false, event_type = tracing::field::Empty))]
+    async fn handle_persistent(
+        &self,
+        _op: &mut es_entity::DbOp<'_>,
+        event: &PersistentOutboxEvent<E>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(e @ CoreTimeEvent::EndOfDay { day, .. }) = event.as_event() {
+            event.inject_trace_parent();
+            Span::current().record("handled", true);
+            Span::current().record("event_type", e.as_ref());
+
+            self.accounting
+                .recurring_journal_entries()
+                .post_due_occurrences(*day)
+                .await?;
+        }
+        Ok(())
+    }
+}
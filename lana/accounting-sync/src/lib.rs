@@ -0,0 +1,79 @@
+#![cfg_attr(feature = "fail-on-warnings", deny(warnings))]
+#![cfg_attr(feature = "fail-on-warnings", deny(clippy::all))]
+
+pub mod error;
+mod job;
+
+use error::*;
+use job::*;
+
+use ::job::Jobs;
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use core_accounting::{
+    CoreAccounting, CoreAccountingAction, CoreAccountingEvent, CoreAccountingObject,
+    GovernanceAction, GovernanceObject,
+};
+use core_time_events::CoreTimeEvent;
+use governance::GovernanceEvent;
+use obix::out::{Outbox, OutboxEventJobConfig, OutboxEventMarker};
+use tracing_macros::record_error_severity;
+
+pub struct AccountingSync<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreAccountingEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreTimeEvent>,
+{
+    _phantom: std::marker::PhantomData<(Perms, E)>,
+    _outbox: Outbox<E>,
+}
+
+impl<Perms, E> Clone for AccountingSync<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreAccountingEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreTimeEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _outbox: self._outbox.clone(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<Perms, E> AccountingSync<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreAccountingEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreTimeEvent>,
+{
+    #[record_error_severity]
+    #[tracing::instrument(name = "accounting_sync.init", skip_all)]
+    pub async fn init(
+        jobs: &mut Jobs,
+        outbox: &Outbox<E>,
+        accounting: &CoreAccounting<Perms, E>,
+    ) -> Result<Self, AccountingSyncError> {
+        outbox
+            .register_event_handler(
+                jobs,
+                OutboxEventJobConfig::new(RECURRING_JOURNAL_ENTRIES_JOB),
+                RecurringJournalEntriesHandler::new(accounting),
+            )
+            .await?;
+
+        Ok(Self {
+            _phantom: std::marker::PhantomData,
+            _outbox: outbox.clone(),
+        })
+    }
+}
@@ -0,0 +1,111 @@
+use async_graphql::*;
+
+use lana_app::cash_flow::{
+    CashFlowLine as DomainCashFlowLine, CashFlowSection as DomainCashFlowSection,
+    CashFlowStatement as DomainCashFlowStatement,
+};
+
+use crate::{graphql::loader::LanaDataLoader, primitives::*};
+
+use super::{AccountingCsvDocument, LedgerAccount};
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct CashFlowStatement {
+    from: Date,
+    until: Option<Date>,
+    net_income: SignedUsdCents,
+    operating: CashFlowSection,
+    investing: CashFlowSection,
+    financing: CashFlowSection,
+    opening_cash: SignedUsdCents,
+    closing_cash: SignedUsdCents,
+
+    #[graphql(skip)]
+    pub entity: Arc<DomainCashFlowStatement>,
+}
+
+impl From<DomainCashFlowStatement> for CashFlowStatement {
+    fn from(statement: DomainCashFlowStatement) -> Self {
+        Self {
+            from: statement.from.into(),
+            until: statement.until.map(Into::into),
+            net_income: SignedUsdCents::from_usd(statement.net_income),
+            operating: statement.operating.clone().into(),
+            investing: statement.investing.clone().into(),
+            financing: statement.financing.clone().into(),
+            opening_cash: SignedUsdCents::from_usd(statement.opening_cash),
+            closing_cash: SignedUsdCents::from_usd(statement.closing_cash),
+            entity: Arc::new(statement),
+        }
+    }
+}
+
+#[ComplexObject]
+impl CashFlowStatement {
+    async fn net_change_in_cash(&self) -> SignedUsdCents {
+        SignedUsdCents::from_usd(self.entity.net_change_in_cash())
+    }
+
+    async fn unreconciled(&self) -> SignedUsdCents {
+        SignedUsdCents::from_usd(self.entity.unreconciled())
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct CashFlowSection {
+    lines: Vec<CashFlowLine>,
+    total: SignedUsdCents,
+}
+
+impl From<DomainCashFlowSection> for CashFlowSection {
+    fn from(section: DomainCashFlowSection) -> Self {
+        Self {
+            lines: section.lines.into_iter().map(CashFlowLine::from).collect(),
+            total: SignedUsdCents::from_usd(section.total),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct CashFlowLine {
+    ledger_account_id: UUID,
+    code: String,
+    name: String,
+    amount: SignedUsdCents,
+
+    #[graphql(skip)]
+    pub entity: DomainCashFlowLine,
+}
+
+impl From<DomainCashFlowLine> for CashFlowLine {
+    fn from(line: DomainCashFlowLine) -> Self {
+        Self {
+            ledger_account_id: UUID::from(line.ledger_account_id),
+            code: line.code.to_string(),
+            name: line.name.clone(),
+            amount: SignedUsdCents::from_usd(line.amount),
+            entity: line,
+        }
+    }
+}
+
+#[ComplexObject]
+impl CashFlowLine {
+    async fn ledger_account(&self, ctx: &Context<'_>) -> async_graphql::Result<LedgerAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.ledger_account_id)
+            .await?
+            .expect("ledger account not found");
+        Ok(account)
+    }
+}
+
+#[derive(InputObject)]
+pub struct CashFlowStatementCsvCreateInput {
+    pub from: Date,
+    pub until: Option<Date>,
+}
+crate::mutation_payload! { CashFlowStatementCsvCreatePayload, accounting_csv_document: AccountingCsvDocument }
@@ -6,7 +6,10 @@ use lana_app::accounting::{
     AccountCategory as DomainAccountCategory, AccountInfo as DomainAccountInfo,
     Chart as DomainChart,
 };
-use lana_app::primitives::{AccountingBaseConfig, DebitOrCredit};
+use lana_app::primitives::{
+    AccountingBaseConfig, CashFlowCategory as DomainCashFlowCategory,
+    CashFlowMapping as DomainCashFlowMapping, DebitOrCredit,
+};
 
 #[derive(SimpleObject, Clone)]
 #[graphql(complex)]
@@ -58,6 +61,7 @@ pub struct AccountingBaseConfigOutput {
     pub revenue_code: String,
     pub cost_of_revenue_code: String,
     pub expenses_code: String,
+    pub cash_flow_mapping: Vec<CashFlowMapping>,
 }
 
 impl From<AccountingBaseConfig> for AccountingBaseConfigOutput {
@@ -75,6 +79,56 @@ impl From<AccountingBaseConfig> for AccountingBaseConfigOutput {
             revenue_code: config.revenue_code.to_string(),
             cost_of_revenue_code: config.cost_of_revenue_code.to_string(),
             expenses_code: config.expenses_code.to_string(),
+            cash_flow_mapping: config
+                .cash_flow_mapping
+                .into_iter()
+                .map(CashFlowMapping::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Enum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CashFlowCategory {
+    Cash,
+    Operating,
+    Investing,
+    Financing,
+}
+
+impl From<CashFlowCategory> for DomainCashFlowCategory {
+    fn from(category: CashFlowCategory) -> Self {
+        match category {
+            CashFlowCategory::Cash => DomainCashFlowCategory::Cash,
+            CashFlowCategory::Operating => DomainCashFlowCategory::Operating,
+            CashFlowCategory::Investing => DomainCashFlowCategory::Investing,
+            CashFlowCategory::Financing => DomainCashFlowCategory::Financing,
+        }
+    }
+}
+
+impl From<DomainCashFlowCategory> for CashFlowCategory {
+    fn from(category: DomainCashFlowCategory) -> Self {
+        match category {
+            DomainCashFlowCategory::Cash => CashFlowCategory::Cash,
+            DomainCashFlowCategory::Operating => CashFlowCategory::Operating,
+            DomainCashFlowCategory::Investing => CashFlowCategory::Investing,
+            DomainCashFlowCategory::Financing => CashFlowCategory::Financing,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct CashFlowMapping {
+    pub code: String,
+    pub category: CashFlowCategory,
+}
+
+impl From<DomainCashFlowMapping> for CashFlowMapping {
+    fn from(mapping: DomainCashFlowMapping) -> Self {
+        Self {
+            code: mapping.code.to_string(),
+            category: mapping.category.into(),
         }
     }
 }
@@ -142,12 +196,20 @@ pub struct AccountingBaseConfigInput {
     pub revenue_code: String,
     pub cost_of_revenue_code: String,
     pub expenses_code: String,
+    #[graphql(default)]
+    pub cash_flow_mapping: Vec<CashFlowMappingInput>,
 }
 
 impl TryFrom<AccountingBaseConfigInput> for AccountingBaseConfig {
     type Error = Box<dyn std::error::Error + Sync + Send>;
 
     fn try_from(input: AccountingBaseConfigInput) -> Result<Self, Self::Error> {
+        let cash_flow_mapping = input
+            .cash_flow_mapping
+            .into_iter()
+            .map(DomainCashFlowMapping::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
         Ok(AccountingBaseConfig::try_new(
             input.assets_code.parse()?,
             input.liabilities_code.parse()?,
@@ -157,10 +219,34 @@ impl TryFrom<AccountingBaseConfigInput> for AccountingBaseConfig {
             input.revenue_code.parse()?,
             input.cost_of_revenue_code.parse()?,
             input.expenses_code.parse()?,
-        )?)
+        )?
+        .with_cash_flow_mapping(cash_flow_mapping)?)
+    }
+}
+
+#[derive(InputObject)]
+pub struct CashFlowMappingInput {
+    pub code: String,
+    pub category: CashFlowCategory,
+}
+
+impl TryFrom<CashFlowMappingInput> for DomainCashFlowMapping {
+    type Error = Box<dyn std::error::Error + Sync + Send>;
+
+    fn try_from(input: CashFlowMappingInput) -> Result<Self, Self::Error> {
+        Ok(Self {
+            code: input.code.parse()?,
+            category: input.category.into(),
+        })
     }
 }
 
+#[derive(InputObject)]
+pub struct ChartOfAccountsCashFlowMappingUpdateInput {
+    pub cash_flow_mapping: Vec<CashFlowMappingInput>,
+}
+crate::mutation_payload! { ChartOfAccountsCashFlowMappingUpdatePayload, chart_of_accounts: ChartOfAccounts }
+
 #[derive(InputObject)]
 pub struct ChartOfAccountsCsvImportWithBaseConfigInput {
     pub file: Upload,
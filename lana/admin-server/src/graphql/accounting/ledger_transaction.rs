@@ -4,7 +4,10 @@ use lana_app::primitives::Subject;
 pub use lana_app::{
     accounting::ledger_transaction::LedgerTransactionCursor,
     credit::DISBURSAL_TRANSACTION_ENTITY_TYPE,
-    deposit::{DEPOSIT_TRANSACTION_ENTITY_TYPE, WITHDRAWAL_TRANSACTION_ENTITY_TYPE},
+    deposit::{
+        DEPOSIT_TRANSACTION_ENTITY_TYPE, TRANSFER_TRANSACTION_ENTITY_TYPE,
+        WITHDRAWAL_TRANSACTION_ENTITY_TYPE,
+    },
 };
 
 pub type DomainLedgerTransaction =
@@ -13,7 +16,7 @@ pub type DomainLedgerTransaction =
 use crate::{
     graphql::{
         access::User, audit::System, credit_facility::CreditFacilityDisbursal, deposit::Deposit,
-        loader::*, withdrawal::Withdrawal,
+        loader::*, transfer::Transfer, withdrawal::Withdrawal,
     },
     primitives::*,
 };
@@ -34,6 +37,7 @@ pub struct LedgerTransaction {
 pub enum LedgerTransactionEntity {
     Deposit(Deposit),
     Withdrawal(Withdrawal),
+    Transfer(Transfer),
     Disbursal(CreditFacilityDisbursal),
 }
 
@@ -72,6 +76,13 @@ impl LedgerTransaction {
                     .expect("Could not find withdrawal entity");
                 Some(LedgerTransactionEntity::Withdrawal(withdrawal))
             }
+            entity_type if entity_type == &TRANSFER_TRANSACTION_ENTITY_TYPE => {
+                let transfer = loader
+                    .load_one(TransferId::from(entity_ref.entity_id))
+                    .await?
+                    .expect("Could not find transfer entity");
+                Some(LedgerTransactionEntity::Transfer(transfer))
+            }
             entity_type if entity_type == &DISBURSAL_TRANSACTION_ENTITY_TYPE => {
                 let disbursal = loader
                     .load_one(DisbursalId::from(entity_ref.entity_id))
@@ -1,13 +1,151 @@
 use async_graphql::*;
 
-pub use lana_app::accounting::manual_transaction::ManualEntryInput;
+pub use lana_app::accounting::manual_transaction::{
+    ManualEntryInput, ManualTransaction as DomainManualTransaction,
+    ManualTransactionEntry as DomainManualTransactionEntry, ManualTransactionStatus,
+    ReversalEffective,
+};
 
-use crate::graphql::primitives::*;
-use crate::primitives::Date;
+use crate::graphql::{approval_process::ApprovalProcess, loader::LanaDataLoader, primitives::*};
+use crate::primitives::*;
 
 use lana_app::primitives::DebitOrCredit;
 
-use super::ledger_transaction::LedgerTransaction;
+use super::{LedgerAccount, ledger_transaction::LedgerTransaction};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct ManualTransaction {
+    id: ID,
+    manual_transaction_id: UUID,
+    reference: String,
+    description: String,
+    effective: Option<Date>,
+    status: ManualTransactionStatus,
+    entries: Vec<ManualTransactionEntry>,
+    reversal_effective: Option<Date>,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(crate) entity: Arc<DomainManualTransaction>,
+}
+
+impl From<DomainManualTransaction> for ManualTransaction {
+    fn from(manual_transaction: DomainManualTransaction) -> Self {
+        Self {
+            id: manual_transaction.id.to_global_id(),
+            manual_transaction_id: UUID::from(manual_transaction.id),
+            reference: manual_transaction.reference.clone(),
+            description: manual_transaction.description.clone(),
+            effective: manual_transaction.effective.map(Into::into),
+            status: manual_transaction.status(),
+            entries: manual_transaction
+                .entries
+                .iter()
+                .cloned()
+                .map(ManualTransactionEntry::from)
+                .collect(),
+            reversal_effective: manual_transaction.reversal_effective().map(Into::into),
+            created_at: manual_transaction.created_at().into(),
+            entity: Arc::new(manual_transaction),
+        }
+    }
+}
+
+#[ComplexObject]
+impl ManualTransaction {
+    async fn status_reason(&self) -> Option<&str> {
+        self.entity.status_reason()
+    }
+
+    async fn approval_process(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<ApprovalProcess>> {
+        let Some(approval_process_id) = self.entity.approval_process_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let process = loader
+            .load_one(approval_process_id)
+            .await?
+            .expect("process not found");
+        Ok(Some(process))
+    }
+
+    async fn reversal_approval_process(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<ApprovalProcess>> {
+        let Some(approval_process_id) = self.entity.reversal_approval_process_id() else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let process = loader
+            .load_one(approval_process_id)
+            .await?
+            .expect("process not found");
+        Ok(Some(process))
+    }
+
+    async fn ledger_transaction(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<LedgerTransaction>> {
+        if !self.entity.has_ledger_transaction() {
+            return Ok(None);
+        }
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(self.entity.ledger_transaction_id).await?)
+    }
+
+    async fn reversal_ledger_transaction(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<LedgerTransaction>> {
+        let Some(reversal_ledger_transaction_id) = self.entity.reversal_ledger_transaction_id()
+        else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(reversal_ledger_transaction_id).await?)
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct ManualTransactionEntry {
+    #[graphql(skip)]
+    ledger_account_id: LedgerAccountId,
+    amount: Decimal,
+    currency: String,
+    direction: DebitOrCredit,
+    description: String,
+}
+
+impl From<DomainManualTransactionEntry> for ManualTransactionEntry {
+    fn from(entry: DomainManualTransactionEntry) -> Self {
+        Self {
+            ledger_account_id: entry.account_id,
+            amount: entry.amount.into(),
+            currency: entry.currency.to_string(),
+            direction: entry.direction,
+            description: entry.description,
+        }
+    }
+}
+
+#[ComplexObject]
+impl ManualTransactionEntry {
+    async fn ledger_account(&self, ctx: &Context<'_>) -> async_graphql::Result<LedgerAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.ledger_account_id)
+            .await?
+            .expect("ledger account not found");
+        Ok(account)
+    }
+}
 
 #[derive(InputObject)]
 pub struct ManualTransactionExecuteInput {
@@ -16,7 +154,31 @@ pub struct ManualTransactionExecuteInput {
     pub effective: Option<Date>,
     pub entries: Vec<ManualTransactionEntryInput>,
 }
-crate::mutation_payload! { ManualTransactionExecutePayload, transaction: LedgerTransaction }
+crate::mutation_payload! { ManualTransactionExecutePayload, manual_transaction: ManualTransaction }
+
+#[derive(InputObject)]
+pub struct ManualTransactionReverseInput {
+    pub manual_transaction_id: UUID,
+    pub effective: Option<Date>,
+    #[graphql(default)]
+    pub next_open_period: bool,
+}
+crate::mutation_payload! { ManualTransactionReversePayload, manual_transaction: ManualTransaction }
+
+impl TryFrom<&ManualTransactionReverseInput> for ReversalEffective {
+    type Error = async_graphql::Error;
+
+    fn try_from(input: &ManualTransactionReverseInput) -> Result<Self, Self::Error> {
+        match (input.effective, input.next_open_period) {
+            (Some(_), true) => Err(async_graphql::Error::new(
+                "effective and nextOpenPeriod cannot be combined",
+            )),
+            (Some(effective), false) => Ok(ReversalEffective::On(effective.into_inner())),
+            (None, true) => Ok(ReversalEffective::NextOpenPeriod),
+            (None, false) => Ok(ReversalEffective::Today),
+        }
+    }
+}
 
 #[derive(InputObject)]
 pub struct ManualTransactionEntryInput {
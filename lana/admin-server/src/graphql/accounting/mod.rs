@@ -1,4 +1,5 @@
 mod balance_sheet;
+mod cash_flow;
 mod chart_of_accounts;
 mod csv;
 mod fiscal_year;
@@ -7,10 +8,12 @@ mod ledger_account;
 mod ledger_transaction;
 mod manual_transaction;
 mod profit_and_loss;
+mod recurring_journal_entry;
 mod transaction_templates;
 mod trial_balance;
 
 pub use balance_sheet::*;
+pub use cash_flow::*;
 pub use chart_of_accounts::*;
 pub use csv::*;
 pub use fiscal_year::*;
@@ -19,5 +22,6 @@ pub use ledger_account::*;
 pub use ledger_transaction::*;
 pub use manual_transaction::*;
 pub use profit_and_loss::*;
+pub use recurring_journal_entry::*;
 pub use transaction_templates::*;
 pub use trial_balance::*;
@@ -0,0 +1,211 @@
+use async_graphql::*;
+
+pub use lana_app::accounting::recurring_journal_entry::{
+    NewRecurringJournalEntryInput, RecurringJournalEntriesByCreatedAtCursor,
+    RecurringJournalEntry as DomainRecurringJournalEntry, RecurringJournalEntryFrequency,
+    RecurringJournalEntryOccurrence as DomainRecurringJournalEntryOccurrence,
+    RecurringJournalEntryStatus, ScheduledAmount,
+};
+
+use crate::graphql::{loader::LanaDataLoader, primitives::*};
+use crate::primitives::*;
+
+use super::{LedgerAccount, ManualTransaction};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct RecurringJournalEntry {
+    id: ID,
+    recurring_journal_entry_id: UUID,
+    reference: String,
+    description: String,
+    currency: String,
+    amount: Decimal,
+    amount_schedule: Vec<RecurringJournalEntryScheduledAmount>,
+    frequency: RecurringJournalEntryFrequency,
+    start_date: Date,
+    end_date: Option<Date>,
+    status: RecurringJournalEntryStatus,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(crate) entity: Arc<DomainRecurringJournalEntry>,
+}
+
+impl From<DomainRecurringJournalEntry> for RecurringJournalEntry {
+    fn from(entry: DomainRecurringJournalEntry) -> Self {
+        Self {
+            id: entry.id.to_global_id(),
+            recurring_journal_entry_id: UUID::from(entry.id),
+            reference: entry.reference.clone(),
+            description: entry.description.clone(),
+            currency: entry.currency.to_string(),
+            amount: entry.amount.into(),
+            amount_schedule: entry
+                .amount_schedule
+                .iter()
+                .copied()
+                .map(RecurringJournalEntryScheduledAmount::from)
+                .collect(),
+            frequency: entry.frequency,
+            start_date: entry.start_date.into(),
+            end_date: entry.end_date.map(Into::into),
+            status: entry.status(),
+            created_at: entry.created_at().into(),
+            entity: Arc::new(entry),
+        }
+    }
+}
+
+#[ComplexObject]
+impl RecurringJournalEntry {
+    async fn paused_on(&self) -> Option<Date> {
+        self.entity.paused_on().map(Into::into)
+    }
+
+    async fn debit_account(&self, ctx: &Context<'_>) -> async_graphql::Result<LedgerAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.debit_account_id)
+            .await?
+            .expect("ledger account not found");
+        Ok(account)
+    }
+
+    async fn credit_account(&self, ctx: &Context<'_>) -> async_graphql::Result<LedgerAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.credit_account_id)
+            .await?
+            .expect("ledger account not found");
+        Ok(account)
+    }
+
+    async fn upcoming_occurrences(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default = 5)] first: usize,
+    ) -> async_graphql::Result<Vec<RecurringJournalEntryOccurrence>> {
+        let (app, _) = crate::app_and_sub_from_ctx!(ctx);
+        Ok(app
+            .accounting()
+            .recurring_journal_entries()
+            .upcoming_occurrences(&self.entity, first)
+            .into_iter()
+            .map(RecurringJournalEntryOccurrence::from)
+            .collect())
+    }
+
+    async fn manual_transactions(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<ManualTransaction>> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let ids = self.entity.submitted_manual_transaction_ids();
+        let loaded_transactions = loader.load_many(ids.iter().copied()).await?;
+
+        Ok(ids
+            .iter()
+            .filter_map(|id| loaded_transactions.get(id).cloned())
+            .collect())
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct RecurringJournalEntryScheduledAmount {
+    starting_on: Date,
+    amount: Decimal,
+}
+
+impl From<ScheduledAmount> for RecurringJournalEntryScheduledAmount {
+    fn from(scheduled: ScheduledAmount) -> Self {
+        Self {
+            starting_on: scheduled.starting_on.into(),
+            amount: scheduled.amount.into(),
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct RecurringJournalEntryOccurrence {
+    date: Date,
+    amount: Decimal,
+    skipped: bool,
+}
+
+impl From<DomainRecurringJournalEntryOccurrence> for RecurringJournalEntryOccurrence {
+    fn from(occurrence: DomainRecurringJournalEntryOccurrence) -> Self {
+        Self {
+            date: occurrence.date.into(),
+            amount: occurrence.amount.into(),
+            skipped: occurrence.skipped,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct RecurringJournalEntryScheduledAmountInput {
+    pub starting_on: Date,
+    pub amount: Decimal,
+}
+
+#[derive(InputObject)]
+pub struct RecurringJournalEntryCreateInput {
+    pub reference: String,
+    pub description: String,
+    pub debit_account_ref: String,
+    pub credit_account_ref: String,
+    pub currency: String,
+    pub amount: Decimal,
+    #[graphql(default)]
+    pub amount_schedule: Vec<RecurringJournalEntryScheduledAmountInput>,
+    pub frequency: RecurringJournalEntryFrequency,
+    pub start_date: Date,
+    pub end_date: Option<Date>,
+}
+crate::mutation_payload! { RecurringJournalEntryCreatePayload, recurring_journal_entry: RecurringJournalEntry }
+
+impl TryFrom<RecurringJournalEntryCreateInput> for NewRecurringJournalEntryInput {
+    type Error = Box<dyn std::error::Error + Sync + Send>;
+
+    fn try_from(i: RecurringJournalEntryCreateInput) -> Result<Self, Self::Error> {
+        Ok(Self {
+            reference: i.reference,
+            description: i.description,
+            debit_account: i.debit_account_ref.parse()?,
+            credit_account: i.credit_account_ref.parse()?,
+            currency: i.currency.parse()?,
+            amount: i.amount.into(),
+            amount_schedule: i
+                .amount_schedule
+                .into_iter()
+                .map(|scheduled| ScheduledAmount {
+                    starting_on: scheduled.starting_on.into_inner(),
+                    amount: scheduled.amount.into(),
+                })
+                .collect(),
+            frequency: i.frequency,
+            start_date: i.start_date.into_inner(),
+            end_date: i.end_date.map(|date| date.into_inner()),
+        })
+    }
+}
+
+#[derive(InputObject)]
+pub struct RecurringJournalEntryPauseInput {
+    pub recurring_journal_entry_id: UUID,
+}
+crate::mutation_payload! { RecurringJournalEntryPausePayload, recurring_journal_entry: RecurringJournalEntry }
+
+#[derive(InputObject)]
+pub struct RecurringJournalEntryResumeInput {
+    pub recurring_journal_entry_id: UUID,
+}
+crate::mutation_payload! { RecurringJournalEntryResumePayload, recurring_journal_entry: RecurringJournalEntry }
+
+#[derive(InputObject)]
+pub struct RecurringJournalEntrySkipOccurrenceInput {
+    pub recurring_journal_entry_id: UUID,
+    pub date: Date,
+}
+crate::mutation_payload! { RecurringJournalEntrySkipOccurrencePayload, recurring_journal_entry: RecurringJournalEntry }
@@ -3,8 +3,8 @@ use async_graphql::*;
 use crate::primitives::*;
 
 use super::{
-    access::User, approval_rules::*, credit_facility::*, loader::LanaDataLoader, policy::*,
-    withdrawal::*,
+    access::User, accounting::ManualTransaction, approval_rules::*, credit_facility::*,
+    loader::LanaDataLoader, policy::*, transfer::*, withdrawal::*,
 };
 
 pub use lana_app::governance::{
@@ -42,13 +42,41 @@ impl From<DomainApprovalProcess> for ApprovalProcess {
 #[ComplexObject]
 impl ApprovalProcess {
     async fn rules(&self) -> ApprovalRules {
-        ApprovalRules::from(self.entity.rules)
+        ApprovalRules::from(self.entity.rules.clone())
+    }
+
+    async fn stages(&self) -> Vec<ApprovalStage> {
+        self.entity
+            .stages
+            .iter()
+            .copied()
+            .map(ApprovalStage::from)
+            .collect()
+    }
+
+    async fn current_stage_index(&self) -> usize {
+        self.entity.current_stage_index()
+    }
+
+    async fn current_stage(&self) -> Option<ApprovalStage> {
+        self.entity
+            .current_stage()
+            .copied()
+            .map(ApprovalStage::from)
     }
 
     async fn denied_reason(&self) -> Option<&str> {
         self.entity.denied_reason()
     }
 
+    async fn expires_at(&self) -> Option<Timestamp> {
+        self.entity.expires_at().map(Into::into)
+    }
+
+    async fn expired(&self) -> bool {
+        self.entity.expired()
+    }
+
     async fn policy(&self, ctx: &Context<'_>) -> async_graphql::Result<Policy> {
         let loader = ctx.data_unchecked::<LanaDataLoader>();
         let policy = loader
@@ -85,8 +113,8 @@ impl ApprovalProcess {
                 .load_one(committee_id)
                 .await?
                 .expect("committee not found");
-            let mut approvers = self.entity.approvers();
-            let mut deniers = self.entity.deniers();
+            let mut approvers = self.entity.stage_approvers();
+            let mut deniers = self.entity.stage_deniers();
             let mut voters: Vec<_> = committee
                 .entity
                 .members()
@@ -129,7 +157,8 @@ impl ApprovalProcess {
     async fn target(&self, ctx: &Context<'_>) -> async_graphql::Result<ApprovalProcessTarget> {
         let loader = ctx.data_unchecked::<LanaDataLoader>();
         match self.approval_process_type {
-            ApprovalProcessType::WithdrawalApproval => {
+            ApprovalProcessType::WithdrawalApproval
+            | ApprovalProcessType::WithdrawalOverLimitApproval => {
                 let withdrawal = loader
                     .load_one(
                         self.entity
@@ -141,6 +170,31 @@ impl ApprovalProcess {
                     .expect("withdrawal not found");
                 Ok(ApprovalProcessTarget::Withdrawal(withdrawal))
             }
+            ApprovalProcessType::TransferApproval => {
+                let transfer = loader
+                    .load_one(
+                        self.entity
+                            .target_ref()
+                            .parse::<TransferId>()
+                            .expect("invalid target ref"),
+                    )
+                    .await?
+                    .expect("transfer not found");
+                Ok(ApprovalProcessTarget::Transfer(transfer))
+            }
+            ApprovalProcessType::ManualTransactionApproval
+            | ApprovalProcessType::ManualTransactionReversalApproval => {
+                let manual_transaction = loader
+                    .load_one(
+                        self.entity
+                            .target_ref()
+                            .parse::<ManualTransactionId>()
+                            .expect("invalid target ref"),
+                    )
+                    .await?
+                    .expect("manual transaction not found");
+                Ok(ApprovalProcessTarget::ManualTransaction(manual_transaction))
+            }
             ApprovalProcessType::CreditFacilityProposalApproval => {
                 let credit_facility_proposal = loader
                     .load_one(
@@ -167,6 +221,19 @@ impl ApprovalProcess {
                     .expect("disbursal not found");
                 Ok(ApprovalProcessTarget::CreditFacilityDisbursal(disbursal))
             }
+            ApprovalProcessType::CreditFacilityAmendmentApproval
+            | ApprovalProcessType::CreditFacilityWriteOffApproval => {
+                let credit_facility = loader
+                    .load_one(
+                        self.entity
+                            .target_ref()
+                            .parse::<CreditFacilityId>()
+                            .expect("invalid target ref"),
+                    )
+                    .await?
+                    .expect("credit facility not found");
+                Ok(ApprovalProcessTarget::CreditFacility(credit_facility))
+            }
         }
     }
 }
@@ -175,18 +242,37 @@ impl ApprovalProcess {
 #[allow(clippy::enum_variant_names)]
 pub enum ApprovalProcessType {
     WithdrawalApproval,
+    WithdrawalOverLimitApproval,
+    TransferApproval,
+    ManualTransactionApproval,
+    ManualTransactionReversalApproval,
     DisbursalApproval,
     CreditFacilityProposalApproval,
+    CreditFacilityAmendmentApproval,
+    CreditFacilityWriteOffApproval,
 }
 
 impl From<&DomainApprovalProcessType> for ApprovalProcessType {
     fn from(process_type: &DomainApprovalProcessType) -> Self {
         if process_type == &lana_app::governance::APPROVE_WITHDRAWAL_PROCESS {
             Self::WithdrawalApproval
+        } else if process_type == &lana_app::governance::APPROVE_WITHDRAWAL_OVER_LIMIT_PROCESS {
+            Self::WithdrawalOverLimitApproval
+        } else if process_type == &lana_app::governance::APPROVE_TRANSFER_PROCESS {
+            Self::TransferApproval
+        } else if process_type == &lana_app::governance::APPROVE_MANUAL_TRANSACTION_PROCESS {
+            Self::ManualTransactionApproval
+        } else if process_type == &lana_app::governance::APPROVE_MANUAL_TRANSACTION_REVERSAL_PROCESS
+        {
+            Self::ManualTransactionReversalApproval
         } else if process_type == &lana_app::governance::APPROVE_DISBURSAL_PROCESS {
             Self::DisbursalApproval
         } else if process_type == &lana_app::governance::APPROVE_CREDIT_FACILITY_PROPOSAL_PROCESS {
             Self::CreditFacilityProposalApproval
+        } else if process_type == &lana_app::governance::APPROVE_CREDIT_FACILITY_AMENDMENT_PROCESS {
+            Self::CreditFacilityAmendmentApproval
+        } else if process_type == &lana_app::governance::APPROVE_CREDIT_FACILITY_WRITE_OFF_PROCESS {
+            Self::CreditFacilityWriteOffApproval
         } else {
             panic!("Unknown approval process type: {process_type:?}");
         }
@@ -221,8 +307,11 @@ impl ApprovalProcessVoter {
 #[derive(async_graphql::Union)]
 pub(super) enum ApprovalProcessTarget {
     Withdrawal(Withdrawal),
+    Transfer(Transfer),
+    ManualTransaction(ManualTransaction),
     CreditFacilityProposal(CreditFacilityProposal),
     CreditFacilityDisbursal(CreditFacilityDisbursal),
+    CreditFacility(CreditFacility),
 }
 
 #[derive(InputObject)]
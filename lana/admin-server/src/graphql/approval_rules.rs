@@ -2,12 +2,19 @@ use async_graphql::*;
 
 use super::{committee::Committee, loader::LanaDataLoader};
 
-use lana_app::governance::{ApprovalRules as DomainApprovalRules, CommitteeId};
+use crate::primitives::UsdCents;
+
+use lana_app::governance::{
+    AmountBand as DomainAmountBand, ApprovalRules as DomainApprovalRules,
+    ApprovalStage as DomainApprovalStage, CommitteeId,
+};
 
 #[derive(async_graphql::Union)]
 pub(super) enum ApprovalRules {
     System(SystemApproval),
     CommitteeThreshold(CommitteeThreshold),
+    MultiStage(MultiStageApproval),
+    AmountBanded(AmountBandedApproval),
 }
 
 impl From<DomainApprovalRules> for ApprovalRules {
@@ -23,6 +30,16 @@ impl From<DomainApprovalRules> for ApprovalRules {
             DomainApprovalRules::SystemAutoApprove => {
                 ApprovalRules::System(SystemApproval { auto_approve: true })
             }
+            DomainApprovalRules::MultiStage { stages } => {
+                ApprovalRules::MultiStage(MultiStageApproval {
+                    stages: stages.into_iter().map(ApprovalStage::from).collect(),
+                })
+            }
+            DomainApprovalRules::AmountBanded { bands } => {
+                ApprovalRules::AmountBanded(AmountBandedApproval {
+                    bands: bands.into_iter().map(AmountBand::from).collect(),
+                })
+            }
         }
     }
 }
@@ -51,3 +68,59 @@ impl CommitteeThreshold {
         Ok(committee)
     }
 }
+
+#[derive(SimpleObject)]
+pub(super) struct MultiStageApproval {
+    stages: Vec<ApprovalStage>,
+}
+
+#[derive(SimpleObject)]
+pub(super) struct AmountBandedApproval {
+    bands: Vec<AmountBand>,
+}
+
+#[derive(SimpleObject)]
+pub(super) struct AmountBand {
+    min_amount: UsdCents,
+    stages: Vec<ApprovalStage>,
+}
+
+impl From<DomainAmountBand> for AmountBand {
+    fn from(band: DomainAmountBand) -> Self {
+        Self {
+            min_amount: UsdCents::from(band.min_amount),
+            stages: band.stages.into_iter().map(ApprovalStage::from).collect(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub(super) struct ApprovalStage {
+    threshold: usize,
+    deny_threshold: Option<usize>,
+    #[graphql(skip)]
+    committee_id: CommitteeId,
+}
+
+impl From<DomainApprovalStage> for ApprovalStage {
+    fn from(stage: DomainApprovalStage) -> Self {
+        Self {
+            threshold: stage.threshold,
+            deny_threshold: stage.deny_threshold,
+            committee_id: stage.committee_id,
+        }
+    }
+}
+
+#[ComplexObject]
+impl ApprovalStage {
+    async fn committee(&self, ctx: &Context<'_>) -> async_graphql::Result<Committee> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let committee = loader
+            .load_one(self.committee_id)
+            .await?
+            .expect("committee not found");
+        Ok(committee)
+    }
+}
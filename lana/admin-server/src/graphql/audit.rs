@@ -1,4 +1,6 @@
-use async_graphql::{ComplexObject, Context, ID, SimpleObject, Union, connection::CursorType};
+use async_graphql::{
+    ComplexObject, Context, Enum, ID, SimpleObject, Union, connection::CursorType,
+};
 use serde::{Deserialize, Serialize};
 
 use crate::primitives::*;
@@ -74,6 +76,94 @@ impl From<lana_app::audit::AuditEntry> for AuditEntry {
     }
 }
 
+#[derive(SimpleObject)]
+pub struct AuditChainVerification {
+    intact: bool,
+    entries_checked: i64,
+    checkpoints_checked: i64,
+    checkpoint_signatures_verified: bool,
+    last_chain_seq: Option<i64>,
+    issues: Vec<AuditChainIssue>,
+}
+
+impl From<lana_app::audit::AuditChainVerification> for AuditChainVerification {
+    fn from(verification: lana_app::audit::AuditChainVerification) -> Self {
+        Self {
+            intact: verification.is_intact(),
+            entries_checked: verification.entries_checked,
+            checkpoints_checked: verification.checkpoints_checked,
+            checkpoint_signatures_verified: verification.checkpoint_signatures_verified,
+            last_chain_seq: verification.last_chain_seq,
+            issues: verification.issues.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum AuditChainIssueKind {
+    MissingEntries,
+    ModifiedEntry,
+    BrokenLink,
+    InvalidCheckpointSignature,
+    CheckpointMismatch,
+    MissingCheckpoint,
+}
+
+#[derive(SimpleObject)]
+pub struct AuditChainIssue {
+    kind: AuditChainIssueKind,
+    from_seq: i64,
+    to_seq: i64,
+    audit_entry_id: Option<AuditEntryId>,
+}
+
+impl From<lana_app::audit::AuditChainIssue> for AuditChainIssue {
+    fn from(issue: lana_app::audit::AuditChainIssue) -> Self {
+        use lana_app::audit::AuditChainIssue::*;
+        let (kind, from_seq, to_seq, audit_entry_id) = match issue {
+            MissingEntries { from_seq, to_seq } => {
+                (AuditChainIssueKind::MissingEntries, from_seq, to_seq, None)
+            }
+            ModifiedEntry { id, chain_seq } => (
+                AuditChainIssueKind::ModifiedEntry,
+                chain_seq,
+                chain_seq,
+                Some(id.into()),
+            ),
+            BrokenLink { id, chain_seq } => (
+                AuditChainIssueKind::BrokenLink,
+                chain_seq,
+                chain_seq,
+                Some(id.into()),
+            ),
+            InvalidCheckpointSignature { chain_seq } => (
+                AuditChainIssueKind::InvalidCheckpointSignature,
+                chain_seq,
+                chain_seq,
+                None,
+            ),
+            CheckpointMismatch { chain_seq } => (
+                AuditChainIssueKind::CheckpointMismatch,
+                chain_seq,
+                chain_seq,
+                None,
+            ),
+            MissingCheckpoint { chain_seq } => (
+                AuditChainIssueKind::MissingCheckpoint,
+                chain_seq,
+                chain_seq,
+                None,
+            ),
+        };
+        Self {
+            kind,
+            from_seq,
+            to_seq,
+            audit_entry_id,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct AuditCursor {
     id: audit::AuditEntryId,
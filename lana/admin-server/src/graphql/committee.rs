@@ -5,7 +5,8 @@ use crate::primitives::*;
 use super::{access::User, loader::LanaDataLoader};
 
 pub use lana_app::governance::{
-    Committee as DomainCommittee, committee_cursor::CommitteesByCreatedAtCursor,
+    Committee as DomainCommittee, CommitteeDelegation as DomainCommitteeDelegation,
+    committee_cursor::CommitteesByCreatedAtCursor,
 };
 
 #[derive(SimpleObject, Clone)]
@@ -45,6 +46,56 @@ impl Committee {
 
         Ok(users)
     }
+
+    async fn delegations(&self) -> Vec<CommitteeDelegation> {
+        self.entity
+            .delegations()
+            .into_iter()
+            .map(CommitteeDelegation::from)
+            .collect()
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct CommitteeDelegation {
+    delegation_id: UUID,
+    starts_on: Date,
+    ends_on: Date,
+    #[graphql(skip)]
+    entity: DomainCommitteeDelegation,
+}
+
+impl From<DomainCommitteeDelegation> for CommitteeDelegation {
+    fn from(delegation: DomainCommitteeDelegation) -> Self {
+        Self {
+            delegation_id: delegation.id.into(),
+            starts_on: delegation.starts_on.into(),
+            ends_on: delegation.ends_on.into(),
+            entity: delegation,
+        }
+    }
+}
+
+#[ComplexObject]
+impl CommitteeDelegation {
+    async fn delegator(&self, ctx: &Context<'_>) -> async_graphql::Result<User> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let user = loader
+            .load_one(UserId::from(self.entity.delegator_id))
+            .await?
+            .expect("delegator not found");
+        Ok(user)
+    }
+
+    async fn delegate(&self, ctx: &Context<'_>) -> async_graphql::Result<User> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let user = loader
+            .load_one(UserId::from(self.entity.delegate_id))
+            .await?
+            .expect("delegate not found");
+        Ok(user)
+    }
 }
 
 #[derive(InputObject)]
@@ -66,3 +117,20 @@ pub struct CommitteeRemoveUserInput {
     pub user_id: UUID,
 }
 crate::mutation_payload! { CommitteeRemoveUserPayload, committee: Committee }
+
+#[derive(InputObject)]
+pub struct CommitteeDelegateVoteInput {
+    pub committee_id: UUID,
+    pub delegator_id: UUID,
+    pub delegate_id: UUID,
+    pub starts_on: Date,
+    pub ends_on: Date,
+}
+crate::mutation_payload! { CommitteeDelegateVotePayload, committee: Committee }
+
+#[derive(InputObject)]
+pub struct CommitteeRevokeDelegationInput {
+    pub committee_id: UUID,
+    pub delegation_id: UUID,
+}
+crate::mutation_payload! { CommitteeRevokeDelegationPayload, committee: Committee }
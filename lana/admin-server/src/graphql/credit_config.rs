@@ -77,6 +77,12 @@ pub struct CreditModuleConfig {
         Option<String>,
     chart_of_account_overdue_non_domiciled_company_disbursed_receivable_parent_code: Option<String>,
 
+    chart_of_account_penalty_receivable_parent_code: Option<String>,
+    chart_of_account_penalty_defaulted_parent_code: Option<String>,
+    chart_of_account_penalty_income_parent_code: Option<String>,
+    chart_of_account_loan_loss_expense_parent_code: Option<String>,
+    chart_of_account_recovery_income_parent_code: Option<String>,
+
     #[graphql(skip)]
     pub(super) _entity: Arc<DomainChartOfAccountsIntegrationConfig>,
 }
@@ -334,6 +340,27 @@ impl From<DomainChartOfAccountsIntegrationConfig> for CreditModuleConfig {
                     .to_string(),
             ),
 
+            chart_of_account_penalty_receivable_parent_code: values
+                .chart_of_account_penalty_receivable_parent_code
+                .as_ref()
+                .map(|code| code.to_string()),
+            chart_of_account_penalty_defaulted_parent_code: values
+                .chart_of_account_penalty_defaulted_parent_code
+                .as_ref()
+                .map(|code| code.to_string()),
+            chart_of_account_penalty_income_parent_code: values
+                .chart_of_account_penalty_income_parent_code
+                .as_ref()
+                .map(|code| code.to_string()),
+            chart_of_account_loan_loss_expense_parent_code: values
+                .chart_of_account_loan_loss_expense_parent_code
+                .as_ref()
+                .map(|code| code.to_string()),
+            chart_of_account_recovery_income_parent_code: values
+                .chart_of_account_recovery_income_parent_code
+                .as_ref()
+                .map(|code| code.to_string()),
+
             _entity: Arc::new(values),
         }
     }
@@ -402,5 +429,11 @@ pub struct CreditModuleConfigureInput {
     pub chart_of_account_overdue_foreign_agency_or_subsidiary_disbursed_receivable_parent_code:
         String,
     pub chart_of_account_overdue_non_domiciled_company_disbursed_receivable_parent_code: String,
+
+    pub chart_of_account_penalty_receivable_parent_code: Option<String>,
+    pub chart_of_account_penalty_defaulted_parent_code: Option<String>,
+    pub chart_of_account_penalty_income_parent_code: Option<String>,
+    pub chart_of_account_loan_loss_expense_parent_code: Option<String>,
+    pub chart_of_account_recovery_income_parent_code: Option<String>,
 }
 crate::mutation_payload! { CreditModuleConfigurePayload, credit_config: CreditModuleConfig }
@@ -91,6 +91,15 @@ impl CreditFacility {
         Ok(app.credit().current_cvl(&self.entity).await?.into())
     }
 
+    async fn cvl_as_of(&self, ctx: &Context<'_>, date: Date) -> async_graphql::Result<CVLPct> {
+        let (app, _) = crate::app_and_sub_from_ctx!(ctx);
+        Ok(app
+            .credit()
+            .cvl_as_of(&self.entity, date.into_inner())
+            .await?
+            .into())
+    }
+
     async fn history(
         &self,
         ctx: &Context<'_>,
@@ -339,6 +348,27 @@ pub struct CreditFacilityPartialPaymentWithDateRecordInput {
 }
 crate::mutation_payload! { CreditFacilityPartialPaymentRecordPayload, credit_facility: CreditFacility }
 
+#[derive(InputObject)]
+pub struct CreditFacilityPrepaymentRecordInput {
+    pub credit_facility_id: UUID,
+    pub amount: UsdCents,
+}
+crate::mutation_payload! { CreditFacilityPrepaymentRecordPayload, credit_facility: CreditFacility }
+
+#[derive(InputObject)]
+pub struct CreditFacilityAmendmentProposeInput {
+    pub credit_facility_id: UUID,
+    pub terms: TermsInput,
+    pub reschedule_overdue_obligations: bool,
+}
+crate::mutation_payload! { CreditFacilityAmendmentProposePayload, credit_facility: CreditFacility }
+
+#[derive(InputObject)]
+pub struct CreditFacilityWriteOffProposeInput {
+    pub credit_facility_id: UUID,
+}
+crate::mutation_payload! { CreditFacilityWriteOffProposePayload, credit_facility: CreditFacility }
+
 #[derive(InputObject)]
 pub struct CreditFacilityCompleteInput {
     pub credit_facility_id: UUID,
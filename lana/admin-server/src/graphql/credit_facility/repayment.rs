@@ -6,6 +6,7 @@ use crate::primitives::*;
 pub enum CreditFacilityRepaymentType {
     Disbursal,
     Interest,
+    Penalty,
 }
 
 impl From<lana_app::credit::RepaymentType> for CreditFacilityRepaymentType {
@@ -13,6 +14,7 @@ impl From<lana_app::credit::RepaymentType> for CreditFacilityRepaymentType {
         match repayment_type {
             lana_app::credit::RepaymentType::Disbursal => Self::Disbursal,
             lana_app::credit::RepaymentType::Interest => Self::Interest,
+            lana_app::credit::RepaymentType::Penalty => Self::Penalty,
         }
     }
 }
@@ -25,6 +27,8 @@ pub enum CreditFacilityRepaymentStatus {
     Overdue,
     Defaulted,
     Paid,
+    Rescheduled,
+    WrittenOff,
 }
 
 impl From<lana_app::credit::RepaymentStatus> for CreditFacilityRepaymentStatus {
@@ -40,6 +44,12 @@ impl From<lana_app::credit::RepaymentStatus> for CreditFacilityRepaymentStatus {
                 CreditFacilityRepaymentStatus::Defaulted
             }
             lana_app::credit::RepaymentStatus::Upcoming => CreditFacilityRepaymentStatus::Upcoming,
+            lana_app::credit::RepaymentStatus::Rescheduled => {
+                CreditFacilityRepaymentStatus::Rescheduled
+            }
+            lana_app::credit::RepaymentStatus::WrittenOff => {
+                CreditFacilityRepaymentStatus::WrittenOff
+            }
         }
     }
 }
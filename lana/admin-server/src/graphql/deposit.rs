@@ -1,6 +1,9 @@
 use async_graphql::*;
 
-use crate::{graphql::accounting::LedgerTransaction, primitives::*};
+use crate::{
+    graphql::{accounting::LedgerTransaction, primitives::Decimal},
+    primitives::*,
+};
 
 use super::loader::LanaDataLoader;
 
@@ -108,8 +111,29 @@ pub struct DepositAccountUnfreezeInput {
 }
 crate::mutation_payload! { DepositAccountUnfreezePayload, account: DepositAccount }
 
+#[derive(InputObject)]
+pub struct DepositAccountReactivateInput {
+    pub deposit_account_id: UUID,
+}
+crate::mutation_payload! { DepositAccountReactivatePayload, account: DepositAccount }
+
 #[derive(InputObject)]
 pub struct DepositAccountCloseInput {
     pub deposit_account_id: UUID,
 }
 crate::mutation_payload! { DepositAccountClosePayload, account: DepositAccount }
+
+#[derive(InputObject)]
+pub struct DepositAccountUpdateInterestTermsInput {
+    pub deposit_account_id: UUID,
+    pub annual_rate: Decimal,
+    pub maturity_date: Option<Date>,
+    pub early_break_penalty_rate: Option<Decimal>,
+}
+crate::mutation_payload! { DepositAccountUpdateInterestTermsPayload, account: DepositAccount }
+
+#[derive(InputObject)]
+pub struct DepositAccountBreakTermInput {
+    pub deposit_account_id: UUID,
+}
+crate::mutation_payload! { DepositAccountBreakTermPayload, account: DepositAccount }
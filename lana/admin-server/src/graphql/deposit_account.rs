@@ -1,15 +1,16 @@
 use async_graphql::{connection::*, *};
 
-use crate::primitives::*;
+use crate::{graphql::primitives::Decimal, primitives::*};
 
 pub use lana_app::deposit::{
     DepositAccount as DomainDepositAccount, DepositAccountHistoryCursor,
-    DepositAccountHistoryEntry as DomainDepositAccountHistoryEntry, DepositAccountStatus,
+    DepositAccountHistoryEntry as DomainDepositAccountHistoryEntry,
+    DepositAccountInterestTerms as DomainDepositAccountInterestTerms, DepositAccountStatus,
 };
 
 use super::{
-    accounting::LedgerAccount, customer::Customer, deposit::*, deposit_account_history::*,
-    loader::LanaDataLoader, withdrawal::*,
+    accounting::LedgerAccount, customer::Customer, deposit::*, deposit_account_history::*, hold::*,
+    loader::LanaDataLoader, transfer::*, withdrawal::*,
 };
 
 #[derive(SimpleObject, Clone)]
@@ -43,6 +44,8 @@ impl From<DomainDepositAccount> for DepositAccount {
 pub struct DepositAccountBalance {
     settled: UsdCents,
     pending: UsdCents,
+    held: UsdCents,
+    available: UsdCents,
 }
 
 impl From<lana_app::deposit::DepositAccountBalance> for DepositAccountBalance {
@@ -50,6 +53,25 @@ impl From<lana_app::deposit::DepositAccountBalance> for DepositAccountBalance {
         Self {
             settled: balance.settled,
             pending: balance.pending,
+            held: balance.held,
+            available: balance.available(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct DepositAccountInterestTerms {
+    annual_rate: Decimal,
+    maturity_date: Option<Date>,
+    early_break_penalty_rate: Option<Decimal>,
+}
+
+impl From<DomainDepositAccountInterestTerms> for DepositAccountInterestTerms {
+    fn from(terms: DomainDepositAccountInterestTerms) -> Self {
+        Self {
+            annual_rate: terms.annual_rate.into(),
+            maturity_date: terms.maturity_date.map(Into::into),
+            early_break_penalty_rate: terms.early_break_penalty_rate.map(Into::into),
         }
     }
 }
@@ -88,6 +110,16 @@ impl DepositAccount {
         &self.entity.public_id
     }
 
+    async fn interest_terms(&self) -> Option<DepositAccountInterestTerms> {
+        self.entity
+            .interest_terms
+            .map(DepositAccountInterestTerms::from)
+    }
+
+    async fn accrued_interest(&self) -> UsdCents {
+        self.entity.accrued_interest()
+    }
+
     async fn deposits(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Deposit>> {
         let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
         let deposits = app
@@ -106,6 +138,36 @@ impl DepositAccount {
         Ok(withdrawals.into_iter().map(Withdrawal::from).collect())
     }
 
+    async fn transfers(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<TransfersByCreatedAtCursor, Transfer, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        crate::list_with_cursor!(
+            TransfersByCreatedAtCursor,
+            Transfer,
+            ctx,
+            after,
+            first,
+            |query| app
+                .deposits()
+                .list_transfers_for_account(sub, self.entity.id, query)
+        )
+    }
+
+    async fn holds(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Hold>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let holds = app
+            .deposits()
+            .list_holds_for_account(sub, self.entity.id)
+            .await?;
+        Ok(holds.into_iter().map(Hold::from).collect())
+    }
+
     async fn history(
         &self,
         ctx: &Context<'_>,
@@ -7,6 +7,7 @@ use super::{
         disbursal::CreditFacilityDisbursal, payment_allocation::CreditFacilityPaymentAllocation,
     },
     deposit::Deposit,
+    transfer::Transfer,
     withdrawal::Withdrawal,
 };
 
@@ -15,6 +16,15 @@ pub enum DepositAccountHistoryEntry {
     Deposit(DepositEntry),
     Withdrawal(WithdrawalEntry),
     CancelledWithdrawal(CancelledWithdrawalEntry),
+    TransferOut(TransferOutEntry),
+    TransferIn(TransferInEntry),
+    CancelledTransfer(CancelledTransferEntry),
+    InterestCapitalized(InterestCapitalizedEntry),
+    EarlyBreakPenalty(EarlyBreakPenaltyEntry),
+    Escheatment(EscheatmentEntry),
+    HoldPlaced(HoldPlacedEntry),
+    HoldReleased(HoldReleasedEntry),
+    HoldCaptured(HoldCapturedEntry),
     Disbursal(DisbursalEntry),
     Payment(PaymentEntry),
     Freeze(FreezeEntry),
@@ -45,6 +55,75 @@ pub struct CancelledWithdrawalEntry {
     pub recorded_at: Timestamp,
 }
 
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct TransferOutEntry {
+    #[graphql(skip)]
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+    pub amount: UsdCents,
+}
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct TransferInEntry {
+    #[graphql(skip)]
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+    pub amount: UsdCents,
+}
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct CancelledTransferEntry {
+    #[graphql(skip)]
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+    pub amount: UsdCents,
+}
+
+#[derive(SimpleObject)]
+pub struct InterestCapitalizedEntry {
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+    pub amount: UsdCents,
+}
+
+#[derive(SimpleObject)]
+pub struct EarlyBreakPenaltyEntry {
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+    pub amount: UsdCents,
+}
+
+#[derive(SimpleObject)]
+pub struct EscheatmentEntry {
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+    pub amount: UsdCents,
+}
+
+#[derive(SimpleObject)]
+pub struct HoldPlacedEntry {
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+    pub amount: UsdCents,
+}
+
+#[derive(SimpleObject)]
+pub struct HoldReleasedEntry {
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+    pub amount: UsdCents,
+}
+
+#[derive(SimpleObject)]
+pub struct HoldCapturedEntry {
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+    pub amount: UsdCents,
+}
+
 #[derive(SimpleObject)]
 #[graphql(complex)]
 pub struct DisbursalEntry {
@@ -124,6 +203,49 @@ impl CancelledWithdrawalEntry {
         Ok(Withdrawal::from(withdrawal))
     }
 }
+#[ComplexObject]
+impl TransferOutEntry {
+    async fn transfer(&self, ctx: &Context<'_>) -> async_graphql::Result<Transfer> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+
+        let transfer = app
+            .deposits()
+            .find_transfer_by_id(sub, self.tx_id)
+            .await?
+            .expect("transfer should exist");
+
+        Ok(Transfer::from(transfer))
+    }
+}
+
+#[ComplexObject]
+impl TransferInEntry {
+    async fn transfer(&self, ctx: &Context<'_>) -> async_graphql::Result<Transfer> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+
+        let transfer = app
+            .deposits()
+            .find_transfer_by_completed_tx_id(sub, self.tx_id)
+            .await?;
+
+        Ok(Transfer::from(transfer))
+    }
+}
+
+#[ComplexObject]
+impl CancelledTransferEntry {
+    async fn transfer(&self, ctx: &Context<'_>) -> async_graphql::Result<Transfer> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+
+        let transfer = app
+            .deposits()
+            .find_transfer_by_denied_tx_id(sub, self.tx_id)
+            .await?;
+
+        Ok(Transfer::from(transfer))
+    }
+}
+
 #[ComplexObject]
 impl DisbursalEntry {
     async fn disbursal(&self, ctx: &Context<'_>) -> async_graphql::Result<CreditFacilityDisbursal> {
@@ -179,6 +301,69 @@ impl From<lana_app::deposit::DepositAccountHistoryEntry> for DepositAccountHisto
                     recorded_at: entry.recorded_at.into(),
                 })
             }
+            lana_app::deposit::DepositAccountHistoryEntry::TransferOut(entry) => {
+                Self::TransferOut(TransferOutEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                    amount: entry.amount,
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::TransferIn(entry) => {
+                Self::TransferIn(TransferInEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                    amount: entry.amount,
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::CancelledTransfer(entry) => {
+                Self::CancelledTransfer(CancelledTransferEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                    amount: entry.amount,
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::InterestCapitalized(entry) => {
+                Self::InterestCapitalized(InterestCapitalizedEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                    amount: entry.amount,
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::EarlyBreakPenalty(entry) => {
+                Self::EarlyBreakPenalty(EarlyBreakPenaltyEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                    amount: entry.amount,
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::Escheatment(entry) => {
+                Self::Escheatment(EscheatmentEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                    amount: entry.amount,
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::HoldPlaced(entry) => {
+                Self::HoldPlaced(HoldPlacedEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                    amount: entry.amount,
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::HoldReleased(entry) => {
+                Self::HoldReleased(HoldReleasedEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                    amount: entry.amount,
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::HoldCaptured(entry) => {
+                Self::HoldCaptured(HoldCapturedEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                    amount: entry.amount,
+                })
+            }
             lana_app::deposit::DepositAccountHistoryEntry::Disbursal(entry) => {
                 Self::Disbursal(DisbursalEntry {
                     tx_id: UUID::from(entry.tx_id),
@@ -20,6 +20,8 @@ pub struct DepositModuleConfig {
     chart_of_account_frozen_bank_deposit_accounts_parent_code: Option<String>,
     chart_of_account_frozen_financial_institution_deposit_accounts_parent_code: Option<String>,
     chart_of_account_frozen_non_domiciled_company_deposit_accounts_parent_code: Option<String>,
+    chart_of_accounts_interest_expense_parent_code: Option<String>,
+    chart_of_accounts_unclaimed_funds_parent_code: Option<String>,
 
     #[graphql(skip)]
     pub(super) _entity: Arc<DomainChartOfAccountsIntegrationConfig>,
@@ -92,6 +94,14 @@ impl From<DomainChartOfAccountsIntegrationConfig> for DepositModuleConfig {
                     .chart_of_account_frozen_non_domiciled_company_deposit_accounts_parent_code
                     .to_string(),
             ),
+            chart_of_accounts_interest_expense_parent_code: values
+                .chart_of_accounts_interest_expense_parent_code
+                .as_ref()
+                .map(|code| code.to_string()),
+            chart_of_accounts_unclaimed_funds_parent_code: values
+                .chart_of_accounts_unclaimed_funds_parent_code
+                .as_ref()
+                .map(|code| code.to_string()),
 
             _entity: Arc::new(values),
         }
@@ -113,5 +123,7 @@ pub struct DepositModuleConfigureInput {
     pub chart_of_account_frozen_bank_deposit_accounts_parent_code: String,
     pub chart_of_account_frozen_financial_institution_deposit_accounts_parent_code: String,
     pub chart_of_account_frozen_non_domiciled_company_deposit_accounts_parent_code: String,
+    pub chart_of_accounts_interest_expense_parent_code: Option<String>,
+    pub chart_of_accounts_unclaimed_funds_parent_code: Option<String>,
 }
 crate::mutation_payload! { DepositModuleConfigurePayload, deposit_config: DepositModuleConfig }
@@ -12,6 +12,8 @@ use lana_app::{
         csv::{AccountingCsvDocumentId, error::AccountingCsvExportError},
         fiscal_year::error::FiscalYearError,
         ledger_transaction::error::LedgerTransactionError,
+        manual_transaction::error::ManualTransactionError,
+        recurring_journal_entry::error::RecurringJournalEntryError,
         transaction_templates::error::TransactionTemplateError,
     },
     app::LanaApp,
@@ -26,8 +28,8 @@ use crate::primitives::*;
 
 use super::{
     access::*, accounting::*, approval_process::*, committee::*, credit_facility::*, custody::*,
-    customer::*, deposit::*, deposit_account::*, document::*, domain_config::*, policy::*,
-    prospect::*, reports::*, terms_template::*, withdrawal::*,
+    customer::*, deposit::*, deposit_account::*, document::*, domain_config::*, hold::*, policy::*,
+    prospect::*, reports::*, terms_template::*, transfer::*, withdrawal::*,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -281,6 +283,37 @@ impl Loader<ChartId> for LanaLoader {
     }
 }
 
+impl Loader<HoldId> for LanaLoader {
+    type Value = Hold;
+    type Error = Arc<CoreDepositError>;
+
+    #[instrument(name = "loader.holds", skip(self), fields(count = keys.len()), err)]
+    async fn load(&self, keys: &[HoldId]) -> Result<HashMap<HoldId, Hold>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_holds(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<TransferId> for LanaLoader {
+    type Value = Transfer;
+    type Error = Arc<CoreDepositError>;
+
+    #[instrument(name = "loader.transfers", skip(self), fields(count = keys.len()), err)]
+    async fn load(
+        &self,
+        keys: &[TransferId],
+    ) -> Result<HashMap<TransferId, Transfer>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_transfers(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
 impl Loader<WithdrawalId> for LanaLoader {
     type Value = Withdrawal;
     type Error = Arc<CoreDepositError>;
@@ -347,6 +380,42 @@ impl Loader<LedgerTransactionId> for LanaLoader {
     }
 }
 
+impl Loader<ManualTransactionId> for LanaLoader {
+    type Value = ManualTransaction;
+    type Error = Arc<ManualTransactionError>;
+
+    #[instrument(name = "loader.manual_transactions", skip(self), fields(count = keys.len()), err)]
+    async fn load(
+        &self,
+        keys: &[ManualTransactionId],
+    ) -> Result<HashMap<ManualTransactionId, Self::Value>, Self::Error> {
+        self.app
+            .accounting()
+            .manual_transactions()
+            .find_all(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<RecurringJournalEntryId> for LanaLoader {
+    type Value = RecurringJournalEntry;
+    type Error = Arc<RecurringJournalEntryError>;
+
+    #[instrument(name = "loader.recurring_journal_entries", skip(self), fields(count = keys.len()), err)]
+    async fn load(
+        &self,
+        keys: &[RecurringJournalEntryId],
+    ) -> Result<HashMap<RecurringJournalEntryId, Self::Value>, Self::Error> {
+        self.app
+            .accounting()
+            .recurring_journal_entries()
+            .find_all(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
 impl Loader<TransactionTemplateId> for LanaLoader {
     type Value = TransactionTemplate;
     type Error = Arc<TransactionTemplateError>;
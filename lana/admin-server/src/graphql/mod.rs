@@ -15,6 +15,7 @@ mod deposit_account_history;
 mod deposit_config;
 mod document;
 mod domain_config;
+mod hold;
 mod loader;
 mod me;
 mod price;
@@ -25,6 +26,7 @@ mod reports;
 mod sumsub;
 mod terms;
 mod terms_template;
+mod transfer;
 mod withdrawal;
 #[macro_use]
 pub(crate) mod macros;
@@ -2,9 +2,14 @@ use async_graphql::*;
 
 use crate::primitives::*;
 
-use super::{approval_process::*, approval_rules::*};
+use super::{approval_process::*, approval_rules::*, committee::Committee, loader::LanaDataLoader};
 
-pub use lana_app::governance::{Policy as DomainPolicy, policy_cursor::PoliciesByCreatedAtCursor};
+pub use lana_app::governance::{
+    AmountBand as DomainAmountBand, ApprovalRules as DomainApprovalRules,
+    ApprovalStage as DomainApprovalStage, ExpiryAction as DomainExpiryAction,
+    Policy as DomainPolicy, VotingDeadline as DomainVotingDeadline,
+    policy_cursor::PoliciesByCreatedAtCursor,
+};
 
 #[derive(SimpleObject, Clone)]
 #[graphql(complex)]
@@ -31,7 +36,71 @@ impl From<DomainPolicy> for Policy {
 #[ComplexObject]
 impl Policy {
     async fn rules(&self) -> ApprovalRules {
-        ApprovalRules::from(self.entity.rules)
+        ApprovalRules::from(self.entity.rules.clone())
+    }
+
+    async fn voting_deadline(&self) -> Option<VotingDeadline> {
+        self.entity.voting_deadline.map(VotingDeadline::from)
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum VotingDeadlineExpiryAction {
+    AutoDeny,
+    AutoApprove,
+    Escalate,
+}
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct VotingDeadline {
+    voting_period_days: u32,
+    on_expiry: VotingDeadlineExpiryAction,
+    escalation_threshold: Option<usize>,
+    #[graphql(skip)]
+    escalation_committee_id: Option<CommitteeId>,
+}
+
+impl From<DomainVotingDeadline> for VotingDeadline {
+    fn from(deadline: DomainVotingDeadline) -> Self {
+        let (on_expiry, escalation_committee_id, escalation_threshold) = match deadline.on_expiry {
+            DomainExpiryAction::AutoDeny => (VotingDeadlineExpiryAction::AutoDeny, None, None),
+            DomainExpiryAction::AutoApprove => {
+                (VotingDeadlineExpiryAction::AutoApprove, None, None)
+            }
+            DomainExpiryAction::Escalate {
+                committee_id,
+                threshold,
+            } => (
+                VotingDeadlineExpiryAction::Escalate,
+                Some(committee_id),
+                Some(threshold),
+            ),
+        };
+        Self {
+            voting_period_days: deadline.voting_period_days,
+            on_expiry,
+            escalation_threshold,
+            escalation_committee_id,
+        }
+    }
+}
+
+#[ComplexObject]
+impl VotingDeadline {
+    async fn escalation_committee(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<Committee>> {
+        let Some(committee_id) = self.escalation_committee_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let committee = loader
+            .load_one(committee_id)
+            .await?
+            .expect("committee not found");
+        Ok(Some(committee))
     }
 }
 
@@ -43,3 +112,95 @@ pub struct PolicyAssignCommitteeInput {
 }
 
 mutation_payload! { PolicyAssignCommitteePayload, policy: Policy }
+
+#[derive(InputObject)]
+pub struct PolicyApprovalStageInput {
+    pub committee_id: UUID,
+    pub threshold: usize,
+    pub deny_threshold: Option<usize>,
+}
+
+impl From<PolicyApprovalStageInput> for DomainApprovalStage {
+    fn from(input: PolicyApprovalStageInput) -> Self {
+        Self {
+            committee_id: input.committee_id.into(),
+            threshold: input.threshold,
+            deny_threshold: input.deny_threshold,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct PolicyAssignStagesInput {
+    pub policy_id: UUID,
+    pub stages: Vec<PolicyApprovalStageInput>,
+}
+
+mutation_payload! { PolicyAssignStagesPayload, policy: Policy }
+
+#[derive(InputObject)]
+pub struct PolicyAmountBandInput {
+    pub min_amount: UsdCents,
+    pub stages: Vec<PolicyApprovalStageInput>,
+}
+
+#[derive(InputObject)]
+pub struct PolicyAssignAmountBandsInput {
+    pub policy_id: UUID,
+    pub bands: Vec<PolicyAmountBandInput>,
+}
+
+mutation_payload! { PolicyAssignAmountBandsPayload, policy: Policy }
+
+#[derive(InputObject)]
+pub struct PolicyVotingDeadlineInput {
+    pub voting_period_days: u32,
+    pub on_expiry: VotingDeadlineExpiryAction,
+    pub escalation_committee_id: Option<UUID>,
+    pub escalation_threshold: Option<usize>,
+}
+
+impl TryFrom<PolicyVotingDeadlineInput> for DomainVotingDeadline {
+    type Error = Error;
+
+    fn try_from(input: PolicyVotingDeadlineInput) -> Result<Self, Self::Error> {
+        let on_expiry = match (
+            input.on_expiry,
+            input.escalation_committee_id,
+            input.escalation_threshold,
+        ) {
+            (VotingDeadlineExpiryAction::AutoDeny, None, None) => DomainExpiryAction::AutoDeny,
+            (VotingDeadlineExpiryAction::AutoApprove, None, None) => {
+                DomainExpiryAction::AutoApprove
+            }
+            (VotingDeadlineExpiryAction::Escalate, Some(committee_id), Some(threshold)) => {
+                DomainExpiryAction::Escalate {
+                    committee_id: committee_id.into(),
+                    threshold,
+                }
+            }
+            (VotingDeadlineExpiryAction::Escalate, _, _) => {
+                return Err(Error::new(
+                    "Escalation requires both a committee and a threshold",
+                ));
+            }
+            _ => {
+                return Err(Error::new(
+                    "Escalation committee and threshold are only allowed when escalating",
+                ));
+            }
+        };
+        Ok(Self {
+            voting_period_days: input.voting_period_days,
+            on_expiry,
+        })
+    }
+}
+
+#[derive(InputObject)]
+pub struct PolicyVotingDeadlineUpdateInput {
+    pub policy_id: UUID,
+    pub voting_deadline: Option<PolicyVotingDeadlineInput>,
+}
+
+mutation_payload! { PolicyVotingDeadlineUpdatePayload, policy: Policy }
@@ -24,8 +24,9 @@ use crate::primitives::*;
 use super::{
     access::*, accounting::*, approval_process::*, audit::*, committee::*, contract_creation::*,
     credit_config::*, credit_facility::*, custody::*, customer::*, dashboard::*, deposit::*,
-    deposit_config::*, document::*, domain_config::*, loader::*, me::*, policy::*, price::*,
-    prospect::*, public_id::*, reports::*, sumsub::*, terms_template::*, withdrawal::*,
+    deposit_config::*, document::*, domain_config::*, hold::*, loader::*, me::*, policy::*,
+    price::*, prospect::*, public_id::*, reports::*, sumsub::*, terms_template::*, transfer::*,
+    withdrawal::*,
 };
 
 pub struct Query;
@@ -256,6 +257,52 @@ impl Query {
         )
     }
 
+    async fn hold(&self, ctx: &Context<'_>, id: UUID) -> async_graphql::Result<Option<Hold>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(Hold, ctx, app.deposits().find_hold_by_id(sub, id))
+    }
+
+    async fn transfer(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<Transfer>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(Transfer, ctx, app.deposits().find_transfer_by_id(sub, id))
+    }
+
+    async fn transfer_by_public_id(
+        &self,
+        ctx: &Context<'_>,
+        id: PublicId,
+    ) -> async_graphql::Result<Option<Transfer>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            Transfer,
+            ctx,
+            app.deposits().find_transfer_by_public_id(sub, id)
+        )
+    }
+
+    async fn transfers(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<TransfersByCreatedAtCursor, Transfer, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            TransfersByCreatedAtCursor,
+            Transfer,
+            ctx,
+            after,
+            first,
+            |query| app.deposits().list_transfers(sub, query)
+        )
+    }
+
     async fn deposit(&self, ctx: &Context<'_>, id: UUID) -> async_graphql::Result<Option<Deposit>> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
         maybe_fetch_one!(Deposit, ctx, app.deposits().find_deposit_by_id(sub, id))
@@ -761,6 +808,63 @@ impl Query {
         )
     }
 
+    async fn manual_transaction(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<ManualTransaction>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            ManualTransaction,
+            ctx,
+            app.accounting()
+                .manual_transactions()
+                .find_manual_transaction_by_id(sub, id)
+        )
+    }
+
+    async fn recurring_journal_entry(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<RecurringJournalEntry>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            RecurringJournalEntry,
+            ctx,
+            app.accounting()
+                .recurring_journal_entries()
+                .find_by_id(sub, id)
+        )
+    }
+
+    async fn recurring_journal_entries(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<
+            RecurringJournalEntriesByCreatedAtCursor,
+            RecurringJournalEntry,
+            EmptyFields,
+            EmptyFields,
+        >,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            RecurringJournalEntriesByCreatedAtCursor,
+            RecurringJournalEntry,
+            ctx,
+            after,
+            first,
+            |query| app
+                .accounting()
+                .recurring_journal_entries()
+                .list(sub, query)
+        )
+    }
+
     async fn ledger_transactions_for_template_code(
         &self,
         ctx: &Context<'_>,
@@ -951,6 +1055,26 @@ impl Query {
         Ok(ProfitAndLossStatement::from(profit_and_loss))
     }
 
+    async fn cash_flow_statement(
+        &self,
+        ctx: &Context<'_>,
+        from: Date,
+        until: Option<Date>,
+    ) -> async_graphql::Result<CashFlowStatement> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let cash_flow = app
+            .accounting()
+            .cash_flow_statements()
+            .cash_flow_statement(
+                sub,
+                CHART_REF.0,
+                from.into_inner(),
+                until.map(|t| t.into_inner()),
+            )
+            .await?;
+        Ok(CashFlowStatement::from(cash_flow))
+    }
+
     async fn realtime_price(&self, ctx: &Context<'_>) -> async_graphql::Result<RealtimePrice> {
         let app = ctx.data_unchecked::<LanaApp>();
         let usd_cents_per_btc = app.price().usd_cents_per_btc().await;
@@ -1020,6 +1144,14 @@ impl Query {
             .collect())
     }
 
+    async fn audit_chain_verification(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<AuditChainVerification> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        Ok(app.verify_audit_chain(sub).await?.into())
+    }
+
     async fn deposit_config(
         &self,
         ctx: &Context<'_>,
@@ -1033,6 +1165,14 @@ impl Query {
         Ok(config.map(DepositModuleConfig::from))
     }
 
+    async fn withdrawal_limits_config(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<WithdrawalLimitsConfig> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        Ok(app.deposits().withdrawal_limits_config(sub).await?.into())
+    }
+
     async fn domain_configs(
         &self,
         ctx: &Context<'_>,
@@ -1092,6 +1232,10 @@ impl Query {
                 .withdrawal(ctx, public_id.target_id.into())
                 .await?
                 .map(PublicIdTarget::Withdrawal),
+            "transfer" => self
+                .transfer(ctx, public_id.target_id.into())
+                .await?
+                .map(PublicIdTarget::Transfer),
             "credit_facility" => self
                 .credit_facility(ctx, public_id.target_id.into())
                 .await?
@@ -1445,6 +1589,8 @@ impl Mutation {
             chart_of_account_frozen_bank_deposit_accounts_parent_code,
             chart_of_account_frozen_financial_institution_deposit_accounts_parent_code,
             chart_of_account_frozen_non_domiciled_company_deposit_accounts_parent_code,
+            chart_of_accounts_interest_expense_parent_code,
+            chart_of_accounts_unclaimed_funds_parent_code,
         } = input;
 
         let config_values = lana_app::deposit::ChartOfAccountsIntegrationConfig {
@@ -1476,13 +1622,22 @@ impl Mutation {
                 chart_of_account_frozen_non_domiciled_company_deposit_accounts_parent_code
                     .parse()?,
             chart_of_accounts_omnibus_parent_code: chart_of_accounts_omnibus_parent_code.parse()?,
+            chart_of_accounts_interest_expense_parent_code:
+                chart_of_accounts_interest_expense_parent_code
+                    .map(|code| code.parse())
+                    .transpose()?,
+            chart_of_accounts_unclaimed_funds_parent_code:
+                chart_of_accounts_unclaimed_funds_parent_code
+                    .map(|code| code.parse())
+                    .transpose()?,
         };
 
-        let config = app
-            .deposits()
-            .chart_of_accounts_integrations()
-            .set_config(sub, chart.as_ref(), config_values)
-            .await?;
+        let config = Box::pin(app.deposits().chart_of_accounts_integrations().set_config(
+            sub,
+            chart.as_ref(),
+            config_values,
+        ))
+        .await?;
         Ok(DepositModuleConfigurePayload::from(
             DepositModuleConfig::from(config),
         ))
@@ -1502,19 +1657,110 @@ impl Mutation {
 
         exec_mutation!(
             ManualTransactionExecutePayload,
-            LedgerTransaction,
+            ManualTransaction,
             ctx,
-            app.accounting().execute_manual_transaction(
+            Box::pin(app.accounting().execute_manual_transaction(
                 sub,
                 CHART_REF.0,
                 input.reference,
                 input.description,
                 input.effective.map(|ts| ts.into_inner()),
                 entries
+            ))
+        )
+    }
+
+    pub async fn manual_transaction_reverse(
+        &self,
+        ctx: &Context<'_>,
+        input: ManualTransactionReverseInput,
+    ) -> async_graphql::Result<ManualTransactionReversePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let effective = ReversalEffective::try_from(&input)?;
+
+        exec_mutation!(
+            ManualTransactionReversePayload,
+            ManualTransaction,
+            ctx,
+            Box::pin(app.accounting().reverse_manual_transaction(
+                sub,
+                CHART_REF.0,
+                input.manual_transaction_id,
+                effective
+            ))
+        )
+    }
+
+    pub async fn recurring_journal_entry_create(
+        &self,
+        ctx: &Context<'_>,
+        input: RecurringJournalEntryCreateInput,
+    ) -> async_graphql::Result<RecurringJournalEntryCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            RecurringJournalEntryCreatePayload,
+            RecurringJournalEntry,
+            ctx,
+            app.accounting().recurring_journal_entries().create(
+                sub,
+                CHART_REF.0,
+                input.try_into()?
             )
         )
     }
 
+    pub async fn recurring_journal_entry_pause(
+        &self,
+        ctx: &Context<'_>,
+        input: RecurringJournalEntryPauseInput,
+    ) -> async_graphql::Result<RecurringJournalEntryPausePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            RecurringJournalEntryPausePayload,
+            RecurringJournalEntry,
+            ctx,
+            app.accounting()
+                .recurring_journal_entries()
+                .pause(sub, input.recurring_journal_entry_id)
+        )
+    }
+
+    pub async fn recurring_journal_entry_resume(
+        &self,
+        ctx: &Context<'_>,
+        input: RecurringJournalEntryResumeInput,
+    ) -> async_graphql::Result<RecurringJournalEntryResumePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            RecurringJournalEntryResumePayload,
+            RecurringJournalEntry,
+            ctx,
+            app.accounting()
+                .recurring_journal_entries()
+                .resume(sub, input.recurring_journal_entry_id)
+        )
+    }
+
+    pub async fn recurring_journal_entry_skip_occurrence(
+        &self,
+        ctx: &Context<'_>,
+        input: RecurringJournalEntrySkipOccurrenceInput,
+    ) -> async_graphql::Result<RecurringJournalEntrySkipOccurrencePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            RecurringJournalEntrySkipOccurrencePayload,
+            RecurringJournalEntry,
+            ctx,
+            app.accounting()
+                .recurring_journal_entries()
+                .skip_occurrence(
+                    sub,
+                    input.recurring_journal_entry_id,
+                    input.date.into_inner()
+                )
+        )
+    }
+
     pub async fn deposit_record(
         &self,
         ctx: &Context<'_>,
@@ -1554,6 +1800,84 @@ impl Mutation {
         )
     }
 
+    async fn withdrawal_limits_config_update(
+        &self,
+        ctx: &Context<'_>,
+        input: WithdrawalLimitsConfigUpdateInput,
+    ) -> async_graphql::Result<WithdrawalLimitsConfigUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let config = app
+            .deposits()
+            .update_withdrawal_limits_config(sub, input.into())
+            .await?;
+        Ok(WithdrawalLimitsConfigUpdatePayload::from(
+            WithdrawalLimitsConfig::from(config),
+        ))
+    }
+
+    pub async fn transfer_initiate(
+        &self,
+        ctx: &Context<'_>,
+        input: TransferInitiateInput,
+    ) -> async_graphql::Result<TransferInitiatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            TransferInitiatePayload,
+            Transfer,
+            ctx,
+            app.deposits().initiate_transfer(
+                sub,
+                input.source_account_id,
+                input.destination_account_id,
+                input.amount,
+                input.reference
+            )
+        )
+    }
+
+    pub async fn hold_place(
+        &self,
+        ctx: &Context<'_>,
+        input: HoldPlaceInput,
+    ) -> async_graphql::Result<HoldPlacePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            HoldPlacePayload,
+            Hold,
+            ctx,
+            app.deposits()
+                .place_hold(sub, input.deposit_account_id, input.amount, input.reason)
+        )
+    }
+
+    pub async fn hold_release(
+        &self,
+        ctx: &Context<'_>,
+        input: HoldReleaseInput,
+    ) -> async_graphql::Result<HoldReleasePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            HoldReleasePayload,
+            Hold,
+            ctx,
+            app.deposits().release_hold(sub, input.hold_id)
+        )
+    }
+
+    pub async fn hold_capture(
+        &self,
+        ctx: &Context<'_>,
+        input: HoldCaptureInput,
+    ) -> async_graphql::Result<HoldCapturePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            HoldCapturePayload,
+            Hold,
+            ctx,
+            app.deposits().capture_hold(sub, input.hold_id)
+        )
+    }
+
     pub async fn withdrawal_confirm(
         &self,
         ctx: &Context<'_>,
@@ -1655,6 +1979,21 @@ impl Mutation {
         )
     }
 
+    pub async fn deposit_account_reactivate(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountReactivateInput,
+    ) -> async_graphql::Result<DepositAccountReactivatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositAccountReactivatePayload,
+            DepositAccount,
+            ctx,
+            app.deposits()
+                .reactivate_account(sub, input.deposit_account_id)
+        )
+    }
+
     pub async fn deposit_account_close(
         &self,
         ctx: &Context<'_>,
@@ -1669,6 +2008,47 @@ impl Mutation {
         )
     }
 
+    pub async fn deposit_account_update_interest_terms(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountUpdateInterestTermsInput,
+    ) -> async_graphql::Result<DepositAccountUpdateInterestTermsPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let DepositAccountUpdateInterestTermsInput {
+            deposit_account_id,
+            annual_rate,
+            maturity_date,
+            early_break_penalty_rate,
+        } = input;
+        let terms = lana_app::deposit::DepositAccountInterestTerms {
+            annual_rate: annual_rate.into(),
+            maturity_date: maturity_date.map(Into::into),
+            early_break_penalty_rate: early_break_penalty_rate.map(Into::into),
+        };
+        exec_mutation!(
+            DepositAccountUpdateInterestTermsPayload,
+            DepositAccount,
+            ctx,
+            app.deposits()
+                .update_account_interest_terms(sub, deposit_account_id, terms)
+        )
+    }
+
+    pub async fn deposit_account_break_term(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountBreakTermInput,
+    ) -> async_graphql::Result<DepositAccountBreakTermPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositAccountBreakTermPayload,
+            DepositAccount,
+            ctx,
+            app.deposits()
+                .break_term_deposit(sub, input.deposit_account_id)
+        )
+    }
+
     async fn terms_template_create(
         &self,
         ctx: &Context<'_>,
@@ -1681,6 +2061,12 @@ impl Mutation {
             .accrual_cycle_interval(input.accrual_cycle_interval)
             .one_time_fee_rate(input.one_time_fee_rate)
             .disbursal_policy(input.disbursal_policy)
+            .day_count_convention(input.day_count_convention)
+            .penalty_rate(input.penalty_rate)
+            .late_fee(input.late_fee)
+            .penalty_allocation(input.penalty_allocation)
+            .allocation_strategy(input.allocation_strategy)
+            .prepayment_fee_rate(input.prepayment_fee_rate)
             .duration(input.duration)
             .interest_due_duration_from_accrual(input.interest_due_duration_from_accrual)
             .obligation_overdue_duration_from_due(input.obligation_overdue_duration_from_due)
@@ -1714,6 +2100,12 @@ impl Mutation {
             .accrual_cycle_interval(input.accrual_cycle_interval)
             .one_time_fee_rate(input.one_time_fee_rate)
             .disbursal_policy(input.disbursal_policy)
+            .day_count_convention(input.day_count_convention)
+            .penalty_rate(input.penalty_rate)
+            .late_fee(input.late_fee)
+            .penalty_allocation(input.penalty_allocation)
+            .allocation_strategy(input.allocation_strategy)
+            .prepayment_fee_rate(input.prepayment_fee_rate)
             .duration(input.duration)
             .interest_due_duration_from_accrual(input.interest_due_duration_from_accrual)
             .obligation_overdue_duration_from_due(input.obligation_overdue_duration_from_due)
@@ -1736,6 +2128,24 @@ impl Mutation {
         )
     }
 
+    async fn terms_template_customer_selectability_update(
+        &self,
+        ctx: &Context<'_>,
+        input: TermsTemplateCustomerSelectabilityUpdateInput,
+    ) -> async_graphql::Result<TermsTemplateCustomerSelectabilityUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            TermsTemplateCustomerSelectabilityUpdatePayload,
+            TermsTemplate,
+            ctx,
+            app.terms_templates().update_customer_selectability(
+                sub,
+                TermsTemplateId::from(input.id),
+                input.customer_selectable
+            )
+        )
+    }
+
     async fn credit_module_configure(
         &self,
         ctx: &Context<'_>,
@@ -1806,6 +2216,11 @@ impl Mutation {
             chart_of_account_overdue_financial_institution_disbursed_receivable_parent_code,
             chart_of_account_overdue_foreign_agency_or_subsidiary_disbursed_receivable_parent_code,
             chart_of_account_overdue_non_domiciled_company_disbursed_receivable_parent_code,
+            chart_of_account_penalty_receivable_parent_code,
+            chart_of_account_penalty_defaulted_parent_code,
+            chart_of_account_penalty_income_parent_code,
+            chart_of_account_loan_loss_expense_parent_code,
+            chart_of_account_recovery_income_parent_code,
         } = input;
 
         let config_values = lana_app::credit::ChartOfAccountsIntegrationConfig {
@@ -1943,7 +2358,27 @@ impl Mutation {
                     .parse()?,
             chart_of_account_overdue_non_domiciled_company_disbursed_receivable_parent_code:
                 chart_of_account_overdue_non_domiciled_company_disbursed_receivable_parent_code
-                    .parse()?
+                    .parse()?,
+            chart_of_account_penalty_receivable_parent_code:
+                chart_of_account_penalty_receivable_parent_code
+                    .map(|code| code.parse())
+                    .transpose()?,
+            chart_of_account_penalty_defaulted_parent_code:
+                chart_of_account_penalty_defaulted_parent_code
+                    .map(|code| code.parse())
+                    .transpose()?,
+            chart_of_account_penalty_income_parent_code:
+                chart_of_account_penalty_income_parent_code
+                    .map(|code| code.parse())
+                    .transpose()?,
+            chart_of_account_loan_loss_expense_parent_code:
+                chart_of_account_loan_loss_expense_parent_code
+                    .map(|code| code.parse())
+                    .transpose()?,
+            chart_of_account_recovery_income_parent_code:
+                chart_of_account_recovery_income_parent_code
+                    .map(|code| code.parse())
+                    .transpose()?,
         };
 
         let config = app
@@ -1975,6 +2410,12 @@ impl Mutation {
             .accrual_cycle_interval(terms.accrual_cycle_interval)
             .one_time_fee_rate(terms.one_time_fee_rate)
             .disbursal_policy(terms.disbursal_policy)
+            .day_count_convention(terms.day_count_convention)
+            .penalty_rate(terms.penalty_rate)
+            .late_fee(terms.late_fee)
+            .penalty_allocation(terms.penalty_allocation)
+            .allocation_strategy(terms.allocation_strategy)
+            .prepayment_fee_rate(terms.prepayment_fee_rate)
             .duration(terms.duration)
             .interest_due_duration_from_accrual(terms.interest_due_duration_from_accrual)
             .obligation_overdue_duration_from_due(terms.obligation_overdue_duration_from_due)
@@ -2061,6 +2502,20 @@ impl Mutation {
         )
     }
 
+    pub async fn credit_facility_prepayment_record(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityPrepaymentRecordInput,
+    ) -> async_graphql::Result<CreditFacilityPrepaymentRecordPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            CreditFacilityPrepaymentRecordPayload,
+            CreditFacility,
+            ctx,
+            app.record_prepayment(sub, input.credit_facility_id, input.amount)
+        )
+    }
+
     pub async fn credit_facility_partial_payment_with_date_record(
         &self,
         ctx: &Context<'_>,
@@ -2095,6 +2550,69 @@ impl Mutation {
         )
     }
 
+    async fn credit_facility_amendment_propose(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityAmendmentProposeInput,
+    ) -> async_graphql::Result<CreditFacilityAmendmentProposePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let CreditFacilityAmendmentProposeInput {
+            credit_facility_id,
+            terms,
+            reschedule_overdue_obligations,
+        } = input;
+
+        let term_values = lana_app::terms::TermValues::builder()
+            .annual_rate(terms.annual_rate)
+            .accrual_interval(terms.accrual_interval)
+            .accrual_cycle_interval(terms.accrual_cycle_interval)
+            .one_time_fee_rate(terms.one_time_fee_rate)
+            .disbursal_policy(terms.disbursal_policy)
+            .day_count_convention(terms.day_count_convention)
+            .penalty_rate(terms.penalty_rate)
+            .late_fee(terms.late_fee)
+            .penalty_allocation(terms.penalty_allocation)
+            .allocation_strategy(terms.allocation_strategy)
+            .prepayment_fee_rate(terms.prepayment_fee_rate)
+            .duration(terms.duration)
+            .interest_due_duration_from_accrual(terms.interest_due_duration_from_accrual)
+            .obligation_overdue_duration_from_due(terms.obligation_overdue_duration_from_due)
+            .obligation_liquidation_duration_from_due(
+                terms.obligation_liquidation_duration_from_due,
+            )
+            .liquidation_cvl(terms.liquidation_cvl)
+            .margin_call_cvl(terms.margin_call_cvl)
+            .initial_cvl(terms.initial_cvl)
+            .build()?;
+
+        exec_mutation!(
+            CreditFacilityAmendmentProposePayload,
+            CreditFacility,
+            ctx,
+            app.credit().propose_facility_amendment(
+                sub,
+                credit_facility_id,
+                term_values,
+                reschedule_overdue_obligations
+            )
+        )
+    }
+
+    async fn credit_facility_write_off_propose(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityWriteOffProposeInput,
+    ) -> async_graphql::Result<CreditFacilityWriteOffProposePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            CreditFacilityWriteOffProposePayload,
+            CreditFacility,
+            ctx,
+            app.credit()
+                .propose_facility_write_off(sub, input.credit_facility_id)
+        )
+    }
+
     async fn credit_facility_complete(
         &self,
         ctx: &Context<'_>,
@@ -2224,6 +2742,45 @@ impl Mutation {
         )
     }
 
+    async fn committee_delegate_vote(
+        &self,
+        ctx: &Context<'_>,
+        input: CommitteeDelegateVoteInput,
+    ) -> async_graphql::Result<CommitteeDelegateVotePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            CommitteeDelegateVotePayload,
+            Committee,
+            ctx,
+            app.governance().delegate_committee_vote(
+                sub,
+                input.committee_id,
+                input.delegator_id,
+                input.delegate_id,
+                input.starts_on.into_inner(),
+                input.ends_on.into_inner(),
+            )
+        )
+    }
+
+    async fn committee_revoke_delegation(
+        &self,
+        ctx: &Context<'_>,
+        input: CommitteeRevokeDelegationInput,
+    ) -> async_graphql::Result<CommitteeRevokeDelegationPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            CommitteeRevokeDelegationPayload,
+            Committee,
+            ctx,
+            app.governance().revoke_committee_delegation(
+                sub,
+                input.committee_id,
+                input.delegation_id
+            )
+        )
+    }
+
     async fn policy_assign_committee(
         &self,
         ctx: &Context<'_>,
@@ -2243,6 +2800,68 @@ impl Mutation {
         )
     }
 
+    async fn policy_assign_stages(
+        &self,
+        ctx: &Context<'_>,
+        input: PolicyAssignStagesInput,
+    ) -> async_graphql::Result<PolicyAssignStagesPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let rules = DomainApprovalRules::MultiStage {
+            stages: input.stages.into_iter().map(Into::into).collect(),
+        };
+        exec_mutation!(
+            PolicyAssignStagesPayload,
+            Policy,
+            ctx,
+            app.governance()
+                .update_policy_rules(sub, input.policy_id, rules)
+        )
+    }
+
+    async fn policy_assign_amount_bands(
+        &self,
+        ctx: &Context<'_>,
+        input: PolicyAssignAmountBandsInput,
+    ) -> async_graphql::Result<PolicyAssignAmountBandsPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let rules = DomainApprovalRules::AmountBanded {
+            bands: input
+                .bands
+                .into_iter()
+                .map(|band| DomainAmountBand {
+                    min_amount: band.min_amount.into_inner(),
+                    stages: band.stages.into_iter().map(Into::into).collect(),
+                })
+                .collect(),
+        };
+        exec_mutation!(
+            PolicyAssignAmountBandsPayload,
+            Policy,
+            ctx,
+            app.governance()
+                .update_policy_rules(sub, input.policy_id, rules)
+        )
+    }
+
+    async fn policy_voting_deadline_update(
+        &self,
+        ctx: &Context<'_>,
+        input: PolicyVotingDeadlineUpdateInput,
+    ) -> async_graphql::Result<PolicyVotingDeadlineUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let voting_deadline = input
+            .voting_deadline
+            .map(DomainVotingDeadline::try_from)
+            .transpose()?;
+        exec_mutation!(
+            PolicyVotingDeadlineUpdatePayload,
+            Policy,
+            ctx,
+            app.governance()
+                .update_policy_voting_deadline(sub, input.policy_id, voting_deadline)
+        )
+    }
+
     async fn approval_process_approve(
         &self,
         ctx: &Context<'_>,
@@ -2404,6 +3023,28 @@ impl Mutation {
         )
     }
 
+    async fn chart_of_accounts_cash_flow_mapping_update(
+        &self,
+        ctx: &Context<'_>,
+        input: ChartOfAccountsCashFlowMappingUpdateInput,
+    ) -> async_graphql::Result<ChartOfAccountsCashFlowMappingUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let cash_flow_mapping = input
+            .cash_flow_mapping
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+        exec_mutation!(
+            ChartOfAccountsCashFlowMappingUpdatePayload,
+            ChartOfAccounts,
+            ChartId,
+            ctx,
+            app.accounting()
+                .chart_of_accounts()
+                .update_cash_flow_mapping(sub, CHART_REF.0, cash_flow_mapping,)
+        )
+    }
+
     async fn chart_of_accounts_add_root_node(
         &self,
         ctx: &Context<'_>,
@@ -2490,6 +3131,32 @@ impl Mutation {
         Ok(LedgerAccountCsvCreatePayload::from(csv_document))
     }
 
+    pub async fn cash_flow_statement_csv_create(
+        &self,
+        ctx: &Context<'_>,
+        input: CashFlowStatementCsvCreateInput,
+    ) -> async_graphql::Result<CashFlowStatementCsvCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let chart = app
+            .accounting()
+            .chart_of_accounts()
+            .find_by_reference_with_sub(sub, CHART_REF.0)
+            .await?;
+        let csv = app
+            .accounting()
+            .csvs()
+            .create_cash_flow_csv(
+                sub,
+                &chart,
+                input.from.into_inner(),
+                input.until.map(|t| t.into_inner()),
+            )
+            .await?;
+
+        let csv_document = AccountingCsvDocument::from(csv);
+        Ok(CashFlowStatementCsvCreatePayload::from(csv_document))
+    }
+
     pub async fn accounting_csv_download_link_generate(
         &self,
         ctx: &Context<'_>,
@@ -1,9 +1,12 @@
 use async_graphql::*;
 use serde::{Deserialize, Serialize};
 
+use crate::primitives::UsdCents;
+
 pub use lana_app::terms::{
-    AnnualRatePct, CVLPct as DomainCVLPct, DisbursalPolicy, FacilityDuration as DomainDuration,
-    InterestInterval, ObligationDuration as DomainObligationDuration, OneTimeFeeRatePct,
+    AllocationStrategy, AnnualRatePct, CVLPct as DomainCVLPct, DayCountConvention, DisbursalPolicy,
+    FacilityDuration as DomainDuration, InterestInterval,
+    ObligationDuration as DomainObligationDuration, OneTimeFeeRatePct, PenaltyAllocation,
     TermValues as DomainTermValues,
 };
 
@@ -14,6 +17,12 @@ pub struct TermValues {
     accrual_cycle_interval: InterestInterval,
     one_time_fee_rate: OneTimeFeeRatePct,
     disbursal_policy: DisbursalPolicy,
+    day_count_convention: DayCountConvention,
+    penalty_rate: Option<AnnualRatePct>,
+    late_fee: Option<UsdCents>,
+    penalty_allocation: PenaltyAllocation,
+    allocation_strategy: AllocationStrategy,
+    prepayment_fee_rate: Option<OneTimeFeeRatePct>,
     duration: Duration,
     liquidation_cvl: CVLPct,
     margin_call_cvl: CVLPct,
@@ -28,6 +37,12 @@ impl From<DomainTermValues> for TermValues {
             accrual_cycle_interval: values.accrual_cycle_interval,
             one_time_fee_rate: values.one_time_fee_rate,
             disbursal_policy: values.disbursal_policy,
+            day_count_convention: values.day_count_convention,
+            penalty_rate: values.penalty_rate,
+            late_fee: values.late_fee,
+            penalty_allocation: values.penalty_allocation,
+            allocation_strategy: values.allocation_strategy,
+            prepayment_fee_rate: values.prepayment_fee_rate,
             duration: values.duration.into(),
             liquidation_cvl: values.liquidation_cvl.into(),
             margin_call_cvl: values.margin_call_cvl.into(),
@@ -42,6 +57,15 @@ pub struct TermsInput {
     pub accrual_cycle_interval: InterestInterval,
     pub one_time_fee_rate: OneTimeFeeRatePct,
     pub disbursal_policy: DisbursalPolicy,
+    #[graphql(default)]
+    pub day_count_convention: DayCountConvention,
+    pub penalty_rate: Option<AnnualRatePct>,
+    pub late_fee: Option<UsdCents>,
+    #[graphql(default)]
+    pub penalty_allocation: PenaltyAllocation,
+    #[graphql(default)]
+    pub allocation_strategy: AllocationStrategy,
+    pub prepayment_fee_rate: Option<OneTimeFeeRatePct>,
     pub duration: DurationInput,
     pub interest_due_duration_from_accrual: DurationInput,
     pub obligation_overdue_duration_from_due: DurationInput,
@@ -12,6 +12,7 @@ pub struct TermsTemplate {
     id: ID,
     terms_id: UUID,
     values: TermValues,
+    customer_selectable: bool,
     created_at: Timestamp,
 
     #[graphql(skip)]
@@ -25,6 +26,7 @@ impl From<DomainTermsTemplate> for TermsTemplate {
             created_at: terms.created_at().into(),
             terms_id: terms.id.into(),
             values: terms.values.into(),
+            customer_selectable: terms.customer_selectable,
             entity: Arc::new(terms),
         }
     }
@@ -57,6 +59,15 @@ pub(super) struct TermsTemplateCreateInput {
     pub accrual_cycle_interval: InterestInterval,
     pub one_time_fee_rate: OneTimeFeeRatePct,
     pub disbursal_policy: DisbursalPolicy,
+    #[graphql(default)]
+    pub day_count_convention: DayCountConvention,
+    pub penalty_rate: Option<AnnualRatePct>,
+    pub late_fee: Option<UsdCents>,
+    #[graphql(default)]
+    pub penalty_allocation: PenaltyAllocation,
+    #[graphql(default)]
+    pub allocation_strategy: AllocationStrategy,
+    pub prepayment_fee_rate: Option<OneTimeFeeRatePct>,
     pub duration: DurationInput,
     pub interest_due_duration_from_accrual: DurationInput,
     pub obligation_overdue_duration_from_due: DurationInput,
@@ -75,6 +86,15 @@ pub(super) struct TermsTemplateUpdateInput {
     pub accrual_cycle_interval: InterestInterval,
     pub one_time_fee_rate: OneTimeFeeRatePct,
     pub disbursal_policy: DisbursalPolicy,
+    #[graphql(default)]
+    pub day_count_convention: DayCountConvention,
+    pub penalty_rate: Option<AnnualRatePct>,
+    pub late_fee: Option<UsdCents>,
+    #[graphql(default)]
+    pub penalty_allocation: PenaltyAllocation,
+    #[graphql(default)]
+    pub allocation_strategy: AllocationStrategy,
+    pub prepayment_fee_rate: Option<OneTimeFeeRatePct>,
     pub liquidation_cvl: CVLPctValue,
     pub duration: DurationInput,
     pub interest_due_duration_from_accrual: DurationInput,
@@ -84,3 +104,10 @@ pub(super) struct TermsTemplateUpdateInput {
     pub initial_cvl: CVLPctValue,
 }
 crate::mutation_payload! { TermsTemplateUpdatePayload, terms_template: TermsTemplate }
+
+#[derive(InputObject)]
+pub(super) struct TermsTemplateCustomerSelectabilityUpdateInput {
+    pub id: UUID,
+    pub customer_selectable: bool,
+}
+crate::mutation_payload! { TermsTemplateCustomerSelectabilityUpdatePayload, terms_template: TermsTemplate }
@@ -0,0 +1,113 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::{
+    accounting::LedgerTransaction, approval_process::ApprovalProcess,
+    deposit_account::DepositAccount, loader::LanaDataLoader,
+};
+
+pub use lana_app::{
+    deposit::{Transfer as DomainTransfer, TransferStatus, TransfersByCreatedAtCursor},
+    public_id::PublicId,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct Transfer {
+    id: ID,
+    transfer_id: UUID,
+    source_account_id: UUID,
+    destination_account_id: UUID,
+    amount: UsdCents,
+    status: TransferStatus,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainTransfer>,
+}
+
+impl From<lana_app::deposit::Transfer> for Transfer {
+    fn from(transfer: lana_app::deposit::Transfer) -> Self {
+        Transfer {
+            id: transfer.id.to_global_id(),
+            created_at: transfer.created_at().into(),
+            transfer_id: UUID::from(transfer.id),
+            source_account_id: transfer.source_account_id.into(),
+            destination_account_id: transfer.destination_account_id.into(),
+            amount: transfer.amount,
+            status: transfer.status(),
+            entity: Arc::new(transfer),
+        }
+    }
+}
+
+#[ComplexObject]
+impl Transfer {
+    async fn public_id(&self) -> &PublicId {
+        &self.entity.public_id
+    }
+
+    async fn reference(&self) -> &str {
+        &self.entity.reference
+    }
+
+    async fn approval_process(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<ApprovalProcess>> {
+        let Some(approval_process_id) = self.entity.approval_process_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let process = loader
+            .load_one(approval_process_id)
+            .await?
+            .expect("process not found");
+        Ok(Some(process))
+    }
+
+    async fn source_account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.source_account_id)
+            .await?
+            .expect("account not found");
+        Ok(account)
+    }
+
+    async fn destination_account(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<DepositAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.destination_account_id)
+            .await?
+            .expect("account not found");
+        Ok(account)
+    }
+
+    async fn ledger_transactions(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<LedgerTransaction>> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let tx_ids = self.entity.ledger_tx_ids();
+        let loaded_transactions = loader.load_many(tx_ids.iter().copied()).await?;
+
+        Ok(tx_ids
+            .iter()
+            .filter_map(|id| loaded_transactions.get(id).cloned())
+            .collect())
+    }
+}
+
+#[derive(InputObject)]
+pub struct TransferInitiateInput {
+    pub source_account_id: UUID,
+    pub destination_account_id: UUID,
+    pub amount: UsdCents,
+    pub reference: Option<String>,
+}
+crate::mutation_payload! { TransferInitiatePayload, transfer: Transfer }
@@ -8,7 +8,13 @@ use super::{
 };
 
 pub use lana_app::{
-    deposit::{Withdrawal as DomainWithdrawal, WithdrawalStatus, WithdrawalsByCreatedAtCursor},
+    customer::CustomerType,
+    deposit::{
+        DepositProduct, Withdrawal as DomainWithdrawal,
+        WithdrawalLimitRule as DomainWithdrawalLimitRule, WithdrawalLimits,
+        WithdrawalLimitsConfig as DomainWithdrawalLimitsConfig, WithdrawalStatus,
+        WithdrawalsByCreatedAtCursor,
+    },
     public_id::PublicId,
 };
 
@@ -110,3 +116,72 @@ pub struct WithdrawalRevertInput {
     pub withdrawal_id: UUID,
 }
 crate::mutation_payload! { WithdrawalRevertPayload, withdrawal: Withdrawal }
+
+#[derive(SimpleObject, Clone)]
+pub struct WithdrawalLimitRule {
+    product: Option<DepositProduct>,
+    customer_type: Option<CustomerType>,
+    daily: Option<UsdCents>,
+    weekly: Option<UsdCents>,
+    monthly: Option<UsdCents>,
+}
+
+impl From<DomainWithdrawalLimitRule> for WithdrawalLimitRule {
+    fn from(rule: DomainWithdrawalLimitRule) -> Self {
+        Self {
+            product: rule.product,
+            customer_type: rule.customer_type,
+            daily: rule.limits.daily,
+            weekly: rule.limits.weekly,
+            monthly: rule.limits.monthly,
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct WithdrawalLimitsConfig {
+    rules: Vec<WithdrawalLimitRule>,
+}
+
+impl From<DomainWithdrawalLimitsConfig> for WithdrawalLimitsConfig {
+    fn from(config: DomainWithdrawalLimitsConfig) -> Self {
+        Self {
+            rules: config.rules.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct WithdrawalLimitRuleInput {
+    pub product: Option<DepositProduct>,
+    pub customer_type: Option<CustomerType>,
+    pub daily: Option<UsdCents>,
+    pub weekly: Option<UsdCents>,
+    pub monthly: Option<UsdCents>,
+}
+
+#[derive(InputObject)]
+pub struct WithdrawalLimitsConfigUpdateInput {
+    pub rules: Vec<WithdrawalLimitRuleInput>,
+}
+
+impl From<WithdrawalLimitsConfigUpdateInput> for DomainWithdrawalLimitsConfig {
+    fn from(input: WithdrawalLimitsConfigUpdateInput) -> Self {
+        Self {
+            rules: input
+                .rules
+                .into_iter()
+                .map(|rule| DomainWithdrawalLimitRule {
+                    product: rule.product,
+                    customer_type: rule.customer_type,
+                    limits: WithdrawalLimits {
+                        daily: rule.daily,
+                        weekly: rule.weekly,
+                        monthly: rule.monthly,
+                    },
+                })
+                .collect(),
+        }
+    }
+}
+crate::mutation_payload! { WithdrawalLimitsConfigUpdatePayload, withdrawal_limits_config: WithdrawalLimitsConfig }
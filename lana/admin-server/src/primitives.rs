@@ -11,10 +11,11 @@ pub use lana_app::{
         AccountSpec, ApprovalProcessId, ChartId, CollateralId, CommitteeId, CreditFacilityId,
         CreditFacilityProposalId, CreditFacilityProposalStatus, CustodianId, CustomerDocumentId,
         CustomerId, DepositAccountId, DepositId, DisbursalId, DisbursalStatus, DocumentId, EntryId,
-        FiscalYearId, LedgerTransactionId, LiquidationId, ManualTransactionId, PaymentAllocationId,
-        PaymentId, PendingCreditFacilityId, PendingCreditFacilityStatus, PermissionSetId, PolicyId,
-        ProspectId, ReportId, RoleId, Satoshis, SignedSatoshis, SignedUsdCents, Subject,
-        TermsTemplateId, UsdCents, UserId, WalletId, WithdrawalId,
+        FiscalYearId, HoldId, LedgerTransactionId, LiquidationId, ManualTransactionId,
+        PaymentAllocationId, PaymentId, PendingCreditFacilityId, PendingCreditFacilityStatus,
+        PermissionSetId, PolicyId, ProspectId, RecurringJournalEntryId, ReportId, RoleId, Satoshis,
+        SignedSatoshis, SignedUsdCents, Subject, TermsTemplateId, TransferId, UsdCents, UserId,
+        WalletId, WithdrawalId,
     },
     public_id::PublicId,
     report::ReportRunId,
@@ -138,10 +139,13 @@ impl_to_global_id! {
     PolicyId,
     CommitteeId,
     WithdrawalId,
+    TransferId,
+    HoldId,
     DepositId,
     ReportId,
     ReportRunId,
     ManualTransactionId,
+    RecurringJournalEntryId,
     ApprovalProcessId,
     DepositAccountId,
     LedgerTransactionId,
@@ -36,9 +36,12 @@ CREATE TABLE core_approval_processes (
   policy_id UUID REFERENCES core_policies(id),
   committee_id UUID REFERENCES core_committees(id),
   process_type VARCHAR NOT NULL,
+  expires_at TIMESTAMPTZ,
   created_at TIMESTAMPTZ NOT NULL
 );
 
+CREATE INDEX idx_core_approval_processes_expires_at ON core_approval_processes(expires_at) WHERE expires_at IS NOT NULL;
+
 CREATE TABLE core_approval_process_events (
   id UUID NOT NULL REFERENCES core_approval_processes(id),
   sequence INT NOT NULL,
@@ -142,6 +145,21 @@ CREATE TABLE customer_activity (
 
 CREATE INDEX idx_customer_activity_last_activity_date ON customer_activity(last_activity_date);
 
+CREATE TABLE core_price_ticks (
+  recorded_at TIMESTAMPTZ PRIMARY KEY,
+  usd_cents_per_btc BIGINT NOT NULL
+);
+
+CREATE TABLE core_price_daily_ohlc (
+  day DATE PRIMARY KEY,
+  open_usd_cents_per_btc BIGINT NOT NULL,
+  high_usd_cents_per_btc BIGINT NOT NULL,
+  low_usd_cents_per_btc BIGINT NOT NULL,
+  close_usd_cents_per_btc BIGINT NOT NULL,
+  opened_at TIMESTAMPTZ NOT NULL,
+  closed_at TIMESTAMPTZ NOT NULL
+);
+
 CREATE TABLE core_prospects (
   id UUID PRIMARY KEY,
   party_id UUID NOT NULL REFERENCES core_parties(id),
@@ -163,6 +181,7 @@ CREATE TABLE core_deposit_accounts (
   id UUID PRIMARY KEY,
   account_holder_id UUID NOT NULL,
   public_id VARCHAR NOT NULL REFERENCES core_public_ids(id),
+  interest_bearing BOOLEAN NOT NULL DEFAULT FALSE,
   created_at TIMESTAMPTZ NOT NULL
 );
 
@@ -214,6 +233,44 @@ CREATE TABLE core_withdrawal_events (
   UNIQUE(id, sequence)
 );
 
+CREATE TABLE core_transfers (
+  id UUID PRIMARY KEY,
+  source_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  destination_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  approval_process_id UUID REFERENCES core_approval_processes(id),
+  completed_tx_id UUID DEFAULT NULL,
+  denied_tx_id UUID DEFAULT NULL,
+  reference VARCHAR NOT NULL UNIQUE,
+  public_id VARCHAR NOT NULL REFERENCES core_public_ids(id),
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_transfer_events (
+  id UUID NOT NULL REFERENCES core_transfers(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  context JSONB DEFAULT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_holds (
+  id UUID PRIMARY KEY,
+  deposit_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_hold_events (
+  id UUID NOT NULL REFERENCES core_holds(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  context JSONB DEFAULT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
 CREATE TABLE core_terms_templates (
   id UUID PRIMARY KEY,
   name VARCHAR NOT NULL UNIQUE,
@@ -294,6 +351,22 @@ CREATE TABLE core_wallet_events (
   UNIQUE(id, sequence)
 );
 
+CREATE TABLE core_wallet_transfers (
+  id UUID PRIMARY KEY,
+  external_transfer_id VARCHAR UNIQUE,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_wallet_transfer_events (
+  id UUID NOT NULL REFERENCES core_wallet_transfers(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  context JSONB DEFAULT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
 CREATE TABLE core_collaterals (
   id UUID PRIMARY KEY,
   custody_wallet_id UUID,
@@ -583,6 +656,22 @@ CREATE TABLE core_manual_transaction_events (
   UNIQUE(id, sequence)
 );
 
+CREATE TABLE core_recurring_journal_entries (
+  id UUID PRIMARY KEY,
+  reference VARCHAR NOT NULL UNIQUE,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_recurring_journal_entry_events (
+  id UUID NOT NULL REFERENCES core_recurring_journal_entries(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  context JSONB DEFAULT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
 CREATE TABLE casbin_rule (
   id SERIAL PRIMARY KEY,
   ptype VARCHAR NOT NULL,
@@ -0,0 +1,141 @@
+-- Auto-generated rollup table for WalletTransferEvent
+CREATE TABLE core_wallet_transfer_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  event_type TEXT NOT NULL,
+  -- Flattened fields from the event JSON
+  amount BIGINT,
+  confirmed_at TIMESTAMPTZ,
+  custodian_id UUID,
+  custodian_response JSONB,
+  destination_address VARCHAR,
+  external_transfer_id VARCHAR,
+  failed_at TIMESTAMPTZ,
+  reason VARCHAR,
+  tx_hash VARCHAR,
+  wallet_id UUID
+,
+  PRIMARY KEY (id, version)
+);
+
+
+-- Auto-generated trigger function for WalletTransferEvent
+CREATE OR REPLACE FUNCTION core_wallet_transfer_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_wallet_transfer_events_rollup%ROWTYPE;
+  new_row core_wallet_transfer_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_wallet_transfer_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'sent', 'confirmed', 'failed') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+  new_row.event_type := NEW.event_type;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+    new_row.confirmed_at := (NEW.event ->> 'confirmed_at')::TIMESTAMPTZ;
+    new_row.custodian_id := (NEW.event ->> 'custodian_id')::UUID;
+    new_row.custodian_response := (NEW.event -> 'custodian_response');
+    new_row.destination_address := (NEW.event ->> 'destination_address');
+    new_row.external_transfer_id := (NEW.event ->> 'external_transfer_id');
+    new_row.failed_at := (NEW.event ->> 'failed_at')::TIMESTAMPTZ;
+    new_row.reason := (NEW.event ->> 'reason');
+    new_row.tx_hash := (NEW.event ->> 'tx_hash');
+    new_row.wallet_id := (NEW.event ->> 'wallet_id')::UUID;
+  ELSE
+    -- Default all fields to current values
+    new_row.amount := current_row.amount;
+    new_row.confirmed_at := current_row.confirmed_at;
+    new_row.custodian_id := current_row.custodian_id;
+    new_row.custodian_response := current_row.custodian_response;
+    new_row.destination_address := current_row.destination_address;
+    new_row.external_transfer_id := current_row.external_transfer_id;
+    new_row.failed_at := current_row.failed_at;
+    new_row.reason := current_row.reason;
+    new_row.tx_hash := current_row.tx_hash;
+    new_row.wallet_id := current_row.wallet_id;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.custodian_id := (NEW.event ->> 'custodian_id')::UUID;
+      new_row.destination_address := (NEW.event ->> 'destination_address');
+      new_row.wallet_id := (NEW.event ->> 'wallet_id')::UUID;
+    WHEN 'sent' THEN
+      new_row.custodian_response := (NEW.event -> 'custodian_response');
+      new_row.external_transfer_id := (NEW.event ->> 'external_transfer_id');
+    WHEN 'confirmed' THEN
+      new_row.confirmed_at := (NEW.event ->> 'confirmed_at')::TIMESTAMPTZ;
+      new_row.tx_hash := (NEW.event ->> 'tx_hash');
+    WHEN 'failed' THEN
+      new_row.failed_at := (NEW.event ->> 'failed_at')::TIMESTAMPTZ;
+      new_row.reason := (NEW.event ->> 'reason');
+  END CASE;
+
+  INSERT INTO core_wallet_transfer_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    event_type,
+    amount,
+    confirmed_at,
+    custodian_id,
+    custodian_response,
+    destination_address,
+    external_transfer_id,
+    failed_at,
+    reason,
+    tx_hash,
+    wallet_id
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.event_type,
+    new_row.amount,
+    new_row.confirmed_at,
+    new_row.custodian_id,
+    new_row.custodian_response,
+    new_row.destination_address,
+    new_row.external_transfer_id,
+    new_row.failed_at,
+    new_row.reason,
+    new_row.tx_hash,
+    new_row.wallet_id
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+
+-- Auto-generated trigger for WalletTransferEvent
+CREATE TRIGGER core_wallet_transfer_events_rollup_trigger
+  AFTER INSERT ON core_wallet_transfer_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_wallet_transfer_events_rollup_trigger();
@@ -0,0 +1,177 @@
+-- Current table structure after migration:
+/*
+-- Auto-generated rollup table for DepositAccountEvent
+CREATE TABLE core_deposit_account_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  event_type TEXT NOT NULL,
+  -- Flattened fields from the event JSON
+  account_holder_id UUID,
+  account_ids JSONB,
+  amount BIGINT,
+  annual_rate VARCHAR,
+  balance BIGINT,
+  day VARCHAR,
+  forfeited_interest BIGINT,
+  ledger_tx_id UUID,
+  penalty BIGINT,
+  public_id VARCHAR,
+  status VARCHAR,
+  terms JSONB
+,
+  PRIMARY KEY (id, version)
+);
+
+*/
+
+-- Migration to update core_deposit_account_events_rollup table schema
+
+-- Add new columns
+ALTER TABLE core_deposit_account_events_rollup ADD COLUMN IF NOT EXISTS amount BIGINT;
+ALTER TABLE core_deposit_account_events_rollup ADD COLUMN IF NOT EXISTS annual_rate VARCHAR;
+ALTER TABLE core_deposit_account_events_rollup ADD COLUMN IF NOT EXISTS balance BIGINT;
+ALTER TABLE core_deposit_account_events_rollup ADD COLUMN IF NOT EXISTS day VARCHAR;
+ALTER TABLE core_deposit_account_events_rollup ADD COLUMN IF NOT EXISTS forfeited_interest BIGINT;
+ALTER TABLE core_deposit_account_events_rollup ADD COLUMN IF NOT EXISTS ledger_tx_id UUID;
+ALTER TABLE core_deposit_account_events_rollup ADD COLUMN IF NOT EXISTS penalty BIGINT;
+ALTER TABLE core_deposit_account_events_rollup ADD COLUMN IF NOT EXISTS terms JSONB;
+
+
+-- Auto-generated trigger function for DepositAccountEvent
+CREATE OR REPLACE FUNCTION core_deposit_account_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_deposit_account_events_rollup%ROWTYPE;
+  new_row core_deposit_account_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_deposit_account_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'account_holder_status_updated', 'frozen', 'unfrozen', 'closed', 'interest_terms_updated', 'interest_accrued', 'interest_capitalized', 'term_matured', 'term_broken') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+  new_row.event_type := NEW.event_type;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.account_holder_id := (NEW.event ->> 'account_holder_id')::UUID;
+    new_row.account_ids := (NEW.event -> 'account_ids');
+    new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+    new_row.annual_rate := (NEW.event ->> 'annual_rate');
+    new_row.balance := (NEW.event ->> 'balance')::BIGINT;
+    new_row.day := (NEW.event ->> 'day');
+    new_row.forfeited_interest := (NEW.event ->> 'forfeited_interest')::BIGINT;
+    new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+    new_row.penalty := (NEW.event ->> 'penalty')::BIGINT;
+    new_row.public_id := (NEW.event ->> 'public_id');
+    new_row.status := (NEW.event ->> 'status');
+    new_row.terms := (NEW.event -> 'terms');
+  ELSE
+    -- Default all fields to current values
+    new_row.account_holder_id := current_row.account_holder_id;
+    new_row.account_ids := current_row.account_ids;
+    new_row.amount := current_row.amount;
+    new_row.annual_rate := current_row.annual_rate;
+    new_row.balance := current_row.balance;
+    new_row.day := current_row.day;
+    new_row.forfeited_interest := current_row.forfeited_interest;
+    new_row.ledger_tx_id := current_row.ledger_tx_id;
+    new_row.penalty := current_row.penalty;
+    new_row.public_id := current_row.public_id;
+    new_row.status := current_row.status;
+    new_row.terms := current_row.terms;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.account_holder_id := (NEW.event ->> 'account_holder_id')::UUID;
+      new_row.account_ids := (NEW.event -> 'account_ids');
+      new_row.public_id := (NEW.event ->> 'public_id');
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'account_holder_status_updated' THEN
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'frozen' THEN
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'unfrozen' THEN
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'closed' THEN
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'interest_terms_updated' THEN
+      new_row.terms := (NEW.event -> 'terms');
+    WHEN 'interest_accrued' THEN
+      new_row.annual_rate := (NEW.event ->> 'annual_rate');
+      new_row.balance := (NEW.event ->> 'balance')::BIGINT;
+      new_row.day := (NEW.event ->> 'day');
+    WHEN 'interest_capitalized' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.day := (NEW.event ->> 'day');
+      new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+    WHEN 'term_matured' THEN
+      new_row.day := (NEW.event ->> 'day');
+    WHEN 'term_broken' THEN
+      new_row.day := (NEW.event ->> 'day');
+      new_row.forfeited_interest := (NEW.event ->> 'forfeited_interest')::BIGINT;
+      new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+      new_row.penalty := (NEW.event ->> 'penalty')::BIGINT;
+  END CASE;
+
+  INSERT INTO core_deposit_account_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    event_type,
+    account_holder_id,
+    account_ids,
+    amount,
+    annual_rate,
+    balance,
+    day,
+    forfeited_interest,
+    ledger_tx_id,
+    penalty,
+    public_id,
+    status,
+    terms
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.event_type,
+    new_row.account_holder_id,
+    new_row.account_ids,
+    new_row.amount,
+    new_row.annual_rate,
+    new_row.balance,
+    new_row.day,
+    new_row.forfeited_interest,
+    new_row.ledger_tx_id,
+    new_row.penalty,
+    new_row.public_id,
+    new_row.status,
+    new_row.terms
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
@@ -0,0 +1,157 @@
+-- Current table structure after migration:
+/*
+-- Auto-generated rollup table for ManualTransactionEvent
+CREATE TABLE core_manual_transaction_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  event_type TEXT NOT NULL,
+  -- Flattened fields from the event JSON
+  approval_process_id UUID,
+  description VARCHAR,
+  effective VARCHAR,
+  entries JSONB,
+  initiated_by VARCHAR,
+  ledger_transaction_id UUID,
+  reason VARCHAR,
+  reference VARCHAR,
+  reversal_effective VARCHAR,
+  reversal_ledger_transaction_id UUID,
+  reversed_by VARCHAR
+,
+  PRIMARY KEY (id, version)
+);
+
+*/
+
+-- Migration to update core_manual_transaction_events_rollup table schema
+
+-- Add new columns
+ALTER TABLE core_manual_transaction_events_rollup ADD COLUMN IF NOT EXISTS reversal_effective VARCHAR;
+ALTER TABLE core_manual_transaction_events_rollup ADD COLUMN IF NOT EXISTS reversal_ledger_transaction_id UUID;
+ALTER TABLE core_manual_transaction_events_rollup ADD COLUMN IF NOT EXISTS reversed_by VARCHAR;
+
+
+-- Auto-generated trigger function for ManualTransactionEvent
+CREATE OR REPLACE FUNCTION core_manual_transaction_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_manual_transaction_events_rollup%ROWTYPE;
+  new_row core_manual_transaction_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_manual_transaction_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'posted', 'denied', 'expired', 'reversed') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+  new_row.event_type := NEW.event_type;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+    new_row.description := (NEW.event ->> 'description');
+    new_row.effective := (NEW.event ->> 'effective');
+    new_row.entries := (NEW.event -> 'entries');
+    new_row.initiated_by := (NEW.event ->> 'initiated_by');
+    new_row.ledger_transaction_id := (NEW.event ->> 'ledger_transaction_id')::UUID;
+    new_row.reason := (NEW.event ->> 'reason');
+    new_row.reference := (NEW.event ->> 'reference');
+    new_row.reversal_effective := (NEW.event ->> 'reversal_effective');
+    new_row.reversal_ledger_transaction_id := (NEW.event ->> 'reversal_ledger_transaction_id')::UUID;
+    new_row.reversed_by := (NEW.event ->> 'reversed_by');
+  ELSE
+    -- Default all fields to current values
+    new_row.approval_process_id := current_row.approval_process_id;
+    new_row.description := current_row.description;
+    new_row.effective := current_row.effective;
+    new_row.entries := current_row.entries;
+    new_row.initiated_by := current_row.initiated_by;
+    new_row.ledger_transaction_id := current_row.ledger_transaction_id;
+    new_row.reason := current_row.reason;
+    new_row.reference := current_row.reference;
+    new_row.reversal_effective := current_row.reversal_effective;
+    new_row.reversal_ledger_transaction_id := current_row.reversal_ledger_transaction_id;
+    new_row.reversed_by := current_row.reversed_by;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+      new_row.description := (NEW.event ->> 'description');
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.entries := (NEW.event -> 'entries');
+      new_row.initiated_by := (NEW.event ->> 'initiated_by');
+      new_row.ledger_transaction_id := (NEW.event ->> 'ledger_transaction_id')::UUID;
+      new_row.reference := (NEW.event ->> 'reference');
+    WHEN 'posted' THEN
+      new_row.ledger_transaction_id := (NEW.event ->> 'ledger_transaction_id')::UUID;
+    WHEN 'denied' THEN
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+      new_row.reason := (NEW.event ->> 'reason');
+    WHEN 'expired' THEN
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+      new_row.reason := (NEW.event ->> 'reason');
+    WHEN 'reversed' THEN
+      new_row.reversal_effective := (NEW.event ->> 'reversal_effective');
+      new_row.reversal_ledger_transaction_id := (NEW.event ->> 'reversal_ledger_transaction_id')::UUID;
+      new_row.reversed_by := (NEW.event ->> 'reversed_by');
+  END CASE;
+
+  INSERT INTO core_manual_transaction_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    event_type,
+    approval_process_id,
+    description,
+    effective,
+    entries,
+    initiated_by,
+    ledger_transaction_id,
+    reason,
+    reference,
+    reversal_effective,
+    reversal_ledger_transaction_id,
+    reversed_by
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.event_type,
+    new_row.approval_process_id,
+    new_row.description,
+    new_row.effective,
+    new_row.entries,
+    new_row.initiated_by,
+    new_row.ledger_transaction_id,
+    new_row.reason,
+    new_row.reference,
+    new_row.reversal_effective,
+    new_row.reversal_ledger_transaction_id,
+    new_row.reversed_by
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
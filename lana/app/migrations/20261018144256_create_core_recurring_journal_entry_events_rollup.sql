@@ -0,0 +1,212 @@
+-- Auto-generated rollup table for RecurringJournalEntryEvent
+CREATE TABLE core_recurring_journal_entry_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  event_type TEXT NOT NULL,
+  -- Flattened fields from the event JSON
+  amount VARCHAR,
+  amount_schedule JSONB,
+  chart_id UUID,
+  created_by VARCHAR,
+  credit_account_id UUID,
+  currency VARCHAR,
+  date VARCHAR,
+  debit_account_id UUID,
+  description VARCHAR,
+  end_date VARCHAR,
+  frequency VARCHAR,
+  paused_by VARCHAR,
+  paused_on VARCHAR,
+  reason VARCHAR,
+  reference VARCHAR,
+  resumed_by VARCHAR,
+  resumed_on VARCHAR,
+  skipped_by VARCHAR,
+  start_date VARCHAR,
+
+  -- Collection rollups
+  manual_transaction_ids UUID[]
+,
+  PRIMARY KEY (id, version)
+);
+
+
+-- Auto-generated trigger function for RecurringJournalEntryEvent
+CREATE OR REPLACE FUNCTION core_recurring_journal_entry_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_recurring_journal_entry_events_rollup%ROWTYPE;
+  new_row core_recurring_journal_entry_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_recurring_journal_entry_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'paused', 'resumed', 'occurrence_skipped', 'occurrence_submitted', 'occurrence_failed') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+  new_row.event_type := NEW.event_type;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.amount := (NEW.event ->> 'amount');
+    new_row.amount_schedule := (NEW.event -> 'amount_schedule');
+    new_row.chart_id := (NEW.event ->> 'chart_id')::UUID;
+    new_row.created_by := (NEW.event ->> 'created_by');
+    new_row.credit_account_id := (NEW.event ->> 'credit_account_id')::UUID;
+    new_row.currency := (NEW.event ->> 'currency');
+    new_row.date := (NEW.event ->> 'date');
+    new_row.debit_account_id := (NEW.event ->> 'debit_account_id')::UUID;
+    new_row.description := (NEW.event ->> 'description');
+    new_row.end_date := (NEW.event ->> 'end_date');
+    new_row.frequency := (NEW.event ->> 'frequency');
+    new_row.manual_transaction_ids := CASE
+       WHEN NEW.event ? 'manual_transaction_ids' THEN
+         ARRAY(SELECT value::text::UUID FROM jsonb_array_elements_text(NEW.event -> 'manual_transaction_ids'))
+       ELSE ARRAY[]::UUID[]
+     END
+;
+    new_row.paused_by := (NEW.event ->> 'paused_by');
+    new_row.paused_on := (NEW.event ->> 'paused_on');
+    new_row.reason := (NEW.event ->> 'reason');
+    new_row.reference := (NEW.event ->> 'reference');
+    new_row.resumed_by := (NEW.event ->> 'resumed_by');
+    new_row.resumed_on := (NEW.event ->> 'resumed_on');
+    new_row.skipped_by := (NEW.event ->> 'skipped_by');
+    new_row.start_date := (NEW.event ->> 'start_date');
+  ELSE
+    -- Default all fields to current values
+    new_row.amount := current_row.amount;
+    new_row.amount_schedule := current_row.amount_schedule;
+    new_row.chart_id := current_row.chart_id;
+    new_row.created_by := current_row.created_by;
+    new_row.credit_account_id := current_row.credit_account_id;
+    new_row.currency := current_row.currency;
+    new_row.date := current_row.date;
+    new_row.debit_account_id := current_row.debit_account_id;
+    new_row.description := current_row.description;
+    new_row.end_date := current_row.end_date;
+    new_row.frequency := current_row.frequency;
+    new_row.manual_transaction_ids := current_row.manual_transaction_ids;
+    new_row.paused_by := current_row.paused_by;
+    new_row.paused_on := current_row.paused_on;
+    new_row.reason := current_row.reason;
+    new_row.reference := current_row.reference;
+    new_row.resumed_by := current_row.resumed_by;
+    new_row.resumed_on := current_row.resumed_on;
+    new_row.skipped_by := current_row.skipped_by;
+    new_row.start_date := current_row.start_date;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.amount := (NEW.event ->> 'amount');
+      new_row.amount_schedule := (NEW.event -> 'amount_schedule');
+      new_row.chart_id := (NEW.event ->> 'chart_id')::UUID;
+      new_row.created_by := (NEW.event ->> 'created_by');
+      new_row.credit_account_id := (NEW.event ->> 'credit_account_id')::UUID;
+      new_row.currency := (NEW.event ->> 'currency');
+      new_row.debit_account_id := (NEW.event ->> 'debit_account_id')::UUID;
+      new_row.description := (NEW.event ->> 'description');
+      new_row.end_date := (NEW.event ->> 'end_date');
+      new_row.frequency := (NEW.event ->> 'frequency');
+      new_row.reference := (NEW.event ->> 'reference');
+      new_row.start_date := (NEW.event ->> 'start_date');
+    WHEN 'paused' THEN
+      new_row.paused_by := (NEW.event ->> 'paused_by');
+      new_row.paused_on := (NEW.event ->> 'paused_on');
+    WHEN 'resumed' THEN
+      new_row.resumed_by := (NEW.event ->> 'resumed_by');
+      new_row.resumed_on := (NEW.event ->> 'resumed_on');
+    WHEN 'occurrence_skipped' THEN
+      new_row.date := (NEW.event ->> 'date');
+      new_row.skipped_by := (NEW.event ->> 'skipped_by');
+    WHEN 'occurrence_submitted' THEN
+      new_row.date := (NEW.event ->> 'date');
+      new_row.manual_transaction_ids := array_append(COALESCE(current_row.manual_transaction_ids, ARRAY[]::UUID[]), (NEW.event ->> 'manual_transaction_id')::UUID);
+    WHEN 'occurrence_failed' THEN
+      new_row.date := (NEW.event ->> 'date');
+      new_row.reason := (NEW.event ->> 'reason');
+  END CASE;
+
+  INSERT INTO core_recurring_journal_entry_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    event_type,
+    amount,
+    amount_schedule,
+    chart_id,
+    created_by,
+    credit_account_id,
+    currency,
+    date,
+    debit_account_id,
+    description,
+    end_date,
+    frequency,
+    manual_transaction_ids,
+    paused_by,
+    paused_on,
+    reason,
+    reference,
+    resumed_by,
+    resumed_on,
+    skipped_by,
+    start_date
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.event_type,
+    new_row.amount,
+    new_row.amount_schedule,
+    new_row.chart_id,
+    new_row.created_by,
+    new_row.credit_account_id,
+    new_row.currency,
+    new_row.date,
+    new_row.debit_account_id,
+    new_row.description,
+    new_row.end_date,
+    new_row.frequency,
+    new_row.manual_transaction_ids,
+    new_row.paused_by,
+    new_row.paused_on,
+    new_row.reason,
+    new_row.reference,
+    new_row.resumed_by,
+    new_row.resumed_on,
+    new_row.skipped_by,
+    new_row.start_date
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+
+-- Auto-generated trigger for RecurringJournalEntryEvent
+CREATE TRIGGER core_recurring_journal_entry_events_rollup_trigger
+  AFTER INSERT ON core_recurring_journal_entry_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_recurring_journal_entry_events_rollup_trigger();
@@ -0,0 +1,167 @@
+-- Current table structure after migration:
+/*
+-- Auto-generated rollup table for CollateralEvent
+CREATE TABLE core_collateral_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  event_type TEXT NOT NULL,
+  -- Flattened fields from the event JSON
+  abs_diff BIGINT,
+  account_ids JSONB,
+  amount BIGINT,
+  collateral_amount BIGINT,
+  custody_wallet_id UUID,
+  direction VARCHAR,
+  facility_ledger_account_ids_for_liquidation JSONB,
+  liquidation_id UUID,
+  payment_id UUID,
+  secured_loan_id UUID,
+
+  -- Collection rollups
+  ledger_tx_ids UUID[]
+,
+  PRIMARY KEY (id, version)
+);
+
+*/
+
+-- Migration to update core_collateral_events_rollup table schema
+
+-- Auto-generated trigger function for CollateralEvent
+CREATE OR REPLACE FUNCTION core_collateral_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_collateral_events_rollup%ROWTYPE;
+  new_row core_collateral_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_collateral_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'updated_via_manual_input', 'updated_via_custodian_sync', 'updated_via_liquidation', 'liquidation_started', 'liquidation_proceeds_received', 'liquidation_completed', 'updated', 'top_up_intent_recorded') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+  new_row.event_type := NEW.event_type;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.abs_diff := (NEW.event ->> 'abs_diff')::BIGINT;
+    new_row.account_ids := (NEW.event -> 'account_ids');
+    new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+    new_row.collateral_amount := (NEW.event ->> 'collateral_amount')::BIGINT;
+    new_row.custody_wallet_id := (NEW.event ->> 'custody_wallet_id')::UUID;
+    new_row.direction := (NEW.event ->> 'direction');
+    new_row.facility_ledger_account_ids_for_liquidation := (NEW.event -> 'facility_ledger_account_ids_for_liquidation');
+    new_row.ledger_tx_ids := CASE
+       WHEN NEW.event ? 'ledger_tx_ids' THEN
+         ARRAY(SELECT value::text::UUID FROM jsonb_array_elements_text(NEW.event -> 'ledger_tx_ids'))
+       ELSE ARRAY[]::UUID[]
+     END
+;
+    new_row.liquidation_id := (NEW.event ->> 'liquidation_id')::UUID;
+    new_row.payment_id := (NEW.event ->> 'payment_id')::UUID;
+    new_row.secured_loan_id := (NEW.event ->> 'secured_loan_id')::UUID;
+  ELSE
+    -- Default all fields to current values
+    new_row.abs_diff := current_row.abs_diff;
+    new_row.account_ids := current_row.account_ids;
+    new_row.amount := current_row.amount;
+    new_row.collateral_amount := current_row.collateral_amount;
+    new_row.custody_wallet_id := current_row.custody_wallet_id;
+    new_row.direction := current_row.direction;
+    new_row.facility_ledger_account_ids_for_liquidation := current_row.facility_ledger_account_ids_for_liquidation;
+    new_row.ledger_tx_ids := current_row.ledger_tx_ids;
+    new_row.liquidation_id := current_row.liquidation_id;
+    new_row.payment_id := current_row.payment_id;
+    new_row.secured_loan_id := current_row.secured_loan_id;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.account_ids := (NEW.event -> 'account_ids');
+      new_row.custody_wallet_id := (NEW.event ->> 'custody_wallet_id')::UUID;
+      new_row.facility_ledger_account_ids_for_liquidation := (NEW.event -> 'facility_ledger_account_ids_for_liquidation');
+      new_row.secured_loan_id := (NEW.event ->> 'secured_loan_id')::UUID;
+    WHEN 'updated_via_manual_input' THEN
+      new_row.abs_diff := (NEW.event ->> 'abs_diff')::BIGINT;
+      new_row.collateral_amount := (NEW.event ->> 'collateral_amount')::BIGINT;
+      new_row.direction := (NEW.event ->> 'direction');
+    WHEN 'updated_via_custodian_sync' THEN
+      new_row.abs_diff := (NEW.event ->> 'abs_diff')::BIGINT;
+      new_row.collateral_amount := (NEW.event ->> 'collateral_amount')::BIGINT;
+      new_row.direction := (NEW.event ->> 'direction');
+    WHEN 'updated_via_liquidation' THEN
+      new_row.abs_diff := (NEW.event ->> 'abs_diff')::BIGINT;
+      new_row.collateral_amount := (NEW.event ->> 'collateral_amount')::BIGINT;
+      new_row.direction := (NEW.event ->> 'direction');
+      new_row.liquidation_id := (NEW.event ->> 'liquidation_id')::UUID;
+    WHEN 'liquidation_started' THEN
+      new_row.liquidation_id := (NEW.event ->> 'liquidation_id')::UUID;
+    WHEN 'liquidation_proceeds_received' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.liquidation_id := (NEW.event ->> 'liquidation_id')::UUID;
+      new_row.payment_id := (NEW.event ->> 'payment_id')::UUID;
+    WHEN 'liquidation_completed' THEN
+      new_row.liquidation_id := (NEW.event ->> 'liquidation_id')::UUID;
+    WHEN 'updated' THEN
+      new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
+    WHEN 'top_up_intent_recorded' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+  END CASE;
+
+  INSERT INTO core_collateral_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    event_type,
+    abs_diff,
+    account_ids,
+    amount,
+    collateral_amount,
+    custody_wallet_id,
+    direction,
+    facility_ledger_account_ids_for_liquidation,
+    ledger_tx_ids,
+    liquidation_id,
+    payment_id,
+    secured_loan_id
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.event_type,
+    new_row.abs_diff,
+    new_row.account_ids,
+    new_row.amount,
+    new_row.collateral_amount,
+    new_row.custody_wallet_id,
+    new_row.direction,
+    new_row.facility_ledger_account_ids_for_liquidation,
+    new_row.ledger_tx_ids,
+    new_row.liquidation_id,
+    new_row.payment_id,
+    new_row.secured_loan_id
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
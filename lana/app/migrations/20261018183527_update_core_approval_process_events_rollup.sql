@@ -0,0 +1,233 @@
+-- Current table structure after migration:
+/*
+-- Auto-generated rollup table for ApprovalProcessEvent
+CREATE TABLE core_approval_process_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  event_type TEXT NOT NULL,
+  -- Flattened fields from the event JSON
+  amount BIGINT,
+  approved BOOLEAN,
+  committee_id UUID,
+  day VARCHAR,
+  expired BOOLEAN,
+  expires_at TIMESTAMPTZ,
+  policy_id UUID,
+  process_type VARCHAR,
+  rules JSONB,
+  stage BIGINT,
+  target_ref VARCHAR,
+  threshold BIGINT,
+  voting_deadline JSONB,
+
+  -- Collection rollups
+  approver_ids UUID[],
+  denier_ids UUID[],
+  deny_reasons VARCHAR[],
+  recipients UUID[],
+
+  -- Toggle fields
+  is_concluded BOOLEAN DEFAULT false
+,
+  PRIMARY KEY (id, version)
+);
+
+*/
+
+-- Migration to update core_approval_process_events_rollup table schema
+
+-- Add new columns
+ALTER TABLE core_approval_process_events_rollup ADD COLUMN IF NOT EXISTS committee_id UUID;
+ALTER TABLE core_approval_process_events_rollup ADD COLUMN IF NOT EXISTS day VARCHAR;
+ALTER TABLE core_approval_process_events_rollup ADD COLUMN IF NOT EXISTS expired BOOLEAN;
+ALTER TABLE core_approval_process_events_rollup ADD COLUMN IF NOT EXISTS expires_at TIMESTAMPTZ;
+ALTER TABLE core_approval_process_events_rollup ADD COLUMN IF NOT EXISTS recipients UUID[];
+ALTER TABLE core_approval_process_events_rollup ADD COLUMN IF NOT EXISTS threshold BIGINT;
+ALTER TABLE core_approval_process_events_rollup ADD COLUMN IF NOT EXISTS voting_deadline JSONB;
+
+
+-- Auto-generated trigger function for ApprovalProcessEvent
+CREATE OR REPLACE FUNCTION core_approval_process_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_approval_process_events_rollup%ROWTYPE;
+  new_row core_approval_process_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_approval_process_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'approved', 'denied', 'stage_approved', 'escalated', 'reminder_sent', 'concluded') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+  new_row.event_type := NEW.event_type;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+    new_row.approved := (NEW.event ->> 'approved')::BOOLEAN;
+    new_row.approver_ids := CASE
+       WHEN NEW.event ? 'approver_ids' THEN
+         ARRAY(SELECT value::text::UUID FROM jsonb_array_elements_text(NEW.event -> 'approver_ids'))
+       ELSE ARRAY[]::UUID[]
+     END
+;
+    new_row.committee_id := (NEW.event ->> 'committee_id')::UUID;
+    new_row.day := (NEW.event ->> 'day');
+    new_row.denier_ids := CASE
+       WHEN NEW.event ? 'denier_ids' THEN
+         ARRAY(SELECT value::text::UUID FROM jsonb_array_elements_text(NEW.event -> 'denier_ids'))
+       ELSE ARRAY[]::UUID[]
+     END
+;
+    new_row.deny_reasons := CASE
+       WHEN NEW.event ? 'deny_reasons' THEN
+         ARRAY(SELECT value::text FROM jsonb_array_elements_text(NEW.event -> 'deny_reasons'))
+       ELSE ARRAY[]::VARCHAR[]
+     END
+;
+    new_row.expired := (NEW.event ->> 'expired')::BOOLEAN;
+    new_row.expires_at := (NEW.event ->> 'expires_at')::TIMESTAMPTZ;
+    new_row.is_concluded := false;
+    new_row.policy_id := (NEW.event ->> 'policy_id')::UUID;
+    new_row.process_type := (NEW.event ->> 'process_type');
+    new_row.recipients := CASE
+       WHEN NEW.event ? 'recipients' THEN
+         ARRAY(SELECT value::text::UUID FROM jsonb_array_elements_text(NEW.event -> 'recipients'))
+       ELSE ARRAY[]::UUID[]
+     END
+;
+    new_row.rules := (NEW.event -> 'rules');
+    new_row.stage := (NEW.event ->> 'stage')::BIGINT;
+    new_row.target_ref := (NEW.event ->> 'target_ref');
+    new_row.threshold := (NEW.event ->> 'threshold')::BIGINT;
+    new_row.voting_deadline := (NEW.event -> 'voting_deadline');
+  ELSE
+    -- Default all fields to current values
+    new_row.amount := current_row.amount;
+    new_row.approved := current_row.approved;
+    new_row.approver_ids := current_row.approver_ids;
+    new_row.committee_id := current_row.committee_id;
+    new_row.day := current_row.day;
+    new_row.denier_ids := current_row.denier_ids;
+    new_row.deny_reasons := current_row.deny_reasons;
+    new_row.expired := current_row.expired;
+    new_row.expires_at := current_row.expires_at;
+    new_row.is_concluded := current_row.is_concluded;
+    new_row.policy_id := current_row.policy_id;
+    new_row.process_type := current_row.process_type;
+    new_row.recipients := current_row.recipients;
+    new_row.rules := current_row.rules;
+    new_row.stage := current_row.stage;
+    new_row.target_ref := current_row.target_ref;
+    new_row.threshold := current_row.threshold;
+    new_row.voting_deadline := current_row.voting_deadline;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.expires_at := (NEW.event ->> 'expires_at')::TIMESTAMPTZ;
+      new_row.policy_id := (NEW.event ->> 'policy_id')::UUID;
+      new_row.process_type := (NEW.event ->> 'process_type');
+      new_row.rules := (NEW.event -> 'rules');
+      new_row.target_ref := (NEW.event ->> 'target_ref');
+      new_row.voting_deadline := (NEW.event -> 'voting_deadline');
+    WHEN 'approved' THEN
+      new_row.approver_ids := array_append(COALESCE(current_row.approver_ids, ARRAY[]::UUID[]), (NEW.event ->> 'approver_id')::UUID);
+      new_row.stage := (NEW.event ->> 'stage')::BIGINT;
+    WHEN 'denied' THEN
+      new_row.denier_ids := array_append(COALESCE(current_row.denier_ids, ARRAY[]::UUID[]), (NEW.event ->> 'denier_id')::UUID);
+      new_row.deny_reasons := array_append(COALESCE(current_row.deny_reasons, ARRAY[]::VARCHAR[]), (NEW.event ->> 'reason'));
+      new_row.stage := (NEW.event ->> 'stage')::BIGINT;
+    WHEN 'stage_approved' THEN
+      new_row.stage := (NEW.event ->> 'stage')::BIGINT;
+    WHEN 'escalated' THEN
+      new_row.committee_id := (NEW.event ->> 'committee_id')::UUID;
+      new_row.expires_at := (NEW.event ->> 'expires_at')::TIMESTAMPTZ;
+      new_row.stage := (NEW.event ->> 'stage')::BIGINT;
+      new_row.threshold := (NEW.event ->> 'threshold')::BIGINT;
+    WHEN 'reminder_sent' THEN
+      new_row.day := (NEW.event ->> 'day');
+      new_row.recipients := CASE
+       WHEN NEW.event ? 'recipients' THEN
+         ARRAY(SELECT value::text::UUID FROM jsonb_array_elements_text(NEW.event -> 'recipients'))
+       ELSE new_row.recipients
+     END
+;
+    WHEN 'concluded' THEN
+      new_row.approved := (NEW.event ->> 'approved')::BOOLEAN;
+      new_row.expired := (NEW.event ->> 'expired')::BOOLEAN;
+      new_row.is_concluded := true;
+  END CASE;
+
+  INSERT INTO core_approval_process_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    event_type,
+    amount,
+    approved,
+    approver_ids,
+    committee_id,
+    day,
+    denier_ids,
+    deny_reasons,
+    expired,
+    expires_at,
+    is_concluded,
+    policy_id,
+    process_type,
+    recipients,
+    rules,
+    stage,
+    target_ref,
+    threshold,
+    voting_deadline
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.event_type,
+    new_row.amount,
+    new_row.approved,
+    new_row.approver_ids,
+    new_row.committee_id,
+    new_row.day,
+    new_row.denier_ids,
+    new_row.deny_reasons,
+    new_row.expired,
+    new_row.expires_at,
+    new_row.is_concluded,
+    new_row.policy_id,
+    new_row.process_type,
+    new_row.recipients,
+    new_row.rules,
+    new_row.stage,
+    new_row.target_ref,
+    new_row.threshold,
+    new_row.voting_deadline
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
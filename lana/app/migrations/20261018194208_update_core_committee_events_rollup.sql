@@ -0,0 +1,139 @@
+-- Current table structure after migration:
+/*
+-- Auto-generated rollup table for CommitteeEvent
+CREATE TABLE core_committee_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  event_type TEXT NOT NULL,
+  -- Flattened fields from the event JSON
+  delegate_id UUID,
+  delegation_id UUID,
+  delegator_id UUID,
+  ends_on VARCHAR,
+  name VARCHAR,
+  starts_on VARCHAR,
+
+  -- Collection rollups
+  member_ids UUID[]
+,
+  PRIMARY KEY (id, version)
+);
+
+*/
+
+-- Migration to update core_committee_events_rollup table schema
+
+-- Add new columns
+ALTER TABLE core_committee_events_rollup ADD COLUMN IF NOT EXISTS delegate_id UUID;
+ALTER TABLE core_committee_events_rollup ADD COLUMN IF NOT EXISTS delegation_id UUID;
+ALTER TABLE core_committee_events_rollup ADD COLUMN IF NOT EXISTS delegator_id UUID;
+ALTER TABLE core_committee_events_rollup ADD COLUMN IF NOT EXISTS ends_on VARCHAR;
+ALTER TABLE core_committee_events_rollup ADD COLUMN IF NOT EXISTS starts_on VARCHAR;
+
+
+-- Auto-generated trigger function for CommitteeEvent
+CREATE OR REPLACE FUNCTION core_committee_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_committee_events_rollup%ROWTYPE;
+  new_row core_committee_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_committee_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'member_added', 'member_removed', 'vote_delegated', 'delegation_revoked') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+  new_row.event_type := NEW.event_type;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.delegate_id := (NEW.event ->> 'delegate_id')::UUID;
+    new_row.delegation_id := (NEW.event ->> 'delegation_id')::UUID;
+    new_row.delegator_id := (NEW.event ->> 'delegator_id')::UUID;
+    new_row.ends_on := (NEW.event ->> 'ends_on');
+    new_row.member_ids := CASE
+       WHEN NEW.event ? 'member_ids' THEN
+         ARRAY(SELECT value::text::UUID FROM jsonb_array_elements_text(NEW.event -> 'member_ids'))
+       ELSE ARRAY[]::UUID[]
+     END
+;
+    new_row.name := (NEW.event ->> 'name');
+    new_row.starts_on := (NEW.event ->> 'starts_on');
+  ELSE
+    -- Default all fields to current values
+    new_row.delegate_id := current_row.delegate_id;
+    new_row.delegation_id := current_row.delegation_id;
+    new_row.delegator_id := current_row.delegator_id;
+    new_row.ends_on := current_row.ends_on;
+    new_row.member_ids := current_row.member_ids;
+    new_row.name := current_row.name;
+    new_row.starts_on := current_row.starts_on;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.name := (NEW.event ->> 'name');
+    WHEN 'member_added' THEN
+      new_row.member_ids := array_append(COALESCE(current_row.member_ids, ARRAY[]::UUID[]), (NEW.event ->> 'member_id')::UUID);
+    WHEN 'member_removed' THEN
+      new_row.member_ids := array_remove(COALESCE(current_row.member_ids, ARRAY[]::UUID[]), (NEW.event ->> 'member_id')::UUID);
+    WHEN 'vote_delegated' THEN
+      new_row.delegate_id := (NEW.event ->> 'delegate_id')::UUID;
+      new_row.delegation_id := (NEW.event ->> 'delegation_id')::UUID;
+      new_row.delegator_id := (NEW.event ->> 'delegator_id')::UUID;
+      new_row.ends_on := (NEW.event ->> 'ends_on');
+      new_row.starts_on := (NEW.event ->> 'starts_on');
+    WHEN 'delegation_revoked' THEN
+      new_row.delegation_id := (NEW.event ->> 'delegation_id')::UUID;
+  END CASE;
+
+  INSERT INTO core_committee_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    event_type,
+    delegate_id,
+    delegation_id,
+    delegator_id,
+    ends_on,
+    member_ids,
+    name,
+    starts_on
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.event_type,
+    new_row.delegate_id,
+    new_row.delegation_id,
+    new_row.delegator_id,
+    new_row.ends_on,
+    new_row.member_ids,
+    new_row.name,
+    new_row.starts_on
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,126 @@
+-- Tamper-evident audit log: every entry carries a SHA-256 hash over its content
+-- and the hash of its predecessor. Signed checkpoints are written by the
+-- application every `checkpoint_interval` entries.
+--
+-- Entries are inserted unsealed and linked into the chain after they commit by
+-- `audit_entries_seal`, so writers inside long transactions never wait on each
+-- other and the chain only ever links committed entries. Existing entries are
+-- sealed in batches by the application's sealing job.
+
+ALTER TABLE audit_entries
+  ADD COLUMN chain_seq BIGINT,
+  ADD COLUMN previous_hash BYTEA,
+  ADD COLUMN hash BYTEA;
+
+CREATE UNIQUE INDEX idx_audit_entries_chain_seq ON audit_entries(chain_seq);
+CREATE INDEX idx_audit_entries_unsealed ON audit_entries(id) WHERE chain_seq IS NULL;
+
+-- Must stay in sync with `entry_hash` in lib/audit/src/chain.rs
+CREATE FUNCTION audit_entry_hash(
+  previous_hash BYTEA,
+  chain_seq BIGINT,
+  subject VARCHAR,
+  object VARCHAR,
+  action VARCHAR,
+  authorized BOOLEAN,
+  recorded_at TIMESTAMPTZ
+) RETURNS BYTEA AS $$
+  SELECT sha256(
+    previous_hash
+    || int8send(chain_seq)
+    || int8send(octet_length(subject)::BIGINT) || convert_to(subject, 'UTF8')
+    || int8send(octet_length(object)::BIGINT) || convert_to(object, 'UTF8')
+    || int8send(octet_length(action)::BIGINT) || convert_to(action, 'UTF8')
+    || CASE WHEN authorized THEN '\x01'::BYTEA ELSE '\x00'::BYTEA END
+    || int8send(
+      EXTRACT(EPOCH FROM date_trunc('second', recorded_at))::BIGINT * 1000000
+      + EXTRACT(MICROSECONDS FROM recorded_at)::BIGINT % 1000000
+    )
+  )
+$$ LANGUAGE sql IMMUTABLE;
+
+-- Links up to `batch_size` committed, unsealed entries to the chain head and
+-- returns them in chain order. Concurrent sealers are serialized by an
+-- advisory lock that only sealers take.
+CREATE FUNCTION audit_entries_seal(batch_size INT)
+RETURNS TABLE (sealed_chain_seq BIGINT, sealed_hash BYTEA) AS $$
+DECLARE
+  entry RECORD;
+  seq BIGINT;
+  prev BYTEA;
+BEGIN
+  PERFORM pg_advisory_xact_lock(hashtext('audit_entries_chain'));
+
+  SELECT e.chain_seq, e.hash INTO seq, prev
+  FROM audit_entries e
+  WHERE e.chain_seq IS NOT NULL
+  ORDER BY e.chain_seq DESC
+  LIMIT 1;
+  seq := COALESCE(seq, 0);
+  prev := COALESCE(prev, decode(repeat('00', 32), 'hex'));
+
+  FOR entry IN
+    SELECT e.id, e.subject, e.object, e.action, e.authorized, e.recorded_at
+    FROM audit_entries e
+    WHERE e.chain_seq IS NULL
+    ORDER BY e.id
+    LIMIT batch_size
+  LOOP
+    seq := seq + 1;
+    UPDATE audit_entries e
+    SET chain_seq = seq,
+        previous_hash = prev,
+        hash = audit_entry_hash(
+          prev, seq, entry.subject, entry.object, entry.action, entry.authorized, entry.recorded_at
+        )
+    WHERE e.id = entry.id
+    RETURNING e.hash INTO prev;
+
+    sealed_chain_seq := seq;
+    sealed_hash := prev;
+    RETURN NEXT;
+  END LOOP;
+END;
+$$ LANGUAGE plpgsql;
+
+-- The only permitted change is sealing an entry: setting its chain columns once
+-- while leaving the recorded content untouched.
+CREATE FUNCTION audit_entries_reject_changes() RETURNS TRIGGER AS $$
+BEGIN
+  IF TG_OP = 'UPDATE'
+    AND OLD.chain_seq IS NULL
+    AND NEW.chain_seq IS NOT NULL
+    AND NEW.previous_hash IS NOT NULL
+    AND NEW.hash IS NOT NULL
+    AND (NEW.id, NEW.subject, NEW.object, NEW.action, NEW.authorized, NEW.recorded_at)
+      IS NOT DISTINCT FROM
+      (OLD.id, OLD.subject, OLD.object, OLD.action, OLD.authorized, OLD.recorded_at)
+  THEN
+    RETURN NEW;
+  END IF;
+  RAISE EXCEPTION 'audit_entries is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER audit_entries_append_only_trigger
+BEFORE UPDATE OR DELETE ON audit_entries
+FOR EACH ROW
+EXECUTE FUNCTION audit_entries_reject_changes();
+
+CREATE TABLE audit_checkpoints (
+  chain_seq BIGINT PRIMARY KEY,
+  hash BYTEA NOT NULL,
+  signature BYTEA NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE FUNCTION audit_checkpoints_reject_changes() RETURNS TRIGGER AS $$
+BEGIN
+  RAISE EXCEPTION 'audit_checkpoints is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER audit_checkpoints_append_only_trigger
+BEFORE UPDATE OR DELETE ON audit_checkpoints
+FOR EACH ROW
+EXECUTE FUNCTION audit_checkpoints_reject_changes();
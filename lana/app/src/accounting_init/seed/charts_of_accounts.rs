@@ -11,9 +11,19 @@ pub(crate) async fn init(
     deposit: &Deposits,
     accounting_init_config: AccountingInitConfig,
 ) -> Result<(), AccountingInitError> {
-    create_chart_of_accounts(accounting, accounting_init_config.clone()).await?;
+    Box::pin(create_chart_of_accounts(
+        accounting,
+        accounting_init_config.clone(),
+    ))
+    .await?;
 
-    seed_chart_of_accounts(accounting, credit, deposit, accounting_init_config).await?;
+    Box::pin(seed_chart_of_accounts(
+        accounting,
+        credit,
+        deposit,
+        accounting_init_config,
+    ))
+    .await?;
 
     Ok(())
 }
@@ -79,7 +89,8 @@ async fn seed_chart_of_accounts(
         None => return Ok(()),
     };
 
-    let chart = accounting
+    let cash_flow_mapping = accounting_integration_config.cash_flow_mapping.clone();
+    let mut chart = accounting
         .import_csv_with_base_config(
             &Subject::System(audit::SystemActor::BOOTSTRAP),
             CHART_REF,
@@ -91,8 +102,20 @@ async fn seed_chart_of_accounts(
         )
         .await?;
 
+    // Charts configured before the mapping was part of the base config pick it up here.
+    if !cash_flow_mapping.is_empty() {
+        chart = accounting
+            .chart_of_accounts()
+            .update_cash_flow_mapping(
+                &Subject::System(audit::SystemActor::BOOTSTRAP),
+                CHART_REF,
+                cash_flow_mapping,
+            )
+            .await?;
+    }
+
     if let Some(config_path) = credit_config_path {
-        credit_module_configure(credit, &chart, config_path)
+        Box::pin(credit_module_configure(credit, &chart, config_path))
             .await
             .unwrap_or_else(|e| {
                 dbg!(&e); // TODO: handle the un-returned error differently
@@ -100,7 +123,7 @@ async fn seed_chart_of_accounts(
     }
 
     if let Some(config_path) = deposit_config_path {
-        deposit_module_configure(deposit, &chart, config_path)
+        Box::pin(deposit_module_configure(deposit, &chart, config_path))
             .await
             .unwrap_or_else(|e| {
                 dbg!(&e); // TODO: handle the un-returned error differently
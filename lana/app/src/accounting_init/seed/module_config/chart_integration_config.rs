@@ -1,7 +1,7 @@
 use serde::Deserialize;
 use std::path::PathBuf;
 
-use core_accounting::AccountingBaseConfig;
+use core_accounting::{AccountingBaseConfig, CashFlowMapping};
 
 use crate::accounting_init::error::AccountingInitError;
 
@@ -15,6 +15,8 @@ struct AccountingBaseConfigData {
     revenue_code: String,
     cost_of_revenue_code: String,
     expenses_code: String,
+    #[serde(default)]
+    cash_flow_mapping: Vec<CashFlowMapping>,
 }
 
 impl TryFrom<AccountingBaseConfigData> for AccountingBaseConfig {
@@ -30,7 +32,8 @@ impl TryFrom<AccountingBaseConfigData> for AccountingBaseConfig {
             data.revenue_code.parse()?,
             data.cost_of_revenue_code.parse()?,
             data.expenses_code.parse()?,
-        )?)
+        )?
+        .with_cash_flow_mapping(data.cash_flow_mapping)?)
     }
 }
 
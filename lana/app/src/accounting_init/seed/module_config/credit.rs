@@ -63,6 +63,16 @@ struct CreditConfigData {
     overdue_financial_institution_disbursed_receivable_parent_code: String,
     overdue_foreign_agency_or_subsidiary_disbursed_receivable_parent_code: String,
     overdue_non_domiciled_company_disbursed_receivable_parent_code: String,
+    #[serde(default)]
+    penalty_receivable_parent_code: Option<String>,
+    #[serde(default)]
+    penalty_defaulted_parent_code: Option<String>,
+    #[serde(default)]
+    penalty_income_parent_code: Option<String>,
+    #[serde(default)]
+    loan_loss_expense_parent_code: Option<String>,
+    #[serde(default)]
+    recovery_income_parent_code: Option<String>,
 }
 
 pub(in crate::accounting_init::seed) async fn credit_module_configure(
@@ -123,6 +133,11 @@ pub(in crate::accounting_init::seed) async fn credit_module_configure(
         overdue_financial_institution_disbursed_receivable_parent_code,
         overdue_foreign_agency_or_subsidiary_disbursed_receivable_parent_code,
         overdue_non_domiciled_company_disbursed_receivable_parent_code,
+        penalty_receivable_parent_code,
+        penalty_defaulted_parent_code,
+        penalty_income_parent_code,
+        loan_loss_expense_parent_code,
+        recovery_income_parent_code,
     } = serde_json::from_str(&data)?;
 
     let config_values = ChartOfAccountsIntegrationConfig {
@@ -221,6 +236,21 @@ pub(in crate::accounting_init::seed) async fn credit_module_configure(
             overdue_foreign_agency_or_subsidiary_disbursed_receivable_parent_code.parse()?,
         chart_of_account_overdue_non_domiciled_company_disbursed_receivable_parent_code:
             overdue_non_domiciled_company_disbursed_receivable_parent_code.parse()?,
+        chart_of_account_penalty_receivable_parent_code: penalty_receivable_parent_code
+            .map(|code| code.parse())
+            .transpose()?,
+        chart_of_account_penalty_defaulted_parent_code: penalty_defaulted_parent_code
+            .map(|code| code.parse())
+            .transpose()?,
+        chart_of_account_penalty_income_parent_code: penalty_income_parent_code
+            .map(|code| code.parse())
+            .transpose()?,
+        chart_of_account_loan_loss_expense_parent_code: loan_loss_expense_parent_code
+            .map(|code| code.parse())
+            .transpose()?,
+        chart_of_account_recovery_income_parent_code: recovery_income_parent_code
+            .map(|code| code.parse())
+            .transpose()?,
     };
 
     match credit
@@ -25,6 +25,10 @@ struct DepositConfigData {
     frozen_bank_deposit_accounts_parent_code: String,
     frozen_financial_institution_deposit_accounts_parent_code: String,
     frozen_non_domiciled_company_deposit_accounts_parent_code: String,
+    #[serde(default)]
+    interest_expense_parent_code: Option<String>,
+    #[serde(default)]
+    unclaimed_funds_parent_code: Option<String>,
 }
 
 pub(in crate::accounting_init::seed) async fn deposit_module_configure(
@@ -47,6 +51,8 @@ pub(in crate::accounting_init::seed) async fn deposit_module_configure(
         frozen_bank_deposit_accounts_parent_code,
         frozen_financial_institution_deposit_accounts_parent_code,
         frozen_non_domiciled_company_deposit_accounts_parent_code,
+        interest_expense_parent_code,
+        unclaimed_funds_parent_code,
     } = serde_json::from_str(&data)?;
 
     let config_values = ChartOfAccountsIntegrationConfig {
@@ -76,6 +82,12 @@ pub(in crate::accounting_init::seed) async fn deposit_module_configure(
             frozen_financial_institution_deposit_accounts_parent_code.parse()?,
         chart_of_account_frozen_non_domiciled_company_deposit_accounts_parent_code:
             frozen_non_domiciled_company_deposit_accounts_parent_code.parse()?,
+        chart_of_accounts_interest_expense_parent_code: interest_expense_parent_code
+            .map(|code| code.parse())
+            .transpose()?,
+        chart_of_accounts_unclaimed_funds_parent_code: unclaimed_funds_parent_code
+            .map(|code| code.parse())
+            .transpose()?,
     };
 
     match deposit
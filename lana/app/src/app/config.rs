@@ -3,10 +3,10 @@ use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
 use crate::{
-    access::config::AccessConfig, custody::CustodyConfig, customer_sync::CustomerSyncConfig,
-    encryption::EncryptionConfig, gotenberg::GotenbergConfig, job::JobPollerConfig,
-    notification::NotificationConfig, price::PriceConfig, report::ReportConfig,
-    storage::config::StorageConfig, user_onboarding::UserOnboardingConfig,
+    access::config::AccessConfig, audit::AuditConfig, custody::CustodyConfig,
+    customer_sync::CustomerSyncConfig, encryption::EncryptionConfig, gotenberg::GotenbergConfig,
+    job::JobPollerConfig, notification::NotificationConfig, price::PriceConfig,
+    report::ReportConfig, storage::config::StorageConfig, user_onboarding::UserOnboardingConfig,
 };
 
 #[derive(Clone, Default, Debug, Deserialize, Serialize)]
@@ -36,6 +36,8 @@ pub struct AppConfig {
     pub gotenberg: GotenbergConfig,
     #[serde(default)]
     pub encryption: EncryptionConfig,
+    #[serde(default)]
+    pub audit: AuditConfig,
 }
 
 #[derive(Clone, Default, Debug, Deserialize, Serialize)]
@@ -14,8 +14,12 @@ pub enum ApplicationError {
     CustomerError(#[from] crate::customer::error::CustomerError),
     #[error("ApplicationError - CustomerSyncError: {0}")]
     CustomerSyncError(#[from] customer_sync::error::CustomerSyncError),
+    #[error("ApplicationError - AccountingSyncError: {0}")]
+    AccountingSyncError(#[from] accounting_sync::error::AccountingSyncError),
     #[error("ApplicationError - DepositSyncError: {0}")]
     DepositSyncError(#[from] deposit_sync::error::DepositSyncError),
+    #[error("ApplicationError - GovernanceSyncError: {0}")]
+    GovernanceSyncError(#[from] governance_sync::error::GovernanceSyncError),
     #[error("ApplicationError - NotificationError: {0}")]
     NotificationError(#[from] notification::error::NotificationError),
     #[error("ApplicationError - CreditFacilityError: {0}")]
@@ -40,6 +44,8 @@ pub enum ApplicationError {
     PriceError(#[from] crate::price::error::PriceError),
     #[error("ApplicationError - TimeEventsError: {0}")]
     TimeEventsError(#[from] crate::time_events::error::TimeEventsError),
+    #[error("ApplicationError - CoreAccountingError: {0}")]
+    CoreAccountingError(#[from] core_accounting::error::CoreAccountingError),
     #[error("ApplicationError - AccountingInitError: {0}")]
     AccountingInitError(#[from] crate::accounting_init::error::AccountingInitError),
     #[error("ApplicationError - GovernanceError: {0}")]
@@ -50,6 +56,8 @@ pub enum ApplicationError {
     CalaError(#[from] cala_ledger::error::LedgerError),
     #[error("ApplicationError - ChartOfAccountsError: {0}")]
     ChartOfAccountsError(#[from] core_accounting::chart_of_accounts::error::ChartOfAccountsError),
+    #[error("ApplicationError - TermsTemplateError: {0}")]
+    TermsTemplateError(#[from] crate::terms_template::TermsTemplateError),
     #[error("ApplicationError - DepositError: {0}")]
     DepositError(#[from] crate::deposit::error::CoreDepositError),
     #[error("ApplicationError - StorageError: {0}")]
@@ -60,6 +68,8 @@ pub enum ApplicationError {
     CustodyError(#[from] crate::custody::error::CoreCustodyError),
     #[error("ApplicationError - ContractCreationError: {0}")]
     ContractCreationError(#[from] crate::contract_creation::ContractCreationError),
+    #[error("ApplicationError - AccountStatementError: {0}")]
+    AccountStatementError(#[from] crate::account_statement::AccountStatementError),
     #[error("ApplicationError - ReportError: {0}")]
     ReportError(#[from] crate::report::error::ReportError),
     #[error("ApplicationError - TracingError: {0}")]
@@ -78,7 +88,9 @@ impl ErrorSeverity for ApplicationError {
             Self::JobError(_) => Level::ERROR,
             Self::CustomerError(e) => e.severity(),
             Self::CustomerSyncError(e) => e.severity(),
+            Self::AccountingSyncError(e) => e.severity(),
             Self::DepositSyncError(e) => e.severity(),
+            Self::GovernanceSyncError(e) => e.severity(),
             Self::NotificationError(e) => e.severity(),
             Self::CreditFacilityError(e) => e.severity(),
             Self::TrialBalanceError(e) => e.severity(),
@@ -90,16 +102,19 @@ impl ErrorSeverity for ApplicationError {
             Self::DomainConfigError(e) => e.severity(),
             Self::AuditError(e) => e.severity(),
             Self::PriceError(e) => e.severity(),
+            Self::CoreAccountingError(e) => e.severity(),
             Self::AccountingInitError(e) => e.severity(),
             Self::GovernanceError(e) => e.severity(),
             Self::DashboardError(e) => e.severity(),
             Self::CalaError(_) => Level::ERROR,
             Self::ChartOfAccountsError(e) => e.severity(),
+            Self::TermsTemplateError(e) => e.severity(),
             Self::DepositError(e) => e.severity(),
             Self::StorageError(e) => e.severity(),
             Self::KycError(e) => e.severity(),
             Self::CustodyError(e) => e.severity(),
             Self::ContractCreationError(e) => e.severity(),
+            Self::AccountStatementError(e) => e.severity(),
             Self::ReportError(e) => e.severity(),
             Self::TracingError(e) => e.severity(),
             Self::CanNotCreateProposalForClosedOrFrozenAccount => Level::WARN,
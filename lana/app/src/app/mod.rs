@@ -12,9 +12,11 @@ use rbac_types::{AuditAction, AuditEntryAction, AuditObject};
 
 use crate::{
     access::Access,
+    account_statement::AccountStatements,
     accounting::Accounting,
     accounting_init::{ChartsInit, JournalInit, StatementsInit},
-    audit::{Audit, AuditCursor, AuditEntry},
+    accounting_sync::AccountingSync,
+    audit::{Audit, AuditChainVerification, AuditCursor, AuditEntry},
     authorization::{Authorization, seed},
     contract_creation::ContractCreation,
     credit::Credit,
@@ -27,6 +29,7 @@ use crate::{
     document::DocumentStorage,
     domain_config::{ExposedDomainConfigs, ExposedDomainConfigsReadOnly, InternalDomainConfigs},
     governance::Governance,
+    governance_sync::GovernanceSync,
     job::Jobs,
     kyc::CustomerKyc,
     notification::Notification,
@@ -64,6 +67,7 @@ pub struct LanaApp {
     dashboard: Dashboard,
     public_ids: PublicIds,
     contract_creation: ContractCreation,
+    account_statements: AccountStatements,
     reports: Reports,
     terms_templates: TermsTemplates,
     storage: Storage,
@@ -71,6 +75,8 @@ pub struct LanaApp {
     _user_onboarding: UserOnboarding,
     _customer_sync: CustomerSync,
     _deposit_sync: DepositSync,
+    _accounting_sync: AccountingSync,
+    _governance_sync: GovernanceSync,
 }
 
 impl LanaApp {
@@ -87,7 +93,7 @@ impl LanaApp {
             .instrument(tracing::info_span!("lana_app.migrations"))
             .await?;
 
-        let audit = Audit::new(&pool);
+        let audit = Audit::with_config(&pool, config.audit);
         let outbox = Outbox::init(
             &pool,
             obix::MailboxConfig::builder()
@@ -128,12 +134,15 @@ impl LanaApp {
         )
         .await?;
 
+        crate::audit_chain::init(&mut jobs, &audit).await?;
+
         let dashboard = Dashboard::init(&pool, &authz, &mut jobs, &outbox).await?;
         let governance = Governance::new(&pool, &authz, &outbox, clock.clone());
+        let governance_sync = GovernanceSync::init(&mut jobs, &outbox, &governance).await?;
         let storage = Storage::new(&config.storage);
         let reports =
             Reports::init(&pool, &authz, config.report, &outbox, &storage, &mut jobs).await?;
-        let price = Price::init(config.price, &mut jobs, &outbox).await?;
+        let price = Price::init(&pool, config.price, &mut jobs, &outbox, clock.clone()).await?;
         let _time_events =
             TimeEvents::init(&exposed_domain_configs_readonly, &mut jobs, &outbox).await?;
         let documents = DocumentStorage::new(&pool, &storage, clock.clone());
@@ -149,7 +158,7 @@ impl LanaApp {
             .expect("cala config");
         let cala = cala_ledger::CalaLedger::init(cala_config).await?;
         let journal_init = JournalInit::journal(&cala).await?;
-        let accounting = Accounting::new(
+        let accounting = Accounting::init(
             &pool,
             &authz,
             &cala,
@@ -157,9 +166,12 @@ impl LanaApp {
             documents.clone(),
             &mut jobs,
             &outbox,
-        );
+            &governance,
+        )
+        .await?;
 
         StatementsInit::statements(&accounting).await?;
+        let accounting_sync = AccountingSync::init(&mut jobs, &outbox, &accounting).await?;
 
         let customers = Customers::new(
             &pool,
@@ -242,7 +254,7 @@ impl LanaApp {
             TermsTemplates::new(&pool, std::sync::Arc::new(authz.clone()), clock.clone());
 
         let contract_creation = ContractCreation::new(
-            config.gotenberg,
+            config.gotenberg.clone(),
             &customers,
             &customer_kyc,
             &documents,
@@ -250,6 +262,18 @@ impl LanaApp {
             &authz,
         );
 
+        let account_statements = AccountStatements::init(
+            config.gotenberg,
+            &mut jobs,
+            &outbox,
+            &deposits,
+            &credit,
+            &customers,
+            &documents,
+            &authz,
+        )
+        .await?;
+
         Notification::init(
             config.notification,
             &mut jobs,
@@ -261,8 +285,13 @@ impl LanaApp {
         )
         .await?;
 
-        ChartsInit::charts_of_accounts(&accounting, &credit, &deposits, config.accounting_init)
-            .await?;
+        Box::pin(ChartsInit::charts_of_accounts(
+            &accounting,
+            &credit,
+            &deposits,
+            config.accounting_init,
+        ))
+        .await?;
 
         jobs.start_poll().await?;
 
@@ -285,6 +314,7 @@ impl LanaApp {
             dashboard,
             public_ids,
             contract_creation,
+            account_statements,
             reports,
             terms_templates,
             storage,
@@ -292,6 +322,8 @@ impl LanaApp {
             _user_onboarding: user_onboarding,
             _customer_sync: customer_sync,
             _deposit_sync: deposit_sync,
+            _accounting_sync: accounting_sync,
+            _governance_sync: governance_sync,
         })
     }
 
@@ -383,6 +415,25 @@ impl LanaApp {
         Ok(self.audit.list_subjects().await?)
     }
 
+    #[record_error_severity]
+    #[instrument(name = "lana.audit.verify_audit_chain", skip(self))]
+    pub async fn verify_audit_chain(
+        &self,
+        sub: &Subject,
+    ) -> Result<AuditChainVerification, ApplicationError> {
+        use crate::audit::AuditSvc;
+
+        self.authz
+            .enforce_permission(
+                sub,
+                AuditObject::all_audits(),
+                AuditAction::from(AuditEntryAction::Verify),
+            )
+            .await?;
+
+        Ok(self.audit.verify_chain().await?)
+    }
+
     pub fn accounting(&self) -> &Accounting {
         &self.accounting
     }
@@ -415,6 +466,10 @@ impl LanaApp {
         &self.contract_creation
     }
 
+    pub fn account_statements(&self) -> &AccountStatements {
+        &self.account_statements
+    }
+
     pub fn terms_templates(&self) -> &TermsTemplates {
         &self.terms_templates
     }
@@ -475,6 +530,43 @@ impl LanaApp {
         Ok(ret)
     }
 
+    #[record_error_severity]
+    #[instrument(name = "lana.app.create_facility_proposal_for_subject", skip(self))]
+    pub async fn create_facility_proposal_for_subject(
+        &self,
+        sub: &Subject,
+        terms_template_id: impl Into<crate::terms_template::TermsTemplateId> + std::fmt::Debug,
+        amount: money::UsdCents,
+    ) -> Result<crate::credit::CreditFacilityProposal, ApplicationError> {
+        let customer = self.customers().find_for_subject(sub).await?;
+        let deposit_account = self
+            .deposits()
+            .find_account_by_account_holder_without_audit(customer.id)
+            .await?;
+
+        if deposit_account.is_closed() || deposit_account.is_frozen() {
+            return Err(ApplicationError::CanNotCreateProposalForClosedOrFrozenAccount);
+        }
+
+        let terms_template = self
+            .terms_templates()
+            .find_customer_selectable_by_id_without_audit(terms_template_id)
+            .await?;
+
+        let ret = self
+            .credit()
+            .for_subject(sub)?
+            .create_facility_proposal(
+                crate::primitives::CustomerId::from(deposit_account.account_holder_id),
+                deposit_account.id,
+                amount,
+                terms_template.values,
+            )
+            .await?;
+
+        Ok(ret)
+    }
+
     #[record_error_severity]
     #[instrument(name = "lana.app.record_payment", skip(self),fields(credit_facility_proposal_id = tracing::field::Empty))]
     pub async fn record_payment(
@@ -505,6 +597,36 @@ impl LanaApp {
         Ok(ret)
     }
 
+    #[record_error_severity]
+    #[instrument(name = "lana.app.record_prepayment", skip(self),fields(credit_facility_id = tracing::field::Empty))]
+    pub async fn record_prepayment(
+        &self,
+        sub: &Subject,
+        credit_facility_id: impl Into<crate::primitives::CreditFacilityId> + std::fmt::Debug + Copy,
+        amount: money::UsdCents,
+    ) -> Result<crate::credit::CreditFacility, ApplicationError> {
+        let facility = self
+            .credit()
+            .find_credit_facility(credit_facility_id)
+            .await?;
+
+        let deposit_account = self
+            .deposits()
+            .find_account_by_account_holder_without_audit(facility.customer_id)
+            .await?;
+        if deposit_account.is_closed() || deposit_account.is_frozen() {
+            return Err(ApplicationError::CanNotCreateProposalForClosedOrFrozenAccount);
+        }
+
+        let payment_source_account_id = PaymentSourceAccountId::new(deposit_account.id.into());
+        let ret = self
+            .credit()
+            .record_prepayment(sub, credit_facility_id, payment_source_account_id, amount)
+            .await?;
+
+        Ok(ret)
+    }
+
     #[record_error_severity]
     #[instrument(name = "lana.app.record_payment_with_date", skip(self),fields(credit_facility_proposal_id = tracing::field::Empty))]
     pub async fn record_payment_with_date(
@@ -0,0 +1,84 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::{select, time::Duration};
+
+use job::*;
+
+use crate::audit::{Audit, AuditSvc};
+
+const SEAL_INTERVAL: Duration = Duration::from_secs(1);
+
+const SEAL_AUDIT_CHAIN_JOB_TYPE: JobType = JobType::new("cron.audit.seal-chain");
+
+/// Links committed audit entries into the tamper-evident hash chain after the
+/// transactions that recorded them have finished.
+pub(crate) async fn init(jobs: &mut Jobs, audit: &Audit) -> Result<(), error::JobError> {
+    jobs.add_initializer(SealAuditChainJobInit {
+        audit: audit.clone(),
+    })
+    .spawn_unique(JobId::new(), SealAuditChainJobConfig {})
+    .await
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SealAuditChainJobConfig {}
+
+struct SealAuditChainJobInit {
+    audit: Audit,
+}
+
+impl JobInitializer for SealAuditChainJobInit {
+    type Config = SealAuditChainJobConfig;
+    fn job_type(&self) -> JobType {
+        SEAL_AUDIT_CHAIN_JOB_TYPE
+    }
+
+    fn init(
+        &self,
+        _job: &Job,
+        _: JobSpawner<Self::Config>,
+    ) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(SealAuditChainJobRunner {
+            audit: self.audit.clone(),
+        }))
+    }
+
+    fn retry_on_error_settings(&self) -> RetrySettings {
+        RetrySettings::repeat_indefinitely()
+    }
+}
+
+struct SealAuditChainJobRunner {
+    audit: Audit,
+}
+
+#[async_trait]
+impl JobRunner for SealAuditChainJobRunner {
+    async fn run(
+        &self,
+        mut current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        loop {
+            // Keep sealing without pausing while a backlog is being worked off.
+            let pause = if self.audit.seal_chain().await? > 0 {
+                Duration::ZERO
+            } else {
+                SEAL_INTERVAL
+            };
+
+            select! {
+                biased;
+
+                _ = current_job.shutdown_requested() => {
+                    tracing::info!(
+                        job_id = %current_job.id(),
+                        job_type = %SEAL_AUDIT_CHAIN_JOB_TYPE,
+                        "Shutdown signal received"
+                    );
+                    return Ok(JobCompletion::RescheduleNow);
+                }
+                _ = tokio::time::sleep(pause) => {}
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use account_statement::PERMISSION_SET_ACCOUNT_STATEMENT_VIEWER;
 use core_access::{PERMISSION_SET_ACCESS_VIEWER, PERMISSION_SET_ACCESS_WRITER};
 use core_accounting::{PERMISSION_SET_ACCOUNTING_VIEWER, PERMISSION_SET_ACCOUNTING_WRITER};
 use core_credit::{
@@ -22,6 +23,7 @@ use rbac_types::{
 };
 
 const ADMIN_PERMISSION_SETS: &[&str] = &[
+    PERMISSION_SET_ACCOUNT_STATEMENT_VIEWER,
     PERMISSION_SET_ACCOUNTING_VIEWER,
     PERMISSION_SET_ACCOUNTING_WRITER,
     PERMISSION_SET_AUDIT_VIEWER,
@@ -52,6 +54,7 @@ const ADMIN_PERMISSION_SETS: &[&str] = &[
 ];
 
 const BANK_MANAGER_PERMISSION_SETS: &[&str] = &[
+    PERMISSION_SET_ACCOUNT_STATEMENT_VIEWER,
     PERMISSION_SET_ACCOUNTING_VIEWER,
     PERMISSION_SET_ACCOUNTING_WRITER,
     PERMISSION_SET_AUDIT_VIEWER,
@@ -3,6 +3,7 @@
 
 pub mod accounting_init;
 pub mod app;
+mod audit_chain;
 pub mod kyc {
     pub use core_customer::kyc::*;
     pub type CustomerKyc = core_customer::kyc::CustomerKyc<
@@ -70,11 +71,25 @@ pub mod customer_sync {
         customer_sync::CustomerSync<crate::authorization::Authorization, lana_events::LanaEvent>;
 }
 
+pub mod accounting_sync {
+    pub type AccountingSync = accounting_sync::AccountingSync<
+        crate::authorization::Authorization,
+        lana_events::LanaEvent,
+    >;
+}
+
 pub mod deposit_sync {
     pub type DepositSync =
         deposit_sync::DepositSync<crate::authorization::Authorization, lana_events::LanaEvent>;
 }
 
+pub mod governance_sync {
+    pub type GovernanceSync = governance_sync::GovernanceSync<
+        crate::authorization::Authorization,
+        lana_events::LanaEvent,
+    >;
+}
+
 pub mod price {
     pub use core_price::*;
 }
@@ -87,12 +102,19 @@ pub mod governance {
     use crate::authorization::Authorization;
     use lana_events::LanaEvent;
     pub type Governance = governance::Governance<Authorization, LanaEvent>;
+    pub use crate::credit::APPROVE_CREDIT_FACILITY_AMENDMENT_PROCESS;
     pub use crate::credit::APPROVE_CREDIT_FACILITY_PROPOSAL_PROCESS;
+    pub use crate::credit::APPROVE_CREDIT_FACILITY_WRITE_OFF_PROCESS;
     pub use crate::credit::APPROVE_DISBURSAL_PROCESS;
+    pub use core_accounting::APPROVE_MANUAL_TRANSACTION_PROCESS;
+    pub use core_accounting::APPROVE_MANUAL_TRANSACTION_REVERSAL_PROCESS;
+    pub use core_deposit::APPROVE_TRANSFER_PROCESS;
+    pub use core_deposit::APPROVE_WITHDRAWAL_OVER_LIMIT_PROCESS;
     pub use core_deposit::APPROVE_WITHDRAWAL_PROCESS;
     pub use governance::{
-        ApprovalProcess, ApprovalProcessStatus, ApprovalProcessType, ApprovalRules, Committee,
-        CommitteeId, Policy, approval_process_cursor, committee_cursor, error, policy_cursor,
+        AmountBand, ApprovalProcess, ApprovalProcessStatus, ApprovalProcessType, ApprovalRules,
+        ApprovalStage, Committee, CommitteeDelegation, CommitteeId, ExpiryAction, Policy,
+        VotingDeadline, approval_process_cursor, committee_cursor, error, policy_cursor,
     };
 }
 
@@ -102,7 +124,10 @@ pub mod audit {
         primitives::Subject,
     };
 
-    pub use audit::{AuditCursor, AuditEntryId, AuditInfo, AuditSvc, error};
+    pub use audit::{
+        AuditChainIssue, AuditChainVerification, AuditCheckpointKey, AuditConfig, AuditCursor,
+        AuditEntryId, AuditInfo, AuditSvc, error,
+    };
     pub type Audit = audit::Audit<Subject, LanaObject, LanaAction>;
     pub type AuditEntry = audit::AuditEntry<Subject, LanaObject, LanaAction>;
 }
@@ -111,10 +136,14 @@ pub mod deposit {
     pub use core_deposit::{
         ChartOfAccountsIntegrationConfig, CoreDepositEvent, DEPOSIT_ACCOUNT_ENTITY_TYPE,
         DEPOSIT_TRANSACTION_ENTITY_TYPE, Deposit, DepositAccount, DepositAccountBalance,
-        DepositAccountHistoryCursor, DepositAccountHistoryEntry, DepositAccountStatus,
-        DepositAccountsByCreatedAtCursor, DepositId, DepositStatus, DepositsByCreatedAtCursor,
-        RequireVerifiedCustomerForAccount, WITHDRAWAL_TRANSACTION_ENTITY_TYPE, Withdrawal,
-        WithdrawalId, WithdrawalStatus, WithdrawalsByCreatedAtCursor, error,
+        DepositAccountHistoryCursor, DepositAccountHistoryEntry, DepositAccountInterestTerms,
+        DepositAccountStatus, DepositAccountsByCreatedAtCursor, DepositId, DepositProduct,
+        DepositStatus, DepositsByCreatedAtCursor, Hold, HoldId, HoldStatus,
+        RequireVerifiedCustomerForAccount, TRANSFER_TRANSACTION_ENTITY_TYPE, Transfer,
+        TransferApprovalThreshold, TransferId, TransferStatus, TransfersByCreatedAtCursor,
+        WITHDRAWAL_TRANSACTION_ENTITY_TYPE, Withdrawal, WithdrawalId, WithdrawalLimitRule,
+        WithdrawalLimits, WithdrawalLimitsConfig, WithdrawalStatus, WithdrawalsByCreatedAtCursor,
+        error,
     };
 
     pub type Deposits =
@@ -126,8 +155,8 @@ pub mod accounting {
         AccountCategory, AccountCode, AccountCodeSection, AccountInfo, AccountingCsvId,
         CalaAccountBalance, CalaAccountId, ChartId, CoreAccountingEvent, FiscalYearId,
         LedgerAccountId, TransactionTemplateId, chart_of_accounts, csv, error, fiscal_year,
-        journal, ledger_account, ledger_transaction, manual_transaction, transaction_templates,
-        {Chart, tree},
+        journal, ledger_account, ledger_transaction, manual_transaction, recurring_journal_entry,
+        transaction_templates, {Chart, tree},
     };
 
     pub type Accounting = core_accounting::CoreAccounting<
@@ -149,6 +178,12 @@ pub mod balance_sheet {
     pub type BalanceSheets = core_accounting::BalanceSheets<crate::authorization::Authorization>;
 }
 
+pub mod cash_flow {
+    pub use core_accounting::cash_flow::*;
+    pub type CashFlowStatements =
+        core_accounting::CashFlowStatements<crate::authorization::Authorization>;
+}
+
 pub mod trial_balance {
     pub use core_accounting::trial_balance::*;
     pub type TrialBalances = core_accounting::TrialBalances<crate::authorization::Authorization>;
@@ -169,7 +204,8 @@ pub mod custody {
 
 pub mod credit {
     pub use core_credit::{
-        APPROVE_CREDIT_FACILITY_PROPOSAL_PROCESS, APPROVE_DISBURSAL_PROCESS,
+        APPROVE_CREDIT_FACILITY_AMENDMENT_PROCESS, APPROVE_CREDIT_FACILITY_PROPOSAL_PROCESS,
+        APPROVE_CREDIT_FACILITY_WRITE_OFF_PROCESS, APPROVE_DISBURSAL_PROCESS,
         COLLATERAL_ENTITY_TYPE, CREDIT_FACILITY_ENTITY_TYPE, ChartOfAccountsIntegrationConfig,
         Collateral, CollateralSentOut, CollateralUpdated, CollateralizationUpdated,
         CoreCreditEvent, CreditFacilitiesCursor, CreditFacilitiesFilters, CreditFacilitiesSortBy,
@@ -182,7 +218,7 @@ pub mod credit {
         ListDirection, Payment, PaymentAllocation, PendingCreditFacilitiesByCreatedAtCursor,
         PendingCreditFacility, PendingCreditFacilityCollateralizationUpdated,
         PendingCreditFacilityId, ProceedsFromLiquidationReceived, RepaymentStatus, RepaymentType,
-        Sort, error,
+        Sort, TermsAmended, WrittenOff, error,
     };
 
     pub type Credit =
@@ -201,9 +237,9 @@ pub mod terms_template {
 
 pub mod terms {
     pub use core_credit::{
-        AnnualRatePct, CVLPct, CollateralizationState, DisbursalPolicy, FacilityDuration,
-        InterestInterval, ObligationDuration, OneTimeFeeRatePct,
-        PendingCreditFacilityCollateralizationState, TermValues,
+        AllocationStrategy, AnnualRatePct, CVLPct, CollateralizationState, DayCountConvention,
+        DisbursalPolicy, FacilityDuration, InterestInterval, ObligationDuration, OneTimeFeeRatePct,
+        PenaltyAllocation, PendingCreditFacilityCollateralizationState, TermValues,
     };
 }
 
@@ -229,6 +265,12 @@ pub mod contract_creation {
     >;
 }
 
+pub mod account_statement {
+    pub use account_statement::*;
+    pub type AccountStatements =
+        account_statement::AccountStatements<crate::authorization::Authorization>;
+}
+
 pub mod gotenberg {
     pub use gotenberg::GotenbergConfig;
 }
@@ -1,7 +1,8 @@
 pub use core_access::{PermissionSetId, RoleId, UserId};
 pub use core_accounting::{
-    AccountInfo, AccountSpec, AccountingBaseConfig, BalanceRange, Chart, ChartId, FiscalYearId,
-    LedgerTransactionId, ManualTransactionId,
+    AccountInfo, AccountSpec, AccountingBaseConfig, BalanceRange, CashFlowCategory,
+    CashFlowMapping, Chart, ChartId, FiscalYearId, LedgerTransactionId, ManualTransactionId,
+    RecurringJournalEntryId,
 };
 pub use core_credit::{
     CollateralDirection, CollateralId, CreditFacilityId, CreditFacilityProposalId,
@@ -11,11 +12,15 @@ pub use core_credit::{
 };
 pub use core_custody::{CustodianId, WalletId};
 pub use core_customer::{CustomerDocumentId, CustomerId, PartyId, ProspectId};
-pub use core_deposit::{DepositAccountHolderId, DepositAccountId, DepositId, WithdrawalId};
+pub use core_deposit::{
+    DepositAccountHolderId, DepositAccountId, DepositId, HoldId, TransferId, WithdrawalId,
+};
 pub use core_price::PriceOfOneBTC;
 pub use core_report::ReportId;
 pub use document_storage::{DocumentId, ReferenceId};
-pub use governance::{ApprovalProcessId, CommitteeId, CommitteeMemberId, PolicyId};
+pub use governance::{
+    ApprovalProcessId, CommitteeDelegationId, CommitteeId, CommitteeMemberId, PolicyId,
+};
 pub use job::JobId;
 pub use lana_ids::*;
 pub use money::*;
@@ -12,7 +12,7 @@ use std::path::Path;
 use super::db::*;
 use admin_server::AdminServerConfig;
 use customer_server::CustomerServerConfig;
-use lana_app::app::AppConfig;
+use lana_app::{app::AppConfig, audit::AuditCheckpointKey};
 
 /// Time configuration for the application clock
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -109,6 +109,7 @@ impl Config {
 
         let key_array: [u8; 32] = key_bytes.as_slice().try_into().expect("key is 32 bytes");
         config.app.encryption.key = key_array.into();
+        config.app.audit.checkpoint_key = Some(AuditCheckpointKey::derive_from(&key_array));
 
         Ok(config)
     }
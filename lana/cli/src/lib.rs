@@ -52,14 +52,32 @@ enum Commands {
     Genencryptionkey,
     /// Generate default configuration file (lana.yml) with all default values
     DumpDefaultConfig,
+    /// Verify the hash chain and signed checkpoints of the audit log
+    VerifyAuditLog,
     /// Run the main server (default when no subcommand is specified)
     Run,
 }
 
+impl Cli {
+    fn load_config(&self) -> anyhow::Result<Config> {
+        Config::try_new(
+            &self.config,
+            EnvSecrets {
+                pg_con: self.pg_con.clone(),
+                smtp_username: self.smtp_username.clone(),
+                smtp_password: self.smtp_password.clone(),
+                encryption_key: self.encryption_key.clone(),
+                keycloak_internal_client_secret: self.keycloak_internal_client_secret.clone(),
+                keycloak_customer_client_secret: self.keycloak_customer_client_secret.clone(),
+            },
+        )
+    }
+}
+
 pub async fn run() -> anyhow::Result<()> {
-    let cli = Cli::parse();
+    let mut cli = Cli::parse();
 
-    match cli.command.unwrap_or(Commands::Run) {
+    match cli.command.take().unwrap_or(Commands::Run) {
         Commands::BuildInfo => {
             let build_info = BuildInfo::get();
             println!("{}", build_info.display());
@@ -76,19 +94,12 @@ pub async fn run() -> anyhow::Result<()> {
             println!("{yaml_output}");
             return Ok(());
         }
+        Commands::VerifyAuditLog => {
+            let config = cli.load_config()?;
+            verify_audit_log_cmd(config).await?;
+        }
         Commands::Run => {
-            let config = Config::try_new(
-                cli.config,
-                EnvSecrets {
-                    pg_con: cli.pg_con,
-                    smtp_username: cli.smtp_username,
-                    smtp_password: cli.smtp_password,
-                    encryption_key: cli.encryption_key,
-                    keycloak_internal_client_secret: cli.keycloak_internal_client_secret,
-                    keycloak_customer_client_secret: cli.keycloak_customer_client_secret,
-                },
-            )?;
-
+            let config = cli.load_config()?;
             run_cmd(&cli.lana_home, config).await?;
         }
     }
@@ -143,6 +154,29 @@ fn setup_gcp_credentials() -> anyhow::Result<()> {
     Ok(())
 }
 
+async fn verify_audit_log_cmd(config: Config) -> anyhow::Result<()> {
+    use lana_app::audit::{Audit, AuditSvc};
+
+    let pool = db::init_pool(&config.db).await?;
+    let verification = Audit::with_config(&pool, config.app.audit)
+        .verify_chain()
+        .await
+        .context("Failed to verify audit log")?;
+    pool.close().await;
+
+    println!("{}", serde_json::to_string_pretty(&verification)?);
+    if !verification.checkpoint_signatures_verified {
+        anyhow::bail!("Audit log verification requires a checkpoint key");
+    }
+    if !verification.is_intact() {
+        anyhow::bail!(
+            "Audit log verification found {} issue(s)",
+            verification.issues.len()
+        );
+    }
+    Ok(())
+}
+
 async fn run_cmd(lana_home: &str, config: Config) -> anyhow::Result<()> {
     tracing_utils::init_tracer(config.tracing)?;
     store_server_pid(lana_home, std::process::id())?;
@@ -0,0 +1,67 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+pub use lana_app::account_statement::AccountStatement as DomainAccountStatement;
+
+#[derive(async_graphql::Enum, Clone, Copy, PartialEq, Eq)]
+pub enum AccountStatementStatus {
+    Pending,
+    Completed,
+    Failed,
+}
+
+impl From<lana_app::account_statement::AccountStatementStatus> for AccountStatementStatus {
+    fn from(status: lana_app::account_statement::AccountStatementStatus) -> Self {
+        use lana_app::account_statement::AccountStatementStatus as Domain;
+        match status {
+            Domain::Pending => Self::Pending,
+            Domain::Completed => Self::Completed,
+            Domain::Failed | Domain::Removed => Self::Failed,
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct AccountStatement {
+    id: ID,
+    account_statement_id: UUID,
+    deposit_account_id: UUID,
+    period_start: Date,
+    period_end: Date,
+    status: AccountStatementStatus,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainAccountStatement>,
+}
+
+impl From<DomainAccountStatement> for AccountStatement {
+    fn from(statement: DomainAccountStatement) -> Self {
+        AccountStatement {
+            id: statement.id.to_global_id(),
+            account_statement_id: statement.id.into(),
+            deposit_account_id: statement.deposit_account_id.into(),
+            period_start: statement.period.start.into(),
+            period_end: statement.period.end.into(),
+            status: statement.status.into(),
+            created_at: statement.created_at.into(),
+
+            entity: Arc::new(statement),
+        }
+    }
+}
+
+#[ComplexObject]
+impl AccountStatement {
+    async fn download_link(&self, ctx: &Context<'_>) -> async_graphql::Result<String> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let link = app
+            .account_statements()
+            .for_subject(sub)?
+            .generate_download_link(self.entity.id)
+            .await?;
+        Ok(link.link)
+    }
+}
@@ -1,6 +1,9 @@
 use async_graphql::*;
 
-use crate::primitives::*;
+use crate::{
+    graphql::terms::{AllocationStrategy, TermValues},
+    primitives::*,
+};
 pub use lana_app::primitives::CollateralDirection;
 
 #[derive(async_graphql::Union)]
@@ -14,6 +17,8 @@ pub enum CreditFacilityHistoryEntry {
     Interest(CreditFacilityInterestAccrued),
     Liquidation(CreditFacilityCollateralSentOut),
     Repayment(CreditFacilityRepaymentAmountReceived),
+    TermsAmended(CreditFacilityTermsAmended),
+    WrittenOff(CreditFacilityWrittenOff),
 }
 
 #[derive(SimpleObject)]
@@ -22,6 +27,7 @@ pub struct CreditFacilityIncrementalPayment {
     pub recorded_at: Timestamp,
     pub effective: Date,
     pub tx_id: UUID,
+    pub allocation_strategy: AllocationStrategy,
 }
 
 #[derive(SimpleObject)]
@@ -94,6 +100,24 @@ pub struct CreditFacilityRepaymentAmountReceived {
     pub tx_id: UUID,
 }
 
+#[derive(SimpleObject)]
+pub struct CreditFacilityTermsAmended {
+    pub previous_terms: TermValues,
+    pub terms: TermValues,
+    pub previous_matures_at: Timestamp,
+    pub matures_at: Timestamp,
+    pub rescheduled_overdue_obligations: bool,
+    pub recorded_at: Timestamp,
+    pub effective: Date,
+}
+
+#[derive(SimpleObject)]
+pub struct CreditFacilityWrittenOff {
+    pub cents: UsdCents,
+    pub recorded_at: Timestamp,
+    pub effective: Date,
+}
+
 impl From<lana_app::credit::CreditFacilityHistoryEntry> for CreditFacilityHistoryEntry {
     fn from(transaction: lana_app::credit::CreditFacilityHistoryEntry) -> Self {
         match transaction {
@@ -126,6 +150,12 @@ impl From<lana_app::credit::CreditFacilityHistoryEntry> for CreditFacilityHistor
             lana_app::credit::CreditFacilityHistoryEntry::Repayment(repayment) => {
                 CreditFacilityHistoryEntry::Repayment(repayment.into())
             }
+            lana_app::credit::CreditFacilityHistoryEntry::TermsAmended(amended) => {
+                CreditFacilityHistoryEntry::TermsAmended(amended.into())
+            }
+            lana_app::credit::CreditFacilityHistoryEntry::WrittenOff(written_off) => {
+                CreditFacilityHistoryEntry::WrittenOff(written_off.into())
+            }
         }
     }
 }
@@ -137,6 +167,7 @@ impl From<lana_app::credit::IncrementalPayment> for CreditFacilityIncrementalPay
             recorded_at: payment.recorded_at.into(),
             effective: payment.effective.into(),
             tx_id: UUID::from(payment.payment_id),
+            allocation_strategy: payment.allocation_strategy,
         }
     }
 }
@@ -240,3 +271,27 @@ impl From<lana_app::credit::ProceedsFromLiquidationReceived>
         }
     }
 }
+
+impl From<lana_app::credit::TermsAmended> for CreditFacilityTermsAmended {
+    fn from(amended: lana_app::credit::TermsAmended) -> Self {
+        Self {
+            previous_terms: amended.previous_terms.into(),
+            terms: amended.terms.into(),
+            previous_matures_at: amended.previous_maturity_date.start_of_day().into(),
+            matures_at: amended.maturity_date.start_of_day().into(),
+            rescheduled_overdue_obligations: amended.rescheduled_overdue_obligations,
+            recorded_at: amended.recorded_at.into(),
+            effective: amended.effective.into(),
+        }
+    }
+}
+
+impl From<lana_app::credit::WrittenOff> for CreditFacilityWrittenOff {
+    fn from(written_off: lana_app::credit::WrittenOff) -> Self {
+        Self {
+            cents: written_off.amount,
+            recorded_at: written_off.recorded_at.into(),
+            effective: written_off.effective.into(),
+        }
+    }
+}
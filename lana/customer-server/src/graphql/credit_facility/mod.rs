@@ -2,6 +2,7 @@ mod balance;
 pub mod disbursal;
 mod history;
 pub(super) mod payment_allocation;
+mod proposal;
 mod repayment;
 
 use async_graphql::*;
@@ -18,6 +19,7 @@ use super::terms::*;
 use balance::*;
 use disbursal::*;
 use history::*;
+pub use proposal::*;
 use repayment::*;
 
 #[derive(SimpleObject, Clone)]
@@ -123,3 +125,10 @@ impl CreditFacility {
             .await?)
     }
 }
+
+#[derive(InputObject)]
+pub struct CollateralTopUpIntentRecordInput {
+    pub credit_facility_id: UUID,
+    pub collateral: Satoshis,
+}
+crate::mutation_payload! { CollateralTopUpIntentRecordPayload, credit_facility: CreditFacility }
@@ -0,0 +1,42 @@
+use async_graphql::*;
+
+use crate::{graphql::terms::TermValues, primitives::*};
+
+pub use lana_app::credit::CreditFacilityProposal as DomainCreditFacilityProposal;
+
+#[derive(SimpleObject, Clone)]
+pub struct CreditFacilityProposal {
+    id: ID,
+    credit_facility_proposal_id: UUID,
+    status: CreditFacilityProposalStatus,
+    created_at: Timestamp,
+    facility_amount: UsdCents,
+    credit_facility_terms: TermValues,
+}
+
+impl From<DomainCreditFacilityProposal> for CreditFacilityProposal {
+    fn from(proposal: DomainCreditFacilityProposal) -> Self {
+        Self {
+            id: proposal.id.to_global_id(),
+            credit_facility_proposal_id: UUID::from(proposal.id),
+            status: proposal.status(),
+            created_at: proposal.created_at().into(),
+            facility_amount: proposal.amount,
+            credit_facility_terms: proposal.terms.into(),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct CreditFacilityProposalCreateInput {
+    pub terms_template_id: UUID,
+    pub facility: UsdCents,
+}
+crate::mutation_payload! { CreditFacilityProposalCreatePayload, credit_facility_proposal: CreditFacilityProposal }
+
+#[derive(InputObject)]
+pub struct CreditFacilityProposalCustomerApprovalConcludeInput {
+    pub credit_facility_proposal_id: UUID,
+    pub approved: bool,
+}
+crate::mutation_payload! { CreditFacilityProposalCustomerApprovalConcludePayload, credit_facility_proposal: CreditFacilityProposal }
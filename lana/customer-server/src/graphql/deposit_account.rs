@@ -7,7 +7,7 @@ pub use lana_app::deposit::{
     DepositAccountHistoryEntry as DomainDepositAccountHistoryEntry,
 };
 
-use super::{deposit::*, deposit_account_history::*, withdrawal::*};
+use super::{account_statement::*, deposit::*, deposit_account_history::*, withdrawal::*};
 
 #[derive(SimpleObject, Clone)]
 #[graphql(complex)]
@@ -38,6 +38,8 @@ impl From<DomainDepositAccount> for DepositAccount {
 pub struct DepositAccountBalance {
     settled: UsdCents,
     pending: UsdCents,
+    held: UsdCents,
+    available: UsdCents,
 }
 
 impl From<lana_app::deposit::DepositAccountBalance> for DepositAccountBalance {
@@ -45,6 +47,8 @@ impl From<lana_app::deposit::DepositAccountBalance> for DepositAccountBalance {
         Self {
             settled: balance.settled,
             pending: balance.pending,
+            held: balance.held,
+            available: balance.available(),
         }
     }
 }
@@ -81,6 +85,16 @@ impl DepositAccount {
         Ok(withdrawals.into_iter().map(Withdrawal::from).collect())
     }
 
+    async fn statements(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<AccountStatement>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let statements = app
+            .account_statements()
+            .for_subject(sub)?
+            .list_for_deposit_account(self.entity.id)
+            .await?;
+        Ok(statements.into_iter().map(AccountStatement::from).collect())
+    }
+
     async fn history(
         &self,
         ctx: &Context<'_>,
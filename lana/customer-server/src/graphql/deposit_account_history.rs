@@ -13,6 +13,15 @@ pub enum DepositAccountHistoryEntry {
     Deposit(DepositEntry),
     Withdrawal(WithdrawalEntry),
     CancelledWithdrawal(CancelledWithdrawalEntry),
+    TransferOut(TransferOutEntry),
+    TransferIn(TransferInEntry),
+    CancelledTransfer(CancelledTransferEntry),
+    InterestCapitalized(InterestCapitalizedEntry),
+    EarlyBreakPenalty(EarlyBreakPenaltyEntry),
+    Escheatment(EscheatmentEntry),
+    HoldPlaced(HoldPlacedEntry),
+    HoldReleased(HoldReleasedEntry),
+    HoldCaptured(HoldCapturedEntry),
     Disbursal(DisbursalEntry),
     Payment(PaymentEntry),
     Freeze(FreezeEntry),
@@ -73,6 +82,69 @@ pub struct UnfreezeEntry {
     pub amount: UsdCents,
 }
 
+#[derive(SimpleObject)]
+pub struct TransferOutEntry {
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+    pub amount: UsdCents,
+}
+
+#[derive(SimpleObject)]
+pub struct TransferInEntry {
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+    pub amount: UsdCents,
+}
+
+#[derive(SimpleObject)]
+pub struct CancelledTransferEntry {
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+    pub amount: UsdCents,
+}
+
+#[derive(SimpleObject)]
+pub struct InterestCapitalizedEntry {
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+    pub amount: UsdCents,
+}
+
+#[derive(SimpleObject)]
+pub struct EarlyBreakPenaltyEntry {
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+    pub amount: UsdCents,
+}
+
+#[derive(SimpleObject)]
+pub struct EscheatmentEntry {
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+    pub amount: UsdCents,
+}
+
+#[derive(SimpleObject)]
+pub struct HoldPlacedEntry {
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+    pub amount: UsdCents,
+}
+
+#[derive(SimpleObject)]
+pub struct HoldReleasedEntry {
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+    pub amount: UsdCents,
+}
+
+#[derive(SimpleObject)]
+pub struct HoldCapturedEntry {
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+    pub amount: UsdCents,
+}
+
 #[derive(SimpleObject)]
 pub struct UnknownEntry {
     pub tx_id: UUID,
@@ -178,6 +250,69 @@ impl From<lana_app::deposit::DepositAccountHistoryEntry> for DepositAccountHisto
                     recorded_at: entry.recorded_at.into(),
                 })
             }
+            lana_app::deposit::DepositAccountHistoryEntry::TransferOut(entry) => {
+                Self::TransferOut(TransferOutEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                    amount: entry.amount,
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::TransferIn(entry) => {
+                Self::TransferIn(TransferInEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                    amount: entry.amount,
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::CancelledTransfer(entry) => {
+                Self::CancelledTransfer(CancelledTransferEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                    amount: entry.amount,
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::InterestCapitalized(entry) => {
+                Self::InterestCapitalized(InterestCapitalizedEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                    amount: entry.amount,
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::EarlyBreakPenalty(entry) => {
+                Self::EarlyBreakPenalty(EarlyBreakPenaltyEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                    amount: entry.amount,
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::Escheatment(entry) => {
+                Self::Escheatment(EscheatmentEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                    amount: entry.amount,
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::HoldPlaced(entry) => {
+                Self::HoldPlaced(HoldPlacedEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                    amount: entry.amount,
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::HoldReleased(entry) => {
+                Self::HoldReleased(HoldReleasedEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                    amount: entry.amount,
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::HoldCaptured(entry) => {
+                Self::HoldCaptured(HoldCapturedEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                    amount: entry.amount,
+                })
+            }
             lana_app::deposit::DepositAccountHistoryEntry::Disbursal(entry) => {
                 Self::Disbursal(DisbursalEntry {
                     tx_id: UUID::from(entry.tx_id),
@@ -1,5 +1,6 @@
 #[macro_use]
 pub mod macros;
+mod account_statement;
 mod credit_facility;
 mod customer;
 mod deposit;
@@ -17,9 +18,9 @@ pub use schema::*;
 
 use lana_app::app::LanaApp;
 
-pub fn schema(app: Option<LanaApp>) -> Schema<Query, EmptyMutation, EmptySubscription> {
+pub fn schema(app: Option<LanaApp>) -> Schema<Query, Mutation, EmptySubscription> {
     let mut schema_builder =
-        Schema::build(Query, EmptyMutation, EmptySubscription).extension(extensions::Tracing);
+        Schema::build(Query, Mutation, EmptySubscription).extension(extensions::Tracing);
 
     if let Some(app) = app {
         schema_builder = schema_builder.data(app);
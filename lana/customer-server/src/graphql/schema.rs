@@ -2,7 +2,7 @@ use async_graphql::{Context, Object};
 
 use crate::{LanaApp, primitives::*};
 
-use super::{credit_facility::*, me::*, price::*};
+use super::{credit_facility::*, me::*, price::*, withdrawal::*};
 
 pub struct Query;
 
@@ -35,3 +35,91 @@ impl Query {
         Ok(usd_cents_per_btc.into())
     }
 }
+
+pub struct Mutation;
+
+#[Object]
+impl Mutation {
+    async fn withdrawal_initiate(
+        &self,
+        ctx: &Context<'_>,
+        input: WithdrawalInitiateInput,
+    ) -> async_graphql::Result<WithdrawalInitiatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let WithdrawalInitiateInput {
+            deposit_account_id,
+            amount,
+            reference,
+        } = input;
+
+        let withdrawal = app
+            .deposits()
+            .for_subject(sub)?
+            .initiate_withdrawal(deposit_account_id, amount, reference)
+            .await?;
+        Ok(WithdrawalInitiatePayload::from(Withdrawal::from(
+            withdrawal,
+        )))
+    }
+
+    async fn credit_facility_proposal_create(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityProposalCreateInput,
+    ) -> async_graphql::Result<CreditFacilityProposalCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let CreditFacilityProposalCreateInput {
+            terms_template_id,
+            facility,
+        } = input;
+
+        let proposal = app
+            .create_facility_proposal_for_subject(sub, terms_template_id, facility)
+            .await?;
+        Ok(CreditFacilityProposalCreatePayload::from(
+            CreditFacilityProposal::from(proposal),
+        ))
+    }
+
+    async fn credit_facility_proposal_customer_approval_conclude(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityProposalCustomerApprovalConcludeInput,
+    ) -> async_graphql::Result<CreditFacilityProposalCustomerApprovalConcludePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let CreditFacilityProposalCustomerApprovalConcludeInput {
+            credit_facility_proposal_id,
+            approved,
+        } = input;
+
+        let proposal = app
+            .credit()
+            .for_subject(sub)?
+            .conclude_proposal_customer_approval(credit_facility_proposal_id, approved)
+            .await?;
+        Ok(CreditFacilityProposalCustomerApprovalConcludePayload::from(
+            CreditFacilityProposal::from(proposal),
+        ))
+    }
+
+    async fn collateral_top_up_intent_record(
+        &self,
+        ctx: &Context<'_>,
+        input: CollateralTopUpIntentRecordInput,
+    ) -> async_graphql::Result<CollateralTopUpIntentRecordPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let CollateralTopUpIntentRecordInput {
+            credit_facility_id,
+            collateral,
+        } = input;
+
+        let credit_facility = app
+            .credit()
+            .for_subject(sub)?
+            .record_collateral_top_up_intent(credit_facility_id, collateral)
+            .await?;
+        Ok(CollateralTopUpIntentRecordPayload::from(
+            CreditFacility::from(credit_facility),
+        ))
+    }
+}
@@ -1,7 +1,10 @@
 use async_graphql::*;
 
+use crate::primitives::UsdCents;
+
 pub use lana_app::terms::{
-    AnnualRatePct, CVLPct, FacilityDuration as DomainDuration, InterestInterval, OneTimeFeeRatePct,
+    AllocationStrategy, AnnualRatePct, CVLPct, DayCountConvention,
+    FacilityDuration as DomainDuration, InterestInterval, OneTimeFeeRatePct, PenaltyAllocation,
     TermValues as DomainTermValues,
 };
 
@@ -11,6 +14,12 @@ pub struct TermValues {
     accrual_interval: InterestInterval,
     accrual_cycle_interval: InterestInterval,
     one_time_fee_rate: OneTimeFeeRatePct,
+    day_count_convention: DayCountConvention,
+    penalty_rate: Option<AnnualRatePct>,
+    late_fee: Option<UsdCents>,
+    penalty_allocation: PenaltyAllocation,
+    allocation_strategy: AllocationStrategy,
+    prepayment_fee_rate: Option<OneTimeFeeRatePct>,
     duration: Duration,
     liquidation_cvl: CVLPct,
     margin_call_cvl: CVLPct,
@@ -24,6 +33,12 @@ impl From<DomainTermValues> for TermValues {
             accrual_interval: values.accrual_interval,
             accrual_cycle_interval: values.accrual_cycle_interval,
             one_time_fee_rate: values.one_time_fee_rate,
+            day_count_convention: values.day_count_convention,
+            penalty_rate: values.penalty_rate,
+            late_fee: values.late_fee,
+            penalty_allocation: values.penalty_allocation,
+            allocation_strategy: values.allocation_strategy,
+            prepayment_fee_rate: values.prepayment_fee_rate,
             duration: values.duration.into(),
             liquidation_cvl: values.liquidation_cvl,
             margin_call_cvl: values.margin_call_cvl,
@@ -38,3 +38,11 @@ impl Withdrawal {
         &self.entity.reference
     }
 }
+
+#[derive(InputObject)]
+pub struct WithdrawalInitiateInput {
+    pub deposit_account_id: UUID,
+    pub amount: UsdCents,
+    pub reference: Option<String>,
+}
+crate::mutation_payload! { WithdrawalInitiatePayload, withdrawal: Withdrawal }
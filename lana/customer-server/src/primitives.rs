@@ -4,10 +4,12 @@ use serde::{Deserialize, Serialize};
 pub use std::sync::Arc;
 
 pub use lana_app::{
+    account_statement::AccountStatementId,
     primitives::{
-        CreditFacilityId, CreditFacilityStatus, CustomerId, DepositAccountId, DepositId,
-        DisbursalId, DisbursalStatus, PaymentAllocationId,
-        PendingCreditFacilityCollateralizationState, Satoshis, Subject, UsdCents, WithdrawalId,
+        CreditFacilityId, CreditFacilityProposalId, CreditFacilityProposalStatus,
+        CreditFacilityStatus, CustomerId, DepositAccountId, DepositId, DisbursalId,
+        DisbursalStatus, PaymentAllocationId, PendingCreditFacilityCollateralizationState,
+        Satoshis, Subject, UsdCents, WithdrawalId,
     },
     terms::CollateralizationState,
 };
@@ -75,11 +77,13 @@ macro_rules! impl_to_global_id {
 }
 
 impl_to_global_id! {
+    AccountStatementId,
     CustomerId,
     DepositAccountId,
     DepositId,
     WithdrawalId,
     CreditFacilityId,
+    CreditFacilityProposalId,
     DisbursalId,
     PaymentAllocationId
 }
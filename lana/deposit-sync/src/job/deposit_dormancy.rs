@@ -0,0 +1,86 @@
+use tracing::{Span, instrument};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use core_customer::{Activity, CoreCustomerAction, CoreCustomerEvent, CustomerObject};
+use core_deposit::{
+    CoreDeposit, CoreDepositAction, CoreDepositEvent, CoreDepositObject, GovernanceAction,
+    GovernanceObject,
+};
+use core_time_events::CoreTimeEvent;
+use governance::GovernanceEvent;
+use obix::out::{OutboxEventHandler, OutboxEventMarker, PersistentOutboxEvent};
+
+use job::JobType;
+use lana_events::LanaEvent;
+
+pub const DEPOSIT_DORMANCY_JOB: JobType = JobType::new("outbox.deposit-dormancy");
+
+pub struct DepositDormancyHandler<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>
+        + OutboxEventMarker<CoreCustomerEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<LanaEvent>
+        + OutboxEventMarker<CoreTimeEvent>
+        + std::fmt::Debug,
+{
+    deposits: CoreDeposit<Perms, E>,
+}
+
+impl<Perms, E> DepositDormancyHandler<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>
+        + OutboxEventMarker<CoreCustomerEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<LanaEvent>
+        + OutboxEventMarker<CoreTimeEvent>
+        + std::fmt::Debug,
+{
+    pub fn new(deposits: &CoreDeposit<Perms, E>) -> Self {
+        Self {
+            deposits: deposits.clone(),
+        }
+    }
+}
+
+impl<Perms, E> OutboxEventHandler<E> for DepositDormancyHandler<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<CoreCustomerAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<CustomerObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreDepositEvent>
+        + OutboxEventMarker<CoreCustomerEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<LanaEvent>
+        + OutboxEventMarker<CoreTimeEvent>
+        + std::fmt::Debug,
+{
+    #[instrument(name = "deposit_sync.deposit_dormancy.process_message", parent = None, skip(self, _op, event), fields(seq = %event.sequence, handled = false, event_type = tracing::field::Empty))]
+    async fn handle_persistent(
+        &self,
+        _op: &mut es_entity::DbOp<'_>,
+        event: &PersistentOutboxEvent<E>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(e @ CoreCustomerEvent::CustomerActivityUpdated { entity }) = event.as_event() {
+            event.inject_trace_parent();
+            Span::current().record("handled", true);
+            Span::current().record("event_type", e.as_ref());
+
+            match entity.activity {
+                Activity::Inactive => {
+                    self.deposits
+                        .mark_accounts_dormant_for_holder(entity.id)
+                        .await?
+                }
+                Activity::Suspended => self.deposits.escheat_accounts_for_holder(entity.id).await?,
+                Activity::Active => (),
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,3 +1,7 @@
+mod deposit_dormancy;
+mod deposit_interest_accrual;
 mod sumsub_export;
 
+pub use deposit_dormancy::*;
+pub use deposit_interest_accrual::*;
 pub use sumsub_export::*;
@@ -15,6 +15,7 @@ use core_deposit::{
     CoreDeposit, CoreDepositAction, CoreDepositEvent, CoreDepositObject, GovernanceAction,
     GovernanceObject,
 };
+use core_time_events::CoreTimeEvent;
 use governance::GovernanceEvent;
 use obix::out::{Outbox, OutboxEventJobConfig, OutboxEventMarker};
 use sumsub::SumsubClient;
@@ -29,6 +30,7 @@ where
         + OutboxEventMarker<CoreCustomerEvent>
         + OutboxEventMarker<GovernanceEvent>
         + OutboxEventMarker<LanaEvent>
+        + OutboxEventMarker<CoreTimeEvent>
         + std::fmt::Debug,
 {
     _phantom: std::marker::PhantomData<(Perms, E)>,
@@ -42,6 +44,7 @@ where
         + OutboxEventMarker<CoreCustomerEvent>
         + OutboxEventMarker<GovernanceEvent>
         + OutboxEventMarker<LanaEvent>
+        + OutboxEventMarker<CoreTimeEvent>
         + std::fmt::Debug,
 {
     fn clone(&self) -> Self {
@@ -63,6 +66,7 @@ where
         + OutboxEventMarker<CoreCustomerEvent>
         + OutboxEventMarker<GovernanceEvent>
         + OutboxEventMarker<LanaEvent>
+        + OutboxEventMarker<CoreTimeEvent>
         + std::fmt::Debug,
 {
     #[record_error_severity]
@@ -82,6 +86,22 @@ where
             )
             .await?;
 
+        outbox
+            .register_event_handler(
+                jobs,
+                OutboxEventJobConfig::new(DEPOSIT_INTEREST_ACCRUAL_JOB),
+                DepositInterestAccrualHandler::new(deposits),
+            )
+            .await?;
+
+        outbox
+            .register_event_handler(
+                jobs,
+                OutboxEventJobConfig::new(DEPOSIT_DORMANCY_JOB),
+                DepositDormancyHandler::new(deposits),
+            )
+            .await?;
+
         Ok(Self {
             _phantom: std::marker::PhantomData,
             _outbox: outbox.clone(),
@@ -0,0 +1,20 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+#[derive(Error, Debug)]
+pub enum GovernanceSyncError {
+    #[error("GovernanceSyncError - JobError: {0}")]
+    Job(#[from] ::job::error::JobError),
+    #[error("GovernanceSyncError - RegisterEventHandler: {0}")]
+    RegisterEventHandler(#[from] Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl ErrorSeverity for GovernanceSyncError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::Job(_) => Level::ERROR,
+            Self::RegisterEventHandler(_) => Level::ERROR,
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod voting_deadlines;
+
+pub use voting_deadlines::*;
@@ -0,0 +1,62 @@
+use tracing::{Span, instrument};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use core_time_events::CoreTimeEvent;
+use governance::{Governance, GovernanceAction, GovernanceEvent, GovernanceObject};
+use obix::out::{OutboxEventHandler, OutboxEventMarker, PersistentOutboxEvent};
+
+use job::JobType;
+
+pub const VOTING_DEADLINES_JOB: JobType = JobType::new("outbox.governance-voting-deadlines");
+
+pub struct VotingDeadlinesHandler<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreTimeEvent>,
+{
+    governance: Governance<Perms, E>,
+}
+
+impl<Perms, E> VotingDeadlinesHandler<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreTimeEvent>,
+{
+    pub fn new(governance: &Governance<Perms, E>) -> Self {
+        Self {
+            governance: governance.clone(),
+        }
+    }
+}
+
+impl<Perms, E> OutboxEventHandler<E> for VotingDeadlinesHandler<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreTimeEvent>,
+{
+    #[instrument(name = "governance_sync.voting_deadlines.process_message", parent = None, skip(self, _op, event), fields(seq = %event.sequence, handled = false, event_type = tracing::field::Empty))]
+    async fn handle_persistent(
+        &self,
+        _op: &mut es_entity::DbOp<'_>,
+        event: &PersistentOutboxEvent<E>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(
+            e @ CoreTimeEvent::EndOfDay {
+                day, closing_time, ..
+            },
+        ) = event.as_event()
+        {
+            event.inject_trace_parent();
+            Span::current().record("handled", true);
+            Span::current().record("event_type", e.as_ref());
+
+            self.governance
+                .process_voting_deadlines(*day, *closing_time)
+                .await?;
+        }
+        Ok(())
+    }
+}
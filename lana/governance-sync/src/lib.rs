@@ -0,0 +1,67 @@
+#![cfg_attr(feature = "fail-on-warnings", deny(warnings))]
+#![cfg_attr(feature = "fail-on-warnings", deny(clippy::all))]
+
+pub mod error;
+mod job;
+
+use error::*;
+use job::*;
+
+use ::job::Jobs;
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use core_time_events::CoreTimeEvent;
+use governance::{Governance, GovernanceAction, GovernanceEvent, GovernanceObject};
+use obix::out::{Outbox, OutboxEventJobConfig, OutboxEventMarker};
+use tracing_macros::record_error_severity;
+
+pub struct GovernanceSync<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreTimeEvent>,
+{
+    _phantom: std::marker::PhantomData<(Perms, E)>,
+    _outbox: Outbox<E>,
+}
+
+impl<Perms, E> Clone for GovernanceSync<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreTimeEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _outbox: self._outbox.clone(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<Perms, E> GovernanceSync<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreTimeEvent>,
+{
+    #[record_error_severity]
+    #[tracing::instrument(name = "governance_sync.init", skip_all)]
+    pub async fn init(
+        jobs: &mut Jobs,
+        outbox: &Outbox<E>,
+        governance: &Governance<Perms, E>,
+    ) -> Result<Self, GovernanceSyncError> {
+        outbox
+            .register_event_handler(
+                jobs,
+                OutboxEventJobConfig::new(VOTING_DEADLINES_JOB),
+                VotingDeadlinesHandler::new(governance),
+            )
+            .await?;
+
+        Ok(Self {
+            _phantom: std::marker::PhantomData,
+            _outbox: outbox.clone(),
+        })
+    }
+}
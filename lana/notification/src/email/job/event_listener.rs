@@ -1,7 +1,8 @@
 use tracing::{Span, instrument};
 
 use lana_events::{
-    CoreAccessEvent, CoreCreditCollectionEvent, CoreCreditEvent, CoreDepositEvent, LanaEvent,
+    CoreAccessEvent, CoreCreditCollectionEvent, CoreCreditEvent, CoreDepositEvent, GovernanceEvent,
+    LanaEvent,
 };
 use obix::out::{OutboxEventHandler, PersistentOutboxEvent};
 
@@ -146,6 +147,26 @@ where
                     .send_role_created_notification_in_op(op, &entity.id, &entity.name)
                     .await?;
             }
+            Some(LanaEvent::Governance(
+                governance_event @ GovernanceEvent::ApprovalProcessVoteReminder {
+                    entity,
+                    pending_voters,
+                    expires_at,
+                },
+            )) => {
+                event.inject_trace_parent();
+                Span::current().record("handled", true);
+                Span::current().record("event_type", governance_event.as_ref());
+
+                self.email_notification
+                    .send_approval_vote_reminder_notification_in_op(
+                        op,
+                        entity,
+                        pending_voters,
+                        expires_at,
+                    )
+                    .await?;
+            }
             _ => {}
         }
         Ok(())
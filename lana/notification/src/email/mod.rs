@@ -15,8 +15,9 @@ use lana_events::LanaEvent;
 use smtp_client::SmtpClient;
 
 use templates::{
-    DepositAccountCreatedEmailData, EmailTemplate, EmailType, OverduePaymentEmailData,
-    PartialLiquidationInitiatedEmailData, RoleCreatedEmailData, UnderMarginCallEmailData,
+    ApprovalVoteReminderEmailData, DepositAccountCreatedEmailData, EmailTemplate, EmailType,
+    OverduePaymentEmailData, PartialLiquidationInitiatedEmailData, RoleCreatedEmailData,
+    UnderMarginCallEmailData,
 };
 
 pub use config::{EmailInfraConfig, NotificationFromEmail, NotificationFromName};
@@ -116,6 +117,7 @@ where
             payment_type: match obligation.obligation_type {
                 ObligationType::Disbursal => "Principal Repayment".to_string(),
                 ObligationType::Interest => "Interest Payment".to_string(),
+                ObligationType::Penalty => "Penalty Payment".to_string(),
             },
             original_amount: obligation.initial_amount,
             outstanding_amount: *amount,
@@ -329,4 +331,37 @@ where
         }
         Ok(())
     }
+
+    pub async fn send_approval_vote_reminder_notification_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        process: &governance::PublicApprovalProcess,
+        pending_voters: &[governance::CommitteeMemberId],
+        expires_at: &chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), EmailError> {
+        let email_data = ApprovalVoteReminderEmailData {
+            process_id: process.id.to_string(),
+            process_type: process.process_type.to_string(),
+            expires_at: *expires_at,
+        };
+
+        let user_ids: Vec<core_access::UserId> = pending_voters
+            .iter()
+            .copied()
+            .map(core_access::UserId::from)
+            .collect();
+        let users: std::collections::HashMap<_, core_access::user::User> =
+            self.users.find_all(&user_ids).await?;
+
+        for user in users.into_values() {
+            let email_config = EmailSenderConfig {
+                recipient: user.email,
+                email_type: EmailType::ApprovalVoteReminder(email_data.clone()),
+            };
+            self.email_sender_job_spawner
+                .spawn_in_op(op, JobId::new(), email_config)
+                .await?;
+        }
+        Ok(())
+    }
 }
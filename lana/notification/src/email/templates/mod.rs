@@ -14,6 +14,7 @@ pub enum EmailType {
     UnderMarginCall(UnderMarginCallEmailData),
     DepositAccountCreated(DepositAccountCreatedEmailData),
     RoleCreated(RoleCreatedEmailData),
+    ApprovalVoteReminder(ApprovalVoteReminderEmailData),
     General { subject: String, body: String },
 }
 
@@ -45,6 +46,10 @@ impl EmailTemplate {
         )?;
         handlebars
             .register_template_string("role_created", include_str!("views/role_created.hbs"))?;
+        handlebars.register_template_string(
+            "approval_vote_reminder",
+            include_str!("views/approval_vote_reminder.hbs"),
+        )?;
         Ok(Self {
             handlebars,
             admin_panel_url,
@@ -63,6 +68,7 @@ impl EmailTemplate {
                 self.render_deposit_account_created_email(data)
             }
             EmailType::RoleCreated(data) => self.render_role_created_email(data),
+            EmailType::ApprovalVoteReminder(data) => self.render_approval_vote_reminder_email(data),
             EmailType::General { subject, body } => self.generic_email_template(subject, body),
         }
     }
@@ -178,6 +184,28 @@ impl EmailTemplate {
         let html_body = self.handlebars.render("role_created", &data)?;
         Ok((subject, html_body))
     }
+
+    #[allow(clippy::result_large_err)]
+    fn render_approval_vote_reminder_email(
+        &self,
+        data: &ApprovalVoteReminderEmailData,
+    ) -> Result<(String, String), EmailError> {
+        let subject = format!("Lana Bank: Vote Pending - {}", data.process_type);
+        let actions_url = self
+            .admin_panel_url
+            .join("actions")
+            .expect("valid URL path")
+            .to_string();
+        let data = json!({
+            "subject": &subject,
+            "process_id": &data.process_id,
+            "process_type": &data.process_type,
+            "expires_at": data.expires_at,
+            "actions_url": &actions_url,
+        });
+        let html_body = self.handlebars.render("approval_vote_reminder", &data)?;
+        Ok((subject, html_body))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -220,3 +248,10 @@ pub struct RoleCreatedEmailData {
     pub role_id: String,
     pub role_name: String,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApprovalVoteReminderEmailData {
+    pub process_id: String,
+    pub process_type: String,
+    pub expires_at: DateTime<Utc>,
+}
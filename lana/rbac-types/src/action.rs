@@ -3,6 +3,7 @@ use std::{fmt::Display, str::FromStr};
 use authz::action_description::*;
 
 use crate::audit_action::AuditAction;
+use account_statement::AccountStatementModuleAction;
 use contract_creation::ContractModuleAction;
 use core_access::CoreAccessAction;
 use core_accounting::CoreAccountingAction;
@@ -37,6 +38,7 @@ pub enum LanaAction {
     Custody(CoreCustodyAction),
     Report(CoreReportAction),
     Contract(ContractModuleAction),
+    AccountStatement(AccountStatementModuleAction),
 }
 
 impl LanaAction {
@@ -58,6 +60,7 @@ impl LanaAction {
             CoreCustodyAction::actions(),
             CoreReportAction::actions(),
             ContractModuleAction::actions(),
+            AccountStatementModuleAction::actions(),
         ]
         .concat()
     }
@@ -138,6 +141,11 @@ impl From<ContractModuleAction> for LanaAction {
         LanaAction::Contract(action)
     }
 }
+impl From<AccountStatementModuleAction> for LanaAction {
+    fn from(action: AccountStatementModuleAction) -> Self {
+        LanaAction::AccountStatement(action)
+    }
+}
 
 impl Display for LanaAction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -159,6 +167,7 @@ impl Display for LanaAction {
             Custody(action) => action.fmt(f),
             Report(action) => action.fmt(f),
             Contract(action) => action.fmt(f),
+            AccountStatement(action) => action.fmt(f),
         }
     }
 }
@@ -185,6 +194,7 @@ impl FromStr for LanaAction {
             Custody => LanaAction::from(action.parse::<CoreCustodyAction>()?),
             Report => LanaAction::from(action.parse::<CoreReportAction>()?),
             Contract => LanaAction::from(action.parse::<ContractModuleAction>()?),
+            AccountStatement => LanaAction::from(action.parse::<AccountStatementModuleAction>()?),
         };
         Ok(res)
     }
@@ -218,6 +228,13 @@ mod test {
             )),
             "report:report:generate",
         )?;
+
+        test_to_and_from_string(
+            LanaAction::from(
+                AccountStatementModuleAction::ACCOUNT_STATEMENT_GENERATE_DOWNLOAD_LINK,
+            ),
+            "account-statement:account-statement:generate-download-link",
+        )?;
         Ok(())
     }
 }
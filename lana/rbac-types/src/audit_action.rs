@@ -48,12 +48,14 @@ impl FromStr for AuditAction {
 #[strum(serialize_all = "kebab-case")]
 pub enum AuditEntryAction {
     List,
+    Verify,
 }
 
 impl ActionPermission for AuditEntryAction {
     fn permission_set(&self) -> &'static str {
         match self {
             Self::List => PERMISSION_SET_AUDIT_VIEWER,
+            Self::Verify => PERMISSION_SET_AUDIT_VIEWER,
         }
     }
 }
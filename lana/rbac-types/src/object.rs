@@ -1,6 +1,7 @@
 use std::{fmt::Display, str::FromStr};
 
 use crate::audit_object::AuditObject;
+use account_statement::AccountStatementModuleObject;
 use contract_creation::ContractModuleObject;
 use core_access::CoreAccessObject;
 use core_accounting::CoreAccountingObject;
@@ -35,6 +36,7 @@ pub enum LanaObject {
     Dashboard(DashboardModuleObject),
     Report(ReportObject),
     Contract(ContractModuleObject),
+    AccountStatement(AccountStatementModuleObject),
 }
 
 impl From<AuditObject> for LanaObject {
@@ -118,6 +120,12 @@ impl From<ContractModuleObject> for LanaObject {
     }
 }
 
+impl From<AccountStatementModuleObject> for LanaObject {
+    fn from(object: AccountStatementModuleObject) -> Self {
+        LanaObject::AccountStatement(object)
+    }
+}
+
 impl Display for LanaObject {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}/", LanaObjectDiscriminants::from(self))?;
@@ -138,6 +146,7 @@ impl Display for LanaObject {
             Dashboard(object) => object.fmt(f),
             Report(object) => object.fmt(f),
             Contract(object) => object.fmt(f),
+            AccountStatement(object) => object.fmt(f),
         }
     }
 }
@@ -172,6 +181,11 @@ impl FromStr for LanaObject {
                     .parse::<ContractModuleObject>()
                     .map_err(|_| "could not parse ContractModuleObject")?,
             ),
+            AccountStatement => LanaObject::from(
+                object
+                    .parse::<AccountStatementModuleObject>()
+                    .map_err(|_| "could not parse AccountStatementModuleObject")?,
+            ),
         };
         Ok(res)
     }
@@ -206,6 +220,11 @@ mod test {
             "audit/audit/*",
         )?;
 
+        test_to_and_from_string(
+            LanaObject::AccountStatement(AccountStatementModuleObject::all_account_statements()),
+            "account-statement/account-statement/*",
+        )?;
+
         Ok(())
     }
 }
@@ -0,0 +1,221 @@
+use chrono::{DateTime, Utc};
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use std::collections::BTreeSet;
+
+use crate::{
+    config::{AuditCheckpointKey, AuditConfig},
+    primitives::AuditEntryId,
+};
+
+pub(crate) const GENESIS_HASH: [u8; 32] = [0; 32];
+pub(crate) const VERIFY_BATCH_SIZE: i64 = 1000;
+pub(crate) const SEAL_BATCH_SIZE: i32 = 1000;
+
+/// Mirrors the `audit_entry_hash` SQL function that seals rows into the chain.
+pub(crate) fn entry_hash(
+    previous_hash: &[u8],
+    chain_seq: i64,
+    subject: &str,
+    object: &str,
+    action: &str,
+    authorized: bool,
+    recorded_at: DateTime<Utc>,
+) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(previous_hash);
+    hasher.update(chain_seq.to_be_bytes());
+    for field in [subject, object, action] {
+        hasher.update((field.len() as i64).to_be_bytes());
+        hasher.update(field.as_bytes());
+    }
+    hasher.update([authorized as u8]);
+    hasher.update(recorded_at.timestamp_micros().to_be_bytes());
+    hasher.finalize().to_vec()
+}
+
+/// The key and interval checkpoints are signed with, if checkpointing is enabled.
+pub(crate) fn checkpointing(config: Option<&AuditConfig>) -> Option<(&AuditCheckpointKey, i64)> {
+    let config = config?;
+    let key = config.checkpoint_key.as_ref()?;
+    (config.checkpoint_interval > 0).then_some((key, config.checkpoint_interval))
+}
+
+/// Signs the chain head every `checkpoint_interval` entries when a checkpoint key is configured.
+pub(crate) async fn record_checkpoint_if_due(
+    executor: impl sqlx::PgExecutor<'_>,
+    config: Option<&AuditConfig>,
+    chain_seq: i64,
+    hash: &[u8],
+) -> Result<(), sqlx::Error> {
+    let Some((key, interval)) = checkpointing(config) else {
+        return Ok(());
+    };
+    if chain_seq % interval != 0 {
+        return Ok(());
+    }
+
+    sqlx::query!(
+        r#"
+            INSERT INTO audit_checkpoints (chain_seq, hash, signature)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (chain_seq) DO NOTHING
+            "#,
+        chain_seq,
+        hash,
+        key.sign(chain_seq, hash),
+    )
+    .execute(executor)
+    .await?;
+
+    Ok(())
+}
+
+/// Reports every multiple of `interval` up to `last_chain_seq` without a recorded checkpoint.
+pub(crate) fn missing_checkpoints<'a>(
+    interval: i64,
+    last_chain_seq: i64,
+    recorded: &'a BTreeSet<i64>,
+) -> impl Iterator<Item = AuditChainIssue> + 'a {
+    (interval..=last_chain_seq)
+        .step_by(interval as usize)
+        .filter(|chain_seq| !recorded.contains(chain_seq))
+        .map(|chain_seq| AuditChainIssue::MissingCheckpoint { chain_seq })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum AuditChainIssue {
+    /// Sequence numbers `from_seq..=to_seq` are absent from the chain.
+    MissingEntries { from_seq: i64, to_seq: i64 },
+    /// The stored hash does not match the entry's content.
+    ModifiedEntry { id: AuditEntryId, chain_seq: i64 },
+    /// The entry does not link to the hash of its predecessor.
+    BrokenLink { id: AuditEntryId, chain_seq: i64 },
+    /// A checkpoint's signature does not verify against the checkpoint key.
+    InvalidCheckpointSignature { chain_seq: i64 },
+    /// A checkpoint refers to an entry that is missing or has a different hash.
+    CheckpointMismatch { chain_seq: i64 },
+    /// No checkpoint was recorded for a sequence number that required one.
+    MissingCheckpoint { chain_seq: i64 },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct AuditChainVerification {
+    pub entries_checked: i64,
+    pub checkpoints_checked: i64,
+    pub checkpoint_signatures_verified: bool,
+    pub last_chain_seq: Option<i64>,
+    pub issues: Vec<AuditChainIssue>,
+}
+
+impl AuditChainVerification {
+    /// Without verified checkpoints a rewritten chain is indistinguishable from
+    /// the original, so the log only counts as intact if they were verified.
+    pub fn is_intact(&self) -> bool {
+        self.checkpoint_signatures_verified && self.issues.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash(subject: &str, authorized: bool) -> Vec<u8> {
+        let recorded_at = DateTime::from_timestamp_micros(1_700_000_000_123_456).unwrap();
+        entry_hash(
+            &GENESIS_HASH,
+            1,
+            subject,
+            "object",
+            "action",
+            authorized,
+            recorded_at,
+        )
+    }
+
+    #[test]
+    fn entry_hash_covers_every_field() {
+        let original = hash("subject", true);
+        assert_eq!(original.len(), 32);
+        assert_eq!(original, hash("subject", true));
+        assert_ne!(original, hash("subject2", true));
+        assert_ne!(original, hash("subject", false));
+    }
+
+    #[test]
+    fn entry_hash_is_not_ambiguous_across_field_boundaries() {
+        let recorded_at = Utc::now();
+        let a = entry_hash(&GENESIS_HASH, 1, "ab", "c", "d", true, recorded_at);
+        let b = entry_hash(&GENESIS_HASH, 1, "a", "bc", "d", true, recorded_at);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn every_checkpoint_interval_requires_a_checkpoint() {
+        let recorded = BTreeSet::from([10, 30]);
+        let missing: Vec<_> = missing_checkpoints(10, 45, &recorded).collect();
+        assert_eq!(
+            missing,
+            vec![
+                AuditChainIssue::MissingCheckpoint { chain_seq: 20 },
+                AuditChainIssue::MissingCheckpoint { chain_seq: 40 },
+            ]
+        );
+        assert_eq!(missing_checkpoints(10, 9, &recorded).count(), 0);
+    }
+
+    #[test]
+    fn unverified_checkpoints_are_not_intact() {
+        let verification = AuditChainVerification {
+            entries_checked: 1,
+            checkpoints_checked: 0,
+            checkpoint_signatures_verified: false,
+            last_chain_seq: Some(1),
+            issues: vec![],
+        };
+        assert!(!verification.is_intact());
+    }
+
+    #[tokio::test]
+    async fn entry_hash_matches_sql_function() -> anyhow::Result<()> {
+        let pg_con = std::env::var("PG_CON").unwrap();
+        let pool = sqlx::PgPool::connect(&pg_con).await?;
+
+        let previous_hash = entry_hash(&GENESIS_HASH, 41, "prev", "obj", "act", true, Utc::now());
+        let recorded_at = DateTime::from_timestamp_micros(1_700_000_000_123_456).unwrap();
+        let (subject, object, action) = ("usuário:ñ", "objeto/€", "acción:✓");
+
+        for authorized in [true, false] {
+            let sql_hash: Vec<u8> =
+                sqlx::query_scalar("SELECT audit_entry_hash($1, $2, $3, $4, $5, $6, $7)")
+                    .bind(&previous_hash)
+                    .bind(42_i64)
+                    .bind(subject)
+                    .bind(object)
+                    .bind(action)
+                    .bind(authorized)
+                    .bind(recorded_at)
+                    .fetch_one(&pool)
+                    .await?;
+            assert_eq!(
+                sql_hash,
+                entry_hash(
+                    &previous_hash,
+                    42,
+                    subject,
+                    object,
+                    action,
+                    authorized,
+                    recorded_at
+                )
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+const CHECKPOINT_KEY_CONTEXT: &[u8] = b"lana-audit-checkpoint";
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AuditConfig {
+    #[serde(default = "default_checkpoint_interval")]
+    pub checkpoint_interval: i64,
+
+    #[serde(skip)]
+    pub checkpoint_key: Option<AuditCheckpointKey>,
+}
+
+impl Default for AuditConfig {
+    fn default() -> Self {
+        Self {
+            checkpoint_interval: default_checkpoint_interval(),
+            checkpoint_key: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for AuditConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuditConfig")
+            .field("checkpoint_interval", &self.checkpoint_interval)
+            .field("checkpoint_key", &"*******Redacted*******")
+            .finish()
+    }
+}
+
+fn default_checkpoint_interval() -> i64 {
+    1000
+}
+
+/// Secret used to sign audit checkpoints. It never leaves the application so
+/// that a checkpoint cannot be forged by someone with write access to the database.
+#[derive(Clone)]
+pub struct AuditCheckpointKey([u8; 32]);
+
+impl AuditCheckpointKey {
+    /// Derives a dedicated checkpoint key so the master secret is not used directly.
+    pub fn derive_from(master: &[u8]) -> Self {
+        let mut mac = Hmac::<Sha256>::new_from_slice(master).expect("HMAC accepts any key length");
+        mac.update(CHECKPOINT_KEY_CONTEXT);
+        let mut key = [0; 32];
+        key.copy_from_slice(&mac.finalize().into_bytes());
+        Self(key)
+    }
+
+    pub(crate) fn sign(&self, chain_seq: i64, hash: &[u8]) -> Vec<u8> {
+        self.mac(chain_seq, hash).finalize().into_bytes().to_vec()
+    }
+
+    pub(crate) fn verify(&self, chain_seq: i64, hash: &[u8], signature: &[u8]) -> bool {
+        self.mac(chain_seq, hash).verify_slice(signature).is_ok()
+    }
+
+    fn mac(&self, chain_seq: i64, hash: &[u8]) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.0).expect("HMAC accepts any key length");
+        mac.update(&chain_seq.to_be_bytes());
+        mac.update(hash);
+        mac
+    }
+}
@@ -3,10 +3,14 @@
 
 use std::{borrow::Cow, fmt, marker::PhantomData, str::FromStr};
 
+mod chain;
+mod config;
 pub mod error;
 mod primitives;
 mod svc_trait;
 
+pub use chain::{AuditChainIssue, AuditChainVerification};
+pub use config::*;
 pub use primitives::*;
 pub use svc_trait::*;
 
@@ -46,6 +50,7 @@ pub use es_entity::{PaginatedQueryArgs, PaginatedQueryRet};
 #[derive(Clone)]
 pub struct Audit<S, O, A> {
     pool: sqlx::PgPool,
+    config: AuditConfig,
     _subject: PhantomData<S>,
     _object: PhantomData<O>,
     _action: PhantomData<A>,
@@ -53,8 +58,13 @@ pub struct Audit<S, O, A> {
 
 impl<S, O, A> Audit<S, O, A> {
     pub fn new(pool: &sqlx::PgPool) -> Self {
+        Self::with_config(pool, AuditConfig::default())
+    }
+
+    pub fn with_config(pool: &sqlx::PgPool, config: AuditConfig) -> Self {
         Self {
             pool: pool.clone(),
+            config,
             _subject: std::marker::PhantomData,
             _object: std::marker::PhantomData,
             _action: std::marker::PhantomData,
@@ -75,4 +85,8 @@ where
     fn pool(&self) -> &sqlx::PgPool {
         &self.pool
     }
+
+    fn config(&self) -> Option<&AuditConfig> {
+        Some(&self.config)
+    }
 }
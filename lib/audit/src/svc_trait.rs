@@ -1,9 +1,19 @@
 use async_trait::async_trait;
 use tracing_macros::record_error_severity;
 
-use std::{collections::HashMap, fmt, str::FromStr};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt,
+    str::FromStr,
+};
 
-use crate::{AuditEntry, error::AuditError, primitives::*};
+use crate::{
+    AuditEntry,
+    chain::{self, AuditChainIssue, AuditChainVerification},
+    config::AuditConfig,
+    error::AuditError,
+    primitives::*,
+};
 
 pub trait SystemSubject: fmt::Display + fmt::Debug {
     fn system(actor: crate::SystemActor) -> Self;
@@ -18,6 +28,10 @@ pub trait AuditSvc: Clone + Sync + Send + 'static {
 
     fn pool(&self) -> &sqlx::PgPool;
 
+    fn config(&self) -> Option<&AuditConfig> {
+        None
+    }
+
     async fn record_system_entry(
         &self,
         actor: crate::SystemActor,
@@ -249,4 +263,147 @@ pub trait AuditSvc: Clone + Sync + Send + 'static {
         .await?;
         Ok(rows)
     }
+
+    /// Links committed entries that are not yet part of the hash chain and signs
+    /// checkpoints that fall due. Returns the number of entries sealed.
+    #[record_error_severity]
+    #[tracing::instrument(name = "audit.seal_chain", skip_all)]
+    async fn seal_chain(&self) -> Result<usize, AuditError> {
+        let mut tx = self.pool().begin().await?;
+        let sealed = sqlx::query!(
+            r#"
+                SELECT sealed_chain_seq AS "chain_seq!", sealed_hash AS "hash!"
+                FROM audit_entries_seal($1)
+                "#,
+            chain::SEAL_BATCH_SIZE,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+        for entry in sealed.iter() {
+            chain::record_checkpoint_if_due(&mut *tx, self.config(), entry.chain_seq, &entry.hash)
+                .await?;
+        }
+        tx.commit().await?;
+
+        Ok(sealed.len())
+    }
+
+    #[record_error_severity]
+    #[tracing::instrument(name = "audit.verify_chain", skip_all)]
+    async fn verify_chain(&self) -> Result<AuditChainVerification, AuditError> {
+        let checkpointing = chain::checkpointing(self.config());
+        let mut checkpoints: BTreeMap<i64, Vec<u8>> = BTreeMap::new();
+        let mut issues = Vec::new();
+
+        let rows = sqlx::query!(
+            r#"SELECT chain_seq, hash, signature FROM audit_checkpoints ORDER BY chain_seq"#,
+        )
+        .fetch_all(self.pool())
+        .await?;
+        let checkpoints_checked = rows.len() as i64;
+        for row in rows {
+            if let Some((key, _)) = checkpointing
+                && !key.verify(row.chain_seq, &row.hash, &row.signature)
+            {
+                issues.push(AuditChainIssue::InvalidCheckpointSignature {
+                    chain_seq: row.chain_seq,
+                });
+            }
+            checkpoints.insert(row.chain_seq, row.hash);
+        }
+        let recorded_checkpoints: BTreeSet<i64> = checkpoints.keys().copied().collect();
+
+        let mut entries_checked = 0;
+        let mut expected_seq = 1;
+        let mut previous_hash = chain::GENESIS_HASH.to_vec();
+        loop {
+            let rows = sqlx::query!(
+                r#"
+                    SELECT id AS "id: AuditEntryId", chain_seq AS "chain_seq!",
+                           previous_hash AS "previous_hash!", hash AS "hash!",
+                           subject, object, action, authorized, recorded_at
+                    FROM audit_entries
+                    WHERE chain_seq >= $1
+                    ORDER BY chain_seq
+                    LIMIT $2
+                    "#,
+                expected_seq,
+                chain::VERIFY_BATCH_SIZE,
+            )
+            .fetch_all(self.pool())
+            .await?;
+            if rows.is_empty() {
+                break;
+            }
+
+            for row in rows {
+                if row.chain_seq != expected_seq {
+                    issues.push(AuditChainIssue::MissingEntries {
+                        from_seq: expected_seq,
+                        to_seq: row.chain_seq - 1,
+                    });
+                }
+                if row.previous_hash != previous_hash {
+                    issues.push(AuditChainIssue::BrokenLink {
+                        id: row.id,
+                        chain_seq: row.chain_seq,
+                    });
+                }
+                let hash = chain::entry_hash(
+                    &row.previous_hash,
+                    row.chain_seq,
+                    &row.subject,
+                    &row.object,
+                    &row.action,
+                    row.authorized,
+                    row.recorded_at,
+                );
+                if hash != row.hash {
+                    issues.push(AuditChainIssue::ModifiedEntry {
+                        id: row.id,
+                        chain_seq: row.chain_seq,
+                    });
+                }
+                if let Some(checkpoint_hash) = checkpoints.remove(&row.chain_seq)
+                    && checkpoint_hash != row.hash
+                {
+                    issues.push(AuditChainIssue::CheckpointMismatch {
+                        chain_seq: row.chain_seq,
+                    });
+                }
+
+                entries_checked += 1;
+                expected_seq = row.chain_seq + 1;
+                previous_hash = row.hash;
+            }
+        }
+
+        // Checkpoints left over point at entries that no longer exist, e.g. a truncated tail.
+        issues.extend(
+            checkpoints
+                .into_keys()
+                .map(|chain_seq| AuditChainIssue::CheckpointMismatch { chain_seq }),
+        );
+
+        // Every interval must be checkpointed, otherwise a rewritten chain could
+        // pass by dropping the checkpoints that would contradict it.
+        let last_chain_seq = (entries_checked > 0).then_some(expected_seq - 1);
+        if let Some((_, interval)) = checkpointing
+            && let Some(last_chain_seq) = last_chain_seq
+        {
+            issues.extend(chain::missing_checkpoints(
+                interval,
+                last_chain_seq,
+                &recorded_checkpoints,
+            ));
+        }
+
+        Ok(AuditChainVerification {
+            entries_checked,
+            checkpoints_checked,
+            checkpoint_signatures_verified: checkpointing.is_some(),
+            last_chain_seq,
+            issues,
+        })
+    }
 }
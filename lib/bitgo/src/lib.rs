@@ -203,6 +203,33 @@ impl BitgoClient {
             .await?;
         Ok(serde_json::from_value(response)?)
     }
+
+    #[record_error_severity]
+    #[tracing::instrument(name = "bitgo.send_coins", skip(self), fields(response, url))]
+    pub async fn send_coins(
+        &self,
+        wallet_id: &str,
+        address: &str,
+        amount: u64,
+        sequence_id: &str,
+    ) -> Result<(SendCoinsResponse, Value), BitgoError> {
+        // https://developers.bitgo.com/api/express.wallet.sendcoins
+
+        let response_json: Value = self
+            .post(
+                self.url_with_coin(&format!("wallet/{wallet_id}/sendcoins")),
+                &SendCoins {
+                    address: address.to_owned(),
+                    amount: amount.to_string(),
+                    wallet_passphrase: self.passphrase.clone(),
+                    sequence_id: sequence_id.to_owned(),
+                },
+            )
+            .await?;
+
+        let response = serde_json::from_value(response_json.clone())?;
+        Ok((response, response_json))
+    }
 }
 
 impl BitgoClient {
@@ -73,6 +73,7 @@ pub struct Address {
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Transfer {
+    pub id: String,
     pub wallet: String,
     pub txid: String,
     pub confirmations: u32,
@@ -83,7 +84,7 @@ pub struct Transfer {
     pub state: TransferState,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub enum TransferType {
     Receive,
@@ -104,6 +105,28 @@ pub enum TransferState {
     Unconfirmed,
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendCoins {
+    pub address: String,
+    pub amount: String,
+    pub wallet_passphrase: String,
+    pub sequence_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendCoinsResponse {
+    pub transfer: SentTransfer,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SentTransfer {
+    pub id: String,
+    pub state: TransferState,
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateKey {
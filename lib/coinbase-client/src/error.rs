@@ -0,0 +1,20 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+#[derive(Error, Debug)]
+pub enum CoinbaseClientError {
+    #[error("CoinbaseClientError - Reqwest: {0}")]
+    Reqwest(#[from] reqwest::Error),
+    #[error("CoinbaseClientError - SerdeJson: {0}")]
+    Deserialization(#[from] serde_json::Error),
+}
+
+impl ErrorSeverity for CoinbaseClientError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::Reqwest(_) => Level::WARN,
+            Self::Deserialization(_) => Level::ERROR,
+        }
+    }
+}
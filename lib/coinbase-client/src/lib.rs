@@ -0,0 +1,51 @@
+pub mod error;
+pub mod response;
+
+use reqwest::Client as ReqwestClient;
+use tracing_macros::record_error_severity;
+
+pub use error::CoinbaseClientError;
+pub use response::{CoinbaseResponse, SpotPrice};
+
+const BASE_URL: &str = "https://api.coinbase.com/v2/";
+
+#[derive(Clone, Default)]
+pub struct CoinbaseClient {
+    client: ReqwestClient,
+}
+
+impl CoinbaseClient {
+    pub fn new() -> Self {
+        CoinbaseClient {
+            client: ReqwestClient::builder()
+                .use_rustls_tls()
+                .build()
+                .expect("should always build CoinbaseClient"),
+        }
+    }
+
+    #[record_error_severity]
+    #[tracing::instrument(
+        name = "coinbase.btc_usd_spot_price",
+        skip(self),
+        fields(url, response)
+    )]
+    pub async fn btc_usd_spot_price(&self) -> Result<SpotPrice, CoinbaseClientError> {
+        let url = format!("{BASE_URL}prices/BTC-USD/spot");
+        tracing::Span::current().record("url", tracing::field::display(&url));
+
+        let response = self
+            .client
+            .get(&url)
+            .header("accept", "application/json")
+            .send()
+            .await?
+            .error_for_status()?;
+        let response_text = response.text().await?;
+        let data = serde_json::from_str::<CoinbaseResponse<SpotPrice>>(&response_text)?;
+
+        tracing::Span::current().record("response", tracing::field::debug(&data.data));
+
+        Ok(data.data)
+    }
+}
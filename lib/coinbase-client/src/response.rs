@@ -0,0 +1,27 @@
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct CoinbaseResponse<T> {
+    pub data: T,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SpotPrice {
+    pub amount: Decimal,
+    pub base: String,
+    pub currency: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn spot_price_data() {
+        let response_text = r#"{"data":{"amount":"64000.12","base":"BTC","currency":"USD"}}"#;
+        let response = serde_json::from_str::<CoinbaseResponse<SpotPrice>>(response_text).unwrap();
+        assert_eq!(response.data.amount, dec!(64000.12));
+    }
+}
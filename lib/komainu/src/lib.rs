@@ -20,6 +20,7 @@ use reqwest::{
     Client, Method, RequestBuilder, Url,
     header::{CONTENT_TYPE, HeaderValue},
 };
+use rust_decimal::Decimal;
 use serde::{Serialize, de::DeserializeOwned};
 use sha2::{Digest as _, Sha256};
 use tokio::sync::RwLock;
@@ -28,8 +29,13 @@ use tracing_macros::record_error_severity;
 
 pub use config::{KomainuConfig, KomainuDirectoryConfig, KomainuSecretKey};
 pub use error::KomainuError;
-pub use wire::{EntityType, EventType, Notification, Request, Transaction, Wallet};
-use wire::{Fallible, GetToken, GetTokenResponse, Many};
+use wire::{
+    CreateRequest, CreateTransaction, Fallible, GetToken, GetTokenResponse, Many, RequestType,
+};
+pub use wire::{
+    EntityType, EventType, Notification, Request, RequestStatus, Transaction, TransactionStatus,
+    Wallet,
+};
 
 #[derive(Clone)]
 pub struct KomainuClient {
@@ -141,6 +147,31 @@ impl KomainuClient {
     pub async fn list_wallets(&self) -> Result<Vec<Wallet>, KomainuError> {
         self.get_many("v1/custody/wallets").await
     }
+
+    #[record_error_severity]
+    #[tracing::instrument(name = "komainu.create_transaction_request", skip(self))]
+    pub async fn create_transaction_request(
+        &self,
+        wallet_id: &str,
+        receiver_address: &str,
+        amount: Decimal,
+        external_reference: &str,
+    ) -> Result<Request, KomainuError> {
+        self.post(
+            "v1/custody/requests",
+            CreateRequest {
+                request_type: RequestType::CreateTransaction,
+                payload: CreateTransaction {
+                    wallet_id: wallet_id.to_owned(),
+                    receiver_address: receiver_address.to_owned(),
+                    amount,
+                    external_reference: external_reference.to_owned(),
+                    note: format!("lana transfer {external_reference}"),
+                },
+            },
+        )
+        .await
+    }
 }
 
 impl KomainuClient {
@@ -192,6 +223,33 @@ impl KomainuClient {
         }
     }
 
+    async fn post<P: Serialize, T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        payload: P,
+    ) -> Result<T, KomainuError> {
+        let response = self
+            .request(Method::POST, endpoint, None, Some(payload))
+            .await?
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        match response {
+            Fallible::Error {
+                error_code,
+                errors,
+                status,
+            } => Err(KomainuError::KomainuError {
+                error_code,
+                errors,
+                status,
+            }),
+            Fallible::Ok(res) => Ok(res),
+        }
+    }
+
     async fn request<T: Serialize>(
         &self,
         method: Method,
@@ -13,12 +13,14 @@ pub struct Notification {
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum EventType {
     BalanceUpdated,
+    TransactionUpdated,
 }
 
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum EntityType {
     Wallet,
+    Transaction,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -103,7 +105,7 @@ pub enum WalletStatus {
     ViewOnly,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum RequestType {
     CreateTransaction,
@@ -137,7 +139,7 @@ pub enum TransactionDirection {
     Flat,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum TransactionStatus {
     Pending,
@@ -146,6 +148,21 @@ pub enum TransactionStatus {
     Failed,
 }
 
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateRequest<T> {
+    pub request_type: RequestType,
+    pub payload: T,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTransaction {
+    pub wallet_id: String,
+    pub receiver_address: String,
+    pub amount: Decimal,
+    pub external_reference: String,
+    pub note: String,
+}
+
 #[derive(Clone, Serialize)]
 pub struct GetToken {
     pub api_user: String,
@@ -0,0 +1,26 @@
+use thiserror::Error;
+use tracing::Level;
+use tracing_utils::ErrorSeverity;
+
+#[derive(Error, Debug)]
+pub enum KrakenClientError {
+    #[error("KrakenClientError - Reqwest: {0}")]
+    Reqwest(#[from] reqwest::Error),
+    #[error("KrakenClientError - SerdeJson: {0}")]
+    Deserialization(#[from] serde_json::Error),
+    #[error("KrakenClientError - UnexpectedResponse: {0:?}")]
+    UnexpectedResponse(Vec<String>),
+    #[error("KrakenClientError - MissingPair: {0}")]
+    MissingPair(String),
+}
+
+impl ErrorSeverity for KrakenClientError {
+    fn severity(&self) -> Level {
+        match self {
+            Self::Reqwest(_) => Level::WARN,
+            Self::Deserialization(_) => Level::ERROR,
+            Self::UnexpectedResponse(_) => Level::ERROR,
+            Self::MissingPair(_) => Level::ERROR,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+pub mod error;
+pub mod response;
+
+use reqwest::Client as ReqwestClient;
+use tracing_macros::record_error_severity;
+
+pub use error::KrakenClientError;
+pub use response::{KrakenResponse, Ticker, TickerResult};
+
+const BASE_URL: &str = "https://api.kraken.com/0/public/";
+const BTC_USD_PAIR: &str = "XXBTZUSD";
+
+#[derive(Clone, Default)]
+pub struct KrakenClient {
+    client: ReqwestClient,
+}
+
+impl KrakenClient {
+    pub fn new() -> Self {
+        KrakenClient {
+            client: ReqwestClient::builder()
+                .use_rustls_tls()
+                .build()
+                .expect("should always build KrakenClient"),
+        }
+    }
+
+    #[record_error_severity]
+    #[tracing::instrument(name = "kraken.btc_usd_ticker", skip(self), fields(url, response))]
+    pub async fn btc_usd_ticker(&self) -> Result<Ticker, KrakenClientError> {
+        let url = format!("{BASE_URL}Ticker?pair=XBTUSD");
+        tracing::Span::current().record("url", tracing::field::display(&url));
+
+        let response = self
+            .client
+            .get(&url)
+            .header("accept", "application/json")
+            .send()
+            .await?
+            .error_for_status()?;
+        let response_text = response.text().await?;
+        let data = serde_json::from_str::<KrakenResponse<TickerResult>>(&response_text)?;
+
+        if !data.error.is_empty() {
+            return Err(KrakenClientError::UnexpectedResponse(data.error));
+        }
+
+        let ticker = data
+            .result
+            .and_then(|mut result| result.remove(BTC_USD_PAIR))
+            .ok_or_else(|| KrakenClientError::MissingPair(BTC_USD_PAIR.to_string()))?;
+
+        tracing::Span::current().record("response", tracing::field::debug(&ticker));
+
+        Ok(ticker)
+    }
+}
@@ -0,0 +1,41 @@
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Deserialize, Debug)]
+pub struct KrakenResponse<T> {
+    pub error: Vec<String>,
+    pub result: Option<T>,
+}
+
+pub type TickerResult = HashMap<String, Ticker>;
+
+#[derive(Deserialize, Debug)]
+pub struct Ticker {
+    #[serde(rename = "a")]
+    pub ask: Vec<Decimal>,
+    #[serde(rename = "b")]
+    pub bid: Vec<Decimal>,
+    #[serde(rename = "c")]
+    pub last_trade_closed: Vec<Decimal>,
+}
+
+impl Ticker {
+    pub fn last_price(&self) -> Option<Decimal> {
+        self.last_trade_closed.first().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn last_price_data() {
+        let response_text = r#"{"error":[],"result":{"XXBTZUSD":{"a":["64000.10000","1","1.000"],"b":["63999.90000","2","2.000"],"c":["64000.00000","0.00100000"],"v":["1","2"],"p":["1","2"],"t":[1,2],"l":["1","2"],"h":["1","2"],"o":"63000.00000"}}}"#;
+        let response = serde_json::from_str::<KrakenResponse<TickerResult>>(response_text).unwrap();
+        let ticker = &response.result.unwrap()["XXBTZUSD"];
+        assert_eq!(ticker.last_price(), Some(dec!(64000)));
+    }
+}